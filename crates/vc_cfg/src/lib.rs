@@ -50,11 +50,14 @@
 //! See more infomations in:
 //! - [`disabled`]: Indicates compilation options that are not enabled.
 //! - [`enabled`]: Indicates compilation options that are enabled.
+//! - [`never`] and [`always`]: Aliases of [`disabled`] and [`enabled`].
 //! - [`switch`]: Blocks similar to 'switch'.
 //! - [`define_alias`]: Define aliases for compilation options.
 //!
 //! [`disabled`]: crate::disabled
 //! [`enabled`]: crate::enabled
+//! [`never`]: crate::never
+//! [`always`]: crate::always
 //! [`switch`]: crate::switch
 //! [`define_alias`]: crate::define_alias
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -141,6 +144,24 @@ macro_rules! enabled {
     ($($p:tt)*) => { $($p)* };
 }
 
+/// A condition that never holds, equivalent to [`disabled`].
+///
+/// Useful to temporarily turn off a branch of [`switch`] or an alias.
+///
+/// ```
+/// assert!(!vc_cfg::never!());
+/// ```
+#[doc(inline)]
+pub use disabled as never;
+
+/// A condition that always holds, equivalent to [`enabled`].
+///
+/// ```
+/// assert!(vc_cfg::always!());
+/// ```
+#[doc(inline)]
+pub use enabled as always;
+
 /// A conditional compilation macro similar to `switch` .
 ///
 /// # Example
@@ -162,11 +183,38 @@ macro_rules! enabled {
 /// }
 /// assert!(x == 1 || x == 10);
 /// ```
+///
+/// Conditions can also be combined with `all`, `any` and `not`, see
+/// [`define_alias`] for the syntax.
+///
+/// ```
+/// use vc_cfg as cfg;
+///
+/// let mut x = 0;
+/// cfg::switch! {
+///     all(cfg::enabled, not(cfg::disabled)) => {
+///         x += 1;
+///     }
+///     _ => {
+///         x += 10;
+///     }
+/// }
+/// assert_eq!(x, 1);
+/// ```
 #[macro_export]
 macro_rules! switch {
     (_ => { $($output:tt)* } $(,)?) => {
         $($output)*
     };
+    ($op:ident ( $($args:tt)* ) => { $($output:tt)* } $(,)?) => {
+        $crate::__eval! { { $($output)* } {} $op( $($args)* ) }
+    };
+    ( $op:ident ( $($args:tt)* ) => { $($output:tt)* } , $( $rest:tt )+ ) => {
+        $crate::__eval! { { $($output)* } { $crate::switch! { $($rest)+ } } $op( $($args)* ) }
+    };
+    ( $op:ident ( $($args:tt)* ) => { $($output:tt)* } $( $rest:tt )+ ) => {
+        $crate::__eval! { { $($output)* } { $crate::switch! { $($rest)+ } } $op( $($args)* ) }
+    };
     ($cond:path => { $($output:tt)* } $(,)?) => {
         $($output)*
     };
@@ -212,6 +260,35 @@ macro_rules! switch {
 /// // So `enable_test!` is eq to 'cfg::disabled'.
 /// assert!(x == false);
 /// ```
+///
+/// ## Combinations
+///
+/// Aliases can be combined with `all(..)`, `any(..)` and `not(..)`, whose
+/// operands are other aliases (including those of other crates), nested
+/// combinations, or `cfg(..)` predicates.
+///
+/// ```
+/// # #![expect(unexpected_cfgs, reason = "doc-test")]
+/// mod cfg {
+///     vc_cfg::define_alias! {
+///         #[cfg(feature = "std")] => std,
+///         #[cfg(feature = "web")] => web,
+///         all(std, not(web)) => native,
+///         any(not(std), cfg(target_arch = "wasm32")) => {
+///             /// Custom docs are appended.
+///             constrained
+///         },
+///         vc_cfg::always => always_on,
+///     }
+/// }
+///
+/// assert!(!cfg::native!());
+/// assert!(cfg::constrained!());
+/// assert!(cfg::always_on!());
+/// ```
+///
+/// A path alone re-exports the alias, which is handy to share the aliases of
+/// another crate.
 #[macro_export]
 macro_rules! define_alias {
     ( #[cfg($meta:meta)] => { $(#[$id_meta:meta])* $id:ident } $(,)? ) => {
@@ -245,4 +322,115 @@ macro_rules! define_alias {
         $crate::define_alias! { #[cfg($meta)] => { $(#[$id_meta])* $id } }
         $crate::define_alias! { $($rest)+ }
     };
+    ( $op:ident ( $($args:tt)* ) => { $(#[$id_meta:meta])* $id:ident } $(,)? ) => {
+        $crate::__eval! {
+            {
+                #[doc = concat!("An alias for `", stringify!($op($($args)*)), "` .")]
+                #[doc = concat!("\n\nSee more infomation in [`vc_cfg::define_alias`].")]
+                $(#[$id_meta])*
+                pub use $crate::enabled as $id;
+            }
+            {
+                #[doc = concat!("An alias for `", stringify!($op($($args)*)), "` .")]
+                #[doc = concat!("\n\nSee more infomation in [`vc_cfg::define_alias`].")]
+                $(#[$id_meta])*
+                pub use $crate::disabled as $id;
+            }
+            $op( $($args)* )
+        }
+    };
+    ( $op:ident ( $($args:tt)* ) => $id:ident $(,)? ) => {
+        $crate::define_alias! { $op( $($args)* ) => { $id } }
+    };
+    ( $op:ident ( $($args:tt)* ) => $id:ident , $( $rest:tt )+ ) => {
+        $crate::define_alias! { $op( $($args)* ) => { $id } }
+        $crate::define_alias! { $( $rest )+ }
+    };
+    ( $op:ident ( $($args:tt)* ) => { $(#[$id_meta:meta])* $id:ident } , $( $rest:tt )+ ) => {
+        $crate::define_alias! { $op( $($args)* ) => { $(#[$id_meta])* $id } }
+        $crate::define_alias! { $($rest)+ }
+    };
+    ( $op:ident ( $($args:tt)* ) => { $(#[$id_meta:meta])* $id:ident } $( $rest:tt )+ ) => {
+        $crate::define_alias! { $op( $($args)* ) => { $(#[$id_meta])* $id } }
+        $crate::define_alias! { $($rest)+ }
+    };
+    ( $path:path => { $(#[$id_meta:meta])* $id:ident } $(,)? ) => {
+        #[doc = concat!("A re-export of `", stringify!($path), "` .")]
+        #[doc = concat!("\n\nSee more infomation in [`vc_cfg::define_alias`].")]
+        $(#[$id_meta])*
+        pub use $path as $id;
+    };
+    ( $path:path => $id:ident $(,)? ) => {
+        $crate::define_alias! { $path => { $id } }
+    };
+    ( $path:path => $id:ident , $( $rest:tt )+ ) => {
+        $crate::define_alias! { $path => { $id } }
+        $crate::define_alias! { $( $rest )+ }
+    };
+    ( $path:path => { $(#[$id_meta:meta])* $id:ident } , $( $rest:tt )+ ) => {
+        $crate::define_alias! { $path => { $(#[$id_meta])* $id } }
+        $crate::define_alias! { $($rest)+ }
+    };
+    ( $path:path => { $(#[$id_meta:meta])* $id:ident } $( $rest:tt )+ ) => {
+        $crate::define_alias! { $path => { $(#[$id_meta])* $id } }
+        $crate::define_alias! { $($rest)+ }
+    };
+}
+
+/// Expands to the first group if the condition holds, otherwise the second.
+///
+/// The condition is an alias path, `cfg(..)`, or `all`, `any` and `not` of
+/// conditions.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __eval {
+    ( { $($t:tt)* } { $($f:tt)* } not( $($x:tt)* ) ) => {
+        $crate::__eval! { { $($f)* } { $($t)* } $($x)* }
+    };
+    ( { $($t:tt)* } { $($f:tt)* } all( $($x:tt)* ) ) => {
+        $crate::__eval! { @all { $($t)* } { $($f)* } [] $($x)* }
+    };
+    ( { $($t:tt)* } { $($f:tt)* } any( $($x:tt)* ) ) => {
+        $crate::__eval! { @any { $($t)* } { $($f)* } [] $($x)* }
+    };
+    ( { $($t:tt)* } { $($f:tt)* } cfg( $meta:meta ) ) => {
+        $crate::switch! { #[cfg($meta)] => { $($t)* } _ => { $($f)* } }
+    };
+    ( { $($t:tt)* } { $($f:tt)* } $cond:path ) => {
+        $cond! { if { $($t)* } else { $($f)* } }
+    };
+    // `all`: the operands are split at the top-level commas.
+    ( @all { $($t:tt)* } { $($f:tt)* } [] ) => {
+        $($t)*
+    };
+    ( @all { $($t:tt)* } { $($f:tt)* } [ $($cur:tt)+ ] ) => {
+        $crate::__eval! { { $($t)* } { $($f)* } $($cur)+ }
+    };
+    ( @all { $($t:tt)* } { $($f:tt)* } [ $($cur:tt)+ ] , $($rest:tt)* ) => {
+        $crate::__eval! {
+            { $crate::__eval! { @all { $($t)* } { $($f)* } [] $($rest)* } }
+            { $($f)* }
+            $($cur)+
+        }
+    };
+    ( @all { $($t:tt)* } { $($f:tt)* } [ $($cur:tt)* ] $next:tt $($rest:tt)* ) => {
+        $crate::__eval! { @all { $($t)* } { $($f)* } [ $($cur)* $next ] $($rest)* }
+    };
+    // `any`: the same as `all` with the branches swapped.
+    ( @any { $($t:tt)* } { $($f:tt)* } [] ) => {
+        $($f)*
+    };
+    ( @any { $($t:tt)* } { $($f:tt)* } [ $($cur:tt)+ ] ) => {
+        $crate::__eval! { { $($t)* } { $($f)* } $($cur)+ }
+    };
+    ( @any { $($t:tt)* } { $($f:tt)* } [ $($cur:tt)+ ] , $($rest:tt)* ) => {
+        $crate::__eval! {
+            { $($t)* }
+            { $crate::__eval! { @any { $($t)* } { $($f)* } [] $($rest)* } }
+            $($cur)+
+        }
+    };
+    ( @any { $($t:tt)* } { $($f:tt)* } [ $($cur:tt)* ] $next:tt $($rest:tt)* ) => {
+        $crate::__eval! { @any { $($t)* } { $($f)* } [ $($cur)* $next ] $($rest)* }
+    };
 }
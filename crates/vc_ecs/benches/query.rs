@@ -0,0 +1,74 @@
+//! Cost of iterating a query over a world with many archetypes.
+//!
+//! Run with `cargo bench -p vc_ecs --bench query`.
+
+use core::hint::black_box;
+use std::time::Instant;
+
+use vc_ecs::component::Component;
+use vc_ecs::query::QueryState;
+use vc_ecs::world::World;
+
+const ROUNDS: u32 = 1000;
+
+#[derive(Component)]
+struct Position(f32);
+
+#[derive(Component)]
+struct Tag<const N: usize>;
+
+fn measure(name: &str, mut f: impl FnMut()) {
+    f(); // warm up
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    let average = start.elapsed() / ROUNDS;
+    println!("{name:<24} {average:>10.3?}");
+}
+
+/// Spawns entities in 2^8 archetypes, half of them with `Position`.
+fn build_world() -> World {
+    let mut world = World::default();
+    for i in 0..256_u32 {
+        let mut entity = world.spawn(());
+        macro_rules! tag {
+            ($($n:literal)*) => {$(
+                if i & (1 << $n) != 0 {
+                    entity.insert(Tag::<$n>);
+                }
+            )*};
+        }
+        tag!(0 1 2 3 4 5 6);
+        if i & (1 << 7) != 0 {
+            entity.insert(Position(i as f32));
+        }
+    }
+    world
+}
+
+fn sum(state: &QueryState<&Position>, world: &World) -> f32 {
+    state.iter(world).map(|p| p.0).sum()
+}
+
+fn main() {
+    let mut world = build_world();
+    println!("archetypes: {}", world.archetypes().len());
+
+    // The cached state only processes archetypes created since its last update.
+    let mut cached = world.query_state::<&Position, ()>();
+    measure("cached state", || {
+        cached.update_archetypes(&world);
+        black_box(sum(&cached, &world));
+    });
+
+    // A new state matches every archetype again.
+    measure("rebuilt state", || {
+        let state = world.query_state::<&Position, ()>();
+        black_box(sum(&state, &world));
+    });
+
+    measure("World::query", || {
+        black_box(world.query::<&Position>().iter().map(|p| p.0).sum::<f32>());
+    });
+}
@@ -1,6 +1,26 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Index, parse_quote};
+use syn::{Data, DeriveInput, Field, Fields, Index, parse_quote};
+
+/// Returns `true` if the field is annotated with `#[bundle(ignore)]`.
+fn parse_ignore(field: &Field) -> syn::Result<bool> {
+    let mut ignore = false;
+
+    for attr in &field.attrs {
+        if attr.path().is_ident("bundle") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("ignore") {
+                    ignore = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported bundle field attribute, expected `ignore`"))
+                }
+            })?;
+        }
+    }
+
+    Ok(ignore)
+}
 
 pub(crate) fn impl_derive_bundle(ast: DeriveInput) -> TokenStream {
     let vc_ecs_path = crate::path::vc_ecs();
@@ -26,26 +46,26 @@ pub(crate) fn impl_derive_bundle(ast: DeriveInput) -> TokenStream {
     let field_access = match &ast.data {
         Data::Struct(data) => match &data.fields {
             Fields::Named(fields) => {
-                let access: Vec<_> = fields
+                let access: syn::Result<Vec<_>> = fields
                     .named
                     .iter()
                     .map(|field| {
                         let ident = field.ident.as_ref().unwrap();
                         let ty = &field.ty;
-                        (quote! { #ident }, ty)
+                        Ok((quote! { #ident }, ty, parse_ignore(field)?))
                     })
                     .collect();
                 access
             }
             Fields::Unnamed(fields) => {
-                let access: Vec<_> = fields
+                let access: syn::Result<Vec<_>> = fields
                     .unnamed
                     .iter()
                     .enumerate()
                     .map(|(i, field)| {
                         let idx = Index::from(i);
                         let ty = &field.ty;
-                        (quote! { #idx }, ty)
+                        Ok((quote! { #idx }, ty, parse_ignore(field)?))
                     })
                     .collect();
                 access
@@ -68,29 +88,52 @@ pub(crate) fn impl_derive_bundle(ast: DeriveInput) -> TokenStream {
         }
     };
 
-    let collect_calls = field_access.iter().map(|(_, ty)| {
-        quote! {
-            <#ty as #bundle_>::collect_components(__collector__);
-        }
-    });
+    let field_access = match field_access {
+        Ok(access) => access,
+        Err(err) => return err.into_compile_error().into(),
+    };
 
-    let write_explicit_calls = field_access.iter().map(|(ident, ty)| {
-        quote! {
-            unsafe {
-                let __offset__ = ::core::mem::offset_of!(Self, #ident) + __base__;
-                <#ty as #bundle_>::write_explicit(__writer__, __offset__);
+    let collect_calls = field_access
+        .iter()
+        .filter(|(.., ignore)| !ignore)
+        .map(|(_, ty, _)| {
+            quote! {
+                <#ty as #bundle_>::collect_components(__collector__);
             }
-        }
-    });
+        });
 
-    let write_required_calls = field_access.iter().map(|(_, ty)| {
-        quote! {
-            unsafe {
-                <#ty as #bundle_>::write_required(__writer__);
+    // Ignored fields are moved into the writer with the rest of the bundle,
+    // so they are dropped in place instead of being written.
+    let write_explicit_calls = field_access.iter().map(|(ident, ty, ignore)| {
+        if *ignore {
+            quote! {
+                unsafe {
+                    let __offset__ = ::core::mem::offset_of!(Self, #ident) + __base__;
+                    __writer__.drop_explicit::<#ty>(__offset__);
+                }
+            }
+        } else {
+            quote! {
+                unsafe {
+                    let __offset__ = ::core::mem::offset_of!(Self, #ident) + __base__;
+                    <#ty as #bundle_>::write_explicit(__writer__, __offset__);
+                }
             }
         }
     });
 
+    let write_required_calls =
+        field_access
+            .iter()
+            .filter(|(.., ignore)| !ignore)
+            .map(|(_, ty, _)| {
+                quote! {
+                    unsafe {
+                        <#ty as #bundle_>::write_required(__writer__);
+                    }
+                }
+            });
+
     quote! {
         #[expect(unsafe_code, reason = "bundle implementation is unsafe.")]
         unsafe impl #impl_generics #bundle_ for #type_ident #ty_generics #where_clause {
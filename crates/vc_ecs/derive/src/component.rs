@@ -1,6 +1,6 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{DeriveInput, Type, parse_quote};
+use syn::{DeriveInput, ExprPath, Type, parse_quote};
 
 #[derive(PartialEq, Eq)]
 enum Cloner {
@@ -19,6 +19,10 @@ struct Attributes {
     cloner: Cloner,
     storage: Storage,
     required: Option<Type>,
+    on_add: Option<ExprPath>,
+    on_remove: Option<ExprPath>,
+    on_replace: Option<ExprPath>,
+    reflect: bool,
 }
 
 fn parse_attributes(attrs: &[syn::Attribute]) -> syn::Result<Attributes> {
@@ -27,6 +31,10 @@ fn parse_attributes(attrs: &[syn::Attribute]) -> syn::Result<Attributes> {
         cloner: Cloner::None,
         storage: Storage::Dense,
         required: None,
+        on_add: None,
+        on_remove: None,
+        on_replace: None,
+        reflect: false,
     };
 
     for attr in attrs {
@@ -62,14 +70,33 @@ fn parse_attributes(attrs: &[syn::Attribute]) -> syn::Result<Attributes> {
                     let value = meta.value()?;
                     ret.required = Some(value.parse()?);
                     Ok(())
+                } else if meta.path.is_ident("on_add") {
+                    let value = meta.value()?;
+                    ret.on_add = Some(value.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("on_remove") {
+                    let value = meta.value()?;
+                    ret.on_remove = Some(value.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("on_replace") {
+                    let value = meta.value()?;
+                    ret.on_replace = Some(value.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("reflect") {
+                    ret.reflect = true;
+                    Ok(())
                 } else {
                     Err(meta.error(concat! {
                         "unsupported component attribute, expected the following:",
                         "- `copy`\n",
                         "- `clone`\n",
                         "- `mutable = true/false`\n",
-                        "- `storages = \"dense\"/\"sparse\"\n",
+                        "- `storage = \"dense\"/\"sparse\"`\n",
                         "- `required = T`, T is a Component or the tuple of Components.\n",
+                        "- `on_add = path`, path is a `fn(&World, Entity)`.\n",
+                        "- `on_remove = path`, path is a `fn(&World, Entity)`.\n",
+                        "- `on_replace = path`, path is a `fn(&World, Entity)`.\n",
+                        "- `reflect`, requires the `reflect` feature.\n",
                     }))
                 }
             });
@@ -92,6 +119,8 @@ pub(crate) fn impl_derive_component(ast: DeriveInput) -> TokenStream {
     let cloner_ = crate::path::cloner_(&vc_ecs_path);
     let component_storage_ = crate::path::component_storage_(&vc_ecs_path);
     let required_ = crate::path::required_(&vc_ecs_path);
+    let component_hook_ = crate::path::component_hook_(&vc_ecs_path);
+    let reflector_ = crate::path::reflector_(&vc_ecs_path);
 
     let mutable_tokens = (!attrs.mutable).then(|| quote! { const MUTABLE: bool = false; });
 
@@ -118,6 +147,44 @@ pub(crate) fn impl_derive_component(ast: DeriveInput) -> TokenStream {
         }
     });
 
+    let on_add_tokens = attrs.on_add.map(|path| {
+        quote! { const ON_ADD: #OptionFP<#component_hook_> = #OptionFP::Some(#path); }
+    });
+
+    let on_remove_tokens = attrs.on_remove.map(|path| {
+        quote! { const ON_REMOVE: #OptionFP<#component_hook_> = #OptionFP::Some(#path); }
+    });
+
+    let on_replace_tokens = attrs.on_replace.map(|path| {
+        quote! { const ON_REPLACE: #OptionFP<#component_hook_> = #OptionFP::Some(#path); }
+    });
+
+    let map_entities_body = match crate::map_entities::map_entities_body(&ast.data) {
+        Ok(body) => body,
+        Err(e) => return e.into_compile_error().into(),
+    };
+
+    let reflect_tokens = attrs.reflect.then(|| {
+        if map_entities_body.is_some() {
+            quote! {
+                const REFLECT: #OptionFP<#reflector_> = #OptionFP::Some(#reflector_::component_with_entities::<Self>());
+            }
+        } else {
+            quote! {
+                const REFLECT: #OptionFP<#reflector_> = #OptionFP::Some(#reflector_::component::<Self>());
+            }
+        }
+    });
+
+    let map_entities_tokens = map_entities_body.map(|body| {
+        let entity_mapper_ = crate::path::entity_mapper_(&vc_ecs_path);
+        quote! {
+            fn map_entities<E: #entity_mapper_>(this: &mut Self, mapper: &mut E) {
+                #body
+            }
+        }
+    });
+
     let type_ident = ast.ident;
 
     let mut generics = ast.generics;
@@ -141,6 +208,11 @@ pub(crate) fn impl_derive_component(ast: DeriveInput) -> TokenStream {
             #cloner_tokens
             #storage_tokens
             #required_tokens
+            #on_add_tokens
+            #on_remove_tokens
+            #on_replace_tokens
+            #reflect_tokens
+            #map_entities_tokens
         }
     }
     .into()
@@ -10,9 +10,12 @@ use syn::{DeriveInput, parse_macro_input};
 
 mod bundle;
 mod component;
+mod map_entities;
 mod path;
+mod query_data;
 mod resource;
 mod schedule;
+mod system_param;
 
 // -----------------------------------------------------------------------------
 // Macros
@@ -29,7 +32,8 @@ mod schedule;
 /// | Attribute | Description | Default |
 /// |-----------|-------------|---------|
 /// | `copy` / `clone` | Sets the cloning behavior. | Not cloneable |
-/// | `mutable = true/false` | Controls whether the resource can be mutated | `true` |
+/// | `mutable = true/false` | Controls whether the resource can be mutated | `false` |
+/// | `reflect` | Provides reflection registration, requires the `reflect` feature | None |
 ///
 /// # Examples
 ///
@@ -52,6 +56,11 @@ mod schedule;
 /// #[derive(Resource, Clone, Copy)]
 /// #[resource(copy, mutable = false)]
 /// struct GameVersion<T: Copy>(T);
+///
+/// // Resource with reflection registration
+/// #[derive(Resource, Reflect)]
+/// #[resource(reflect)]
+/// struct Score(u32);
 /// ```
 #[proc_macro_derive(Resource, attributes(resource))]
 pub fn derive_resource(input: TokenStream) -> TokenStream {
@@ -71,12 +80,22 @@ pub fn derive_resource(input: TokenStream) -> TokenStream {
 /// | Attribute | Description | Default |
 /// |-----------|-------------|---------|
 /// | `copy` / `clone` | Sets the cloning behavior. | Not cloneable |
-/// | `mutable = true/false` | Controls whether the component can be mutated | `true` |
+/// | `mutable = true/false` | Controls whether the component can be mutated | `false` |
 /// | `storage = "dense"/"sparse"` | Controls how the component is stored in memory | `"dense"` |
 /// | `required = T` | Specifies dependency components. `T` can be a single type or a tuple of types | `()` |
+/// | `on_add = path` | A `fn(&World, Entity)` invoked after the component is added to an entity | None |
+/// | `on_remove = path` | A `fn(&World, Entity)` invoked before the component is removed from an entity | None |
+/// | `on_replace = path` | A `fn(&World, Entity)` invoked after the component of an entity is overwritten | None |
+/// | `reflect` | Provides reflection registration, requires the `reflect` feature | None |
+///
+/// Fields annotated with `#[entities]` are remapped in `Component::map_entities`,
+/// they must implement `MapEntities`. With `reflect`, `ReflectMapEntities` is
+/// registered as well.
 ///
 /// **Note**: Components used in `required` must implement the `Default` trait.
 ///
+/// **Note**: Types using `reflect` must also derive `Reflect`.
+///
 /// # Examples
 ///
 /// ```ignore
@@ -103,13 +122,54 @@ pub fn derive_resource(input: TokenStream) -> TokenStream {
 /// #[derive(Component, Clone, Copy)]
 /// #[component(copy, mutable = false, required = (Foo, Bar))]
 /// struct GameVersion<T: Copy>(T);
+///
+/// // Component referencing other entities
+/// #[derive(Component)]
+/// struct Children(#[entities] Vec<Entity>);
+///
+/// // Component with hooks and reflection registration
+/// #[derive(Component, Reflect)]
+/// #[component(reflect, on_add = on_add_player, on_remove = on_remove_player)]
+/// struct Player;
+///
+/// fn on_add_player(world: &World, entity: Entity) { /* .. */ }
+/// fn on_remove_player(world: &World, entity: Entity) { /* .. */ }
 /// ```
-#[proc_macro_derive(Component, attributes(component))]
+#[proc_macro_derive(Component, attributes(component, entities))]
 pub fn derive_component(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     component::impl_derive_component(ast)
 }
 
+/// Derives the `MapEntities` trait implementation.
+///
+/// Fields annotated with `#[entities]` are mapped, they must implement
+/// `MapEntities` as well. Other fields are left untouched.
+///
+/// # Examples
+///
+/// ```ignore
+/// #[derive(MapEntities)]
+/// struct Joint {
+///     #[entities]
+///     parent: Entity,
+///     #[entities]
+///     children: Vec<Entity>,
+///     stiffness: f32,
+/// }
+///
+/// #[derive(MapEntities)]
+/// enum Target {
+///     Entity(#[entities] Entity),
+///     Position(f32, f32),
+/// }
+/// ```
+#[proc_macro_derive(MapEntities, attributes(entities))]
+pub fn derive_map_entities(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    map_entities::impl_derive_map_entities(ast)
+}
+
 /// Derives the `Bundle` trait implementation.
 ///
 /// This macro automatically implements the `Bundle` trait for your struct,
@@ -122,6 +182,13 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 /// - Components from all fields are merged when spawning entities
 /// - If duplicate components exist across fields, later fields override earlier ones
 /// - The `()` (unit) type can be used for empty bundles
+/// - Fields that are bundles themselves are flattened into the same component set
+///
+/// # Supported Attributes
+///
+/// | Attribute | Description |
+/// |-----------|-------------|
+/// | `#[bundle(ignore)]` | Excludes a field from the bundle, its value is dropped when the bundle is written |
 ///
 /// # Examples
 ///
@@ -154,13 +221,106 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 ///     first: Baz,
 ///     second: Baz,  // This value will override `first` for the same component type
 /// }
+///
+/// // Nested bundle with a non-component field
+/// // Equivalent to `(Foo, Bar, Baz)` when spawning
+/// #[derive(Bundle)]
+/// struct NestedBundle {
+///     inner: MyBundle,
+///     baz: Baz,
+///     #[bundle(ignore)]
+///     note: String,
+/// }
 /// ```
-#[proc_macro_derive(Bundle)]
+#[proc_macro_derive(Bundle, attributes(bundle))]
 pub fn derive_bundle(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     bundle::impl_derive_bundle(ast)
 }
 
+/// Derives the `QueryData` trait implementation.
+///
+/// Each field must implement `QueryData`. Fetching the struct yields a
+/// generated `{Name}Item<'w>` struct with the same field names and
+/// visibility, where every field holds the item of the corresponding
+/// query data. Entities that do not match any field are skipped.
+///
+/// `ReadOnlyQueryData` is also implemented unless `mutable` is specified,
+/// in which case all fields must be read-only.
+///
+/// # Supported Attributes
+///
+/// The `#[query_data(...)]` attribute can be used to configure the generated code:
+///
+/// | Attribute | Description | Default |
+/// |-----------|-------------|---------|
+/// | `mutable` | Allows fields with mutable access, e.g. `&mut T` | Read-only |
+/// | `derive(...)` | Derives the given traits for the item struct | None |
+///
+/// # Examples
+///
+/// ```ignore
+/// #[derive(QueryData)]
+/// #[query_data(derive(Debug))]
+/// struct PlayerQuery {
+///     entity: Entity,
+///     transform: &'static Transform,
+///     velocity: Option<&'static Velocity>,
+/// }
+///
+/// #[derive(QueryData)]
+/// #[query_data(mutable)]
+/// struct MoveQuery(&'static mut Transform, &'static Velocity);
+///
+/// fn print_players(query: Query<PlayerQuery>) {
+///     for player in query {
+///         // `player` is a `PlayerQueryItem<'_>`
+///         println!("{:?} {:?}", player.entity, player.transform);
+///     }
+/// }
+/// ```
+#[proc_macro_derive(QueryData, attributes(query_data))]
+pub fn derive_query_data(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    query_data::impl_derive_query_data(ast)
+}
+
+/// Derives the `SystemParam` trait implementation.
+///
+/// Groups several system parameters into a named struct, which is then
+/// passed to a system as a single argument. Every field must be a system
+/// parameter, they are initialized, marked and fetched in declaration order,
+/// exactly like a tuple of the same parameters.
+///
+/// The struct may only use the lifetimes `'w`, borrowed from the world, and
+/// `'s`, borrowed from the system state. Type parameters must be bounded
+/// on the struct so that every field is a valid parameter, for example
+/// `T: Resource + Sync` for `Res<'w, T>`. `ReadOnlySystemParam` is also
+/// implemented when all fields are read-only.
+///
+/// # Examples
+///
+/// ```ignore
+/// #[derive(SystemParam)]
+/// struct Movement<'w, 's> {
+///     time: Res<'w, Time>,
+///     query: Query<'w, 's, (&'static mut Transform, &'static Velocity)>,
+///     frames: Local<'s, u64>,
+/// }
+///
+/// fn movement(mut movement: Movement) {
+///     *movement.frames += 1;
+///     for (mut transform, velocity) in movement.query.iter_mut() {
+///         transform.translation += velocity.0 * movement.time.delta();
+///     }
+/// }
+/// ```
+#[proc_macro_derive(SystemParam)]
+pub fn derive_system_param(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    system_param::impl_derive_system_param(ast)
+}
+
 /// Derives the `ScheduleLabel` trait implementation.
 ///
 /// # Required Traits
@@ -0,0 +1,111 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Field, Member};
+
+const ENTITIES_ATTRIBUTE: &str = "entities";
+
+/// Returns the members of the fields annotated with `#[entities]`.
+fn entity_members<'a>(fields: impl Iterator<Item = &'a Field>) -> Vec<Member> {
+    fields
+        .enumerate()
+        .filter(|(_, field)| {
+            field
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident(ENTITIES_ATTRIBUTE))
+        })
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(index.into()),
+        })
+        .collect()
+}
+
+/// Generates the statements mapping the `#[entities]` fields of `this`,
+/// a `&mut Self` named by `this`, with `mapper`.
+///
+/// Returns `None` if there is no such field.
+pub(crate) fn map_entities_body(data: &Data) -> syn::Result<Option<TokenStream2>> {
+    let map_entities_ = crate::path::map_entities_(&crate::path::vc_ecs());
+
+    match data {
+        Data::Struct(data) => {
+            let members = entity_members(data.fields.iter());
+            if members.is_empty() {
+                return Ok(None);
+            }
+            Ok(Some(quote! {
+                #( #map_entities_::map_entities(&mut this.#members, mapper); )*
+            }))
+        }
+        Data::Enum(data) => {
+            let mut any = false;
+            let arms = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let members = entity_members(variant.fields.iter());
+                any |= !members.is_empty();
+                let bindings = (0..members.len())
+                    .map(|index| format_ident!("__entities_{}", index))
+                    .collect::<Vec<_>>();
+                quote! {
+                    Self::#ident { #( #members: #bindings, )* .. } => {
+                        #( #map_entities_::map_entities(#bindings, mapper); )*
+                    }
+                }
+            });
+            let arms = arms.collect::<Vec<_>>();
+            if !any {
+                return Ok(None);
+            }
+            Ok(Some(quote! {
+                match this {
+                    #( #arms )*
+                }
+            }))
+        }
+        Data::Union(data) => {
+            if entity_members(data.fields.named.iter()).is_empty() {
+                return Ok(None);
+            }
+            Err(syn::Error::new(
+                Span::call_site(),
+                "`#[entities]` is not supported for unions",
+            ))
+        }
+    }
+}
+
+pub(crate) fn impl_derive_map_entities(ast: DeriveInput) -> TokenStream {
+    let body = match map_entities_body(&ast.data) {
+        Ok(body) => body,
+        Err(e) => return e.into_compile_error().into(),
+    };
+
+    let vc_ecs_path = crate::path::vc_ecs();
+    let map_entities_ = crate::path::map_entities_(&vc_ecs_path);
+    let entity_mapper_ = crate::path::entity_mapper_(&vc_ecs_path);
+
+    let type_ident = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let function = match body {
+        Some(body) => quote! {
+            fn map_entities<E: #entity_mapper_>(&mut self, mapper: &mut E) {
+                let this = self;
+                #body
+            }
+        },
+        None => quote! {
+            #[inline(always)]
+            fn map_entities<E: #entity_mapper_>(&mut self, _mapper: &mut E) {}
+        },
+    };
+
+    quote! {
+        impl #impl_generics #map_entities_ for #type_ident #ty_generics #where_clause {
+            #function
+        }
+    }
+    .into()
+}
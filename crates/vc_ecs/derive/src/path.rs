@@ -31,6 +31,13 @@ pub(crate) fn cloner_(vc_ecs_path: &syn::Path) -> TokenStream {
     }
 }
 
+#[inline(always)]
+pub(crate) fn reflector_(vc_ecs_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_ecs_path::utils::Reflector
+    }
+}
+
 #[inline(always)]
 pub(crate) fn resource_(vc_ecs_path: &syn::Path) -> TokenStream {
     quote! {
@@ -52,6 +59,13 @@ pub(crate) fn required_(vc_ecs_path: &syn::Path) -> TokenStream {
     }
 }
 
+#[inline(always)]
+pub(crate) fn component_hook_(vc_ecs_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_ecs_path::component::ComponentHook
+    }
+}
+
 #[inline(always)]
 pub(crate) fn component_storage_(vc_ecs_path: &syn::Path) -> TokenStream {
     quote! {
@@ -80,6 +94,60 @@ pub(crate) fn bundle_(vc_ecs_path: &syn::Path) -> TokenStream {
     }
 }
 
+// -----------------------------------------------------------------------------
+// Entity
+
+#[inline(always)]
+pub(crate) fn entity_mapper_(vc_ecs_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_ecs_path::entity::EntityMapper
+    }
+}
+
+#[inline(always)]
+pub(crate) fn map_entities_(vc_ecs_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_ecs_path::entity::MapEntities
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Query
+
+#[inline(always)]
+pub(crate) fn query_data_(vc_ecs_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_ecs_path::query::QueryData
+    }
+}
+
+#[inline(always)]
+pub(crate) fn read_only_query_data_(vc_ecs_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_ecs_path::query::ReadOnlyQueryData
+    }
+}
+
+// -----------------------------------------------------------------------------
+// System
+
+#[inline(always)]
+pub(crate) fn system_param_(vc_ecs_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_ecs_path::system::SystemParam
+    }
+}
+
+#[inline(always)]
+pub(crate) fn read_only_system_param_(vc_ecs_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_ecs_path::system::ReadOnlySystemParam
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Schedule
+
 #[inline(always)]
 pub(crate) fn schedule_label_(vc_ecs_path: &syn::Path) -> TokenStream {
     quote! {
@@ -0,0 +1,305 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::{Data, DeriveInput, Fields, GenericParam, Index, Lifetime, LifetimeParam};
+use syn::{Path, Token, parse_quote};
+
+struct Attributes {
+    mutable: bool,
+    derives: Vec<Path>,
+}
+
+fn parse_attributes(attrs: &[syn::Attribute]) -> syn::Result<Attributes> {
+    let mut ret = Attributes {
+        mutable: false,
+        derives: Vec::new(),
+    };
+
+    for attr in attrs {
+        if attr.path().is_ident("query_data") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("mutable") {
+                    ret.mutable = true;
+                    Ok(())
+                } else if meta.path.is_ident("derive") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let paths = Punctuated::<Path, Token![,]>::parse_terminated(&content)?;
+                    ret.derives.extend(paths);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported attribute, expected `mutable` or `derive(..)`"))
+                }
+            })?;
+        }
+    }
+
+    Ok(ret)
+}
+
+pub(crate) fn impl_derive_query_data(ast: DeriveInput) -> TokenStream {
+    let vc_ecs_path = crate::path::vc_ecs();
+    let query_data_ = crate::path::query_data_(&vc_ecs_path);
+    let read_only_query_data_ = crate::path::read_only_query_data_(&vc_ecs_path);
+    let macro_utils_ = crate::path::macro_utils_(&vc_ecs_path);
+    let world_ = quote! { #vc_ecs_path::world::World };
+    let unsafe_world_ = quote! { #vc_ecs_path::world::UnsafeWorld };
+    let world_mode_ = quote! { #vc_ecs_path::world::WorldMode };
+    let tick_ = quote! { #vc_ecs_path::tick::Tick };
+    let entity_ = quote! { #vc_ecs_path::entity::Entity };
+    let archetype_ = quote! { #vc_ecs_path::archetype::Archetype };
+    let table_ = quote! { #vc_ecs_path::storage::Table };
+    let table_row_ = quote! { #vc_ecs_path::storage::TableRow };
+    let access_param_ = quote! { #vc_ecs_path::system::AccessParam };
+    let filter_param_builder_ = quote! { #vc_ecs_path::system::FilterParamBuilder };
+
+    let attributes = match parse_attributes(&ast.attrs) {
+        Ok(attributes) => attributes,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    let type_ident = &ast.ident;
+    let item_ident = format_ident!("{}Item", type_ident);
+    let vis = &ast.vis;
+
+    let fields = match &ast.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return syn::Error::new_spanned(
+                type_ident,
+                "QueryData can only be derived for structs",
+            )
+            .into_compile_error()
+            .into();
+        }
+    };
+
+    if fields.is_empty() {
+        return syn::Error::new_spanned(
+            type_ident,
+            "QueryData cannot be derived for structs without fields, use `()` instead",
+        )
+        .into_compile_error()
+        .into();
+    }
+
+    let field_vis: Vec<_> = fields.iter().map(|field| &field.vis).collect();
+    let field_types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let field_index: Vec<_> = (0..fields.len()).map(Index::from).collect();
+    let field_members: Vec<_> = fields.members().collect();
+    let field_attrs: Vec<_> = fields
+        .iter()
+        .map(|field| {
+            let docs = field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("doc"));
+            quote! { #(#docs)* }
+        })
+        .collect();
+
+    // `FieldType: QueryData` for every field, so that generic fields can be projected.
+    let mut generics = ast.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .extend(field_types.iter().map(|ty| -> syn::WherePredicate {
+            parse_quote! { #ty: #query_data_ }
+        }));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let world_lifetime = Lifetime::new("'__w", Span::call_site());
+    let mut item_generics = generics.clone();
+    item_generics.params.insert(
+        0,
+        GenericParam::Lifetime(LifetimeParam::new(world_lifetime.clone())),
+    );
+    let (_, item_ty_generics, item_where_clause) = item_generics.split_for_impl();
+    let item_generic_params = &item_generics.params;
+
+    let item_derives = if attributes.derives.is_empty() {
+        quote! {}
+    } else {
+        let derives = &attributes.derives;
+        quote! { #[derive(#(#derives),*)] }
+    };
+
+    let item_doc = format!("Automatically generated query item for [`{type_ident}`].");
+
+    let (item_struct, item_fetch) = match fields {
+        Fields::Named(_) => {
+            let idents: Vec<_> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+            let item_struct = quote! {
+                #[doc = #item_doc]
+                #item_derives
+                #vis struct #item_ident<#item_generic_params> #item_where_clause {
+                    #(
+                        #field_attrs
+                        #field_vis #idents: <#field_types as #query_data_>::Item<#world_lifetime>,
+                    )*
+                }
+            };
+            let item_fetch = quote! {
+                #item_ident {
+                    #(
+                        #idents: <#field_types as #query_data_>::fetch(
+                            &__state__.#field_index,
+                            &mut __cache__.#field_index,
+                            __entity__,
+                            __table_row__,
+                        )?,
+                    )*
+                }
+            };
+            (item_struct, item_fetch)
+        }
+        Fields::Unnamed(_) => {
+            let item_struct = quote! {
+                #[doc = #item_doc]
+                #item_derives
+                #vis struct #item_ident<#item_generic_params> (
+                    #(
+                        #field_attrs
+                        #field_vis <#field_types as #query_data_>::Item<#world_lifetime>,
+                    )*
+                ) #item_where_clause;
+            };
+            let item_fetch = quote! {
+                #item_ident (
+                    #(
+                        <#field_types as #query_data_>::fetch(
+                            &__state__.#field_index,
+                            &mut __cache__.#field_index,
+                            __entity__,
+                            __table_row__,
+                        )?,
+                    )*
+                )
+            };
+            (item_struct, item_fetch)
+        }
+        Fields::Unit => unreachable!(),
+    };
+
+    let read_only_impl = if attributes.mutable {
+        quote! {}
+    } else {
+        let mut generics = generics.clone();
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(field_types.iter().map(|ty| -> syn::WherePredicate {
+                parse_quote! { #ty: #read_only_query_data_ }
+            }));
+        let where_clause = &generics.where_clause;
+
+        quote! {
+            #[expect(unsafe_code, reason = "query data implementation is unsafe.")]
+            unsafe impl #impl_generics #read_only_query_data_ for #type_ident #ty_generics #where_clause {}
+        }
+    };
+
+    quote! {
+        #item_struct
+
+        #read_only_impl
+
+        // The fields of the query struct only describe the item and are never read.
+        const _: () = {
+            #[allow(dead_code)]
+            fn __read_fields #impl_generics (__query__: &#type_ident #ty_generics) #where_clause {
+                #( let _ = &__query__.#field_members; )*
+            }
+        };
+
+        #[expect(unsafe_code, reason = "query data implementation is unsafe.")]
+        unsafe impl #impl_generics #query_data_ for #type_ident #ty_generics #where_clause {
+            type State = ( #( <#field_types as #query_data_>::State, )* );
+            type Cache<#world_lifetime> = ( #( <#field_types as #query_data_>::Cache<#world_lifetime>, )* );
+            type Item<#world_lifetime> = #item_ident #item_ty_generics;
+
+            const COMPONENTS_ARE_DENSE: bool =
+                true #( && <#field_types as #query_data_>::COMPONENTS_ARE_DENSE )*;
+            const WORLD_MODE: #world_mode_ =
+                #world_mode_::Shared #( .merge(<#field_types as #query_data_>::WORLD_MODE) )*;
+
+            fn build_state(__world__: &mut #world_) -> Self::State {
+                ( #( <#field_types as #query_data_>::build_state(__world__), )* )
+            }
+
+            unsafe fn build_cache<#world_lifetime>(
+                __state__: &Self::State,
+                __world__: #unsafe_world_<#world_lifetime>,
+                __last_run__: #tick_,
+                __this_run__: #tick_,
+            ) -> Self::Cache<#world_lifetime> {
+                unsafe {
+                    ( #(
+                        <#field_types as #query_data_>::build_cache(
+                            &__state__.#field_index,
+                            __world__,
+                            __last_run__,
+                            __this_run__,
+                        ),
+                    )* )
+                }
+            }
+
+            fn build_filter(
+                __state__: &Self::State,
+                __out__: &mut #macro_utils_::Vec<#filter_param_builder_>,
+            ) {
+                #( <#field_types as #query_data_>::build_filter(&__state__.#field_index, __out__); )*
+            }
+
+            fn build_access(__state__: &Self::State, __out__: &mut #access_param_) -> bool {
+                true #( && <#field_types as #query_data_>::build_access(&__state__.#field_index, __out__) )*
+            }
+
+            unsafe fn set_for_arche<#world_lifetime>(
+                __state__: &Self::State,
+                __cache__: &mut Self::Cache<#world_lifetime>,
+                __arche__: &#world_lifetime #archetype_,
+                __table__: &#world_lifetime #table_,
+            ) {
+                unsafe {
+                    #(
+                        <#field_types as #query_data_>::set_for_arche(
+                            &__state__.#field_index,
+                            &mut __cache__.#field_index,
+                            __arche__,
+                            __table__,
+                        );
+                    )*
+                }
+            }
+
+            unsafe fn set_for_table<#world_lifetime>(
+                __state__: &Self::State,
+                __cache__: &mut Self::Cache<#world_lifetime>,
+                __table__: &#world_lifetime #table_,
+            ) {
+                unsafe {
+                    #(
+                        <#field_types as #query_data_>::set_for_table(
+                            &__state__.#field_index,
+                            &mut __cache__.#field_index,
+                            __table__,
+                        );
+                    )*
+                }
+            }
+
+            unsafe fn fetch<#world_lifetime>(
+                __state__: &Self::State,
+                __cache__: &mut Self::Cache<#world_lifetime>,
+                __entity__: #entity_,
+                __table_row__: #table_row_,
+            ) -> ::core::option::Option<Self::Item<#world_lifetime>> {
+                unsafe { ::core::option::Option::Some(#item_fetch) }
+            }
+        }
+    }
+    .into()
+}
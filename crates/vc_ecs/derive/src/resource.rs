@@ -12,12 +12,14 @@ enum Cloner {
 struct Attributes {
     mutable: bool,
     cloner: Cloner,
+    reflect: bool,
 }
 
 fn parse_attributes(attrs: &[syn::Attribute]) -> syn::Result<Attributes> {
     let mut ret = Attributes {
         mutable: false,
         cloner: Cloner::None,
+        reflect: false,
     };
 
     for attr in attrs {
@@ -36,12 +38,16 @@ fn parse_attributes(attrs: &[syn::Attribute]) -> syn::Result<Attributes> {
                 } else if meta.path.is_ident("copy") {
                     ret.cloner = Cloner::Copy;
                     Ok(())
+                } else if meta.path.is_ident("reflect") {
+                    ret.reflect = true;
+                    Ok(())
                 } else {
                     Err(meta.error(concat! {
                         "unsupported resource attribute, expected the following:",
                         "- `copy` \n",
                         "- `clone` \n",
                         "- `mutable = true/false` \n",
+                        "- `reflect`, requires the `reflect` feature. \n",
                     }))
                 }
             });
@@ -62,6 +68,7 @@ pub(crate) fn impl_derive_resource(ast: DeriveInput) -> TokenStream {
     let vc_ecs_path = crate::path::vc_ecs();
     let resource_ = crate::path::resource_(&vc_ecs_path);
     let cloner_ = crate::path::cloner_(&vc_ecs_path);
+    let reflector_ = crate::path::reflector_(&vc_ecs_path);
 
     let mutable_tokens = (!attrs.mutable).then(|| quote! { const MUTABLE: bool = false; });
 
@@ -75,6 +82,12 @@ pub(crate) fn impl_derive_resource(ast: DeriveInput) -> TokenStream {
         Cloner::None => None,
     };
 
+    let reflect_tokens = attrs.reflect.then(|| {
+        quote! {
+            const REFLECT: #OptionFP<#reflector_> = #OptionFP::Some(#reflector_::resource::<Self>());
+        }
+    });
+
     let type_ident = ast.ident;
     let mut generics = ast.generics.clone();
     if generics.type_params().next().is_some() {
@@ -95,6 +108,7 @@ pub(crate) fn impl_derive_resource(ast: DeriveInput) -> TokenStream {
         impl #impl_generics #resource_ for #type_ident #ty_generics #where_clause {
             #mutable_tokens
             #cloner_tokens
+            #reflect_tokens
         }
     }
     .into()
@@ -0,0 +1,174 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{ToTokens, quote};
+use syn::visit_mut::VisitMut;
+use syn::{Data, DeriveInput, GenericParam, Index, Lifetime, Type, parse_quote};
+
+/// Replaces the lifetimes `'w` and `'s` of the struct with `'static`.
+struct StaticLifetimes;
+
+impl VisitMut for StaticLifetimes {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime.ident == "w" || lifetime.ident == "s" {
+            *lifetime = Lifetime::new("'static", lifetime.span());
+        }
+    }
+}
+
+pub(crate) fn impl_derive_system_param(ast: DeriveInput) -> TokenStream {
+    use crate::path::fp::ResultFP;
+
+    let vc_ecs_path = crate::path::vc_ecs();
+    let system_param_ = crate::path::system_param_(&vc_ecs_path);
+    let read_only_system_param_ = crate::path::read_only_system_param_(&vc_ecs_path);
+    let world_ = quote! { #vc_ecs_path::world::World };
+    let unsafe_world_ = quote! { #vc_ecs_path::world::UnsafeWorld };
+    let tick_ = quote! { #vc_ecs_path::tick::Tick };
+    let access_table_ = quote! { #vc_ecs_path::system::AccessTable };
+    let ecs_error_ = quote! { #vc_ecs_path::error::EcsError };
+    let debug_name_ = quote! { #vc_ecs_path::utils::DebugName };
+    let result_ = ResultFP.to_token_stream();
+
+    let type_ident = &ast.ident;
+
+    let fields = match &ast.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return syn::Error::new_spanned(
+                type_ident,
+                "SystemParam can only be derived for structs",
+            )
+            .into_compile_error()
+            .into();
+        }
+    };
+
+    // The item of `Foo<'w, 's, T>` is `Foo<'__w, '__s, T>`.
+    let world_lifetime = Lifetime::new("'__w", Span::call_site());
+    let state_lifetime = Lifetime::new("'__s", Span::call_site());
+    let mut item_args = Vec::new();
+    for param in &ast.generics.params {
+        match param {
+            GenericParam::Lifetime(param) if param.lifetime.ident == "w" => {
+                item_args.push(quote! { #world_lifetime });
+            }
+            GenericParam::Lifetime(param) if param.lifetime.ident == "s" => {
+                item_args.push(quote! { #state_lifetime });
+            }
+            GenericParam::Lifetime(param) => {
+                return syn::Error::new_spanned(
+                    &param.lifetime,
+                    "SystemParam only supports the lifetimes `'w` (world) and `'s` (state)",
+                )
+                .into_compile_error()
+                .into();
+            }
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                item_args.push(quote! { #ident });
+            }
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                item_args.push(quote! { #ident });
+            }
+        }
+    }
+
+    let field_types: Vec<&Type> = fields.iter().map(|field| &field.ty).collect();
+    let field_index: Vec<_> = (0..fields.len()).map(Index::from).collect();
+    let field_members: Vec<_> = fields.members().collect();
+    // The parameters are used with `'static` lifetimes, so that the state does
+    // not depend on the lifetimes of the item.
+    let param_types: Vec<Type> = field_types
+        .iter()
+        .map(|&ty| {
+            let mut ty = ty.clone();
+            StaticLifetimes.visit_type_mut(&mut ty);
+            ty
+        })
+        .collect();
+
+    // No `FieldType: SystemParam` bounds, a where clause would shadow the impl
+    // of the field and hide its `Item`. The bounds of the struct must suffice.
+    let generics = &ast.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // The field types still borrow `'w` or `'s`, so these bounds are only
+    // checked when used instead of rejecting the impl.
+    let mut read_only_generics = generics.clone();
+    read_only_generics
+        .make_where_clause()
+        .predicates
+        .extend(field_types.iter().map(|ty| -> syn::WherePredicate {
+            parse_quote! { #ty: #read_only_system_param_ }
+        }));
+    let read_only_where_clause = &read_only_generics.where_clause;
+
+    let allow_unused = fields
+        .is_empty()
+        .then(|| quote! { #[allow(unused_variables, reason = "no fields to mark")] });
+
+    let build_param = if fields.is_empty() {
+        quote! {
+            unsafe fn build_param<#world_lifetime, #state_lifetime>(
+                _world: #unsafe_world_<#world_lifetime>,
+                _state: &#state_lifetime mut Self::State,
+                _last_run: #tick_,
+                _this_run: #tick_,
+            ) -> #result_<Self::Item<#world_lifetime, #state_lifetime>, #ecs_error_> {
+                #result_::Ok(#type_ident {})
+            }
+        }
+    } else {
+        quote! {
+            unsafe fn build_param<#world_lifetime, #state_lifetime>(
+                __world__: #unsafe_world_<#world_lifetime>,
+                __state__: &#state_lifetime mut Self::State,
+                __last_run__: #tick_,
+                __this_run__: #tick_,
+            ) -> #result_<Self::Item<#world_lifetime, #state_lifetime>, #ecs_error_> {
+                unsafe {
+                    #result_::Ok(#type_ident {
+                        #(
+                            #field_members: <#param_types as #system_param_>::build_param(
+                                __world__,
+                                &mut __state__.#field_index,
+                                __last_run__,
+                                __this_run__,
+                            )?,
+                        )*
+                    })
+                }
+            }
+        }
+    };
+
+    quote! {
+        #[expect(unsafe_code, reason = "system param implementation is unsafe.")]
+        unsafe impl #impl_generics #read_only_system_param_ for #type_ident #ty_generics #read_only_where_clause {}
+
+        #[expect(unsafe_code, reason = "system param implementation is unsafe.")]
+        unsafe impl #impl_generics #system_param_ for #type_ident #ty_generics #where_clause {
+            type State = ( #( <#param_types as #system_param_>::State, )* );
+            type Item<#world_lifetime, #state_lifetime> = #type_ident < #(#item_args),* >;
+
+            const NON_SEND: bool = false #( || <#param_types as #system_param_>::NON_SEND )*;
+            const EXCLUSIVE: bool = false #( || <#param_types as #system_param_>::EXCLUSIVE )*;
+
+            fn init_state(__world__: &mut #world_) -> Self::State {
+                ( #( <#param_types as #system_param_>::init_state(__world__), )* )
+            }
+
+            #allow_unused
+            fn mark_access(__table__: &mut #access_table_, __state__: &Self::State) -> bool {
+                true #( && {
+                    __table__.set_param(#debug_name_::type_name::<#param_types>());
+                    <#param_types as #system_param_>::mark_access(__table__, &__state__.#field_index)
+                } )*
+            }
+
+            #build_param
+        }
+    }
+    .into()
+}
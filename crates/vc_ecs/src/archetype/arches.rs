@@ -7,9 +7,9 @@ use core::fmt::Debug;
 use vc_os::sync::Arc;
 use vc_utils::hash::{HashMap, SparseHashSet};
 
-use crate::archetype::{ArcheId, Archetype};
+use crate::archetype::{ArcheFlags, ArcheId, Archetype};
 use crate::bundle::BundleId;
-use crate::component::ComponentId;
+use crate::component::{ComponentId, Components};
 use crate::entity::StorageId;
 use crate::storage::TableId;
 
@@ -61,7 +61,15 @@ impl Archetypes {
             }
         };
 
-        let arche = unsafe { Archetype::new(ArcheId::EMPTY, TableId::EMPTY, 0, Arc::new([])) };
+        let arche = unsafe {
+            Archetype::new(
+                ArcheId::EMPTY,
+                TableId::EMPTY,
+                0,
+                Arc::new([]),
+                ArcheFlags::empty(),
+            )
+        };
         val.arches.push(arche);
         val.bundle_map.push(Some(ArcheId::EMPTY));
         val.precise_map.insert(Arc::new([]), ArcheId::EMPTY);
@@ -110,7 +118,7 @@ impl Archetypes {
     /// This method is unsafe and requires the caller to ensure:
     ///
     /// - **Component validity**: All `ComponentId`s in `components` must be valid and
-    ///   properly registered in the component registry.
+    ///   properly registered in the component registry `infos`.
     /// - **Uniqueness**: The exact component set must not already have an archetype
     ///   (no duplicates), unless intentionally creating a new archetype for the same
     ///   set (which would violate ECS invariants).
@@ -120,6 +128,7 @@ impl Archetypes {
     ///   mapping should be updated separately via [`insert_bundle_id`](Self::insert_bundle_id).
     pub(crate) unsafe fn register(
         &mut self,
+        infos: &Components,
         table_id: TableId,
         dense_len: usize,
         components: Arc<[ComponentId]>,
@@ -133,7 +142,22 @@ impl Archetypes {
 
         let arche_id = ArcheId::new(self.arches.len() as u32);
 
-        let arche = unsafe { Archetype::new(arche_id, table_id, dense_len, components.clone()) };
+        let mut flags = ArcheFlags::empty();
+        components.iter().for_each(|&cid| {
+            let info = unsafe { infos.get_unchecked(cid) };
+            if info.on_add().is_some() {
+                flags |= ArcheFlags::ON_ADD_HOOK;
+            }
+            if info.on_remove().is_some() {
+                flags |= ArcheFlags::ON_REMOVE_HOOK;
+            }
+            if info.on_replace().is_some() {
+                flags |= ArcheFlags::ON_REPLACE_HOOK;
+            }
+        });
+
+        let arche =
+            unsafe { Archetype::new(arche_id, table_id, dense_len, components.clone(), flags) };
 
         self.arches.push(arche);
 
@@ -162,6 +186,18 @@ impl Archetypes {
         self.arches.len()
     }
 
+    /// Returns an iterator over all archetypes, ordered by `ArcheId`.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &Archetype> {
+        self.arches.iter()
+    }
+
+    /// Returns an iterator over all archetypes, with mutable access.
+    #[inline]
+    pub(crate) fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = &mut Archetype> {
+        self.arches.iter_mut()
+    }
+
     /// Returns a reference to the archetype with the given ID, if it exists.
     #[inline]
     pub fn get(&self, id: ArcheId) -> Option<&Archetype> {
@@ -2,6 +2,7 @@ use alloc::vec::Vec;
 use core::fmt::Debug;
 use vc_utils::hash::SparseHashMap;
 
+use bitflags::bitflags;
 use vc_os::sync::Arc;
 
 use crate::archetype::{ArcheId, ArcheRow};
@@ -10,6 +11,22 @@ use crate::component::ComponentId;
 use crate::entity::{Entity, MovedEntityRow};
 use crate::storage::TableId;
 
+// -----------------------------------------------------------------------------
+// ArcheFlags
+
+bitflags! {
+    /// Bitflags caching properties of the components in an archetype.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct ArcheFlags: u8 {
+        /// Set if any component has an `ON_ADD` hook.
+        const ON_ADD_HOOK = 1 << 0;
+        /// Set if any component has an `ON_REMOVE` hook.
+        const ON_REMOVE_HOOK = 1 << 1;
+        /// Set if any component has an `ON_REPLACE` hook.
+        const ON_REPLACE_HOOK = 1 << 2;
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Archetype
 
@@ -76,6 +93,8 @@ pub struct Archetype {
     // - `[dense_len..]` are stored in Maps, sorted.
     // We use Arc to reduce memory allocation overhead.
     components: Arc<[ComponentId]>,
+    // Cached properties of `components`, such as whether hooks exist.
+    flags: ArcheFlags,
     /// Maps archetype rows to their corresponding entities.
     /// The vector index = `ArcheRow`, value = `Entity`.
     /// Maintained in contiguous order for O(1) entity lookup by row.
@@ -101,17 +120,20 @@ impl Archetype {
     /// - table_id matched components
     /// - `components[..dense_len]` are stored in Tables, sorted.
     /// - `components[dense_len..]` are stored in Maps, sorted.
+    /// - `flags` matched components
     pub(super) unsafe fn new(
         arche_id: ArcheId,
         table_id: TableId,
         dense_len: usize,
         components: Arc<[ComponentId]>,
+        flags: ArcheFlags,
     ) -> Self {
         Archetype {
             id: arche_id,
             table_id,
             dense_len,
             components,
+            flags,
             entities: Vec::new(),
             after_insert: SparseHashMap::new(),
             after_remove: SparseHashMap::new(),
@@ -130,6 +152,12 @@ impl Archetype {
         self.table_id
     }
 
+    /// Returns the cached properties of the components in this archetype.
+    #[inline(always)]
+    pub fn flags(&self) -> ArcheFlags {
+        self.flags
+    }
+
     /// Returns the complete list of component types in this archetype.
     ///
     /// The returned slice combines both dense and sparse components in sorted order.
@@ -315,8 +343,14 @@ impl Archetype {
     /// - Time: O(1)
     /// - Space: O(1)
     pub unsafe fn entity_at(&mut self, row: ArcheRow) -> Entity {
-        debug_assert!((row.0 as usize) < self.entities.len());
-        unsafe { *self.entities.get_unchecked(row.0 as usize) }
+        debug_assert!((row.index()) < self.entities.len());
+        unsafe { *self.entities.get_unchecked(row.index()) }
+    }
+
+    /// Reserves capacity for at least `additional` more entities.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.entities.reserve(additional);
     }
 
     /// Inserts a new entity into this archetype, reserving space at the end.
@@ -350,7 +384,8 @@ impl Archetype {
     /// - Time: O(1)
     /// - Space: O(1)
     pub unsafe fn insert_entity(&mut self, entity: Entity) -> ArcheRow {
-        let row = ArcheRow(self.entities.len() as u32);
+        // `0 < EntityId < u32::MAX`, so `len < u32::MAX`
+        let row = unsafe { ArcheRow::from_index_unchecked(self.entities.len()) };
         self.entities.push(entity);
         row
     }
@@ -386,10 +421,10 @@ impl Archetype {
     /// - Time: O(1)
     /// - Space: O(1)
     pub unsafe fn remove_entity(&mut self, row: ArcheRow) -> MovedEntityRow {
-        debug_assert!((row.0 as usize) < self.entities.len());
+        debug_assert!((row.index()) < self.entities.len());
 
         let last = self.entities.len() - 1;
-        let dst = row.0 as usize;
+        let dst = row.index();
 
         unsafe {
             if dst == last {
@@ -404,6 +439,15 @@ impl Archetype {
         }
     }
 
+    /// Removes all entities from this archetype.
+    ///
+    /// Like [`remove_entity`](Self::remove_entity), component data and entity
+    /// locations are left to the caller.
+    #[inline]
+    pub(crate) fn clear_entities(&mut self) {
+        self.entities.clear();
+    }
+
     /// Obtain the new archetype id after inserting a Component.
     pub fn after_insert(&self, bundle: BundleId) -> Option<ArcheId> {
         self.after_insert.get(&bundle).copied()
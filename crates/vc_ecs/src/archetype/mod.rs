@@ -12,4 +12,4 @@ mod info;
 
 pub use arches::{ArcheFilter, Archetypes};
 pub use ident::{ArcheId, ArcheRow};
-pub use info::Archetype;
+pub use info::{ArcheFlags, Archetype};
@@ -0,0 +1,421 @@
+//! Frame-scoped allocation of temporary data.
+//!
+//! A [`FrameArena`] is a resource bump-allocating values that only live until
+//! the end of the frame, such as sorted entity lists or scratch buffers built
+//! by systems. Allocations only need `&FrameArena`, so systems can use it
+//! through [`Res`] in parallel.
+//!
+//! The arena is emptied by the [`FrameArena::reset`] system, usually at the
+//! end of the frame schedule. The memory is kept, so after the first frames
+//! the arena no longer calls the global allocator.
+//!
+//! [`Res`]: crate::borrow::Res
+
+use alloc::alloc as malloc;
+use alloc::vec::Vec;
+use core::alloc::Layout;
+use core::fmt;
+use core::ptr::NonNull;
+
+use vc_os::sync::{Mutex, PoisonError};
+
+use crate::borrow::ResMut;
+use crate::resource::Resource;
+#[cfg(any(debug_assertions, feature = "debug"))]
+use crate::utils::DebugName;
+
+/// The size of the first chunk allocated by an empty arena.
+const MIN_CHUNK_SIZE: usize = 16 * 1024;
+
+/// The alignment of chunks, large enough for most types.
+const CHUNK_ALIGN: usize = 16;
+
+// -----------------------------------------------------------------------------
+// Chunk
+
+/// A block of memory owned by the arena.
+struct Chunk {
+    data: NonNull<u8>,
+    layout: Layout,
+}
+
+impl Chunk {
+    fn new(size: usize, align: usize) -> Self {
+        let layout = Layout::from_size_align(size, align.max(CHUNK_ALIGN))
+            .expect("`FrameArena` chunk size overflow");
+        let data = unsafe { malloc::alloc(layout) };
+        let Some(data) = NonNull::new(data) else {
+            malloc::handle_alloc_error(layout);
+        };
+        Self { data, layout }
+    }
+}
+
+impl Drop for Chunk {
+    fn drop(&mut self) {
+        unsafe { malloc::dealloc(self.data.as_ptr(), self.layout) };
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Bump
+
+#[derive(Default)]
+struct Bump {
+    chunks: Vec<Chunk>,
+    /// The index of the chunk being allocated from.
+    current: usize,
+    /// The offset of the first free byte in the current chunk.
+    offset: usize,
+    /// The bytes allocated since the last reset, including padding.
+    allocated: usize,
+    /// The number of values whose destructors will not run.
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    leaks: usize,
+    /// The type of the first leaked value.
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    leaked: Option<DebugName>,
+}
+
+// SAFETY: `Bump` uniquely owns its chunks.
+unsafe impl Send for Bump {}
+
+impl Bump {
+    fn alloc(&mut self, layout: Layout) -> NonNull<u8> {
+        if layout.size() == 0 {
+            return unsafe {
+                NonNull::new_unchecked(core::ptr::without_provenance_mut(layout.align()))
+            };
+        }
+
+        loop {
+            if let Some(chunk) = self.chunks.get(self.current) {
+                let free = unsafe { chunk.data.add(self.offset) };
+                let start = self.offset + free.align_offset(layout.align());
+                if let Some(end) = start.checked_add(layout.size())
+                    && end <= chunk.layout.size()
+                {
+                    self.allocated += end - self.offset;
+                    self.offset = end;
+                    return unsafe { chunk.data.add(start) };
+                }
+                if self.current + 1 < self.chunks.len() {
+                    self.current += 1;
+                    self.offset = 0;
+                    continue;
+                }
+            }
+
+            let last = self.chunks.last().map_or(0, |chunk| chunk.layout.size());
+            let size = (last * 2).max(MIN_CHUNK_SIZE).max(layout.size());
+            self.chunks.push(Chunk::new(size, layout.align()));
+            self.current = self.chunks.len() - 1;
+            self.offset = 0;
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        self.chunks.iter().map(|chunk| chunk.layout.size()).sum()
+    }
+
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    fn report_leaks(&mut self) {
+        if let Some(name) = self.leaked.take() {
+            vc_os::log::warn!(
+                "{} values were leaked by `FrameArena` without running their destructors, such as `{name}`",
+                self.leaks,
+            );
+            self.leaks = 0;
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// FrameArena
+
+/// A bump allocator for temporary per-frame data.
+///
+/// Allocations borrow the arena, and are freed together when the arena is
+/// [cleared](Self::clear), usually by the [`FrameArena::reset`] system at the
+/// end of each frame. The memory is kept for the next frame, and merged into
+/// a single chunk if the frame needed more than one.
+///
+/// Destructors of allocated values are **never run**, so the arena is meant
+/// for plain data. In debug builds, values that need to be dropped are
+/// reported with `log::warn!` when the arena is cleared.
+///
+/// # Examples
+///
+/// ```
+/// use vc_ecs::arena::FrameArena;
+/// use vc_ecs::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Depth(f32);
+///
+/// fn sort_by_depth(arena: Res<FrameArena>, query: Query<(Entity, &Depth)>) {
+///     let sorted = arena.alloc_iter(query.iter().map(|(e, d)| (d.0, e)));
+///     sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+///     // ...
+/// }
+///
+/// let mut world = World::default();
+/// world.insert_resource(FrameArena::new());
+/// world.spawn(Depth(2.0));
+/// world.spawn(Depth(1.0));
+///
+/// let mut schedule = Schedule::default();
+/// let sort = schedule.add_system(sort_by_depth);
+/// let reset = schedule.add_system(FrameArena::reset);
+/// schedule.insert_order(sort, reset);
+/// schedule.run(&mut world);
+///
+/// assert_eq!(world.get_resource::<FrameArena>().unwrap().allocated_bytes(), 0);
+/// ```
+pub struct FrameArena {
+    bump: Mutex<Bump>,
+}
+
+impl Resource for FrameArena {}
+
+impl Default for FrameArena {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for FrameArena {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrameArena")
+            .field("allocated_bytes", &self.allocated_bytes())
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+impl FrameArena {
+    /// Creates an empty arena.
+    ///
+    /// No memory is allocated until the first allocation.
+    pub fn new() -> Self {
+        Self {
+            bump: Mutex::new(Bump::default()),
+        }
+    }
+
+    /// Creates an empty arena with a chunk of at least `bytes`.
+    pub fn with_capacity(bytes: usize) -> Self {
+        let mut bump = Bump::default();
+        if bytes > 0 {
+            bump.chunks.push(Chunk::new(bytes, CHUNK_ALIGN));
+        }
+        Self {
+            bump: Mutex::new(bump),
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let mut bump = self.bump.lock().unwrap_or_else(PoisonError::into_inner);
+        bump.alloc(layout)
+    }
+
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    fn track_leak<T>(&self, count: usize) {
+        if core::mem::needs_drop::<T>() && count > 0 {
+            let mut bump = self.bump.lock().unwrap_or_else(PoisonError::into_inner);
+            bump.leaks += count;
+            bump.leaked.get_or_insert(DebugName::type_name::<T>());
+        }
+    }
+
+    /// Moves `value` into the arena and returns a reference to it.
+    ///
+    /// The destructor of `value` will not run.
+    #[expect(clippy::mut_from_ref, reason = "each call returns a new allocation")]
+    pub fn alloc<T>(&self, value: T) -> &mut T {
+        crate::cfg::debug! { self.track_leak::<T>(1); }
+        let ptr = self.alloc_layout(Layout::new::<T>()).cast::<T>();
+        unsafe {
+            ptr.write(value);
+            &mut *ptr.as_ptr()
+        }
+    }
+
+    /// Copies `src` into the arena and returns the copy.
+    #[expect(clippy::mut_from_ref, reason = "each call returns a new allocation")]
+    pub fn alloc_slice<T: Copy>(&self, src: &[T]) -> &mut [T] {
+        let layout = Layout::for_value(src);
+        let ptr = self.alloc_layout(layout).cast::<T>();
+        unsafe {
+            ptr.copy_from_nonoverlapping(NonNull::from(src).cast(), src.len());
+            NonNull::slice_from_raw_parts(ptr, src.len()).as_mut()
+        }
+    }
+
+    /// Collects `iter` into the arena and returns the items.
+    ///
+    /// The memory is reserved from the upper bound of the
+    /// [size hint](Iterator::size_hint), and the unused part stays allocated
+    /// until the arena is cleared. Iterators without an upper bound, or
+    /// whose upper bound is inexact and larger than a chunk, are collected
+    /// into a temporary `Vec` first.
+    ///
+    /// The destructors of the items will not run.
+    #[expect(clippy::mut_from_ref, reason = "each call returns a new allocation")]
+    pub fn alloc_iter<T, I: IntoIterator<Item = T>>(&self, iter: I) -> &mut [T] {
+        let iter = iter.into_iter();
+        let (lower, upper) = iter.size_hint();
+        let reserved = upper.and_then(|capacity| {
+            let layout = Layout::array::<T>(capacity).ok()?;
+            (capacity == lower || layout.size() <= MIN_CHUNK_SIZE).then_some((capacity, layout))
+        });
+        let Some((capacity, layout)) = reserved else {
+            vc_utils::cold_path();
+            let mut items: Vec<T> = iter.collect();
+            return self.alloc_iter(items.drain(..));
+        };
+
+        // The lock is released before iterating, so the iterator can use the arena.
+        let ptr = self.alloc_layout(layout).cast::<T>();
+
+        let mut len = 0;
+        for item in iter.take(capacity) {
+            unsafe { ptr.add(len).write(item) };
+            len += 1;
+        }
+        crate::cfg::debug! { self.track_leak::<T>(len); }
+        unsafe { NonNull::slice_from_raw_parts(ptr, len).as_mut() }
+    }
+
+    /// Returns the bytes allocated since the arena was last cleared,
+    /// including alignment padding.
+    pub fn allocated_bytes(&self) -> usize {
+        self.bump
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .allocated
+    }
+
+    /// Returns the bytes of memory owned by the arena.
+    pub fn capacity(&self) -> usize {
+        self.bump
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .capacity()
+    }
+
+    /// Frees all allocations, keeping the memory for later use.
+    ///
+    /// If the allocations needed more than one chunk, they are replaced by a
+    /// single chunk of the same total size.
+    pub fn clear(&mut self) {
+        let bump = self.bump.get_mut().unwrap_or_else(PoisonError::into_inner);
+        crate::cfg::debug! { bump.report_leaks(); }
+
+        if bump.chunks.len() > 1 {
+            let capacity = bump.capacity();
+            bump.chunks.clear();
+            bump.chunks.push(Chunk::new(capacity, CHUNK_ALIGN));
+        }
+        bump.current = 0;
+        bump.offset = 0;
+        bump.allocated = 0;
+    }
+
+    /// Clears the arena.
+    ///
+    /// This is a system, and should run after every system using the arena,
+    /// usually at the end of the frame.
+    pub fn reset(mut arena: ResMut<Self>) {
+        arena.clear();
+    }
+}
+
+impl Drop for FrameArena {
+    fn drop(&mut self) {
+        crate::cfg::debug! {
+            self.bump
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .report_leaks();
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::{FrameArena, MIN_CHUNK_SIZE};
+
+    #[test]
+    fn alloc_and_clear() {
+        let mut arena = FrameArena::new();
+        assert_eq!(arena.capacity(), 0);
+
+        let a = arena.alloc(1_u8);
+        let b = arena.alloc_slice(&[2_u64, 3, 4]);
+        let c = arena.alloc_iter((0..5_u32).map(|x| x * x));
+        *a += 1;
+        b[0] += 1;
+        assert_eq!((*a, &*b, &*c), (2, &[3, 3, 4][..], &[0, 1, 4, 9, 16][..]));
+        assert_eq!(b.as_ptr() as usize % align_of::<u64>(), 0);
+        assert_eq!(arena.allocated_bytes(), 8 + 24 + 20);
+
+        // Larger than the first chunk.
+        let large = arena.alloc_iter(core::iter::repeat_n(7_u8, MIN_CHUNK_SIZE + 1));
+        assert!(large.iter().all(|&x| x == 7));
+        let capacity = arena.capacity();
+        assert!(capacity > MIN_CHUNK_SIZE * 2);
+
+        arena.clear();
+        assert_eq!(arena.allocated_bytes(), 0);
+        assert_eq!(arena.capacity(), capacity);
+
+        // Everything fits in the merged chunk.
+        arena.alloc_iter(core::iter::repeat_n(0_u8, MIN_CHUNK_SIZE * 2));
+        assert_eq!(arena.capacity(), capacity);
+        assert_eq!(arena.alloc_iter(core::iter::empty::<u32>()).len(), 0);
+    }
+
+    #[test]
+    fn inexact_size_hint() {
+        let arena = FrameArena::new();
+
+        // The upper bound would overflow the layout.
+        let evens = arena.alloc_iter(
+            (0..usize::MAX)
+                .filter(|x| x % 2 == 0)
+                .take_while(|&x| x < 8),
+        );
+        assert_eq!(evens, &[0, 2, 4, 6]);
+        assert_eq!(arena.allocated_bytes(), 4 * size_of::<usize>());
+
+        // The upper bound is larger than a chunk, only the items are reserved.
+        let small = arena.alloc_iter((0..MIN_CHUNK_SIZE as u32).filter(|&x| x < 2));
+        assert_eq!(small, &[0, 1]);
+        assert_eq!(arena.allocated_bytes(), 4 * size_of::<usize>() + 8);
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    fn track_leaks() {
+        use alloc::string::String;
+
+        let mut arena = FrameArena::new();
+        arena.alloc(1_u32);
+        arena.alloc_iter((0..3).map(|_| String::new()));
+        arena.alloc_iter((0..4).map(|_| String::new()).filter(|_| true));
+        {
+            let bump = arena.bump.get_mut().unwrap();
+            assert_eq!(bump.leaks, 7);
+            assert!(bump.leaked.is_some());
+        }
+
+        arena.clear();
+        let bump = arena.bump.get_mut().unwrap();
+        assert_eq!(bump.leaks, 0);
+        assert!(bump.leaked.is_none());
+    }
+}
@@ -7,7 +7,7 @@ use core::ptr::NonNull;
 use vc_ptr::{Ptr, PtrMut, ThinSlice, ThinSliceMut};
 
 use crate::resource::Resource;
-use crate::tick::{DetectChanges, Tick, TicksMut, TicksRef};
+use crate::tick::{DetectChanges, DetectChangesMut, Tick, TicksMut, TicksRef};
 use crate::tick::{TicksSliceMut, TicksSliceRef};
 
 // -----------------------------------------------------------------------------
@@ -698,6 +698,33 @@ macro_rules! impl_ref_methods {
                 })
             }
 
+            /// Narrows the reference to a part of the target, e.g. a field,
+            /// keeping the change ticks of the whole target.
+            ///
+            /// Returns the generic [`Ref`] container.
+            #[inline(always)]
+            pub fn map<U: ?Sized>(self, f: impl FnOnce(&$target) -> &U) -> Ref<'w, U> {
+                Ref {
+                    value: f(self.value),
+                    ticks: self.ticks,
+                }
+            }
+
+            /// Narrows the reference to a part of the target, if `f` returns `Some`.
+            ///
+            /// See [`map`](Self::map).
+            #[inline]
+            pub fn filter_map<U: ?Sized>(
+                self,
+                f: impl FnOnce(&$target) -> Option<&U>,
+            ) -> Option<Ref<'w, U>> {
+                let value = f(self.value)?;
+                Some(Ref {
+                    value,
+                    ticks: self.ticks,
+                })
+            }
+
             /// Dereferences the inner type, e.g., converts `Ref<'a, Box<T>>` to `Ref<'a, T>`.
             ///
             /// Returns the generic [`Ref`] container.
@@ -812,6 +839,45 @@ macro_rules! impl_mut_methods {
                 })
             }
 
+            /// Narrows the reference to a part of the target, e.g. a field.
+            ///
+            /// Returns the generic [`Mut`] container, which writes through to the change
+            /// ticks of the whole target: it is marked as changed once the returned value
+            /// is mutably dereferenced, not by this call. This allows passing a part of a
+            /// component down a call tree without marking it changed up front.
+            ///
+            /// # Examples
+            ///
+            /// ```ignore
+            /// fn heal(mut health: Mut<f32>) {
+            ///     health.set_if_neq(100.0);
+            /// }
+            ///
+            /// heal(player.map(|p| &mut p.health));
+            /// ```
+            #[inline(always)]
+            pub fn map<U: ?Sized>(self, f: impl FnOnce(&mut $target) -> &mut U) -> Mut<'w, U> {
+                Mut {
+                    value: f(self.value),
+                    ticks: self.ticks,
+                }
+            }
+
+            /// Narrows the reference to a part of the target, if `f` returns `Some`.
+            ///
+            /// See [`map`](Self::map).
+            #[inline]
+            pub fn filter_map<U: ?Sized>(
+                self,
+                f: impl FnOnce(&mut $target) -> Option<&mut U>,
+            ) -> Option<Mut<'w, U>> {
+                let value = f(self.value)?;
+                Some(Mut {
+                    value,
+                    ticks: self.ticks,
+                })
+            }
+
             /// Dereferences the inner type, e.g., converts `Mut<'a, Box<T>>` to `Mut<'a, T>`.
             ///
             /// Returns the generic [`Mut`] container.
@@ -859,7 +925,6 @@ macro_rules! impl_change_detection {
             fn added_tick(&self) -> Tick {
                 *self.ticks.added
             }
-
         }
     }
 }
@@ -871,6 +936,36 @@ impl_change_detection!(NonSendMut<'w, T>, T, Resource);
 impl_change_detection!(Ref<'w, T>, T,);
 impl_change_detection!(Mut<'w, T>, T,);
 
+// -----------------------------------------------------------------------------
+// impl_change_detection_mut
+
+macro_rules! impl_change_detection_mut {
+    ($name:ident < $( $generics:tt ),+ >, $target:ty, $($traits:ident)*) => {
+        impl<$($generics),* : ?Sized $(+ $traits)*> DetectChangesMut for $name<$($generics),*> {
+            type Inner = $target;
+
+            #[inline(always)]
+            fn set_changed(&mut self) {
+                *self.ticks.changed = self.ticks.this_run;
+            }
+
+            #[inline(always)]
+            fn set_last_changed(&mut self, last_changed: Tick) {
+                *self.ticks.changed = last_changed;
+            }
+
+            #[inline(always)]
+            fn bypass_change_detection(&mut self) -> &mut $target {
+                self.value
+            }
+        }
+    }
+}
+
+impl_change_detection_mut!(ResMut<'w, T>, T, Resource Send);
+impl_change_detection_mut!(NonSendMut<'w, T>, T, Resource);
+impl_change_detection_mut!(Mut<'w, T>, T,);
+
 // -----------------------------------------------------------------------------
 // impl_deref
 
@@ -1414,3 +1509,43 @@ impl Debug for UntypedSliceMut<'_> {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::Resource;
+    use crate::tick::{DetectChanges, DetectChangesMut};
+    use crate::world::World;
+
+    #[derive(Resource, Debug, PartialEq)]
+    struct Player {
+        health: u32,
+        items: Option<u32>,
+    }
+
+    #[test]
+    fn map_mut() {
+        let mut world = World::default();
+        world.insert_resource(Player {
+            health: 10,
+            items: None,
+        });
+        world.update_tick();
+
+        let mut player = world.get_resource_mut::<Player>().unwrap();
+        let mut health = player.reborrow().map(|p| &mut p.health);
+        assert!(!health.set_if_neq(10));
+        assert!(!health.is_changed());
+        *health.bypass_change_detection() = 20;
+        assert!(!player.is_changed());
+
+        assert!(player.reborrow().filter_map(|p| p.items.as_mut()).is_none());
+
+        let mut health = player.map(|p| &mut p.health);
+        assert!(health.set_if_neq(30));
+        assert!(health.is_changed());
+
+        let player = world.get_resource_ref::<Player>().unwrap();
+        assert!(player.is_changed());
+        assert_eq!(*player.map(|p| &p.health), 30);
+    }
+}
@@ -7,9 +7,10 @@ use crate::bundle::Bundle;
 use crate::command::EntityCommands;
 use crate::entity::Entity;
 use crate::error::EcsError;
+use crate::resource::Resource;
 use crate::system::{AccessTable, ReadOnlySystemParam, SystemParam};
 use crate::tick::Tick;
-use crate::world::{UnsafeWorld, World, WorldId};
+use crate::world::{FromWorld, UnsafeWorld, World, WorldId};
 
 /// A deferred command buffer used to optimize System parallelism.
 ///
@@ -119,7 +120,7 @@ impl<'a> Commands<'a> {
     pub fn flush(&mut self) {
         if !self.buffer.is_empty() {
             let commands = ::core::mem::take(&mut self.buffer);
-            self.world.command_queue.extend(commands);
+            self.world.pending.commands.extend(commands);
         }
     }
 
@@ -323,6 +324,31 @@ impl<'a> Commands<'a> {
         }));
     }
 
+    /// Initializes a resource with [`FromWorld`] if it does not exist.
+    ///
+    /// See [`World::init_resource`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vc_ecs::prelude::*;
+    ///
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u64);
+    /// #
+    /// fn example(mut commands: Commands) {
+    ///     commands.init_resource::<Score>();
+    /// }
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn init_resource<T: Resource + Send + FromWorld>(&mut self) {
+        self.buffer.push(CommandObject::new(|world| {
+            world.init_resource::<T>();
+            Ok(())
+        }));
+    }
+
     /// Return an `EntityCommands` instance for further operations on the spawned entity.
     ///
     /// This function will flushes any pending commands in the current buffer,
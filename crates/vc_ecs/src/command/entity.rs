@@ -166,6 +166,48 @@ impl<'a> EntityCommands<'a> {
         });
     }
 
+    /// Inserts the components of a bundle that the target entity does not
+    /// contain yet, see [`EntityOwned::insert_if_new`].
+    ///
+    /// This operation is deferred and will run when commands are applied.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vc_ecs::prelude::*;
+    ///
+    /// # #[derive(Component)]
+    /// # struct Health(u32);
+    /// #
+    /// fn example(mut commands: Commands) {
+    ///     let mut entity = commands.spawn(Health(50));
+    ///     // Keeps `Health(50)`.
+    ///     entity.insert_if_new(Health(100));
+    /// }
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn insert_if_new<B: Bundle>(&mut self, bundle: B) {
+        self.push(move |mut entity| {
+            entity.insert_if_new(bundle);
+            Ok(())
+        });
+    }
+
+    /// Attempts to insert a bundle into the target entity,
+    /// silently ignoring failures if it has been despawned.
+    ///
+    /// This operation is deferred and will run when commands are applied.
+    #[inline]
+    #[track_caller]
+    pub fn try_insert<B: Bundle>(&mut self, bundle: B) {
+        let entity = self.entity;
+        self.commands.push(move |world| {
+            let _ = world.try_insert(entity, bundle);
+            Ok(())
+        });
+    }
+
     /// Removes a bundle from the target entity.
     ///
     /// This operation is deferred and will run when commands are applied.
@@ -0,0 +1,242 @@
+use core::any::TypeId;
+
+use thiserror::Error;
+use vc_reflect::Reflect;
+use vc_reflect::access::{CompileError, CompiledPath, PathAccessor};
+use vc_reflect::registry::TypeRegistry;
+
+use crate::component::{ComponentId, ReflectComponent};
+use crate::utils::DebugName;
+use crate::world::{EntityMut, EntityRef, World};
+
+// -----------------------------------------------------------------------------
+// PropertyBindingError
+
+/// An error returned by [`PropertyBinding::new`].
+#[derive(Error, Debug, Clone)]
+pub enum PropertyBindingError {
+    /// The type is not registered in the [`TypeRegistry`].
+    #[error("type is not registered in the `TypeRegistry`")]
+    NotRegistered,
+    /// The type is not registered as a component in the world.
+    #[error("type `{0}` is not registered as a component")]
+    NotComponent(&'static str),
+    /// The component is not registered with `ReflectComponent`.
+    #[error("component `{0}` is not registered with `ReflectComponent`")]
+    NotReflected(DebugName),
+    /// The path cannot be compiled for the component type.
+    #[error(transparent)]
+    Compile(#[from] CompileError),
+}
+
+// -----------------------------------------------------------------------------
+// PropertyBinding
+
+/// A property of a component, bound by a compiled reflection path.
+///
+/// The component type and the path are resolved once in [`PropertyBinding::new`],
+/// so reading and writing the property of an entity only looks up the component
+/// and follows the [`CompiledPath`]. This is the primitive that animation and
+/// tweening systems use to drive many properties every frame.
+///
+/// Writing through a binding marks the component as changed.
+///
+/// # Examples
+///
+/// ```
+/// use core::any::TypeId;
+/// use vc_ecs::prelude::*;
+/// use vc_ecs::component::PropertyBinding;
+/// use vc_reflect::access::PathAccessor;
+/// use vc_reflect::prelude::*;
+///
+/// #[derive(Component, Reflect)]
+/// #[component(reflect, mutable = true)]
+/// struct Transform {
+///     translation: [f32; 3],
+///     scale: f32,
+/// }
+///
+/// let mut world = World::default();
+/// let entity = world.spawn(Transform { translation: [0.0; 3], scale: 1.0 }).entity();
+///
+/// let mut registry = TypeRegistry::new();
+/// world.register_reflect_types(&mut registry);
+///
+/// let path = PathAccessor::parse_static(".translation").unwrap();
+/// let binding = PropertyBinding::new(&world, &registry, TypeId::of::<Transform>(), &path).unwrap();
+///
+/// let mut entity_mut = world.entity_mut(entity);
+/// assert!(binding.write(&mut entity_mut, [1.0_f32, 2.0, 3.0]));
+///
+/// let entity_ref = world.entity_ref(entity);
+/// assert_eq!(binding.read::<[f32; 3]>(&entity_ref), Some([1.0, 2.0, 3.0]));
+/// ```
+#[derive(Clone)]
+pub struct PropertyBinding {
+    component_id: ComponentId,
+    reflect: ReflectComponent,
+    path: CompiledPath,
+}
+
+impl PropertyBinding {
+    /// Binds `path` on the component with the given `type_id`.
+    ///
+    /// The component must be registered in `world`, and registered with
+    /// [`ReflectComponent`] in `registry`, e.g. by [`World::register_reflect_types`].
+    pub fn new(
+        world: &World,
+        registry: &TypeRegistry,
+        type_id: TypeId,
+        path: &PathAccessor,
+    ) -> Result<Self, PropertyBindingError> {
+        let Some(meta) = registry.get(type_id) else {
+            return Err(PropertyBindingError::NotRegistered);
+        };
+        let info = meta.type_info();
+        let Some(component_id) = world.components().get_id(type_id) else {
+            return Err(PropertyBindingError::NotComponent(info.type_path()));
+        };
+        let Some(reflect) = meta.get_trait::<ReflectComponent>() else {
+            let name = world.components().get(component_id).unwrap().debug_name();
+            return Err(PropertyBindingError::NotReflected(name));
+        };
+        let path = path.compile(info)?;
+
+        Ok(Self {
+            component_id,
+            reflect: reflect.clone(),
+            path,
+        })
+    }
+
+    /// Returns the id of the bound component.
+    #[inline]
+    pub fn component_id(&self) -> ComponentId {
+        self.component_id
+    }
+
+    /// Returns the compiled path of the property.
+    #[inline]
+    pub fn path(&self) -> &CompiledPath {
+        &self.path
+    }
+
+    /// Returns the property of the entity as a reflected value.
+    ///
+    /// Returns `None` if the entity does not contain the component,
+    /// or if a list index of the path is out of bounds.
+    #[inline]
+    pub fn get<'a>(&self, entity: &'a EntityRef<'_>) -> Option<&'a dyn Reflect> {
+        self.path.access(self.reflect.get(entity)?)
+    }
+
+    /// Returns the property of the entity as a mutable reflected value,
+    /// marking the component as changed.
+    ///
+    /// Returns `None` if the entity does not contain the component,
+    /// or if a list index of the path is out of bounds.
+    #[inline]
+    pub fn get_mut<'a>(&self, entity: &'a mut EntityMut<'_>) -> Option<&'a mut dyn Reflect> {
+        self.path.access_mut(self.reflect.get_mut(entity)?)
+    }
+
+    /// Returns a copy of the property of the entity.
+    ///
+    /// Returns `None` if the property cannot be accessed or is not `T`.
+    #[inline]
+    pub fn read<T: Reflect + Copy>(&self, entity: &EntityRef<'_>) -> Option<T> {
+        self.get(entity)?.downcast_ref::<T>().copied()
+    }
+
+    /// Writes `value` to the property of the entity, marking the component as changed.
+    ///
+    /// Returns `false` if the property cannot be accessed or is not `T`,
+    /// in which case the component is only marked as changed if it exists
+    /// and the property type matches.
+    pub fn write<T: Reflect>(&self, entity: &mut EntityMut<'_>, value: T) -> bool {
+        if self.path.target_info().type_id() != TypeId::of::<T>() {
+            return false;
+        }
+        match self.get_mut(entity).and_then(|it| it.downcast_mut::<T>()) {
+            Some(it) => {
+                *it = value;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::any::TypeId;
+
+    use vc_reflect::Reflect;
+    use vc_reflect::access::PathAccessor;
+    use vc_reflect::registry::TypeRegistry;
+
+    use super::{PropertyBinding, PropertyBindingError};
+    use crate::component::Component;
+    use crate::world::{World, WorldIdAllocator};
+
+    #[derive(Component, Reflect)]
+    #[component(reflect, mutable = true)]
+    struct Transform {
+        translation: [f32; 3],
+        weights: Vec<f32>,
+    }
+
+    #[derive(Component, Reflect)]
+    struct Hidden(f32);
+
+    #[test]
+    fn read_write() {
+        let allocator = WorldIdAllocator::new();
+        let mut world = World::new(allocator.alloc());
+        let a = world
+            .spawn(Transform {
+                translation: [0.0; 3],
+                weights: vec![0.5],
+            })
+            .entity();
+        let b = world.spawn(Hidden(1.0)).entity();
+
+        let mut registry = TypeRegistry::new();
+        world.register_reflect_types(&mut registry);
+        registry.register::<Hidden>();
+
+        let bind = |type_id, path| {
+            let path = PathAccessor::parse_static(path).unwrap();
+            PropertyBinding::new(&world, &registry, type_id, &path)
+        };
+        let y = bind(TypeId::of::<Transform>(), ".translation[1]").unwrap();
+        let weight = bind(TypeId::of::<Transform>(), ".weights[1]").unwrap();
+        assert!(matches!(
+            bind(TypeId::of::<Transform>(), ".scale"),
+            Err(PropertyBindingError::Compile(_))
+        ));
+        assert!(matches!(
+            bind(TypeId::of::<Hidden>(), ".0"),
+            Err(PropertyBindingError::NotReflected(_))
+        ));
+        assert!(matches!(
+            bind(TypeId::of::<WorldIdAllocator>(), ".0"),
+            Err(PropertyBindingError::NotRegistered)
+        ));
+
+        let mut entity = world.entity_mut(a);
+        assert!(y.write(&mut entity, 2.0_f32));
+        assert!(!y.write(&mut entity, 2.0_f64));
+        assert!(!weight.write(&mut entity, 1.0_f32));
+        assert_eq!(entity.get::<Transform>().unwrap().translation[1], 2.0);
+
+        assert_eq!(y.read::<f32>(&world.entity_ref(a)), Some(2.0));
+        assert_eq!(y.read::<f32>(&world.entity_ref(b)), None);
+    }
+}
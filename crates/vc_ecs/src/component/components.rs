@@ -4,7 +4,7 @@ use alloc::vec::Vec;
 use core::any::TypeId;
 use core::fmt::Debug;
 
-use vc_utils::extra::TypeIdMap;
+use vc_utils::extra::{TypeIdEntry, TypeIdMap, VacantTypeIdEntry};
 
 use super::{Component, ComponentId, ComponentInfo};
 use super::{ComponentDescriptor, ComponentRegistrar};
@@ -40,6 +40,12 @@ impl Components {
         self.infos.len()
     }
 
+    /// Returns an iterator over all registered component infos, ordered by ID.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &ComponentInfo> {
+        self.infos.iter()
+    }
+
     /// Looks up a component ID by its [`TypeId`].
     #[inline]
     pub fn get_id(&self, type_id: TypeId) -> Option<ComponentId> {
@@ -89,26 +95,30 @@ impl Components {
         // thereby improving the execution speed of the hot path.
         #[cold]
         #[inline(never)]
-        fn register_internal<T: Component>(this: &mut Components) -> ComponentId {
-            let type_id = TypeId::of::<T>();
+        fn register_internal<T: Component>(
+            infos: &mut Vec<ComponentInfo>,
+            entry: VacantTypeIdEntry<'_, ComponentId>,
+        ) -> ComponentId {
             let descriptor = ComponentDescriptor::new::<T>();
-            let component_id = ComponentId::new(this.infos.len() as u32);
-
-            this.infos
-                .push(ComponentInfo::new(component_id, descriptor));
-            this.mapper.insert(type_id, component_id);
+            let component_id = ComponentId::new(infos.len() as u32);
 
-            if let Some(required) = T::REQUIRED {
-                required.register(&mut ComponentRegistrar::new(this));
-            }
+            infos.push(ComponentInfo::new(component_id, descriptor));
+            entry.insert(component_id);
 
             component_id
         }
 
-        if let Some(id) = self.get_id(TypeId::of::<T>()) {
-            id
-        } else {
-            register_internal::<T>(self)
+        match self.mapper.entry_type::<T>() {
+            TypeIdEntry::Occupied(entry) => *entry.get(),
+            TypeIdEntry::Vacant(entry) => {
+                let component_id = register_internal::<T>(&mut self.infos, entry);
+
+                if let Some(required) = T::REQUIRED {
+                    required.register(&mut ComponentRegistrar::new(self));
+                }
+
+                component_id
+            }
         }
     }
 }
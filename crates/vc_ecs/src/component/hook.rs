@@ -0,0 +1,30 @@
+use crate::entity::Entity;
+use crate::world::World;
+
+// -----------------------------------------------------------------------------
+// ComponentHook
+
+/// A function invoked when a component is added to, overwritten on or removed
+/// from an entity.
+///
+/// Hooks are configured through [`Component::ON_ADD`], [`Component::ON_REPLACE`]
+/// and [`Component::ON_REMOVE`]:
+///
+/// - `ON_ADD` runs after the component has been written, for components that
+///   the entity did not have before (spawn, insert, required components).
+/// - `ON_REPLACE` runs after an existing component has been overwritten by an
+///   insert in [`InsertMode::Replace`]; `ON_ADD` and `ON_REMOVE` do not run.
+/// - `ON_REMOVE` runs before the component is dropped (remove, despawn).
+///
+/// Hooks only receive shared access to the [`World`]. Structural changes,
+/// such as spawning entities or inserting components, can be queued through
+/// [`World::pending`] or [`Commands::new`], and are applied by [`World::flush`].
+///
+/// [`Component::ON_ADD`]: crate::component::Component::ON_ADD
+/// [`Component::ON_REMOVE`]: crate::component::Component::ON_REMOVE
+/// [`Component::ON_REPLACE`]: crate::component::Component::ON_REPLACE
+/// [`InsertMode::Replace`]: crate::component::InsertMode::Replace
+/// [`Commands::new`]: crate::command::Commands::new
+/// [`World::pending`]: crate::world::World::pending
+/// [`World::flush`]: crate::world::World::flush
+pub type ComponentHook = fn(&World, Entity);
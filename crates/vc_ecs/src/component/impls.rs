@@ -4,7 +4,7 @@
 //! must implement, along with associated configuration constants that control
 //! component behavior within the system.
 
-use super::{ComponentStorage, Required};
+use super::{ComponentHook, ComponentStorage, Required};
 use crate::entity::EntityMapper;
 use crate::utils::{Cloner, Dropper};
 
@@ -105,6 +105,27 @@ use crate::utils::{Cloner, Dropper};
 ///
 /// With the derive macro, use `#[component(required = T)]`.
 ///
+/// ## Hooks
+///
+/// [`Component::ON_ADD`] and [`Component::ON_REMOVE`] are invoked when the
+/// component is added to or removed from an entity, [`Component::ON_REPLACE`]
+/// when an existing value is overwritten. All default to `None`.
+///
+/// With the derive macro, use `#[component(on_add = path, on_remove = path)]`
+/// or `on_replace = path`, where `path` refers to a function matching
+/// [`ComponentHook`].
+///
+/// ## Reflect
+///
+/// With the `reflect` feature, `Component::REFLECT` allows
+/// [`World::register_reflect_types`] to register the component into a type
+/// registry, together with `ReflectComponent`.
+///
+/// With the derive macro, use `#[component(reflect)]`. The type must also
+/// derive `Reflect`.
+///
+/// [`World::register_reflect_types`]: crate::world::World::register_reflect_types
+///
 /// ## Dropper
 ///
 /// [`Component::DROPPER`] stores the function pointer for [`Drop::drop`].
@@ -143,6 +164,19 @@ pub trait Component: Sized + Send + Sync + 'static {
     /// The required components, default is `None`.
     const REQUIRED: Option<Required> = None;
 
+    /// The hook invoked after the component is added to an entity, default is `None`.
+    const ON_ADD: Option<ComponentHook> = None;
+
+    /// The hook invoked before the component is removed from an entity, default is `None`.
+    const ON_REMOVE: Option<ComponentHook> = None;
+
+    /// The hook invoked after the component of an entity is overwritten, default is `None`.
+    const ON_REPLACE: Option<ComponentHook> = None;
+
+    /// The reflection registration of the component, default is `None`.
+    #[cfg(feature = "reflect")]
+    const REFLECT: Option<crate::utils::Reflector> = None;
+
     /// Maps the entities on this component using the given [`EntityMapper`].
     ///
     /// This is used to remap entities in contexts like scenes and entity cloning.
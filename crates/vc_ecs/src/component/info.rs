@@ -2,7 +2,7 @@ use core::alloc::Layout;
 use core::any::TypeId;
 use core::fmt::Debug;
 
-use super::{Component, ComponentId, ComponentStorage, Required};
+use super::{Component, ComponentHook, ComponentId, ComponentStorage, Required};
 use crate::utils::{Cloner, DebugName, Dropper};
 
 // -----------------------------------------------------------------------------
@@ -22,6 +22,11 @@ pub struct ComponentDescriptor {
     pub dropper: Option<Dropper>,
     pub cloner: Option<Cloner>,
     pub required: Option<Required>,
+    pub on_add: Option<ComponentHook>,
+    pub on_remove: Option<ComponentHook>,
+    pub on_replace: Option<ComponentHook>,
+    #[cfg(feature = "reflect")]
+    pub reflect: Option<crate::utils::Reflector>,
 }
 
 impl ComponentDescriptor {
@@ -37,6 +42,11 @@ impl ComponentDescriptor {
                 dropper: T::DROPPER,
                 cloner: T::CLONER,
                 required: T::REQUIRED,
+                on_add: T::ON_ADD,
+                on_remove: T::ON_REMOVE,
+                on_replace: T::ON_REPLACE,
+                #[cfg(feature = "reflect")]
+                reflect: T::REFLECT,
             }
         }
     }
@@ -124,4 +134,29 @@ impl ComponentInfo {
     pub fn required(&self) -> Option<Required> {
         self.descriptor.required
     }
+
+    /// Returns the hook invoked after the component is added.
+    #[inline(always)]
+    pub fn on_add(&self) -> Option<ComponentHook> {
+        self.descriptor.on_add
+    }
+
+    /// Returns the hook invoked before the component is removed.
+    #[inline(always)]
+    pub fn on_remove(&self) -> Option<ComponentHook> {
+        self.descriptor.on_remove
+    }
+
+    /// Returns the hook invoked after the component is overwritten.
+    #[inline(always)]
+    pub fn on_replace(&self) -> Option<ComponentHook> {
+        self.descriptor.on_replace
+    }
+
+    /// Returns the component's reflection registration.
+    #[cfg(feature = "reflect")]
+    #[inline(always)]
+    pub fn reflect(&self) -> Option<crate::utils::Reflector> {
+        self.descriptor.reflect
+    }
 }
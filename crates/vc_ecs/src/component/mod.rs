@@ -4,6 +4,7 @@
 // Modules
 
 mod components;
+mod hook;
 mod ident;
 mod impls;
 mod info;
@@ -11,15 +12,26 @@ mod required;
 mod storage;
 mod tools;
 
+crate::cfg::reflect! {
+    mod binding;
+    mod reflect;
+}
+
 // -----------------------------------------------------------------------------
 // Exports
 
 pub use vc_ecs_derive::Component;
 
 pub use components::Components;
+pub use hook::ComponentHook;
 pub use ident::ComponentId;
 pub use impls::Component;
 pub use info::{ComponentDescriptor, ComponentInfo};
 pub use required::{Required, RequiredComponents};
 pub use storage::ComponentStorage;
 pub use tools::*;
+
+crate::cfg::reflect! {
+    pub use binding::{PropertyBinding, PropertyBindingError};
+    pub use reflect::{ReflectComponent, ReflectMapEntities};
+}
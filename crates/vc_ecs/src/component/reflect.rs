@@ -0,0 +1,193 @@
+use vc_reflect::Reflect;
+use vc_reflect::info::{TypePath, Typed};
+use vc_reflect::registry::FromType;
+
+use crate::component::Component;
+use crate::entity::EntityMapper;
+use crate::world::{EntityMut, EntityRef};
+
+// -----------------------------------------------------------------------------
+// ReflectComponent
+
+/// A type trait providing reflected access to a component of an entity.
+///
+/// It is registered by [`World::register_reflect_types`] for components
+/// annotated with `#[component(reflect)]`, or manually:
+///
+/// ```
+/// use core::any::TypeId;
+/// use vc_ecs::prelude::*;
+/// use vc_ecs::component::ReflectComponent;
+/// use vc_reflect::prelude::*;
+///
+/// #[derive(Component, Reflect)]
+/// struct Health(u32);
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Health>();
+/// registry.register_type_trait::<Health, ReflectComponent>();
+///
+/// let mut world = World::default();
+/// let entity = world.spawn(Health(10)).entity();
+///
+/// let reflect = registry.get_type_trait::<ReflectComponent>(TypeId::of::<Health>()).unwrap();
+/// let entity = world.entity_ref(entity);
+/// let value = reflect.get(&entity).unwrap();
+/// assert_eq!(value.downcast_ref::<Health>().unwrap().0, 10);
+/// ```
+///
+/// [`World::register_reflect_types`]: crate::world::World::register_reflect_types
+#[derive(Clone)]
+pub struct ReflectComponent {
+    contains: fn(&EntityRef<'_>) -> bool,
+    get: for<'a> fn(&'a EntityRef<'_>) -> Option<&'a dyn Reflect>,
+    get_mut: for<'a> fn(&'a mut EntityMut<'_>) -> Option<&'a mut dyn Reflect>,
+}
+
+impl ReflectComponent {
+    /// Returns `true` if the entity contains the component.
+    #[inline(always)]
+    pub fn contains(&self, entity: &EntityRef<'_>) -> bool {
+        (self.contains)(entity)
+    }
+
+    /// Returns the component of the entity as a reflected value.
+    #[inline(always)]
+    pub fn get<'a>(&self, entity: &'a EntityRef<'_>) -> Option<&'a dyn Reflect> {
+        (self.get)(entity)
+    }
+
+    /// Returns the component of the entity as a mutable reflected value,
+    /// marking it as changed.
+    #[inline(always)]
+    pub fn get_mut<'a>(&self, entity: &'a mut EntityMut<'_>) -> Option<&'a mut dyn Reflect> {
+        (self.get_mut)(entity)
+    }
+}
+
+impl<T: Component + Reflect + Typed> FromType<T> for ReflectComponent {
+    fn from_type() -> Self {
+        Self {
+            contains: |entity| entity.contains::<T>(),
+            get: |entity| entity.get::<T>().map(|value| value as &dyn Reflect),
+            get_mut: |entity| {
+                entity
+                    .get_mut::<T>()
+                    .map(|value| value.into_inner() as &mut dyn Reflect)
+            },
+        }
+    }
+}
+
+impl TypePath for ReflectComponent {
+    #[inline(always)]
+    fn type_path() -> &'static str {
+        "vc_ecs::component::ReflectComponent"
+    }
+
+    #[inline(always)]
+    fn type_name() -> &'static str {
+        "ReflectComponent"
+    }
+
+    #[inline(always)]
+    fn type_ident() -> &'static str {
+        "ReflectComponent"
+    }
+
+    #[inline(always)]
+    fn module_path() -> Option<&'static str> {
+        Some("vc_ecs::component")
+    }
+}
+
+// -----------------------------------------------------------------------------
+// ReflectMapEntities
+
+/// A type trait mapping the entities referenced by a reflected component.
+///
+/// Entities stored in serialized data, e.g. a scene, are not valid in the
+/// world they are loaded into. After the entities are spawned, the loaded
+/// component values should be passed to [`map_entities`] with a mapper from
+/// the serialized entities to the spawned ones, before they are inserted.
+///
+/// It is registered by [`World::register_reflect_types`] for components
+/// annotated with `#[component(reflect)]` that have `#[entities]` fields.
+///
+/// ```
+/// use core::any::TypeId;
+/// use vc_ecs::prelude::*;
+/// use vc_ecs::component::ReflectMapEntities;
+/// use vc_ecs::entity::EntityMap;
+/// use vc_reflect::prelude::*;
+///
+/// #[derive(Component, Reflect)]
+/// #[component(reflect)]
+/// struct Parent(#[entities] Entity);
+///
+/// let mut world = World::default();
+/// let (old, new) = (world.spawn(()).entity(), world.spawn(()).entity());
+/// world.register_component::<Parent>();
+///
+/// let mut registry = TypeRegistry::new();
+/// world.register_reflect_types(&mut registry);
+///
+/// let mut mapper = EntityMap::default();
+/// mapper.insert(old, new);
+///
+/// let reflect = registry.get_type_trait::<ReflectMapEntities>(TypeId::of::<Parent>()).unwrap();
+/// let mut parent = Parent(old);
+/// reflect.map_entities(&mut parent, &mut mapper);
+/// assert_eq!(parent.0, new);
+/// ```
+///
+/// [`map_entities`]: ReflectMapEntities::map_entities
+/// [`World::register_reflect_types`]: crate::world::World::register_reflect_types
+#[derive(Clone)]
+pub struct ReflectMapEntities {
+    map_entities: fn(&mut dyn Reflect, &mut dyn EntityMapper),
+}
+
+impl ReflectMapEntities {
+    /// Maps the entities of a component with [`Component::map_entities`].
+    ///
+    /// Does nothing if `value` is not of the component type.
+    #[inline(always)]
+    pub fn map_entities(&self, value: &mut dyn Reflect, mapper: &mut dyn EntityMapper) {
+        (self.map_entities)(value, mapper);
+    }
+}
+
+impl<T: Component + Reflect + Typed> FromType<T> for ReflectMapEntities {
+    fn from_type() -> Self {
+        Self {
+            map_entities: |value, mut mapper| {
+                if let Some(value) = value.downcast_mut::<T>() {
+                    T::map_entities(value, &mut mapper);
+                }
+            },
+        }
+    }
+}
+
+impl TypePath for ReflectMapEntities {
+    #[inline(always)]
+    fn type_path() -> &'static str {
+        "vc_ecs::component::ReflectMapEntities"
+    }
+
+    #[inline(always)]
+    fn type_name() -> &'static str {
+        "ReflectMapEntities"
+    }
+
+    #[inline(always)]
+    fn type_ident() -> &'static str {
+        "ReflectMapEntities"
+    }
+
+    #[inline(always)]
+    fn module_path() -> Option<&'static str> {
+        Some("vc_ecs::component")
+    }
+}
@@ -133,6 +133,22 @@ impl<'a> ComponentCollector<'a> {
     }
 }
 
+// -----------------------------------------------------------------------------
+// InsertMode
+
+/// Decides how explicit components of a bundle treat components
+/// that already exist on the target entity.
+///
+/// Required components never overwrite existing components, regardless of the mode.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InsertMode {
+    /// Existing components are overwritten.
+    #[default]
+    Replace,
+    /// Existing components are kept, the new values are dropped.
+    Keep,
+}
+
 // -----------------------------------------------------------------------------
 // ComponentWriter
 
@@ -155,6 +171,10 @@ enum WritedState {
 /// The writer is entity-scoped. Callers must provide the target [`Entity`],
 /// along with the corresponding [`Table`] and [`TableRow`] context.
 ///
+/// A writer created by [`ComponentWriter::new_batch`] writes bundles of the
+/// same type for several entities in consecutive table rows instead, so each
+/// write fills a whole column.
+///
 /// Internally, one `WritedState` is tracked per component ID to indicate
 /// whether a component has already been written. This ensures repeated writes
 /// replace prior values correctly and prevents memory leaks.
@@ -172,6 +192,9 @@ enum WritedState {
 /// Intuitively, `Explicit` values may overwrite `Required` values, but not the
 /// other way around. Entries marked by [`set_writed`] are always treated as `Explicit`.
 ///
+/// With [`InsertMode::Keep`], explicit values do not overwrite entries marked
+/// by [`set_writed`] either, see [`ComponentWriter::set_mode`].
+///
 /// [`write_required`]: ComponentWriter::write_required
 /// [`write_explicit`]: ComponentWriter::write_explicit
 /// [`set_writed`]: ComponentWriter::set_writed
@@ -187,9 +210,11 @@ pub struct ComponentWriter<'a> {
     components: &'a Components,
     maps: &'a mut Maps,
     table: &'a mut Table,
-    entity: Entity,
+    entities: &'a [Entity],
     table_row: TableRow,
+    stride: usize,
     tick: Tick,
+    mode: InsertMode,
     writed: SparseHashMap<ComponentId, WritedState>,
 }
 
@@ -199,7 +224,39 @@ impl ComponentWriter<'_> {
     #[inline]
     pub unsafe fn new<'a>(
         data: OwningPtr<'a>,
-        entity: Entity,
+        entity: &'a Entity,
+        table_row: TableRow,
+        tick: Tick,
+        maps: &'a mut Maps,
+        table: &'a mut Table,
+        components: &'a Components,
+    ) -> ComponentWriter<'a> {
+        ComponentWriter {
+            data,
+            components,
+            maps,
+            table,
+            entities: core::slice::from_ref(entity),
+            table_row,
+            stride: 0,
+            tick,
+            mode: InsertMode::Replace,
+            writed: SparseHashMap::new(),
+        }
+    }
+
+    /// Creates a writer for `entities`, which occupy consecutive table rows
+    /// starting at `table_row`.
+    ///
+    /// `data` points to one bundle per entity, `stride` bytes apart.
+    ///
+    /// # Safety
+    /// Guaranteed by the caller.
+    #[inline]
+    pub unsafe fn new_batch<'a>(
+        data: OwningPtr<'a>,
+        stride: usize,
+        entities: &'a [Entity],
         table_row: TableRow,
         tick: Tick,
         maps: &'a mut Maps,
@@ -211,13 +268,22 @@ impl ComponentWriter<'_> {
             components,
             maps,
             table,
-            entity,
+            entities,
             table_row,
+            stride,
             tick,
+            mode: InsertMode::Replace,
             writed: SparseHashMap::new(),
         }
     }
 
+    /// Sets how explicit writes treat components marked by
+    /// [`ComponentWriter::set_writed`], defaults to [`InsertMode::Replace`].
+    #[inline]
+    pub fn set_mode(&mut self, mode: InsertMode) {
+        self.mode = mode;
+    }
+
     /// Marks a component as already written explicitly.
     ///
     /// If [`ComponentWriter::write_required`] or
@@ -244,20 +310,23 @@ impl ComponentWriter<'_> {
     /// - `T` must be part of the target entity layout.
     /// - `T` must be registered and storage for it must be prepared.
     #[inline(never)]
-    pub unsafe fn write_required<T: Component>(&mut self, func: impl FnOnce() -> T) {
+    pub unsafe fn write_required<T: Component>(&mut self, func: impl Fn() -> T) {
         let type_id = TypeId::of::<T>();
         let component = unsafe { self.components.get_id(type_id).debug_checked_unwrap() };
         if !self.writed.contains_key(&component) {
-            let data = func();
-            vc_ptr::into_owning!(data);
-            match T::STORAGE {
-                ComponentStorage::Dense => unsafe {
-                    self.init_dense(component, data);
-                },
-                ComponentStorage::Sparse => unsafe {
-                    self.init_sparse(component, data);
-                },
+            for index in 0..self.entities.len() {
+                let data = func();
+                vc_ptr::into_owning!(data);
+                match T::STORAGE {
+                    ComponentStorage::Dense => unsafe {
+                        self.init_dense(component, index, data);
+                    },
+                    ComponentStorage::Sparse => unsafe {
+                        self.init_sparse(component, index, data);
+                    },
+                }
             }
+            self.writed.insert(component, WritedState::Required);
         }
     }
 
@@ -266,6 +335,9 @@ impl ComponentWriter<'_> {
     /// This method writes a component that was explicitly provided,
     /// reading it from the internal data buffer at the specified offset.
     ///
+    /// With [`InsertMode::Keep`], the value is dropped instead if the component
+    /// is already written.
+    ///
     /// # Safety
     /// - `T` must be part of the target entity layout.
     /// - `T` must be registered and storage for it must be prepared.
@@ -274,6 +346,12 @@ impl ComponentWriter<'_> {
     pub unsafe fn write_explicit<T: Component>(&mut self, offset: usize) {
         let type_id = TypeId::of::<T>();
         let component = unsafe { self.components.get_id(type_id).debug_checked_unwrap() };
+        if self.mode == InsertMode::Keep
+            && matches!(self.writed.get(&component), Some(WritedState::Explicit))
+        {
+            unsafe { self.drop_explicit::<T>(offset) };
+            return;
+        }
         match T::STORAGE {
             ComponentStorage::Dense => unsafe {
                 self.write_dense(component, offset);
@@ -284,16 +362,45 @@ impl ComponentWriter<'_> {
         }
     }
 
+    /// Drops a value that is part of the data buffer but is not a component.
+    ///
+    /// This is used for bundle fields that are ignored, which would otherwise
+    /// be leaked since the buffer is consumed by the writer.
+    ///
+    /// # Safety
+    /// - `offset` must be valid in `self.data` and point to a value of type `T`.
+    /// - The value must not be accessed after this call.
+    #[inline]
+    pub unsafe fn drop_explicit<T>(&mut self, offset: usize) {
+        for index in 0..self.entities.len() {
+            let offset = index * self.stride + offset;
+            unsafe {
+                self.data
+                    .borrow_mut()
+                    .byte_add(offset)
+                    .promote()
+                    .drop_as::<T>();
+            }
+        }
+    }
+
+    /// Returns the table row of the `index`-th entity.
+    #[inline(always)]
+    fn table_row(&self, index: usize) -> TableRow {
+        // The rows are allocated, so they are less than `u32::MAX`.
+        unsafe { TableRow::from_index_unchecked(self.table_row.index() + index) }
+    }
+
     /// Initializes a new component in dense storage.
     ///
     /// # Safety
     /// Guaranteed by the caller.
     #[inline(never)]
-    unsafe fn init_dense(&mut self, component: ComponentId, data: OwningPtr<'_>) {
+    unsafe fn init_dense(&mut self, component: ComponentId, index: usize, data: OwningPtr<'_>) {
         unsafe {
             let col = self.table.get_table_col(component).debug_checked_unwrap();
-            self.table.init_item(col, self.table_row, data, self.tick);
-            self.writed.insert(component, WritedState::Required);
+            let row = self.table_row(index);
+            self.table.init_item(col, row, data, self.tick);
         }
     }
 
@@ -302,13 +409,12 @@ impl ComponentWriter<'_> {
     /// # Safety
     /// Guaranteed by the caller.
     #[inline(never)]
-    unsafe fn init_sparse(&mut self, component: ComponentId, data: OwningPtr<'_>) {
+    unsafe fn init_sparse(&mut self, component: ComponentId, index: usize, data: OwningPtr<'_>) {
         unsafe {
             let map_id = self.maps.get_id(component).debug_checked_unwrap();
             let map = self.maps.get_unchecked_mut(map_id);
-            let row = map.get_map_row(self.entity).debug_checked_unwrap();
+            let row = map.get_map_row(self.entities[index]).debug_checked_unwrap();
             map.init_item(row, data, self.tick);
-            self.writed.insert(component, WritedState::Required);
         }
     }
 
@@ -318,19 +424,18 @@ impl ComponentWriter<'_> {
     /// Guaranteed by the caller.
     #[inline(never)]
     unsafe fn write_dense(&mut self, component: ComponentId, offset: usize) {
-        use vc_utils::hash::hash_map::Entry;
-        unsafe {
-            let data = self.data.borrow_mut().byte_add(offset).promote();
-            let col = self.table.get_table_col(component).debug_checked_unwrap();
-            let row = self.table_row;
-            match self.writed.entry(component) {
-                Entry::Occupied(mut entry) => {
-                    self.table.replace_item(col, row, data, self.tick);
-                    *entry.get_mut() = WritedState::Explicit;
-                }
-                Entry::Vacant(entry) => {
-                    self.table.init_item(col, row, data, self.tick);
-                    entry.insert(WritedState::Explicit);
+        let replace = self.set_explicit(component);
+        let col = unsafe { self.table.get_table_col(component).debug_checked_unwrap() };
+        for index in 0..self.entities.len() {
+            let row = self.table_row(index);
+            let tick = self.tick;
+            let offset = index * self.stride + offset;
+            unsafe {
+                let data = self.data.borrow_mut().byte_add(offset).promote();
+                if replace {
+                    self.table.replace_item(col, row, data, tick);
+                } else {
+                    self.table.init_item(col, row, data, tick);
                 }
             }
         }
@@ -342,22 +447,30 @@ impl ComponentWriter<'_> {
     /// Guaranteed by the caller.
     #[inline(never)]
     unsafe fn write_sparse(&mut self, component: ComponentId, offset: usize) {
-        use vc_utils::hash::hash_map::Entry;
-        unsafe {
-            let data = self.data.borrow_mut().byte_add(offset).promote();
-            let map_id = self.maps.get_id(component).debug_checked_unwrap();
-            let map = self.maps.get_unchecked_mut(map_id);
-            let row = map.get_map_row(self.entity).debug_checked_unwrap();
-            match self.writed.entry(component) {
-                Entry::Occupied(mut entry) => {
-                    map.replace_item(row, data, self.tick);
-                    *entry.get_mut() = WritedState::Explicit;
-                }
-                Entry::Vacant(entry) => {
-                    map.init_item(row, data, self.tick);
-                    entry.insert(WritedState::Explicit);
+        let replace = self.set_explicit(component);
+        let map_id = unsafe { self.maps.get_id(component).debug_checked_unwrap() };
+        for (index, &entity) in self.entities.iter().enumerate() {
+            let tick = self.tick;
+            let offset = index * self.stride + offset;
+            unsafe {
+                let data = self.data.borrow_mut().byte_add(offset).promote();
+                let map = self.maps.get_unchecked_mut(map_id);
+                let row = map.get_map_row(entity).debug_checked_unwrap();
+                if replace {
+                    map.replace_item(row, data, tick);
+                } else {
+                    map.init_item(row, data, tick);
                 }
             }
         }
     }
+
+    /// Marks a component as written explicitly,
+    /// returns `true` if it was written before and must be replaced.
+    #[inline]
+    fn set_explicit(&mut self, component: ComponentId) -> bool {
+        self.writed
+            .insert(component, WritedState::Explicit)
+            .is_some()
+    }
 }
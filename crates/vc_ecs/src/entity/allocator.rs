@@ -676,7 +676,7 @@ impl Drop for AllocEntitiesIter<'_> {
     fn drop(&mut self) {
         let leaking = self.len();
         if leaking > 0 {
-            log::warn!("{leaking} entities being leaked via unfinished `AllocEntitiesIter`");
+            vc_os::log::warn!("{leaking} entities being leaked via unfinished `AllocEntitiesIter`");
         }
     }
 }
@@ -94,7 +94,11 @@ impl Display for EntityId {
 /// still referring to different conceptual entities. Therefore, users should not
 /// hold an `Entity` for a long time.
 ///
+/// By default, the world retires a slot once its generation wraps, so aliasing
+/// can not occur, see [`GenerationPolicy`].
+///
 /// [`Entity`]: crate::entity::Entity
+/// [`GenerationPolicy`]: crate::entity::GenerationPolicy
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct EntityGeneration(u32);
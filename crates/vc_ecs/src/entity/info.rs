@@ -22,6 +22,14 @@ pub struct EntityLocation {
     pub table_row: TableRow,
 }
 
+// All fields are non-max integers, so optional locations and rows need no
+// extra space or sentinel values.
+const _STATIC_ASSERT_: () = const {
+    assert!(size_of::<Option<EntityLocation>>() == size_of::<EntityLocation>());
+    assert!(size_of::<Option<TableRow>>() == size_of::<u32>());
+    assert!(size_of::<Option<ArcheRow>>() == size_of::<u32>());
+};
+
 // -----------------------------------------------------------------------------
 // EntityInfo
 
@@ -32,6 +40,28 @@ struct EntityInfo {
     location: Option<EntityLocation>,
 }
 
+// -----------------------------------------------------------------------------
+// GenerationPolicy
+
+/// Behavior of an entity slot whose generation wraps on [`Entities::free`].
+///
+/// A generation is a `u32`, it only wraps after a single slot has been
+/// despawned `2^32` times, which long-running servers can still reach.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GenerationPolicy {
+    /// The slot is retired and never handed out again.
+    ///
+    /// Stale [`Entity`] handles can never alias, at the cost of one unused
+    /// slot per wrap.
+    #[default]
+    Retire,
+    /// The slot is reused with the wrapped generation.
+    ///
+    /// Stale [`Entity`] handles from `2^32` generations ago may alias the
+    /// new entity. Every wrap is reported with `log::warn!`.
+    Alias,
+}
+
 // -----------------------------------------------------------------------------
 // Entities
 
@@ -49,6 +79,9 @@ struct EntityInfo {
 /// "stale reference" problem where a component reference could accidentally
 /// access data belonging to a different entity that now occupies the same slot.
 ///
+/// When the generation of a slot wraps, the [`GenerationPolicy`] decides
+/// whether the slot is retired or reused.
+///
 /// # Storage
 ///
 /// The registry uses a dense vector indexed by entity ID, with holes for
@@ -56,6 +89,9 @@ struct EntityInfo {
 /// memory usage.
 pub struct Entities {
     infos: Vec<EntityInfo>,
+    policy: GenerationPolicy,
+    total_spawned: u64,
+    wrapped: usize,
 }
 
 impl Debug for Entities {
@@ -80,7 +116,46 @@ impl Debug for Entities {
 impl Entities {
     /// Creates a new empty entity registry.
     pub(crate) const fn new() -> Self {
-        Self { infos: Vec::new() }
+        Self {
+            infos: Vec::new(),
+            policy: GenerationPolicy::Retire,
+            total_spawned: 0,
+            wrapped: 0,
+        }
+    }
+
+    /// Returns the policy applied when a generation wraps.
+    #[inline]
+    pub fn generation_policy(&self) -> GenerationPolicy {
+        self.policy
+    }
+
+    /// Sets the policy applied when a generation wraps.
+    ///
+    /// Only affects later wraps, already retired slots stay retired.
+    #[inline]
+    pub fn set_generation_policy(&mut self, policy: GenerationPolicy) {
+        self.policy = policy;
+    }
+
+    /// Returns the number of entities spawned since the registry was created.
+    ///
+    /// Unlike [`len`](Self::len), this never decreases.
+    ///
+    /// # Complexity
+    /// time: O(1)
+    #[inline]
+    pub fn total_spawned(&self) -> u64 {
+        self.total_spawned
+    }
+
+    /// Returns the number of generation wraps, including retired slots.
+    ///
+    /// # Complexity
+    /// time: O(1)
+    #[inline]
+    pub fn wrapped(&self) -> usize {
+        self.wrapped
     }
 
     /// Return the number of spawned entities.
@@ -172,8 +247,10 @@ impl Entities {
     /// - The slot is valid for the given ID
     ///
     /// # Returns
-    /// The new entity with advanced generation.
-    pub unsafe fn free(&mut self, id: EntityId, generation: u32) -> Entity {
+    /// The new entity with advanced generation, or `None` if the generation
+    /// wrapped and the slot was retired by [`GenerationPolicy::Retire`].
+    /// A retired slot must not be given back to the allocator.
+    pub unsafe fn free(&mut self, id: EntityId, generation: u32) -> Option<Entity> {
         let index = id.index();
         if index >= self.infos.len() {
             self.resize(index + 1);
@@ -185,10 +262,23 @@ impl Entities {
         let (new_gen, wrapping) = info.generation.checked_add(generation);
         info.generation = new_gen;
         if wrapping {
-            log::warn!("Entity({id}) generation wrapped on Entities::free, aliasing may occur.");
+            self.wrapped += 1;
+            match self.policy {
+                GenerationPolicy::Retire => {
+                    vc_os::log::debug!(
+                        "Entity({id}) generation wrapped on Entities::free, slot retired."
+                    );
+                    return None;
+                }
+                GenerationPolicy::Alias => {
+                    vc_os::log::warn!(
+                        "Entity({id}) generation wrapped on Entities::free, aliasing may occur."
+                    );
+                }
+            }
         }
 
-        Entity::new(id, new_gen)
+        Some(Entity::new(id, new_gen))
     }
 
     /// Checks if an entity can be spawned.
@@ -256,6 +346,7 @@ impl Entities {
         }
 
         info.location = Some(location);
+        self.total_spawned += 1;
         Ok(())
     }
 
@@ -386,3 +477,46 @@ impl MovedEntityRow {
         }
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::{Entities, GenerationPolicy};
+    use crate::entity::Entity;
+    use crate::world::World;
+
+    #[test]
+    fn generation_wrap() {
+        let id = Entity::from_bits(1).id();
+        let mut entities = Entities::new();
+
+        unsafe {
+            assert!(entities.free(id, u32::MAX).is_some());
+            // The default policy retires the slot.
+            assert!(entities.free(id, 1).is_none());
+        }
+        assert_eq!(entities.wrapped(), 1);
+
+        entities.set_generation_policy(GenerationPolicy::Alias);
+        unsafe {
+            assert!(entities.free(id, u32::MAX).is_some());
+            let aliased = entities.free(id, 1).unwrap();
+            assert_eq!(aliased, Entity::from_id(id));
+        }
+        assert_eq!(entities.wrapped(), 2);
+    }
+
+    #[test]
+    fn total_spawned() {
+        let mut world = World::default();
+        let entity = world.spawn(()).entity();
+        world.spawn(());
+        world.despawn(entity).unwrap();
+        world.spawn(());
+
+        assert_eq!(world.entities().len(), 2);
+        assert_eq!(world.entities().total_spawned(), 3);
+    }
+}
@@ -1,3 +1,6 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
 use vc_utils::hash::SparseHashMap;
 
 use super::Entity;
@@ -64,3 +67,128 @@ impl EntityMapper for &mut dyn EntityMapper {
         (*self).set_mapped(source, target);
     }
 }
+
+// -----------------------------------------------------------------------------
+// MapEntities
+
+/// Types that contain [`Entity`] references which must be remapped, for
+/// example when entities are loaded into another world.
+///
+/// This can be derived: fields annotated with `#[entities]` are mapped, the
+/// others are left untouched.
+///
+/// ```
+/// use vc_ecs::entity::{Entity, EntityMapper, MapEntities};
+///
+/// #[derive(MapEntities)]
+/// struct Links {
+///     #[entities]
+///     targets: Vec<Entity>,
+///     weight: f32,
+/// }
+/// ```
+///
+/// The [`Component`] derive macro accepts the same attribute, and maps the
+/// annotated fields in [`Component::map_entities`].
+///
+/// [`Component`]: crate::component::Component
+/// [`Component::map_entities`]: crate::component::Component::map_entities
+pub trait MapEntities {
+    /// Replaces all contained entities with the result of [`EntityMapper::get_mapped`].
+    fn map_entities<E: EntityMapper>(&mut self, mapper: &mut E);
+}
+
+impl MapEntities for Entity {
+    #[inline]
+    fn map_entities<E: EntityMapper>(&mut self, mapper: &mut E) {
+        *self = mapper.get_mapped(*self);
+    }
+}
+
+impl<T: MapEntities> MapEntities for Option<T> {
+    #[inline]
+    fn map_entities<E: EntityMapper>(&mut self, mapper: &mut E) {
+        if let Some(value) = self {
+            value.map_entities(mapper);
+        }
+    }
+}
+
+impl<T: MapEntities + ?Sized> MapEntities for Box<T> {
+    #[inline]
+    fn map_entities<E: EntityMapper>(&mut self, mapper: &mut E) {
+        (**self).map_entities(mapper);
+    }
+}
+
+impl<T: MapEntities> MapEntities for [T] {
+    fn map_entities<E: EntityMapper>(&mut self, mapper: &mut E) {
+        self.iter_mut().for_each(|value| value.map_entities(mapper));
+    }
+}
+
+impl<T: MapEntities, const N: usize> MapEntities for [T; N] {
+    #[inline]
+    fn map_entities<E: EntityMapper>(&mut self, mapper: &mut E) {
+        self.as_mut_slice().map_entities(mapper);
+    }
+}
+
+impl<T: MapEntities> MapEntities for Vec<T> {
+    #[inline]
+    fn map_entities<E: EntityMapper>(&mut self, mapper: &mut E) {
+        self.as_mut_slice().map_entities(mapper);
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::component::Component;
+    use crate::entity::{Entity, EntityMap, EntityMapper, MapEntities};
+    use crate::world::World;
+
+    #[derive(MapEntities, PartialEq, Debug)]
+    enum Target {
+        Entity(u32, #[entities] Entity),
+        Position { x: f32 },
+    }
+
+    #[derive(Component)]
+    struct Links {
+        #[entities]
+        targets: Vec<Target>,
+        #[entities]
+        parent: Option<Entity>,
+    }
+
+    #[test]
+    fn derive_map_entities() {
+        let mut world = World::default();
+        let [a, b, c] = [(); 3].map(|()| world.spawn(()).entity());
+
+        let mut mapper = EntityMap::default();
+        mapper.set_mapped(a, b);
+        mapper.set_mapped(b, c);
+
+        let mut links = Links {
+            targets: vec![Target::Entity(1, a), Target::Position { x: 2.0 }],
+            parent: Some(b),
+        };
+        Links::map_entities(&mut links, &mut mapper);
+        assert_eq!(
+            links.targets,
+            [Target::Entity(1, b), Target::Position { x: 2.0 }]
+        );
+        assert_eq!(links.parent, Some(c));
+
+        // Unmapped entities are kept.
+        links.parent.map_entities(&mut mapper);
+        assert_eq!(links.parent, Some(c));
+    }
+}
@@ -13,9 +13,11 @@ mod storage;
 // -----------------------------------------------------------------------------
 // Exports
 
+pub use vc_ecs_derive::MapEntities;
+
 pub use allocator::{AllocEntitiesIter, EntityAllocator, RemoteAllocator};
 pub use error::*;
 pub use ident::{Entity, EntityGeneration, EntityId};
-pub use info::{Entities, EntityLocation, MovedEntityRow};
-pub use mapper::{EntityMap, EntityMapper};
+pub use info::{Entities, EntityLocation, GenerationPolicy, MovedEntityRow};
+pub use mapper::{EntityMap, EntityMapper, MapEntities};
 pub use storage::StorageId;
@@ -183,35 +183,35 @@ pub fn panic(error: EcsError, ctx: ErrorContext) {
 #[track_caller]
 #[inline]
 pub fn error(error: EcsError, ctx: ErrorContext) {
-    inner!(log::error, error, ctx);
+    inner!(vc_os::log::error, error, ctx);
 }
 
 /// Error handler that logs the system error at the `warn` level.
 #[track_caller]
 #[inline]
 pub fn warn(error: EcsError, ctx: ErrorContext) {
-    inner!(log::warn, error, ctx);
+    inner!(vc_os::log::warn, error, ctx);
 }
 
 /// Error handler that logs the system error at the `info` level.
 #[track_caller]
 #[inline]
 pub fn info(error: EcsError, ctx: ErrorContext) {
-    inner!(log::info, error, ctx);
+    inner!(vc_os::log::info, error, ctx);
 }
 
 /// Error handler that logs the system error at the `debug` level.
 #[track_caller]
 #[inline]
 pub fn debug(error: EcsError, ctx: ErrorContext) {
-    inner!(log::debug, error, ctx);
+    inner!(vc_os::log::debug, error, ctx);
 }
 
 /// Error handler that logs the system error at the `trace` level.
 #[track_caller]
 #[inline]
 pub fn trace(error: EcsError, ctx: ErrorContext) {
-    inner!(log::trace, error, ctx);
+    inner!(vc_os::log::trace, error, ctx);
 }
 
 /// Error handler that ignores the system error.
@@ -0,0 +1,220 @@
+//! Lookup of entities by component value.
+//!
+//! An [`Index<C>`] is a resource mapping each value of the component `C` to
+//! the entities holding it, so value-based lookups such as "all entities of
+//! `TeamId(3)`" do not need to scan every entity.
+//!
+//! Indexes are opt-in: the component must be immutable and route its hooks
+//! to [`Index::on_add`], [`Index::on_replace`] and [`Index::on_remove`], and
+//! the index is created with [`World::init_index`].
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::Hash;
+use core::ops::Deref;
+
+use vc_os::sync::{Mutex, MutexGuard, PoisonError};
+use vc_utils::hash::HashMap;
+
+use crate::component::Component;
+use crate::entity::Entity;
+use crate::resource::Resource;
+use crate::world::World;
+
+// -----------------------------------------------------------------------------
+// Index
+
+/// The index from values of the component `C` to the entities holding them.
+///
+/// The index is updated by the component hooks of `C`, which must be set to
+/// [`Index::on_add`], [`Index::on_replace`] and [`Index::on_remove`]. Since
+/// values are only indexed when added or replaced, `C` must be immutable,
+/// which is checked at compile time.
+///
+/// The index is a resource created by [`World::init_index`], and is read in
+/// systems through [`Res<Index<C>>`](crate::borrow::Res). The hooks do nothing
+/// while the index does not exist.
+///
+/// # Examples
+///
+/// ```
+/// use vc_ecs::index::Index;
+/// use vc_ecs::prelude::*;
+///
+/// #[derive(Component, Clone, PartialEq, Eq, Hash)]
+/// #[component(
+///     mutable = false,
+///     on_add = Index::<Self>::on_add,
+///     on_replace = Index::<Self>::on_replace,
+///     on_remove = Index::<Self>::on_remove,
+/// )]
+/// struct TeamId(u32);
+///
+/// let mut world = World::default();
+/// let a = world.spawn(TeamId(3)).entity();
+/// world.init_index::<TeamId>();
+/// let b = world.spawn(TeamId(3)).entity();
+/// world.spawn(TeamId(4));
+///
+/// let index = world.get_resource::<Index<TeamId>>().unwrap();
+/// assert_eq!(*index.get(&TeamId(3)), [a, b]);
+/// assert_eq!(index.count(&TeamId(4)), 1);
+/// assert!(!index.contains(&TeamId(5)));
+/// ```
+pub struct Index<C: Component + Hash + Eq + Clone + Send> {
+    entries: Mutex<Entries<C>>,
+}
+
+struct Entries<C> {
+    /// Entities of each value.
+    entities: HashMap<C, Vec<Entity>>,
+    /// The indexed value of each entity.
+    values: HashMap<Entity, C>,
+}
+
+impl<C: Hash + Eq> Entries<C> {
+    fn unlink(&mut self, entity: Entity, value: &C) {
+        if let Some(list) = self.entities.get_mut(value) {
+            list.retain(|&e| e != entity);
+            if list.is_empty() {
+                self.entities.remove(value);
+            }
+        }
+    }
+}
+
+impl<C: Component + Hash + Eq + Clone + Send> Resource for Index<C> {
+    const MUTABLE: bool = false;
+}
+
+impl<C: Component + Hash + Eq + Clone + Send> Index<C> {
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: Mutex::new(Entries {
+                entities: HashMap::default(),
+                values: HashMap::default(),
+            }),
+        }
+    }
+
+    /// The `ON_ADD` hook of `C`, indexing the value of `entity`.
+    pub fn on_add(world: &World, entity: Entity) {
+        const {
+            assert!(!C::MUTABLE, "indexed components must be immutable");
+        }
+
+        let Some(index) = world.get_resource::<Self>() else {
+            return;
+        };
+        if let Some(value) = world.entity_ref(entity).get::<C>() {
+            index.insert(entity, value.clone());
+        }
+    }
+
+    /// The `ON_REPLACE` hook of `C`, moving `entity` to its new value.
+    #[inline]
+    pub fn on_replace(world: &World, entity: Entity) {
+        Self::on_add(world, entity);
+    }
+
+    /// The `ON_REMOVE` hook of `C`, removing `entity` from the index.
+    pub fn on_remove(world: &World, entity: Entity) {
+        if let Some(index) = world.get_resource::<Self>() {
+            index.remove(entity);
+        }
+    }
+
+    /// Indexes `entity` with `value`, unlinking its previous value.
+    pub(crate) fn insert(&self, entity: Entity, value: C) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        match entries.values.insert(entity, value.clone()) {
+            Some(old) if old == value => return,
+            Some(old) => entries.unlink(entity, &old),
+            None => {}
+        }
+        entries.entities.entry(value).or_default().push(entity);
+    }
+
+    fn remove(&self, entity: Entity) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(old) = entries.values.remove(&entity) {
+            entries.unlink(entity, &old);
+        }
+    }
+
+    /// Returns all entities with the given value, in the order they were indexed.
+    ///
+    /// The returned guard dereferences to the entities and keeps the index
+    /// locked, so drop it before changing the world.
+    pub fn get<'a>(&'a self, value: &'a C) -> IndexGuard<'a, C> {
+        IndexGuard {
+            entries: self.entries.lock().unwrap_or_else(PoisonError::into_inner),
+            value,
+        }
+    }
+
+    /// Returns the first entity indexed with the given value.
+    pub fn first(&self, value: &C) -> Option<Entity> {
+        let entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.entities.get(value)?.first().copied()
+    }
+
+    /// Returns `true` if any entity has the given value.
+    pub fn contains(&self, value: &C) -> bool {
+        let entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.entities.contains_key(value)
+    }
+
+    /// Returns the number of entities with the given value.
+    pub fn count(&self, value: &C) -> usize {
+        let entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.entities.get(value).map_or(0, Vec::len)
+    }
+
+    /// Returns the number of distinct values.
+    pub fn len(&self) -> usize {
+        let entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.entities.len()
+    }
+
+    /// Returns `true` if no entity is indexed.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<C: Component + Hash + Eq + Clone + Send> fmt::Debug for Index<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Index").field("len", &self.len()).finish()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// IndexGuard
+
+/// The entities with a value in an [`Index`], returned by [`Index::get`].
+///
+/// It dereferences to a slice of entities, empty if no entity has the value,
+/// and holds the lock of the index until dropped.
+pub struct IndexGuard<'a, C: Component + Hash + Eq + Clone + Send> {
+    entries: MutexGuard<'a, Entries<C>>,
+    value: &'a C,
+}
+
+impl<C: Component + Hash + Eq + Clone + Send> Deref for IndexGuard<'_, C> {
+    type Target = [Entity];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.entries
+            .entities
+            .get(self.value)
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+impl<C: Component + Hash + Eq + Clone + Send> fmt::Debug for IndexGuard<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -12,6 +12,7 @@ pub mod cfg {
     vc_cfg::define_alias! {
         #[cfg(feature = "std")] => std,
         #[cfg(any(feature = "debug", debug_assertions))] => debug,
+        #[cfg(feature = "reflect")] => reflect,
     }
 }
 
@@ -47,11 +48,15 @@ pub mod resource;
 pub mod storage;
 
 pub mod archetype;
+pub mod arena;
 pub mod entity;
 
+pub mod index;
 pub mod label;
+pub mod name;
 pub mod query;
 pub mod schedule;
+pub mod spatial;
 pub mod system;
 
 pub mod world;
@@ -67,10 +72,11 @@ pub mod prelude {
     pub use crate::command::{Commands, EntityCommands};
     pub use crate::component::Component;
     pub use crate::entity::Entity;
-    pub use crate::query::{Added, And, Changed, Or, Query, With, Without};
+    pub use crate::name::Name;
+    pub use crate::query::{Added, And, AnyOf, Changed, Has, Or, Query, QueryData, With, Without};
     pub use crate::resource::Resource;
     pub use crate::schedule::{Schedule, ScheduleLabel};
-    pub use crate::system::{IntoSystem, Local, System};
-    pub use crate::tick::{DetectChanges, Tick};
+    pub use crate::system::{IntoSystem, Local, System, SystemParam};
+    pub use crate::tick::{DetectChanges, DetectChangesMut, Tick};
     pub use crate::world::{EntityMut, EntityOwned, EntityRef, World};
 }
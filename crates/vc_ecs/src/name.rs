@@ -0,0 +1,232 @@
+//! Readable entity names.
+//!
+//! [`Name`] is a built-in component holding a shared string, and
+//! [`NameLookup`] is the index maintained by each [`World`] through the
+//! component hooks of [`Name`], see [`World::find_by_name`].
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::fmt;
+use core::ops::Deref;
+
+use vc_os::sync::{Arc, Mutex, PoisonError};
+use vc_utils::hash::HashMap;
+
+use crate::component::Component;
+use crate::entity::Entity;
+use crate::world::World;
+
+// -----------------------------------------------------------------------------
+// Name
+
+/// A readable name of an entity, for editors, logs and tests.
+///
+/// The string is reference counted, so names are cheap to clone, and it is
+/// freed with the last name referring to it.
+///
+/// Named entities are indexed by their world, see [`World::find_by_name`].
+/// The component is immutable and the index is updated by its `ON_ADD`,
+/// `ON_REPLACE` and `ON_REMOVE` hooks.
+///
+/// # Examples
+///
+/// ```
+/// use vc_ecs::name::Name;
+/// use vc_ecs::world::World;
+///
+/// let mut world = World::default();
+/// let player = world.spawn(Name::new("Player")).entity();
+///
+/// assert_eq!(world.find_by_name("Player"), Some(player));
+/// assert_eq!(world.find_by_name("Enemy"), None);
+///
+/// world.despawn(player).unwrap();
+/// assert_eq!(world.find_by_name("Player"), None);
+/// ```
+#[derive(Component, Clone, PartialEq, Eq, Hash)]
+#[component(
+    clone,
+    mutable = false,
+    on_add = Name::on_add,
+    on_replace = Name::on_add,
+    on_remove = Name::on_remove
+)]
+pub struct Name(Arc<str>);
+
+impl Name {
+    /// Creates a name, copying the string.
+    #[inline]
+    pub fn new(name: &str) -> Self {
+        Self(Arc::from(name))
+    }
+
+    /// Returns the name as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn on_add(world: &World, entity: Entity) {
+        if let Some(name) = world.entity_ref(entity).get::<Name>() {
+            world.names.insert(entity, name.clone());
+        }
+    }
+
+    fn on_remove(world: &World, entity: Entity) {
+        world.names.remove(entity);
+    }
+}
+
+impl Deref for Name {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Name {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&str> for Name {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for Name {
+    #[inline]
+    fn from(value: String) -> Self {
+        Self(Arc::from(value))
+    }
+}
+
+// `Hash` and `Eq` of `Arc<str>` match the ones of `str`.
+impl Borrow<str> for Name {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for Name {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Name {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Name").field(&self.as_str()).finish()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// NameLookup
+
+#[derive(Default)]
+struct NameIndex {
+    /// Entities of each name, in the order they were named.
+    entities: HashMap<Name, Vec<Entity>>,
+    /// The indexed name of each entity.
+    names: HashMap<Entity, Name>,
+}
+
+/// The index from [`Name`]s to the entities of a [`World`].
+///
+/// It is updated by the component hooks of [`Name`], and can be accessed
+/// with [`World::names`]. Several entities may share the same name, they
+/// are returned in the order they were named.
+pub struct NameLookup {
+    index: Mutex<NameIndex>,
+}
+
+impl NameLookup {
+    pub(crate) fn new() -> Self {
+        Self {
+            index: Mutex::new(NameIndex::default()),
+        }
+    }
+
+    fn insert(&self, entity: Entity, name: Name) {
+        let mut index = self.index.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(old) = index.names.insert(entity, name.clone()) {
+            Self::unlink(&mut index, entity, &old);
+        }
+        index.entities.entry(name).or_default().push(entity);
+    }
+
+    fn remove(&self, entity: Entity) {
+        let mut index = self.index.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(old) = index.names.remove(&entity) {
+            Self::unlink(&mut index, entity, &old);
+        }
+    }
+
+    fn unlink(index: &mut NameIndex, entity: Entity, name: &Name) {
+        if let Some(entities) = index.entities.get_mut(name) {
+            entities.retain(|&e| e != entity);
+            if entities.is_empty() {
+                index.entities.remove(name);
+            }
+        }
+    }
+
+    /// Returns the first entity named `name`.
+    pub fn find(&self, name: &str) -> Option<Entity> {
+        let index = self.index.lock().unwrap_or_else(PoisonError::into_inner);
+        index.entities.get(name)?.first().copied()
+    }
+
+    /// Returns all entities named `name`.
+    pub fn find_all(&self, name: &str) -> Vec<Entity> {
+        let index = self.index.lock().unwrap_or_else(PoisonError::into_inner);
+        index.entities.get(name).cloned().unwrap_or_default()
+    }
+
+    /// Returns the name of `entity`, if it is named.
+    pub fn get(&self, entity: Entity) -> Option<Name> {
+        let index = self.index.lock().unwrap_or_else(PoisonError::into_inner);
+        index.names.get(&entity).cloned()
+    }
+
+    /// Returns the number of named entities.
+    pub fn len(&self) -> usize {
+        let index = self.index.lock().unwrap_or_else(PoisonError::into_inner);
+        index.names.len()
+    }
+
+    /// Returns `true` if no entity is named.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl fmt::Debug for NameLookup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NameLookup")
+            .field("len", &self.len())
+            .finish()
+    }
+}
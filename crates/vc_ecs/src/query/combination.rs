@@ -0,0 +1,363 @@
+use core::iter::FusedIterator;
+use core::mem::ManuallyDrop;
+
+use super::{Query, QueryData, QueryFilter, QueryState, ReadOnlyQueryData};
+use crate::entity::Entity;
+use crate::storage::TableRow;
+use crate::tick::Tick;
+use crate::utils::DebugName;
+use crate::world::{UnsafeWorld, World};
+
+// -----------------------------------------------------------------------------
+// Cursor
+
+/// A position in the matched storages, with caches set for its storage.
+struct Cursor<'w, D: QueryData, F: QueryFilter> {
+    d_cache: D::Cache<'w>,
+    f_cache: F::Cache<'w>,
+    /// Index of the current storage in `QueryState::storages`.
+    storage: usize,
+    entities: &'w [Entity],
+    /// The next row to check.
+    row: usize,
+    /// The last matched entity, `None` before the first match.
+    matched: Option<(Entity, TableRow)>,
+}
+
+impl<'w, D: QueryData, F: QueryFilter> Cursor<'w, D, F> {
+    /// # Safety
+    /// Guaranteed by the caller.
+    unsafe fn new(
+        world: UnsafeWorld<'w>,
+        state: &QueryState<D, F>,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self {
+        let _builder = world.debug_check_mode(D::WORLD_MODE, DebugName::type_name::<D>());
+        unsafe {
+            Cursor {
+                d_cache: D::build_cache(&state.d_state, world, last_run, this_run),
+                f_cache: F::build_cache(&state.f_state, world, last_run, this_run),
+                storage: 0,
+                entities: &[],
+                row: 0,
+                matched: None,
+            }
+        }
+    }
+
+    /// Moves to the start of the `index`-th storage and refreshes caches.
+    ///
+    /// Returns `false` if there is no such storage.
+    fn seek(&mut self, world: UnsafeWorld<'w>, state: &QueryState<D, F>, index: usize) -> bool {
+        let Some(&id) = state.storages.get(index) else {
+            return false;
+        };
+        self.storage = index;
+        self.row = 0;
+        if QueryState::<D, F>::IS_DENSE {
+            let table_id = unsafe { id.table_id };
+            let storages = unsafe { &world.read_only().storages };
+            let table = unsafe { storages.tables.get_unchecked(table_id) };
+            self.entities = table.entities();
+            if !self.entities.is_empty() {
+                unsafe {
+                    D::set_for_table(&state.d_state, &mut self.d_cache, table);
+                    F::set_for_table(&state.f_state, &mut self.f_cache, table);
+                }
+            }
+        } else {
+            let arche_id = unsafe { id.arche_id };
+            let arches = unsafe { &world.read_only().archetypes };
+            let arche = unsafe { arches.get_unchecked(arche_id) };
+            self.entities = arche.entities();
+            if !self.entities.is_empty() {
+                let table_id = arche.table_id();
+                let storages = unsafe { &world.read_only().storages };
+                let table = unsafe { storages.tables.get_unchecked(table_id) };
+                unsafe {
+                    D::set_for_arche(&state.d_state, &mut self.d_cache, arche, table);
+                    F::set_for_arche(&state.f_state, &mut self.f_cache, arche, table);
+                }
+            }
+        }
+        true
+    }
+
+    /// Moves to the same position as `other`, matching entities after it.
+    fn seek_after(
+        &mut self,
+        world: UnsafeWorld<'w>,
+        state: &QueryState<D, F>,
+        other: (usize, usize),
+    ) {
+        let (storage, row) = other;
+        if self.storage != storage || self.entities.is_empty() {
+            self.seek(world, state, storage);
+        }
+        self.row = row;
+    }
+
+    /// Advances to the next entity passing the filter.
+    ///
+    /// Returns `false` when no storage remains.
+    fn advance(&mut self, world: UnsafeWorld<'w>, state: &QueryState<D, F>) -> bool {
+        loop {
+            while self.row >= self.entities.len() {
+                if !self.seek(world, state, self.storage + 1) {
+                    return false;
+                }
+            }
+
+            let row = self.row;
+            let entity = unsafe { *self.entities.get_unchecked(row) };
+            self.row += 1;
+
+            let table_row = if QueryState::<D, F>::IS_DENSE {
+                unsafe { TableRow::from_index_unchecked(row) }
+            } else {
+                let infos = unsafe { &world.read_only().entities };
+                infos.locate(entity).unwrap().table_row
+            };
+
+            if F::ENABLE_ENTITY_FILTER {
+                let f_state = &state.f_state;
+                if unsafe { !F::filter(f_state, &mut self.f_cache, entity, table_row) } {
+                    continue;
+                }
+            }
+
+            self.matched = Some((entity, table_row));
+            return true;
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// QueryCombinationIter
+
+/// Iterator over all combinations of `K` distinct query results.
+///
+/// Each combination is yielded once, as an array in iteration order, so
+/// `[a, b]` is returned but `[b, a]` is not. For `N` matched entities there
+/// are `N! / (K! * (N - K)!)` combinations, this is typically used for
+/// pairwise interactions like collisions with `K = 2`.
+///
+/// The iterator keeps `K` cursors over the matched tables or archetypes,
+/// each with its own caches, so no result is fetched twice per combination
+/// and storages are not rescanned from the start.
+///
+/// Read-only queries can use it as an [`Iterator`]. Mutable items of two
+/// successive combinations may alias the same entity, so they are only
+/// available through [`fetch_next`](Self::fetch_next), which borrows the
+/// iterator until the items are dropped.
+///
+/// It can be obtained from:
+/// - [`Query::iter_combinations`]
+/// - [`Query::iter_combinations_mut`]
+/// - [`QueryState::iter_combinations`]
+/// - [`QueryState::iter_combinations_mut`]
+///
+/// # Examples
+///
+/// ```ignore
+/// fn system(query: Query<&Position>) {
+///     for [a, b] in query.iter_combinations() {
+///         /* ... */
+///     }
+/// }
+///
+/// fn system_mut(mut query: Query<&mut Velocity>) {
+///     let mut combinations = query.iter_combinations_mut();
+///     while let Some([a, b]) = combinations.fetch_next() {
+///         /* ... */
+///     }
+/// }
+/// ```
+pub struct QueryCombinationIter<'w, 's, D: QueryData, F: QueryFilter, const K: usize> {
+    world: UnsafeWorld<'w>,
+    state: &'s QueryState<D, F>,
+    cursors: [Cursor<'w, D, F>; K],
+    started: bool,
+    finished: bool,
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, const K: usize> QueryCombinationIter<'w, 's, D, F, K> {
+    /// # Safety
+    /// Guaranteed by the caller.
+    unsafe fn new(
+        world: UnsafeWorld<'w>,
+        state: &'s QueryState<D, F>,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self {
+        QueryCombinationIter {
+            world,
+            state,
+            cursors: core::array::from_fn(|_| unsafe {
+                Cursor::new(world, state, last_run, this_run)
+            }),
+            started: false,
+            finished: K == 0,
+        }
+    }
+
+    /// Moves cursors `from..K` right after their previous cursor.
+    ///
+    /// Returns `false` if there are not enough entities left.
+    fn reset_from(&mut self, from: usize) -> bool {
+        for j in from..K {
+            let prev = &self.cursors[j - 1];
+            let position = (prev.storage, prev.row);
+            let cursor = &mut self.cursors[j];
+            cursor.seek_after(self.world, self.state, position);
+            if !cursor.advance(self.world, self.state) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Moves to the next combination of entities.
+    fn advance(&mut self) -> bool {
+        if self.finished {
+            return false;
+        }
+        if !self.started {
+            self.started = true;
+            let first = &mut self.cursors[0];
+            if first.seek(self.world, self.state, 0)
+                && first.advance(self.world, self.state)
+                && self.reset_from(1)
+            {
+                return true;
+            }
+            self.finished = true;
+            return false;
+        }
+
+        // Advance the last cursor that can move, and reset the following
+        // ones right after it.
+        for i in (0..K).rev() {
+            if self.cursors[i].advance(self.world, self.state) && self.reset_from(i + 1) {
+                return true;
+            }
+        }
+        self.finished = true;
+        false
+    }
+
+    /// Fetches the items of the next combination, skipping the ones
+    /// that cannot be fetched.
+    ///
+    /// Items of different combinations may alias, the caller must not
+    /// keep mutable ones across calls.
+    fn fetch(&mut self) -> Option<[D::Item<'w>; K]> {
+        loop {
+            if !self.advance() {
+                return None;
+            }
+
+            let d_state = &self.state.d_state;
+            let cursors = &mut self.cursors;
+            let items: [Option<D::Item<'w>>; K] = core::array::from_fn(|i| {
+                let cursor = &mut cursors[i];
+                let (entity, table_row) = cursor.matched?;
+                unsafe { D::fetch(d_state, &mut cursor.d_cache, entity, table_row) }
+            });
+
+            if items.iter().all(Option::is_some) {
+                return Some(items.map(Option::unwrap));
+            }
+        }
+    }
+
+    /// Fetches the items of the next combination.
+    ///
+    /// The items borrow the iterator, so that mutable items of different
+    /// combinations never alias.
+    pub fn fetch_next(&mut self) -> Option<[D::Item<'_>; K]> {
+        let items = ManuallyDrop::new(self.fetch()?);
+        // SAFETY: Items only differ in lifetime, and the shorter one
+        // keeps the iterator borrowed while they are alive.
+        Some(unsafe {
+            core::ptr::from_ref(&*items)
+                .cast::<[D::Item<'_>; K]>()
+                .read()
+        })
+    }
+}
+
+impl<'w, D: ReadOnlyQueryData, F: QueryFilter, const K: usize> Iterator
+    for QueryCombinationIter<'w, '_, D, F, K>
+{
+    type Item = [D::Item<'w>; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.fetch()
+    }
+}
+
+impl<D: ReadOnlyQueryData, F: QueryFilter, const K: usize> FusedIterator
+    for QueryCombinationIter<'_, '_, D, F, K>
+{
+}
+
+// -----------------------------------------------------------------------------
+// Query -> QueryCombinationIter
+
+impl<'s, D: QueryData, F: QueryFilter> Query<'_, 's, D, F> {
+    /// Returns an iterator over all combinations of `K` distinct results.
+    ///
+    /// See [`QueryCombinationIter`] for details.
+    pub fn iter_combinations<const K: usize>(&self) -> QueryCombinationIter<'_, 's, D, F, K>
+    where
+        D: ReadOnlyQueryData,
+    {
+        unsafe { QueryCombinationIter::new(self.world, self.state, self.last_run, self.this_run) }
+    }
+
+    /// Returns a lending iterator over all combinations of `K` distinct
+    /// results, use [`QueryCombinationIter::fetch_next`] to get them.
+    ///
+    /// See [`QueryCombinationIter`] for details.
+    pub fn iter_combinations_mut<const K: usize>(
+        &mut self,
+    ) -> QueryCombinationIter<'_, 's, D, F, K> {
+        unsafe { QueryCombinationIter::new(self.world, self.state, self.last_run, self.this_run) }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// QueryState -> QueryCombinationIter
+
+impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
+    /// Creates a combination iterator from this query state and world.
+    ///
+    /// See [`QueryCombinationIter`] for details.
+    pub fn iter_combinations<'s, 'w, const K: usize>(
+        &'s self,
+        world: &'w World,
+    ) -> QueryCombinationIter<'w, 's, D, F, K>
+    where
+        D: ReadOnlyQueryData,
+    {
+        let last_run = world.last_run();
+        let this_run = world.this_run();
+        let world = world.unsafe_world();
+        unsafe { QueryCombinationIter::new(world, self, last_run, this_run) }
+    }
+
+    /// Creates a lending combination iterator from this query state and world.
+    ///
+    /// See [`QueryCombinationIter`] for details.
+    pub fn iter_combinations_mut<'s, 'w, const K: usize>(
+        &'s self,
+        world: &'w mut World,
+    ) -> QueryCombinationIter<'w, 's, D, F, K> {
+        let last_run = world.last_run();
+        let this_run = world.this_run();
+        let world = world.unsafe_world_mut();
+        unsafe { QueryCombinationIter::new(world, self, last_run, this_run) }
+    }
+}
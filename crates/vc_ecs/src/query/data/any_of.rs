@@ -0,0 +1,235 @@
+use core::marker::PhantomData;
+
+use alloc::vec::Vec;
+
+use super::{QueryData, ReadOnlyQueryData};
+use crate::archetype::Archetype;
+use crate::entity::Entity;
+use crate::storage::{Table, TableRow};
+use crate::system::{AccessParam, FilterParamBuilder};
+use crate::tick::Tick;
+use crate::world::{UnsafeWorld, World, WorldMode};
+
+// -----------------------------------------------------------------------------
+// InAnyOf
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used in `AnyOf<..>`",
+    label = "Expected a tuple of 1-12 elements, each implementing `QueryData`",
+    note = "If there are more than 12 elements, nesting can be used."
+)]
+pub trait InAnyOf {}
+
+// -----------------------------------------------------------------------------
+// AnyOf
+
+/// Query data that matches entities containing at least one of the inner data.
+///
+/// The item is a tuple of `Option`s, e.g. `AnyOf<(&A, &mut B)>` yields
+/// `(Option<&A>, Option<&mut B>)`, where at least one element is `Some`.
+///
+/// This is the data counterpart of the [`Or`] filter. Unlike `(Option<&A>,
+/// Option<&B>)`, entities that have neither component are not matched.
+///
+/// # Examples
+///
+/// ```
+/// # use vc_ecs::prelude::*;
+/// #[derive(Component)]
+/// struct Sprite;
+///
+/// #[derive(Component)]
+/// struct Mesh;
+///
+/// let mut world = World::default();
+/// world.spawn(Sprite);
+/// world.spawn((Sprite, Mesh));
+/// world.spawn(Mesh);
+/// world.spawn(());
+///
+/// let query = world.query::<AnyOf<(&Sprite, &Mesh)>>();
+/// assert_eq!(query.iter().count(), 3);
+/// ```
+///
+/// [`Or`]: crate::query::Or
+pub struct AnyOf<T: InAnyOf>(PhantomData<T>);
+
+// -----------------------------------------------------------------------------
+// AnyOf for Tuple
+
+macro_rules! impl_any_of {
+    ([$(#[$meta:meta])*] $($index:tt : $name:ident),*) => {
+        $(#[$meta])*
+        impl<$($name: QueryData),*> InAnyOf for ($($name,)*) {}
+
+        $(#[$meta])*
+        unsafe impl<$($name: ReadOnlyQueryData),*> ReadOnlyQueryData for AnyOf<($($name,)*)> {}
+
+        $(#[$meta])*
+        unsafe impl<$($name: QueryData),*> QueryData for AnyOf<($($name,)*)> {
+            type State = ( $( <$name>::State, )* );
+            type Cache<'world> = ( $( <$name>::Cache<'world>, )* );
+            type Item<'world> = ( $( Option<<$name>::Item<'world>>, )* );
+
+            // Missing dense columns are handled by the inner data,
+            // so table iteration is still valid.
+            const COMPONENTS_ARE_DENSE: bool = { true $( && <$name>::COMPONENTS_ARE_DENSE )* };
+            const WORLD_MODE: WorldMode = WorldMode::Shared $( .merge(<$name>::WORLD_MODE) )*;
+
+            fn build_state(world: &mut World) -> Self::State {
+                ( $( <$name>::build_state(world), )* )
+            }
+
+            unsafe fn build_cache<'w>(
+                state: &Self::State,
+                world: UnsafeWorld<'w>,
+                last_run: Tick,
+                this_run: Tick,
+            ) -> Self::Cache<'w> {
+                unsafe {
+                    ( $( <$name>::build_cache(&state.$index, world, last_run, this_run), )* )
+                }
+            }
+
+            fn build_filter(state: &Self::State, out: &mut Vec<FilterParamBuilder>) {
+                // Each existing branch is split into one branch per inner data.
+                let mut ret = Vec::<FilterParamBuilder>::with_capacity(out.len());
+                $({
+                    let mut branch = out.clone();
+                    <$name>::build_filter(&state.$index, &mut branch);
+                    ret.append(&mut branch);
+                })*
+                *out = ret;
+            }
+
+            fn build_access(state: &Self::State, out: &mut AccessParam) -> bool {
+                true $( && <$name>::build_access(&state.$index, out) )*
+            }
+
+            unsafe fn set_for_arche<'w>(
+                state: &Self::State,
+                cache: &mut Self::Cache<'w>,
+                arche: &'w Archetype,
+                table: &'w Table,
+            ) {
+                unsafe {
+                    $( <$name>::set_for_arche(&state.$index, &mut cache.$index, arche, table); )*
+                }
+            }
+
+            unsafe fn set_for_table<'w>(
+                state: &Self::State,
+                cache: &mut Self::Cache<'w>,
+                table: &'w Table,
+            ) {
+                unsafe {
+                    $( <$name>::set_for_table(&state.$index, &mut cache.$index, table); )*
+                }
+            }
+
+            unsafe fn fetch<'w>(
+                state: &Self::State,
+                cache: &mut Self::Cache<'w>,
+                entity: Entity,
+                table_row: TableRow,
+            ) -> Option<Self::Item<'w>> {
+                let item = unsafe {
+                    ( $( <$name>::fetch(&state.$index, &mut cache.$index, entity, table_row), )* )
+                };
+                if false $( || item.$index.is_some() )* {
+                    Some(item)
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_tuple {
+    (0 : []) => {};
+    (1 : [ $index:tt : $name:ident ]) => {
+        impl_any_of! {
+            [
+                #[cfg_attr(docsrs, doc(fake_variadic))]
+                #[cfg_attr(docsrs, doc = "This trait is implemented for tuples up to 12 items long.")]
+            ]
+            $index : $name
+        }
+    };
+    ($num:literal : [$($index:tt : $name:ident),*]) => {
+        impl_any_of! {
+            [#[cfg_attr(docsrs, doc(hidden))]]
+            $($index : $name),*
+        }
+    };
+}
+
+vc_utils::range_invoke!(impl_tuple, 12);
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::AnyOf;
+    use crate::component::Component;
+    use crate::query::{QueryState, With};
+    use crate::world::World;
+
+    #[derive(Component)]
+    struct Foo(u32);
+
+    #[derive(Component)]
+    struct Bar(u32);
+
+    #[derive(Component)]
+    #[component(storage = "sparse")]
+    struct Baz(u32);
+
+    #[test]
+    fn any_of_components() {
+        let mut world = World::default();
+        world.spawn(Foo(1));
+        world.spawn((Foo(2), Bar(20)));
+        world.spawn(Bar(30));
+        world.spawn(Baz(400));
+        world.spawn((Bar(50), Baz(500)));
+        world.spawn(());
+
+        const { assert!(QueryState::<AnyOf<(&Foo, &Bar)>, ()>::IS_DENSE) };
+        const { assert!(!QueryState::<AnyOf<(&Foo, &Baz)>, ()>::IS_DENSE) };
+
+        let query = world.query::<AnyOf<(&Foo, &Bar)>>();
+        let mut items: Vec<_> = query
+            .iter()
+            .map(|(foo, bar)| (foo.map(|f| f.0), bar.map(|b| b.0)))
+            .collect();
+        items.sort();
+        assert_eq!(
+            items,
+            [
+                (None, Some(30)),
+                (None, Some(50)),
+                (Some(1), None),
+                (Some(2), Some(20))
+            ]
+        );
+
+        let mut query = world.query::<AnyOf<(&mut Foo, &Baz)>>();
+        for (foo, baz) in query.iter_mut() {
+            if let Some(foo) = foo {
+                foo.0 += 1;
+            }
+            assert!(baz.is_none_or(|b| b.0 >= 400));
+        }
+        assert_eq!(query.iter_mut().count(), 4);
+
+        let query = world.query_with::<AnyOf<(&Foo, &Baz)>, With<Bar>>();
+        assert_eq!(query.iter().count(), 2);
+        let sum: u32 = world.query::<&Foo>().iter().map(|f| f.0).sum();
+        assert_eq!(sum, 5);
+    }
+}
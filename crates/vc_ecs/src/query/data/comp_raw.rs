@@ -9,7 +9,7 @@ use crate::entity::Entity;
 use crate::storage::{Column, Map, Table, TableRow};
 use crate::system::{AccessParam, FilterParamBuilder};
 use crate::tick::Tick;
-use crate::world::{UnsafeWorld, World};
+use crate::world::{UnsafeWorld, World, WorldMode};
 
 // -----------------------------------------------------------------------------
 // DataView / ComponentView
@@ -82,6 +82,7 @@ unsafe impl<T: Component> QueryData for &T {
     type Item<'world> = &'world T;
 
     const COMPONENTS_ARE_DENSE: bool = T::STORAGE.is_dense();
+    const WORLD_MODE: WorldMode = WorldMode::Shared;
 
     fn build_state(world: &mut World) -> Self::State {
         world.register_component::<T>()
@@ -140,7 +141,7 @@ unsafe impl<T: Component> QueryData for &T {
             ComponentStorage::Dense => {
                 let ptr = unsafe { cache.dense }?;
                 let column = unsafe { &*ptr.as_ptr() };
-                let row = table_row.0 as usize;
+                let row = table_row.index();
                 let data = unsafe { column.get_data(row) };
                 data.debug_assert_aligned::<T>();
                 Some(unsafe { data.as_ref::<T>() })
@@ -167,8 +168,10 @@ unsafe impl<T: Component> QueryData for Option<&T> {
     type Cache<'world> = DataView;
     type Item<'world> = Option<&'world T>;
 
-    // Due to `Option`, this data will not affect the filter.
-    const COMPONENTS_ARE_DENSE: bool = false;
+    // `Option` does not affect the filter, missing dense columns are
+    // handled by `update_dense`, so table iteration is still valid.
+    const COMPONENTS_ARE_DENSE: bool = T::STORAGE.is_dense();
+    const WORLD_MODE: WorldMode = WorldMode::Shared;
 
     fn build_state(world: &mut World) -> Self::State {
         world.register_component::<T>()
@@ -231,6 +234,7 @@ unsafe impl<T: Component> QueryData for &mut T {
     type Item<'world> = &'world mut T;
 
     const COMPONENTS_ARE_DENSE: bool = T::STORAGE.is_dense();
+    const WORLD_MODE: WorldMode = WorldMode::Exclusive;
 
     fn build_state(world: &mut World) -> Self::State {
         world.register_component::<T>()
@@ -289,7 +293,7 @@ unsafe impl<T: Component> QueryData for &mut T {
             ComponentStorage::Dense => {
                 let ptr = unsafe { cache.data.dense }?;
                 let column = unsafe { &mut *ptr.as_ptr() };
-                let row = table_row.0 as usize;
+                let row = table_row.index();
                 unsafe {
                     *column.get_changed_mut(row) = cache.this_run;
                 }
@@ -320,8 +324,10 @@ unsafe impl<T: Component> QueryData for Option<&mut T> {
     type Cache<'world> = ComponentView;
     type Item<'world> = Option<&'world mut T>;
 
-    // Due to `Option`, this data will not affect the filter.
-    const COMPONENTS_ARE_DENSE: bool = false;
+    // `Option` does not affect the filter, missing dense columns are
+    // handled by `update_dense`, so table iteration is still valid.
+    const COMPONENTS_ARE_DENSE: bool = T::STORAGE.is_dense();
+    const WORLD_MODE: WorldMode = WorldMode::Exclusive;
 
     fn build_state(world: &mut World) -> Self::State {
         world.register_component::<T>()
@@ -10,7 +10,7 @@ use crate::entity::Entity;
 use crate::storage::{Column, Map, Table, TableRow};
 use crate::system::{AccessParam, FilterParamBuilder};
 use crate::tick::Tick;
-use crate::world::{UnsafeWorld, World};
+use crate::world::{UnsafeWorld, World, WorldMode};
 
 // -----------------------------------------------------------------------------
 // ComponentView
@@ -82,6 +82,7 @@ unsafe impl<T: Component> QueryData for Ref<'_, T> {
     type Item<'world> = Ref<'world, T>;
 
     const COMPONENTS_ARE_DENSE: bool = T::STORAGE.is_dense();
+    const WORLD_MODE: WorldMode = WorldMode::Shared;
 
     fn build_state(world: &mut World) -> Self::State {
         world.register_component::<T>()
@@ -144,7 +145,7 @@ unsafe impl<T: Component> QueryData for Ref<'_, T> {
             ComponentStorage::Dense => {
                 let ptr = unsafe { cache.data.dense }?;
                 let column = unsafe { &*ptr.as_ptr() };
-                let row = table_row.0 as usize;
+                let row = table_row.index();
                 let untyped = unsafe { column.get_ref(row, last_run, this_run) };
                 unsafe { Some(untyped.with_type::<T>()) }
             }
@@ -169,8 +170,10 @@ unsafe impl<T: Component> QueryData for Option<Ref<'_, T>> {
     type Cache<'world> = ComponentView;
     type Item<'world> = Option<Ref<'world, T>>;
 
-    // Due to `Option`, this data will not affect the filter.
-    const COMPONENTS_ARE_DENSE: bool = false;
+    // `Option` does not affect the filter, missing dense columns are
+    // handled by `update_dense`, so table iteration is still valid.
+    const COMPONENTS_ARE_DENSE: bool = T::STORAGE.is_dense();
+    const WORLD_MODE: WorldMode = WorldMode::Shared;
 
     fn build_state(world: &mut World) -> Self::State {
         world.register_component::<T>()
@@ -229,6 +232,7 @@ unsafe impl<T: Component> QueryData for Mut<'_, T> {
     type Item<'world> = Mut<'world, T>;
 
     const COMPONENTS_ARE_DENSE: bool = T::STORAGE.is_dense();
+    const WORLD_MODE: WorldMode = WorldMode::Exclusive;
 
     fn build_state(world: &mut World) -> Self::State {
         world.register_component::<T>()
@@ -291,7 +295,7 @@ unsafe impl<T: Component> QueryData for Mut<'_, T> {
             ComponentStorage::Dense => {
                 let ptr = unsafe { cache.data.dense }?;
                 let column = unsafe { &mut *ptr.as_ptr() };
-                let row = table_row.0 as usize;
+                let row = table_row.index();
                 let untyped = unsafe { column.get_mut(row, last_run, this_run) };
                 unsafe { Some(untyped.with_type::<T>()) }
             }
@@ -314,8 +318,10 @@ unsafe impl<T: Component> QueryData for Option<Mut<'_, T>> {
     type Cache<'world> = ComponentView;
     type Item<'world> = Option<Mut<'world, T>>;
 
-    // Due to `Option`, this data will not affect the filter.
-    const COMPONENTS_ARE_DENSE: bool = false;
+    // `Option` does not affect the filter, missing dense columns are
+    // handled by `update_dense`, so table iteration is still valid.
+    const COMPONENTS_ARE_DENSE: bool = T::STORAGE.is_dense();
+    const WORLD_MODE: WorldMode = WorldMode::Exclusive;
 
     fn build_state(world: &mut World) -> Self::State {
         world.register_component::<T>()
@@ -6,7 +6,7 @@ use crate::entity::Entity;
 use crate::storage::{Table, TableRow};
 use crate::system::{AccessParam, FilterParamBuilder};
 use crate::tick::Tick;
-use crate::world::{EntityMut, EntityRef, UnsafeWorld, World};
+use crate::world::{EntityMut, EntityRef, UnsafeWorld, World, WorldMode};
 
 // -----------------------------------------------------------------------------
 // Entity
@@ -19,6 +19,7 @@ unsafe impl QueryData for Entity {
     type Item<'world> = Entity;
 
     const COMPONENTS_ARE_DENSE: bool = true;
+    const WORLD_MODE: WorldMode = WorldMode::Shared;
 
     fn build_state(_world: &mut World) -> Self::State {}
 
@@ -78,6 +79,7 @@ unsafe impl QueryData for EntityRef<'_> {
     type Item<'world> = EntityRef<'world>;
 
     const COMPONENTS_ARE_DENSE: bool = true;
+    const WORLD_MODE: WorldMode = WorldMode::Shared;
 
     fn build_state(_world: &mut World) -> Self::State {}
 
@@ -139,6 +141,7 @@ unsafe impl QueryData for EntityMut<'_> {
     type Item<'world> = EntityMut<'world>;
 
     const COMPONENTS_ARE_DENSE: bool = true;
+    const WORLD_MODE: WorldMode = WorldMode::Exclusive;
 
     fn build_state(_world: &mut World) -> Self::State {}
 
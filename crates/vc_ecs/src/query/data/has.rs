@@ -0,0 +1,165 @@
+use core::marker::PhantomData;
+
+use alloc::vec::Vec;
+
+use super::{QueryData, ReadOnlyQueryData};
+use crate::archetype::Archetype;
+use crate::component::{Component, ComponentId, ComponentStorage};
+use crate::entity::Entity;
+use crate::storage::{Table, TableRow};
+use crate::system::{AccessParam, FilterParamBuilder};
+use crate::tick::Tick;
+use crate::world::{UnsafeWorld, World, WorldMode};
+
+// -----------------------------------------------------------------------------
+// Has
+
+/// Query data that returns whether the entity has the component `T`.
+///
+/// Unlike `Option<&T>`, this does not access the component data, so it never
+/// conflicts with other queries, e.g. `(&mut T, Has<T>)` is valid.
+///
+/// # Examples
+///
+/// ```
+/// # use vc_ecs::prelude::*;
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// #[derive(Component)]
+/// struct Shield;
+///
+/// let mut world = World::default();
+/// world.spawn((Health(10), Shield));
+/// world.spawn(Health(10));
+///
+/// let query = world.query::<(&Health, Has<Shield>)>();
+/// assert_eq!(query.iter().filter(|(_, shielded)| *shielded).count(), 1);
+/// ```
+pub struct Has<T: Component>(PhantomData<T>);
+
+unsafe impl<T: Component> ReadOnlyQueryData for Has<T> {}
+
+unsafe impl<T: Component> QueryData for Has<T> {
+    type State = ComponentId;
+    type Cache<'world> = bool;
+    type Item<'world> = bool;
+
+    const COMPONENTS_ARE_DENSE: bool = T::STORAGE.is_dense();
+    const WORLD_MODE: WorldMode = WorldMode::Shared;
+
+    fn build_state(world: &mut World) -> Self::State {
+        world.register_component::<T>()
+    }
+
+    unsafe fn build_cache<'w>(
+        _state: &Self::State,
+        _world: UnsafeWorld<'w>,
+        _last_run: Tick,
+        _this_run: Tick,
+    ) -> Self::Cache<'w> {
+        false
+    }
+
+    fn build_filter(_state: &Self::State, _out: &mut Vec<FilterParamBuilder>) {
+        // `Has` matches entities with or without the component.
+    }
+
+    fn build_access(_state: &Self::State, _out: &mut AccessParam) -> bool {
+        // Only the archetype is inspected, the component is never accessed.
+        true
+    }
+
+    unsafe fn set_for_arche<'w>(
+        state: &Self::State,
+        cache: &mut Self::Cache<'w>,
+        arche: &'w Archetype,
+        _table: &'w Table,
+    ) {
+        match T::STORAGE {
+            ComponentStorage::Dense => {
+                *cache = arche.contains_dense_component(*state);
+            }
+            ComponentStorage::Sparse => {
+                *cache = arche.contains_sparse_component(*state);
+            }
+        }
+    }
+
+    unsafe fn set_for_table<'w>(
+        state: &Self::State,
+        cache: &mut Self::Cache<'w>,
+        table: &'w Table,
+    ) {
+        // Sparse components make the query non-dense, tables are never
+        // iterated directly in that case.
+        debug_assert!(T::STORAGE.is_dense());
+        *cache = table.get_table_col(*state).is_some();
+    }
+
+    unsafe fn fetch<'w>(
+        _state: &Self::State,
+        cache: &mut Self::Cache<'w>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> Option<Self::Item<'w>> {
+        Some(*cache)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::Has;
+    use crate::component::Component;
+    use crate::query::QueryState;
+    use crate::world::World;
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Foo(u32);
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Bar(u32);
+
+    #[derive(Component, Debug, PartialEq)]
+    #[component(storage = "sparse")]
+    struct Baz(u32);
+
+    #[test]
+    fn optional_components() {
+        let mut world = World::default();
+        world.spawn((Foo(0), Bar(1)));
+        world.spawn((Foo(1), Baz(2)));
+        world.spawn(Foo(2));
+
+        const { assert!(QueryState::<(&Foo, Has<Bar>, Option<&Bar>), ()>::IS_DENSE) };
+        const { assert!(!QueryState::<(&Foo, Has<Baz>), ()>::IS_DENSE) };
+
+        let mut query = world.query::<(&Foo, Has<Bar>, Has<Baz>)>();
+        let mut items: Vec<_> = query
+            .iter_mut()
+            .map(|(f, bar, baz)| (f.0, bar, baz))
+            .collect();
+        items.sort();
+        assert_eq!(
+            items,
+            [(0, true, false), (1, false, true), (2, false, false)]
+        );
+
+        // Writing a component while checking for it does not conflict.
+        let mut query = world.query::<(&mut Foo, Option<&mut Bar>, Option<&Baz>, Has<Foo>)>();
+        for (foo, bar, baz, has_foo) in query.iter_mut() {
+            assert!(has_foo);
+            foo.0 += bar.map_or(0, |b| b.0) + baz.map_or(0, |b| b.0);
+        }
+
+        let query = world.query::<&Foo>();
+        let mut values: Vec<_> = query.iter().map(|f| f.0).collect();
+        values.sort();
+        assert_eq!(values, [1, 2, 3]);
+    }
+}
@@ -1,10 +1,15 @@
 #![allow(clippy::missing_safety_doc, reason = "todo")]
 
+mod any_of;
 mod comp_raw;
 mod comp_ref;
 mod entity;
+mod has;
 mod tuples;
 
+pub use any_of::AnyOf;
+pub use has::Has;
+
 // -----------------------------------------------------------------------------
 // QueryData
 
@@ -15,7 +20,7 @@ use crate::entity::Entity;
 use crate::storage::{Table, TableRow};
 use crate::system::{AccessParam, FilterParamBuilder};
 use crate::tick::Tick;
-use crate::world::{UnsafeWorld, World};
+use crate::world::{UnsafeWorld, World, WorldMode};
 
 /// Core trait for types that can be fetched from entities in a query.
 ///
@@ -29,6 +34,8 @@ use crate::world::{UnsafeWorld, World};
 /// - **Entity handles**: `Entity`, `EntityRef`, `EntityMut`
 /// - **Component references**: `&T`, `&mut T`, `Ref<T>`, `Mut<T>` where `T` is a component type
 /// - **Optional components**: `Option<&T>`, `Option<&mut T>`, `Option<Ref<T>>`, `Option<Mut<T>>`
+/// - **Component presence**: `Has<T>`, a `bool` that does not access the component
+/// - **Any of**: `AnyOf<(D1, D2, ...)>`, a tuple of `Option`s where at least one is `Some`
 ///
 /// Tuples composed from these forms are also valid, for example `(&Foo, &mut Bar)`.
 ///
@@ -71,6 +78,17 @@ pub unsafe trait QueryData {
     /// - If `false`, the filter may access sparse components requiring map lookups.
     const COMPONENTS_ARE_DENSE: bool;
 
+    /// The world access needed to build the cache of this query data.
+    ///
+    /// This is [`WorldMode::Exclusive`] for data handing out mutable component
+    /// references, such as `&mut T`, `Mut<T>` and `EntityMut`, and for tuples
+    /// containing them, otherwise [`WorldMode::Shared`].
+    ///
+    /// In debug mode, building an exclusive cache from an [`UnsafeWorld`]
+    /// created from `&World` panics with the name of the query data, see
+    /// [`UnsafeWorld::debug_check_mode`].
+    const WORLD_MODE: WorldMode;
+
     /// Builds the static state for this query data.
     ///
     /// This is called once when the query is first created. The state is
@@ -6,7 +6,7 @@ use crate::entity::Entity;
 use crate::storage::{Table, TableRow};
 use crate::system::{AccessParam, FilterParamBuilder};
 use crate::tick::Tick;
-use crate::world::{UnsafeWorld, World};
+use crate::world::{UnsafeWorld, World, WorldMode};
 
 macro_rules! impl_tuple {
     (0: []) => {
@@ -18,6 +18,7 @@ macro_rules! impl_tuple {
             type Item<'world> = ();
 
             const COMPONENTS_ARE_DENSE: bool = true;
+            const WORLD_MODE: WorldMode = WorldMode::Shared;
 
             fn build_state(_world: &mut World) -> Self::State {}
 
@@ -67,6 +68,7 @@ macro_rules! impl_tuple {
             type Item<'world> = ( <$name>::Item<'world>, );
 
             const COMPONENTS_ARE_DENSE: bool = <$name>::COMPONENTS_ARE_DENSE;
+            const WORLD_MODE: WorldMode = <$name>::WORLD_MODE;
 
             fn build_state(world: &mut World) -> Self::State {
                 <$name>::build_state(world)
@@ -127,6 +129,7 @@ macro_rules! impl_tuple {
             type Item<'world> = ( $( <$name>::Item<'world> ),* );
 
             const COMPONENTS_ARE_DENSE: bool = { true $( && <$name>::COMPONENTS_ARE_DENSE )* };
+            const WORLD_MODE: WorldMode = WorldMode::Shared $( .merge(<$name>::WORLD_MODE) )*;
 
             fn build_state(world: &mut World) -> Self::State {
                 ( $( <$name>::build_state(world), )* )
@@ -151,7 +151,7 @@ unsafe impl<T: Component> QueryFilter for Added<T> {
                 let Some(slice) = dense else {
                     return false;
                 };
-                let added = unsafe { *slice.get(table_row.0 as usize) };
+                let added = unsafe { *slice.get(table_row.index()) };
                 added.is_newer_than(cache.last_run, cache.this_run)
             }
             ComponentStorage::Sparse => {
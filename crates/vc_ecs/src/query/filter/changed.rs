@@ -151,7 +151,7 @@ unsafe impl<T: Component> QueryFilter for Changed<T> {
                 let Some(slice) = dense else {
                     return false;
                 };
-                let changed = unsafe { *slice.get(table_row.0 as usize) };
+                let changed = unsafe { *slice.get(table_row.index()) };
                 changed.is_newer_than(cache.last_run, cache.this_run)
             }
             ComponentStorage::Sparse => {
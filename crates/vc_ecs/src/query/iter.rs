@@ -1,9 +1,12 @@
 use core::iter::FusedIterator;
+use core::ops::Range;
 
 use super::{Query, QueryData, QueryFilter, QueryState, ReadOnlyQueryData};
+use crate::archetype::ArcheId;
 use crate::entity::{Entity, StorageId};
-use crate::storage::TableRow;
+use crate::storage::{Map, MapIter, TableRow};
 use crate::tick::Tick;
+use crate::utils::DebugName;
 use crate::world::{UnsafeWorld, World};
 
 // -----------------------------------------------------------------------------
@@ -14,6 +17,9 @@ use crate::world::{UnsafeWorld, World};
 /// This iterator traverses matched storages from [`QueryState`], applies
 /// optional entity-level filtering, and fetches query items lazily.
 ///
+/// Queries with a required sparse component may walk that component's map
+/// instead, see [`QueryState`].
+///
 /// It can be obtained from:
 /// - [`QueryState::iter_mut`]
 /// - [`QueryState::iter`] for read-only data
@@ -40,6 +46,8 @@ pub struct QueryIter<'w, 's, D: QueryData, F: QueryFilter> {
     storages: core::slice::Iter<'s, StorageId>,
     entities: &'w [Entity],
     row: usize,
+    sparse: Option<MapIter<'w>>,
+    arche_id: Option<ArcheId>,
 }
 
 // -----------------------------------------------------------------------------
@@ -56,6 +64,19 @@ impl<D: QueryData, F: QueryFilter> QueryIter<'_, '_, D, F> {
         last_run: Tick,
         this_run: Tick,
     ) -> QueryIter<'w, 's, D, F> {
+        let _builder = world.debug_check_mode(D::WORLD_MODE, DebugName::type_name::<D>());
+
+        // Walking a map costs an archetype switch per entity at worst,
+        // so it only pays off when there are more archetypes than entities.
+        let maps = unsafe { &world.read_only().storages.maps };
+        let sparse = state
+            .sparse_maps
+            .iter()
+            .map(|&id| unsafe { maps.get_unchecked(id) })
+            .min_by_key(|map| map.len())
+            .filter(|map| map.len() < state.storages.len())
+            .map(Map::iter);
+
         unsafe {
             QueryIter {
                 world,
@@ -65,10 +86,34 @@ impl<D: QueryData, F: QueryFilter> QueryIter<'_, '_, D, F> {
                 storages: state.storages.iter(),
                 entities: EMPTY_ENTITIES,
                 row: 0,
+                sparse,
+                arche_id: None,
             }
         }
     }
 
+    /// Creates an iterator over the rows `rows` of a single storage.
+    ///
+    /// # Safety
+    /// Same as [`QueryIter::new`], `storage` must come from `state`.
+    pub(super) unsafe fn new_batch<'w, 's>(
+        world: UnsafeWorld<'w>,
+        state: &'s QueryState<D, F>,
+        storage: &'s StorageId,
+        rows: Range<usize>,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> QueryIter<'w, 's, D, F> {
+        let mut iter = unsafe { Self::new(world, state, last_run, this_run) };
+        iter.sparse = None;
+        iter.storages = core::slice::from_ref(storage).iter();
+        if iter.update_slice().is_some() {
+            iter.entities = &iter.entities[..rows.end.min(iter.entities.len())];
+            iter.row = rows.start;
+        }
+        iter
+    }
+
     /// Advances to the next non-empty storage slice and refreshes caches.
     ///
     /// Returns `None` when no storage remains.
@@ -110,11 +155,70 @@ impl<D: QueryData, F: QueryFilter> QueryIter<'_, '_, D, F> {
     }
 }
 
+impl<'w, D: QueryData, F: QueryFilter> QueryIter<'w, '_, D, F> {
+    /// Applies the entity filter and fetches the item of `entity`.
+    ///
+    /// # Safety
+    /// The caches must have been set for the storage of `entity`.
+    #[inline(always)]
+    unsafe fn fetch(&mut self, entity: Entity, table_row: TableRow) -> Option<D::Item<'w>> {
+        // Important optimization: skip entity filtering when the filter
+        // type guarantees no entity-level checks are needed.
+        if F::ENABLE_ENTITY_FILTER {
+            let f_state = &self.state.f_state;
+            let f_cache = &mut self.f_cache;
+            if unsafe { !F::filter(f_state, f_cache, entity, table_row) } {
+                return None;
+            }
+        }
+
+        let d_state = &self.state.d_state;
+        let d_cache = &mut self.d_cache;
+        unsafe { D::fetch(d_state, d_cache, entity, table_row) }
+    }
+
+    /// Walks the entities of the sparse map chosen in [`QueryIter::new`],
+    /// skipping those outside the matched archetypes.
+    #[inline(never)]
+    fn next_sparse(&mut self) -> Option<D::Item<'w>> {
+        let world = unsafe { self.world.read_only() };
+        loop {
+            let (entity, _) = self.sparse.as_mut()?.next()?;
+            let location = world.entities.locate(entity).unwrap();
+
+            if self.arche_id != Some(location.arche_id) {
+                let id = StorageId {
+                    arche_id: location.arche_id,
+                };
+                // Matched archetypes are sorted, see `update_sparse_state`.
+                if self.state.storages.binary_search(&id).is_err() {
+                    continue;
+                }
+                let arche = unsafe { world.archetypes.get_unchecked(location.arche_id) };
+                let table = unsafe { world.storages.tables.get_unchecked(location.table_id) };
+                unsafe {
+                    D::set_for_arche(&self.state.d_state, &mut self.d_cache, arche, table);
+                    F::set_for_arche(&self.state.f_state, &mut self.f_cache, arche, table);
+                }
+                self.arche_id = Some(location.arche_id);
+            }
+
+            if let Some(data) = unsafe { self.fetch(entity, location.table_row) } {
+                return Some(data);
+            }
+        }
+    }
+}
+
 impl<'w, D: QueryData, F: QueryFilter> Iterator for QueryIter<'w, '_, D, F> {
     type Item = D::Item<'w>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        'looper: loop {
+        if self.sparse.is_some() {
+            return self.next_sparse();
+        }
+
+        loop {
             if self.row >= self.entities.len() {
                 // If there is no entities, `update_slice` will return None.
                 // otherwise `self.entities` is not empty after this function.
@@ -129,29 +233,36 @@ impl<'w, D: QueryData, F: QueryFilter> Iterator for QueryIter<'w, '_, D, F> {
             self.row += 1;
 
             let table_row = if QueryState::<D, F>::IS_DENSE {
-                TableRow(old_row as u32)
+                unsafe { TableRow::from_index_unchecked(old_row) }
             } else {
                 let infos = unsafe { &self.world.read_only().entities };
                 infos.locate(entity).unwrap().table_row
             };
 
-            // Important optimization: skip entity filtering when the filter
-            // type guarantees no entity-level checks are needed.
-            if F::ENABLE_ENTITY_FILTER {
-                let f_state = &self.state.f_state;
-                let f_cache = &mut self.f_cache;
-                if unsafe { !F::filter(f_state, f_cache, entity, table_row) } {
-                    continue 'looper;
-                }
-            }
-
-            let d_state = &self.state.d_state;
-            let d_cache = &mut self.d_cache;
-            if let Some(data) = unsafe { D::fetch(d_state, d_cache, entity, table_row) } {
+            if let Some(data) = unsafe { self.fetch(entity, table_row) } {
                 return Some(data);
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Filters and data may skip entities, so only the upper bound is known.
+        if let Some(sparse) = &self.sparse {
+            return (0, Some(sparse.len()));
+        }
+        let world = unsafe { self.world.read_only() };
+        let rest = self.storages.clone().map(|id| {
+            if QueryState::<D, F>::IS_DENSE {
+                let tables = &world.storages.tables;
+                unsafe { tables.get_unchecked(id.table_id).entities().len() }
+            } else {
+                let arches = &world.archetypes;
+                unsafe { arches.get_unchecked(id.arche_id).entities().len() }
+            }
+        });
+        let current = self.entities.len().saturating_sub(self.row);
+        (0, Some(current + rest.sum::<usize>()))
+    }
 }
 
 impl<D: QueryData, F: QueryFilter> FusedIterator for QueryIter<'_, '_, D, F> {}
@@ -211,7 +322,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     pub fn iter_mut<'s, 'w>(&'s self, world: &'w mut World) -> QueryIter<'w, 's, D, F> {
         let last_run = world.last_run();
         let this_run = world.this_run();
-        let world = world.unsafe_world();
+        let world = world.unsafe_world_mut();
         unsafe { QueryIter::new(world, self, last_run, this_run) }
     }
 
@@ -226,3 +337,59 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         unsafe { QueryIter::new(world, self, last_run, this_run) }
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::component::Component;
+    use crate::query::Without;
+    use crate::world::World;
+
+    #[derive(Component)]
+    struct Foo;
+
+    #[derive(Component)]
+    struct Bar;
+
+    #[derive(Component)]
+    struct Qux;
+
+    #[derive(Component)]
+    #[component(storage = "sparse")]
+    struct Baz(u32);
+
+    #[test]
+    fn sparse_map_walk() {
+        let mut world = World::default();
+        // Leave behind empty archetypes that contain `Baz`.
+        let empty = [
+            world.spawn(Baz(0)).entity(),
+            world.spawn((Baz(0), Foo)).entity(),
+            world.spawn((Baz(0), Qux)).entity(),
+            world.spawn((Baz(0), Foo, Qux)).entity(),
+        ];
+        for entity in empty {
+            world.despawn(entity).unwrap();
+        }
+
+        world.spawn((Baz(10), Foo));
+        world.spawn((Baz(20), Bar));
+        world.spawn((Baz(30), Qux));
+
+        let state = world.query_state::<&mut Baz, Without<Bar>>();
+        assert_eq!(state.matched_storages(), 4);
+        let iter = state.iter_mut(&mut world);
+        // Fewer entities than archetypes, so the map of `Baz` is walked.
+        assert_eq!(iter.size_hint(), (0, Some(3)));
+        iter.for_each(|baz| baz.0 += 1);
+
+        let state = world.query_state::<&Baz, ()>();
+        let mut values: Vec<u32> = state.iter(&world).map(|baz| baz.0).collect();
+        values.sort_unstable();
+        assert_eq!(values, [11, 20, 31]);
+    }
+}
@@ -0,0 +1,224 @@
+use core::iter::FusedIterator;
+
+use super::{Query, QueryData, QueryFilter, QueryState};
+use crate::entity::{Entity, StorageId};
+use crate::storage::TableRow;
+use crate::tick::Tick;
+use crate::world::{UnsafeWorld, World};
+
+// -----------------------------------------------------------------------------
+// QueryEntityIter
+
+/// Iterator over the entities matched by a query, without fetching its data.
+///
+/// This yields the same entities as iterating the query with [`Entity`]
+/// added to its data, but only reads the entity arrays of the matched
+/// storages. The cache of the query data is never built, and the filter
+/// cache is only used if the filter checks entities one by one, such as
+/// [`Changed<T>`]. Otherwise whole entity slices are yielded unchanged.
+///
+/// It can be obtained from:
+/// - [`Query::iter_entities`]
+/// - [`QueryState::iter_entities`]
+///
+/// [`Changed<T>`]: crate::query::Changed
+///
+/// # Examples
+///
+/// ```ignore
+/// fn system(query: Query<&Transform, With<Selected>>) {
+///     let selection: Vec<Entity> = query.iter_entities().collect();
+/// }
+/// ```
+pub struct QueryEntityIter<'w, 's, D: QueryData, F: QueryFilter> {
+    world: UnsafeWorld<'w>,
+    state: &'s QueryState<D, F>,
+    f_cache: F::Cache<'w>,
+    storages: core::slice::Iter<'s, StorageId>,
+    entities: core::slice::Iter<'w, Entity>,
+    row: usize,
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter> QueryEntityIter<'w, 's, D, F> {
+    /// # Safety
+    /// Guaranteed by the caller.
+    unsafe fn new(
+        world: UnsafeWorld<'w>,
+        state: &'s QueryState<D, F>,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self {
+        QueryEntityIter {
+            world,
+            state,
+            f_cache: unsafe { F::build_cache(&state.f_state, world, last_run, this_run) },
+            storages: state.storages.iter(),
+            entities: [].iter(),
+            row: 0,
+        }
+    }
+
+    /// Advances to the next non-empty storage and refreshes the filter cache.
+    ///
+    /// Returns `None` when no storage remains.
+    #[cold]
+    #[inline(never)]
+    fn update_slice(&mut self) -> Option<()> {
+        self.row = 0;
+        loop {
+            let id = *self.storages.next()?;
+            let world = unsafe { self.world.read_only() };
+            let entities = if QueryState::<D, F>::IS_DENSE {
+                let table_id = unsafe { id.table_id };
+                let table = unsafe { world.storages.tables.get_unchecked(table_id) };
+                if F::ENABLE_ENTITY_FILTER && !table.entities().is_empty() {
+                    unsafe { F::set_for_table(&self.state.f_state, &mut self.f_cache, table) };
+                }
+                table.entities()
+            } else {
+                let arche_id = unsafe { id.arche_id };
+                let arche = unsafe { world.archetypes.get_unchecked(arche_id) };
+                if F::ENABLE_ENTITY_FILTER && !arche.entities().is_empty() {
+                    let table = unsafe { world.storages.tables.get_unchecked(arche.table_id()) };
+                    let f_cache = &mut self.f_cache;
+                    unsafe { F::set_for_arche(&self.state.f_state, f_cache, arche, table) };
+                }
+                arche.entities()
+            };
+            if !entities.is_empty() {
+                self.entities = entities.iter();
+                return Some(());
+            }
+        }
+    }
+}
+
+impl<D: QueryData, F: QueryFilter> Iterator for QueryEntityIter<'_, '_, D, F> {
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(&entity) = self.entities.next() else {
+                self.update_slice()?;
+                continue;
+            };
+            if !F::ENABLE_ENTITY_FILTER {
+                return Some(entity);
+            }
+
+            let old_row = self.row;
+            self.row += 1;
+            let table_row = if QueryState::<D, F>::IS_DENSE {
+                unsafe { TableRow::from_index_unchecked(old_row) }
+            } else {
+                let infos = unsafe { &self.world.read_only().entities };
+                infos.locate(entity).unwrap().table_row
+            };
+
+            let f_state = &self.state.f_state;
+            if unsafe { F::filter(f_state, &mut self.f_cache, entity, table_row) } {
+                return Some(entity);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let world = unsafe { self.world.read_only() };
+        let rest = self.storages.clone().map(|id| {
+            if QueryState::<D, F>::IS_DENSE {
+                let tables = &world.storages.tables;
+                unsafe { tables.get_unchecked(id.table_id).entities().len() }
+            } else {
+                let arches = &world.archetypes;
+                unsafe { arches.get_unchecked(id.arche_id).entities().len() }
+            }
+        });
+        let upper = self.entities.len() + rest.sum::<usize>();
+        let lower = if F::ENABLE_ENTITY_FILTER { 0 } else { upper };
+        (lower, Some(upper))
+    }
+}
+
+impl<D: QueryData, F: QueryFilter> FusedIterator for QueryEntityIter<'_, '_, D, F> {}
+
+// -----------------------------------------------------------------------------
+// Query -> QueryEntityIter
+
+impl<'s, D: QueryData, F: QueryFilter> Query<'_, 's, D, F> {
+    /// Returns an iterator over the matched entities, without fetching the
+    /// query data, see [`QueryEntityIter`].
+    ///
+    /// This only needs shared access, even if the query data is mutable.
+    pub fn iter_entities(&self) -> QueryEntityIter<'_, 's, D, F> {
+        unsafe { QueryEntityIter::new(self.world, self.state, self.last_run, self.this_run) }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// QueryState -> QueryEntityIter
+
+impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
+    /// Creates an iterator over the matched entities from this query state
+    /// and world, without fetching the query data, see [`QueryEntityIter`].
+    pub fn iter_entities<'s, 'w>(&'s self, world: &'w World) -> QueryEntityIter<'w, 's, D, F> {
+        let last_run = world.last_run();
+        let this_run = world.this_run();
+        let world = world.unsafe_world();
+        unsafe { QueryEntityIter::new(world, self, last_run, this_run) }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::borrow::Mut;
+    use crate::component::Component;
+    use crate::entity::Entity;
+    use crate::query::{And, Changed, QueryFilter, With};
+    use crate::world::World;
+
+    #[derive(Component)]
+    struct Foo(u32);
+
+    #[derive(Component)]
+    #[component(storage = "sparse")]
+    struct Baz;
+
+    fn check<F: QueryFilter + 'static>(world: &mut World) {
+        let state = world.query_state::<Mut<Foo>, F>();
+        let entities: Vec<Entity> = state.iter_entities(world).collect();
+        let expected = world.query_state::<Entity, F>();
+        let expected: Vec<Entity> = expected.iter(world).collect();
+        assert_eq!(entities, expected);
+    }
+
+    #[test]
+    fn matches_entity_data() {
+        let mut world = World::default();
+        for i in 0..20 {
+            world.spawn(Foo(i));
+        }
+        for i in 20..30 {
+            world.spawn((Foo(i), Baz));
+        }
+        world.update_tick();
+
+        for mut foo in world.query::<Mut<Foo>>() {
+            if foo.0 % 3 == 0 {
+                foo.0 += 1000;
+            }
+        }
+
+        check::<()>(&mut world);
+        check::<With<Baz>>(&mut world);
+        check::<Changed<Foo>>(&mut world);
+        check::<And<(With<Baz>, Changed<Foo>)>>(&mut world);
+
+        let state = world.query_state::<&Foo, ()>();
+        assert_eq!(state.iter_entities(&world).size_hint(), (30, Some(30)));
+    }
+}
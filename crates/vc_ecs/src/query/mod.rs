@@ -3,17 +3,25 @@
 // -----------------------------------------------------------------------------
 // Modules
 
+mod combination;
 mod data;
 mod filter;
 mod iter;
+mod iter_changed;
+mod iter_entities;
+mod par;
 mod query;
 mod state;
 
 // -----------------------------------------------------------------------------
 // Exports
 
-pub use data::{QueryData, ReadOnlyQueryData};
+pub use combination::QueryCombinationIter;
+pub use data::{AnyOf, Has, QueryData, ReadOnlyQueryData};
 pub use filter::{Added, And, Changed, Or, QueryFilter, With, Without};
 pub use iter::QueryIter;
+pub use iter_changed::QueryChangedIter;
+pub use iter_entities::QueryEntityIter;
 pub use query::Query;
 pub use state::QueryState;
+pub use vc_ecs_derive::QueryData;
@@ -0,0 +1,186 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use vc_task::ComputeTaskPool;
+
+use super::{Query, QueryFilter, QueryIter, QueryState, ReadOnlyQueryData};
+use crate::entity::StorageId;
+
+/// Batches are never smaller than this, unless the storage itself is.
+const MIN_BATCH_SIZE: usize = 256;
+
+/// The number of batches per thread, for load balancing.
+const BATCHES_PER_THREAD: usize = 4;
+
+// -----------------------------------------------------------------------------
+// Query -> Parallel Aggregates
+
+impl<'s, D: ReadOnlyQueryData, F: QueryFilter> Query<'_, 's, D, F> {
+    /// Folds all results in parallel on the [`ComputeTaskPool`].
+    ///
+    /// Results are split into batches of table (or archetype) rows. Each
+    /// batch is folded with `fold`, starting from `identity()`, and the
+    /// partial results are combined with `reduce`. The combination order is
+    /// unspecified, so `reduce` should be associative and commutative.
+    ///
+    /// Runs on the calling thread if the pool is not initialized.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// fn bounds(query: Query<&Position>) {
+    ///     let max_x = query.par_fold(|| f32::MIN, |m, p| m.max(p.x), f32::max);
+    /// }
+    /// ```
+    pub fn par_fold<T, Id, Op, Re>(&self, identity: Id, fold: Op, reduce: Re) -> T
+    where
+        T: Send + 'static,
+        Id: Fn() -> T + Sync,
+        Op: Fn(T, D::Item<'_>) -> T + Sync,
+        Re: Fn(T, T) -> T,
+    {
+        self.par_batches(|iter| iter.fold(identity(), &fold))
+            .into_iter()
+            .reduce(reduce)
+            .unwrap_or_else(identity)
+    }
+
+    /// Counts the results in parallel on the [`ComputeTaskPool`].
+    ///
+    /// See [`par_fold`](Self::par_fold) for how the work is split.
+    pub fn par_count(&self) -> usize {
+        self.par_batches(|iter| iter.count()).into_iter().sum()
+    }
+
+    /// Returns `true` if any result satisfies `predicate`, testing them in
+    /// parallel on the [`ComputeTaskPool`].
+    ///
+    /// Batches stop early once a match is found in any of them.
+    /// See [`par_fold`](Self::par_fold) for how the work is split.
+    pub fn par_any<P>(&self, predicate: P) -> bool
+    where
+        P: Fn(D::Item<'_>) -> bool + Sync,
+    {
+        let found = AtomicBool::new(false);
+        self.par_batches(|mut iter| {
+            let matched = iter.any(|item| found.load(Ordering::Relaxed) || predicate(item));
+            if matched {
+                found.store(true, Ordering::Relaxed);
+            }
+        });
+        found.into_inner()
+    }
+
+    /// Runs `task` on each batch of results, returns the outputs in an
+    /// unspecified order.
+    fn par_batches<T, Task>(&self, task: Task) -> Vec<T>
+    where
+        T: Send + 'static,
+        Task: Fn(QueryIter<'_, 's, D, F>) -> T + Sync,
+    {
+        let Some(task_pool) = ComputeTaskPool::try_get() else {
+            return vec![task(self.iter())];
+        };
+
+        let batches = self.batches(task_pool.thread_num());
+        if batches.len() <= 1 {
+            return vec![task(self.iter())];
+        }
+
+        let (world, state) = (self.world, self.state);
+        let (last_run, this_run) = (self.last_run, self.this_run);
+        let task = &task;
+        task_pool.scope(|scope| {
+            for (storage, rows) in batches {
+                scope.spawn(async move {
+                    // SAFETY: The query data is read-only, so batches can be
+                    // fetched concurrently, and the storages come from `state`.
+                    task(unsafe {
+                        QueryIter::new_batch(world, state, storage, rows, last_run, this_run)
+                    })
+                });
+            }
+        })
+    }
+
+    /// Splits the matched storages into row ranges of similar size.
+    fn batches(&self, thread_num: usize) -> Vec<(&'s StorageId, Range<usize>)> {
+        let world = unsafe { self.world.read_only() };
+        let lengths: Vec<usize> = self
+            .state
+            .storages
+            .iter()
+            .map(|id| unsafe {
+                if QueryState::<D, F>::IS_DENSE {
+                    world
+                        .storages
+                        .tables
+                        .get_unchecked(id.table_id)
+                        .entities()
+                        .len()
+                } else {
+                    world.archetypes.get_unchecked(id.arche_id).entities().len()
+                }
+            })
+            .collect();
+
+        let total: usize = lengths.iter().sum();
+        let batch_size = (total / (thread_num.max(1) * BATCHES_PER_THREAD)).max(MIN_BATCH_SIZE);
+
+        let mut batches = Vec::new();
+        for (storage, len) in self.state.storages.iter().zip(lengths) {
+            let mut start = 0;
+            while start < len {
+                let end = (start + batch_size).min(len);
+                batches.push((storage, start..end));
+                start = end;
+            }
+        }
+        batches
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use vc_task::{ComputeTaskPool, TaskPool};
+
+    use crate::component::Component;
+    use crate::query::Without;
+    use crate::world::World;
+
+    #[derive(Component)]
+    struct Value(u64);
+
+    #[derive(Component)]
+    #[component(storage = "sparse")]
+    struct Marker;
+
+    #[test]
+    fn par_aggregates() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+
+        let mut world = World::default();
+        for i in 0..10_000_u64 {
+            if i % 3 == 0 {
+                world.spawn((Value(i), Marker));
+            } else {
+                world.spawn(Value(i));
+            }
+        }
+
+        let query = world.query::<&Value>();
+        assert_eq!(query.par_count(), 10_000);
+        let sum = query.par_fold(|| 0, |sum, v| sum + v.0, |a, b| a + b);
+        assert_eq!(sum, (0..10_000).sum::<u64>());
+        assert!(query.par_any(|v| v.0 == 9_999));
+        assert!(!query.par_any(|v| v.0 == 10_000));
+
+        let query = world.query_with::<&Value, Without<Marker>>();
+        assert_eq!(query.par_count(), query.iter().count());
+    }
+}
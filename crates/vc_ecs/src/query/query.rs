@@ -48,6 +48,8 @@ use crate::world::{UnsafeWorld, World};
 /// - **Entity handles**: `Entity`, `EntityRef`, `EntityMut`
 /// - **Component references**: `&T`, `&mut T`, `Ref<T>`, `Mut<T>` where `T` is a component type
 /// - **Optional components**: `Option<&T>`, `Option<&mut T>`, `Option<Ref<T>>`, `Option<Mut<T>>`
+/// - **Component presence**: `Has<T>`, a `bool` that does not access the component
+/// - **Any of**: `AnyOf<(D1, D2, ...)>`, a tuple of `Option`s where at least one is `Some`
 ///
 /// # Query Filter Types
 ///
@@ -103,6 +105,10 @@ use crate::world::{UnsafeWorld, World};
 ///    iteration is organized by table rather than archetype. This maximizes cache locality
 ///    as entities within the same table are stored contiguously in memory.
 ///
+/// 5. **Changed scan**: [`Query::iter_changed`] scans the changed ticks of a dense
+///    component in chunks before fetching anything, which is faster than a `Changed`
+///    filter when only a few entities changed.
+///
 /// [`Archetype`]: crate::archetype::Archetype
 /// [`QueryIter`]: crate::query::QueryIter
 /// [`QueryState`]: crate::query::QueryState
@@ -137,7 +143,7 @@ unsafe impl<D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for Qu
         last_run: Tick,
         this_run: Tick,
     ) -> Result<Self::Item<'w, 's>, EcsError> {
-        state.update(unsafe { world.read_only() });
+        state.update_archetypes(unsafe { world.read_only() });
         Ok(Query {
             world,
             state,
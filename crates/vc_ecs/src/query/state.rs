@@ -9,6 +9,7 @@ use crate::archetype::{ArcheId, Archetypes};
 use crate::entity::StorageId;
 use crate::query::{QueryData, QueryFilter};
 use crate::resource::Resource;
+use crate::storage::{MapId, Maps};
 use crate::system::{AccessParam, AccessTable, FilterParam, FilterParamBuilder};
 use crate::utils::DebugName;
 use crate::world::{World, WorldId};
@@ -39,6 +40,13 @@ use crate::world::{World, WorldId};
 /// is used as a version number, and updates only need to process newly added
 /// archetypes.
 ///
+/// # Sparse Iteration
+///
+/// If every matched archetype must contain the same sparse component, that
+/// component's map lists a superset of the matched entities. When the map
+/// holds fewer entities than there are matched archetypes, iterators walk the
+/// map with [`Map::iter`] instead of visiting every archetype.
+///
 /// # Usage
 ///
 /// [`Query`] is effectively a typed view over [`QueryState`]. In most contexts,
@@ -46,6 +54,7 @@ use crate::world::{World, WorldId};
 /// [`QueryState`], such as iterating with `iter_mut`.
 ///
 /// [`Query`]: crate::query::Query
+/// [`Map::iter`]: crate::storage::Map::iter
 #[derive(Clone)]
 pub struct QueryState<D: QueryData, F: QueryFilter = ()> {
     pub(super) world_id: WorldId,
@@ -53,6 +62,7 @@ pub struct QueryState<D: QueryData, F: QueryFilter = ()> {
     pub(super) storages: Vec<StorageId>,
     pub(super) filter_data: AccessParam,
     pub(super) filter_params: Box<[FilterParam]>,
+    pub(super) sparse_maps: Box<[MapId]>,
     pub(super) d_state: D::State,
     pub(super) f_state: F::State,
 }
@@ -116,35 +126,59 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             collect_arches(&filter_params, &world.archetypes)
         };
 
+        let sparse_maps: Box<[MapId]> = if Self::IS_DENSE {
+            Box::default()
+        } else {
+            collect_sparse_maps(&filter_params, &world.storages.maps)
+        };
+
         QueryState {
             world_id,
             version,
             storages,
             filter_data,
             filter_params,
+            sparse_maps,
             d_state,
             f_state,
         }
     }
 
+    /// Returns the number of archetypes already processed by this state.
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Returns the number of matched tables, or archetypes if the query
+    /// involves sparse components.
+    pub fn matched_storages(&self) -> usize {
+        self.storages.len()
+    }
+
     /// Incrementally updates cached storage matches against the current world.
     ///
-    /// Only archetypes added since the last recorded version are processed.
+    /// Only archetypes added since the last recorded version are processed,
+    /// so calling this on every iteration is cheap once the set of
+    /// archetypes is stable. [`Query`] system params and [`World::query`]
+    /// call it automatically.
+    ///
     /// Panics if `world` does not match [`QueryState::world_id`].
-    pub fn update(&mut self, world: &World) {
+    ///
+    /// [`Query`]: crate::query::Query
+    pub fn update_archetypes(&mut self, world: &World) {
         assert!(self.world_id == world.id());
 
         let archetypes = &world.archetypes;
         if archetypes.len() > self.version {
             if Self::IS_DENSE {
-                updata_dense_state(
+                update_dense_state(
                     &mut self.version,
                     &mut self.storages,
                     &self.filter_params,
                     archetypes,
                 );
             } else {
-                updata_sparse_state(
+                update_sparse_state(
                     &mut self.version,
                     &mut self.storages,
                     &self.filter_params,
@@ -165,7 +199,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
 }
 
 #[inline(never)]
-fn updata_dense_state(
+fn update_dense_state(
     version: &mut usize,
     storages: &mut Vec<StorageId>,
     filter_params: &[FilterParam],
@@ -200,7 +234,7 @@ fn updata_dense_state(
 }
 
 #[inline(never)]
-fn updata_sparse_state(
+fn update_sparse_state(
     version: &mut usize,
     storages: &mut Vec<StorageId>,
     filter_params: &[FilterParam],
@@ -288,3 +322,67 @@ fn collect_tables(params: &[FilterParam], archetypes: &Archetypes) -> Vec<Storag
 
     collector.into_iter().collect()
 }
+
+/// Collects the maps of the sparse components required by every filter param.
+#[inline(never)]
+fn collect_sparse_maps(params: &[FilterParam], maps: &Maps) -> Box<[MapId]> {
+    let Some((first, rest)) = params.split_first() else {
+        return Box::default();
+    };
+
+    first
+        .with()
+        .iter()
+        .filter(|id| {
+            rest.iter()
+                .all(|param| param.with().binary_search(id).is_ok())
+        })
+        .filter_map(|&id| maps.get_id(id))
+        .collect()
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::component::Component;
+    use crate::world::World;
+
+    #[derive(Component)]
+    struct Foo;
+
+    #[derive(Component)]
+    struct Bar;
+
+    #[derive(Component)]
+    #[component(storage = "sparse")]
+    struct Baz;
+
+    #[test]
+    fn update_archetypes() {
+        let mut world = World::default();
+        world.spawn(Foo);
+
+        let mut dense = world.query_state::<&Foo, ()>();
+        let mut sparse = world.query_state::<(&Foo, &Baz), ()>();
+        assert_eq!(dense.matched_storages(), 1);
+        assert_eq!(sparse.matched_storages(), 0);
+
+        world.spawn((Foo, Bar));
+        world.spawn((Foo, Baz));
+        world.spawn(Bar);
+        dense.update_archetypes(&world);
+        sparse.update_archetypes(&world);
+        assert_eq!(dense.version(), world.archetypes().len());
+        // `Foo` and `(Foo, Baz)` share a table.
+        assert_eq!(dense.matched_storages(), 2);
+        assert_eq!(sparse.matched_storages(), 1);
+
+        // Nothing new to process.
+        let version = dense.version();
+        dense.update_archetypes(&world);
+        assert_eq!(dense.version(), version);
+        assert_eq!(dense.matched_storages(), 2);
+    }
+}
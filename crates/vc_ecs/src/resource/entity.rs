@@ -0,0 +1,35 @@
+use crate::component::Component;
+
+use super::ResourceId;
+
+// -----------------------------------------------------------------------------
+// ResourceEntity
+
+/// Marks the entity standing for a resource, see [`World::resource_entity`].
+///
+/// Resources are not stored on this entity, it only gives them an identity in
+/// the entity world: components, hooks and queries can target it like any
+/// other entity. Use `Without<ResourceEntity>` to exclude these entities from
+/// queries.
+///
+/// The component is immutable. Removing it, or despawning the entity, detaches
+/// the entity from the resource, and a new one is spawned on the next call to
+/// [`World::resource_entity`].
+///
+/// [`World::resource_entity`]: crate::world::World::resource_entity
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[component(copy, mutable = false)]
+pub struct ResourceEntity(ResourceId);
+
+impl ResourceEntity {
+    #[inline(always)]
+    pub(crate) const fn new(id: ResourceId) -> Self {
+        Self(id)
+    }
+
+    /// Returns the resource this entity stands for.
+    #[inline(always)]
+    pub const fn id(&self) -> ResourceId {
+        self.0
+    }
+}
@@ -76,6 +76,17 @@ use crate::utils::{Cloner, Dropper};
 /// With the derive macro, this can be configured via `#[resource(copy)]` or
 /// `#[resource(clone)]`.
 ///
+/// ## Reflect
+///
+/// With the `reflect` feature, `Resource::REFLECT` allows
+/// [`World::register_reflect_types`] to register the resource into a type
+/// registry, together with `ReflectResource`.
+///
+/// With the derive macro, use `#[resource(reflect)]`. The type must also
+/// derive `Reflect`.
+///
+/// [`World::register_reflect_types`]: crate::world::World::register_reflect_types
+///
 /// ## Dropper
 ///
 /// [`Resource::DROPPER`] stores the function pointer for [`Drop::drop`].
@@ -98,4 +109,6 @@ pub trait Resource: Sized + 'static {
     const MUTABLE: bool = true;
     const CLONER: Option<Cloner> = None;
     const DROPPER: Option<Dropper> = Dropper::of::<Self>();
+    #[cfg(feature = "reflect")]
+    const REFLECT: Option<crate::utils::Reflector> = None;
 }
@@ -3,6 +3,7 @@ use core::any::TypeId;
 use core::fmt::Debug;
 
 use super::{Resource, ResourceId};
+use crate::entity::Entity;
 use crate::utils::{Cloner, DebugName, Dropper};
 
 // -----------------------------------------------------------------------------
@@ -20,6 +21,8 @@ pub struct ResourceDescriptor {
     pub mutable: bool,
     pub dropper: Option<Dropper>,
     pub cloner: Option<Cloner>,
+    #[cfg(feature = "reflect")]
+    pub reflect: Option<crate::utils::Reflector>,
 }
 
 impl ResourceDescriptor {
@@ -33,6 +36,8 @@ impl ResourceDescriptor {
                 mutable: T::MUTABLE,
                 cloner: T::CLONER,
                 dropper: T::DROPPER,
+                #[cfg(feature = "reflect")]
+                reflect: T::REFLECT,
             }
         }
     }
@@ -47,6 +52,8 @@ impl ResourceDescriptor {
 pub struct ResourceInfo {
     id: ResourceId,
     descriptor: ResourceDescriptor,
+    /// The last entity spawned by `World::resource_entity`, it may be stale.
+    pub(crate) entity: Option<Entity>,
 }
 
 impl Debug for ResourceInfo {
@@ -63,7 +70,11 @@ impl ResourceInfo {
     /// Creates a new resource info with given ID and descriptor.
     #[inline(always)]
     pub(crate) fn new(id: ResourceId, descriptor: ResourceDescriptor) -> Self {
-        Self { id, descriptor }
+        Self {
+            id,
+            descriptor,
+            entity: None,
+        }
     }
 
     /// Returns the resource's unique ID.
@@ -107,4 +118,11 @@ impl ResourceInfo {
     pub fn cloner(&self) -> Option<Cloner> {
         self.descriptor.cloner
     }
+
+    /// Returns the resource's reflection registration.
+    #[cfg(feature = "reflect")]
+    #[inline(always)]
+    pub fn reflect(&self) -> Option<crate::utils::Reflector> {
+        self.descriptor.reflect
+    }
 }
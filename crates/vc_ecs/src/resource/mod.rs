@@ -3,17 +3,27 @@
 // -----------------------------------------------------------------------------
 // Modules
 
+mod entity;
 mod ident;
 mod impls;
 mod info;
 mod resources;
 
+crate::cfg::reflect! {
+    mod reflect;
+}
+
 // -----------------------------------------------------------------------------
 // Exports
 
 pub use vc_ecs_derive::Resource;
 
+pub use entity::ResourceEntity;
 pub use ident::ResourceId;
 pub use impls::Resource;
 pub use info::{ResourceDescriptor, ResourceInfo};
 pub use resources::Resources;
+
+crate::cfg::reflect! {
+    pub use reflect::{ReflectResource, ReflectResourceError, ResourceConflict};
+}
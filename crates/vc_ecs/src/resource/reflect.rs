@@ -0,0 +1,144 @@
+use thiserror::Error;
+use vc_reflect::Reflect;
+use vc_reflect::info::{TypePath, Typed};
+use vc_reflect::registry::{FromType, TypeRegistry};
+
+use crate::resource::Resource;
+use crate::world::World;
+
+// -----------------------------------------------------------------------------
+// ReflectResource
+
+/// A type trait providing reflected access to a resource of a [`World`].
+///
+/// It is registered by [`World::register_reflect_types`] for resources
+/// annotated with `#[resource(reflect)]`, or manually:
+///
+/// ```
+/// use core::any::TypeId;
+/// use vc_ecs::prelude::*;
+/// use vc_ecs::resource::ReflectResource;
+/// use vc_reflect::prelude::*;
+///
+/// #[derive(Resource, Reflect)]
+/// struct Score(u32);
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Score>();
+/// registry.register_type_trait::<Score, ReflectResource>();
+///
+/// let mut world = World::default();
+/// world.insert_resource(Score(7));
+///
+/// let reflect = registry.get_type_trait::<ReflectResource>(TypeId::of::<Score>()).unwrap();
+/// let value = reflect.get(&world).unwrap();
+/// assert_eq!(value.downcast_ref::<Score>().unwrap().0, 7);
+/// ```
+///
+/// [`World::register_reflect_types`]: crate::world::World::register_reflect_types
+#[derive(Clone)]
+pub struct ReflectResource {
+    contains: fn(&World) -> bool,
+    get: fn(&World) -> Option<&dyn Reflect>,
+    insert: fn(&mut World, &dyn Reflect, &TypeRegistry) -> bool,
+}
+
+impl ReflectResource {
+    /// Returns `true` if the resource exists in the world.
+    #[inline(always)]
+    pub fn contains(&self, world: &World) -> bool {
+        (self.contains)(world)
+    }
+
+    /// Returns the resource as a reflected value, `None` if it does not exist.
+    #[inline(always)]
+    pub fn get<'a>(&self, world: &'a World) -> Option<&'a dyn Reflect> {
+        (self.get)(world)
+    }
+
+    /// Inserts the resource from a reflected value, replacing the existing one.
+    ///
+    /// The value may be dynamic, e.g. deserialized from a scene file. It is
+    /// converted with the `ReflectFromReflect` registered in `registry`.
+    ///
+    /// Returns `false` if the conversion fails, the world is unchanged then.
+    #[inline(always)]
+    pub fn insert(&self, world: &mut World, value: &dyn Reflect, registry: &TypeRegistry) -> bool {
+        (self.insert)(world, value, registry)
+    }
+}
+
+impl<T: Resource + Reflect + Typed> FromType<T> for ReflectResource {
+    fn from_type() -> Self {
+        Self {
+            contains: |world| world.get_resource::<T>().is_some(),
+            get: |world| world.get_resource::<T>().map(|value| value as &dyn Reflect),
+            insert: |world, value, registry| {
+                let Some(value) = registry.from_reflect_boxed(T::type_path(), value) else {
+                    return false;
+                };
+                let Ok(value) = value.take::<T>() else {
+                    return false;
+                };
+                world.insert_resource(value);
+                true
+            },
+        }
+    }
+}
+
+impl TypePath for ReflectResource {
+    #[inline(always)]
+    fn type_path() -> &'static str {
+        "vc_ecs::resource::ReflectResource"
+    }
+
+    #[inline(always)]
+    fn type_name() -> &'static str {
+        "ReflectResource"
+    }
+
+    #[inline(always)]
+    fn type_ident() -> &'static str {
+        "ReflectResource"
+    }
+
+    #[inline(always)]
+    fn module_path() -> Option<&'static str> {
+        Some("vc_ecs::resource")
+    }
+}
+
+// -----------------------------------------------------------------------------
+// ResourceConflict
+
+/// How [`World::insert_reflect_resources`] handles resources that already exist.
+///
+/// [`World::insert_reflect_resources`]: crate::world::World::insert_reflect_resources
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceConflict {
+    /// Replaces the existing resource.
+    #[default]
+    Overwrite,
+    /// Keeps the existing resource, the reflected value is ignored.
+    Skip,
+}
+
+// -----------------------------------------------------------------------------
+// ReflectResourceError
+
+/// An error returned by [`World::insert_reflect_resources`].
+///
+/// [`World::insert_reflect_resources`]: crate::world::World::insert_reflect_resources
+#[derive(Error, Debug, Clone)]
+pub enum ReflectResourceError {
+    /// The value is dynamic and does not represent any type.
+    #[error("reflected value does not represent any type")]
+    NotTyped,
+    /// The type is not registered with `ReflectResource`.
+    #[error("resource `{0}` is not registered with `ReflectResource`")]
+    NotReflected(&'static str),
+    /// The value cannot be converted to the resource type.
+    #[error("resource `{0}` cannot be converted from the reflected value")]
+    NotConvertible(&'static str),
+}
@@ -4,7 +4,7 @@ use alloc::vec::Vec;
 use core::any::TypeId;
 use core::fmt::Debug;
 
-use vc_utils::extra::TypeIdMap;
+use vc_utils::extra::{TypeIdEntry, TypeIdMap, VacantTypeIdEntry};
 
 use super::{Resource, ResourceDescriptor};
 use super::{ResourceId, ResourceInfo};
@@ -40,6 +40,12 @@ impl Resources {
         self.infos.len()
     }
 
+    /// Returns an iterator over all registered resource infos, ordered by ID.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &ResourceInfo> {
+        self.infos.iter()
+    }
+
     /// Looks up a resource ID by its [`TypeId`].
     #[inline]
     pub fn get_id(&self, type_id: TypeId) -> Option<ResourceId> {
@@ -89,21 +95,24 @@ impl Resources {
         // thereby improving the execution speed of the hot path.
         #[cold]
         #[inline(never)]
-        fn register_internal(this: &mut Resources, func: fn() -> ResourceDescriptor) -> ResourceId {
-            let id = ResourceId::new(this.infos.len() as u32);
-            let descriptor = func();
-            let type_id = descriptor.type_id;
+        fn register_internal(
+            infos: &mut Vec<ResourceInfo>,
+            entry: VacantTypeIdEntry<'_, ResourceId>,
+            func: fn() -> ResourceDescriptor,
+        ) -> ResourceId {
+            let id = ResourceId::new(infos.len() as u32);
 
-            this.infos.push(ResourceInfo::new(id, descriptor));
-            this.mapper.insert(type_id, id);
+            infos.push(ResourceInfo::new(id, func()));
+            entry.insert(id);
 
             id
         }
 
-        if let Some(id) = self.get_id(TypeId::of::<T>()) {
-            id
-        } else {
-            register_internal(self, ResourceDescriptor::new::<T>)
+        match self.mapper.entry_type::<T>() {
+            TypeIdEntry::Occupied(entry) => *entry.get(),
+            TypeIdEntry::Vacant(entry) => {
+                register_internal(&mut self.infos, entry, ResourceDescriptor::new::<T>)
+            }
         }
     }
 }
@@ -0,0 +1,121 @@
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter, Result};
+
+use crate::system::{AccessConflict, SystemName};
+
+// -----------------------------------------------------------------------------
+// Ambiguity
+
+/// Two systems with conflicting accesses and no ordering between them.
+///
+/// The executor still runs them one after the other, but which one runs
+/// first is arbitrary and may change whenever the schedule is rebuilt.
+/// Adding an ordering with [`Schedule::insert_order`] makes it deterministic.
+///
+/// Returned by [`Schedule::report_ambiguities`], the [`Display`] output
+/// describes the conflicts and suggests the fix.
+///
+/// [`Schedule::insert_order`]: crate::schedule::Schedule::insert_order
+/// [`Schedule::report_ambiguities`]: crate::schedule::Schedule::report_ambiguities
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ambiguity {
+    /// The systems, in the order they currently run.
+    pub systems: [SystemName; 2],
+    /// The reasons why the systems cannot run in parallel.
+    pub conflicts: Vec<AccessConflict>,
+}
+
+impl Display for Ambiguity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let [a, b] = self.systems;
+        write!(f, "`{a}` and `{b}` have no ordering but conflict on")?;
+        for (index, conflict) in self.conflicts.iter().enumerate() {
+            let sep = if index == 0 { " " } else { ", " };
+            match conflict {
+                AccessConflict::World => write!(f, "{sep}the world")?,
+                AccessConflict::Resource(id) => write!(f, "{sep}resource {id}")?,
+                AccessConflict::Query {
+                    filters,
+                    components,
+                } => {
+                    if components.is_empty() {
+                        write!(f, "{sep}whole entities")?;
+                    } else {
+                        write!(f, "{sep}components {components:?}")?;
+                    }
+                    write!(f, " (queries {:?} and {:?})", filters[0], filters[1])?;
+                }
+            }
+        }
+        write!(
+            f,
+            "; consider `insert_order({a:?}, {b:?})` or the reverse order"
+        )
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::component::Component;
+    use crate::query::{Query, With, Without};
+    use crate::schedule::Schedule;
+    use crate::system::AccessConflict;
+    use crate::world::World;
+
+    #[derive(Component)]
+    struct Pos;
+
+    #[derive(Component)]
+    struct Frozen;
+
+    fn write_pos(_: Query<&mut Pos, Without<Frozen>>) {}
+    fn read_pos(_: Query<&Pos>) {}
+    fn read_frozen(_: Query<&Pos, With<Frozen>>) {}
+    fn write_frozen(_: Query<&mut Pos, With<Frozen>>) {}
+
+    #[test]
+    fn report_ambiguities() {
+        let mut world = World::default();
+        let mut schedule = Schedule::default();
+        let write = schedule.add_system(write_pos);
+        let read = schedule.add_system(read_pos);
+        let frozen = schedule.add_system(read_frozen);
+        let last = schedule.add_system(write_frozen);
+        schedule.insert_order(write, frozen);
+        schedule.update(&mut world);
+
+        // `write_pos` never matches the entities of `write_frozen`.
+        let report = schedule.report_ambiguities();
+        let mut pairs: Vec<_> = report
+            .iter()
+            .map(|a| {
+                let mut pair = a.systems;
+                pair.sort();
+                pair
+            })
+            .collect();
+        pairs.sort();
+        let mut expected = [[write, read], [read, last], [frozen, last]].map(|mut pair| {
+            pair.sort();
+            pair
+        });
+        expected.sort();
+        assert_eq!(pairs, expected);
+
+        let AccessConflict::Query { components, .. } = &report[0].conflicts[0] else {
+            panic!("expected a query conflict");
+        };
+        assert_eq!(components.len(), 1);
+
+        // Ordering through another system resolves the ambiguity.
+        schedule.insert_order(read, write);
+        schedule.insert_order(frozen, last);
+        schedule.update(&mut world);
+        assert!(schedule.report_ambiguities().is_empty());
+    }
+}
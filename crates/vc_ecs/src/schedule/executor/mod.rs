@@ -3,6 +3,7 @@ mod single;
 
 pub use multi::MultiThreadedExecutor;
 pub use single::SingleThreadedExecutor;
+pub(crate) use single::run_system;
 
 // -----------------------------------------------------------------------------
 // Exports
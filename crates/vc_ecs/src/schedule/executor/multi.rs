@@ -108,7 +108,7 @@ impl<'scope, 'env: 'scope, 'sys: 'scope> Context<'scope, 'env, 'sys> {
         } = schedule.view();
 
         Self {
-            world: world.unsafe_world(),
+            world: world.unsafe_world_mut(),
             executor,
             systems: SyncUnsafeCell::from_mut(systems).transpose(),
             outgoing,
@@ -154,7 +154,11 @@ impl<'scope, 'env: 'scope, 'sys: 'scope> Context<'scope, 'env, 'sys> {
     }
 
     fn spawn_system_task(&self, system_index: u16) {
-        let system = &mut unsafe { &mut *self.systems[system_index as usize].get() }.system;
+        let SystemObject {
+            system,
+            last_duration,
+            ..
+        } = unsafe { &mut *self.systems[system_index as usize].get() };
         let non_send = system.is_non_send();
         let name = system.name();
         let context: Context<'scope, 'env, 'sys> = *self;
@@ -170,9 +174,12 @@ impl<'scope, 'env: 'scope, 'sys: 'scope> Context<'scope, 'env, 'sys> {
 
             cfg::std! {
                 if {
+                    let start = vc_os::time::Instant::now();
                     let result = ::std::panic::catch_unwind(func);
+                    *last_duration = Some(start.elapsed());
                     context.push_completed_system(system_index, result, system);
                 } else {
+                    let _ = last_duration;
                     (func)();
                     context.push_completed_system(system_index, Ok(()), system);
                 }
@@ -2,9 +2,9 @@ use core::marker::PhantomData;
 use core::panic::AssertUnwindSafe;
 
 use crate::cfg;
-use crate::error::{EcsError, ErrorContext};
+use crate::error::{EcsError, ErrorContext, ErrorHandler};
 use crate::schedule::schedule::SystemScheduleView;
-use crate::schedule::{ExecutorKind, SystemExecutor, SystemSchedule};
+use crate::schedule::{ExecutorKind, SystemExecutor, SystemObject, SystemSchedule};
 use crate::world::World;
 
 /// Runs the schedule using a single thread.
@@ -52,11 +52,8 @@ impl SystemExecutor for SingleThreadedExecutor {
 
     /// Runs all systems sequentially on the current thread.
     ///
-    /// Execution order follows `schedule.systems` order. System-returned errors
-    /// are forwarded to `handler` with [`ErrorContext::System`].
-    ///
-    /// When `std` is available, each system call is wrapped in `catch_unwind`:
-    /// panic information is printed and then rethrown.
+    /// Execution order follows `schedule.systems` order, see [`run_system`]
+    /// for error and panic handling.
     fn run(
         &mut self,
         schedule: &mut SystemSchedule,
@@ -74,27 +71,40 @@ impl SystemExecutor for SingleThreadedExecutor {
         assert_eq!(system_count, incoming.len());
         assert_eq!(system_count, outgoing.len());
 
-        systems.iter_mut().for_each(|obj| {
-            let system = &mut obj.system;
-            let name = system.name();
-            let func = AssertUnwindSafe(|| unsafe {
-                if let Err(e) = system.run((), world.unsafe_world()) {
-                    let last_run = system.get_last_run();
-                    let ctx = ErrorContext::System { name, last_run };
-                    handler(e, ctx);
-                }
-            });
+        systems
+            .iter_mut()
+            .for_each(|obj| run_system(obj, world, handler));
+    }
+}
 
-            cfg::std! {
-                if {
-                    if let Err(payload) = ::std::panic::catch_unwind(func) {
-                        ::std::eprintln!("Encountered a panic in system `{}`!", system.name());
-                        ::std::panic::resume_unwind(payload);
-                    }
-                } else {
-                    (func)();
-                }
+/// Runs one system on the current thread.
+///
+/// System-returned errors are forwarded to `handler` with [`ErrorContext::System`].
+/// When `std` is available, the call is wrapped in `catch_unwind`: panic
+/// information is printed and then rethrown, and the run duration is stored
+/// in [`SystemObject::last_duration`].
+pub(crate) fn run_system(obj: &mut SystemObject, world: &mut World, handler: ErrorHandler) {
+    let system = &mut obj.system;
+    let name = system.name();
+    let func = AssertUnwindSafe(|| unsafe {
+        if let Err(e) = system.run((), world.unsafe_world_mut()) {
+            let last_run = system.get_last_run();
+            let ctx = ErrorContext::System { name, last_run };
+            handler(e, ctx);
+        }
+    });
+
+    cfg::std! {
+        if {
+            let start = vc_os::time::Instant::now();
+            let result = ::std::panic::catch_unwind(func);
+            obj.last_duration = Some(start.elapsed());
+            if let Err(payload) = result {
+                ::std::eprintln!("Encountered a panic in system `{}`!", obj.system.name());
+                ::std::panic::resume_unwind(payload);
             }
-        });
+        } else {
+            (func)();
+        }
     }
 }
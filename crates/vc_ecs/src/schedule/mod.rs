@@ -3,17 +3,20 @@
 //! This module contains:
 //! - schedule labels and schedule collections,
 //! - dependency graph utilities,
-//! - system ordering/concurrency planning,
-//! - executor backends (single-threaded and multi-threaded).
+//! - system ordering/concurrency planning and ambiguity reports,
+//! - executor backends (single-threaded and multi-threaded),
+//! - step-by-step execution for debugging.
 
 // -----------------------------------------------------------------------------
 // Modules
 
+mod ambiguity;
 mod executor;
 mod graph;
 mod label;
 mod schedule;
 mod schedules;
+mod stepping;
 mod system;
 
 // -----------------------------------------------------------------------------
@@ -21,6 +24,7 @@ mod system;
 
 pub use vc_ecs_derive::ScheduleLabel;
 
+pub use ambiguity::Ambiguity;
 pub use executor::{ExecutorKind, MainThreadExecutor, SystemExecutor};
 pub use executor::{MultiThreadedExecutor, SingleThreadedExecutor};
 pub use graph::{Dag, DiGraph, ToposortError, UnGraph};
@@ -28,6 +32,7 @@ pub use graph::{Direction, Graph, GraphNode, SccIterator, SccNodes};
 pub use label::{AnonymousSchedule, InternedScheduleLabel, ScheduleLabel};
 pub use schedule::{Schedule, SystemSchedule};
 pub use schedules::Schedules;
+pub use stepping::{Stepping, SteppingHook};
 pub use system::{SystemKey, SystemObject, UnitSystem};
 
 // -----------------------------------------------------------------------------
@@ -77,7 +82,64 @@ mod tests {
         assert!(qux_values.contains(&3.0));
     }
 
-    fn spawn_entities(world: &mut World) -> () {
+    #[test]
+    fn diagnostics() {
+        let mut world = World::default();
+        let mut schedules = Schedules::new();
+
+        schedules.add_system(Testing, spawn_entities);
+        schedules.entry(Testing).run(&mut world);
+        world.insert_non_send(schedules);
+
+        let diagnostics = world.diagnostics();
+        assert_eq!(diagnostics.entity_count, 5);
+        assert_eq!(diagnostics.systems.len(), 1);
+        assert_eq!(diagnostics.systems[0].schedule, "Testing");
+        #[cfg(feature = "std")]
+        assert!(diagnostics.systems[0].last_run.is_some());
+
+        let baz = diagnostics.components.iter().find(|c| c.sparse).unwrap();
+        assert_eq!(baz.map_len, 3);
+        assert!(baz.map_bytes > 0);
+        assert_eq!(baz.table_count, 0);
+
+        let foo = diagnostics
+            .components
+            .iter()
+            .find(|c| c.table_len == 5)
+            .unwrap();
+        assert!(!foo.sparse);
+        assert!(foo.table_count > 1);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn non_send_off_thread() {
+        use crate::borrow::NonSend;
+        use crate::resource::Resource;
+
+        #[derive(Resource)]
+        struct Local(u32);
+
+        fn read_local(local: NonSend<Local>) {
+            assert_eq!(local.0, 1);
+        }
+
+        let run = |world: &mut World| {
+            let mut schedules = Schedules::new();
+            schedules.add_system(Testing, read_local);
+            schedules.entry(Testing).run(world);
+        };
+
+        let mut world = World::default();
+        world.insert_non_send(Local(1));
+        run(&mut world);
+
+        let result = std::thread::scope(|s| s.spawn(|| run(&mut world)).join());
+        assert_eq!(result.is_err(), cfg!(debug_assertions));
+    }
+
+    fn spawn_entities(world: &mut World) {
         world.spawn((Foo, Bar(100), Baz(String::from("a")), Qux(1.0)));
         world.spawn((Foo, Bar(200), Baz(String::from("b"))));
         world.spawn((Foo, Bar(300), Qux(3.0)));
@@ -1,6 +1,7 @@
 #![expect(clippy::module_inception, reason = "For better structure.")]
 
 use core::fmt::Debug;
+use core::time::Duration;
 
 use alloc::boxed::Box;
 use alloc::vec::Vec;
@@ -10,11 +11,11 @@ use slotmap::{SecondaryMap, SlotMap};
 use vc_utils::extra::PagePool;
 use vc_utils::hash::{HashMap, HashSet, NoOpHashMap};
 
-use super::{Dag, SystemKey, SystemObject, UnitSystem};
+use super::{Ambiguity, Dag, SystemKey, SystemObject, UnitSystem};
 use super::{ExecutorKind, MultiThreadedExecutor, SingleThreadedExecutor};
-use super::{InternedScheduleLabel, ScheduleLabel, SystemExecutor};
+use super::{InternedScheduleLabel, ScheduleLabel, Stepping, SystemExecutor};
 use crate::schedule::AnonymousSchedule;
-use crate::system::{IntoSystem, SystemName};
+use crate::system::{AccessConflict, IntoSystem, SystemName};
 use crate::world::World;
 
 // -----------------------------------------------------------------------------
@@ -51,6 +52,7 @@ pub struct Schedule {
     executor: Box<dyn SystemExecutor>,
     executor_initialized: bool,
     is_changed: bool,
+    stepping: Option<Stepping>,
 }
 
 // -----------------------------------------------------------------------------
@@ -372,7 +374,7 @@ impl Schedule {
 
         schedule.incoming.resize(topo.len(), 0);
         schedule.outgoing.resize(topo.len(), &[]);
-        let mut outgoing: Vec<Vec<u16>> = Vec::with_capacity(topo.len());
+        let mut outgoing: Vec<Vec<u16>> = alloc::vec![Vec::new(); topo.len()];
 
         let mut indices: HashMap<SystemKey, usize> = HashMap::with_capacity(topo.len());
         topo.iter().enumerate().for_each(|(idx, &key)| {
@@ -407,6 +409,9 @@ impl Schedule {
             self.init_systems(world);
             self.build_schedule();
             self.is_changed = false;
+            if let Some(stepping) = &mut self.stepping {
+                stepping.reset_cursor();
+            }
         }
 
         if !self.executor_initialized {
@@ -419,16 +424,25 @@ impl Schedule {
     /// Executes the schedule once.
     ///
     /// This performs [`Schedule::update`] first, runs all systems through the
-    /// configured executor, then updates world ticks and applies deferred
-    /// commands.
+    /// configured executor, then updates world ticks and flushes the world,
+    /// see [`World::flush`].
+    ///
+    /// With [`Stepping`] enabled, only the systems it allows are run, and the
+    /// ticks and commands are only handled once a frame is finished.
     pub fn run(&mut self, world: &mut World) {
         self.update(world);
 
         let handler = world.default_error_handler();
-        self.executor.run(&mut self.schedule, world, handler.0);
+        if let Some(stepping) = &mut self.stepping {
+            if !stepping.run(&mut self.schedule.systems, world, handler.0) {
+                return;
+            }
+        } else {
+            self.executor.run(&mut self.schedule, world, handler.0);
+        }
 
         world.update_tick();
-        world.apply_commands();
+        world.flush();
     }
 
     /// Creates a new schedule with the given label.
@@ -448,6 +462,7 @@ impl Schedule {
             ordering: Default::default(),
             conflict: Default::default(),
             schedule: Default::default(),
+            stepping: None,
         }
     }
 
@@ -592,6 +607,134 @@ impl Schedule {
     pub fn order_graph(&self) -> &Dag<SystemKey> {
         &self.ordering.ordering
     }
+
+    /// Reports pairs of systems that conflict without an explicit ordering.
+    ///
+    /// Conflicting systems never run in parallel, but unless an ordering is
+    /// given with [`Schedule::insert_order`], directly or through other systems,
+    /// the order between them is arbitrary. See [`Ambiguity`].
+    ///
+    /// Only systems compiled by the last [`Schedule::update`] are checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vc_ecs::prelude::*;
+    /// use vc_ecs::schedule::Schedule;
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Score(u32);
+    ///
+    /// fn add(mut score: ResMut<Score>) { score.0 += 1; }
+    /// fn double(mut score: ResMut<Score>) { score.0 *= 2; }
+    ///
+    /// let mut world = World::default();
+    /// world.insert_resource(Score::default());
+    ///
+    /// let mut schedule = Schedule::default();
+    /// let a = schedule.add_system(add);
+    /// let b = schedule.add_system(double);
+    /// schedule.update(&mut world);
+    ///
+    /// let report = schedule.report_ambiguities();
+    /// assert_eq!(report.len(), 1);
+    /// assert!(report[0].to_string().contains("insert_order"));
+    ///
+    /// schedule.insert_order(a, b);
+    /// schedule.update(&mut world);
+    /// assert!(schedule.report_ambiguities().is_empty());
+    /// ```
+    pub fn report_ambiguities(&self) -> Vec<Ambiguity> {
+        let keys = &self.schedule.keys;
+        let systems = &self.schedule.systems;
+        let graph = self.ordering.ordering.graph();
+
+        let mut indices: HashMap<SystemKey, usize> = HashMap::with_capacity(keys.len());
+        indices.extend(keys.iter().enumerate().map(|(idx, &key)| (key, idx)));
+
+        // `keys` is toposorted and contains the explicit ordering,
+        // so each system can only reach the systems after it.
+        let mut reachable = alloc::vec![FixedBitSet::with_capacity(keys.len()); keys.len()];
+        for idx in (0..keys.len()).rev() {
+            let (head, tail) = reachable.split_at_mut(idx + 1);
+            graph.neighbors(keys[idx]).for_each(|to| {
+                let to_idx = indices[&to];
+                head[idx].insert(to_idx);
+                head[idx].union_with(&tail[to_idx - idx - 1]);
+            });
+        }
+
+        let mut ambiguities = Vec::new();
+        for (ia, a) in systems.iter().enumerate() {
+            for (ib, b) in systems.iter().enumerate().skip(ia + 1) {
+                if reachable[ia].contains(ib) {
+                    continue;
+                }
+                let conflicts = if a.system.is_exclusive() || b.system.is_exclusive() {
+                    alloc::vec![AccessConflict::World]
+                } else {
+                    a.access.conflicts(&b.access)
+                };
+                if !conflicts.is_empty() {
+                    ambiguities.push(Ambiguity {
+                        systems: [a.system.name(), b.system.name()],
+                        conflicts,
+                    });
+                }
+            }
+        }
+        ambiguities
+    }
+
+    /// Enables [`Stepping`] and returns it, keeping the current state if
+    /// already enabled.
+    pub fn enable_stepping(&mut self) -> &mut Stepping {
+        self.stepping.get_or_insert_with(Stepping::new)
+    }
+
+    /// Disables [`Stepping`] and returns its state.
+    ///
+    /// If a frame was in progress, the next run starts a new one.
+    pub fn disable_stepping(&mut self) -> Option<Stepping> {
+        self.stepping.take().map(|mut stepping| {
+            stepping.reset_cursor();
+            stepping
+        })
+    }
+
+    /// Returns the [`Stepping`] state, if enabled.
+    pub fn stepping(&self) -> Option<&Stepping> {
+        self.stepping.as_ref()
+    }
+
+    /// Returns the [`Stepping`] state mutably, if enabled.
+    pub fn stepping_mut(&mut self) -> Option<&mut Stepping> {
+        self.stepping.as_mut()
+    }
+
+    /// Returns the next system [`Stepping`] will execute.
+    ///
+    /// Returns `None` if stepping is disabled, or the schedule changed since
+    /// the last [`Schedule::update`].
+    pub fn next_system(&self) -> Option<SystemName> {
+        if self.is_changed {
+            return None;
+        }
+        self.stepping.as_ref()?.next_system(&self.schedule)
+    }
+
+    /// Iterates over compiled systems with the duration of their last run.
+    ///
+    /// Systems inserted since the last [`Schedule::update`] are not included,
+    /// and durations are only recorded when `std` is available.
+    pub fn last_run_durations(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (SystemName, Option<Duration>)> + '_ {
+        self.schedule
+            .systems
+            .iter()
+            .map(|obj| (obj.system.name(), obj.last_duration))
+    }
 }
 
 fn transitive_reduction(conflict: &ConflictTable, ordering: &mut OrderingGraph) -> Dag<SystemKey> {
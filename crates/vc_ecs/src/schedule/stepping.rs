@@ -0,0 +1,335 @@
+use alloc::boxed::Box;
+use core::fmt::Debug;
+
+use vc_utils::hash::NoOpHashSet;
+
+use super::executor::run_system;
+use super::{SystemObject, SystemSchedule};
+use crate::error::ErrorHandler;
+use crate::system::SystemName;
+use crate::world::World;
+
+// -----------------------------------------------------------------------------
+// Stepping
+
+/// Callback invoked around each system run by [`Stepping`].
+pub type SteppingHook = Box<dyn FnMut(SystemName, &mut World)>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pending {
+    None,
+    System,
+    Frame,
+}
+
+/// Step-by-step control over the execution of a [`Schedule`].
+///
+/// Once enabled with [`Schedule::enable_stepping`], the schedule runs its
+/// systems sequentially in their topological order, and [`Schedule::run`]
+/// is controlled by this struct:
+///
+/// - When running, each call executes the whole frame like a normal run.
+/// - When [paused], a call executes nothing, unless [`step_system`] or
+///   [`step_frame`] was requested before it.
+/// - [Skipped] systems never run, neither while running nor while stepping.
+///
+/// World ticks are only advanced and commands only applied once the last
+/// system of a frame has run. The [before] and [after] hooks are called
+/// with the [`World`] around every executed system, which is the entry
+/// point of debuggers and inspectors.
+///
+/// # Examples
+///
+/// ```
+/// use vc_ecs::prelude::*;
+/// use vc_ecs::schedule::Schedule;
+///
+/// #[derive(Resource, Default)]
+/// struct Counter(u32);
+///
+/// fn first(mut counter: ResMut<Counter>) { counter.0 += 1; }
+/// fn second(mut counter: ResMut<Counter>) { counter.0 += 10; }
+///
+/// let mut world = World::default();
+/// world.insert_resource(Counter::default());
+///
+/// let mut schedule = Schedule::default();
+/// let a = schedule.add_system(first);
+/// let b = schedule.add_system(second);
+/// schedule.insert_order(a, b);
+///
+/// schedule.enable_stepping().pause();
+/// schedule.run(&mut world);
+/// assert_eq!(world.get_resource::<Counter>().unwrap().0, 0);
+/// assert_eq!(schedule.next_system(), Some(a));
+///
+/// schedule.stepping_mut().unwrap().step_system();
+/// schedule.run(&mut world);
+/// assert_eq!(world.get_resource::<Counter>().unwrap().0, 1);
+/// assert_eq!(schedule.next_system(), Some(b));
+///
+/// schedule.stepping_mut().unwrap().skip_system(b).resume();
+/// schedule.run(&mut world);
+/// schedule.run(&mut world);
+/// assert_eq!(world.get_resource::<Counter>().unwrap().0, 2);
+/// ```
+///
+/// [`Schedule`]: crate::schedule::Schedule
+/// [`Schedule::enable_stepping`]: crate::schedule::Schedule::enable_stepping
+/// [`Schedule::run`]: crate::schedule::Schedule::run
+/// [paused]: Stepping::pause
+/// [`step_system`]: Stepping::step_system
+/// [`step_frame`]: Stepping::step_frame
+/// [Skipped]: Stepping::skip_system
+/// [before]: Stepping::on_before_system
+/// [after]: Stepping::on_after_system
+pub struct Stepping {
+    paused: bool,
+    pending: Pending,
+    cursor: usize,
+    skipped: NoOpHashSet<SystemName>,
+    before: Option<SteppingHook>,
+    after: Option<SteppingHook>,
+}
+
+impl Default for Stepping {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for Stepping {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Stepping")
+            .field("paused", &self.paused)
+            .field("pending", &self.pending)
+            .field("cursor", &self.cursor)
+            .field("skipped", &self.skipped.len())
+            .finish()
+    }
+}
+
+impl Stepping {
+    /// Creates a running stepping state without skipped systems or hooks.
+    pub fn new() -> Self {
+        Self {
+            paused: false,
+            pending: Pending::None,
+            cursor: 0,
+            skipped: NoOpHashSet::new(),
+            before: None,
+            after: None,
+        }
+    }
+
+    /// Pauses execution, the following runs execute only requested steps.
+    pub fn pause(&mut self) -> &mut Self {
+        self.paused = true;
+        self
+    }
+
+    /// Resumes execution, the next run completes the current frame.
+    pub fn resume(&mut self) -> &mut Self {
+        self.paused = false;
+        self.pending = Pending::None;
+        self
+    }
+
+    /// Returns `true` if execution is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Requests the next run to execute only the next system.
+    ///
+    /// Has no effect unless paused.
+    pub fn step_system(&mut self) -> &mut Self {
+        self.pending = Pending::System;
+        self
+    }
+
+    /// Requests the next run to execute the rest of the current frame.
+    ///
+    /// Has no effect unless paused.
+    pub fn step_frame(&mut self) -> &mut Self {
+        self.pending = Pending::Frame;
+        self
+    }
+
+    /// Skips the system in all following runs.
+    pub fn skip_system(&mut self, name: SystemName) -> &mut Self {
+        self.skipped.insert(name);
+        self
+    }
+
+    /// Stops skipping the system.
+    pub fn unskip_system(&mut self, name: SystemName) -> &mut Self {
+        self.skipped.remove(&name);
+        self
+    }
+
+    /// Returns `true` if the system is skipped.
+    pub fn is_skipped(&self, name: SystemName) -> bool {
+        self.skipped.contains(&name)
+    }
+
+    /// Sets a hook called before each executed system.
+    pub fn on_before_system(
+        &mut self,
+        hook: impl FnMut(SystemName, &mut World) + 'static,
+    ) -> &mut Self {
+        self.before = Some(Box::new(hook));
+        self
+    }
+
+    /// Sets a hook called after each executed system.
+    pub fn on_after_system(
+        &mut self,
+        hook: impl FnMut(SystemName, &mut World) + 'static,
+    ) -> &mut Self {
+        self.after = Some(Box::new(hook));
+        self
+    }
+
+    /// Returns `true` if the current frame has started but not finished.
+    pub fn is_mid_frame(&self) -> bool {
+        self.cursor != 0
+    }
+
+    /// Restarts the current frame, e.g. after the schedule was rebuilt.
+    pub(super) fn reset_cursor(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// Returns the next system that will be executed.
+    pub(super) fn next_system(&self, schedule: &SystemSchedule) -> Option<SystemName> {
+        schedule
+            .systems()
+            .get(self.cursor..)?
+            .iter()
+            .map(|obj| obj.system.name())
+            .find(|name| !self.skipped.contains(name))
+    }
+
+    /// Runs systems as allowed by the current state.
+    ///
+    /// Returns `true` if the frame has been finished.
+    pub(super) fn run(
+        &mut self,
+        systems: &mut [SystemObject],
+        world: &mut World,
+        handler: ErrorHandler,
+    ) -> bool {
+        let mut budget = match (self.paused, self.pending) {
+            (false, _) | (true, Pending::Frame) => usize::MAX,
+            (true, Pending::System) => 1,
+            (true, Pending::None) => return false,
+        };
+        self.pending = Pending::None;
+
+        while let Some(obj) = systems.get_mut(self.cursor) {
+            let name = obj.system.name();
+            if self.skipped.contains(&name) {
+                self.cursor += 1;
+                continue;
+            }
+            if budget == 0 {
+                return false;
+            }
+            budget -= 1;
+
+            if let Some(hook) = self.before.as_mut() {
+                hook(name, world);
+            }
+            run_system(obj, world, handler);
+            if let Some(hook) = self.after.as_mut() {
+                hook(name, world);
+            }
+            self.cursor += 1;
+        }
+
+        self.cursor = 0;
+        true
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+
+    use alloc::rc::Rc;
+
+    use crate::borrow::ResMut;
+    use crate::resource::Resource;
+    use crate::schedule::Schedule;
+    use crate::system::SystemName;
+    use crate::world::World;
+
+    #[derive(Resource, Default)]
+    struct Log(Vec<u8>);
+
+    fn a(mut log: ResMut<Log>) {
+        log.0.push(1);
+    }
+
+    fn b(mut log: ResMut<Log>) {
+        log.0.push(2);
+    }
+
+    fn c(mut log: ResMut<Log>) {
+        log.0.push(3);
+    }
+
+    #[test]
+    fn step_through_frame() {
+        let mut world = World::default();
+        world.insert_resource(Log::default());
+
+        let mut schedule = Schedule::default();
+        let na = schedule.add_system(a);
+        let nb = schedule.add_system(b);
+        let nc = schedule.add_system(c);
+        schedule.insert_order(na, nb);
+        schedule.insert_order(nb, nc);
+
+        let seen: Rc<RefCell<Vec<SystemName>>> = Rc::default();
+        let hook_seen = seen.clone();
+        schedule
+            .enable_stepping()
+            .pause()
+            .on_before_system(move |name, world| {
+                assert!(world.get_resource::<Log>().is_some());
+                hook_seen.borrow_mut().push(name);
+            });
+
+        let tick = world.last_run();
+        schedule.run(&mut world);
+        assert_eq!(schedule.next_system(), Some(na));
+
+        schedule.stepping_mut().unwrap().step_system();
+        schedule.run(&mut world);
+        assert_eq!(world.get_resource::<Log>().unwrap().0, [1]);
+        assert_eq!(schedule.next_system(), Some(nb));
+        // The frame is not finished yet.
+        assert_eq!(world.last_run(), tick);
+
+        schedule
+            .stepping_mut()
+            .unwrap()
+            .skip_system(nb)
+            .step_frame();
+        schedule.run(&mut world);
+        assert_eq!(world.get_resource::<Log>().unwrap().0, [1, 3]);
+        assert_eq!(schedule.next_system(), Some(na));
+        assert_ne!(world.last_run(), tick);
+        assert_eq!(*seen.borrow(), [na, nc]);
+
+        schedule.disable_stepping();
+        schedule.run(&mut world);
+        assert_eq!(world.get_resource::<Log>().unwrap().0, [1, 3, 1, 2, 3]);
+    }
+}
@@ -1,5 +1,6 @@
 use alloc::boxed::Box;
 use core::fmt::Debug;
+use core::time::Duration;
 
 use super::{Direction, GraphNode};
 use crate::system::{AccessTable, System};
@@ -30,9 +31,13 @@ pub type UnitSystem = Box<dyn System<Input = (), Output = ()>>;
 ///
 /// `access` is filled during initialization and later used by
 /// the scheduler to validate conflicts and build execution order.
+///
+/// `last_duration` is recorded by the executors after each run when
+/// `std` is available, and stays `None` until the system first runs.
 pub struct SystemObject {
     pub system: UnitSystem,
     pub access: AccessTable,
+    pub last_duration: Option<Duration>,
 }
 
 impl SystemObject {
@@ -45,6 +50,7 @@ impl SystemObject {
         Self {
             system,
             access: AccessTable::new(),
+            last_duration: None,
         }
     }
 }
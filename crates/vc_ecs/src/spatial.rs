@@ -0,0 +1,277 @@
+//! Spatial lookup of entities by position.
+//!
+//! A [`SpatialGrid<P>`] is a resource bucketing entities into the cells of a
+//! uniform 3D grid by their position component `P`, so that neighbourhood
+//! queries only visit nearby cells instead of every entity.
+//!
+//! Like [`Index`](crate::index::Index), the grid is opt-in: the component
+//! routes its hooks to [`SpatialGrid::on_add`] and [`SpatialGrid::on_remove`],
+//! the grid is created with [`World::init_spatial_grid`], and the system
+//! [`SpatialGrid::update`] moves the entities whose position changed.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use vc_os::sync::{Mutex, PoisonError};
+use vc_utils::hash::HashMap;
+
+use crate::borrow::Res;
+use crate::component::Component;
+use crate::entity::Entity;
+use crate::query::{Changed, Query};
+use crate::resource::Resource;
+use crate::world::World;
+
+// -----------------------------------------------------------------------------
+// GridPosition
+
+/// A component locating an entity in a [`SpatialGrid`].
+///
+/// 2D positions can use `0.0` as the third coordinate.
+pub trait GridPosition: Component {
+    /// Returns the position as `[x, y, z]`.
+    fn grid_position(&self) -> [f32; 3];
+}
+
+// -----------------------------------------------------------------------------
+// SpatialGrid
+
+type Cell = [i32; 3];
+
+#[derive(Default)]
+struct Grid {
+    /// Entities of each non-empty cell.
+    cells: HashMap<Cell, Vec<Entity>>,
+    /// The indexed position of each entity.
+    positions: HashMap<Entity, [f32; 3]>,
+}
+
+/// A uniform grid from positions to the entities of a [`World`].
+///
+/// Entities are added and removed by the hooks of `P`, which must be set to
+/// [`SpatialGrid::on_add`] and [`SpatialGrid::on_remove`]. Positions usually
+/// change every frame without invoking hooks, so [`SpatialGrid::update`] must
+/// run as a system to move the entities whose `P` [changed](Changed).
+///
+/// The cell size should be close to the usual query radius: small cells make
+/// large queries visit many cells, large cells make queries filter many
+/// entities.
+///
+/// # Examples
+///
+/// ```
+/// use vc_ecs::prelude::*;
+/// use vc_ecs::spatial::{GridPosition, SpatialGrid};
+///
+/// #[derive(Component)]
+/// #[component(
+///     mutable = true,
+///     on_add = SpatialGrid::<Self>::on_add,
+///     on_remove = SpatialGrid::<Self>::on_remove,
+/// )]
+/// struct Position(f32, f32);
+///
+/// impl GridPosition for Position {
+///     fn grid_position(&self) -> [f32; 3] {
+///         [self.0, self.1, 0.0]
+///     }
+/// }
+///
+/// let mut world = World::default();
+/// world.init_spatial_grid::<Position>(10.0);
+/// let a = world.spawn(Position(1.0, 1.0)).entity();
+/// let b = world.spawn(Position(4.0, 5.0)).entity();
+/// world.spawn(Position(50.0, 0.0));
+///
+/// let grid = world.get_resource::<SpatialGrid<Position>>().unwrap();
+/// assert_eq!(grid.query_radius([0.0; 3], 2.0), [a]);
+/// let found = grid.query_aabb([0.0; 3], [5.0, 5.0, 0.0]);
+/// assert_eq!(found.len(), 2);
+/// assert!(found.contains(&b));
+/// ```
+pub struct SpatialGrid<P: GridPosition> {
+    cell_size: f32,
+    grid: Mutex<Grid>,
+    _marker: PhantomData<fn() -> P>,
+}
+
+impl<P: GridPosition> Resource for SpatialGrid<P> {
+    const MUTABLE: bool = false;
+}
+
+impl<P: GridPosition> SpatialGrid<P> {
+    /// Creates an empty grid with the given cell size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is not positive.
+    pub(crate) fn new(cell_size: f32) -> Self {
+        assert!(cell_size > 0.0, "the cell size must be positive");
+        Self {
+            cell_size,
+            grid: Mutex::new(Grid::default()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the size of the grid cells.
+    #[inline]
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// The `ON_ADD` hook of `P`, adding `entity` to the grid.
+    pub fn on_add(world: &World, entity: Entity) {
+        let Some(grid) = world.get_resource::<Self>() else {
+            return;
+        };
+        if let Some(position) = world.entity_ref(entity).get::<P>() {
+            grid.insert(entity, position.grid_position());
+        }
+    }
+
+    /// The `ON_REMOVE` hook of `P`, removing `entity` from the grid.
+    pub fn on_remove(world: &World, entity: Entity) {
+        if let Some(grid) = world.get_resource::<Self>() {
+            grid.remove(entity);
+        }
+    }
+
+    /// Moves the entities whose position changed since the last run.
+    ///
+    /// This is a system, and should run after the systems moving entities.
+    pub fn update(grid: Res<Self>, query: Query<(Entity, &P), Changed<P>>) {
+        for (entity, position) in query.iter() {
+            grid.insert(entity, position.grid_position());
+        }
+    }
+
+    fn cell(&self, position: [f32; 3]) -> Cell {
+        // `f32::floor` is not available in `core`.
+        position.map(|x| {
+            let x = x / self.cell_size;
+            let cell = x as i32;
+            if (cell as f32) > x { cell - 1 } else { cell }
+        })
+    }
+
+    /// Adds `entity` at `position`, moving it if it is already in the grid.
+    pub(crate) fn insert(&self, entity: Entity, position: [f32; 3]) {
+        let cell = self.cell(position);
+        let mut grid = self.grid.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(old) = grid.positions.insert(entity, position) {
+            let old = self.cell(old);
+            if old == cell {
+                return;
+            }
+            Self::unlink(&mut grid, entity, old);
+        }
+        grid.cells.entry(cell).or_default().push(entity);
+    }
+
+    fn remove(&self, entity: Entity) {
+        let mut grid = self.grid.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(old) = grid.positions.remove(&entity) {
+            Self::unlink(&mut grid, entity, self.cell(old));
+        }
+    }
+
+    fn unlink(grid: &mut Grid, entity: Entity, cell: Cell) {
+        if let Some(entities) = grid.cells.get_mut(&cell) {
+            entities.retain(|&e| e != entity);
+            if entities.is_empty() {
+                grid.cells.remove(&cell);
+            }
+        }
+    }
+
+    /// Visits the entities inside the box `[min, max]`, with their position.
+    fn visit_aabb(&self, min: [f32; 3], max: [f32; 3], mut f: impl FnMut(Entity, [f32; 3])) {
+        let inside = |p: &[f32; 3]| (0..3).all(|i| min[i] <= p[i] && p[i] <= max[i]);
+
+        let grid = self.grid.lock().unwrap_or_else(PoisonError::into_inner);
+        let (lo, hi) = (self.cell(min), self.cell(max));
+        let cells = (0..3)
+            .map(|i| (hi[i] as i64 - lo[i] as i64 + 1).max(0) as u64)
+            .fold(1u64, u64::saturating_mul);
+
+        // A huge box visits fewer entities than cells.
+        if cells > grid.positions.len() as u64 {
+            grid.positions
+                .iter()
+                .filter(|(_, p)| inside(p))
+                .for_each(|(&entity, &p)| f(entity, p));
+            return;
+        }
+
+        for x in lo[0]..=hi[0] {
+            for y in lo[1]..=hi[1] {
+                for z in lo[2]..=hi[2] {
+                    let Some(entities) = grid.cells.get(&[x, y, z]) else {
+                        continue;
+                    };
+                    for &entity in entities {
+                        let p = grid.positions[&entity];
+                        if inside(&p) {
+                            f(entity, p);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the entities inside the axis-aligned box `[min, max]`.
+    ///
+    /// The order of the entities is unspecified.
+    pub fn query_aabb(&self, min: [f32; 3], max: [f32; 3]) -> Vec<Entity> {
+        let mut result = Vec::new();
+        self.visit_aabb(min, max, |entity, _| result.push(entity));
+        result
+    }
+
+    /// Returns the entities within `radius` of `center`.
+    ///
+    /// The order of the entities is unspecified.
+    pub fn query_radius(&self, center: [f32; 3], radius: f32) -> Vec<Entity> {
+        let min = center.map(|x| x - radius);
+        let max = center.map(|x| x + radius);
+        let mut result = Vec::new();
+        self.visit_aabb(min, max, |entity, p| {
+            let dist2: f32 = (0..3)
+                .map(|i| (p[i] - center[i]) * (p[i] - center[i]))
+                .sum();
+            if dist2 <= radius * radius {
+                result.push(entity);
+            }
+        });
+        result
+    }
+
+    /// Returns the indexed position of `entity`.
+    pub fn get(&self, entity: Entity) -> Option<[f32; 3]> {
+        let grid = self.grid.lock().unwrap_or_else(PoisonError::into_inner);
+        grid.positions.get(&entity).copied()
+    }
+
+    /// Returns the number of entities in the grid.
+    pub fn len(&self) -> usize {
+        let grid = self.grid.lock().unwrap_or_else(PoisonError::into_inner);
+        grid.positions.len()
+    }
+
+    /// Returns `true` if the grid contains no entity.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<P: GridPosition> fmt::Debug for SpatialGrid<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpatialGrid")
+            .field("cell_size", &self.cell_size)
+            .field("len", &self.len())
+            .finish()
+    }
+}
@@ -5,6 +5,7 @@ use core::ptr::{self, NonNull};
 
 use vc_ptr::{OwningPtr, Ptr, PtrMut};
 
+use crate::storage::ColumnStats;
 use crate::utils::Dropper;
 
 // -----------------------------------------------------------------------------
@@ -38,11 +39,16 @@ impl Drop for AbortOnDropFail {
 /// types with and without drop logic.
 ///
 /// The internal elements can be either dense or sparse.
+///
+/// In debug mode, the number of items and the allocated bytes are counted,
+/// see [`ColumnStats`].
 #[derive(Debug)]
 pub(super) struct BlobArray {
     item_layout: Layout,
     data: NonNull<u8>,
     dropper: Option<Dropper>,
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    stats: ColumnStats,
 }
 
 impl BlobArray {
@@ -64,6 +70,18 @@ impl BlobArray {
         self.dropper
     }
 
+    /// Returns the memory statistics, always zero in release mode.
+    #[inline(always)]
+    pub fn stats(&self) -> ColumnStats {
+        crate::cfg::debug! {
+            if {
+                self.stats
+            } else {
+                ColumnStats::default()
+            }
+        }
+    }
+
     /// Creates a new uninitialized `BlobArray`.
     ///
     /// # Safety
@@ -77,6 +95,12 @@ impl BlobArray {
             item_layout,
             dropper,
             data: NonNull::without_provenance(align),
+            #[cfg(any(debug_assertions, feature = "debug"))]
+            stats: ColumnStats {
+                live: 0,
+                peak: 0,
+                bytes: 0,
+            },
         }
     }
 
@@ -91,6 +115,8 @@ impl BlobArray {
 
             self.data = NonNull::new(unsafe { malloc::alloc(new_layout) })
                 .unwrap_or_else(|| malloc::handle_alloc_error(new_layout));
+
+            crate::cfg::debug! { self.stats.bytes = new_layout.size(); }
         }
     }
 
@@ -112,6 +138,8 @@ impl BlobArray {
                 )
             })
             .unwrap_or_else(|| malloc::handle_alloc_error(new_layout));
+
+            crate::cfg::debug! { self.stats.bytes = new_layout.size(); }
         }
     }
 
@@ -130,6 +158,7 @@ impl BlobArray {
                     malloc::dealloc(self.data.as_ptr(), layout);
                 }
             }
+            crate::cfg::debug! { self.stats.bytes = 0; }
         }
     }
 
@@ -166,8 +195,9 @@ impl BlobArray {
         let size = self.item_layout.size();
         unsafe {
             let dst = self.data.as_ptr().byte_add(index * size);
-            ptr::copy_nonoverlapping::<u8>(value.as_ptr(), dst, size);
+            value.move_to(dst, size);
         }
+        crate::cfg::debug! { self.stats.add_live(1); }
     }
 
     /// Replaces an existing item at the specified index.
@@ -188,7 +218,7 @@ impl BlobArray {
 
                 ::core::mem::forget(drop_guard);
             }
-            ptr::copy_nonoverlapping::<u8>(value.as_ptr(), dst.as_ptr(), size);
+            value.move_to(dst.as_ptr(), size);
         }
     }
 
@@ -203,12 +233,13 @@ impl BlobArray {
     #[inline(always)]
     #[must_use = "The returned pointer should be used"]
     pub unsafe fn remove_item(&mut self, index: usize) -> OwningPtr<'_> {
+        crate::cfg::debug! { self.stats.sub_live(1); }
         unsafe { self.get_mut(index).promote() }
     }
 
     /// Forgets specified items but no effect on the allocated capacity.
     ///
-    /// Actually, do nothing
+    /// Actually, do nothing, the item was already moved out by `remove_item`.
     ///
     /// # Safety
     /// - `index` must be within bounds (0..capacity)
@@ -225,6 +256,7 @@ impl BlobArray {
     /// - The item at `index` must be properly initialized
     #[inline]
     pub unsafe fn drop_item(&mut self, index: usize) {
+        crate::cfg::debug! { self.stats.sub_live(1); }
         if let Some(dropper) = self.dropper {
             let drop_guard = AbortOnDropFail;
 
@@ -241,6 +273,7 @@ impl BlobArray {
     /// - All items from `0..len` must be properly initialized
     #[inline]
     pub unsafe fn drop_slice(&mut self, len: usize) {
+        crate::cfg::debug! { self.stats.sub_live(len); }
         if let Some(dropper) = self.dropper {
             let drop_guard = AbortOnDropFail;
             (0..len).for_each(|index| unsafe { dropper.call(self.get_mut(index).promote()) });
@@ -248,6 +281,30 @@ impl BlobArray {
         }
     }
 
+    /// Moves `len` items starting at `src` to `other`, starting at `dst`.
+    ///
+    /// The whole range is copied at once, without reading individual items.
+    ///
+    /// # Safety
+    /// - `self` and `other` must be different arrays with the same item layout
+    /// - `src..src + len` must be within bounds and properly initialized,
+    ///   these items are treated as uninitialized afterwards
+    /// - `dst..dst + len` must be within bounds of `other` and uninitialized
+    #[inline]
+    pub unsafe fn move_range_to(&mut self, other: &mut Self, src: usize, dst: usize, len: usize) {
+        debug_assert_eq!(self.item_layout, other.item_layout);
+        crate::cfg::debug! {
+            self.stats.sub_live(len);
+            other.stats.add_live(len);
+        }
+        let size = self.item_layout.size();
+        unsafe {
+            let src = self.data.as_ptr().byte_add(src * size);
+            let dst = other.data.as_ptr().byte_add(dst * size);
+            ptr::copy_nonoverlapping::<u8>(src, dst, len * size);
+        }
+    }
+
     /// Swaps the item at `index` with the last item and returns the moved item.
     ///
     /// # Safety
@@ -261,6 +318,7 @@ impl BlobArray {
         index: usize,
         last_index: usize,
     ) -> OwningPtr<'_> {
+        crate::cfg::debug! { self.stats.sub_live(1); }
         let size = self.item_layout.size();
         unsafe {
             let item = self.data.as_ptr().byte_add(size * index);
@@ -273,6 +331,8 @@ impl BlobArray {
 
     /// Swaps the item at `index` with the last item and forget the moved item.
     ///
+    /// The moved item was already moved out by `remove_item`.
+    ///
     /// # Safety
     /// - `index` must be < `last_index`
     /// - Both `index` and `last_index` must be within bounds
@@ -6,10 +6,12 @@ use tick_array::TickArray;
 
 use core::alloc::Layout;
 use core::num::NonZeroUsize;
+use core::ops::Range;
 
 use vc_ptr::{OwningPtr, Ptr, PtrMut, ThinSlice};
 
 use crate::borrow::{UntypedMut, UntypedRef, UntypedSliceMut, UntypedSliceRef};
+use crate::storage::ColumnStats;
 use crate::tick::{CheckTicks, Tick, TicksMut, TicksRef};
 use crate::tick::{TicksSliceMut, TicksSliceRef};
 use crate::utils::Dropper;
@@ -46,6 +48,14 @@ impl Column {
         self.data.dropper()
     }
 
+    /// Returns the memory statistics of the component data.
+    ///
+    /// Only tracked in debug mode, see [`ColumnStats`].
+    #[inline(always)]
+    pub fn stats(&self) -> ColumnStats {
+        self.data.stats()
+    }
+
     /// Creates a new empty column.
     ///
     /// # Safety
@@ -330,6 +340,35 @@ impl Column {
         }
     }
 
+    /// Moves the elements in `self[src]` to `other[dst_start..]`.
+    ///
+    /// Data and ticks of the whole range are copied at once, which is much
+    /// faster than moving the elements one by one through [`move_item_to`].
+    ///
+    /// This function does not drop the elements in `other`.
+    ///
+    /// # Safety
+    /// - `self != other`
+    /// - `src` must be within bounds (0..self.capacity)
+    /// - `dst_start + src.len()` must be within bounds (0..=other.capacity)
+    /// - The items in `src` must be properly initialized, they are
+    ///   treated as uninitialized afterwards
+    /// - The items in `other[dst_start..dst_start + src.len()]` must be uninitialized
+    ///
+    /// [`move_item_to`]: Self::move_item_to
+    #[inline]
+    pub unsafe fn move_range_to(&mut self, other: &mut Self, src: Range<usize>, dst_start: usize) {
+        let len = src.len();
+        unsafe {
+            self.data
+                .move_range_to(&mut other.data, src.start, dst_start, len);
+            self.added
+                .copy_range_to(&mut other.added, src.start, dst_start, len);
+            self.changed
+                .copy_range_to(&mut other.changed, src.start, dst_start, len);
+        }
+    }
+
     /// Check the ticks of all components and ensure they are valid.
     ///
     /// # Safety
@@ -132,6 +132,21 @@ impl TickArray {
         unsafe { ThinSliceMut::from_raw(self.data) }
     }
 
+    /// Copies `len` ticks starting at `src` to `other`, starting at `dst`.
+    ///
+    /// # Safety
+    /// - `self` and `other` must be different arrays
+    /// - `src..src + len` must be within bounds
+    /// - `dst..dst + len` must be within bounds of `other`
+    #[inline(always)]
+    pub const unsafe fn copy_range_to(&self, other: &mut Self, src: usize, dst: usize, len: usize) {
+        unsafe {
+            let src = self.data.as_ptr().add(src);
+            let dst = other.data.as_ptr().add(dst);
+            ptr::copy_nonoverlapping::<Tick>(src, dst, len);
+        }
+    }
+
     /// Copies the last item to the specified index without returning the moved item.
     ///
     /// This is equivalent to `swap_remove_not_last` but without reading the removed value.
@@ -74,28 +74,67 @@ impl Eq for TableId {}
 /// Row position within a table.
 ///
 /// Represents an index into a table's columnar storage.
+///
+/// Stored as a `NonMaxU32`, so `Option<TableRow>` has the same size.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
-pub struct TableRow(pub u32);
+pub struct TableRow(NonMaxU32);
+
+impl TableRow {
+    /// Creates a row from an index.
+    ///
+    /// # Panics
+    /// Panics if `index >= u32::MAX`.
+    #[inline(always)]
+    pub const fn from_index(index: usize) -> Self {
+        match Self::try_from_index(index) {
+            Some(row) => row,
+            None => panic!("row index out of range"),
+        }
+    }
+
+    /// Creates a row from an index, returns `None` if `index >= u32::MAX`.
+    #[inline(always)]
+    pub const fn try_from_index(index: usize) -> Option<Self> {
+        if index < u32::MAX as usize {
+            Some(Self(unsafe { NonMaxU32::new_unchecked(index as u32) }))
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
+    /// `index` must be less than `u32::MAX`.
+    #[inline(always)]
+    pub(crate) const unsafe fn from_index_unchecked(index: usize) -> Self {
+        Self(unsafe { NonMaxU32::new_unchecked(index as u32) })
+    }
+
+    /// Returns the row index as a usize.
+    #[inline(always)]
+    pub const fn index(self) -> usize {
+        self.0.get() as usize
+    }
+}
 
 impl Debug for TableRow {
     #[inline(always)]
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        Debug::fmt(&self.0, f)
+        Debug::fmt(&self.0.get(), f)
     }
 }
 
 impl Display for TableRow {
     #[inline(always)]
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        Display::fmt(&self.0, f)
+        Display::fmt(&self.0.get(), f)
     }
 }
 
 impl Hash for TableRow {
     #[inline(always)]
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-        state.write_u32(self.0);
+        state.write_u32(self.0.get());
     }
 }
 
@@ -16,7 +16,7 @@ use crate::borrow::UntypedSliceRef;
 use crate::component::ComponentId;
 use crate::entity::Entity;
 use crate::entity::MovedEntityRow;
-use crate::storage::{AbortOnPanic, Column, VecRemoveExt};
+use crate::storage::{AbortOnPanic, Column, ColumnStats, VecRemoveExt};
 use crate::tick::CheckTicks;
 use crate::tick::Tick;
 use crate::utils::Dropper;
@@ -125,16 +125,30 @@ unsafe impl Send for Table {}
 impl Table {
     /// Returns the current allocation capacity of the table.
     #[inline(always)]
-    fn capacity(&self) -> usize {
+    pub fn capacity(&self) -> usize {
         self.entities.capacity()
     }
 
     /// Returns the number of entities currently stored in the table.
     #[inline(always)]
-    fn entity_count(&self) -> usize {
+    pub fn entity_count(&self) -> usize {
         self.entities.len()
     }
 
+    /// Returns the sorted component IDs stored in this table.
+    #[inline(always)]
+    pub fn components(&self) -> &[ComponentId] {
+        &self.idents
+    }
+
+    /// Returns the memory statistics of each column, see [`ColumnStats`].
+    pub fn column_stats(&self) -> impl ExactSizeIterator<Item = (ComponentId, ColumnStats)> + '_ {
+        self.idents
+            .iter()
+            .zip(self.columns.iter())
+            .map(|(&id, column)| (id, column.stats()))
+    }
+
     #[inline(always)]
     pub fn entities(&self) -> &[Entity] {
         &self.entities
@@ -146,39 +160,46 @@ impl Table {
     /// - The entity must be unique within this table
     /// - The returned row is valid until the entity is removed
     pub unsafe fn allocate(&mut self, entity: Entity) -> TableRow {
-        #[cold]
-        #[inline(never)]
-        fn reserve_one(this: &mut Table) {
-            let abort_guard = AbortOnPanic;
-
-            let old_capacity = this.entities.capacity();
-            this.entities.reserve(1);
-            let new_capacity = this.entities.capacity();
-
-            unsafe {
-                let new_capacity = NonZeroUsize::new_unchecked(new_capacity);
-                if let Some(current) = NonZeroUsize::new(old_capacity) {
-                    this.columns.iter_mut().for_each(|col| {
-                        col.realloc(current, new_capacity);
-                    });
-                } else {
-                    this.columns
-                        .iter_mut()
-                        .for_each(|col| col.alloc(new_capacity));
-                }
-            }
-
-            ::core::mem::forget(abort_guard);
-        }
-
         let len = self.entities.len();
         if len == self.entities.capacity() {
-            reserve_one(self);
+            self.grow(1);
         }
 
         self.entities.push(entity);
         // `0 < EntityId < u32::MAX`, so `len < u32::MAX`
-        TableRow(len as u32)
+        unsafe { TableRow::from_index_unchecked(len) }
+    }
+
+    /// Reserves capacity for at least `additional` more entities.
+    pub fn reserve(&mut self, additional: usize) {
+        if self.entities.capacity() - self.entities.len() < additional {
+            self.grow(additional);
+        }
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn grow(&mut self, additional: usize) {
+        let abort_guard = AbortOnPanic;
+
+        let old_capacity = self.entities.capacity();
+        self.entities.reserve(additional);
+        let new_capacity = self.entities.capacity();
+
+        unsafe {
+            let new_capacity = NonZeroUsize::new_unchecked(new_capacity);
+            if let Some(current) = NonZeroUsize::new(old_capacity) {
+                self.columns.iter_mut().for_each(|col| {
+                    col.realloc(current, new_capacity);
+                });
+            } else {
+                self.columns
+                    .iter_mut()
+                    .for_each(|col| col.alloc(new_capacity));
+            }
+        }
+
+        ::core::mem::forget(abort_guard);
     }
 
     /// Finds the column index for a given component ID using binary search.
@@ -199,7 +220,7 @@ impl Table {
     /// returned by `allocate()` instead.
     pub fn get_table_row(&self, key: Entity) -> Option<TableRow> {
         let index = self.entities.iter().position(|it| *it == key)?;
-        Some(TableRow(index as u32))
+        Some(TableRow::from_index(index))
     }
 
     /// Returns a reference to a column by its index.
@@ -230,10 +251,10 @@ impl Table {
     /// - `table_col` must be a valid column index
     #[inline(always)]
     pub unsafe fn get_data(&self, table_row: TableRow, table_col: TableCol) -> Ptr<'_> {
-        debug_assert!((table_row.0 as usize) < self.entity_count());
+        debug_assert!((table_row.index()) < self.entity_count());
         unsafe {
             let col = self.get_column(table_col);
-            col.get_data(table_row.0 as usize)
+            col.get_data(table_row.index())
         }
     }
 
@@ -244,10 +265,10 @@ impl Table {
     /// - `table_col` must be a valid column index
     #[inline(always)]
     pub unsafe fn get_data_mut(&mut self, table_row: TableRow, table_col: TableCol) -> PtrMut<'_> {
-        debug_assert!((table_row.0 as usize) < self.entity_count());
+        debug_assert!((table_row.index()) < self.entity_count());
         unsafe {
             let col = self.get_column_mut(table_col);
-            col.get_data_mut(table_row.0 as usize)
+            col.get_data_mut(table_row.index())
         }
     }
 
@@ -258,10 +279,10 @@ impl Table {
     /// - `table_col` must be a valid column index
     #[inline(always)]
     pub unsafe fn get_added(&self, table_row: TableRow, table_col: TableCol) -> Tick {
-        debug_assert!((table_row.0 as usize) < self.entity_count());
+        debug_assert!((table_row.index()) < self.entity_count());
         unsafe {
             let col = self.get_column(table_col);
-            col.get_added(table_row.0 as usize)
+            col.get_added(table_row.index())
         }
     }
 
@@ -272,10 +293,10 @@ impl Table {
     /// - `table_col` must be a valid column index
     #[inline(always)]
     pub unsafe fn get_changed(&self, table_row: TableRow, table_col: TableCol) -> Tick {
-        debug_assert!((table_row.0 as usize) < self.entity_count());
+        debug_assert!((table_row.index()) < self.entity_count());
         unsafe {
             let col = self.get_column(table_col);
-            col.get_changed(table_row.0 as usize)
+            col.get_changed(table_row.index())
         }
     }
 
@@ -286,10 +307,10 @@ impl Table {
     /// - `table_col` must be a valid column index
     #[inline(always)]
     pub unsafe fn get_added_mut(&mut self, table_row: TableRow, table_col: TableCol) -> &mut Tick {
-        debug_assert!((table_row.0 as usize) < self.entity_count());
+        debug_assert!((table_row.index()) < self.entity_count());
         unsafe {
             let col = self.get_column_mut(table_col);
-            col.get_added_mut(table_row.0 as usize)
+            col.get_added_mut(table_row.index())
         }
     }
 
@@ -304,10 +325,10 @@ impl Table {
         table_row: TableRow,
         table_col: TableCol,
     ) -> &mut Tick {
-        debug_assert!((table_row.0 as usize) < self.entity_count());
+        debug_assert!((table_row.index()) < self.entity_count());
         unsafe {
             let col = self.get_column_mut(table_col);
-            col.get_changed_mut(table_row.0 as usize)
+            col.get_changed_mut(table_row.index())
         }
     }
 
@@ -352,10 +373,10 @@ impl Table {
         last_run: Tick,
         this_run: Tick,
     ) -> UntypedRef<'_> {
-        debug_assert!((table_row.0 as usize) < self.entity_count());
+        debug_assert!((table_row.index()) < self.entity_count());
         unsafe {
             let col = self.get_column(table_col);
-            col.get_ref(table_row.0 as usize, last_run, this_run)
+            col.get_ref(table_row.index(), last_run, this_run)
         }
     }
 
@@ -373,10 +394,10 @@ impl Table {
         last_run: Tick,
         this_run: Tick,
     ) -> UntypedMut<'_> {
-        debug_assert!((table_row.0 as usize) < self.entity_count());
+        debug_assert!((table_row.index()) < self.entity_count());
         unsafe {
             let col = self.get_column_mut(table_col);
-            col.get_mut(table_row.0 as usize, last_run, this_run)
+            col.get_mut(table_row.index(), last_run, this_run)
         }
     }
 
@@ -433,11 +454,11 @@ impl Table {
         data: OwningPtr<'_>,
         tick: Tick,
     ) {
-        debug_assert!((table_row.0 as usize) < self.entity_count());
+        debug_assert!((table_row.index()) < self.entity_count());
 
         unsafe {
             let column = self.get_column_mut(table_col);
-            column.init_item(table_row.0 as usize, data, tick);
+            column.init_item(table_row.index(), data, tick);
         }
     }
 
@@ -455,11 +476,11 @@ impl Table {
         data: OwningPtr<'_>,
         tick: Tick,
     ) {
-        debug_assert!((table_row.0 as usize) < self.entity_count());
+        debug_assert!((table_row.index()) < self.entity_count());
 
         unsafe {
             let column = self.get_column_mut(table_col);
-            column.replace_item(table_row.0 as usize, data, tick);
+            column.replace_item(table_row.index(), data, tick);
         }
     }
 
@@ -476,11 +497,11 @@ impl Table {
         table_col: TableCol,
         table_row: TableRow,
     ) -> OwningPtr<'_> {
-        debug_assert!((table_row.0 as usize) < self.entity_count());
+        debug_assert!((table_row.index()) < self.entity_count());
 
         unsafe {
             let column = self.get_column_mut(table_col);
-            column.remove_item(table_row.0 as usize)
+            column.remove_item(table_row.index())
         }
     }
 
@@ -491,6 +512,17 @@ impl Table {
             c.check_ticks(len, check);
         });
     }
+
+    /// Drops all entities and their components, keeping the allocated capacity.
+    ///
+    /// Entity locations are not updated, the caller must despawn them.
+    pub(crate) fn clear(&mut self) {
+        let len = self.entity_count();
+        self.columns.iter_mut().for_each(|c| unsafe {
+            c.drop_slice(len);
+        });
+        self.entities.clear();
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -503,7 +535,7 @@ impl Table {
     /// - `table_row` must be a valid, initialized row
     /// - After this operation, the row is no longer valid
     pub unsafe fn swap_remove_and_drop(&mut self, table_row: TableRow) -> MovedEntityRow {
-        let removal = table_row.0 as usize;
+        let removal = table_row.index();
         let last = self.entity_count() - 1;
         debug_assert!(removal <= last);
 
@@ -532,7 +564,7 @@ impl Table {
     /// - Caller must ensure components are properly handled elsewhere
     /// - After this operation, the row is no longer valid
     pub unsafe fn swap_remove_and_forget(&mut self, table_row: TableRow) -> MovedEntityRow {
-        let removal = table_row.0 as usize;
+        let removal = table_row.index();
         let last = self.entity_count() - 1;
         debug_assert!(removal <= last);
 
@@ -564,7 +596,7 @@ impl Table {
         table_row: TableRow,
         other: &mut Table,
     ) -> (MovedEntityRow, TableRow) {
-        let src = table_row.0 as usize;
+        let src = table_row.index();
         let last = self.entity_count() - 1;
         debug_assert!(src <= last);
 
@@ -573,7 +605,7 @@ impl Table {
                 let moved = *self.entities.get_unchecked(src);
                 let swapped = self.entities.move_last_to(last, src);
                 let new_row = other.allocate(moved);
-                let dst = new_row.0 as usize;
+                let dst = new_row.index();
 
                 self.idents
                     .iter()
@@ -593,7 +625,7 @@ impl Table {
                 vc_utils::cold_path();
                 let moved = self.entities.remove_last(last);
                 let new_row = other.allocate(moved);
-                let dst = new_row.0 as usize;
+                let dst = new_row.index();
 
                 self.idents
                     .iter()
@@ -624,7 +656,7 @@ impl Table {
         table_row: TableRow,
         other: &mut Table,
     ) -> (MovedEntityRow, TableRow) {
-        let src = table_row.0 as usize;
+        let src = table_row.index();
         let last = self.entity_count() - 1;
         debug_assert!(src <= last);
 
@@ -633,7 +665,7 @@ impl Table {
                 let moved = *self.entities.get_unchecked(src);
                 let swapped = self.entities.move_last_to(last, src);
                 let new_row = other.allocate(moved);
-                let dst = new_row.0 as usize;
+                let dst = new_row.index();
 
                 self.idents
                     .iter()
@@ -651,7 +683,7 @@ impl Table {
                 vc_utils::cold_path();
                 let moved = self.entities.remove_last(last);
                 let new_row = other.allocate(moved);
-                let dst = new_row.0 as usize;
+                let dst = new_row.index();
 
                 self.idents
                     .iter()
@@ -668,4 +700,124 @@ impl Table {
             }
         }
     }
+
+    /// Moves all entities to the end of `other`, leaving this table empty.
+    ///
+    /// Components present in both tables are moved as whole column ranges
+    /// instead of row by row. Components not present in `other` are dropped.
+    ///
+    /// Returns the row of the first moved entity in `other`,
+    /// the others follow in their original order.
+    ///
+    /// # Safety
+    /// - `self` and `other` must be different tables
+    /// - Components of `other` not present in this table are uninitialized
+    ///   for the moved rows, the caller must initialize them
+    /// - The caller must update the locations of the moved entities
+    pub unsafe fn move_all_matching_columns(&mut self, other: &mut Table) -> TableRow {
+        let len = self.entity_count();
+        other.reserve(len);
+
+        let dst = other.entity_count();
+        other.entities.extend_from_slice(&self.entities);
+        self.entities.clear();
+
+        // A panicking destructor would leave `other` with uninitialized rows.
+        let abort_guard = AbortOnPanic;
+        self.idents
+            .iter()
+            .zip(self.columns.iter_mut())
+            .for_each(|(&id, col)| unsafe {
+                if let Some(table_col) = other.get_table_col(id) {
+                    let other_col = other.get_column_mut(table_col);
+                    col.move_range_to(other_col, 0..len, dst);
+                } else {
+                    col.drop_slice(len);
+                }
+            });
+        ::core::mem::forget(abort_guard);
+
+        // `0 < EntityId < u32::MAX`, so `dst < u32::MAX`
+        unsafe { TableRow::from_index_unchecked(dst) }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use core::alloc::Layout;
+
+    use vc_os::sync::Arc;
+
+    use super::{Table, TableBuilder};
+    use crate::component::ComponentId;
+    use crate::entity::Entity;
+    use crate::storage::{TableCol, TableRow};
+    use crate::tick::Tick;
+    use crate::utils::Dropper;
+
+    fn table<const N: usize>(columns: [(u32, Layout, Option<Dropper>); N]) -> Table {
+        let mut builder = TableBuilder::new(N);
+        for (id, layout, dropper) in columns {
+            unsafe { builder.insert(ComponentId::new(id), layout, dropper) };
+        }
+        builder.build()
+    }
+
+    unsafe fn init<T>(table: &mut Table, col: TableCol, row: TableRow, value: T) {
+        vc_ptr::into_owning!(value);
+        unsafe { table.init_item(col, row, value, Tick::new(row.index() as u32)) };
+    }
+
+    #[test]
+    fn move_all_matching_columns() {
+        let counter = Arc::new(());
+
+        // columns: 1 => u32, 2 => Arc<()>
+        let mut src = table([
+            (1, Layout::new::<u32>(), None),
+            (2, Layout::new::<Arc<()>>(), Dropper::of::<Arc<()>>()),
+        ]);
+        // columns: 1 => u32, 3 => u64
+        let mut dst = table([
+            (1, Layout::new::<u32>(), None),
+            (3, Layout::new::<u64>(), None),
+        ]);
+
+        let (col_1, col_2, col_3) = (TableCol(0), TableCol(1), TableCol(1));
+
+        unsafe {
+            let row = dst.allocate(Entity::from_bits(100));
+            init(&mut dst, col_1, row, 100_u32);
+            init(&mut dst, col_3, row, 0_u64);
+        }
+        for i in 1..=3 {
+            unsafe {
+                let row = src.allocate(Entity::from_bits(i));
+                init(&mut src, col_1, row, i as u32);
+                init(&mut src, col_2, row, counter.clone());
+            }
+        }
+        assert_eq!(Arc::strong_count(&counter), 4);
+
+        let first = unsafe { src.move_all_matching_columns(&mut dst) };
+        assert_eq!(first, TableRow::from_index(1));
+        assert_eq!(src.entity_count(), 0);
+        assert_eq!(Arc::strong_count(&counter), 1);
+
+        let entities: Vec<u64> = dst.entities().iter().map(|e| e.to_bits()).collect();
+        assert_eq!(entities, [100, 1, 2, 3]);
+        for index in 1..4 {
+            let row = TableRow::from_index(index);
+            unsafe {
+                // Ticks are moved along with the data.
+                assert_eq!(*dst.get_data(row, col_1).as_ref::<u32>(), index as u32);
+                assert_eq!(dst.get_added(row, col_1), Tick::new(index as u32 - 1));
+                init(&mut dst, col_3, row, 0_u64);
+            }
+        }
+    }
 }
@@ -43,6 +43,19 @@ impl Tables {
         Tables { tables, mapper }
     }
 
+    /// Returns the number of tables, including the empty table.
+    #[inline(always)]
+    #[expect(clippy::len_without_is_empty, reason = "the empty table always exists")]
+    pub fn len(&self) -> usize {
+        self.tables.len()
+    }
+
+    /// Returns an iterator over all tables.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &Table> {
+        self.tables.iter()
+    }
+
     /// Returns a reference to the table with the given ID, if it exists.
     #[inline(always)]
     pub fn get(&self, id: TableId) -> Option<&Table> {
@@ -8,7 +8,7 @@ use vc_ptr::{OwningPtr, Ptr, PtrMut};
 
 use crate::borrow::{UntypedMut, UntypedRef};
 use crate::resource::{Resource, ResourceInfo};
-use crate::tick::{Tick, TicksMut, TicksRef};
+use crate::tick::{ChangeTicks, Tick};
 use crate::utils::{DebugName, Dropper};
 
 // -----------------------------------------------------------------------------
@@ -43,8 +43,7 @@ pub struct ResData {
     layout: Layout,
     dropper: Option<Dropper>,
     data: *mut u8,
-    added: Tick,
-    changed: Tick,
+    ticks: ChangeTicks,
 }
 
 impl Debug for ResData {
@@ -80,8 +79,7 @@ impl ResData {
             layout,
             dropper,
             data: ptr::null_mut(),
-            added: Tick::new(0),
-            changed: Tick::new(0),
+            ticks: ChangeTicks::new(Tick::new(0)),
         }
     }
 
@@ -113,7 +111,7 @@ impl ResData {
     #[inline]
     pub fn get_added(&self) -> Option<Tick> {
         if self.is_active() {
-            Some(self.added)
+            Some(self.ticks.added)
         } else {
             None
         }
@@ -123,7 +121,7 @@ impl ResData {
     #[inline]
     pub fn get_changed(&self) -> Option<Tick> {
         if self.is_active() {
-            Some(self.changed)
+            Some(self.ticks.changed)
         } else {
             None
         }
@@ -198,6 +196,22 @@ impl ResData {
         unsafe { self.insert_untyped(value, tick) };
     }
 
+    /// Returns the added and changed ticks if the resource is initialized.
+    #[inline]
+    pub fn get_ticks(&self) -> Option<ChangeTicks> {
+        if self.is_active() {
+            Some(self.ticks)
+        } else {
+            None
+        }
+    }
+
+    /// Overwrites the added and changed ticks.
+    #[inline]
+    pub(crate) fn set_ticks(&mut self, ticks: ChangeTicks) {
+        self.ticks = ticks;
+    }
+
     /// Inserts a new resource value.
     ///
     /// # Safety
@@ -223,11 +237,11 @@ impl ResData {
                     .unwrap_or_else(|| malloc::handle_alloc_error(layout))
                     .as_ptr();
             };
-            self.added = tick;
+            self.ticks.added = tick;
         }
         unsafe {
-            self.changed = tick;
-            ptr::copy_nonoverlapping::<u8>(value.as_ptr(), self.data, self.layout.size());
+            self.ticks.set_changed(tick);
+            value.move_to(self.data, self.layout.size());
         }
     }
 
@@ -237,12 +251,7 @@ impl ResData {
         let data = NonNull::new(self.data)?;
         Some(UntypedRef {
             value: unsafe { Ptr::new(data) },
-            ticks: TicksRef {
-                added: &self.added,
-                changed: &self.changed,
-                last_run,
-                this_run,
-            },
+            ticks: self.ticks.ticks_ref(last_run, this_run),
         })
     }
 
@@ -252,19 +261,14 @@ impl ResData {
         let data = NonNull::new(self.data)?;
         Some(UntypedMut {
             value: unsafe { PtrMut::new(data) },
-            ticks: TicksMut {
-                added: &mut self.added,
-                changed: &mut self.changed,
-                last_run,
-                this_run,
-            },
+            ticks: self.ticks.ticks_mut(last_run, this_run),
         })
     }
 
     /// Updates ticks with quick-check logic.
     #[inline(always)]
     pub(super) fn quick_check(&mut self, now: Tick, fall_back: Tick) {
-        self.added.quick_check(now, fall_back);
-        self.changed.quick_check(now, fall_back);
+        self.ticks.added.quick_check(now, fall_back);
+        self.ticks.changed.quick_check(now, fall_back);
     }
 }
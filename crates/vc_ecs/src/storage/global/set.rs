@@ -89,6 +89,16 @@ impl ResSet {
         }
     }
 
+    /// Drops all resources, keeping them registered.
+    ///
+    /// # Safety
+    /// - NonSend resources must be dropped on the correct thread.
+    pub(crate) unsafe fn clear(&mut self) {
+        self.data.iter_mut().flatten().for_each(|data| unsafe {
+            data.clear();
+        });
+    }
+
     /// Updates all resource ticks to prevent overflow.
     pub(crate) fn check_ticks(&mut self, check: CheckTicks) {
         let now = check.tick();
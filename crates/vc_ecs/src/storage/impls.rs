@@ -2,7 +2,7 @@ use vc_task::ComputeTaskPool;
 
 use crate::component::{ComponentInfo, ComponentStorage};
 use crate::resource::ResourceInfo;
-use crate::storage::{Maps, Tables};
+use crate::storage::{Maps, StorageStats, Tables};
 use crate::tick::CheckTicks;
 
 use super::ResSet;
@@ -34,6 +34,39 @@ pub struct Storages {
     pub res: ResSet,
     pub tables: Tables,
     pub maps: Maps,
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    leak_assertions: bool,
+}
+
+crate::cfg::debug! {
+    impl Drop for Storages {
+        fn drop(&mut self) {
+            if !self.leak_assertions {
+                return;
+            }
+            crate::cfg::std! {
+                if ::std::thread::panicking() {
+                    return;
+                }
+            }
+            // The tables and maps drop exactly the items of their entities.
+            let columns = self
+                .tables
+                .iter()
+                .flat_map(|table| {
+                    let len = table.entity_count();
+                    table.column_stats().map(move |(id, stats)| (id, stats, len))
+                })
+                .chain(self.maps.iter().map(|(id, map)| (id, map.stats(), map.len())));
+            for (id, stats, len) in columns {
+                assert!(
+                    stats.live == len,
+                    "the column of {id:?} is dropped with {} live items for {len} entities",
+                    stats.live,
+                );
+            }
+        }
+    }
 }
 
 impl Storages {
@@ -43,6 +76,26 @@ impl Storages {
             res: ResSet::new(),
             tables: Tables::new(),
             maps: Maps::new(),
+            #[cfg(any(debug_assertions, feature = "debug"))]
+            leak_assertions: false,
+        }
+    }
+
+    /// Enables or disables the assertion that no component leaked when the
+    /// storages are dropped, disabled by default.
+    ///
+    /// A leak means that items were forgotten without being moved out, so a
+    /// column holds more items than the entities of its table or map. Like
+    /// [`ColumnStats`], this only has an effect in debug mode.
+    ///
+    /// [`ColumnStats`]: crate::storage::ColumnStats
+    #[cfg_attr(
+        not(any(debug_assertions, feature = "debug")),
+        expect(unused_variables, reason = "unused in release mode")
+    )]
+    pub fn set_leak_assertions(&mut self, enabled: bool) {
+        crate::cfg::debug! {
+            self.leak_assertions = enabled;
         }
     }
 
@@ -100,6 +153,26 @@ impl Storages {
         }
     }
 
+    /// Collects the memory statistics of all component columns.
+    ///
+    /// The counters are only maintained in debug mode, see [`ColumnStats`].
+    /// Together with [`Storages::set_leak_assertions`], this helps tracking
+    /// down components that are never dropped.
+    ///
+    /// [`ColumnStats`]: crate::storage::ColumnStats
+    pub fn stats(&self) -> StorageStats {
+        let mut stats = StorageStats::default();
+        self.tables.iter().for_each(|table| {
+            table
+                .column_stats()
+                .for_each(|(id, column)| stats.add(id, column));
+        });
+        self.maps.iter().for_each(|(id, map)| {
+            stats.add(id, map.stats());
+        });
+        stats
+    }
+
     /// Updates tick information across all storage backends.
     ///
     /// This method advances the tick counters for all stored data, marking which
@@ -115,7 +188,9 @@ impl Storages {
     /// This provides near-optimal parallel utilization for large worlds with
     /// many tables and maps.
     pub fn check_ticks(&mut self, check: CheckTicks) {
-        let Storages { res, tables, maps } = self;
+        let Storages {
+            res, tables, maps, ..
+        } = self;
 
         if let Some(task_pool) = ComputeTaskPool::try_get() {
             task_pool.scope(|scope| {
@@ -8,6 +8,7 @@ mod dense;
 mod global;
 mod impls;
 mod sparse;
+mod stats;
 mod utils;
 
 // -----------------------------------------------------------------------------
@@ -23,5 +24,6 @@ pub use dense::{Table, Tables};
 pub use dense::{TableCol, TableId, TableRow};
 pub use global::{ResData, ResSet};
 pub use impls::Storages;
-pub use sparse::{Map, Maps};
+pub use sparse::{Map, MapIter, Maps};
 pub use sparse::{MapId, MapRow};
+pub use stats::{ColumnStats, StorageStats};
@@ -1,15 +1,18 @@
 use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
 use core::alloc::Layout;
 use core::cmp::Reverse;
 use core::fmt::Debug;
+use core::iter::FusedIterator;
 use core::num::NonZeroUsize;
 
 use vc_ptr::{OwningPtr, Ptr, PtrMut};
 use vc_utils::hash::SparseHashMap;
+use vc_utils::hash::hashbrown::hash_map;
 
 use crate::borrow::{UntypedMut, UntypedRef};
 use crate::entity::Entity;
-use crate::storage::{AbortOnPanic, Column, MapRow};
+use crate::storage::{AbortOnPanic, Column, ColumnStats, MapRow};
 use crate::tick::{CheckTicks, Tick};
 use crate::utils::Dropper;
 
@@ -44,7 +47,7 @@ impl Debug for Map {
 impl Drop for Map {
     fn drop(&mut self) {
         self.mapper.values().for_each(|v| unsafe {
-            self.column.drop_item(v.0 as usize);
+            self.column.drop_item(v.index());
         });
         unsafe {
             self.column.dealloc(self.capacity);
@@ -63,6 +66,30 @@ impl Map {
         }
     }
 
+    /// Returns the number of entities currently stored in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.mapper.len()
+    }
+
+    /// Returns `true` if the map contains no entity.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.mapper.is_empty()
+    }
+
+    /// Returns the number of rows the underlying column has allocated.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the memory statistics of the column, see [`ColumnStats`].
+    #[inline]
+    pub fn stats(&self) -> ColumnStats {
+        self.column.stats()
+    }
+
     /// Allocates a new storage row for the given entity.
     ///
     /// This function either reuses a free row or reserves new memory when needed.
@@ -81,7 +108,7 @@ impl Map {
             let new_cap = (this.capacity << 1).max(4);
             debug_assert!(new_cap <= u32::MAX as usize);
 
-            let row = MapRow(this.capacity as u32);
+            let row = unsafe { MapRow::from_index_unchecked(this.capacity) };
             unsafe {
                 let new_capacity = NonZeroUsize::new_unchecked(new_cap);
                 if this.capacity == 0 {
@@ -92,8 +119,10 @@ impl Map {
                 }
             }
 
-            let range = (this.capacity as u32 + 1)..(new_cap as u32);
-            this.free.extend(range.map(MapRow).map(Reverse));
+            // `new_cap <= u32::MAX`, so every row is less than `u32::MAX`.
+            let range = (this.capacity + 1)..new_cap;
+            this.free
+                .extend(range.map(|row| Reverse(unsafe { MapRow::from_index_unchecked(row) })));
 
             this.capacity = new_cap;
 
@@ -127,6 +156,17 @@ impl Map {
         Some(map_row)
     }
 
+    /// Returns an iterator over the entities and their component data.
+    ///
+    /// Iteration order is undefined.
+    #[inline]
+    pub fn iter(&self) -> MapIter<'_> {
+        MapIter {
+            column: &self.column,
+            rows: self.mapper.iter(),
+        }
+    }
+
     /// Gets the storage row for the given entity, if it exists.
     #[inline]
     pub fn get_map_row(&self, entity: Entity) -> Option<MapRow> {
@@ -140,8 +180,8 @@ impl Map {
     /// - The caller must ensure proper synchronization when accessing the data
     #[inline(always)]
     pub unsafe fn get_data(&self, map_row: MapRow) -> Ptr<'_> {
-        debug_assert!((map_row.0 as usize) < self.capacity);
-        unsafe { self.column.get_data(map_row.0 as usize) }
+        debug_assert!((map_row.index()) < self.capacity);
+        unsafe { self.column.get_data(map_row.index()) }
     }
 
     /// Gets a raw pointer to the component data at the specified row.
@@ -151,8 +191,8 @@ impl Map {
     /// - The caller must ensure proper synchronization when accessing the data
     #[inline(always)]
     pub unsafe fn get_data_mut(&mut self, map_row: MapRow) -> PtrMut<'_> {
-        debug_assert!((map_row.0 as usize) < self.capacity);
-        unsafe { self.column.get_data_mut(map_row.0 as usize) }
+        debug_assert!((map_row.index()) < self.capacity);
+        unsafe { self.column.get_data_mut(map_row.index()) }
     }
 
     /// Gets the tick when the component was added at the specified row.
@@ -161,8 +201,8 @@ impl Map {
     /// - `map_row` must be valid (obtained from `allocate` or `get_map_row`)
     #[inline(always)]
     pub unsafe fn get_added(&self, map_row: MapRow) -> Tick {
-        debug_assert!((map_row.0 as usize) < self.capacity);
-        unsafe { self.column.get_added(map_row.0 as usize) }
+        debug_assert!((map_row.index()) < self.capacity);
+        unsafe { self.column.get_added(map_row.index()) }
     }
 
     /// Gets the tick when the component was last changed at the specified row.
@@ -171,8 +211,8 @@ impl Map {
     /// - `map_row` must be valid (obtained from `allocate` or `get_map_row`)
     #[inline(always)]
     pub unsafe fn get_changed(&self, map_row: MapRow) -> Tick {
-        debug_assert!((map_row.0 as usize) < self.capacity);
-        unsafe { self.column.get_changed(map_row.0 as usize) }
+        debug_assert!((map_row.index()) < self.capacity);
+        unsafe { self.column.get_changed(map_row.index()) }
     }
 
     /// Gets the tick when the component was added at the specified row.
@@ -181,8 +221,8 @@ impl Map {
     /// - `map_row` must be valid (obtained from `allocate` or `get_map_row`)
     #[inline(always)]
     pub unsafe fn get_added_mut(&mut self, map_row: MapRow) -> &mut Tick {
-        debug_assert!((map_row.0 as usize) < self.capacity);
-        unsafe { self.column.get_added_mut(map_row.0 as usize) }
+        debug_assert!((map_row.index()) < self.capacity);
+        unsafe { self.column.get_added_mut(map_row.index()) }
     }
 
     /// Gets the tick when the component was last changed at the specified row.
@@ -191,8 +231,8 @@ impl Map {
     /// - `map_row` must be valid (obtained from `allocate` or `get_map_row`)
     #[inline(always)]
     pub unsafe fn get_changed_mut(&mut self, map_row: MapRow) -> &mut Tick {
-        debug_assert!((map_row.0 as usize) < self.capacity);
-        unsafe { self.column.get_added_mut(map_row.0 as usize) }
+        debug_assert!((map_row.index()) < self.capacity);
+        unsafe { self.column.get_added_mut(map_row.index()) }
     }
 
     /// Gets an immutable reference to the component at the specified row.
@@ -208,8 +248,8 @@ impl Map {
         last_run: Tick,
         this_run: Tick,
     ) -> UntypedRef<'_> {
-        debug_assert!((map_row.0 as usize) < self.capacity);
-        unsafe { self.column.get_ref(map_row.0 as usize, last_run, this_run) }
+        debug_assert!((map_row.index()) < self.capacity);
+        unsafe { self.column.get_ref(map_row.index(), last_run, this_run) }
     }
 
     /// Gets a mutable reference to the component at the specified row.
@@ -225,8 +265,8 @@ impl Map {
         last_run: Tick,
         this_run: Tick,
     ) -> UntypedMut<'_> {
-        debug_assert!((map_row.0 as usize) < self.capacity);
-        unsafe { self.column.get_mut(map_row.0 as usize, last_run, this_run) }
+        debug_assert!((map_row.index()) < self.capacity);
+        unsafe { self.column.get_mut(map_row.index(), last_run, this_run) }
     }
 
     /// Initializes a new component at the specified row.
@@ -236,9 +276,9 @@ impl Map {
     /// - The layout of `data` must match the column's layout
     #[inline]
     pub unsafe fn init_item(&mut self, map_row: MapRow, data: OwningPtr<'_>, tick: Tick) {
-        debug_assert!((map_row.0 as usize) < self.capacity);
+        debug_assert!((map_row.index()) < self.capacity);
         unsafe {
-            self.column.init_item(map_row.0 as usize, data, tick);
+            self.column.init_item(map_row.index(), data, tick);
         }
     }
 
@@ -249,9 +289,9 @@ impl Map {
     /// - The layout of `data` must match the column's layout
     #[inline]
     pub unsafe fn replace_item(&mut self, map_row: MapRow, data: OwningPtr<'_>, tick: Tick) {
-        debug_assert!((map_row.0 as usize) < self.capacity);
+        debug_assert!((map_row.index()) < self.capacity);
         unsafe {
-            self.column.replace_item(map_row.0 as usize, data, tick);
+            self.column.replace_item(map_row.index(), data, tick);
         }
     }
 
@@ -264,8 +304,8 @@ impl Map {
     #[inline]
     #[must_use = "The returned pointer should be used."]
     pub unsafe fn remove_item(&mut self, map_row: MapRow) -> OwningPtr<'_> {
-        debug_assert!((map_row.0 as usize) < self.capacity);
-        unsafe { self.column.remove_item(map_row.0 as usize) }
+        debug_assert!((map_row.index()) < self.capacity);
+        unsafe { self.column.remove_item(map_row.index()) }
     }
 
     /// Drops the component data at the specified row without returning it.
@@ -275,8 +315,8 @@ impl Map {
     /// - Call [`Map::deallocate`] before this function if the entity is removed.
     #[inline]
     pub unsafe fn drop_item(&mut self, map_row: MapRow) {
-        debug_assert!((map_row.0 as usize) < self.capacity);
-        unsafe { self.column.drop_item(map_row.0 as usize) }
+        debug_assert!((map_row.index()) < self.capacity);
+        unsafe { self.column.drop_item(map_row.index()) }
     }
 
     /// Updates tick information for all components in this map.
@@ -285,8 +325,155 @@ impl Map {
     pub(crate) fn check_ticks(&mut self, check: CheckTicks) {
         if let Some(&row) = self.mapper.values().max() {
             unsafe {
-                self.column.check_ticks(row.0 as usize, check);
+                self.column.check_ticks(row.index() + 1, check);
+            }
+        }
+    }
+
+    /// Restores the density of the map after many removals.
+    ///
+    /// If at most a quarter of the rows are in use, the components are moved
+    /// to the lowest rows and the column is shrunk. Otherwise, this does nothing.
+    ///
+    /// Returns `true` if the map was repacked.
+    pub(crate) fn maintain(&mut self) -> bool {
+        /// Small maps are not worth repacking.
+        const MIN_CAPACITY: usize = 64;
+
+        let len = self.mapper.len();
+        if self.capacity < MIN_CAPACITY || len * 4 > self.capacity {
+            return false;
+        }
+
+        // The free rows below `len` are exactly as many as the used rows above it.
+        let mut free: Vec<usize> = self
+            .free
+            .iter()
+            .map(|row| row.0.index())
+            .filter(|&row| row < len)
+            .collect();
+
+        self.mapper.values_mut().for_each(|row| {
+            let src = row.index();
+            if src >= len {
+                let dst = free.pop().unwrap();
+                // `dst` is uninitialized, so nothing is forgotten.
+                unsafe {
+                    self.column.swap_forget_not_last(dst, src);
+                    *row = MapRow::from_index_unchecked(dst);
+                }
             }
+        });
+        debug_assert!(free.is_empty());
+
+        // `capacity` is a power of two, so the column is at least halved.
+        let new_cap = (len * 2).next_power_of_two().max(4);
+        debug_assert!(new_cap < self.capacity);
+        unsafe {
+            let current = NonZeroUsize::new_unchecked(self.capacity);
+            let new_capacity = NonZeroUsize::new_unchecked(new_cap);
+            self.column.realloc(current, new_capacity);
+        }
+        self.capacity = new_cap;
+
+        self.free.clear();
+        let rows = len..new_cap;
+        self.free
+            .extend(rows.map(|row| Reverse(unsafe { MapRow::from_index_unchecked(row) })));
+        true
+    }
+
+    /// Drops all components, keeping the allocated capacity.
+    ///
+    /// Every row is returned to the free pool.
+    pub(crate) fn clear(&mut self) {
+        self.mapper.values().for_each(|v| unsafe {
+            self.column.drop_item(v.index());
+        });
+        self.mapper.clear();
+        self.free.clear();
+        let rows = 0..self.capacity;
+        self.free
+            .extend(rows.map(|row| Reverse(unsafe { MapRow::from_index_unchecked(row) })));
+    }
+}
+
+// -----------------------------------------------------------------------------
+// MapIter
+
+/// An iterator over the entities of a [`Map`] and their component data.
+///
+/// Created by [`Map::iter`].
+#[derive(Clone)]
+pub struct MapIter<'a> {
+    column: &'a Column,
+    rows: hash_map::Iter<'a, Entity, MapRow>,
+}
+
+impl<'a> Iterator for MapIter<'a> {
+    type Item = (Entity, Ptr<'a>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (&entity, row) = self.rows.next()?;
+        // Every row in the mapper is initialized.
+        Some((entity, unsafe { self.column.get_data(row.index()) }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl ExactSizeIterator for MapIter<'_> {}
+
+impl FusedIterator for MapIter<'_> {}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::component::Component;
+    use crate::world::{World, WorldIdAllocator};
+
+    #[derive(Component)]
+    #[component(storage = "sparse")]
+    struct Foo(u32);
+
+    #[test]
+    fn maintain() {
+        let allocator = WorldIdAllocator::new();
+        let mut world = World::new(allocator.alloc());
+
+        let entities: Vec<_> = (0..100).map(|i| world.spawn(Foo(i)).entity()).collect();
+        for &entity in &entities[..95] {
+            world.despawn(entity).unwrap();
+        }
+
+        let map = &world.storages.maps.maps[0];
+        assert_eq!(map.capacity(), 128);
+        world.flush();
+
+        let map = &world.storages.maps.maps[0];
+        assert_eq!(map.capacity(), 16);
+        let mut values: Vec<u32> = map
+            .iter()
+            .map(|(_, ptr)| unsafe { ptr.as_ref::<Foo>().0 })
+            .collect();
+        values.sort_unstable();
+        assert_eq!(values, [95, 96, 97, 98, 99]);
+
+        for (i, &entity) in entities[95..].iter().enumerate() {
+            assert_eq!(
+                world.entity_ref(entity).get::<Foo>().unwrap().0,
+                95 + i as u32
+            );
         }
+        world.spawn(Foo(100));
+        assert_eq!(world.storages.maps.maps[0].len(), 6);
     }
 }
@@ -49,6 +49,28 @@ impl Maps {
         }
     }
 
+    /// Returns the number of sparse maps.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.maps.len()
+    }
+
+    /// Returns `true` if no sparse map has been prepared.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.maps.is_empty()
+    }
+
+    /// Returns an iterator over all maps with the component they store.
+    ///
+    /// Iteration order is undefined.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (ComponentId, &Map)> {
+        self.mapper
+            .iter()
+            .map(|(&component, id)| (component, &self.maps[id.index()]))
+    }
+
     /// Gets a reference to the map with the given ID.
     #[inline(always)]
     pub fn get(&self, id: MapId) -> Option<&Map> {
@@ -103,6 +125,15 @@ impl Maps {
         }
     }
 
+    /// Restores the density of the maps after many removals.
+    ///
+    /// See [`World::flush`](crate::world::World::flush).
+    pub(crate) fn maintain(&mut self) {
+        self.maps.iter_mut().for_each(|map| {
+            map.maintain();
+        });
+    }
+
     /// Registers multiple components for sparse storage.
     ///
     /// This is typically called during archetype registrationn.
@@ -9,5 +9,5 @@ mod maps;
 // Exports
 
 pub use ident::{MapId, MapRow};
-pub use map::Map;
+pub use map::{Map, MapIter};
 pub use maps::Maps;
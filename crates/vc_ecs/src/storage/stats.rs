@@ -0,0 +1,162 @@
+use alloc::vec::Vec;
+use core::ops::AddAssign;
+
+use crate::component::ComponentId;
+
+// -----------------------------------------------------------------------------
+// ColumnStats
+
+/// Memory statistics of component data.
+///
+/// The counters are only maintained in debug mode (`debug_assertions` or
+/// the `debug` feature), otherwise they are always zero.
+///
+/// For a single component, the statistics of all its columns are summed up,
+/// so `peak` is an upper bound of the real high-water mark.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnStats {
+    /// The number of initialized items.
+    pub live: usize,
+    /// The maximum of `live` over the lifetime of the columns.
+    pub peak: usize,
+    /// The bytes allocated for the items, excluding change detection ticks.
+    pub bytes: usize,
+}
+
+#[cfg_attr(
+    not(any(debug_assertions, feature = "debug")),
+    expect(dead_code, reason = "only tracked in debug mode")
+)]
+impl ColumnStats {
+    #[inline]
+    pub(super) fn add_live(&mut self, count: usize) {
+        self.live += count;
+        self.peak = self.peak.max(self.live);
+    }
+
+    #[inline]
+    pub(super) fn sub_live(&mut self, count: usize) {
+        debug_assert!(self.live >= count, "more items removed than initialized");
+        self.live -= count;
+    }
+}
+
+impl AddAssign for ColumnStats {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.live += rhs.live;
+        self.peak += rhs.peak;
+        self.bytes += rhs.bytes;
+    }
+}
+
+// -----------------------------------------------------------------------------
+// StorageStats
+
+/// Memory statistics of all components, returned by [`Storages::stats`].
+///
+/// [`Storages::stats`]: crate::storage::Storages::stats
+#[derive(Debug, Default, Clone)]
+pub struct StorageStats {
+    components: Vec<(ComponentId, ColumnStats)>,
+}
+
+impl StorageStats {
+    /// Adds the statistics of a column storing the component.
+    pub(super) fn add(&mut self, id: ComponentId, stats: ColumnStats) {
+        match self.components.binary_search_by_key(&id, |&(key, _)| key) {
+            Ok(index) => self.components[index].1 += stats,
+            Err(index) => self.components.insert(index, (id, stats)),
+        }
+    }
+
+    /// Returns the statistics of a component, if it has any column.
+    pub fn get(&self, id: ComponentId) -> Option<ColumnStats> {
+        self.components
+            .binary_search_by_key(&id, |&(key, _)| key)
+            .ok()
+            .map(|index| self.components[index].1)
+    }
+
+    /// Returns an iterator over the statistics, sorted by component.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (ComponentId, ColumnStats)> + '_ {
+        self.components.iter().copied()
+    }
+
+    /// Returns the sum of the statistics of all components.
+    pub fn total(&self) -> ColumnStats {
+        let mut total = ColumnStats::default();
+        self.components
+            .iter()
+            .for_each(|&(_, stats)| total += stats);
+        total
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(all(test, any(debug_assertions, feature = "debug")))]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use crate::component::Component;
+    use crate::world::World;
+
+    #[derive(Component)]
+    struct Dense(#[expect(dead_code, reason = "only stored")] u64);
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[derive(Component)]
+    #[component(storage = "sparse")]
+    struct Sparse(#[expect(dead_code, reason = "only stored")] String);
+
+    #[test]
+    fn column_stats() {
+        let mut world = World::default();
+        world.storages_mut().set_leak_assertions(true);
+        let dense = world.register_component::<Dense>();
+        let sparse = world.register_component::<Sparse>();
+
+        let entities: Vec<_> = (0..4)
+            .map(|i| {
+                world
+                    .spawn((Dense(i), Marker, Sparse(String::new())))
+                    .entity()
+            })
+            .collect();
+        // Moves `Dense` to another table and drops `Sparse`.
+        world.entity_owned(entities[0]).remove::<(Marker, Sparse)>();
+
+        let stats = world.storages().stats();
+        let dense_stats = stats.get(dense).unwrap();
+        assert_eq!(dense_stats.live, 4);
+        assert!(dense_stats.peak >= 4);
+        assert!(dense_stats.bytes >= 4 * 8);
+        assert_eq!(stats.get(sparse).unwrap().live, 3);
+
+        for entity in entities {
+            world.despawn(entity).unwrap();
+        }
+        let stats = world.storages().stats();
+        assert_eq!(stats.total().live, 0);
+        assert_eq!(stats.get(sparse).unwrap().peak, 4);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[should_panic(expected = "dropped with 1 live items for 0 entities")]
+    fn leak_assertions() {
+        let mut world = World::default();
+        world.storages_mut().set_leak_assertions(true);
+
+        let entity = world.spawn(Dense(0)).entity();
+        let location = world.entities.locate(entity).unwrap();
+        let table = unsafe { world.storages.tables.get_unchecked_mut(location.table_id) };
+        // `Dense` has no drop logic, but is counted as a live item.
+        let _ = unsafe { table.swap_remove_and_forget(location.table_row) };
+    }
+}
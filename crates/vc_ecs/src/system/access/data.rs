@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::fmt::Debug;
 
 use vc_utils::hash::SparseHashSet;
@@ -111,6 +112,13 @@ impl AccessParam {
         }
     }
 
+    /// Returns `true` if the component is read or written, including
+    /// through `EntityRef` or `EntityMut`.
+    #[must_use]
+    pub fn is_accessing(&self, id: ComponentId) -> bool {
+        self.entity_ref || self.entity_mut || self.reading.contains(&id)
+    }
+
     #[must_use]
     pub fn is_read_only(&self) -> bool {
         self.entity_ref || (!self.entity_mut && self.writing.is_empty())
@@ -130,6 +138,28 @@ impl AccessParam {
         self.writing.is_disjoint(&other.reading) && other.writing.is_disjoint(&self.reading)
     }
 
+    /// Returns the components accessed by both, with at least one write, sorted.
+    ///
+    /// Holding `EntityRef` or `EntityMut` conflicts with the components accessed
+    /// by the other side, and the list is empty if both access whole entities.
+    pub fn conflicting_components(&self, other: &Self) -> Vec<ComponentId> {
+        let mut ids: Vec<ComponentId> = match (self.entity_mut, self.entity_ref) {
+            (true, _) => other.reading.iter().copied().collect(),
+            (_, true) => other.writing.iter().copied().collect(),
+            _ if other.entity_mut => self.reading.iter().copied().collect(),
+            _ if other.entity_ref => self.writing.iter().copied().collect(),
+            _ => self
+                .writing
+                .intersection(&other.reading)
+                .chain(other.writing.intersection(&self.reading))
+                .copied()
+                .collect(),
+        };
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
     pub fn merge_with(&mut self, other: &Self) {
         self.entity_mut |= other.entity_mut;
         self.entity_ref &= other.entity_ref;
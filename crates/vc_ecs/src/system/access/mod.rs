@@ -4,4 +4,4 @@ mod table;
 
 pub use data::AccessParam;
 pub use filter::{FilterParam, FilterParamBuilder};
-pub use table::AccessTable;
+pub use table::{AccessConflict, AccessTable, ParamAccess, ParamConflict};
@@ -1,19 +1,137 @@
-use core::fmt::Debug;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display};
 
 use fixedbitset::FixedBitSet;
 use vc_utils::hash::NoOpHashMap;
 
 use super::{AccessParam, FilterParam};
+use crate::component::ComponentId;
 use crate::resource::ResourceId;
+use crate::utils::DebugName;
+
+/// A reason why two [`AccessTable`]s are not parallelizable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccessConflict {
+    /// One side holds `&mut World`.
+    World,
+    /// A resource is written by at least one side.
+    Resource(ResourceId),
+    /// Two queries may match the same entities, and access some of their
+    /// components with at least one write.
+    ///
+    /// `components` is empty if both sides access whole entities.
+    Query {
+        filters: [FilterParam; 2],
+        components: Vec<ComponentId>,
+    },
+}
+
+/// An access requested by a system parameter, see [`ParamConflict`].
+#[derive(Debug, Clone)]
+pub enum ParamAccess {
+    /// Holding `&World`.
+    WorldRef,
+    /// Holding `&mut World`.
+    WorldMut,
+    /// Reading a resource.
+    ReadRes(ResourceId),
+    /// Writing a resource.
+    WriteRes(ResourceId),
+    /// A query accessing `data` on the entities matching one of `filters`.
+    Query {
+        data: AccessParam,
+        filters: Vec<FilterParam>,
+    },
+}
+
+impl ParamAccess {
+    /// Returns `true` if both accesses cannot be held at the same time.
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        use ParamAccess::{Query, ReadRes, WorldMut, WorldRef, WriteRes};
+
+        match (self, other) {
+            (WorldMut, _) | (_, WorldMut) => true,
+            (WorldRef, WriteRes(_)) | (WriteRes(_), WorldRef) => true,
+            (WorldRef, Query { data, .. }) | (Query { data, .. }, WorldRef) => !data.is_read_only(),
+            (ReadRes(a), WriteRes(b)) | (WriteRes(a), ReadRes(b) | WriteRes(b)) => a == b,
+            (
+                Query {
+                    data: a,
+                    filters: x,
+                },
+                Query {
+                    data: b,
+                    filters: y,
+                },
+            ) => !a.parallelizable(b) && x.iter().any(|p| y.iter().any(|q| !p.is_disjoint(q))),
+            _ => false,
+        }
+    }
+}
+
+impl Display for ParamAccess {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WorldRef => f.write_str("reads the world"),
+            Self::WorldMut => f.write_str("writes the world"),
+            Self::ReadRes(id) => write!(f, "reads resource {id}"),
+            Self::WriteRes(id) => write!(f, "writes resource {id}"),
+            Self::Query { data, .. } if data.is_read_only() => f.write_str("reads components"),
+            Self::Query { .. } => f.write_str("writes components"),
+        }
+    }
+}
+
+/// Why a system parameter was rejected by an [`AccessTable`], see
+/// [`AccessTable::explain_conflict`].
+#[derive(Debug, Clone)]
+pub struct ParamConflict {
+    /// The rejected parameter.
+    pub param: DebugName,
+    /// The access requested by the rejected parameter.
+    pub access: ParamAccess,
+    /// The previously declared parameters it conflicts with.
+    pub conflicts_with: Vec<(DebugName, ParamAccess)>,
+}
+
+impl Display for ParamConflict {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "`{}` ({})", self.param, self.access)?;
+        if self.conflicts_with.is_empty() {
+            return f.write_str(" conflicts with the other params");
+        }
+        f.write_str(" conflicts with ")?;
+        for (index, (param, access)) in self.conflicts_with.iter().enumerate() {
+            if index != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "`{param}` ({access})")?;
+        }
+        Ok(())
+    }
+}
 
 /// Tracks access patterns for system execution and conflict detection.
-#[derive(Default)]
+///
+/// In debug mode, each access is recorded with the [`DebugName`] of the
+/// parameter that declared it, set by [`AccessTable::set_param`]. When an
+/// access is refused, [`AccessTable::explain_conflict`] reports the parameters
+/// involved.
 pub struct AccessTable {
     world_mut: bool,          // holding `&mut world`
     world_ref: bool,          // holding `&world`
     res_reading: FixedBitSet, // resource reading
     res_writing: FixedBitSet, // resource writing
     filter: NoOpHashMap<FilterParam, AccessParam>,
+    param: DebugName,                        // parameter being marked
+    declared: Vec<(DebugName, ParamAccess)>, // accepted accesses
+    rejected: Option<(DebugName, ParamAccess)>,
+}
+
+impl Default for AccessTable {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // `#[derive(Clone)]` does not generate optimized `clone_from`.
@@ -25,6 +143,9 @@ impl Clone for AccessTable {
             res_reading: self.res_reading.clone(),
             res_writing: self.res_writing.clone(),
             filter: self.filter.clone(),
+            param: self.param,
+            declared: self.declared.clone(),
+            rejected: self.rejected.clone(),
         }
     }
 
@@ -34,6 +155,9 @@ impl Clone for AccessTable {
         self.res_reading.clone_from(&source.res_reading);
         self.res_writing.clone_from(&source.res_writing);
         self.filter.clone_from(&source.filter);
+        self.param = source.param;
+        self.declared.clone_from(&source.declared);
+        self.rejected.clone_from(&source.rejected);
     }
 }
 
@@ -64,7 +188,68 @@ impl AccessTable {
             res_reading: FixedBitSet::new(),
             res_writing: FixedBitSet::new(),
             filter: NoOpHashMap::new(),
+            param: DebugName::anonymous(),
+            declared: Vec::new(),
+            rejected: None,
+        }
+    }
+
+    /// Sets the parameter that declares the following accesses.
+    ///
+    /// This is called for each element of a parameter tuple, so nested params
+    /// are reported by their innermost name.
+    pub fn set_param(&mut self, name: DebugName) {
+        self.param = name;
+    }
+
+    /// Explains why the last refused access conflicts with the declared ones.
+    ///
+    /// Returns `None` if no access was refused, and always outside debug mode
+    /// (`debug_assertions` or the `debug` feature), where the accesses are not
+    /// recorded.
+    pub fn explain_conflict(&self) -> Option<ParamConflict> {
+        let (param, access) = self.rejected.clone()?;
+        let conflicts_with = self
+            .declared
+            .iter()
+            .filter(|(_, declared)| declared.conflicts_with(&access))
+            .cloned()
+            .collect();
+        Some(ParamConflict {
+            param,
+            access,
+            conflicts_with,
+        })
+    }
+
+    /// Records the result of marking `access` for the current parameter.
+    ///
+    /// `access` is only built in debug mode.
+    fn record(&mut self, access: impl FnOnce() -> ParamAccess, accepted: bool) -> bool {
+        if !accepted {
+            vc_utils::cold_path();
+        }
+        crate::cfg::debug! {
+            if {
+                if accepted {
+                    self.declared.push((self.param, access()));
+                } else {
+                    self.rejected = Some((self.param, access()));
+                }
+            } else {
+                let _ = access;
+            }
         }
+        accepted
+    }
+
+    /// Clears the accesses, keeping the declared parameters.
+    fn clear_access(&mut self) {
+        self.world_mut = false;
+        self.world_ref = false;
+        self.res_reading.clear();
+        self.res_writing.clear();
+        self.filter.clear();
     }
 
     pub fn can_world_mut(&self) -> bool {
@@ -83,27 +268,21 @@ impl AccessTable {
     }
 
     pub fn set_world_mut(&mut self) -> bool {
-        if self.can_world_mut() {
-            *self = const { Self::new() };
+        let accepted = self.can_world_mut();
+        if accepted {
+            self.clear_access();
             self.world_mut = true;
-            true
-        } else {
-            vc_utils::cold_path();
-            false
         }
+        self.record(|| ParamAccess::WorldMut, accepted)
     }
 
     pub fn set_world_ref(&mut self) -> bool {
-        if self.can_world_ref() {
-            if !self.world_ref {
-                *self = const { Self::new() };
-                self.world_ref = true;
-            }
-            true
-        } else {
-            vc_utils::cold_path();
-            false
+        let accepted = self.can_world_ref();
+        if accepted && !self.world_ref {
+            self.clear_access();
+            self.world_ref = true;
         }
+        self.record(|| ParamAccess::WorldRef, accepted)
     }
 
     pub fn can_reading_res(&self, id: ResourceId) -> bool {
@@ -115,27 +294,21 @@ impl AccessTable {
     }
 
     pub fn set_reading_res(&mut self, id: ResourceId) -> bool {
-        if self.can_reading_res(id) {
-            if !self.world_ref {
-                self.res_reading.grow_and_insert(id.index());
-            }
-            true
-        } else {
-            vc_utils::cold_path();
-            false
+        let accepted = self.can_reading_res(id);
+        if accepted && !self.world_ref {
+            self.res_reading.grow_and_insert(id.index());
         }
+        self.record(|| ParamAccess::ReadRes(id), accepted)
     }
 
     pub fn set_writing_res(&mut self, id: ResourceId) -> bool {
-        if self.can_writing_res(id) {
+        let accepted = self.can_writing_res(id);
+        if accepted {
             let index = id.index();
             self.res_reading.grow_and_insert(index);
             self.res_writing.grow_and_insert(index);
-            true
-        } else {
-            vc_utils::cold_path();
-            false
         }
+        self.record(|| ParamAccess::WriteRes(id), accepted)
     }
 
     pub fn can_query(&self, data: &AccessParam, params: &[FilterParam]) -> bool {
@@ -157,20 +330,21 @@ impl AccessTable {
     }
 
     pub fn set_query(&mut self, data: &AccessParam, params: &[FilterParam]) -> bool {
-        if self.can_query(data, params) {
-            if !self.world_ref {
-                params.iter().for_each(|param| {
-                    if let Some(item) = self.filter.get_mut(param) {
-                        item.merge_with(data);
-                    } else {
-                        self.filter.insert(param.clone(), data.clone());
-                    }
-                });
-            }
-            true
-        } else {
-            false
+        let accepted = self.can_query(data, params);
+        if accepted && !self.world_ref {
+            params.iter().for_each(|param| {
+                if let Some(item) = self.filter.get_mut(param) {
+                    item.merge_with(data);
+                } else {
+                    self.filter.insert(param.clone(), data.clone());
+                }
+            });
         }
+        let access = || ParamAccess::Query {
+            data: data.clone(),
+            filters: params.to_vec(),
+        };
+        self.record(access, accepted)
     }
 
     pub fn parallelizable(&self, other: &Self) -> bool {
@@ -196,9 +370,47 @@ impl AccessTable {
         })
     }
 
+    /// Lists why `self` and `other` are not [parallelizable].
+    ///
+    /// The list is empty if they are parallelizable.
+    ///
+    /// [parallelizable]: Self::parallelizable
+    pub fn conflicts(&self, other: &Self) -> Vec<AccessConflict> {
+        if self.world_mut || other.world_mut {
+            return alloc::vec![AccessConflict::World];
+        }
+        if self.world_ref && other.world_ref {
+            return Vec::new();
+        }
+
+        let mut resources = self.res_writing.clone();
+        resources.intersect_with(&other.res_reading);
+        let mut reverse = other.res_writing.clone();
+        reverse.intersect_with(&self.res_reading);
+        resources.union_with(&reverse);
+
+        let mut conflicts: Vec<AccessConflict> = resources
+            .ones()
+            .map(|index| AccessConflict::Resource(ResourceId::new(index as u32)))
+            .collect();
+
+        self.filter.iter().for_each(|(k, v)| {
+            other.filter.iter().for_each(|(x, y)| {
+                if !k.is_disjoint(x) && !v.parallelizable(y) {
+                    conflicts.push(AccessConflict::Query {
+                        filters: [k.clone(), x.clone()],
+                        components: v.conflicting_components(y),
+                    });
+                }
+            });
+        });
+        conflicts
+    }
+
     pub fn merge(mut self, other: Self) -> Self {
         self.world_mut |= other.world_mut;
         self.world_ref &= other.world_ref;
+        self.declared.extend(other.declared);
         if self.world_mut || self.world_ref {
             self.res_reading = FixedBitSet::new();
             self.res_writing = FixedBitSet::new();
@@ -217,3 +429,54 @@ impl AccessTable {
         self
     }
 }
+
+#[cfg(all(test, any(debug_assertions, feature = "debug")))]
+mod tests {
+    use crate::borrow::{Res, ResMut};
+    use crate::component::Component;
+    use crate::query::Query;
+    use crate::resource::Resource;
+    use crate::system::{AccessTable, Local, ParamAccess, SystemParam};
+    use crate::world::World;
+
+    #[derive(Resource)]
+    struct Foo;
+
+    #[derive(Component)]
+    struct Pos;
+
+    #[test]
+    fn explain_conflict() {
+        type Params<'w, 's> = (Res<'w, Foo>, Local<'s, u8>, ResMut<'w, Foo>);
+
+        let mut world = World::default();
+        let state = Params::init_state(&mut world);
+        let mut table = AccessTable::new();
+        assert!(table.explain_conflict().is_none());
+        assert!(!Params::mark_access(&mut table, &state));
+
+        let conflict = table.explain_conflict().unwrap();
+        assert!(matches!(conflict.access, ParamAccess::WriteRes(_)));
+        assert_eq!(conflict.conflicts_with.len(), 1);
+        assert!(matches!(
+            conflict.conflicts_with[0].1,
+            ParamAccess::ReadRes(_)
+        ));
+
+        let message = alloc::format!("{conflict}");
+        assert!(message.starts_with("`ResMut<'_, Foo>`"), "{message}");
+        assert!(message.contains("`Res<'_, Foo>`"), "{message}");
+
+        let mut table = AccessTable::new();
+        let state = <(Query<&mut Pos>, &World)>::init_state(&mut world);
+        assert!(!<(Query<&mut Pos>, &World)>::mark_access(
+            &mut table, &state
+        ));
+        let conflict = table.explain_conflict().unwrap();
+        assert!(matches!(conflict.access, ParamAccess::WorldRef));
+        assert!(matches!(
+            conflict.conflicts_with[0].1,
+            ParamAccess::Query { .. }
+        ));
+    }
+}
@@ -2,6 +2,7 @@ use super::{AccessTable, System, SystemFlags, SystemMeta};
 use crate::error::EcsError;
 use crate::system::{IntoSystem, SystemName, UninitSystemError};
 use crate::tick::Tick;
+use crate::utils::DebugName;
 use crate::world::{World, WorldId};
 
 use super::{SystemInput, SystemParam};
@@ -278,8 +279,9 @@ impl<M: 'static, F: SystemFunction<M> + 'static> System for FunctionSystem<M, F>
             param: <F::Param as SystemParam>::init_state(world),
             world_id: world.id(),
         });
+        table.set_param(DebugName::type_name::<F::Param>());
         if !<F::Param as SystemParam>::mark_access(&mut table, &state.param) {
-            invalid_system_access(self.meta.name());
+            invalid_system_access(self.meta.name(), &table);
         }
         table
     }
@@ -296,6 +298,12 @@ impl<M: 'static, F: SystemFunction<M> + 'static> System for FunctionSystem<M, F>
         if state.world_id != world_id {
             mismatched_world(self.meta.name(), state.world_id, world_id);
         }
+        if ::core::cfg!(debug_assertions) && <F::Param as SystemParam>::NON_SEND {
+            let world_thread = unsafe { world.read_only().thread_hash() };
+            if world_thread != crate::utils::thread_hash() {
+                non_send_off_thread(self.meta.name());
+            }
+        }
 
         let last_run = self.meta.get_last_run();
         let this_run = unsafe { world.read_only().advance_tick() };
@@ -319,8 +327,19 @@ fn uninit_system_error(name: SystemName) -> EcsError {
 
 #[cold]
 #[inline(never)]
-fn invalid_system_access(name: SystemName) -> ! {
-    panic!("System {name} params access conflict.")
+fn invalid_system_access(name: SystemName, table: &AccessTable) -> ! {
+    match table.explain_conflict() {
+        Some(conflict) => panic!("System {name} params access conflict: {conflict}."),
+        None => panic!("System {name} params access conflict."),
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn non_send_off_thread(name: SystemName) -> ! {
+    panic!(
+        "System {name} has main-thread params, but runs on a thread other than the one that created the world."
+    )
 }
 
 #[cold]
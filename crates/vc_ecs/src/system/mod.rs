@@ -13,12 +13,13 @@ mod system;
 // -----------------------------------------------------------------------------
 // Exports
 
-pub use access::{AccessParam, AccessTable, FilterParam, FilterParamBuilder};
+pub use access::{AccessConflict, AccessParam, AccessTable, FilterParam, FilterParamBuilder};
+pub use access::{ParamAccess, ParamConflict};
 pub use error::UninitSystemError;
 pub use function::{FunctionSystem, SystemFunction};
 pub use input::{In, InMut, InRef, SystemInput};
 pub use meta::{SystemFlags, SystemMeta};
 pub use name::SystemName;
-pub use param::{Local, ReadOnlySystemParam, SystemParam};
+pub use param::{Deferred, Local, ReadOnlySystemParam, SystemBuffer, SystemParam};
 pub use system::{IntoMapSystem, IntoPipeSystem, IntoRunIfSystem};
 pub use system::{IntoSystem, MapSystem, PipeSystem, RunIfSystem, System};
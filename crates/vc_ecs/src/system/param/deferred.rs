@@ -0,0 +1,223 @@
+use core::fmt::Debug;
+use core::ops::{Deref, DerefMut};
+
+use super::{ReadOnlySystemParam, SystemParam};
+use crate::command::CommandObject;
+use crate::error::EcsError;
+use crate::system::AccessTable;
+use crate::tick::Tick;
+use crate::world::{UnsafeWorld, World};
+
+// -----------------------------------------------------------------------------
+// SystemBuffer
+
+/// A user-defined buffer of deferred effects, used through [`Deferred`].
+///
+/// Systems fill the buffer without accessing the world, and the buffer is
+/// applied later with exclusive world access, at the same sync points as
+/// [`Commands`].
+///
+/// [`Commands`]: crate::command::Commands
+pub trait SystemBuffer: Default + Send + Sync + 'static {
+    /// Applies the buffered effects to the world.
+    ///
+    /// Errors are passed to the world's default error handler, like the
+    /// errors of commands.
+    fn apply(self, world: &mut World) -> Result<(), EcsError>;
+
+    /// Returns `true` if there is nothing to apply, so the buffer is not queued.
+    ///
+    /// Returns `false` by default.
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Deferred
+
+/// A system parameter holding a [`SystemBuffer`], applied at the next sync point.
+///
+/// Each compiled system instance owns one buffer, initialized from
+/// `T::default()`. When the parameter is dropped, or [`flush`] is called, the
+/// filled buffer is replaced by a new one and moved to the world's deferred
+/// command queue, then applied by [`World::flush`], which runs at the end of
+/// each [`Schedule::run`].
+///
+/// Buffers and [`Commands`] share the same queue, so they are applied in the
+/// order they are flushed. Like `Commands`, `Deferred` does not count as
+/// component or resource access and does not reduce system parallelism.
+///
+/// [`flush`]: Deferred::flush
+/// [`Commands`]: crate::command::Commands
+/// [`Schedule::run`]: crate::schedule::Schedule::run
+///
+/// # Examples
+///
+/// ```no_run
+/// use vc_ecs::prelude::*;
+/// use vc_ecs::error::EcsError;
+/// use vc_ecs::system::{Deferred, SystemBuffer};
+///
+/// #[derive(Resource, Default)]
+/// struct TotalDamage(u32);
+///
+/// #[derive(Default)]
+/// struct DamageBuffer(Vec<u32>);
+///
+/// impl SystemBuffer for DamageBuffer {
+///     fn apply(self, world: &mut World) -> Result<(), EcsError> {
+///         if let Some(mut total) = world.get_resource_mut::<TotalDamage>() {
+///             total.0 += self.0.iter().sum::<u32>();
+///         }
+///         Ok(())
+///     }
+///
+///     fn is_empty(&self) -> bool {
+///         self.0.is_empty()
+///     }
+/// }
+///
+/// fn hit(mut damage: Deferred<DamageBuffer>) {
+///     damage.0.push(10);
+/// }
+/// ```
+pub struct Deferred<'w, 's, T: SystemBuffer> {
+    world: &'w World,
+    buffer: &'s mut T,
+}
+
+unsafe impl<T: SystemBuffer> ReadOnlySystemParam for Deferred<'_, '_, T> {}
+
+unsafe impl<T: SystemBuffer> SystemParam for Deferred<'_, '_, T> {
+    type State = T;
+    type Item<'world, 'state> = Deferred<'world, 'state, T>;
+
+    const NON_SEND: bool = false;
+    const EXCLUSIVE: bool = false;
+
+    fn init_state(_world: &mut World) -> Self::State {
+        T::default()
+    }
+
+    fn mark_access(_table: &mut AccessTable, _state: &Self::State) -> bool {
+        true
+    }
+
+    unsafe fn build_param<'w, 's>(
+        world: UnsafeWorld<'w>,
+        state: &'s mut Self::State,
+        _last_run: Tick,
+        _this_run: Tick,
+    ) -> Result<Self::Item<'w, 's>, EcsError> {
+        Ok(Deferred {
+            world: unsafe { world.read_only() },
+            buffer: state,
+        })
+    }
+}
+
+impl<T: SystemBuffer> Deferred<'_, '_, T> {
+    /// Moves the buffer to the world's deferred command queue, unless it
+    /// [is empty](SystemBuffer::is_empty), and starts a new one.
+    ///
+    /// Note that this function will be called in [`Drop::drop`] automatically.
+    pub fn flush(&mut self) {
+        if !self.buffer.is_empty() {
+            let buffer = core::mem::take(&mut *self.buffer);
+            self.world
+                .pending
+                .commands
+                .push(CommandObject::new(move |world| buffer.apply(world)));
+        }
+    }
+}
+
+impl<T: SystemBuffer> Drop for Deferred<'_, '_, T> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+impl<T: SystemBuffer> Deref for Deferred<'_, '_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.buffer
+    }
+}
+
+impl<T: SystemBuffer> DerefMut for Deferred<'_, '_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.buffer
+    }
+}
+
+impl<T: SystemBuffer + Debug> Debug for Deferred<'_, '_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Deferred")
+            .field("world", &self.world.id())
+            .field("buffer", &self.buffer)
+            .finish()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{Deferred, SystemBuffer};
+    use crate::command::Commands;
+    use crate::error::EcsError;
+    use crate::resource::Resource;
+    use crate::schedule::Schedule;
+    use crate::world::World;
+
+    #[derive(Resource, Default)]
+    struct Log(Vec<u32>);
+
+    #[derive(Default)]
+    struct Events(Vec<u32>);
+
+    impl SystemBuffer for Events {
+        fn apply(self, world: &mut World) -> Result<(), EcsError> {
+            world.get_resource_mut::<Log>().unwrap().0.extend(self.0);
+            Ok(())
+        }
+
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+    }
+
+    fn emit(mut commands: Commands, mut events: Deferred<Events>) {
+        events.0.extend([1, 2]);
+        events.flush();
+        commands.push(|world| {
+            world.get_resource_mut::<Log>().unwrap().0.push(3);
+            Ok(())
+        });
+        commands.flush();
+        events.0.push(4);
+    }
+
+    #[test]
+    fn deferred_buffer() {
+        let mut world = World::default();
+        world.insert_resource(Log::default());
+
+        let mut schedule = Schedule::default();
+        schedule.add_system(emit);
+        schedule.run(&mut world);
+        assert_eq!(world.get_resource::<Log>().unwrap().0, [1, 2, 3, 4]);
+
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get_resource::<Log>().unwrap().0,
+            [1, 2, 3, 4, 1, 2, 3, 4]
+        );
+    }
+}
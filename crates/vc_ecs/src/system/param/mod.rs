@@ -3,6 +3,7 @@
 // -----------------------------------------------------------------------------
 // Modules
 
+mod deferred;
 mod local;
 mod resource;
 mod tuples;
@@ -11,6 +12,9 @@ mod world;
 // -----------------------------------------------------------------------------
 // marker
 
+pub use vc_ecs_derive::SystemParam;
+
+pub use deferred::{Deferred, SystemBuffer};
 pub use local::Local;
 
 // -----------------------------------------------------------------------------
@@ -27,6 +31,7 @@ use crate::world::{UnsafeWorld, World};
 ///
 /// - [`&World`] and [`&mut World`]
 /// - [`Commands`]
+/// - [`Deferred`]
 /// - [`Query`]
 /// - [`Local`]
 /// - [`Res`], [`ResRef`], [`ResMut`]
@@ -137,3 +142,72 @@ pub unsafe trait SystemParam: Sized {
 /// The implementer must guarantee that this parameter never performs mutable
 /// access to world data and never requires exclusive scheduling.
 pub unsafe trait ReadOnlySystemParam: SystemParam {}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::{Local, ReadOnlySystemParam, SystemParam};
+    use crate::borrow::{Res, ResMut};
+    use crate::component::Component;
+    use crate::query::Query;
+    use crate::resource::Resource;
+    use crate::schedule::Schedule;
+    use crate::system::AccessTable;
+    use crate::world::World;
+
+    #[derive(Resource)]
+    struct Step(u32);
+
+    #[derive(Resource, Default)]
+    struct Total(u32);
+
+    #[derive(Component)]
+    struct Value(u32);
+
+    #[derive(SystemParam)]
+    struct Values<'w, 's> {
+        step: Res<'w, Step>,
+        query: Query<'w, 's, &'static Value>,
+        runs: Local<'s, u32>,
+    }
+
+    #[derive(SystemParam)]
+    struct Output<'w>(ResMut<'w, Total>);
+
+    #[derive(SystemParam)]
+    struct Single<'w, T: Resource + Sync>(#[expect(dead_code, reason = "only fetched")] Res<'w, T>);
+
+    fn sum(mut values: Values, mut output: Output) {
+        *values.runs += 1;
+        let sum: u32 = values.query.into_iter().map(|value| value.0).sum();
+        output.0.0 = sum * values.step.0 + *values.runs;
+    }
+
+    fn assert_read_only<P: ReadOnlySystemParam>() {}
+
+    #[test]
+    fn derive_system_param() {
+        assert_read_only::<Values>();
+        assert_read_only::<Single<Step>>();
+
+        let mut world = World::default();
+        world.insert_resource(Step(10));
+        world.insert_resource(Total::default());
+        world.spawn(Value(1));
+        world.spawn(Value(2));
+
+        let mut schedule = Schedule::default();
+        schedule.add_system(sum);
+        schedule.run(&mut world);
+        assert_eq!(world.get_resource::<Total>().unwrap().0, 31);
+        schedule.run(&mut world);
+        assert_eq!(world.get_resource::<Total>().unwrap().0, 32);
+
+        type Conflict<'w, 's> = (Output<'w>, Values<'w, 's>, Output<'w>);
+        let state = Conflict::init_state(&mut world);
+        let mut table = AccessTable::new();
+        assert!(!Conflict::mark_access(&mut table, &state));
+    }
+}
@@ -2,6 +2,7 @@ use super::{ReadOnlySystemParam, SystemParam};
 use crate::error::EcsError;
 use crate::system::AccessTable;
 use crate::tick::Tick;
+use crate::utils::DebugName;
 use crate::world::{UnsafeWorld, World};
 
 macro_rules! impl_tuple {
@@ -48,6 +49,7 @@ macro_rules! impl_tuple {
             }
 
             fn mark_access(table: &mut AccessTable, state: &Self::State) -> bool {
+                table.set_param(DebugName::type_name::<$name>());
                 <$name>::mark_access(table, state)
             }
 
@@ -78,7 +80,10 @@ macro_rules! impl_tuple {
             }
 
             fn mark_access(table: &mut AccessTable, state: &Self::State) -> bool {
-                true $( && <$name>::mark_access(table, &state.$index) )*
+                true $( && {
+                    table.set_param(DebugName::type_name::<$name>());
+                    <$name>::mark_access(table, &state.$index)
+                } )*
             }
 
             unsafe fn build_param<'w, 's>(
@@ -56,7 +56,10 @@ use super::SystemInput;
 /// There are two special categories of systems.
 ///
 /// A system that accesses `NonSend` data cannot be moved across threads,
-/// so it must be scheduled on the main thread:
+/// so it must be scheduled on the main thread, the thread that created the
+/// [`World`]. The executors run such systems on the thread running the
+/// schedule, or on the [`MainThreadExecutor`](crate::schedule::MainThreadExecutor)
+/// if it is inserted:
 ///
 /// ```ignore
 /// fn system_a(foo: NonSend<Foo>) {
@@ -122,7 +125,8 @@ pub trait System: Send + Sync + 'static {
     /// - The implementation must respect the access patterns declared in
     ///   `initialize` and not access components/resources outside those patterns.
     /// - For `NON_SEND` systems, the caller must ensure execution occurs on the
-    ///   same thread where the system was created.
+    ///   thread that created the world. Function systems panic otherwise in
+    ///   debug builds.
     /// - For `EXCLUSIVE` systems, the caller must ensure exclusive world access.
     unsafe fn run(
         &mut self,
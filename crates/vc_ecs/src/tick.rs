@@ -133,6 +133,34 @@ impl Tick {
         since_system > since_insert
     }
 
+    /// Clamps this tick if it is older than [`MAX_TICK_AGE`] at the time of
+    /// `check`, returning `true` if it was clamped.
+    ///
+    /// Storage outside the world must call this for each of its ticks when
+    /// receiving [`CheckTicks`], otherwise a stale tick may wrap around and
+    /// appear newer than it is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use vc_ecs::tick::{CheckTicks, Tick};
+    /// let now = Tick::new(u32::MAX);
+    /// let mut tick = Tick::new(0);
+    ///
+    /// assert!(tick.check_tick(CheckTicks::new(now)));
+    /// assert_eq!(now.relative_to(tick), Tick::MAX_AGE);
+    /// assert!(!tick.check_tick(CheckTicks::new(now)));
+    /// ```
+    #[inline]
+    pub fn check_tick(&mut self, check: CheckTicks) -> bool {
+        let now = check.tick();
+        if now.relative_to(*self).0 > MAX_TICK_AGE {
+            *self = now.relative_to(Self::MAX_AGE);
+            true
+        } else {
+            false
+        }
+    }
+
     /// Clamps a single tick value if it is older than `MAX_TICK_AGE`.
     ///
     /// `fall_back` should be computed as `now.relative_to(Tick::MAX_AGE)`.
@@ -189,26 +217,135 @@ impl core::fmt::Debug for Tick {
 pub struct CheckTicks(Tick);
 
 impl CheckTicks {
+    /// Creates the event for the current tick `now`.
     #[inline(always)]
     pub const fn new(tick: Tick) -> Self {
         CheckTicks(tick)
     }
 
+    /// Returns the tick at which the check runs.
     #[inline(always)]
     pub const fn tick(self) -> Tick {
         self.0
     }
 }
 
+// -----------------------------------------------------------------------------
+// ChangeTicks
+
+/// The owned insertion/change ticks of a single value.
+///
+/// Resources store their ticks this way; components keep their added and
+/// changed ticks in separate per-column arrays instead.
+///
+/// Storage extensions outside the world (custom indexes, spatial structures,
+/// ...) can keep a `ChangeTicks` per entry to take part in change detection.
+/// The world does not know about these entries and never clamps them, so the
+/// extension has to call [`check_ticks`](Self::check_ticks) itself, e.g. with
+/// the [`CheckTicks`] returned by [`World::check_ticks`].
+///
+/// [`World::check_ticks`]: crate::world::World::check_ticks
+///
+/// # Examples
+/// ```
+/// # use vc_ecs::tick::{ChangeTicks, Tick};
+/// let mut ticks = ChangeTicks::new(Tick::new(10));
+///
+/// assert!(ticks.is_added(Tick::new(5), Tick::new(12)));
+/// assert!(!ticks.is_changed(Tick::new(10), Tick::new(12)));
+///
+/// ticks.set_changed(Tick::new(12));
+/// assert!(ticks.is_changed(Tick::new(10), Tick::new(12)));
+/// assert!(!ticks.is_added(Tick::new(10), Tick::new(12)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChangeTicks {
+    pub added: Tick,
+    pub changed: Tick,
+}
+
+impl ChangeTicks {
+    /// Creates ticks for a value inserted at `now`.
+    #[inline(always)]
+    pub const fn new(now: Tick) -> Self {
+        Self {
+            added: now,
+            changed: now,
+        }
+    }
+
+    /// Returns `true` if the value was added after `last_run`.
+    #[inline]
+    pub const fn is_added(&self, last_run: Tick, this_run: Tick) -> bool {
+        self.added.is_newer_than(last_run, this_run)
+    }
+
+    /// Returns `true` if the value was added or changed after `last_run`.
+    #[inline]
+    pub const fn is_changed(&self, last_run: Tick, this_run: Tick) -> bool {
+        self.changed.is_newer_than(last_run, this_run)
+    }
+
+    /// Marks the value as changed at `now`.
+    #[inline(always)]
+    pub const fn set_changed(&mut self, now: Tick) {
+        self.changed = now;
+    }
+
+    /// Clamps both ticks, see [`Tick::check_tick`].
+    ///
+    /// Returns `true` if any tick was clamped.
+    #[inline]
+    pub fn check_ticks(&mut self, check: CheckTicks) -> bool {
+        let added = self.added.check_tick(check);
+        let changed = self.changed.check_tick(check);
+        added || changed
+    }
+
+    /// Borrows the ticks with the run context of a system.
+    #[inline(always)]
+    pub const fn ticks_ref(&self, last_run: Tick, this_run: Tick) -> TicksRef<'_> {
+        TicksRef {
+            added: &self.added,
+            changed: &self.changed,
+            last_run,
+            this_run,
+        }
+    }
+
+    /// Mutably borrows the ticks with the run context of a system.
+    #[inline(always)]
+    pub const fn ticks_mut(&mut self, last_run: Tick, this_run: Tick) -> TicksMut<'_> {
+        TicksMut {
+            added: &mut self.added,
+            changed: &mut self.changed,
+            last_run,
+            this_run,
+        }
+    }
+}
+
 // -----------------------------------------------------------------------------
 // DetectChanges
 
 /// Change-detection trait for components and resources.
 ///
 /// Types implementing this trait can report when they were inserted and when
-/// they were most recently modified.
+/// they were most recently modified. It is implemented by [`Ref`], [`Mut`],
+/// [`ResRef`], [`ResMut`], [`NonSendRef`] and [`NonSendMut`], so code such as
+/// networking or persistence can inspect ticks without knowing the wrapper.
+/// The thin [`Res`] and [`NonSend`] do not carry ticks and do not implement it.
 ///
 /// See [`vc_ecs::borrow`](crate::borrow) for more infomation.
+///
+/// [`Ref`]: crate::borrow::Ref
+/// [`Mut`]: crate::borrow::Mut
+/// [`Res`]: crate::borrow::Res
+/// [`ResRef`]: crate::borrow::ResRef
+/// [`ResMut`]: crate::borrow::ResMut
+/// [`NonSend`]: crate::borrow::NonSend
+/// [`NonSendRef`]: crate::borrow::NonSendRef
+/// [`NonSendMut`]: crate::borrow::NonSendMut
 pub trait DetectChanges {
     /// Returns `true` if this value was added after the system last ran.
     fn is_added(&self) -> bool;
@@ -225,6 +362,93 @@ pub trait DetectChanges {
     ///
     /// Note that components and resources are also marked as changed upon insertion.
     fn changed_tick(&self) -> Tick;
+
+    /// Returns the tick of the most recent change, the same as [`changed_tick`].
+    ///
+    /// This is the counterpart of [`DetectChangesMut::set_last_changed`].
+    ///
+    /// [`changed_tick`]: Self::changed_tick
+    #[inline]
+    fn last_changed(&self) -> Tick {
+        self.changed_tick()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// DetectChangesMut
+
+/// Change-detection trait for mutable access to components and resources.
+///
+/// Implemented by [`Mut`], [`ResMut`] and [`NonSendMut`]. Besides marking the
+/// value as changed, it allows writing the change tick directly, e.g. to
+/// restore the ticks of replicated or loaded data.
+///
+/// # Examples
+///
+/// ```
+/// use vc_ecs::prelude::*;
+/// use vc_ecs::tick::DetectChangesMut;
+///
+/// #[derive(Resource, PartialEq)]
+/// struct Score(u32);
+///
+/// let mut world = World::default();
+/// world.insert_resource(Score(0));
+/// world.update_tick();
+///
+/// let mut score = world.get_resource_mut::<Score>().unwrap();
+/// assert!(!score.set_if_neq(Score(0)));
+/// assert!(!score.is_changed());
+///
+/// let synced = score.added_tick();
+/// score.set_last_changed(synced);
+/// assert!(!score.is_changed());
+///
+/// score.set_changed();
+/// assert!(score.is_changed());
+/// ```
+///
+/// [`Mut`]: crate::borrow::Mut
+/// [`ResMut`]: crate::borrow::ResMut
+/// [`NonSendMut`]: crate::borrow::NonSendMut
+pub trait DetectChangesMut: DetectChanges {
+    /// The type of the referenced value.
+    type Inner: ?Sized;
+
+    /// Marks the value as changed in the current run.
+    ///
+    /// This is done automatically by mutable dereferencing.
+    fn set_changed(&mut self);
+
+    /// Overwrites the change tick of the value.
+    ///
+    /// Unlike [`set_changed`](Self::set_changed), this can move the tick
+    /// backwards, e.g. to hide a change made by a synchronization step.
+    fn set_last_changed(&mut self, last_changed: Tick);
+
+    /// Returns the inner reference without marking the value as changed.
+    ///
+    /// Changes made through it are invisible to change detection.
+    fn bypass_change_detection(&mut self) -> &mut Self::Inner;
+
+    /// Overwrites the value with `value` if they differ, only marking
+    /// the value as changed in this case.
+    ///
+    /// Returns `true` if the value was overwritten.
+    #[inline]
+    fn set_if_neq(&mut self, value: Self::Inner) -> bool
+    where
+        Self::Inner: Sized + PartialEq,
+    {
+        let inner = self.bypass_change_detection();
+        if *inner == value {
+            false
+        } else {
+            *inner = value;
+            self.set_changed();
+            true
+        }
+    }
 }
 
 // -----------------------------------------------------------------------------
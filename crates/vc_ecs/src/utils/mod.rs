@@ -6,6 +6,10 @@ mod debug_name;
 mod debug_unwrap;
 mod dropper;
 
+crate::cfg::reflect! {
+    mod reflector;
+}
+
 // -----------------------------------------------------------------------------
 // Exports
 
@@ -14,6 +18,10 @@ pub use debug_name::DebugName;
 pub use debug_unwrap::DebugCheckedUnwrap;
 pub use dropper::Dropper;
 
+crate::cfg::reflect! {
+    pub use reflector::Reflector;
+}
+
 // -----------------------------------------------------------------------------
 // Inline
 
@@ -0,0 +1,74 @@
+// -----------------------------------------------------------------------------
+// Reflector
+
+use vc_reflect::Reflect;
+use vc_reflect::registry::{GetTypeMeta, TypeRegistry};
+
+use crate::component::{Component, ReflectComponent, ReflectMapEntities};
+use crate::resource::{ReflectResource, Resource};
+
+/// Type-erased function that registers the reflection data of a component or resource.
+///
+/// Besides the [`TypeMeta`] of the type itself, the ECS type trait
+/// ([`ReflectComponent`] or [`ReflectResource`]) is registered, so that the
+/// value can be accessed from a [`World`] through the [`TypeRegistry`].
+///
+/// See [`World::register_reflect_types`] for usage.
+///
+/// [`TypeMeta`]: vc_reflect::registry::TypeMeta
+/// [`World`]: crate::world::World
+/// [`World::register_reflect_types`]: crate::world::World::register_reflect_types
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+pub struct Reflector {
+    func: fn(&mut TypeRegistry),
+}
+
+impl Reflector {
+    fn register_component<T: Component + Reflect + GetTypeMeta>(registry: &mut TypeRegistry) {
+        registry
+            .register::<T>()
+            .register_type_trait::<T, ReflectComponent>();
+    }
+
+    fn register_component_with_entities<T: Component + Reflect + GetTypeMeta>(
+        registry: &mut TypeRegistry,
+    ) {
+        Self::register_component::<T>(registry);
+        registry.register_type_trait::<T, ReflectMapEntities>();
+    }
+
+    fn register_resource<T: Resource + Reflect + GetTypeMeta>(registry: &mut TypeRegistry) {
+        registry
+            .register::<T>()
+            .register_type_trait::<T, ReflectResource>();
+    }
+
+    /// Creates a reflector for component `T`.
+    pub const fn component<T: Component + Reflect + GetTypeMeta>() -> Self {
+        Self {
+            func: Self::register_component::<T>,
+        }
+    }
+
+    /// Creates a reflector for component `T` referencing other entities,
+    /// which also registers [`ReflectMapEntities`].
+    pub const fn component_with_entities<T: Component + Reflect + GetTypeMeta>() -> Self {
+        Self {
+            func: Self::register_component_with_entities::<T>,
+        }
+    }
+
+    /// Creates a reflector for resource `T`.
+    pub const fn resource<T: Resource + Reflect + GetTypeMeta>() -> Self {
+        Self {
+            func: Self::register_resource::<T>,
+        }
+    }
+
+    /// Registers the type and its ECS type trait into `registry`.
+    #[inline(always)]
+    pub fn call(self, registry: &mut TypeRegistry) {
+        (self.func)(registry);
+    }
+}
@@ -1,9 +1,10 @@
 use vc_ptr::OwningPtr;
 
-use crate::archetype::ArcheId;
-use crate::bundle::Bundle;
-use crate::component::ComponentWriter;
+use crate::archetype::{ArcheFlags, ArcheId};
+use crate::bundle::{Bundle, BundleId};
+use crate::component::{ComponentId, ComponentWriter, InsertMode};
 use crate::tick::Tick;
+use crate::utils::DebugCheckedUnwrap;
 use crate::world::EntityOwned;
 
 impl EntityOwned<'_> {
@@ -16,6 +17,10 @@ impl EntityOwned<'_> {
     /// If need required components will be create,
     /// but will not overwrite existing components.
     ///
+    /// The `ON_ADD` hooks of newly added components are invoked after insertion.
+    /// Overwriting an existing component invokes only its `ON_REPLACE` hook,
+    /// after the new value is written.
+    ///
     /// # Examples
     ///
     /// ```
@@ -34,25 +39,126 @@ impl EntityOwned<'_> {
     /// entity.insert(Bar);
     /// assert!(entity.contains::<Bar>());
     /// ```
+    #[inline]
     pub fn insert<B: Bundle>(&mut self, bundle: B) {
+        self.insert_with_mode(bundle, InsertMode::Replace);
+    }
+
+    /// Insert components that the entity does not contain yet.
+    ///
+    /// Existing components are kept and the corresponding values of
+    /// the bundle are dropped. Other rules are the same as [`insert`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_ecs::world::World;
+    /// # use vc_ecs::component::Component;
+    /// # #[derive(Component, Debug, PartialEq)]
+    /// # struct Foo(u32);
+    /// # #[derive(Component, Debug, PartialEq)]
+    /// # struct Bar(u32);
+    /// let mut world = World::default();
+    ///
+    /// let mut entity = world.spawn(Foo(1));
+    /// entity.insert_if_new((Foo(2), Bar(2)));
+    ///
+    /// assert_eq!(entity.get::<Foo>(), Some(&Foo(1)));
+    /// assert_eq!(entity.get::<Bar>(), Some(&Bar(2)));
+    /// ```
+    ///
+    /// [`insert`]: Self::insert
+    #[inline]
+    pub fn insert_if_new<B: Bundle>(&mut self, bundle: B) {
+        self.insert_with_mode(bundle, InsertMode::Keep);
+    }
+
+    /// Insert components, `mode` decides whether existing components are
+    /// overwritten, see [`InsertMode`].
+    ///
+    /// This is useful for code that applies the same data in different
+    /// ways, such as prefabs merging into existing entities.
+    pub fn insert_with_mode<B: Bundle>(&mut self, bundle: B, mode: InsertMode) {
         let world = unsafe { self.world.full_mut() };
         let bundle_id = world.register_bundle::<B>();
-        let old_arche_id = self.location.arche_id;
-        let new_arche_id = world.arche_after_insert(old_arche_id, bundle_id);
+        let new_arche_id = world.arche_after_insert(self.location.arche_id, bundle_id);
 
         vc_ptr::into_owning!(bundle);
 
+        unsafe {
+            self.insert_into(
+                bundle,
+                bundle_id,
+                new_arche_id,
+                mode,
+                B::write_explicit,
+                B::write_required,
+            );
+        }
+    }
+
+    /// Writes the bundle of `data` into the entity, moving it to `new_arche_id`
+    /// if that differs from its current archetype.
+    ///
+    /// `mode` decides whether explicit components overwrite existing ones.
+    ///
+    /// # Safety
+    /// - `data` must point to a bundle registered as `bundle_id`.
+    /// - `new_arche_id` must be the result of [`World::arche_after_insert`]
+    ///   for the current archetype and `bundle_id`.
+    /// - `write_explicit` and `write_required` must belong to that bundle.
+    ///
+    /// [`World::arche_after_insert`]: crate::world::World::arche_after_insert
+    #[inline(always)]
+    pub(crate) unsafe fn insert_into(
+        &mut self,
+        data: OwningPtr<'_>,
+        bundle_id: BundleId,
+        new_arche_id: ArcheId,
+        mode: InsertMode,
+        write_explicit: unsafe fn(&mut ComponentWriter, usize),
+        write_required: unsafe fn(&mut ComponentWriter),
+    ) {
+        let old_arche_id = self.location.arche_id;
+        let world = unsafe { self.world.read_only() };
+        let old_arche = unsafe { world.archetypes.get_unchecked(old_arche_id) };
+        let replace_hooks =
+            mode == InsertMode::Replace && old_arche.flags().contains(ArcheFlags::ON_REPLACE_HOOK);
+
         if old_arche_id == new_arche_id {
-            self.insert_local(bundle, B::write_explicit);
+            self.insert_local(data, mode, write_explicit);
         } else {
-            self.insert_moved(bundle, new_arche_id, B::write_explicit, B::write_required);
+            self.insert_moved(data, new_arche_id, mode, write_explicit, write_required);
         }
+
+        if replace_hooks {
+            let world = unsafe { self.world.read_only() };
+            world.trigger_on_replace(self.entity, self.replaced(bundle_id, old_arche_id));
+        }
+    }
+
+    /// Returns the explicit components of the bundle that already existed
+    /// in the old archetype, i.e. the components overwritten in replace mode.
+    fn replaced(
+        &self,
+        bundle_id: BundleId,
+        old_arche_id: ArcheId,
+    ) -> impl Iterator<Item = ComponentId> + use<'_> {
+        let world = unsafe { self.world.read_only() };
+        let bundle = unsafe { world.bundles.get_unchecked(bundle_id) };
+        let old_arche = unsafe { world.archetypes.get_unchecked(old_arche_id) };
+        bundle
+            .components()
+            .iter()
+            .copied()
+            .filter(move |&id| old_arche.contains_component(id))
     }
 
     #[inline(never)]
     fn insert_local(
         &mut self,
         data: OwningPtr<'_>,
+        mode: InsertMode,
         write_explicit: unsafe fn(&mut ComponentWriter, usize),
     ) {
         let world = unsafe { self.world.data_mut() };
@@ -71,7 +177,8 @@ impl EntityOwned<'_> {
 
         unsafe {
             let mut writer =
-                ComponentWriter::new(data, entity, table_row, tick, maps, table, components);
+                ComponentWriter::new(data, &entity, table_row, tick, maps, table, components);
+            writer.set_mode(mode);
             arche.components().iter().for_each(|&id| {
                 writer.set_writed(id);
             });
@@ -85,11 +192,10 @@ impl EntityOwned<'_> {
         &mut self,
         data: OwningPtr<'_>,
         new_arche_id: ArcheId,
+        mode: InsertMode,
         write_explicit: unsafe fn(&mut ComponentWriter, usize),
         write_required: unsafe fn(&mut ComponentWriter),
     ) {
-        let tick = Tick::new(unsafe { *self.world.full_mut().this_run.get_mut() });
-
         let old_arche_id = self.location.arche_id;
         let old_arche = unsafe {
             self.world
@@ -142,6 +248,48 @@ impl EntityOwned<'_> {
         }
 
         let world = unsafe { self.world.data_mut() };
+        let maps = &mut world.storages.maps;
+        new_arche.sparse_components().iter().for_each(|&id| {
+            if !old_arche.contains_sparse_component(id) {
+                let map_id = unsafe { maps.get_id(id).debug_checked_unwrap() };
+                let map = unsafe { maps.get_unchecked_mut(map_id) };
+                let _ = unsafe { map.allocate(self.entity) };
+            }
+        });
+
+        unsafe {
+            world
+                .entities
+                .update_location(self.entity, self.location)
+                .unwrap();
+        }
+
+        unsafe {
+            self.write_moved(data, old_arche_id, mode, write_explicit, write_required);
+        }
+    }
+
+    /// Writes the bundle of `data` into an entity that has been moved from
+    /// `old_arche_id` to its current archetype, then invokes the `ON_ADD`
+    /// hooks of the added components.
+    ///
+    /// # Safety
+    /// - The entity must be located in its new archetype, with the components
+    ///   missing from `old_arche_id` allocated but uninitialized.
+    /// - The other arguments must satisfy [`EntityOwned::insert_into`] for
+    ///   `old_arche_id` and the current archetype.
+    pub(crate) unsafe fn write_moved(
+        &mut self,
+        data: OwningPtr<'_>,
+        old_arche_id: ArcheId,
+        mode: InsertMode,
+        write_explicit: unsafe fn(&mut ComponentWriter, usize),
+        write_required: unsafe fn(&mut ComponentWriter),
+    ) {
+        let world = unsafe { self.world.data_mut() };
+        let tick = Tick::new(*world.this_run.get_mut());
+        let new_arche_id = self.location.arche_id;
+
         let old_arche = unsafe { world.archetypes.get_unchecked(old_arche_id) };
         let table_row = self.location.table_row;
         let table_id = self.location.table_id;
@@ -152,7 +300,8 @@ impl EntityOwned<'_> {
 
         unsafe {
             let mut writer =
-                ComponentWriter::new(data, entity, table_row, tick, maps, table, components);
+                ComponentWriter::new(data, &entity, table_row, tick, maps, table, components);
+            writer.set_mode(mode);
             old_arche.components().iter().for_each(|&id| {
                 writer.set_writed(id);
             });
@@ -161,11 +310,15 @@ impl EntityOwned<'_> {
             write_required(&mut writer);
         }
 
-        unsafe {
-            world
-                .entities
-                .update_location(self.entity, self.location)
-                .unwrap();
+        let world = unsafe { self.world.read_only() };
+        let new_arche = unsafe { world.archetypes.get_unchecked(new_arche_id) };
+        if new_arche.flags().contains(ArcheFlags::ON_ADD_HOOK) {
+            let old_arche = unsafe { world.archetypes.get_unchecked(old_arche_id) };
+            let added = new_arche.components().iter().copied();
+            world.trigger_on_add(
+                self.entity,
+                added.filter(|&id| !old_arche.contains_component(id)),
+            );
         }
     }
 }
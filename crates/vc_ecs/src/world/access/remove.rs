@@ -1,4 +1,4 @@
-use crate::archetype::ArcheId;
+use crate::archetype::{ArcheFlags, ArcheId};
 use crate::bundle::Bundle;
 use crate::utils::DebugCheckedUnwrap;
 use crate::world::EntityOwned;
@@ -22,6 +22,8 @@ impl EntityOwned<'_> {
     /// For example, given an entity `(A, B, C)` where `B` requires `A`:
     /// You cannot remove only `A`. When removing `B`, `A` will be automatically removed.
     ///
+    /// The `ON_REMOVE` hooks of removed components are invoked before removal.
+    ///
     /// # Examples
     ///
     /// ```
@@ -53,6 +55,18 @@ impl EntityOwned<'_> {
     #[inline(never)]
     fn remove_moved(&mut self, new_arche_id: ArcheId) {
         let old_arche_id = self.location.arche_id;
+
+        let world = unsafe { self.world.read_only() };
+        let old_arche = unsafe { world.archetypes.get_unchecked(old_arche_id) };
+        if old_arche.flags().contains(ArcheFlags::ON_REMOVE_HOOK) {
+            let new_arche = unsafe { world.archetypes.get_unchecked(new_arche_id) };
+            let removed = old_arche.components().iter().copied();
+            world.trigger_on_remove(
+                self.entity,
+                removed.filter(|&id| !new_arche.contains_component(id)),
+            );
+        }
+
         let old_arche = unsafe {
             self.world
                 .full_mut()
@@ -0,0 +1,87 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use serde::Serialize;
+
+// -----------------------------------------------------------------------------
+// WorldDiagnostics
+
+/// A snapshot of storage and scheduling statistics of a [`World`].
+///
+/// Created by [`World::diagnostics`]. All fields are plain data, so the
+/// snapshot can be logged, compared or serialized by external tools.
+///
+/// [`World`]: crate::world::World
+/// [`World::diagnostics`]: crate::world::World::diagnostics
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct WorldDiagnostics {
+    /// The number of spawned entities.
+    pub entity_count: usize,
+    /// The number of archetypes, including the empty archetype.
+    pub archetype_count: usize,
+    /// The number of dense tables, including the empty table.
+    pub table_count: usize,
+    /// Statistics of every registered component, ordered by `ComponentId`.
+    pub components: Vec<ComponentDiagnostics>,
+    /// Statistics of every compiled system in the `Schedules` resource.
+    ///
+    /// Empty if the resource does not exist or the snapshot was not
+    /// taken on the world's main thread.
+    pub systems: Vec<SystemDiagnostics>,
+}
+
+impl WorldDiagnostics {
+    /// Returns the total bytes allocated for component data,
+    /// including change detection ticks.
+    pub fn total_bytes(&self) -> usize {
+        self.components
+            .iter()
+            .map(|c| c.table_bytes + c.map_bytes)
+            .sum()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// ComponentDiagnostics
+
+/// Storage statistics of a single component type.
+///
+/// Byte counts are based on allocated capacity rather than the number
+/// of stored entities, and include the added/changed ticks.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ComponentDiagnostics {
+    /// The index of the `ComponentId`.
+    pub id: usize,
+    /// The type name, or a placeholder if the `debug` feature is disabled.
+    pub name: String,
+    /// `true` for sparse storage, `false` for dense storage.
+    pub sparse: bool,
+    /// The number of tables containing this component.
+    pub table_count: usize,
+    /// The number of entities stored in tables.
+    pub table_len: usize,
+    /// The bytes allocated in tables.
+    pub table_bytes: usize,
+    /// The number of entities stored in the sparse map.
+    pub map_len: usize,
+    /// The number of rows allocated by the sparse map.
+    pub map_capacity: usize,
+    /// The bytes allocated by the sparse map, excluding the entity index.
+    pub map_bytes: usize,
+}
+
+// -----------------------------------------------------------------------------
+// SystemDiagnostics
+
+/// Timing statistics of a single system.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct SystemDiagnostics {
+    /// The debug representation of the schedule label.
+    pub schedule: String,
+    /// The system name.
+    pub name: String,
+    /// The duration of the last run, `None` if the system has not run
+    /// yet or timing is unavailable (without `std`).
+    pub last_run: Option<Duration>,
+}
@@ -0,0 +1,43 @@
+use super::World;
+
+/// Creates a value from the [`World`].
+///
+/// This is the constructor used by [`World::init_resource`], so resources
+/// can read other resources or register components when they are created.
+///
+/// It is implemented for every type that implements [`Default`].
+///
+/// # Examples
+///
+/// ```
+/// use vc_ecs::prelude::*;
+/// use vc_ecs::world::FromWorld;
+///
+/// #[derive(Resource)]
+/// struct TileSize(u32);
+///
+/// #[derive(Resource)]
+/// struct TileBuffer(Vec<u8>);
+///
+/// impl FromWorld for TileBuffer {
+///     fn from_world(world: &mut World) -> Self {
+///         let size = world.get_resource::<TileSize>().map_or(16, |size| size.0);
+///         TileBuffer(vec![0; (size * size) as usize])
+///     }
+/// }
+///
+/// let mut world = World::default();
+/// world.insert_resource(TileSize(4));
+/// assert_eq!(world.init_resource::<TileBuffer>().0.len(), 16);
+/// ```
+pub trait FromWorld {
+    /// Creates `Self` from the given world.
+    fn from_world(world: &mut World) -> Self;
+}
+
+impl<T: Default> FromWorld for T {
+    #[inline]
+    fn from_world(_world: &mut World) -> Self {
+        T::default()
+    }
+}
@@ -69,7 +69,9 @@ impl World {
         };
 
         unsafe {
-            let result = self.archetypes.register(table_id, dense_len, components);
+            let result =
+                self.archetypes
+                    .register(&self.components, table_id, dense_len, components);
             let arche = self.archetypes.get_unchecked_mut(arche_id);
             arche.set_after_insert(bundle_id, result);
             result
@@ -159,7 +161,9 @@ impl World {
         };
 
         unsafe {
-            let result = self.archetypes.register(table_id, dense_len, components);
+            let result =
+                self.archetypes
+                    .register(&self.components, table_id, dense_len, components);
             let arche = self.archetypes.get_unchecked_mut(arche_id);
             arche.set_after_remove(bundle_id, result);
             result
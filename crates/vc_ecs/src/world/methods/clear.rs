@@ -0,0 +1,162 @@
+use alloc::vec::Vec;
+
+use crate::archetype::ArcheFlags;
+use crate::entity::Entity;
+use crate::world::World;
+
+impl World {
+    /// Despawns all entities, keeping resources and registrations.
+    ///
+    /// This is intended for bulk teardown, such as unloading a level:
+    /// - Invokes the `ON_REMOVE` hooks of all components.
+    /// - Drops the components of each table and sparse map at once,
+    ///   instead of removing entities one by one.
+    /// - Releases all entity ids back to the allocator.
+    ///
+    /// Components, archetypes and tables stay registered with their
+    /// allocated capacity, so that the next level can reuse them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_ecs::component::Component;
+    /// # use vc_ecs::resource::Resource;
+    /// # use vc_ecs::world::World;
+    /// # #[derive(Component)]
+    /// # struct Foo;
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// let mut world = World::default();
+    /// let entity = world.spawn(Foo).entity();
+    /// world.insert_resource(Score(10));
+    ///
+    /// world.clear_entities();
+    /// assert_eq!(world.entity_count(), 0);
+    /// assert!(world.despawn(entity).is_err());
+    /// assert!(world.get_resource::<Score>().is_some());
+    /// ```
+    pub fn clear_entities(&mut self) {
+        self.archetypes.iter().for_each(|archetype| {
+            if archetype.flags().contains(ArcheFlags::ON_REMOVE_HOOK) {
+                archetype.entities().iter().for_each(|&entity| {
+                    self.trigger_on_remove(entity, archetype.components().iter().copied());
+                });
+            }
+        });
+
+        let mut freed: Vec<Entity> = Vec::new();
+        self.archetypes.iter_mut().for_each(|archetype| {
+            archetype.entities().iter().for_each(|&entity| unsafe {
+                if self.entities.set_despawned(entity).is_ok() {
+                    freed.extend(self.entities.free(entity.id(), 1));
+                }
+            });
+            archetype.clear_entities();
+        });
+
+        self.storages.tables.tables.iter_mut().for_each(|table| {
+            table.clear();
+        });
+        self.storages.maps.maps.iter_mut().for_each(|map| {
+            map.clear();
+        });
+
+        self.allocator.free_many(&freed);
+    }
+
+    /// Drops all resources, keeping them registered.
+    ///
+    /// # Panics
+    /// Panics if called from a thread other than the world's main thread,
+    /// as `!Send` resources may be dropped.
+    pub fn clear_resources(&mut self) {
+        assert! {
+            self.thread_hash == crate::utils::thread_hash(),
+            "!Send Resource can only be inserted/removed on the main thread.",
+        }
+
+        unsafe {
+            self.storages.res.clear();
+        }
+    }
+
+    /// Despawns all entities and drops all resources.
+    ///
+    /// Registrations are kept, see [`clear_entities`](Self::clear_entities)
+    /// and [`clear_resources`](Self::clear_resources).
+    ///
+    /// # Panics
+    /// Panics if called from a thread other than the world's main thread.
+    pub fn clear_all(&mut self) {
+        self.clear_entities();
+        self.clear_resources();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::component::{Component, ComponentStorage};
+    use crate::name::Name;
+    use crate::resource::Resource;
+    use crate::world::{World, WorldIdAllocator};
+
+    static DENSE: AtomicUsize = AtomicUsize::new(0);
+    static SPARSE: AtomicUsize = AtomicUsize::new(0);
+
+    struct Dense;
+    struct Sparse;
+
+    impl Component for Dense {}
+    impl Component for Sparse {
+        const STORAGE: ComponentStorage = ComponentStorage::Sparse;
+    }
+
+    impl Drop for Dense {
+        fn drop(&mut self) {
+            DENSE.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    impl Drop for Sparse {
+        fn drop(&mut self) {
+            SPARSE.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[derive(Resource)]
+    struct Score;
+
+    #[test]
+    fn clear_world() {
+        let mut world = World::new(WorldIdAllocator::new().alloc());
+
+        let mut entities = alloc::vec::Vec::new();
+        for _ in 0..10 {
+            entities.push(world.spawn(Dense).entity());
+            entities.push(world.spawn((Dense, Sparse, Name::new("Clear"))).entity());
+            entities.push(world.spawn(Sparse).entity());
+        }
+        world.insert_resource(Score);
+
+        world.clear_entities();
+        assert_eq!(DENSE.load(Ordering::SeqCst), 20);
+        assert_eq!(SPARSE.load(Ordering::SeqCst), 20);
+        assert_eq!(world.entity_count(), 0);
+        assert!(world.names().is_empty());
+        assert!(entities.iter().all(|&e| world.despawn(e).is_err()));
+        assert!(world.get_resource::<Score>().is_some());
+
+        // Storages are reusable.
+        let entity = world.spawn((Dense, Sparse)).entity();
+        assert!(world.entity_ref(entity).contains::<Sparse>());
+        world.despawn(entity).unwrap();
+        assert_eq!(SPARSE.load(Ordering::SeqCst), 21);
+
+        world.spawn(Dense);
+        world.clear_all();
+        assert_eq!(DENSE.load(Ordering::SeqCst), 22);
+        assert!(world.get_resource::<Score>().is_none());
+    }
+}
@@ -1,4 +1,8 @@
-use crate::entity::{Entity, EntityError};
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+use crate::archetype::ArcheFlags;
+use crate::entity::{Entity, EntityError, EntityLocation};
 use crate::utils::DebugCheckedUnwrap;
 use crate::world::World;
 
@@ -6,6 +10,7 @@ impl World {
     /// Despawns an entity and removes all of its components.
     ///
     /// This operation:
+    /// - Invokes the `ON_REMOVE` hooks of all components.
     /// - Marks the entity as despawned in the entity registry.
     /// - Removes the entity row from its archetype and table.
     /// - Drops sparse-component values associated with that entity.
@@ -33,6 +38,71 @@ impl World {
     /// assert!(world.despawn(entity).is_err());
     /// ```
     pub fn despawn(&mut self, entity: Entity) -> Result<(), EntityError> {
+        if let Ok(location) = self.entities.locate(entity) {
+            let archetype = unsafe { self.archetypes.get_unchecked(location.arche_id) };
+            if archetype.flags().contains(ArcheFlags::ON_REMOVE_HOOK) {
+                self.trigger_on_remove(entity, archetype.components().iter().copied());
+            }
+        }
+
+        self.despawn_without_hooks(entity)
+    }
+
+    /// Despawns a batch of entities and removes all of their components,
+    /// returning the number of despawned entities.
+    ///
+    /// Entities that are invalid, not spawned or repeated are skipped.
+    ///
+    /// The `ON_REMOVE` hooks of all entities are invoked first, grouped by
+    /// archetype, so a hook can still access the other entities of the batch.
+    /// The entities are then removed one by one like with [`World::despawn`],
+    /// each table from its last row backwards.
+    ///
+    /// Only the given entities are despawned, there is no cleanup of
+    /// children or relationships.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_ecs::component::Component;
+    /// # use vc_ecs::world::World;
+    /// # #[derive(Component, Debug)]
+    /// # struct Foo;
+    /// #
+    /// # let mut world = World::default();
+    /// let a = world.spawn(Foo).entity();
+    /// let b = world.spawn(Foo).entity();
+    /// assert_eq!(world.despawn_batch([a, b, a]), 2);
+    /// assert_eq!(world.despawn_batch([a]), 0);
+    /// ```
+    pub fn despawn_batch(&mut self, entities: impl IntoIterator<Item = Entity>) -> usize {
+        let mut located: Vec<(Entity, EntityLocation)> = entities
+            .into_iter()
+            .filter_map(|entity| Some((entity, self.entities.locate(entity).ok()?)))
+            .collect();
+
+        located.sort_unstable_by_key(|(entity, location)| (location.arche_id, entity.to_bits()));
+        located.dedup_by_key(|(entity, _)| *entity);
+
+        for group in located.chunk_by(|(_, a), (_, b)| a.arche_id == b.arche_id) {
+            let archetype = unsafe { self.archetypes.get_unchecked(group[0].1.arche_id) };
+            if archetype.flags().contains(ArcheFlags::ON_REMOVE_HOOK) {
+                group.iter().for_each(|&(entity, _)| {
+                    self.trigger_on_remove(entity, archetype.components().iter().copied());
+                });
+            }
+        }
+
+        located
+            .sort_unstable_by_key(|(_, location)| (location.table_id, Reverse(location.table_row)));
+        located
+            .into_iter()
+            .filter(|&(entity, _)| self.despawn_without_hooks(entity).is_ok())
+            .count()
+    }
+
+    /// Removes the entity and drops its components, hooks are not invoked.
+    fn despawn_without_hooks(&mut self, entity: Entity) -> Result<(), EntityError> {
         let location = unsafe { self.entities.set_despawned(entity)? };
 
         let arche_id = location.arche_id;
@@ -56,8 +126,9 @@ impl World {
                 map.drop_item(map_row);
             });
 
-        let new_entity = unsafe { self.entities.free(entity.id(), 1) };
-        self.allocator.free(new_entity);
+        if let Some(new_entity) = unsafe { self.entities.free(entity.id(), 1) } {
+            self.allocator.free(new_entity);
+        }
 
         let res1 = unsafe { self.entities.update_row(arche_moved) };
         let res2 = unsafe { self.entities.update_row(table_moved) };
@@ -67,9 +138,11 @@ impl World {
 
 #[cfg(test)]
 mod tests {
-    use crate::component::{Component, ComponentStorage};
+    use crate::component::{Component, ComponentHook, ComponentStorage};
+    use crate::entity::Entity;
     use crate::world::{World, WorldIdAllocator};
     use alloc::string::String;
+    use alloc::vec::Vec;
     use core::sync::atomic::{AtomicUsize, Ordering};
 
     #[derive(Debug, PartialEq, Eq)]
@@ -163,6 +236,45 @@ mod tests {
         assert_eq!(DROP_COUNTER.load(Ordering::SeqCst), 2);
     }
 
+    #[test]
+    fn despawn_batch() {
+        static REMOVED: AtomicUsize = AtomicUsize::new(0);
+
+        fn on_remove(world: &World, _entity: Entity) {
+            // All entities of the batch are still alive when hooks run.
+            assert_eq!(world.entities().len(), 5);
+            REMOVED.fetch_add(1, Ordering::SeqCst);
+        }
+
+        struct Hooked;
+        impl Component for Hooked {
+            const ON_REMOVE: Option<ComponentHook> = Some(on_remove);
+        }
+
+        let mut world = World::new(WorldIdAllocator::new().alloc());
+        let entities: Vec<Entity> = (0..5)
+            .map(|i| match i % 3 {
+                0 => world.spawn((Hooked, Bar(i))).entity,
+                1 => world.spawn((Bar(i), Baz(String::from("baz")))).entity,
+                _ => world.spawn(Bar(i)).entity,
+            })
+            .collect();
+
+        let batch = [
+            entities[4],
+            entities[0],
+            entities[1],
+            entities[0],
+            entities[3],
+        ];
+        assert_eq!(world.despawn_batch(batch), 4);
+        assert_eq!(REMOVED.load(Ordering::SeqCst), 2);
+
+        assert!(world.despawn(entities[0]).is_err());
+        assert_eq!(world.entity_ref(entities[2]).get::<Bar>(), Some(&Bar(2)));
+        assert_eq!(world.despawn_batch(entities), 1);
+    }
+
     #[test]
     fn drop_world() {
         static DENSE_COUNTER: AtomicUsize = AtomicUsize::new(0);
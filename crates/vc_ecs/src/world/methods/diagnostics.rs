@@ -0,0 +1,97 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::mem::size_of;
+
+use crate::schedule::Schedules;
+use crate::tick::Tick;
+use crate::world::{ComponentDiagnostics, SystemDiagnostics, World, WorldDiagnostics};
+
+impl World {
+    /// Collects a snapshot of storage and scheduling statistics.
+    ///
+    /// This walks all tables and sparse maps, so it is intended for
+    /// debugging and profiling tools rather than per-frame use.
+    ///
+    /// System timings are read from the [`Schedules`] non-send resource.
+    /// They are only collected on the world's main thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vc_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Foo(u64);
+    ///
+    /// let mut world = World::default();
+    /// world.spawn(Foo(1));
+    /// world.spawn(Foo(2));
+    ///
+    /// let diagnostics = world.diagnostics();
+    /// assert_eq!(diagnostics.entity_count, 2);
+    ///
+    /// let foo = diagnostics.components.iter().find(|c| c.table_len == 2).unwrap();
+    /// assert!(foo.table_bytes >= 2 * size_of::<u64>());
+    /// ```
+    pub fn diagnostics(&self) -> WorldDiagnostics {
+        const TICKS_SIZE: usize = 2 * size_of::<Tick>();
+
+        let components = self.components();
+        let tables = &self.storages.tables;
+        let maps = &self.storages.maps;
+
+        let mut result: Vec<ComponentDiagnostics> = components
+            .iter()
+            .map(|info| ComponentDiagnostics {
+                id: info.id().index(),
+                name: info.debug_name().parse(),
+                sparse: info.storage().is_sparse(),
+                ..Default::default()
+            })
+            .collect();
+
+        tables.iter().for_each(|table| {
+            let capacity = table.capacity();
+            table.components().iter().for_each(|&id| {
+                let size = components.get(id).unwrap().layout().size();
+                let item = &mut result[id.index()];
+                item.table_count += 1;
+                item.table_len += table.entity_count();
+                item.table_bytes += capacity * (size + TICKS_SIZE);
+            });
+        });
+
+        maps.iter().for_each(|(id, map)| {
+            let size = components.get(id).unwrap().layout().size();
+            let item = &mut result[id.index()];
+            item.map_len = map.len();
+            item.map_capacity = map.capacity();
+            item.map_bytes = map.capacity() * (size + TICKS_SIZE);
+        });
+
+        let mut systems = Vec::new();
+        if self.thread_hash == crate::utils::thread_hash()
+            && let Some(schedules) = self.get_non_send_ref::<Schedules>()
+        {
+            schedules.iter().for_each(|(label, schedule)| {
+                let label = format!("{label:?}");
+                systems.extend(schedule.last_run_durations().map(|(name, last_run)| {
+                    SystemDiagnostics {
+                        schedule: label.clone(),
+                        name: String::from(name),
+                        last_run,
+                    }
+                }));
+            });
+        }
+
+        WorldDiagnostics {
+            entity_count: self.entity_count(),
+            archetype_count: self.archetypes().len(),
+            table_count: tables.len(),
+            components: result,
+            systems,
+        }
+    }
+}
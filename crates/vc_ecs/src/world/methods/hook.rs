@@ -0,0 +1,133 @@
+use crate::component::ComponentId;
+use crate::entity::Entity;
+use crate::world::World;
+
+impl World {
+    /// Invokes the `ON_ADD` hooks of the given components.
+    pub(crate) fn trigger_on_add(
+        &self,
+        entity: Entity,
+        ids: impl IntoIterator<Item = ComponentId>,
+    ) {
+        ids.into_iter().for_each(|id| {
+            let info = unsafe { self.components.get_unchecked(id) };
+            if let Some(hook) = info.on_add() {
+                hook(self, entity);
+            }
+        });
+    }
+
+    /// Invokes the `ON_REPLACE` hooks of the given components.
+    pub(crate) fn trigger_on_replace(
+        &self,
+        entity: Entity,
+        ids: impl IntoIterator<Item = ComponentId>,
+    ) {
+        ids.into_iter().for_each(|id| {
+            let info = unsafe { self.components.get_unchecked(id) };
+            if let Some(hook) = info.on_replace() {
+                hook(self, entity);
+            }
+        });
+    }
+
+    /// Invokes the `ON_REMOVE` hooks of the given components.
+    pub(crate) fn trigger_on_remove(
+        &self,
+        entity: Entity,
+        ids: impl IntoIterator<Item = ComponentId>,
+    ) {
+        ids.into_iter().for_each(|id| {
+            let info = unsafe { self.components.get_unchecked(id) };
+            if let Some(hook) = info.on_remove() {
+                hook(self, entity);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::command::Commands;
+    use crate::component::Component;
+    use crate::entity::Entity;
+    use crate::world::{World, WorldIdAllocator};
+
+    static ADDED: AtomicUsize = AtomicUsize::new(0);
+    static REMOVED: AtomicUsize = AtomicUsize::new(0);
+    static REPLACED: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Component, Default)]
+    #[component(on_add = on_add, on_remove = on_remove, on_replace = on_replace)]
+    struct Foo;
+
+    #[derive(Component)]
+    #[component(required = Foo)]
+    struct Bar;
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[derive(Component)]
+    struct Other;
+
+    fn on_add(world: &World, entity: Entity) {
+        assert!(world.entity_ref(entity).contains::<Foo>());
+        ADDED.fetch_add(1, Ordering::Relaxed);
+        Commands::new(world).with_entity(entity).insert(Marker);
+    }
+
+    fn on_remove(world: &World, entity: Entity) {
+        assert!(world.entity_ref(entity).contains::<Foo>());
+        REMOVED.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn on_replace(world: &World, entity: Entity) {
+        assert!(world.entity_ref(entity).contains::<Foo>());
+        REPLACED.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[test]
+    fn component_hooks() {
+        let allocator = WorldIdAllocator::new();
+        let mut world = World::new(allocator.alloc());
+
+        let e1 = world.spawn(Foo).entity();
+        let e2 = world.spawn(Bar).entity();
+        assert_eq!(ADDED.load(Ordering::Relaxed), 2);
+
+        world.apply_commands();
+        assert!(world.entity_ref(e1).contains::<Marker>());
+        assert!(world.entity_ref(e2).contains::<Marker>());
+
+        // Overwriting an existing component invokes only `on_replace`.
+        world.entity_owned(e1).insert(Foo);
+        assert_eq!(REPLACED.load(Ordering::Relaxed), 1);
+        assert_eq!(REMOVED.load(Ordering::Relaxed), 0);
+        assert_eq!(ADDED.load(Ordering::Relaxed), 2);
+
+        // Keeping it invokes none.
+        world.entity_owned(e1).insert_if_new(Foo);
+        assert_eq!(REPLACED.load(Ordering::Relaxed), 1);
+        assert_eq!(REMOVED.load(Ordering::Relaxed), 0);
+        assert_eq!(ADDED.load(Ordering::Relaxed), 2);
+
+        // Overwriting while moving to another archetype, too.
+        world.entity_owned(e2).insert((Foo, Other));
+        assert_eq!(REPLACED.load(Ordering::Relaxed), 2);
+        assert_eq!(ADDED.load(Ordering::Relaxed), 2);
+
+        world.entity_owned(e1).remove::<Foo>();
+        assert_eq!(REMOVED.load(Ordering::Relaxed), 1);
+        world.entity_owned(e1).insert(Foo);
+        assert_eq!(ADDED.load(Ordering::Relaxed), 3);
+
+        world.despawn(e2).unwrap();
+        assert_eq!(REMOVED.load(Ordering::Relaxed), 2);
+        assert!(world.despawn(e2).is_err());
+        assert_eq!(REMOVED.load(Ordering::Relaxed), 2);
+        assert_eq!(REPLACED.load(Ordering::Relaxed), 2);
+    }
+}
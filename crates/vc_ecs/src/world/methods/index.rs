@@ -0,0 +1,88 @@
+use core::hash::Hash;
+
+use crate::component::Component;
+use crate::entity::Entity;
+use crate::index::Index;
+use crate::world::World;
+
+impl World {
+    /// Creates the [`Index`] of the component `C`, if it does not exist.
+    ///
+    /// Entities that already have a `C` are indexed immediately, later
+    /// changes are tracked by the hooks of `C`.
+    pub fn init_index<C: Component + Hash + Eq + Clone + Send>(&mut self) -> &Index<C> {
+        if self.get_resource::<Index<C>>().is_none() {
+            let index = Index::<C>::new();
+            for (entity, value) in self.query::<(Entity, &C)>() {
+                index.insert(entity, value.clone());
+            }
+            self.insert_resource(index);
+        }
+        self.get_resource::<Index<C>>().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::component::Component;
+    use crate::index::Index;
+    use crate::world::{World, WorldIdAllocator};
+
+    #[derive(Component, Clone, Debug, PartialEq, Eq, Hash)]
+    #[component(
+        mutable = false,
+        on_add = Index::<Self>::on_add,
+        on_replace = Index::<Self>::on_replace,
+        on_remove = Index::<Self>::on_remove,
+    )]
+    struct TeamId(u32);
+
+    #[test]
+    fn component_index() {
+        let allocator = WorldIdAllocator::new();
+        let mut world = World::new(allocator.alloc());
+
+        let a = world.spawn(TeamId(1)).entity();
+        assert_eq!(*world.init_index::<TeamId>().get(&TeamId(1)), [a]);
+
+        let b = world.spawn(TeamId(1)).entity();
+        let c = world.spawn(()).entity();
+        world.entity_owned(c).insert(TeamId(2));
+
+        let index = world.get_resource::<Index<TeamId>>().unwrap();
+        assert_eq!(*index.get(&TeamId(1)), [a, b]);
+        assert_eq!(index.first(&TeamId(2)), Some(c));
+        assert_eq!(index.len(), 2);
+
+        world.entity_owned(a).remove::<TeamId>();
+        world.despawn(c).unwrap();
+
+        let index = world.get_resource::<Index<TeamId>>().unwrap();
+        assert_eq!(*index.get(&TeamId(1)), [b]);
+        assert!(index.get(&TeamId(2)).is_empty());
+        assert!(!index.contains(&TeamId(2)));
+        assert_eq!(index.count(&TeamId(2)), 0);
+    }
+
+    #[test]
+    fn replace_value() {
+        let allocator = WorldIdAllocator::new();
+        let mut world = World::new(allocator.alloc());
+        world.init_index::<TeamId>();
+
+        let a = world.spawn(TeamId(1)).entity();
+        let b = world.spawn(TeamId(1)).entity();
+        world.entity_owned(a).insert(TeamId(2));
+
+        let index = world.get_resource::<Index<TeamId>>().unwrap();
+        assert_eq!(*index.get(&TeamId(1)), [b]);
+        assert_eq!(*index.get(&TeamId(2)), [a]);
+
+        world.insert_batch([(b, TeamId(2))]).unwrap();
+
+        let index = world.get_resource::<Index<TeamId>>().unwrap();
+        assert!(!index.contains(&TeamId(1)));
+        assert_eq!(*index.get(&TeamId(2)), [a, b]);
+        assert_eq!(index.len(), 1);
+    }
+}
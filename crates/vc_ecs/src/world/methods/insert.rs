@@ -0,0 +1,561 @@
+use alloc::vec::Vec;
+
+use crate::archetype::{ArcheFlags, ArcheId};
+use crate::bundle::Bundle;
+use crate::component::InsertMode;
+use crate::entity::{Entity, EntityError, EntityLocation};
+use crate::storage::TableRow;
+use crate::tick::Tick;
+use crate::utils::DebugCheckedUnwrap;
+use crate::world::{EntityOwned, World};
+
+impl World {
+    /// Inserts a bundle into the entity, overwriting existing components.
+    ///
+    /// This is the fallible version of [`EntityOwned::insert`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EntityError`] if the entity is not spawned in this world,
+    /// e.g. it has been despawned, in which case the bundle is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_ecs::world::World;
+    /// # use vc_ecs::component::Component;
+    /// #
+    /// # #[derive(Component, Debug, PartialEq, Eq)]
+    /// # struct Foo;
+    /// #
+    /// let mut world = World::default();
+    /// let entity = world.spawn(()).entity();
+    /// assert!(world.try_insert(entity, Foo).is_ok());
+    ///
+    /// world.despawn(entity).unwrap();
+    /// assert!(world.try_insert(entity, Foo).is_err());
+    /// ```
+    #[inline]
+    pub fn try_insert<B: Bundle>(&mut self, entity: Entity, bundle: B) -> Result<(), EntityError> {
+        self.try_insert_with_mode(entity, bundle, InsertMode::Replace)
+    }
+
+    /// Inserts the components of a bundle that the entity does not contain yet.
+    ///
+    /// This is the fallible version of [`EntityOwned::insert_if_new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EntityError`] if the entity is not spawned in this world,
+    /// e.g. it has been despawned, in which case the bundle is dropped.
+    #[inline]
+    pub fn try_insert_if_new<B: Bundle>(
+        &mut self,
+        entity: Entity,
+        bundle: B,
+    ) -> Result<(), EntityError> {
+        self.try_insert_with_mode(entity, bundle, InsertMode::Keep)
+    }
+
+    /// Inserts a bundle into the entity, `mode` decides whether existing
+    /// components are overwritten.
+    ///
+    /// This is the fallible version of [`EntityOwned::insert_with_mode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EntityError`] if the entity is not spawned in this world,
+    /// e.g. it has been despawned, in which case the bundle is dropped.
+    pub fn try_insert_with_mode<B: Bundle>(
+        &mut self,
+        entity: Entity,
+        bundle: B,
+        mode: InsertMode,
+    ) -> Result<(), EntityError> {
+        let location = self.entities.locate(entity)?;
+        let mut owned = EntityOwned {
+            world: self.unsafe_world(),
+            entity,
+            location,
+        };
+        owned.insert_with_mode(bundle, mode);
+        Ok(())
+    }
+
+    /// Inserts a bundle into each entity of `iter`.
+    ///
+    /// This behaves like calling [`EntityOwned::insert`] for every pair, but
+    /// the entities are grouped by their current archetype, so that the target
+    /// archetype is resolved once per group and its storage is reserved for
+    /// the whole group in advance. If a group holds every entity of its table,
+    /// the shared columns are moved to the new table as whole ranges.
+    ///
+    /// Entities of the same group are processed in the order of `iter`.
+    /// An entity may appear more than once, later bundles overwrite earlier ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EntityError`] if any entity is not spawned in this world,
+    /// in which case nothing is inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_ecs::world::World;
+    /// # use vc_ecs::component::Component;
+    /// #
+    /// # #[derive(Component, Debug, PartialEq, Eq)]
+    /// # struct Foo;
+    /// # #[derive(Component, Debug, PartialEq, Eq)]
+    /// # struct Bar(u64);
+    /// #
+    /// let mut world = World::default();
+    /// let entities = world.spawn_batch((0..10).map(|_| Foo));
+    ///
+    /// world
+    ///     .insert_batch(entities.iter().map(|&e| (e, Bar(e.index() as u64))))
+    ///     .unwrap();
+    ///
+    /// let entity = world.entity_ref(entities[3]);
+    /// assert_eq!(entity.get::<Bar>(), Some(&Bar(entities[3].index() as u64)));
+    /// ```
+    pub fn insert_batch<I, B>(&mut self, iter: I) -> Result<(), EntityError>
+    where
+        I: IntoIterator<Item = (Entity, B)>,
+        B: Bundle,
+    {
+        let iter = iter.into_iter();
+        let mut batch = Vec::with_capacity(iter.size_hint().0);
+        for (entity, bundle) in iter {
+            let location = self.entities.locate(entity)?;
+            batch.push((Some(location.arche_id), entity, bundle));
+        }
+
+        self.insert_grouped(batch, InsertMode::Replace);
+        Ok(())
+    }
+
+    /// Inserts the components of a bundle that each entity of `iter`
+    /// does not contain yet.
+    ///
+    /// This behaves like [`insert_batch`], but existing components are kept,
+    /// as in [`EntityOwned::insert_if_new`]. If an entity appears more than
+    /// once, the earliest bundle wins.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EntityError`] if any entity is not spawned in this world,
+    /// in which case nothing is inserted.
+    ///
+    /// [`insert_batch`]: Self::insert_batch
+    pub fn insert_batch_if_new<I, B>(&mut self, iter: I) -> Result<(), EntityError>
+    where
+        I: IntoIterator<Item = (Entity, B)>,
+        B: Bundle,
+    {
+        let iter = iter.into_iter();
+        let mut batch = Vec::with_capacity(iter.size_hint().0);
+        for (entity, bundle) in iter {
+            let location = self.entities.locate(entity)?;
+            batch.push((Some(location.arche_id), entity, bundle));
+        }
+
+        self.insert_grouped(batch, InsertMode::Keep);
+        Ok(())
+    }
+
+    /// Inserts a bundle into each entity of `iter`, spawning the entities
+    /// that are not spawned yet.
+    ///
+    /// Entities that are spawned are handled as in [`insert_batch`], the others
+    /// are spawned with their bundle as in [`spawn_in`]. Spawns are performed
+    /// after all insertions, and the storage of the spawned archetype is
+    /// reserved in advance.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EntityError`] if any entity is neither spawned nor can be
+    /// spawned, e.g. due to a generation mismatch, in which case nothing is
+    /// inserted or spawned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_ecs::world::World;
+    /// # use vc_ecs::component::Component;
+    /// #
+    /// # #[derive(Component, Debug, PartialEq, Eq)]
+    /// # struct Foo;
+    /// # #[derive(Component, Debug, PartialEq, Eq)]
+    /// # struct Bar(u64);
+    /// #
+    /// let mut world = World::default();
+    /// let spawned = world.spawn(Foo).entity();
+    /// let reserved = world.alloc_entity();
+    ///
+    /// world
+    ///     .insert_or_spawn_batch([(spawned, Bar(1)), (reserved, Bar(2))])
+    ///     .unwrap();
+    ///
+    /// assert!(world.entity_ref(spawned).contains::<(Foo, Bar)>());
+    /// assert_eq!(world.entity_ref(reserved).get::<Bar>(), Some(&Bar(2)));
+    /// assert!(!world.entity_ref(reserved).contains::<Foo>());
+    /// ```
+    ///
+    /// [`insert_batch`]: Self::insert_batch
+    /// [`spawn_in`]: Self::spawn_in
+    pub fn insert_or_spawn_batch<I, B>(&mut self, iter: I) -> Result<(), EntityError>
+    where
+        I: IntoIterator<Item = (Entity, B)>,
+        B: Bundle,
+    {
+        let iter = iter.into_iter();
+        let mut batch = Vec::with_capacity(iter.size_hint().0);
+        for (entity, bundle) in iter {
+            let arche_id = match self.entities.locate(entity) {
+                Ok(location) => Some(location.arche_id),
+                Err(err) => {
+                    self.entities.can_spawn(entity).map_err(|_| err)?;
+                    None
+                }
+            };
+            batch.push((arche_id, entity, bundle));
+        }
+
+        self.insert_grouped(batch, InsertMode::Replace);
+        Ok(())
+    }
+
+    /// Inserts the bundles grouped by archetype, `None` marks entities
+    /// of `insert_or_spawn_batch` that are not spawned yet.
+    ///
+    /// `mode` only applies to spawned entities.
+    fn insert_grouped<B: Bundle>(
+        &mut self,
+        mut batch: Vec<(Option<ArcheId>, Entity, B)>,
+        mode: InsertMode,
+    ) {
+        // Stable, so that duplicated entities keep their order.
+        batch.sort_by_key(|(arche_id, ..)| *arche_id);
+
+        let mut groups: Vec<(Option<ArcheId>, usize)> = Vec::new();
+        for (arche_id, ..) in &batch {
+            match groups.last_mut() {
+                Some((last, len)) if last == arche_id => *len += 1,
+                _ => groups.push((*arche_id, 1)),
+            }
+        }
+
+        let bundle_id = self.register_bundle::<B>();
+        let tick = Tick::new(*self.this_run.get_mut());
+        let mut items = batch.into_iter();
+
+        for (old_arche_id, len) in groups {
+            let new_arche_id = match old_arche_id {
+                Some(old_arche_id) => self.arche_after_insert(old_arche_id, bundle_id),
+                None => self.register_archetype_by_bundle(bundle_id),
+            };
+            if old_arche_id != Some(new_arche_id) {
+                self.reserve_arche(old_arche_id, new_arche_id, len);
+            }
+
+            if let Some(old_arche_id) = old_arche_id
+                && self.can_move_archetype(old_arche_id, new_arche_id, &items.as_slice()[..len])
+            {
+                unsafe { self.move_archetype(old_arche_id, new_arche_id) };
+                for (_, entity, bundle) in items.by_ref().take(len) {
+                    vc_ptr::into_owning!(bundle);
+                    let location = self.entities.locate(entity).unwrap();
+                    let mut owned = EntityOwned {
+                        world: self.unsafe_world(),
+                        entity,
+                        location,
+                    };
+                    unsafe {
+                        owned.write_moved(
+                            bundle,
+                            old_arche_id,
+                            mode,
+                            B::write_explicit,
+                            B::write_required,
+                        );
+                    }
+                }
+                continue;
+            }
+
+            for (_, entity, bundle) in items.by_ref().take(len) {
+                vc_ptr::into_owning!(bundle);
+
+                // Earlier items may have moved the entity, or spawned it.
+                let Ok(location) = self.entities.locate(entity) else {
+                    unsafe {
+                        self.spawn_at(
+                            bundle,
+                            entity,
+                            new_arche_id,
+                            tick,
+                            B::write_explicit,
+                            B::write_required,
+                        );
+                    }
+                    continue;
+                };
+                let arche_id = if Some(location.arche_id) == old_arche_id {
+                    new_arche_id
+                } else {
+                    self.arche_after_insert(location.arche_id, bundle_id)
+                };
+
+                let mut owned = EntityOwned {
+                    world: self.unsafe_world(),
+                    entity,
+                    location,
+                };
+                unsafe {
+                    owned.insert_into(
+                        bundle,
+                        bundle_id,
+                        arche_id,
+                        mode,
+                        B::write_explicit,
+                        B::write_required,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if `group` holds every entity of the table of
+    /// `old_arche_id` exactly once, so that the whole table can be moved
+    /// to `new_arche_id` at once with [`move_archetype`].
+    ///
+    /// Hooks may observe the world between two writes, so archetypes
+    /// with hooks are always moved entity by entity.
+    ///
+    /// [`move_archetype`]: Self::move_archetype
+    fn can_move_archetype<B>(
+        &self,
+        old_arche_id: ArcheId,
+        new_arche_id: ArcheId,
+        group: &[(Option<ArcheId>, Entity, B)],
+    ) -> bool {
+        let old_arche = unsafe { self.archetypes.get_unchecked(old_arche_id) };
+        let new_arche = unsafe { self.archetypes.get_unchecked(new_arche_id) };
+        if old_arche.table_id() == new_arche.table_id()
+            || new_arche.flags().intersects(
+                ArcheFlags::ON_ADD_HOOK | ArcheFlags::ON_REMOVE_HOOK | ArcheFlags::ON_REPLACE_HOOK,
+            )
+        {
+            return false;
+        }
+
+        let old_table = unsafe { self.storages.tables.get_unchecked(old_arche.table_id()) };
+        if old_table.entity_count() != group.len() || old_arche.entities().len() != group.len() {
+            return false;
+        }
+
+        // Earlier groups may have moved other entities into `old_arche_id`,
+        // and an entity may appear more than once.
+        let mut entities: Vec<Entity> = group.iter().map(|(_, entity, _)| *entity).collect();
+        entities.sort_unstable();
+        entities.dedup();
+        entities.len() == group.len()
+            && entities.iter().all(|&entity| {
+                self.entities
+                    .locate(entity)
+                    .is_ok_and(|location| location.arche_id == old_arche_id)
+            })
+    }
+
+    /// Moves every entity of `old_arche_id` to `new_arche_id`, moving the
+    /// matching table columns as whole ranges.
+    ///
+    /// The components of `new_arche_id` missing from `old_arche_id` are
+    /// allocated but left uninitialized.
+    ///
+    /// # Safety
+    /// - [`can_move_archetype`] must have returned `true` for the archetypes.
+    /// - `new_arche_id` must be the result of [`World::arche_after_insert`]
+    ///   for `old_arche_id`, and the caller must initialize the missing
+    ///   components of every moved entity.
+    ///
+    /// [`can_move_archetype`]: Self::can_move_archetype
+    unsafe fn move_archetype(&mut self, old_arche_id: ArcheId, new_arche_id: ArcheId) {
+        let world = self.unsafe_world_mut();
+        let old_arche = unsafe { world.full_mut().archetypes.get_unchecked_mut(old_arche_id) };
+        let new_arche = unsafe { world.full_mut().archetypes.get_unchecked_mut(new_arche_id) };
+
+        let old_table_id = old_arche.table_id();
+        let new_table_id = new_arche.table_id();
+        let old_table = unsafe {
+            world
+                .data_mut()
+                .storages
+                .tables
+                .get_unchecked_mut(old_table_id)
+        };
+        let new_table = unsafe {
+            world
+                .data_mut()
+                .storages
+                .tables
+                .get_unchecked_mut(new_table_id)
+        };
+
+        let first = unsafe { old_table.move_all_matching_columns(new_table) }.index();
+        old_arche.clear_entities();
+
+        let maps = unsafe { &mut world.data_mut().storages.maps };
+        let entities = unsafe { &mut world.full_mut().entities };
+        let moved = &new_table.entities()[first..];
+        for (index, &entity) in (first..).zip(moved) {
+            new_arche.sparse_components().iter().for_each(|&id| {
+                if !old_arche.contains_sparse_component(id) {
+                    let map_id = unsafe { maps.get_id(id).debug_checked_unwrap() };
+                    let map = unsafe { maps.get_unchecked_mut(map_id) };
+                    let _ = unsafe { map.allocate(entity) };
+                }
+            });
+
+            let location = EntityLocation {
+                arche_id: new_arche_id,
+                table_id: new_table_id,
+                arche_row: unsafe { new_arche.insert_entity(entity) },
+                table_row: unsafe { TableRow::from_index_unchecked(index) },
+            };
+            unsafe { entities.update_location(entity, location).unwrap() };
+        }
+    }
+
+    /// Reserves room for `additional` entities moving from `old_arche_id`
+    /// (or being spawned, if `None`) into `new_arche_id`.
+    fn reserve_arche(
+        &mut self,
+        old_arche_id: Option<ArcheId>,
+        new_arche_id: ArcheId,
+        additional: usize,
+    ) {
+        let new_arche = unsafe { self.archetypes.get_unchecked_mut(new_arche_id) };
+        new_arche.reserve(additional);
+        let new_table_id = new_arche.table_id();
+
+        let same_table = old_arche_id.is_some_and(|old_arche_id| unsafe {
+            self.archetypes.get_unchecked(old_arche_id).table_id() == new_table_id
+        });
+        if !same_table {
+            let table = unsafe { self.storages.tables.get_unchecked_mut(new_table_id) };
+            table.reserve(additional);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::component::Component;
+    use crate::world::World;
+
+    #[derive(Component, Debug, PartialEq, Eq)]
+    struct Foo;
+
+    #[derive(Component, Debug, PartialEq, Eq)]
+    struct Bar(u64);
+
+    #[derive(Component, Debug, PartialEq, Eq)]
+    #[component(storage = "sparse")]
+    struct Baz(u64);
+
+    #[test]
+    fn insert_batch() {
+        let mut world = World::default();
+        let foos = world.spawn_batch((0..4).map(|_| Foo));
+        let bars = world.spawn_batch((0..4).map(Bar));
+
+        let mut batch: Vec<_> = foos.iter().chain(&bars).map(|&e| (e, Baz(1))).collect();
+        batch.push((foos[0], Baz(2)));
+        world.insert_batch(batch).unwrap();
+
+        assert_eq!(world.entity_ref(foos[0]).get::<Baz>(), Some(&Baz(2)));
+        for (i, &entity) in bars.iter().enumerate() {
+            let entity = world.entity_ref(entity);
+            assert_eq!(entity.get::<Bar>(), Some(&Bar(i as u64)));
+            assert_eq!(entity.get::<Baz>(), Some(&Baz(1)));
+        }
+
+        // Nothing is inserted if any entity is invalid.
+        let despawned = world.spawn(Foo).entity();
+        world.despawn(despawned).unwrap();
+        assert!(
+            world
+                .insert_batch([(bars[0], Foo), (despawned, Foo)])
+                .is_err()
+        );
+        assert!(!world.entity_ref(bars[0]).contains::<Foo>());
+
+        let reserved = world.alloc_entity();
+        world
+            .insert_or_spawn_batch([(reserved, Bar(7)), (foos[1], Bar(8)), (reserved, Bar(9))])
+            .unwrap();
+        assert_eq!(world.entity_ref(reserved).get::<Bar>(), Some(&Bar(9)));
+        assert!(world.entity_ref(foos[1]).contains::<(Foo, Bar, Baz)>());
+    }
+
+    #[test]
+    fn insert_batch_whole_table() {
+        let mut world = World::default();
+        let other = world.spawn((Foo, Bar(100))).entity();
+        let bars = world.spawn_batch((0..4).map(Bar));
+
+        // Every entity of the `Bar` table moves, along with its column.
+        let batch = bars
+            .iter()
+            .rev()
+            .map(|&e| (e, (Foo, Baz(e.index() as u64))));
+        world.insert_batch(batch).unwrap();
+
+        for (i, &entity) in bars.iter().enumerate() {
+            let entity = world.entity_ref(entity);
+            assert_eq!(entity.get::<Bar>(), Some(&Bar(i as u64)));
+            assert_eq!(
+                entity.get::<Baz>(),
+                Some(&Baz(entity.entity().index() as u64))
+            );
+            assert!(entity.contains::<Foo>());
+        }
+        assert_eq!(world.entity_ref(other).get::<Bar>(), Some(&Bar(100)));
+        assert_eq!(world.query::<&Bar>().iter().count(), 5);
+
+        // The moved locations are consistent.
+        world.despawn(bars[1]).unwrap();
+        assert_eq!(world.entity_ref(bars[3]).get::<Bar>(), Some(&Bar(3)));
+        assert_eq!(world.query::<(&Bar, &Baz)>().iter().count(), 3);
+    }
+
+    #[test]
+    fn insert_if_new() {
+        let mut world = World::default();
+        let a = world.spawn((Bar(1), Baz(1))).entity();
+        let b = world.spawn(Foo).entity();
+
+        world.try_insert_if_new(a, (Foo, Bar(2), Baz(2))).unwrap();
+        let entity = world.entity_ref(a);
+        assert!(entity.contains::<Foo>());
+        assert_eq!(entity.get::<Bar>(), Some(&Bar(1)));
+        assert_eq!(entity.get::<Baz>(), Some(&Baz(1)));
+
+        world
+            .insert_batch_if_new([(b, Bar(3)), (a, Bar(3)), (b, Bar(4))])
+            .unwrap();
+        assert_eq!(world.entity_ref(a).get::<Bar>(), Some(&Bar(1)));
+        assert_eq!(world.entity_ref(b).get::<Bar>(), Some(&Bar(3)));
+
+        world.try_insert(a, Baz(5)).unwrap();
+        assert_eq!(world.entity_ref(a).get::<Baz>(), Some(&Baz(5)));
+
+        world.despawn(a).unwrap();
+        assert!(world.try_insert(a, Foo).is_err());
+        assert!(world.try_insert_if_new(a, Foo).is_err());
+    }
+}
@@ -2,14 +2,34 @@
 //!
 //! This module is split by domain:
 //! - archetype inspection,
-//! - entity spawn/despawn,
+//! - bulk teardown,
+//! - diagnostics,
+//! - entity spawn/despawn and component hooks,
+//! - batched component insertion,
+//! - component value indexes,
+//! - named entity lookup,
+//! - pending structural changes,
 //! - query creation,
 //! - registration helpers,
-//! - resource insertion/removal/access.
+//! - reflection registration,
+//! - resource insertion/removal/access,
+//! - spatial grids.
 
 mod arche;
+mod clear;
 mod despawn;
+mod diagnostics;
+mod hook;
+mod index;
+mod insert;
+mod name;
+mod pending;
 mod query;
 mod register;
 mod resource;
+mod spatial;
 mod spawn;
+
+crate::cfg::reflect! {
+    mod reflect;
+}
@@ -0,0 +1,69 @@
+use crate::entity::Entity;
+use crate::world::World;
+
+impl World {
+    /// Returns the first entity with the given [`Name`].
+    ///
+    /// This is a lookup in the [`NameLookup`] index, no entity is scanned.
+    ///
+    /// [`Name`]: crate::name::Name
+    /// [`NameLookup`]: crate::name::NameLookup
+    pub fn find_by_name(&self, name: &str) -> Option<Entity> {
+        self.names.find(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use crate::name::Name;
+    use crate::world::{World, WorldIdAllocator};
+
+    #[test]
+    fn find_by_name() {
+        let allocator = WorldIdAllocator::new();
+        let mut world = World::new(allocator.alloc());
+
+        let a = world.spawn(Name::new("Enemy")).entity();
+        let b = world.spawn(()).entity();
+        world.entity_owned(b).insert(Name::from("Enemy"));
+        let c = world.spawn(Name::new("Player")).entity();
+
+        assert_eq!(world.names().len(), 3);
+        assert_eq!(world.find_by_name("Player"), Some(c));
+        assert_eq!(world.find_by_name("Enemy"), Some(a));
+        assert_eq!(world.names().find_all("Enemy"), vec![a, b]);
+        assert_eq!(world.names().get(b), Some(Name::new("Enemy")));
+        assert_eq!(world.find_by_name("Boss"), None);
+
+        world.entity_owned(a).remove::<Name>();
+        assert_eq!(world.find_by_name("Enemy"), Some(b));
+        world.despawn(b).unwrap();
+        assert_eq!(world.find_by_name("Enemy"), None);
+        assert_eq!(world.names().len(), 1);
+
+        let name = world.entity_ref(c).get::<Name>().cloned().unwrap();
+        assert_eq!(name, "Player");
+        assert_eq!(name.as_str(), "Player");
+    }
+
+    #[test]
+    fn rename() {
+        let allocator = WorldIdAllocator::new();
+        let mut world = World::new(allocator.alloc());
+
+        let a = world.spawn(Name::new("Enemy")).entity();
+        let b = world.spawn(Name::new("Enemy")).entity();
+
+        world.entity_owned(a).insert(Name::new("Boss"));
+        assert_eq!(world.find_by_name("Boss"), Some(a));
+        assert_eq!(world.names().find_all("Enemy"), vec![b]);
+        assert_eq!(world.names().get(a), Some(Name::new("Boss")));
+
+        world.insert_batch([(b, Name::new("Boss"))]).unwrap();
+        assert_eq!(world.find_by_name("Enemy"), None);
+        assert_eq!(world.names().find_all("Boss"), vec![a, b]);
+        assert_eq!(world.names().len(), 2);
+    }
+}
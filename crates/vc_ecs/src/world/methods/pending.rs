@@ -0,0 +1,89 @@
+use crate::entity::Entity;
+use crate::world::{PendingWorldOps, World};
+
+impl World {
+    /// Returns the queue of pending structural changes.
+    ///
+    /// See [`PendingWorldOps`] for the order in which they are applied.
+    pub fn pending(&self) -> &PendingWorldOps {
+        &self.pending
+    }
+
+    /// Allocates an entity that is spawned without components by the next flush.
+    ///
+    /// Changes to the entity can be queued right away through [`World::pending`].
+    pub fn reserve_entity(&self) -> Entity {
+        let entity = self.alloc_entity();
+        self.pending.reserve(entity);
+        entity
+    }
+
+    /// Applies all pending structural changes.
+    ///
+    /// See [`PendingWorldOps`] for the order in which they are applied.
+    pub fn apply_pending(&mut self) {
+        // Each round only takes the operations queued before it.
+        while !self.pending.is_empty() {
+            for _ in 0..self.pending.reserved.len() {
+                let entity = self.pending.reserved.pop().unwrap();
+                if self.entities.can_spawn(entity).is_ok() {
+                    self.spawn_in((), entity);
+                }
+            }
+            let handler = self.default_error_handler();
+            for _ in 0..self.pending.commands.len() {
+                let command = self.pending.commands.pop().unwrap();
+                self.apply_command(command, handler);
+            }
+            for _ in 0..self.pending.despawns.len() {
+                let entity = self.pending.despawns.pop().unwrap();
+                let _ = self.despawn(entity);
+            }
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::component::Component;
+    use crate::entity::Entity;
+    use crate::world::World;
+
+    #[derive(Component, Debug, PartialEq, Eq)]
+    #[component(on_add = spawn_child)]
+    struct Parent;
+
+    #[derive(Component, Debug, PartialEq, Eq)]
+    struct Child(Entity);
+
+    fn spawn_child(world: &World, entity: Entity) {
+        let child = world.reserve_entity();
+        world.pending().insert(child, Child(entity));
+    }
+
+    #[test]
+    fn flush_order() {
+        let mut world = World::default();
+        let a = world.reserve_entity();
+        let b = world.spawn(()).entity();
+
+        // Despawns run after insertions of the same round.
+        world.pending().despawn(b);
+        world.pending().insert(b, Parent);
+        world.pending().insert(a, Parent);
+        world.pending().remove::<Parent>(b);
+        assert_eq!(world.pending().len(), 5);
+
+        // Hooks queue into the next round of the same flush,
+        // `a` is spawned, `b` is despawned and each gets a child.
+        let count = world.entity_count();
+        world.flush();
+        assert!(world.pending().is_empty());
+        assert!(world.entity_ref(a).contains::<Parent>());
+        assert!(world.entities().locate(b).is_err());
+        assert_eq!(world.entity_count(), count + 2);
+    }
+}
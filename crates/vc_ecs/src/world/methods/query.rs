@@ -26,7 +26,7 @@ impl World {
     pub fn cache_query_state<D: QueryData + 'static, F: QueryFilter + 'static>(
         &mut self,
     ) -> &mut QueryState<D, F> {
-        let world: UnsafeWorld<'_> = self.unsafe_world();
+        let world: UnsafeWorld<'_> = self.unsafe_world_mut();
         if let Some(state) = unsafe { world.data_mut().get_resource_mut::<QueryState<D, F>>() } {
             state.into_inner()
         } else {
@@ -71,10 +71,9 @@ impl World {
     /// assert_eq!(query.iter().count(), 2);
     /// ```
     pub fn query<D: QueryData + 'static>(&mut self) -> Query<'_, '_, D> {
-        let world: UnsafeWorld<'_> = self.unsafe_world();
+        let world: UnsafeWorld<'_> = self.unsafe_world_mut();
         let state = unsafe { world.full_mut().cache_query_state::<D, ()>() };
         let read_only_world = unsafe { world.read_only() };
-        state.update(read_only_world);
         let last_run = read_only_world.last_run();
         let this_run = read_only_world.this_run();
 
@@ -110,10 +109,9 @@ impl World {
     pub fn query_with<D: QueryData + 'static, F: QueryFilter + 'static>(
         &mut self,
     ) -> Query<'_, '_, D, F> {
-        let world: UnsafeWorld<'_> = self.unsafe_world();
+        let world: UnsafeWorld<'_> = self.unsafe_world_mut();
         let state = unsafe { world.full_mut().cache_query_state::<D, F>() };
         let read_only_world = unsafe { world.read_only() };
-        state.update(read_only_world);
         let last_run = read_only_world.last_run();
         let this_run = read_only_world.this_run();
 
@@ -128,7 +126,7 @@ mod tests {
     use crate::borrow::{Mut, Ref};
     use crate::component::{Component, ComponentStorage};
     use crate::entity::Entity;
-    use crate::query::{And, Or, With, Without};
+    use crate::query::{And, Or, QueryData, With, Without};
     use crate::tick::DetectChanges;
     use crate::world::{EntityMut, EntityRef, World, WorldIdAllocator};
     use alloc::string::String;
@@ -439,4 +437,134 @@ mod tests {
         let qux_values: Vec<f32> = query.into_iter().map(|q| q.0).collect();
         assert!(qux_values.contains(&3.0));
     }
+
+    #[test]
+    fn query_combinations() {
+        let allocator = WorldIdAllocator::new();
+        let mut world = World::new(allocator.alloc());
+
+        world.spawn((Foo, Bar(1)));
+        world.spawn((Bar(2),));
+        world.spawn((Foo, Bar(3), Baz(String::from("a"))));
+        world.spawn((Bar(4),));
+        world.update_tick();
+
+        let query = world.query::<&Bar>();
+        let mut pairs: Vec<(u64, u64)> = query
+            .iter_combinations()
+            .map(|[a, b]| (a.0.min(b.0), a.0.max(b.0)))
+            .collect();
+        pairs.sort();
+        assert_eq!(pairs, [(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)]);
+        assert_eq!(query.iter_combinations::<3>().count(), 4);
+        assert_eq!(query.iter_combinations::<4>().count(), 1);
+        assert_eq!(query.iter_combinations::<5>().count(), 0);
+
+        // Sparse queries iterate archetypes instead of tables.
+        let query = world.query_with::<&Bar, Or<(With<Foo>, With<Baz>)>>();
+        assert_eq!(query.iter_combinations::<2>().count(), 1);
+
+        let mut query = world.query::<&mut Bar>();
+        let mut combinations = query.iter_combinations_mut();
+        while let Some([a, b]) = combinations.fetch_next() {
+            a.0 += 10;
+            b.0 += 10;
+        }
+
+        let query = world.query::<&Bar>();
+        let mut values: Vec<u64> = query.into_iter().map(|bar| bar.0).collect();
+        values.sort();
+        assert_eq!(values, [31, 32, 33, 34]);
+    }
+
+    #[derive(QueryData)]
+    struct Named {
+        entity: Entity,
+        bar: &'static Bar,
+        baz: Option<&'static Baz>,
+    }
+
+    #[derive(QueryData)]
+    #[query_data(mutable)]
+    struct Unnamed(&'static mut Bar, Ref<'static, Foo>);
+
+    #[test]
+    fn query_derive() {
+        let allocator = WorldIdAllocator::new();
+        let mut world = World::new(allocator.alloc());
+
+        let e1 = world
+            .spawn((Foo, Bar(100), Baz(String::from("a"))))
+            .entity();
+        let e2 = world.spawn((Foo, Bar(200))).entity();
+        world.spawn((Foo, Zaz(1)));
+        world.update_tick();
+
+        let query = world.query::<Unnamed>();
+        for UnnamedItem(bar, foo) in query {
+            assert!(!foo.is_changed());
+            bar.0 += 1;
+        }
+
+        let query = world.query::<Named>();
+        let mut items: Vec<NamedItem> = query.into_iter().collect();
+        items.sort_by_key(|item| item.bar.0);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].entity, e1);
+        assert_eq!(items[0].bar.0, 101);
+        assert_eq!(items[0].baz.unwrap().0, "a");
+        assert_eq!(items[1].entity, e2);
+        assert_eq!(items[1].bar.0, 201);
+        assert!(items[1].baz.is_none());
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    #[should_panic(expected = "builds an exclusive cache from a shared world")]
+    fn exclusive_cache_from_shared_world() {
+        use crate::query::{Query, QueryState};
+        use crate::system::SystemParam;
+
+        let allocator = WorldIdAllocator::new();
+        let mut world = World::new(allocator.alloc());
+        world.spawn((Foo, Bar(100)));
+
+        let mut state = QueryState::<&mut Bar>::new(&mut world);
+        let world = &world;
+        let (last_run, this_run) = (world.last_run(), world.this_run());
+        let mut query = unsafe {
+            <Query<&mut Bar> as SystemParam>::build_param(
+                world.unsafe_world(),
+                &mut state,
+                last_run,
+                this_run,
+            )
+            .unwrap()
+        };
+        query.iter_mut().for_each(|bar| bar.0 += 1);
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    #[should_panic(expected = "builds a cache while a conflicting cache is being built")]
+    fn conflicting_cache_builders() {
+        use crate::utils::DebugName;
+        use crate::world::WorldMode;
+
+        let allocator = WorldIdAllocator::new();
+        let mut world = World::new(allocator.alloc());
+        let exclusive = world.unsafe_world_mut();
+
+        // Exclusive caches may be built at the same time, and shared caches
+        // from an exclusive handle do not count.
+        let name = DebugName::type_name::<&mut Bar>();
+        let first = exclusive.debug_check_mode(WorldMode::Exclusive, name);
+        let second = exclusive.debug_check_mode(WorldMode::Exclusive, name);
+        let _ = exclusive.debug_check_mode(WorldMode::Shared, DebugName::type_name::<&Bar>());
+        drop((first, second));
+
+        let shared = unsafe { exclusive.read_only() }.unsafe_world();
+        let _builder = shared.debug_check_mode(WorldMode::Shared, DebugName::type_name::<&Bar>());
+        let _ = exclusive.debug_check_mode(WorldMode::Exclusive, name);
+    }
 }
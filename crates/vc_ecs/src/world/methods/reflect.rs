@@ -0,0 +1,367 @@
+use alloc::vec::Vec;
+use core::hash::Hasher;
+
+use vc_reflect::Reflect;
+use vc_reflect::registry::TypeRegistry;
+
+use crate::component::{ComponentInfo, ReflectComponent};
+use crate::entity::Entity;
+use crate::resource::{ReflectResource, ReflectResourceError, ResourceConflict};
+use crate::world::{StateHashError, World};
+
+impl World {
+    /// Registers the reflection data of all registered components and resources
+    /// that provide it, i.e. those derived with `#[component(reflect)]` or
+    /// `#[resource(reflect)]`.
+    ///
+    /// Types are only known to the world after they have been registered, for
+    /// example by spawning or inserting them, or through [`World::register_component`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::any::TypeId;
+    /// use vc_ecs::prelude::*;
+    /// use vc_ecs::component::ReflectComponent;
+    /// use vc_reflect::prelude::*;
+    ///
+    /// #[derive(Component, Reflect)]
+    /// #[component(reflect)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::default();
+    /// world.register_component::<Health>();
+    ///
+    /// let mut registry = TypeRegistry::new();
+    /// world.register_reflect_types(&mut registry);
+    ///
+    /// let id = TypeId::of::<Health>();
+    /// assert!(registry.get_type_trait::<ReflectComponent>(id).is_some());
+    /// ```
+    pub fn register_reflect_types(&self, registry: &mut TypeRegistry) {
+        self.components
+            .iter()
+            .filter_map(|info| info.reflect())
+            .for_each(|reflector| reflector.call(registry));
+
+        self.resources
+            .iter()
+            .filter_map(|info| info.reflect())
+            .for_each(|reflector| reflector.call(registry));
+    }
+
+    /// Returns the resources registered with [`ReflectResource`] in `registry`,
+    /// as reflected values.
+    ///
+    /// Resources opt in through the registration of [`ReflectResource`], e.g.
+    /// with `#[resource(reflect)]` and [`World::register_reflect_types`], so
+    /// runtime-only resources are not captured. The values are yielded in the
+    /// order of resource registration.
+    ///
+    /// Together with [`World::insert_reflect_resources`], this allows saving
+    /// and loading global settings alongside entities.
+    pub fn reflect_resources<'a>(
+        &'a self,
+        registry: &'a TypeRegistry,
+    ) -> impl Iterator<Item = &'a dyn Reflect> {
+        self.resources.iter().filter_map(|info| {
+            registry
+                .get_type_trait::<ReflectResource>(info.type_id())?
+                .get(self)
+        })
+    }
+
+    /// Inserts reflected resources, e.g. captured by [`World::reflect_resources`].
+    ///
+    /// Values may be dynamic, in which case the type they represent is used.
+    /// Existing resources are replaced or kept depending on `conflict`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a value's type is not registered with
+    /// [`ReflectResource`], or if it cannot be converted. Values before the
+    /// failing one are already inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vc_ecs::prelude::*;
+    /// use vc_ecs::resource::ResourceConflict;
+    /// use vc_reflect::prelude::*;
+    ///
+    /// #[derive(Resource, Reflect, PartialEq, Debug)]
+    /// #[resource(reflect)]
+    /// struct Gravity(f32);
+    ///
+    /// let mut level = World::default();
+    /// level.insert_resource(Gravity(9.8));
+    ///
+    /// let mut registry = TypeRegistry::new();
+    /// level.register_reflect_types(&mut registry);
+    ///
+    /// let mut world = World::default();
+    /// world.insert_resource(Gravity(1.6));
+    ///
+    /// let resources = level.reflect_resources(&registry);
+    /// world.insert_reflect_resources(&registry, resources, ResourceConflict::Skip).unwrap();
+    /// assert_eq!(world.get_resource::<Gravity>(), Some(&Gravity(1.6)));
+    ///
+    /// let resources = level.reflect_resources(&registry);
+    /// world.insert_reflect_resources(&registry, resources, ResourceConflict::Overwrite).unwrap();
+    /// assert_eq!(world.get_resource::<Gravity>(), Some(&Gravity(9.8)));
+    /// ```
+    pub fn insert_reflect_resources<'a>(
+        &mut self,
+        registry: &TypeRegistry,
+        values: impl IntoIterator<Item = &'a dyn Reflect>,
+        conflict: ResourceConflict,
+    ) -> Result<(), ReflectResourceError> {
+        for value in values {
+            let Some(info) = value.represented_type_info() else {
+                return Err(ReflectResourceError::NotTyped);
+            };
+            let Some(reflect) = registry.get_type_trait::<ReflectResource>(info.type_id()) else {
+                return Err(ReflectResourceError::NotReflected(info.type_path()));
+            };
+            if conflict == ResourceConflict::Skip && reflect.contains(self) {
+                continue;
+            }
+            if !reflect.insert(self, value, registry) {
+                return Err(ReflectResourceError::NotConvertible(info.type_path()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Computes a deterministic hash of the components selected by `filter`.
+    ///
+    /// Components are read through the [`ReflectComponent`] registered in
+    /// `registry` and hashed with [`Reflect::reflect_hash`]. Entities are
+    /// visited in ascending order and components in the order of their type
+    /// paths, so the result does not depend on the archetype layout or on the
+    /// component registration order.
+    ///
+    /// Worlds with the same entities and the same values of the selected
+    /// components produce the same hash, on any peer running the same build.
+    /// This makes it cheap to detect desyncs in lockstep simulations by
+    /// comparing the hashes of each tick.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a selected component is not registered with
+    /// [`ReflectComponent`], or if it does not support `reflect_hash`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vc_ecs::prelude::*;
+    /// use vc_reflect::prelude::*;
+    ///
+    /// #[derive(Component, Reflect, Hash)]
+    /// #[component(reflect)]
+    /// #[reflect(hash)]
+    /// struct Position(i32);
+    ///
+    /// let mut registry = TypeRegistry::new();
+    /// let mut hashes = Vec::new();
+    /// for _ in 0..2 {
+    ///     let mut world = World::default();
+    ///     world.spawn(Position(1));
+    ///     world.spawn(Position(2));
+    ///     world.register_reflect_types(&mut registry);
+    ///     hashes.push(world.state_hash(&registry, |_| true).unwrap());
+    /// }
+    /// assert_eq!(hashes[0], hashes[1]);
+    /// ```
+    ///
+    /// [`Reflect::reflect_hash`]: vc_reflect::Reflect::reflect_hash
+    pub fn state_hash(
+        &self,
+        registry: &TypeRegistry,
+        mut filter: impl FnMut(&ComponentInfo) -> bool,
+    ) -> Result<u64, StateHashError> {
+        let mut selected = Vec::new();
+        for info in self.components.iter().filter(|info| filter(info)) {
+            let Some(meta) = registry.get(info.type_id()) else {
+                return Err(StateHashError::NotReflected(info.debug_name()));
+            };
+            let Some(reflect) = meta.get_trait::<ReflectComponent>() else {
+                return Err(StateHashError::NotReflected(info.debug_name()));
+            };
+            // Unlike `ComponentId`, type paths are the same on every peer.
+            let path = meta.type_info().type_path();
+            let mut hasher = vc_reflect::reflect_hasher();
+            hasher.write(path.as_bytes());
+            selected.push((path, hasher.finish(), info.id(), info.debug_name(), reflect));
+        }
+        selected.sort_unstable_by_key(|(path, ..)| *path);
+
+        let mut entities: Vec<Entity> = self
+            .archetypes
+            .iter()
+            .filter(|arche| selected.iter().any(|(_, _, id, ..)| arche.contains_component(*id)))
+            .flat_map(|arche| arche.entities().iter().copied())
+            .collect();
+        entities.sort_unstable_by_key(|entity| (entity.index(), entity.to_bits()));
+
+        let mut hasher = vc_reflect::reflect_hasher();
+        for entity in entities {
+            let entity_ref = self.entity_ref(entity);
+            hasher.write_u64(entity.to_bits());
+            for (_, path_hash, _, name, reflect) in &selected {
+                let Some(value) = reflect.get(&entity_ref) else {
+                    continue;
+                };
+                let Some(hash) = value.reflect_hash() else {
+                    return Err(StateHashError::NotHashable(*name));
+                };
+                hasher.write_u64(*path_hash);
+                hasher.write_u64(hash);
+            }
+        }
+        Ok(hasher.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::any::TypeId;
+
+    use vc_reflect::Reflect;
+    use vc_reflect::registry::TypeRegistry;
+
+    use crate::component::{Component, ReflectComponent};
+    use crate::resource::{ReflectResource, ReflectResourceError, Resource, ResourceConflict};
+    use crate::world::{StateHashError, World, WorldIdAllocator};
+
+    #[derive(Component, Reflect)]
+    #[component(reflect)]
+    struct Foo(u32);
+
+    #[derive(Component, Reflect)]
+    struct Bar;
+
+    #[derive(Resource, Reflect)]
+    #[resource(reflect)]
+    struct Baz(u32);
+
+    #[test]
+    fn register_reflect_types() {
+        let allocator = WorldIdAllocator::new();
+        let mut world = World::new(allocator.alloc());
+
+        let entity = world.spawn((Foo(1), Bar)).entity();
+        world.insert_resource(Baz(2));
+
+        let mut registry = TypeRegistry::new();
+        world.register_reflect_types(&mut registry);
+        assert!(!registry.contains(TypeId::of::<Bar>()));
+
+        let reflect = registry
+            .get_type_trait::<ReflectComponent>(TypeId::of::<Foo>())
+            .unwrap();
+        let entity = world.entity_ref(entity);
+        assert!(reflect.contains(&entity));
+        let foo = reflect.get(&entity).unwrap();
+        assert_eq!(foo.downcast_ref::<Foo>().unwrap().0, 1);
+
+        let reflect = registry
+            .get_type_trait::<ReflectResource>(TypeId::of::<Baz>())
+            .unwrap();
+        let baz = reflect.get(&world).unwrap();
+        assert_eq!(baz.downcast_ref::<Baz>().unwrap().0, 2);
+    }
+
+    #[derive(Resource, Reflect)]
+    struct Qux;
+
+    #[test]
+    fn reflect_resources() {
+        let allocator = WorldIdAllocator::new();
+        let mut registry = TypeRegistry::new();
+
+        let mut a = World::new(allocator.alloc());
+        a.insert_resource(Baz(1));
+        a.insert_resource(Qux);
+        a.register_reflect_types(&mut registry);
+        // `Qux` is not opted in.
+        assert_eq!(a.reflect_resources(&registry).count(), 1);
+
+        let mut b = World::new(allocator.alloc());
+        b.insert_reflect_resources(&registry, a.reflect_resources(&registry), ResourceConflict::Skip)
+            .unwrap();
+        assert_eq!(b.get_resource::<Baz>().unwrap().0, 1);
+
+        // Dynamic values, e.g. deserialized from a scene file.
+        let dynamic = Baz(2).to_dynamic();
+        b.insert_reflect_resources(&registry, [&*dynamic], ResourceConflict::Skip)
+            .unwrap();
+        assert_eq!(b.get_resource::<Baz>().unwrap().0, 1);
+        b.insert_reflect_resources(&registry, [&*dynamic], ResourceConflict::Overwrite)
+            .unwrap();
+        assert_eq!(b.get_resource::<Baz>().unwrap().0, 2);
+
+        assert!(matches!(
+            b.insert_reflect_resources(&registry, [&Foo(3) as &dyn Reflect], ResourceConflict::Skip),
+            Err(ReflectResourceError::NotReflected(_))
+        ));
+    }
+
+    #[derive(Component, Reflect, Hash)]
+    #[component(reflect)]
+    #[reflect(hash)]
+    struct Position(i32);
+
+    #[derive(Component, Reflect, Hash)]
+    #[component(reflect)]
+    #[reflect(hash)]
+    struct Velocity(i32);
+
+    #[derive(Component, Reflect)]
+    #[component(reflect)]
+    struct Mass(f32);
+
+    #[test]
+    fn state_hash() {
+        let allocator = WorldIdAllocator::new();
+        let mut registry = TypeRegistry::new();
+
+        // Components are registered in a different order.
+        let mut a = World::new(allocator.alloc());
+        a.spawn((Position(1), Velocity(2)));
+        a.spawn(Position(3));
+        a.register_reflect_types(&mut registry);
+
+        let mut b = World::new(allocator.alloc());
+        b.register_component::<Velocity>();
+        b.spawn((Velocity(2), Position(1)));
+        b.spawn(Position(3));
+        b.register_component::<Mass>();
+        b.register_reflect_types(&mut registry);
+
+        let all = |world: &World| world.state_hash(&registry, |_| true).unwrap();
+        assert_eq!(all(&a), all(&b));
+
+        let entity = b.spawn(Position(4)).entity();
+        assert_ne!(all(&a), all(&b));
+        b.despawn(entity).unwrap();
+        let entity = b.spawn(Position(4)).entity();
+        assert_ne!(all(&a), all(&b));
+        b.despawn(entity).unwrap();
+
+        // Only selected components are hashed.
+        b.spawn(Mass(5.0));
+        let mass = b.components().get_id(TypeId::of::<Mass>()).unwrap();
+        let hash = b.state_hash(&registry, |info| info.id() != mass).unwrap();
+        assert_eq!(all(&a), hash);
+        assert!(matches!(
+            b.state_hash(&registry, |_| true),
+            Err(StateHashError::NotHashable(_))
+        ));
+        b.register_component::<Bar>();
+        assert!(matches!(
+            b.state_hash(&registry, |_| true),
+            Err(StateHashError::NotReflected(_))
+        ));
+    }
+}
@@ -1,13 +1,15 @@
 use core::any::TypeId;
+use core::ptr::NonNull;
 use core::sync::atomic::Ordering;
 
 use vc_ptr::{OwningPtr, PtrMut};
 
 use crate::borrow::{NonSendMut, NonSendRef, ResMut, ResRef};
-use crate::resource::{Resource, ResourceId};
-use crate::tick::Tick;
-use crate::utils::DebugCheckedUnwrap;
-use crate::world::World;
+use crate::entity::Entity;
+use crate::resource::{Resource, ResourceEntity, ResourceId};
+use crate::tick::{ChangeTicks, Tick};
+use crate::utils::{DebugCheckedUnwrap, DebugName};
+use crate::world::{FromWorld, World};
 
 #[inline(never)]
 fn insert_internal<'a, 'b>(
@@ -51,6 +53,43 @@ impl World {
         unsafe { insert_internal(self, value, id).consume::<T>() }
     }
 
+    /// Initializes a `Send` resource with [`FromWorld`] if it does not exist,
+    /// and returns a mutable reference to it.
+    ///
+    /// Existing resources are kept. [`FromWorld`] is implemented for every
+    /// [`Default`] type, so this also works with derived defaults.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_ecs::resource::Resource;
+    /// # use vc_ecs::world::World;
+    /// # let mut world = World::default();
+    /// #[derive(Resource, Default, Debug, PartialEq, Eq)]
+    /// struct Score(u64);
+    ///
+    /// assert_eq!(*world.init_resource::<Score>(), Score(0));
+    ///
+    /// world.insert_resource(Score(5));
+    /// assert_eq!(*world.init_resource::<Score>(), Score(5));
+    /// ```
+    pub fn init_resource<T: Resource + Send + FromWorld>(&mut self) -> &mut T {
+        let id = self.resources.register::<T>();
+        if !self
+            .storages
+            .res
+            .get(id)
+            .is_some_and(|data| data.is_active())
+        {
+            let value = T::from_world(self);
+            return self.insert_resource(value);
+        }
+        unsafe {
+            let data = self.storages.res.get_unchecked_mut(id);
+            data.get_data_mut().debug_checked_unwrap().consume::<T>()
+        }
+    }
+
     /// Removes and returns a `Send` resource if it exists.
     ///
     /// # Examples
@@ -407,12 +446,179 @@ impl World {
     }
 }
 
+// -----------------------------------------------------------------------------
+// Resource scope
+
+/// Reinserts the resource taken by [`World::try_resource_scope`] when dropped,
+/// including when the scope unwinds.
+struct ScopeGuard<T: Resource + Send> {
+    world: NonNull<World>,
+    id: ResourceId,
+    value: Option<T>,
+    ticks: ChangeTicks,
+}
+
+impl<T: Resource + Send> Drop for ScopeGuard<T> {
+    fn drop(&mut self) {
+        let Some(value) = self.value.take() else {
+            return;
+        };
+        // SAFETY: the `&mut World` given to the scope is no longer used.
+        let world = unsafe { self.world.as_mut() };
+        world.prepare_resource(self.id);
+        unsafe {
+            let data = world.storages.res.get_unchecked_mut(self.id);
+            // A value inserted during the scope is dropped.
+            data.drop_in_place::<T>();
+            data.insert(value, self.ticks.added);
+            data.set_ticks(self.ticks);
+        }
+    }
+}
+
+impl World {
+    /// Temporarily removes the `Send` resource `T`, and runs `f` with both the
+    /// world and the resource.
+    ///
+    /// This is the workaround for needing the world and a resource mutably at
+    /// the same time. The resource is reinserted afterwards with its change
+    /// ticks, even if `f` panics. While `f` runs, the world does not contain
+    /// the resource. If `f` inserts a new `T`, it is replaced by the scoped one.
+    ///
+    /// Returns `None` if the resource does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_ecs::resource::Resource;
+    /// # use vc_ecs::world::World;
+    /// # let mut world = World::default();
+    /// #[derive(Resource)]
+    /// struct Spawned(Vec<vc_ecs::entity::Entity>);
+    ///
+    /// world.insert_resource(Spawned(Vec::new()));
+    ///
+    /// let len = world.try_resource_scope(|world, mut spawned: vc_ecs::borrow::ResMut<Spawned>| {
+    ///     assert!(world.get_resource::<Spawned>().is_none());
+    ///     spawned.0.push(world.spawn(()).entity());
+    ///     spawned.0.len()
+    /// });
+    ///
+    /// assert_eq!(len, Some(1));
+    /// assert_eq!(world.get_resource::<Spawned>().unwrap().0.len(), 1);
+    /// ```
+    pub fn try_resource_scope<T: Resource + Send, R>(
+        &mut self,
+        f: impl FnOnce(&mut World, ResMut<'_, T>) -> R,
+    ) -> Option<R> {
+        let id = self.resources.get_id(TypeId::of::<T>())?;
+        let data = self.storages.res.get_mut(id)?;
+        let ticks = data.get_ticks()?;
+        let value = unsafe { data.remove::<T>()? };
+
+        let last_run = self.last_run;
+        let this_run = Tick::new(*self.this_run.get_mut());
+
+        let mut guard = ScopeGuard {
+            world: NonNull::from(&mut *self),
+            id,
+            value: Some(value),
+            ticks,
+        };
+        let res = ResMut {
+            value: guard.value.as_mut().unwrap(),
+            ticks: guard.ticks.ticks_mut(last_run, this_run),
+        };
+        // SAFETY: the resource is no longer in the world, so `res` does not
+        // alias it, and `guard` only accesses the world once dropped.
+        let world = unsafe { guard.world.as_mut() };
+        Some(f(world, res))
+    }
+
+    /// Like [`World::try_resource_scope`], but panics if the resource does
+    /// not exist.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource `T` does not exist.
+    pub fn resource_scope<T: Resource + Send, R>(
+        &mut self,
+        f: impl FnOnce(&mut World, ResMut<'_, T>) -> R,
+    ) -> R {
+        match self.try_resource_scope(f) {
+            Some(ret) => ret,
+            None => panic!("resource `{}` does not exist", DebugName::type_name::<T>()),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Resource entities
+
+impl World {
+    /// Returns the entity standing for the resource `T`, spawning it on first use.
+    ///
+    /// The entity carries a [`ResourceEntity`] component and nothing else,
+    /// the resource itself stays in the resource storage. This is an opt-in
+    /// bridge for the entity-based APIs: components, hooks and commands can
+    /// target the entity to treat the resource like any other entity.
+    ///
+    /// The same entity is returned until it is despawned or its
+    /// [`ResourceEntity`] is removed. The resource does not need to be inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_ecs::resource::{Resource, ResourceEntity};
+    /// # use vc_ecs::world::World;
+    /// # let mut world = World::default();
+    /// #[derive(Resource)]
+    /// struct Score(u64);
+    ///
+    /// let entity = world.resource_entity::<Score>();
+    /// assert_eq!(world.resource_entity::<Score>(), entity);
+    /// assert_eq!(world.get_resource_entity::<Score>(), Some(entity));
+    ///
+    /// let marker = world.entity_ref(entity).get::<ResourceEntity>().copied();
+    /// assert_eq!(marker.map(|m| m.id()), world.resources().get_id(core::any::TypeId::of::<Score>()));
+    /// ```
+    pub fn resource_entity<T: Resource>(&mut self) -> Entity {
+        let id = self.resources.register::<T>();
+        if let Some(entity) = self.get_resource_entity_by_id(id) {
+            return entity;
+        }
+
+        let entity = self.spawn(ResourceEntity::new(id)).entity();
+        unsafe {
+            self.resources.get_unchecked_mut(id).entity = Some(entity);
+        }
+        entity
+    }
+
+    /// Returns the entity standing for the resource `T`, if it was spawned by
+    /// [`World::resource_entity`] and is still attached to the resource.
+    pub fn get_resource_entity<T: Resource>(&self) -> Option<Entity> {
+        let id = self.resources.get_id(TypeId::of::<T>())?;
+        self.get_resource_entity_by_id(id)
+    }
+
+    /// Returns the entity standing for the resource `id`, if it was spawned by
+    /// [`World::resource_entity`] and is still attached to the resource.
+    pub fn get_resource_entity_by_id(&self, id: ResourceId) -> Option<Entity> {
+        let entity = self.resources.get(id)?.entity?;
+        self.entities.locate(entity).ok()?;
+        let marker = self.entity_ref(entity).get::<ResourceEntity>().copied();
+        (marker == Some(ResourceEntity::new(id))).then_some(entity)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::sync::atomic::Ordering;
     use vc_os::sync::atomic::AtomicUsize;
 
-    use crate::resource::Resource;
+    use crate::borrow::ResMut;
+    use crate::resource::{Resource, ResourceEntity};
     use crate::tick::DetectChanges;
     use crate::world::World;
 
@@ -612,4 +818,83 @@ mod tests {
         world.remove_resource::<DropTracker>();
         assert_eq!(DROP_COUNTER.load(Ordering::SeqCst), 20);
     }
+
+    #[test]
+    fn resource_entity() {
+        let mut world = World::default();
+        assert_eq!(world.get_resource_entity::<Foo>(), None);
+
+        let entity = world.resource_entity::<Foo>();
+        assert_eq!(world.resource_entity::<Foo>(), entity);
+        assert_ne!(world.resource_entity::<Bar>(), entity);
+        assert_eq!(world.get_resource_entity::<Foo>(), Some(entity));
+        assert_eq!(world.get_resource::<Foo>(), None);
+
+        world.entity_owned(entity).remove::<ResourceEntity>();
+        assert_eq!(world.get_resource_entity::<Foo>(), None);
+
+        let entity = world.resource_entity::<Foo>();
+        world.despawn(entity).unwrap();
+        assert_eq!(world.get_resource_entity::<Foo>(), None);
+        assert_ne!(world.resource_entity::<Foo>(), entity);
+    }
+
+    #[test]
+    fn resource_scope() {
+        let mut world = World::default();
+        assert_eq!(world.try_resource_scope(|_, _: ResMut<Bar>| ()), None);
+
+        world.insert_resource(Bar(1));
+        let added = world.get_resource_ref::<Bar>().unwrap().added_tick();
+        world.update_tick();
+
+        world.resource_scope(|world, mut bar: ResMut<Bar>| {
+            assert_eq!(world.get_resource::<Bar>(), None);
+            world.insert_resource(Bar(100));
+            bar.0 += 1;
+        });
+        let bar = world.get_resource_ref::<Bar>().unwrap();
+        assert_eq!(*bar, Bar(2));
+        assert_eq!(bar.added_tick(), added);
+        assert!(bar.is_changed());
+
+        crate::cfg::std! {
+            let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+                world.resource_scope(|_, mut bar: ResMut<Bar>| {
+                    bar.0 = 3;
+                    panic!("scope panicked");
+                })
+            }));
+            assert!(result.is_err());
+            assert_eq!(world.get_resource::<Bar>(), Some(&Bar(3)));
+        }
+    }
+
+    #[test]
+    fn init_resource() {
+        use crate::command::Commands;
+        use crate::world::FromWorld;
+
+        #[derive(Resource, Debug, PartialEq, Eq)]
+        struct Doubled(u64);
+
+        impl FromWorld for Doubled {
+            fn from_world(world: &mut World) -> Self {
+                Doubled(world.get_resource::<Bar>().map_or(0, |bar| bar.0 * 2))
+            }
+        }
+
+        let mut world = World::default();
+        world.insert_resource(Bar(21));
+        assert_eq!(*world.init_resource::<Doubled>(), Doubled(42));
+
+        world.insert_resource(Bar(1));
+        assert_eq!(*world.init_resource::<Doubled>(), Doubled(42));
+
+        world.remove_resource::<Doubled>();
+        Commands::new(&world).init_resource::<Doubled>();
+        assert_eq!(world.get_resource::<Doubled>(), None);
+        world.apply_commands();
+        assert_eq!(world.get_resource::<Doubled>(), Some(&Doubled(2)));
+    }
 }
@@ -0,0 +1,84 @@
+use crate::entity::Entity;
+use crate::spatial::{GridPosition, SpatialGrid};
+use crate::world::World;
+
+impl World {
+    /// Creates the [`SpatialGrid`] of the component `P`, if it does not exist.
+    ///
+    /// Entities that already have a `P` are added immediately. An existing
+    /// grid keeps its cell size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is not positive.
+    pub fn init_spatial_grid<P: GridPosition>(&mut self, cell_size: f32) -> &SpatialGrid<P> {
+        if self.get_resource::<SpatialGrid<P>>().is_none() {
+            let grid = SpatialGrid::<P>::new(cell_size);
+            for (entity, position) in self.query::<(Entity, &P)>() {
+                grid.insert(entity, position.grid_position());
+            }
+            self.insert_resource(grid);
+        }
+        self.get_resource::<SpatialGrid<P>>().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use crate::component::Component;
+    use crate::schedule::Schedule;
+    use crate::spatial::{GridPosition, SpatialGrid};
+    use crate::world::{World, WorldIdAllocator};
+
+    #[derive(Component)]
+    #[component(
+        mutable = true,
+        on_add = SpatialGrid::<Self>::on_add,
+        on_remove = SpatialGrid::<Self>::on_remove,
+    )]
+    struct Position([f32; 3]);
+
+    impl GridPosition for Position {
+        fn grid_position(&self) -> [f32; 3] {
+            self.0
+        }
+    }
+
+    #[test]
+    fn spatial_grid() {
+        let allocator = WorldIdAllocator::new();
+        let mut world = World::new(allocator.alloc());
+
+        let a = world.spawn(Position([-1.0, -1.0, 0.0])).entity();
+        world.init_spatial_grid::<Position>(4.0);
+        let b = world.spawn(Position([3.0, 0.0, 0.0])).entity();
+        let c = world.spawn(Position([100.0, 0.0, 0.0])).entity();
+
+        let grid = world.get_resource::<SpatialGrid<Position>>().unwrap();
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.query_radius([-1.0, -1.0, 0.0], 1.0), vec![a]);
+        let mut found = grid.query_aabb([-2.0; 3], [4.0; 3]);
+        found.sort_by_key(|e| e.index());
+        assert_eq!(found, vec![a, b]);
+        assert_eq!(grid.query_aabb([-1e9; 3], [1e9; 3]).len(), 3);
+
+        let mut schedule = Schedule::default();
+        schedule.add_system(SpatialGrid::<Position>::update);
+        schedule.run(&mut world);
+
+        world.update_tick();
+        world.entity_owned(c).get_mut::<Position>().unwrap().0 = [0.0, 0.0, 0.0];
+        schedule.run(&mut world);
+
+        let grid = world.get_resource::<SpatialGrid<Position>>().unwrap();
+        assert_eq!(grid.get(c), Some([0.0, 0.0, 0.0]));
+        assert!(grid.query_radius([100.0, 0.0, 0.0], 10.0).is_empty());
+
+        world.despawn(a).unwrap();
+        let grid = world.get_resource::<SpatialGrid<Position>>().unwrap();
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get(a), None);
+    }
+}
@@ -1,9 +1,13 @@
+use alloc::vec::Vec;
+use core::ptr::NonNull;
+
 use vc_ptr::OwningPtr;
 
-use crate::archetype::ArcheId;
+use crate::archetype::{ArcheFlags, ArcheId, ArcheRow};
 use crate::bundle::{Bundle, BundleId};
 use crate::component::ComponentWriter;
 use crate::entity::{Entity, EntityLocation};
+use crate::storage::TableRow;
 use crate::tick::Tick;
 use crate::utils::DebugCheckedUnwrap;
 use crate::world::{EntityOwned, World};
@@ -15,6 +19,7 @@ impl World {
     /// - Registers the bundle type (if needed).
     /// - Resolves or creates the matching archetype/table layout.
     /// - Allocates entity storage and writes all explicit/required components.
+    /// - Invokes the `ON_ADD` hooks of all components.
     ///
     /// The returned [`EntityOwned`] borrows the world and provides convenient
     /// typed access to the spawned entity.
@@ -56,6 +61,7 @@ impl World {
     /// - Registers the bundle type (if needed).
     /// - Resolves or creates the matching archetype/table layout.
     /// - Allocates entity storage and writes all explicit/required components.
+    /// - Invokes the `ON_ADD` hooks of all components.
     ///
     /// The returned [`EntityOwned`] borrows the world and provides convenient
     /// typed access to the spawned entity.
@@ -92,6 +98,160 @@ impl World {
         )
     }
 
+    /// Spawns an entity for each bundle of `iter` and returns them in order.
+    ///
+    /// Compared to calling [`spawn`](Self::spawn) in a loop, the archetype is
+    /// resolved only once, and table storage is reserved in advance based on
+    /// the lower bound of [`Iterator::size_hint`]. The bundles are buffered in
+    /// chunks, and each chunk is written column by column into consecutive
+    /// table rows.
+    ///
+    /// The `ON_ADD` hooks are invoked for each entity after its chunk is written.
+    /// If `iter` panics, the entities of the chunks written so far stay spawned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_ecs::world::World;
+    /// # use vc_ecs::component::Component;
+    /// #
+    /// # #[derive(Component, Debug, PartialEq, Eq)]
+    /// # struct Foo;
+    /// # #[derive(Component, Debug, PartialEq, Eq)]
+    /// # struct Bar(u64);
+    /// #
+    /// let mut world = World::default();
+    /// let entities = world.spawn_batch((0..100).map(|i| (Foo, Bar(i))));
+    ///
+    /// assert_eq!(entities.len(), 100);
+    /// assert_eq!(world.entity_ref(entities[42]).get::<Bar>(), Some(&Bar(42)));
+    /// ```
+    pub fn spawn_batch<I>(&mut self, iter: I) -> Vec<Entity>
+    where
+        I: IntoIterator,
+        I::Item: Bundle,
+    {
+        /// The maximum number of bundles buffered before they are written.
+        const CHUNK_SIZE: usize = 256;
+
+        let mut iter = iter.into_iter();
+        let bundle_id = self.register_bundle::<I::Item>();
+        let arche_id = self.register_archetype_by_bundle(bundle_id);
+        let tick = Tick::new(*self.this_run.get_mut());
+
+        let reserved = iter.size_hint().0;
+        let archetype = unsafe { self.archetypes.get_unchecked_mut(arche_id) };
+        archetype.reserve(reserved);
+        let table = unsafe { self.storages.tables.get_unchecked_mut(archetype.table_id()) };
+        table.reserve(reserved);
+
+        let mut entities = Vec::with_capacity(reserved);
+        let mut chunk = Vec::with_capacity(reserved.min(CHUNK_SIZE));
+        loop {
+            // Ids are allocated after the chunk is collected,
+            // so a panicking iterator cannot leak them.
+            chunk.extend(iter.by_ref().take(CHUNK_SIZE));
+            if chunk.is_empty() {
+                break;
+            }
+
+            let start = entities.len();
+            entities.extend(self.allocator.alloc_many(chunk.len() as u32));
+            unsafe {
+                self.spawn_chunk(&mut chunk, &entities[start..], arche_id, tick);
+            }
+        }
+
+        entities
+    }
+
+    /// Spawns `entities` with the bundles of `chunk`, leaving `chunk` empty.
+    ///
+    /// # Safety
+    /// - `arche_id` must be the archetype registered for `B`.
+    /// - `entities` must be allocated, not spawned, and as many as the bundles.
+    unsafe fn spawn_chunk<B: Bundle>(
+        &mut self,
+        chunk: &mut Vec<B>,
+        entities: &[Entity],
+        arche_id: ArcheId,
+        tick: Tick,
+    ) {
+        debug_assert_eq!(chunk.len(), entities.len());
+
+        let archetype = unsafe { self.archetypes.get_unchecked_mut(arche_id) };
+
+        let table_id = archetype.table_id();
+        let table = unsafe { self.storages.tables.get_unchecked_mut(table_id) };
+
+        let maps = &mut self.storages.maps;
+        let components = &self.components;
+
+        for &cid in archetype.sparse_components() {
+            unsafe {
+                let map_id = maps.get_id(cid).debug_checked_unwrap();
+                let map = maps.get_unchecked_mut(map_id);
+                for &entity in entities {
+                    let _ = map.allocate(entity);
+                }
+            }
+        }
+
+        let table_start = table.entity_count();
+        let arche_start = archetype.entities().len();
+        for &entity in entities {
+            if ::core::cfg!(debug_assertions) {
+                self.entities.can_spawn(entity).unwrap();
+            }
+            unsafe {
+                table.allocate(entity);
+                archetype.insert_entity(entity);
+            }
+        }
+
+        unsafe {
+            // The writer moves the bundles out, so they must not be dropped
+            // again, and are leaked if it panics.
+            chunk.set_len(0);
+            let data = OwningPtr::new(NonNull::new_unchecked(chunk.as_mut_ptr().cast()));
+            let table_row = TableRow::from_index_unchecked(table_start);
+            let mut writer = ComponentWriter::new_batch(
+                data,
+                size_of::<B>(),
+                entities,
+                table_row,
+                tick,
+                maps,
+                table,
+                components,
+            );
+
+            B::write_explicit(&mut writer, 0);
+            B::write_required(&mut writer);
+        }
+
+        for (index, &entity) in entities.iter().enumerate() {
+            let location = unsafe {
+                EntityLocation {
+                    arche_id,
+                    arche_row: ArcheRow::from_index_unchecked(arche_start + index),
+                    table_id,
+                    table_row: TableRow::from_index_unchecked(table_start + index),
+                }
+            };
+            unsafe {
+                self.entities.set_spawned(entity, location).unwrap();
+            }
+        }
+
+        let archetype = unsafe { self.archetypes.get_unchecked(arche_id) };
+        if archetype.flags().contains(ArcheFlags::ON_ADD_HOOK) {
+            for &entity in entities {
+                self.trigger_on_add(entity, archetype.components().iter().copied());
+            }
+        }
+    }
+
     #[inline(never)]
     fn spawn_internal(
         &mut self,
@@ -101,13 +261,35 @@ impl World {
         write_explicit: unsafe fn(&mut ComponentWriter, usize),
         write_required: unsafe fn(&mut ComponentWriter),
     ) -> EntityOwned<'_> {
+        let tick = Tick::new(*self.this_run.get_mut());
+        let arche_id = self.register_archetype_by_bundle(bundle_id);
+
+        let location =
+            unsafe { self.spawn_at(data, entity, arche_id, tick, write_explicit, write_required) };
+
+        EntityOwned {
+            world: self.unsafe_world(),
+            entity,
+            location,
+        }
+    }
+
+    /// # Safety
+    /// - `arche_id` must be the archetype registered for the bundle of `data`.
+    /// - `write_explicit` and `write_required` must belong to that bundle.
+    pub(super) unsafe fn spawn_at(
+        &mut self,
+        data: OwningPtr<'_>,
+        entity: Entity,
+        arche_id: ArcheId,
+        tick: Tick,
+        write_explicit: unsafe fn(&mut ComponentWriter, usize),
+        write_required: unsafe fn(&mut ComponentWriter),
+    ) -> EntityLocation {
         if ::core::cfg!(debug_assertions) {
             self.entities.can_spawn(entity).unwrap();
         }
 
-        let tick = Tick::new(*self.this_run.get_mut());
-
-        let arche_id = self.register_archetype_by_bundle(bundle_id);
         let archetype = unsafe { self.archetypes.get_unchecked_mut(arche_id) };
 
         let table_id = archetype.table_id();
@@ -129,7 +311,7 @@ impl World {
 
         unsafe {
             let mut writer =
-                ComponentWriter::new(data, entity, table_row, tick, maps, table, components);
+                ComponentWriter::new(data, &entity, table_row, tick, maps, table, components);
 
             write_explicit(&mut writer, 0);
             write_required(&mut writer);
@@ -146,15 +328,16 @@ impl World {
             self.entities.set_spawned(entity, location).unwrap();
         }
 
-        EntityOwned {
-            world: self.unsafe_world(),
-            entity,
-            location,
+        let archetype = unsafe { self.archetypes.get_unchecked(arche_id) };
+        if archetype.flags().contains(ArcheFlags::ON_ADD_HOOK) {
+            self.trigger_on_add(entity, archetype.components().iter().copied());
         }
+
+        location
     }
 
     #[inline]
-    fn register_archetype_by_bundle(&mut self, bundle_id: BundleId) -> ArcheId {
+    pub(super) fn register_archetype_by_bundle(&mut self, bundle_id: BundleId) -> ArcheId {
         if let Some(id) = self.archetypes.get_id_by_bundle(bundle_id) {
             id
         } else {
@@ -183,7 +366,9 @@ impl World {
         };
 
         unsafe {
-            let id = self.archetypes.register(table_id, dense_len, components);
+            let id = self
+                .archetypes
+                .register(&self.components, table_id, dense_len, components);
             self.archetypes.set_bundle_map(bundle_id, id);
             id
         }
@@ -192,9 +377,12 @@ impl World {
 
 #[cfg(test)]
 mod tests {
+    use crate::bundle::Bundle;
     use crate::component::{Component, ComponentStorage};
     use crate::world::World;
+    use alloc::format;
     use alloc::string::String;
+    use vc_os::sync::Arc;
 
     #[derive(Debug, PartialEq, Eq)]
     struct Foo;
@@ -242,4 +430,118 @@ mod tests {
         assert_eq!(entity.get::<Bar>().unwrap(), &Bar(123));
         assert_eq!(entity.get::<Baz>().unwrap(), &Baz(String::from("hello")));
     }
+
+    #[test]
+    fn insert_sparse() {
+        let mut world = World::default();
+
+        // Moving to an archetype with a new sparse component allocates its map row.
+        let mut entity = world.spawn(Foo);
+        entity.insert(Baz(String::from("hello")));
+        assert_eq!(entity.get::<Foo>().unwrap(), &Foo);
+        assert_eq!(entity.get::<Baz>().unwrap(), &Baz(String::from("hello")));
+
+        let entity = entity.entity();
+        let other = world.spawn((Foo, Baz(String::from("world")))).entity();
+        assert_eq!(
+            world.entity_ref(entity).get::<Baz>().unwrap(),
+            &Baz(String::from("hello"))
+        );
+        assert_eq!(
+            world.entity_ref(other).get::<Baz>().unwrap(),
+            &Baz(String::from("world"))
+        );
+    }
+
+    #[derive(Bundle)]
+    struct Inner(Foo, Bar);
+
+    #[derive(Bundle)]
+    struct Outer {
+        inner: Inner,
+        #[bundle(ignore)]
+        counter: Arc<()>,
+        baz: Baz,
+    }
+
+    #[test]
+    fn spawn_derived() {
+        let mut world = World::default();
+        let counter = Arc::new(());
+
+        let entity = world.spawn(Outer {
+            inner: Inner(Foo, Bar(123)),
+            counter: counter.clone(),
+            baz: Baz(String::from("hello")),
+        });
+        assert_eq!(entity.get::<Foo>().unwrap(), &Foo);
+        assert_eq!(entity.get::<Bar>().unwrap(), &Bar(123));
+        assert_eq!(entity.get::<Baz>().unwrap(), &Baz(String::from("hello")));
+
+        // The ignored field has been dropped.
+        assert_eq!(Arc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn spawn_batch() {
+        let mut world = World::default();
+
+        let entities = world.spawn_batch((0..10).map(|i| (Foo, Bar(i))));
+        assert_eq!(entities.len(), 10);
+
+        // `size_hint` has no useful lower bound.
+        let sparse = world.spawn_batch(
+            (0_usize..10)
+                .filter(|i| i % 2 == 0)
+                .map(|i| Baz(format!("{i}"))),
+        );
+        assert_eq!(sparse.len(), 5);
+
+        for (i, &entity) in entities.iter().enumerate() {
+            let entity = world.entity_ref(entity);
+            assert_eq!(entity.get::<Bar>(), Some(&Bar(i as u64)));
+        }
+        for (i, &entity) in sparse.iter().enumerate() {
+            let entity = world.entity_ref(entity);
+            assert_eq!(entity.get::<Baz>(), Some(&Baz(format!("{}", i * 2))));
+            assert!(!entity.contains::<Foo>());
+        }
+    }
+
+    #[test]
+    fn spawn_batch_chunks() {
+        #[derive(Component, Debug, Default, PartialEq, Eq)]
+        struct Level(u32);
+
+        #[derive(Component, Debug, PartialEq, Eq)]
+        #[component(required = Level)]
+        struct Player(u64);
+
+        let mut world = World::default();
+
+        // More than one chunk, with required and sparse components.
+        let entities = world.spawn_batch((0..600).map(|i| (Player(i), Baz(format!("{i}")))));
+        assert_eq!(entities.len(), 600);
+        for (i, &entity) in entities.iter().enumerate() {
+            let entity = world.entity_ref(entity);
+            assert_eq!(entity.get::<Player>(), Some(&Player(i as u64)));
+            assert_eq!(entity.get::<Level>(), Some(&Level(0)));
+            assert_eq!(entity.get::<Baz>(), Some(&Baz(format!("{i}"))));
+        }
+
+        crate::cfg::std! {
+            let count = world.entity_count();
+
+            // The first chunk is spawned, the ids of the second are never allocated.
+            let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+                world.spawn_batch((0..300).map(|i| {
+                    assert!(i < 280, "iterator panicked");
+                    Bar(i)
+                }))
+            }));
+            assert!(result.is_err());
+            assert_eq!(world.entity_count(), count + 256);
+            assert_eq!(world.query::<&Bar>().iter().count(), 256);
+        }
+    }
 }
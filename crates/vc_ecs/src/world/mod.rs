@@ -7,15 +7,29 @@
 // Modules
 
 mod access;
+mod diagnostics;
+mod from_world;
 mod ident;
 mod methods;
+mod pending;
 mod unsafe_world;
 mod world;
 
+crate::cfg::reflect! {
+    mod state_hash;
+}
+
 // -----------------------------------------------------------------------------
 // Exports
 
 pub use access::*;
+pub use diagnostics::{ComponentDiagnostics, SystemDiagnostics, WorldDiagnostics};
+pub use from_world::FromWorld;
 pub use ident::{WorldId, WorldIdAllocator};
-pub use unsafe_world::UnsafeWorld;
+pub use pending::PendingWorldOps;
+pub use unsafe_world::{CacheBuilder, UnsafeWorld, WorldMode};
 pub use world::World;
+
+crate::cfg::reflect! {
+    pub use state_hash::StateHashError;
+}
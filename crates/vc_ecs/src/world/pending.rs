@@ -0,0 +1,125 @@
+use core::fmt::Debug;
+
+use vc_os::utils::ListQueue;
+
+use crate::bundle::Bundle;
+use crate::command::{CommandObject, CommandQueue};
+use crate::entity::Entity;
+
+// -----------------------------------------------------------------------------
+// PendingWorldOps
+
+/// A thread-safe queue of structural changes, applied by [`World::flush`].
+///
+/// Structural changes require `&mut World`, while hooks, observers and
+/// systems running in parallel only have shared access. They can queue the
+/// changes here through [`World::pending`] instead, and every subsystem
+/// shares the same flush point.
+///
+/// The command queue of the world is part of it: [`Commands`] flush into the
+/// same queue as the insertions and removals queued here, so all deferred
+/// changes are applied in the order they were queued.
+///
+/// # Flush Order
+///
+/// [`World::apply_pending`] applies the queued operations in rounds, each
+/// round performs:
+///
+/// 1. Reservations: entities of [`World::reserve_entity`] are spawned empty.
+/// 2. Commands, including insertions and removals, in the order they were queued.
+/// 3. Despawns, in the order they were queued.
+///
+/// Operations queued while applying, e.g. by hooks, run in the next round,
+/// until the queue is empty. Operations on entities that do not exist
+/// anymore are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use vc_ecs::world::World;
+/// # use vc_ecs::component::Component;
+/// # #[derive(Component, Debug, PartialEq, Eq)]
+/// # struct Foo;
+/// let mut world = World::default();
+/// let a = world.spawn(()).entity();
+///
+/// // Only shared access is needed to queue changes.
+/// let b = world.reserve_entity();
+/// world.pending().insert(b, Foo);
+/// world.pending().despawn(a);
+///
+/// world.flush();
+/// assert!(world.entity_ref(b).contains::<Foo>());
+/// assert!(world.entities().locate(a).is_err());
+/// ```
+///
+/// [`World::flush`]: crate::world::World::flush
+/// [`World::pending`]: crate::world::World::pending
+/// [`World::apply_pending`]: crate::world::World::apply_pending
+/// [`World::reserve_entity`]: crate::world::World::reserve_entity
+/// [`Commands`]: crate::command::Commands
+pub struct PendingWorldOps {
+    pub(crate) reserved: ListQueue<Entity>,
+    pub(crate) commands: CommandQueue,
+    pub(crate) despawns: ListQueue<Entity>,
+}
+
+impl Debug for PendingWorldOps {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PendingWorldOps")
+            .field("reserved", &self.reserved.len())
+            .field("commands", &self.commands.len())
+            .field("despawns", &self.despawns.len())
+            .finish()
+    }
+}
+
+impl PendingWorldOps {
+    pub(crate) fn new() -> Self {
+        Self {
+            reserved: ListQueue::default(),
+            commands: CommandQueue::new(),
+            despawns: ListQueue::default(),
+        }
+    }
+
+    /// Returns the number of queued operations.
+    pub fn len(&self) -> usize {
+        self.reserved.len() + self.commands.len() + self.despawns.len()
+    }
+
+    /// Returns `true` if no operation is queued.
+    pub fn is_empty(&self) -> bool {
+        self.reserved.is_empty() && self.commands.is_empty() && self.despawns.is_empty()
+    }
+
+    /// Queues spawning an allocated entity without components.
+    pub(crate) fn reserve(&self, entity: Entity) {
+        self.reserved.push(entity);
+    }
+
+    /// Queues inserting a bundle into the entity, overwriting existing components.
+    #[track_caller]
+    pub fn insert<B: Bundle>(&self, entity: Entity, bundle: B) {
+        self.commands.push(CommandObject::new(move |world| {
+            let _ = world.try_insert(entity, bundle);
+            Ok(())
+        }));
+    }
+
+    /// Queues removing a bundle from the entity.
+    #[track_caller]
+    pub fn remove<B: Bundle>(&self, entity: Entity) {
+        self.commands.push(CommandObject::new(move |world| {
+            if world.entities.locate(entity).is_ok() {
+                world.entity_owned(entity).remove::<B>();
+            }
+            Ok(())
+        }));
+    }
+
+    /// Queues despawning the entity.
+    pub fn despawn(&self, entity: Entity) {
+        self.despawns.push(entity);
+    }
+}
@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+use crate::utils::DebugName;
+
+// -----------------------------------------------------------------------------
+// StateHashError
+
+/// An error returned by [`World::state_hash`].
+///
+/// [`World::state_hash`]: crate::world::World::state_hash
+#[derive(Error, Debug, Clone)]
+pub enum StateHashError {
+    /// The component is selected, but `ReflectComponent` is not registered for it.
+    #[error("component `{0}` is not registered with `ReflectComponent`")]
+    NotReflected(DebugName),
+    /// The component is selected, but its `reflect_hash` returns `None`.
+    #[error("component `{0}` does not support `reflect_hash`")]
+    NotHashable(DebugName),
+}
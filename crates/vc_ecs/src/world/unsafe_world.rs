@@ -2,8 +2,84 @@ use core::cell::UnsafeCell;
 use core::marker::PhantomData;
 use core::ptr::NonNull;
 
+use crate::utils::DebugName;
 use crate::world::World;
 
+crate::cfg::debug! {
+    use vc_os::sync::atomic::{AtomicUsize, Ordering};
+}
+
+// -----------------------------------------------------------------------------
+// WorldMode
+
+/// The kind of world access an [`UnsafeWorld`] was created from, or that a
+/// query cache needs.
+///
+/// Caches of read-only query data, such as `&T` or `EntityRef`, only read the
+/// world and can be built from any handle. Caches of mutable query data, such
+/// as `&mut T` or `EntityMut`, hand out mutable component references and must
+/// be built from a handle created from `&mut World`.
+///
+/// The mode of a handle and the outstanding cache builders are only tracked
+/// in debug mode, see [`UnsafeWorld::debug_check_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorldMode {
+    /// Created from, or only needs, `&World`.
+    Shared,
+    /// Created from, or needs, `&mut World`.
+    Exclusive,
+}
+
+impl WorldMode {
+    /// Returns [`WorldMode::Exclusive`] if either mode is exclusive.
+    ///
+    /// This is used to compute the mode of tuples of query data.
+    #[inline]
+    pub const fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Shared, Self::Shared) => Self::Shared,
+            _ => Self::Exclusive,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// CacheBuilder
+
+/// The number of query caches being built from a world, tracked in debug mode.
+#[cfg(any(debug_assertions, feature = "debug"))]
+#[derive(Default)]
+pub(crate) struct CacheBuilders {
+    /// Builders using a handle created from `&World`.
+    shared: AtomicUsize,
+    /// Builders of [`WorldMode::Exclusive`] caches.
+    exclusive: AtomicUsize,
+}
+
+/// An outstanding query cache builder, returned by
+/// [`UnsafeWorld::debug_check_mode`].
+///
+/// In debug mode, the builder is counted by the world until it is dropped.
+/// It should live as long as the cache is being built.
+#[must_use]
+pub struct CacheBuilder<'a> {
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    counter: Option<&'a AtomicUsize>,
+    _marker: PhantomData<&'a World>,
+}
+
+#[cfg(any(debug_assertions, feature = "debug"))]
+impl Drop for CacheBuilder<'_> {
+    fn drop(&mut self) {
+        if let Some(counter) = self.counter {
+            counter.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// UnsafeWorld
+
 /// A copyable raw handle to [`World`] with manually enforced borrow rules.
 ///
 /// `UnsafeWorld` is used in performance-sensitive internals where temporarily
@@ -13,9 +89,15 @@ use crate::world::World;
 ///
 /// The exposed methods are `unsafe` because the caller must uphold the borrow
 /// invariants required by Rust and by ECS world semantics.
+///
+/// In debug mode, the handle also records the [`WorldMode`] it was created
+/// from, so building a mutable query cache from a shared world panics instead
+/// of silently aliasing.
 #[derive(Clone, Copy)]
 pub struct UnsafeWorld<'a> {
     world: NonNull<World>,
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    mode: WorldMode,
     _marker: PhantomData<&'a UnsafeCell<World>>,
 }
 
@@ -25,20 +107,14 @@ unsafe impl Sync for UnsafeWorld<'_> {}
 impl<'a> From<&'a World> for UnsafeWorld<'a> {
     /// Creates an [`UnsafeWorld`] from a shared world reference.
     fn from(value: &'a World) -> Self {
-        UnsafeWorld {
-            world: NonNull::from_ref(value),
-            _marker: PhantomData,
-        }
+        value.unsafe_world()
     }
 }
 
 impl<'a> From<&'a mut World> for UnsafeWorld<'a> {
     /// Creates an [`UnsafeWorld`] from an exclusive world reference.
     fn from(value: &'a mut World) -> Self {
-        UnsafeWorld {
-            world: NonNull::from_mut(value),
-            _marker: PhantomData,
-        }
+        value.unsafe_world_mut()
     }
 }
 
@@ -47,9 +123,26 @@ impl World {
     ///
     /// This does not grant any additional guarantees by itself. Safety must be
     /// enforced by the code that later dereferences the returned handle.
+    ///
+    /// The handle is [`WorldMode::Shared`], use [`World::unsafe_world_mut`]
+    /// to build mutable query caches from it.
     pub const fn unsafe_world(&self) -> UnsafeWorld<'_> {
         UnsafeWorld {
             world: NonNull::from_ref(self),
+            #[cfg(any(debug_assertions, feature = "debug"))]
+            mode: WorldMode::Shared,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a raw-access handle to this world, created from exclusive access.
+    ///
+    /// Like [`World::unsafe_world`], but the handle is [`WorldMode::Exclusive`].
+    pub const fn unsafe_world_mut(&mut self) -> UnsafeWorld<'_> {
+        UnsafeWorld {
+            world: NonNull::from_mut(self),
+            #[cfg(any(debug_assertions, feature = "debug"))]
+            mode: WorldMode::Exclusive,
             _marker: PhantomData,
         }
     }
@@ -94,6 +187,60 @@ impl<'a> UnsafeWorld<'a> {
         unsafe { &mut *self.world.as_ptr() }
     }
 
+    /// Checks that a cache needing `mode` can be built from this handle,
+    /// and returns the builder counted until the cache is built.
+    ///
+    /// In debug mode, this panics with `name` if:
+    /// - `mode` is [`WorldMode::Exclusive`] but the handle was created
+    ///   from `&World`.
+    /// - `mode` is [`WorldMode::Exclusive`] while a cache is being built
+    ///   from a handle created from `&World`, or the other way around.
+    ///
+    /// Exclusive caches may be built at the same time, e.g. by parallel
+    /// systems, since their overlaps are checked by the access of systems.
+    /// This is a no-op in release mode.
+    #[inline(always)]
+    #[track_caller]
+    #[cfg_attr(
+        not(any(debug_assertions, feature = "debug")),
+        expect(unused_variables, reason = "unused in release mode")
+    )]
+    pub fn debug_check_mode(self, mode: WorldMode, name: DebugName) -> CacheBuilder<'a> {
+        crate::cfg::debug! {
+            if {
+                assert!(
+                    mode == WorldMode::Shared || self.mode == WorldMode::Exclusive,
+                    "`{name}` builds an exclusive cache from a shared world",
+                );
+
+                // Only the counters are accessed, which are atomic.
+                let builders = unsafe { &(*self.world.as_ptr()).cache_builders };
+                let (counter, other) = match (self.mode, mode) {
+                    (WorldMode::Shared, _) => (Some(&builders.shared), &builders.exclusive),
+                    (WorldMode::Exclusive, WorldMode::Exclusive) => {
+                        (Some(&builders.exclusive), &builders.shared)
+                    }
+                    (WorldMode::Exclusive, WorldMode::Shared) => (None, &builders.shared),
+                };
+                if let Some(counter) = counter {
+                    assert!(
+                        other.load(Ordering::SeqCst) == 0,
+                        "`{name}` builds a cache while a conflicting cache is being built",
+                    );
+                    counter.fetch_add(1, Ordering::SeqCst);
+                }
+                CacheBuilder {
+                    counter,
+                    _marker: PhantomData,
+                }
+            } else {
+                CacheBuilder {
+                    _marker: PhantomData,
+                }
+            }
+        }
+    }
+
     /// Returns the underlying non-null world pointer.
     ///
     /// This does not dereference the pointer.
@@ -7,14 +7,18 @@ use vc_os::sync::atomic::AtomicU32;
 
 use crate::archetype::Archetypes;
 use crate::bundle::Bundles;
-use crate::command::CommandQueue;
+use crate::command::{CommandObject, CommandQueue};
 use crate::component::Components;
 use crate::entity::{Entities, Entity, EntityAllocator};
 use crate::error::{DefaultErrorHandler, ErrorContext};
+use crate::name::NameLookup;
 use crate::resource::Resources;
 use crate::storage::Storages;
 use crate::tick::{CHECK_CYCLE, CheckTicks, Tick};
-use crate::world::{EntityMut, EntityOwned, EntityRef, WorldId, WorldIdAllocator};
+crate::cfg::debug! {
+    use crate::world::unsafe_world::CacheBuilders;
+}
+use crate::world::{EntityMut, EntityOwned, EntityRef, PendingWorldOps, WorldId, WorldIdAllocator};
 
 // -----------------------------------------------------------------------------
 // World
@@ -40,7 +44,10 @@ pub struct World {
     pub(crate) storages: Storages,
     pub(crate) bundles: Bundles,
     pub(crate) archetypes: Archetypes,
-    pub(crate) command_queue: CommandQueue,
+    pub(crate) pending: PendingWorldOps,
+    pub(crate) names: NameLookup,
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    pub(crate) cache_builders: CacheBuilders,
     pub(crate) this_run: AtomicU32,
     pub(crate) last_run: Tick,
     pub(crate) last_check: Tick,
@@ -58,7 +65,8 @@ impl Debug for World {
             .field("storages", &self.storages)
             .field("bundles", &self.bundles)
             .field("archetypes", &self.archetypes)
-            .field("command_queue", &self.command_queue)
+            .field("pending", &self.pending)
+            .field("names", &self.names)
             .finish()
     }
 }
@@ -83,7 +91,10 @@ impl World {
             storages: Storages::new(),
             bundles: Bundles::new(),
             archetypes: Archetypes::new(),
-            command_queue: CommandQueue::new(),
+            pending: PendingWorldOps::new(),
+            names: NameLookup::new(),
+            #[cfg(any(debug_assertions, feature = "debug"))]
+            cache_builders: CacheBuilders::default(),
             this_run: AtomicU32::new(1),
             last_run: Tick::new(0),
             last_check: Tick::new(0),
@@ -180,9 +191,14 @@ impl World {
         &mut self.archetypes
     }
 
-    /// Returns the archetype registry.
+    /// Returns the queue of deferred commands, part of [`World::pending`].
     pub fn command_queue(&self) -> &CommandQueue {
-        &self.command_queue
+        &self.pending.commands
+    }
+
+    /// Returns the index of named entities.
+    pub fn names(&self) -> &NameLookup {
+        &self.names
     }
 }
 
@@ -271,17 +287,43 @@ impl World {
             .unwrap_or_default()
     }
 
+    /// Applies the queued commands until the queue is empty.
+    ///
+    /// Reservations and despawns of [`World::pending`] are not applied,
+    /// see [`World::flush`] to apply all deferred changes.
     pub fn apply_commands(&mut self) {
         let handler = self.default_error_handler();
 
-        while let Some(cmd) = self.command_queue.pop() {
-            let location = cmd.location();
-            if let Err(err) = cmd.run(self) {
-                vc_utils::cold_path();
-                let this_run = self.this_run();
-                let ctx = ErrorContext::Command { location, this_run };
-                (handler)(err, ctx);
-            }
+        while let Some(cmd) = self.pending.commands.pop() {
+            self.apply_command(cmd, handler);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn apply_command(&mut self, cmd: CommandObject, handler: DefaultErrorHandler) {
+        let location = cmd.location();
+        if let Err(err) = cmd.run(self) {
+            vc_utils::cold_path();
+            let this_run = self.this_run();
+            let ctx = ErrorContext::Command { location, this_run };
+            (handler)(err, ctx);
         }
     }
+
+    /// Applies all deferred changes, then performs storage maintenance.
+    ///
+    /// The [`pending`](Self::pending) structural changes and the queued
+    /// commands share one queue, see [`PendingWorldOps`] for the order in
+    /// which they are applied. Changes queued by commands or hooks are
+    /// applied in the same flush.
+    ///
+    /// Sparse component maps that became mostly empty after many removals
+    /// are repacked, so iterating them stays proportional to their length.
+    ///
+    /// This is called by [`Schedule::run`](crate::schedule::Schedule::run)
+    /// after the systems have run.
+    pub fn flush(&mut self) {
+        self.apply_pending();
+        self.storages.maps.maintain();
+    }
 }
@@ -0,0 +1,113 @@
+//! Minimal asynchronous file system access, requires the `std` feature.
+//!
+//! - [`read`] and [`write`] run [`std::fs::read`] and [`std::fs::write`] on
+//!   a background thread.
+//! - [`watch`] returns a [`Watcher`], a [`Stream`] of [`FsEvent`]s for a file
+//!   or directory, which is the portable base for asset hot-reloading.
+//!
+//! There is no OS notification backend, the watcher polls the modification
+//! times in a background thread. In `web` environments there is no file
+//! system, the watcher ends immediately and never reports events.
+//!
+//! [`Stream`]: futures_core::Stream
+
+mod watcher;
+
+pub use watcher::{FsEvent, Watcher, watch, watch_with_interval};
+
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::io;
+use std::path::Path;
+
+use crate::sync::{Mutex, PoisonError};
+
+/// Reads the entire contents of a file.
+///
+/// The file is read on a background thread, so the calling task is not
+/// blocked.
+///
+/// See [`std::fs::read`].
+pub async fn read(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+    let path = path.as_ref().to_path_buf();
+    unblock(move || std::fs::read(path)).await
+}
+
+/// Writes `contents` to a file, replacing it if it exists.
+///
+/// The contents are copied and written on a background thread, so the
+/// calling task is not blocked.
+///
+/// See [`std::fs::write`].
+pub async fn write(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let path = path.as_ref().to_path_buf();
+    let contents = contents.as_ref().to_vec();
+    unblock(move || std::fs::write(path, contents)).await
+}
+
+// -----------------------------------------------------------------------------
+// Unblock
+
+struct Slot<T> {
+    output: Option<T>,
+    waker: Option<Waker>,
+}
+
+/// A future waiting for a closure running on a background thread.
+struct Unblock<T> {
+    slot: Arc<Mutex<Slot<io::Result<T>>>>,
+}
+
+impl<T> Future for Unblock<T> {
+    type Output = io::Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<T>> {
+        let mut slot = self.slot.lock().unwrap_or_else(PoisonError::into_inner);
+        match slot.output.take() {
+            Some(output) => Poll::Ready(output),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Runs the blocking `f` on a new thread.
+///
+/// In `web` environments `f` runs on the calling thread instead. If the
+/// thread cannot be spawned, the future completes with the error.
+fn unblock<T: Send + 'static>(
+    f: impl FnOnce() -> io::Result<T> + Send + 'static,
+) -> Unblock<T> {
+    let slot = Arc::new(Mutex::new(Slot {
+        output: None,
+        waker: None,
+    }));
+
+    crate::cfg::switch! {
+        crate::cfg::web => {
+            slot.lock().unwrap_or_else(PoisonError::into_inner).output = Some(f());
+        }
+        _ => {
+            let shared = slot.clone();
+            let spawned = std::thread::Builder::new()
+                .name("vc_os::fs".into())
+                .spawn(move || {
+                    let output = f();
+                    let mut slot = shared.lock().unwrap_or_else(PoisonError::into_inner);
+                    slot.output = Some(output);
+                    if let Some(waker) = slot.waker.take() {
+                        waker.wake();
+                    }
+                });
+            if let Err(err) = spawned {
+                slot.lock().unwrap_or_else(PoisonError::into_inner).output = Some(Err(err));
+            }
+        }
+    }
+
+    Unblock { slot }
+}
@@ -0,0 +1,249 @@
+use alloc::collections::VecDeque;
+use alloc::sync::{Arc, Weak};
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use futures_core::Stream;
+
+use crate::sync::{Mutex, PoisonError};
+
+/// The interval used by [`watch`].
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+// -----------------------------------------------------------------------------
+// FsEvent
+
+/// A change of a watched file, reported by a [`Watcher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FsEvent {
+    /// The file was created.
+    Created(PathBuf),
+    /// The modification time of the file changed.
+    Modified(PathBuf),
+    /// The file was removed.
+    Removed(PathBuf),
+}
+
+impl FsEvent {
+    /// Returns the path of the changed file.
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Created(path) | Self::Modified(path) | Self::Removed(path) => path,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Watcher
+
+#[derive(Default)]
+struct Queue {
+    events: VecDeque<FsEvent>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+/// A [`Stream`] of the [`FsEvent`]s of a file or directory, see [`watch`].
+///
+/// Dropping the watcher stops the background thread at its next poll.
+pub struct Watcher {
+    queue: Arc<Mutex<Queue>>,
+}
+
+impl Stream for Watcher {
+    type Item = FsEvent;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<FsEvent>> {
+        let mut queue = self.queue.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(event) = queue.events.pop_front() {
+            Poll::Ready(Some(event))
+        } else if queue.closed {
+            Poll::Ready(None)
+        } else {
+            queue.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl core::fmt::Debug for Watcher {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Watcher").finish_non_exhaustive()
+    }
+}
+
+/// Watches a file or directory, polling every 500 milliseconds.
+///
+/// See [`watch_with_interval`].
+pub fn watch(path: impl AsRef<Path>) -> io::Result<Watcher> {
+    watch_with_interval(path, DEFAULT_INTERVAL)
+}
+
+/// Watches a file or directory, polling every `interval`.
+///
+/// Directories are watched recursively, events are only reported for files.
+/// Changes made within one interval are merged, e.g. a file created and
+/// removed in between two polls is not reported.
+///
+/// Returns an error if `path` does not exist or the thread cannot be spawned.
+/// In `web` environments, the watcher ends immediately.
+pub fn watch_with_interval(path: impl AsRef<Path>, interval: Duration) -> io::Result<Watcher> {
+    let queue = Arc::new(Mutex::new(Queue::default()));
+
+    crate::cfg::switch! {
+        crate::cfg::web => {
+            let _ = (path, interval);
+            queue.lock().unwrap_or_else(PoisonError::into_inner).closed = true;
+        }
+        _ => {
+            let root = path.as_ref().to_path_buf();
+            std::fs::metadata(&root)?;
+
+            // Taken before returning, so later changes are always reported.
+            let mut files = HashMap::new();
+            snapshot(&root, &mut files);
+
+            let weak = Arc::downgrade(&queue);
+            std::thread::Builder::new()
+                .name("vc_os::fs::watch".into())
+                .spawn(move || poll_loop(root, files, interval, weak))?;
+        }
+    }
+
+    Ok(Watcher { queue })
+}
+
+#[cfg_attr(
+    all(target_arch = "wasm32", feature = "web"),
+    expect(dead_code, reason = "no file system")
+)]
+fn poll_loop(
+    root: PathBuf,
+    mut files: HashMap<PathBuf, Option<SystemTime>>,
+    interval: Duration,
+    queue: Weak<Mutex<Queue>>,
+) {
+    loop {
+        std::thread::sleep(interval);
+        let Some(queue) = queue.upgrade() else {
+            return;
+        };
+
+        let mut current = HashMap::with_capacity(files.len());
+        snapshot(&root, &mut current);
+
+        let mut events = VecDeque::new();
+        for (path, modified) in &current {
+            match files.get(path) {
+                None => events.push_back(FsEvent::Created(path.clone())),
+                Some(old) if old != modified => events.push_back(FsEvent::Modified(path.clone())),
+                Some(_) => {}
+            }
+        }
+        for path in files.keys() {
+            if !current.contains_key(path) {
+                events.push_back(FsEvent::Removed(path.clone()));
+            }
+        }
+        files = current;
+
+        if !events.is_empty() {
+            let mut queue = queue.lock().unwrap_or_else(PoisonError::into_inner);
+            queue.events.append(&mut events);
+            if let Some(waker) = queue.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// Collects the modification time of the files under `path`, ignoring errors.
+#[cfg_attr(
+    all(target_arch = "wasm32", feature = "web"),
+    expect(dead_code, reason = "no file system")
+)]
+fn snapshot(path: &Path, files: &mut HashMap<PathBuf, Option<SystemTime>>) {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return;
+    };
+    if metadata.is_dir() {
+        let Ok(entries) = std::fs::read_dir(path) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            snapshot(&entry.path(), files);
+        }
+    } else {
+        files.insert(path.to_path_buf(), metadata.modified().ok());
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+    use core::pin::Pin;
+    use core::task::{Context, Poll, Waker};
+    use core::time::Duration;
+
+    use futures_core::Stream;
+
+    use super::{FsEvent, watch_with_interval};
+    use crate::fs::{read, write};
+
+    #[test]
+    fn watch_directory() {
+        let dir = std::env::temp_dir().join(std::format!("vc_os_watch_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("asset.txt");
+
+        let mut watcher = watch_with_interval(&dir, Duration::from_millis(5)).unwrap();
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut next = || {
+            for _ in 0..1000 {
+                if let Poll::Ready(event) = Pin::new(&mut watcher).poll_next(&mut cx) {
+                    return event;
+                }
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            None
+        };
+
+        block_on(write(&file, b"foo")).unwrap();
+        assert_eq!(next(), Some(FsEvent::Created(file.clone())));
+        assert_eq!(block_on(read(&file)).unwrap(), b"foo");
+
+        std::fs::remove_file(&file).unwrap();
+        assert_eq!(next(), Some(FsEvent::Removed(file.clone())));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Polls `future` until it completes, parking the thread in between.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        struct Unpark(std::thread::Thread);
+
+        impl alloc::task::Wake for Unpark {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = Waker::from(Arc::new(Unpark(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = core::pin::pin!(future);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+}
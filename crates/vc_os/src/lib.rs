@@ -12,6 +12,8 @@ pub mod cfg {
     vc_cfg::define_alias! {
         #[cfg(feature = "std")] => std,
         #[cfg(all(target_arch = "wasm32", feature = "web"))] => web,
+        #[cfg(feature = "log")] => log,
+        #[cfg(feature = "tracing")] => tracing,
     }
 }
 
@@ -25,6 +27,11 @@ extern crate alloc;
 // -----------------------------------------------------------------------------
 // Modules
 
+cfg::std! {
+    pub mod fs;
+}
+
+pub mod log;
 pub mod sync;
 pub mod thread;
 pub mod time;
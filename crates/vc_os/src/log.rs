@@ -0,0 +1,342 @@
+//! A minimal logging facade, usable in `no_std` environments.
+//!
+//! Lower-level crates emit diagnostics through the macros of this module
+//! without depending on a logging framework:
+//!
+//! ```
+//! use vc_os::log;
+//!
+//! log::warn!("pool {} has {} pending tasks", "compute", 3);
+//! log::debug!(target: "vc_ecs::storage", "table grown");
+//! ```
+//!
+//! Each message becomes a [`Record`], which is passed to the [`Sink`] set by
+//! [`set_sink`]. Without a sink, records are forwarded depending on features:
+//!
+//! - `log`: forwarded to the [`log`](https://docs.rs/log) crate.
+//! - `tracing`: forwarded to the [`tracing`](https://docs.rs/tracing) crate,
+//!   the target is recorded as the `log.target` field. Unused if `log` is
+//!   also enabled, to avoid duplicated messages.
+//! - Otherwise, records are discarded.
+//!
+//! Records above [`max_level`] are discarded before formatting.
+
+use core::fmt::{self, Arguments, Display};
+
+use crate::sync::atomic::{AtomicU8, Ordering};
+use crate::sync::{PoisonError, RwLock};
+
+// -----------------------------------------------------------------------------
+// Level
+
+/// The severity of a [`Record`], ordered from [`Error`](Level::Error) to
+/// [`Trace`](Level::Trace).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Level {
+    /// Something failed.
+    Error = 1,
+    /// Something is likely wrong, e.g. a stalled task.
+    Warn,
+    /// Useful information.
+    Info,
+    /// Information for debugging.
+    Debug,
+    /// Very verbose information.
+    Trace,
+}
+
+impl Level {
+    /// Returns the uppercase name of the level, e.g. `"WARN"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        }
+    }
+
+    const fn from_u8(value: u8) -> Option<Level> {
+        match value {
+            1 => Some(Level::Error),
+            2 => Some(Level::Warn),
+            3 => Some(Level::Info),
+            4 => Some(Level::Debug),
+            5 => Some(Level::Trace),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Record
+
+/// A single log message, passed to the [`Sink`].
+#[derive(Debug, Clone, Copy)]
+pub struct Record<'a> {
+    level: Level,
+    target: &'a str,
+    args: Arguments<'a>,
+}
+
+impl<'a> Record<'a> {
+    /// Creates a record, usually done by the macros of this module.
+    pub const fn new(level: Level, target: &'a str, args: Arguments<'a>) -> Self {
+        Self {
+            level,
+            target,
+            args,
+        }
+    }
+
+    /// Returns the severity of the message.
+    pub const fn level(&self) -> Level {
+        self.level
+    }
+
+    /// Returns the target, the module path of the caller by default.
+    pub const fn target(&self) -> &'a str {
+        self.target
+    }
+
+    /// Returns the unformatted message.
+    pub const fn args(&self) -> &Arguments<'a> {
+        &self.args
+    }
+}
+
+impl Display for Record<'_> {
+    /// Formats the record as `LEVEL target: message`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.level, self.target, self.args)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Sink
+
+/// A receiver of [`Record`]s, see [`set_sink`].
+///
+/// This is implemented for closures taking a `&Record`.
+pub trait Sink: Send + Sync {
+    /// Handles a record, which is below [`max_level`].
+    fn log(&self, record: &Record<'_>);
+}
+
+impl<F: Fn(&Record<'_>) + Send + Sync> Sink for F {
+    fn log(&self, record: &Record<'_>) {
+        self(record)
+    }
+}
+
+static SINK: RwLock<Option<&'static dyn Sink>> = RwLock::new(None);
+
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Trace as u8);
+
+/// Sets the sink receiving all records, replacing the feature-based forwarding.
+///
+/// Use `None` to restore the default behavior.
+pub fn set_sink(sink: Option<&'static dyn Sink>) {
+    *SINK.write().unwrap_or_else(PoisonError::into_inner) = sink;
+}
+
+/// Returns the most verbose level that is not discarded, `None` if all are.
+pub fn max_level() -> Option<Level> {
+    Level::from_u8(MAX_LEVEL.load(Ordering::Relaxed))
+}
+
+/// Sets the most verbose level that is not discarded, `None` to discard all.
+///
+/// Defaults to [`Level::Trace`].
+pub fn set_max_level(level: Option<Level>) {
+    MAX_LEVEL.store(level.map_or(0, |level| level as u8), Ordering::Relaxed);
+}
+
+/// Returns `true` if records of this level are not discarded.
+#[inline]
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+/// Passes a record to the sink, or forwards it. Called by the macros.
+#[doc(hidden)]
+pub fn __private_log(level: Level, target: &str, args: Arguments<'_>) {
+    let record = Record::new(level, target, args);
+    let sink = *SINK.read().unwrap_or_else(PoisonError::into_inner);
+    match sink {
+        Some(sink) => sink.log(&record),
+        None => forward(&record),
+    }
+}
+
+fn forward(record: &Record<'_>) {
+    crate::cfg::switch! {
+        crate::cfg::log => {
+            let level = match record.level {
+                Level::Error => ::log::Level::Error,
+                Level::Warn => ::log::Level::Warn,
+                Level::Info => ::log::Level::Info,
+                Level::Debug => ::log::Level::Debug,
+                Level::Trace => ::log::Level::Trace,
+            };
+            if level <= ::log::max_level() {
+                ::log::logger().log(
+                    &::log::Record::builder()
+                        .level(level)
+                        .target(record.target)
+                        .args(record.args)
+                        .build(),
+                );
+            }
+        }
+        crate::cfg::tracing => {
+            let target = record.target;
+            let args = record.args;
+            match record.level {
+                Level::Error => ::tracing::error!(log.target = target, "{args}"),
+                Level::Warn => ::tracing::warn!(log.target = target, "{args}"),
+                Level::Info => ::tracing::info!(log.target = target, "{args}"),
+                Level::Debug => ::tracing::debug!(log.target = target, "{args}"),
+                Level::Trace => ::tracing::trace!(log.target = target, "{args}"),
+            }
+        }
+        _ => {
+            let _ = record;
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Macros
+
+/// Logs a message at the given [`Level`].
+///
+/// ```
+/// use vc_os::log::{self, Level};
+///
+/// log::log!(Level::Info, "loaded {} assets", 3);
+/// log::log!(target: "assets", Level::Info, "loaded {} assets", 3);
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log {
+    (target: $target:expr, $level:expr, $($arg:tt)+) => {{
+        let level: $crate::log::Level = $level;
+        if $crate::log::enabled(level) {
+            $crate::log::__private_log(level, $target, ::core::format_args!($($arg)+));
+        }
+    }};
+    ($level:expr, $($arg:tt)+) => {
+        $crate::__log!(target: ::core::module_path!(), $level, $($arg)+)
+    };
+}
+
+/// Logs a message at the [`Error`](Level::Error) level, see [`log!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_error {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::__log!(target: $target, $crate::log::Level::Error, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::__log!($crate::log::Level::Error, $($arg)+)
+    };
+}
+
+/// Logs a message at the [`Warn`](Level::Warn) level, see [`log!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_warn {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::__log!(target: $target, $crate::log::Level::Warn, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::__log!($crate::log::Level::Warn, $($arg)+)
+    };
+}
+
+/// Logs a message at the [`Info`](Level::Info) level, see [`log!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_info {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::__log!(target: $target, $crate::log::Level::Info, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::__log!($crate::log::Level::Info, $($arg)+)
+    };
+}
+
+/// Logs a message at the [`Debug`](Level::Debug) level, see [`log!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_debug {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::__log!(target: $target, $crate::log::Level::Debug, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::__log!($crate::log::Level::Debug, $($arg)+)
+    };
+}
+
+/// Logs a message at the [`Trace`](Level::Trace) level, see [`log!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_trace {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::__log!(target: $target, $crate::log::Level::Trace, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::__log!($crate::log::Level::Trace, $($arg)+)
+    };
+}
+
+#[doc(inline)]
+pub use crate::{
+    __log as log, __log_debug as debug, __log_error as error, __log_info as info,
+    __log_trace as trace, __log_warn as warn,
+};
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    use super::{Level, Record};
+    use crate::sync::Mutex;
+
+    static RECORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    fn collect(record: &Record<'_>) {
+        RECORDS.lock().unwrap().push(record.to_string());
+    }
+
+    #[test]
+    fn sink_and_max_level() {
+        super::set_sink(Some(&collect));
+        super::set_max_level(Some(Level::Info));
+
+        super::warn!("{} tasks", 3);
+        super::debug!("discarded");
+        super::info!(target: "custom", "done");
+
+        super::set_max_level(Some(Level::Trace));
+        super::set_sink(None);
+
+        assert_eq!(
+            *RECORDS.lock().unwrap(),
+            ["WARN vc_os::log::tests: 3 tasks", "INFO custom: done"]
+        );
+    }
+}
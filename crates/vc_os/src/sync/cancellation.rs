@@ -0,0 +1,272 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+use crate::sync::atomic::AtomicBool;
+use crate::sync::atomic::Ordering::{Acquire, Release};
+use crate::sync::{Arc, Weak};
+use crate::utils::SpinLock;
+
+// -----------------------------------------------------------------------------
+// CancellationToken
+
+/// A token for cooperative cancellation of long-running work.
+///
+/// Clones share the same state: cancelling one of them cancels all.
+/// The work holding the token checks [`is_cancelled`] at convenient points,
+/// or awaits [`cancelled`] to be woken when it happens.
+///
+/// [`child_token`] creates a token that is cancelled together with its
+/// parent, but can also be cancelled on its own without affecting the
+/// parent. This allows a token per level or subsystem, all stopped by a
+/// single root token on shutdown.
+///
+/// Nothing is interrupted forcefully, the work must observe the token.
+///
+/// # Examples
+///
+/// ```
+/// use vc_os::sync::CancellationToken;
+///
+/// let root = CancellationToken::new();
+/// let level = root.child_token();
+///
+/// let worker = level.clone();
+/// let work = move || {
+///     let mut steps = 0;
+///     while !worker.is_cancelled() && steps < 1000 {
+///         steps += 1;
+///         # if steps == 10 { worker.cancel(); }
+///     }
+///     steps
+/// };
+///
+/// assert_eq!(work(), 10);
+/// assert!(level.is_cancelled());
+/// assert!(!root.is_cancelled());
+///
+/// // Cancelling the root cancels all children.
+/// let other = root.child_token();
+/// root.cancel();
+/// assert!(other.is_cancelled());
+/// ```
+///
+/// [`is_cancelled`]: Self::is_cancelled
+/// [`cancelled`]: Self::cancelled
+/// [`child_token`]: Self::child_token
+#[derive(Clone)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    cancelled: AtomicBool,
+    state: SpinLock<State>,
+}
+
+#[derive(Default)]
+struct State {
+    wakers: Vec<Waker>,
+    children: Vec<Weak<Inner>>,
+}
+
+impl Inner {
+    fn new(cancelled: bool) -> Self {
+        Self {
+            cancelled: AtomicBool::new(cancelled),
+            state: SpinLock::new(State::default()),
+        }
+    }
+
+    #[inline]
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Acquire)
+    }
+
+    /// Sets the flag and takes the wakers and children to notify,
+    /// returns `None` if already cancelled.
+    fn take_state(&self) -> Option<State> {
+        if self.cancelled.swap(true, Release) {
+            return None;
+        }
+        // Taken under the lock, registrations check the flag under it as well.
+        Some(core::mem::take(&mut *self.state.lock()))
+    }
+}
+
+impl CancellationToken {
+    /// Creates a new token that is not cancelled.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner::new(false)),
+        }
+    }
+
+    /// Creates a child token.
+    ///
+    /// The child is cancelled when `self` is, immediately if `self` is
+    /// already cancelled. Cancelling the child does not affect `self`.
+    pub fn child_token(&self) -> Self {
+        let mut state = self.inner.state.lock();
+        if self.inner.is_cancelled() {
+            return Self {
+                inner: Arc::new(Inner::new(true)),
+            };
+        }
+
+        let child = Arc::new(Inner::new(false));
+        // Forget the children that are already gone.
+        state.children.retain(|c| c.strong_count() > 0);
+        state.children.push(Arc::downgrade(&child));
+        Self { inner: child }
+    }
+
+    /// Cancels this token and all of its children.
+    ///
+    /// Wakes all tasks waiting on [`cancelled`](Self::cancelled).
+    /// Cancelling again has no effect.
+    pub fn cancel(&self) {
+        let mut pending = Vec::new();
+        let Some(state) = self.inner.take_state() else {
+            return;
+        };
+        pending.push(state);
+
+        // Iterative, so that deep token trees do not overflow the stack.
+        while let Some(State { wakers, children }) = pending.pop() {
+            wakers.into_iter().for_each(Waker::wake);
+            for child in children {
+                if let Some(child) = child.upgrade()
+                    && let Some(state) = child.take_state()
+                {
+                    pending.push(state);
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if this token has been cancelled.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.is_cancelled()
+    }
+
+    /// Returns a future that completes once this token is cancelled.
+    ///
+    /// The future registers the waker of the polling task, so it works with
+    /// any executor, including the task pools of `vc_task`.
+    #[inline]
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled { token: self }
+    }
+}
+
+impl Default for CancellationToken {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("is_cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Cancelled
+
+/// A future that completes once a [`CancellationToken`] is cancelled.
+///
+/// Created by [`CancellationToken::cancelled`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Cancelled<'a> {
+    token: &'a CancellationToken,
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let inner = &self.token.inner;
+        if inner.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        let mut state = inner.state.lock();
+        if inner.is_cancelled() {
+            return Poll::Ready(());
+        }
+        if !state.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            state.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+impl fmt::Debug for Cancelled<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cancelled")
+            .field("token", self.token)
+            .finish()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+    use alloc::task::Wake;
+    use core::future::Future;
+    use core::pin::pin;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use core::task::{Context, Poll, Waker};
+
+    use super::CancellationToken;
+
+    struct Counter(AtomicUsize);
+
+    impl Wake for Counter {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn cancel_tree() {
+        let counter = Arc::new(Counter(AtomicUsize::new(0)));
+        let waker = Waker::from(counter.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        let root = CancellationToken::new();
+        let child = root.child_token();
+        let grandchild = child.child_token();
+        let sibling = root.child_token();
+
+        let mut waiting = pin!(grandchild.cancelled());
+        assert!(waiting.as_mut().poll(&mut cx).is_pending());
+        assert!(waiting.as_mut().poll(&mut cx).is_pending());
+
+        sibling.cancel();
+        assert!(!root.is_cancelled());
+        assert!(!grandchild.is_cancelled());
+
+        root.cancel();
+        assert!(child.is_cancelled() && grandchild.is_cancelled());
+        assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+        assert_eq!(waiting.as_mut().poll(&mut cx), Poll::Ready(()));
+
+        // Children of cancelled tokens start cancelled.
+        assert!(root.child_token().is_cancelled());
+        root.cancel();
+        assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+    }
+}
@@ -41,6 +41,8 @@
 // -----------------------------------------------------------------------------
 // Modules
 
+mod cancellation;
+mod once_cell_async;
 mod sync_cell;
 mod sync_unsafe_cell;
 
@@ -50,6 +52,8 @@ pub mod atomic;
 // Exports
 
 pub use alloc::sync::{Arc, Weak};
+pub use cancellation::{CancellationToken, Cancelled};
+pub use once_cell_async::OnceCellAsync;
 pub use sync_cell::SyncCell;
 pub use sync_unsafe_cell::SyncUnsafeCell;
 
@@ -0,0 +1,374 @@
+#![expect(unsafe_code, reason = "OnceCellAsync requires unsafe code.")]
+
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::future::{Future, poll_fn};
+use core::mem::MaybeUninit;
+use core::panic::{RefUnwindSafe, UnwindSafe};
+use core::task::{Poll, Waker};
+
+use crate::sync::atomic::AtomicU8;
+use crate::sync::atomic::Ordering::{Acquire, Relaxed, Release};
+use crate::utils::{Backoff, SpinLock};
+
+const UNINIT: u8 = 0;
+const RUNNING: u8 = 1;
+const COMPLETE: u8 = 2;
+
+// -----------------------------------------------------------------------------
+// OnceCellAsync
+
+/// A thread-safe cell that can be initialized once, from either blocking
+/// or async code.
+///
+/// Unlike [`OnceLock`](crate::sync::OnceLock), initialization may be an
+/// async computation. Tasks waiting for another initializer are suspended
+/// instead of blocking their executor thread, which makes the cell suitable
+/// for global pools or registries that are first accessed inside tasks.
+///
+/// Only one initializer runs at a time and the value is set at most once.
+/// If an initializer fails, panics or its future is dropped before finishing,
+/// the cell stays uninitialized and one of the waiters takes over.
+///
+/// The implementation is the same with and without the `std` feature.
+///
+/// # Deadlocks
+///
+/// [`get_or_init_blocking`] blocks the current thread while another
+/// initializer is running. Calling it on a thread that also has to drive
+/// a pending async initializer of the same cell (e.g. from a single-threaded
+/// executor) never returns.
+///
+/// # Examples
+///
+/// ```
+/// use vc_os::sync::OnceCellAsync;
+///
+/// static CONFIG: OnceCellAsync<String> = OnceCellAsync::new();
+///
+/// async fn load() -> Result<String, ()> {
+///     Ok(String::from("config"))
+/// }
+///
+/// async fn config() -> &'static str {
+///     CONFIG.get_or_try_init(load).await.unwrap()
+/// }
+///
+/// // From blocking code, the value is already there or built in place.
+/// let value = CONFIG.get_or_init_blocking(|| String::from("default"));
+/// assert_eq!(value, "default");
+/// assert_eq!(CONFIG.get().map(String::as_str), Some("default"));
+/// ```
+///
+/// [`get_or_init_blocking`]: Self::get_or_init_blocking
+pub struct OnceCellAsync<T> {
+    state: AtomicU8,
+    waiters: SpinLock<Vec<Waker>>,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+// SAFETY: The value is only written by the single initializer holding
+// the `RUNNING` state, and only read after `COMPLETE` is observed.
+unsafe impl<T: Sync + Send> Sync for OnceCellAsync<T> {}
+unsafe impl<T: Send> Send for OnceCellAsync<T> {}
+impl<T: RefUnwindSafe + UnwindSafe> RefUnwindSafe for OnceCellAsync<T> {}
+impl<T: UnwindSafe> UnwindSafe for OnceCellAsync<T> {}
+
+/// Releases the `RUNNING` state on drop and wakes all waiters.
+///
+/// Dropped without [`Self::complete`], the cell returns to `UNINIT`, so that
+/// a waiter can retry.
+struct InitGuard<'a, T> {
+    cell: &'a OnceCellAsync<T>,
+    state: u8,
+}
+
+impl<T> InitGuard<'_, T> {
+    fn complete(mut self, value: T) {
+        unsafe { (*self.cell.value.get()).write(value) };
+        self.state = COMPLETE;
+    }
+}
+
+impl<T> Drop for InitGuard<'_, T> {
+    fn drop(&mut self) {
+        self.cell.state.store(self.state, Release);
+        let waiters = core::mem::take(&mut *self.cell.waiters.lock());
+        waiters.into_iter().for_each(Waker::wake);
+    }
+}
+
+impl<T> OnceCellAsync<T> {
+    /// Creates a new uninitialized cell.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(UNINIT),
+            waiters: SpinLock::new(Vec::new()),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Returns `true` if the cell has been initialized.
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.state.load(Acquire) == COMPLETE
+    }
+
+    /// Gets the reference to the underlying value.
+    ///
+    /// Returns `None` if the cell is uninitialized, or being initialized.
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        if self.is_initialized() {
+            Some(unsafe { self.get_unchecked() })
+        } else {
+            None
+        }
+    }
+
+    /// Gets the mutable reference to the underlying value.
+    ///
+    /// Returns `None` if the cell is uninitialized.
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        if *self.state.get_mut() == COMPLETE {
+            Some(unsafe { self.value.get_mut().assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Initializes the cell with `value`.
+    ///
+    /// Returns `Err(value)` if the cell is initialized, or being initialized.
+    pub fn set(&self, value: T) -> Result<(), T> {
+        match self.try_start() {
+            Some(guard) => {
+                guard.complete(value);
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+
+    /// Gets the contents of the cell, initializing it with `f` if the cell
+    /// was uninitialized.
+    ///
+    /// If another initializer is running, blocking or async, the current
+    /// thread waits for it to finish. See the [type level docs](Self) for
+    /// when this can deadlock.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the panic is propagated and the cell stays uninitialized.
+    pub fn get_or_init_blocking<F>(&self, f: F) -> &T
+    where
+        F: FnOnce() -> T,
+    {
+        let backoff = Backoff::new();
+        loop {
+            if let Some(value) = self.get() {
+                return value;
+            }
+            if let Some(guard) = self.try_start() {
+                guard.complete(f());
+                return unsafe { self.get_unchecked() };
+            }
+            backoff.snooze();
+        }
+    }
+
+    /// Gets the contents of the cell, initializing it with the future
+    /// returned by `f` if the cell was uninitialized.
+    ///
+    /// If another initializer is running, the current task is suspended
+    /// until it finishes.
+    pub async fn get_or_init<F, Fut>(&self, f: F) -> &T
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = T>,
+    {
+        match self
+            .get_or_try_init(|| async { Ok::<T, core::convert::Infallible>(f().await) })
+            .await
+        {
+            Ok(value) => value,
+        }
+    }
+
+    /// Gets the contents of the cell, initializing it with the future
+    /// returned by `f` if the cell was uninitialized.
+    ///
+    /// If another initializer is running, the current task is suspended
+    /// until it finishes. If the future fails, the error is returned and
+    /// the cell stays uninitialized, so that the next caller retries.
+    ///
+    /// `f` is only called if this task becomes the initializer.
+    pub async fn get_or_try_init<F, Fut, E>(&self, f: F) -> Result<&T, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let guard = poll_fn(|cx| {
+            loop {
+                if self.is_initialized() {
+                    return Poll::Ready(None);
+                }
+                if let Some(guard) = self.try_start() {
+                    return Poll::Ready(Some(guard));
+                }
+
+                let mut waiters = self.waiters.lock();
+                // Checked under the lock, the initializer stores its final
+                // state before draining the waiters.
+                if self.state.load(Acquire) == RUNNING {
+                    if !waiters.iter().any(|w| w.will_wake(cx.waker())) {
+                        waiters.push(cx.waker().clone());
+                    }
+                    return Poll::Pending;
+                }
+            }
+        })
+        .await;
+
+        if let Some(guard) = guard {
+            guard.complete(f().await?);
+        }
+        Ok(unsafe { self.get_unchecked() })
+    }
+
+    /// Consumes the cell, returning the wrapped value.
+    #[inline]
+    pub fn into_inner(mut self) -> Option<T> {
+        self.take()
+    }
+
+    /// Takes the value out of the cell, moving it back to an uninitialized state.
+    #[inline]
+    pub fn take(&mut self) -> Option<T> {
+        if *self.state.get_mut() == COMPLETE {
+            *self.state.get_mut() = UNINIT;
+            Some(unsafe { self.value.get_mut().assume_init_read() })
+        } else {
+            None
+        }
+    }
+
+    fn try_start(&self) -> Option<InitGuard<'_, T>> {
+        self.state
+            .compare_exchange(UNINIT, RUNNING, Acquire, Relaxed)
+            .ok()
+            .map(|_| InitGuard {
+                cell: self,
+                state: UNINIT,
+            })
+    }
+
+    /// # Safety
+    ///
+    /// The cell must be initialized.
+    #[inline]
+    unsafe fn get_unchecked(&self) -> &T {
+        debug_assert!(self.is_initialized());
+        unsafe { (*self.value.get()).assume_init_ref() }
+    }
+}
+
+impl<T> Default for OnceCellAsync<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<T> for OnceCellAsync<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self {
+            state: AtomicU8::new(COMPLETE),
+            waiters: SpinLock::new(Vec::new()),
+            value: UnsafeCell::new(MaybeUninit::new(value)),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for OnceCellAsync<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_tuple("OnceCellAsync");
+        match self.get() {
+            Some(v) => d.field(v),
+            None => d.field(&format_args!("<uninit>")),
+        };
+        d.finish()
+    }
+}
+
+impl<T> Drop for OnceCellAsync<T> {
+    #[inline]
+    fn drop(&mut self) {
+        if *self.state.get_mut() == COMPLETE {
+            unsafe { self.value.get_mut().assume_init_drop() };
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+
+    use super::OnceCellAsync;
+
+    #[test]
+    fn async_init() {
+        let cell = OnceCellAsync::<u32>::new();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let ready = Cell::new(false);
+        let mut first = pin!(cell.get_or_init(|| async {
+            core::future::poll_fn(|_| match ready.get() {
+                true => Poll::Ready(1),
+                false => Poll::Pending,
+            })
+            .await
+        }));
+        let mut second = pin!(cell.get_or_init(|| async { 2 }));
+
+        // The first task is initializing, the second one waits for it.
+        assert!(first.as_mut().poll(&mut cx).is_pending());
+        assert!(second.as_mut().poll(&mut cx).is_pending());
+        assert!(cell.get().is_none());
+
+        ready.set(true);
+        assert_eq!(first.as_mut().poll(&mut cx), Poll::Ready(&1));
+        assert_eq!(second.as_mut().poll(&mut cx), Poll::Ready(&1));
+        assert_eq!(cell.get_or_init_blocking(|| 3), &1);
+    }
+
+    #[test]
+    fn failed_init() {
+        let cell = OnceCellAsync::<u32>::new();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let failed = pin!(cell.get_or_try_init(|| async { Err::<u32, _>("error") }));
+        assert_eq!(failed.poll(&mut cx), Poll::Ready(Err("error")));
+        assert!(cell.get().is_none());
+
+        // A dropped initializer releases the cell as well.
+        {
+            let mut pending = pin!(cell.get_or_init(core::future::pending));
+            assert!(pending.as_mut().poll(&mut cx).is_pending());
+            assert!(cell.set(5).is_err());
+        }
+        assert_eq!(cell.set(5), Ok(()));
+        assert_eq!(cell.get_or_init_blocking(|| 6), &5);
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! - In `std` environments, it directly re-exports `std::thread::sleep`.
 //! - In non-`std` environments, implementations based on spin locks are used.
+//!
+//! [`set_affinity`] pins the current thread to a core where supported.
 
 pub use thread_impl::sleep;
 
@@ -37,3 +39,40 @@ pub fn available_parallelism() -> NonZero<usize> {
         }
     }
 }
+
+// -----------------------------------------------------------------------------
+// set_affinity
+
+/// Pins the current thread to the logical core `core`, so the OS scheduler
+/// does not migrate it. Returns `true` on success.
+///
+/// Cores are numbered from `0` to [`available_parallelism`] minus one, unless
+/// the process is already restricted to a subset of cores.
+///
+/// This is only supported on Linux and Android with `std`. Elsewhere, this
+/// does nothing and returns `false`.
+pub fn set_affinity(core: usize) -> bool {
+    #[cfg(all(feature = "std", any(target_os = "linux", target_os = "android")))]
+    {
+        #[expect(unsafe_code, reason = "FFI call to `sched_setaffinity`.")]
+        fn set_affinity_impl(core: usize) -> bool {
+            if core >= libc::CPU_SETSIZE as usize {
+                return false;
+            }
+            // SAFETY: `cpu_set_t` is a bit set, all zeros is an empty set.
+            let mut set: libc::cpu_set_t = unsafe { core::mem::zeroed() };
+            // SAFETY: `core` is below `CPU_SETSIZE`, `0` is the calling thread.
+            unsafe {
+                libc::CPU_SET(core, &mut set);
+                libc::sched_setaffinity(0, size_of::<libc::cpu_set_t>(), &set) == 0
+            }
+        }
+
+        set_affinity_impl(core)
+    }
+    #[cfg(not(all(feature = "std", any(target_os = "linux", target_os = "android"))))]
+    {
+        let _ = core;
+        false
+    }
+}
@@ -0,0 +1,231 @@
+use core::iter::FusedIterator;
+use core::time::Duration;
+
+use super::Instant;
+
+// -----------------------------------------------------------------------------
+// FixedTicker
+
+/// A fixed timestep accumulator based on the cross-platform [`Instant`].
+///
+/// Each frame, the elapsed time is added to an accumulator, and the returned
+/// iterator yields once per whole timestep it contains. The remainder is
+/// kept for the next frame and can be used to interpolate between steps.
+///
+/// To avoid a spiral of death after a long frame, the accumulator is clamped
+/// to [`max_steps`](Self::max_steps) timesteps; the time beyond it is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use vc_os::time::FixedTicker;
+///
+/// let mut ticker = FixedTicker::new(Duration::from_millis(10));
+///
+/// assert_eq!(ticker.accumulate(Duration::from_millis(25)).count(), 2);
+/// assert_eq!(ticker.overstep(), Duration::from_millis(5));
+///
+/// assert_eq!(ticker.accumulate(Duration::from_millis(5)).count(), 1);
+/// assert_eq!(ticker.overstep(), Duration::ZERO);
+///
+/// // Long frames are clamped to `max_steps` timesteps.
+/// assert_eq!(ticker.accumulate(Duration::from_secs(1)).count(), 8);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FixedTicker {
+    last: Option<Instant>,
+    timestep: Duration,
+    accumulated: Duration,
+    max_steps: u32,
+}
+
+impl FixedTicker {
+    /// The default maximum number of steps per frame.
+    pub const DEFAULT_MAX_STEPS: u32 = 8;
+
+    /// Creates a ticker with the given timestep.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestep` is zero.
+    #[inline]
+    pub const fn new(timestep: Duration) -> Self {
+        assert!(!timestep.is_zero(), "the timestep must not be zero");
+        Self {
+            last: None,
+            timestep,
+            accumulated: Duration::ZERO,
+            max_steps: Self::DEFAULT_MAX_STEPS,
+        }
+    }
+
+    /// Creates a ticker running `hz` steps per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hz` is not positive and finite.
+    #[inline]
+    pub fn from_hz(hz: f64) -> Self {
+        assert!(hz > 0.0 && hz.is_finite(), "the frequency must be positive");
+        Self::new(Duration::from_secs_f64(1.0 / hz))
+    }
+
+    /// Sets the maximum number of steps per frame, at least one.
+    #[inline]
+    pub const fn with_max_steps(mut self, max_steps: u32) -> Self {
+        self.max_steps = if max_steps == 0 { 1 } else { max_steps };
+        self
+    }
+
+    /// Returns the timestep.
+    #[inline]
+    pub const fn timestep(&self) -> Duration {
+        self.timestep
+    }
+
+    /// Changes the timestep, keeping the accumulated time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestep` is zero.
+    #[inline]
+    pub fn set_timestep(&mut self, timestep: Duration) {
+        assert!(!timestep.is_zero(), "the timestep must not be zero");
+        self.timestep = timestep;
+        self.accumulated = self.accumulated.min(self.max_accumulated());
+    }
+
+    /// Returns the maximum number of steps per frame.
+    #[inline]
+    pub const fn max_steps(&self) -> u32 {
+        self.max_steps
+    }
+
+    /// Returns the accumulated time that did not fill a whole timestep yet.
+    ///
+    /// Only meaningful once the iterator of the last frame is exhausted.
+    #[inline]
+    pub const fn overstep(&self) -> Duration {
+        self.accumulated
+    }
+
+    /// Returns [`overstep`](Self::overstep) as a fraction of the timestep,
+    /// which is the interpolation factor between the last two steps.
+    #[inline]
+    pub fn overstep_fraction(&self) -> f64 {
+        self.accumulated.as_secs_f64() / self.timestep.as_secs_f64()
+    }
+
+    /// Adds the time elapsed since the previous call, returning the steps to run.
+    ///
+    /// The first call only records the starting point and yields nothing.
+    #[inline]
+    pub fn tick(&mut self) -> FixedSteps<'_> {
+        self.tick_at(Instant::now())
+    }
+
+    /// Adds the time elapsed until `now`, returning the steps to run.
+    ///
+    /// The first call only records the starting point and yields nothing.
+    pub fn tick_at(&mut self, now: Instant) -> FixedSteps<'_> {
+        let delta = match self.last.replace(now) {
+            Some(last) => now.saturating_duration_since(last),
+            None => Duration::ZERO,
+        };
+        self.accumulate(delta)
+    }
+
+    /// Adds an explicit delta time, returning the steps to run.
+    ///
+    /// This does not touch the internal timestamp. Each item of the iterator
+    /// consumes one timestep; steps that are not consumed stay accumulated
+    /// for the next frame.
+    pub fn accumulate(&mut self, delta: Duration) -> FixedSteps<'_> {
+        self.accumulated = self
+            .accumulated
+            .saturating_add(delta)
+            .min(self.max_accumulated());
+        FixedSteps { ticker: self }
+    }
+
+    /// Clears the accumulated time and the internal timestamp.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.accumulated = Duration::ZERO;
+    }
+
+    #[inline]
+    fn max_accumulated(&self) -> Duration {
+        self.timestep.saturating_mul(self.max_steps)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// FixedSteps
+
+/// The steps of a frame, returned by [`FixedTicker::accumulate`].
+#[derive(Debug)]
+#[must_use = "steps are only consumed when iterated"]
+pub struct FixedSteps<'a> {
+    ticker: &'a mut FixedTicker,
+}
+
+impl Iterator for FixedSteps<'_> {
+    type Item = ();
+
+    #[inline]
+    fn next(&mut self) -> Option<()> {
+        let ticker = &mut *self.ticker;
+        ticker.accumulated = ticker.accumulated.checked_sub(ticker.timestep)?;
+        Some(())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for FixedSteps<'_> {
+    #[inline]
+    fn len(&self) -> usize {
+        let ticker = &*self.ticker;
+        (ticker.accumulated.as_nanos() / ticker.timestep.as_nanos()) as usize
+    }
+}
+
+impl FusedIterator for FixedSteps<'_> {}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::FixedTicker;
+
+    #[test]
+    fn overrun_clamping() {
+        let step = Duration::from_millis(10);
+        let mut ticker = FixedTicker::new(step).with_max_steps(3);
+
+        let steps = ticker.accumulate(Duration::from_millis(95));
+        assert_eq!(steps.len(), 3);
+        assert_eq!(steps.count(), 3);
+        assert_eq!(ticker.overstep(), Duration::ZERO);
+
+        // Unconsumed steps are kept for the next frame.
+        let mut steps = ticker.accumulate(Duration::from_millis(24));
+        assert_eq!(steps.next(), Some(()));
+        assert_eq!(ticker.accumulate(Duration::from_millis(1)).count(), 1);
+        assert_eq!(ticker.overstep(), Duration::from_millis(5));
+        assert!((ticker.overstep_fraction() - 0.5).abs() < 1e-9);
+
+        ticker.set_timestep(Duration::from_millis(1));
+        assert_eq!(ticker.overstep(), Duration::from_millis(3));
+    }
+}
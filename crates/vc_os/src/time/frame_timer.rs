@@ -0,0 +1,219 @@
+use core::time::Duration;
+
+use super::Instant;
+
+// -----------------------------------------------------------------------------
+// FrameTimer
+
+/// Frame timing statistics based on the cross-platform [`Instant`].
+///
+/// Call [`tick`](Self::tick) once per frame. The first tick only records
+/// the starting point, every subsequent tick records one frame.
+///
+/// Tracks:
+/// - The delta time of the last frame.
+/// - The total time accumulated over all frames.
+/// - An exponentially smoothed average of the delta time.
+/// - The maximum delta time since the last [`reset_max`](Self::reset_max).
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use vc_os::time::FrameTimer;
+///
+/// let mut timer = FrameTimer::new();
+///
+/// timer.advance(Duration::from_millis(16));
+/// timer.advance(Duration::from_millis(20));
+///
+/// assert_eq!(timer.frame_count(), 2);
+/// assert_eq!(timer.delta(), Duration::from_millis(20));
+/// assert_eq!(timer.max_delta(), Duration::from_millis(20));
+/// assert_eq!(timer.elapsed(), Duration::from_millis(36));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FrameTimer {
+    last: Option<Instant>,
+    delta: Duration,
+    elapsed: Duration,
+    average: Duration,
+    max_delta: Duration,
+    frame_count: u64,
+    smoothing: f64,
+}
+
+impl Default for FrameTimer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FrameTimer {
+    /// The default smoothing factor of the average delta time.
+    pub const DEFAULT_SMOOTHING: f64 = 0.1;
+
+    /// Creates a frame timer with [`DEFAULT_SMOOTHING`](Self::DEFAULT_SMOOTHING).
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_smoothing(Self::DEFAULT_SMOOTHING)
+    }
+
+    /// Creates a frame timer with the given smoothing factor.
+    ///
+    /// The factor is the weight of the newest frame in the average, and
+    /// is clamped to `(0, 1]`. A value of `1.0` disables smoothing.
+    #[inline]
+    pub const fn with_smoothing(smoothing: f64) -> Self {
+        let smoothing = if smoothing > 1.0 {
+            1.0
+        } else if smoothing > 0.0 {
+            smoothing
+        } else {
+            f64::EPSILON
+        };
+
+        Self {
+            last: None,
+            delta: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            average: Duration::ZERO,
+            max_delta: Duration::ZERO,
+            frame_count: 0,
+            smoothing,
+        }
+    }
+
+    /// Records a frame ending now, returning its delta time.
+    ///
+    /// The first call only records the starting point and returns zero.
+    #[inline]
+    pub fn tick(&mut self) -> Duration {
+        self.tick_at(Instant::now())
+    }
+
+    /// Records a frame ending at `now`, returning its delta time.
+    ///
+    /// The first call only records the starting point and returns zero.
+    pub fn tick_at(&mut self, now: Instant) -> Duration {
+        match self.last.replace(now) {
+            Some(last) => {
+                self.advance(now.saturating_duration_since(last));
+                self.delta
+            }
+            None => Duration::ZERO,
+        }
+    }
+
+    /// Records a frame with an explicit delta time.
+    ///
+    /// This does not touch the internal timestamp, which is useful for
+    /// fixed or externally provided frame times.
+    pub fn advance(&mut self, delta: Duration) {
+        self.delta = delta;
+        self.elapsed += delta;
+        self.max_delta = self.max_delta.max(delta);
+
+        self.average = if self.frame_count == 0 {
+            delta
+        } else {
+            let average = self.average.as_secs_f64();
+            let current = delta.as_secs_f64();
+            Duration::from_secs_f64(average + (current - average) * self.smoothing)
+        };
+
+        self.frame_count += 1;
+    }
+
+    /// Returns the delta time of the last frame.
+    #[inline]
+    pub const fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    /// Returns the total time of all recorded frames.
+    #[inline]
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the smoothed average delta time.
+    #[inline]
+    pub const fn average_delta(&self) -> Duration {
+        self.average
+    }
+
+    /// Returns the maximum delta time since the last [`reset_max`](Self::reset_max).
+    #[inline]
+    pub const fn max_delta(&self) -> Duration {
+        self.max_delta
+    }
+
+    /// Returns the number of recorded frames.
+    #[inline]
+    pub const fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Returns the frames per second derived from the smoothed average,
+    /// or `0.0` if no frame has been recorded.
+    #[inline]
+    pub fn fps(&self) -> f64 {
+        let average = self.average.as_secs_f64();
+        if average > 0.0 { 1.0 / average } else { 0.0 }
+    }
+
+    /// Resets the maximum delta time, e.g. at the start of a profiling window.
+    #[inline]
+    pub fn reset_max(&mut self) {
+        self.max_delta = Duration::ZERO;
+    }
+
+    /// Resets all statistics, keeping the smoothing factor.
+    #[inline]
+    pub fn reset(&mut self) {
+        *self = Self::with_smoothing(self.smoothing);
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::FrameTimer;
+
+    #[test]
+    fn smoothing() {
+        let mut timer = FrameTimer::with_smoothing(0.5);
+
+        timer.advance(Duration::from_millis(10));
+        assert_eq!(timer.average_delta(), Duration::from_millis(10));
+
+        timer.advance(Duration::from_millis(30));
+        assert_eq!(timer.average_delta(), Duration::from_millis(20));
+        assert_eq!(timer.max_delta(), Duration::from_millis(30));
+
+        timer.reset_max();
+        timer.advance(Duration::from_millis(20));
+        assert_eq!(timer.average_delta(), Duration::from_millis(20));
+        assert_eq!(timer.max_delta(), Duration::from_millis(20));
+        assert!((timer.fps() - 50.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn reset() {
+        let mut timer = FrameTimer::with_smoothing(2.0);
+        timer.advance(Duration::from_millis(10));
+        timer.advance(Duration::from_millis(30));
+        assert_eq!(timer.average_delta(), Duration::from_millis(30));
+
+        timer.reset();
+        assert_eq!(timer.frame_count(), 0);
+        assert_eq!(timer.elapsed(), Duration::ZERO);
+        assert_eq!(timer.fps(), 0.0);
+    }
+}
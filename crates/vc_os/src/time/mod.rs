@@ -11,10 +11,25 @@
 //! please submit an Issue in the [repository](https://github.com/VoidCraft-Engine/vc-core) for such cases.
 //!
 //! See the [standard library](https://doc.rust-lang.org/std/time) for further details.
+//!
+//! On top of `Instant`, this module also provides [`Stopwatch`], [`FrameTimer`] and [`FixedTicker`],
+//! so that frame timing behaves identically on every backend.
+//! [`format_rfc3339`] and [`parse_rfc3339`] convert a [`SystemTime`] to and from
+//! UTC timestamps, such as for log entries.
+
+mod fixed_ticker;
+mod frame_timer;
+mod rfc3339;
+mod stopwatch;
 
 pub use core::time::{Duration, TryFromFloatSecsError};
 pub use time_impl::{Instant, SystemTime, SystemTimeError};
 
+pub use fixed_ticker::{FixedSteps, FixedTicker};
+pub use frame_timer::FrameTimer;
+pub use rfc3339::{ParseRfc3339Error, Rfc3339, format_rfc3339, parse_rfc3339};
+pub use stopwatch::Stopwatch;
+
 crate::cfg::switch! {
     crate::cfg::web => {
         use ::web_time as time_impl;
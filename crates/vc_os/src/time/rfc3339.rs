@@ -0,0 +1,368 @@
+use core::fmt;
+
+use super::{Duration, SystemTime};
+
+const SECS_PER_DAY: i64 = 86_400;
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+// -----------------------------------------------------------------------------
+// Rfc3339
+
+/// A [`SystemTime`] displayed as an [RFC 3339] UTC timestamp.
+///
+/// The fractional seconds default to microseconds, and the formatter precision
+/// selects between `0` and `9` digits.
+///
+/// Years outside `0000..=9999` are printed as-is, which is not valid RFC 3339.
+///
+/// Returned by [`format_rfc3339`].
+///
+/// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rfc3339 {
+    /// Seconds since the Unix epoch, rounded down.
+    secs: i64,
+    /// Nanoseconds after `secs`.
+    nanos: u32,
+}
+
+impl fmt::Display for Rfc3339 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let days = self.secs.div_euclid(SECS_PER_DAY);
+        let secs = self.secs.rem_euclid(SECS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+        let (hour, minute, second) = (secs / 3600, secs / 60 % 60, secs % 60);
+
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}"
+        )?;
+
+        let digits = f.precision().unwrap_or(6).min(9);
+        if digits > 0 {
+            let fraction = self.nanos / 10u32.pow(9 - digits as u32);
+            write!(f, ".{fraction:0digits$}")?;
+        }
+        f.write_str("Z")
+    }
+}
+
+/// Formats `time` as an [RFC 3339] UTC timestamp, such as
+/// `2024-06-30T21:59:59.500000Z`.
+///
+/// Times before the Unix epoch are supported where [`SystemTime`] can
+/// represent them. See [`Rfc3339`] for the formatting options.
+///
+/// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+///
+/// # Examples
+///
+/// ```
+/// use vc_os::time::{Duration, SystemTime, format_rfc3339};
+///
+/// let time = SystemTime::UNIX_EPOCH + Duration::from_millis(1_719_784_799_500);
+///
+/// assert_eq!(format_rfc3339(time).to_string(), "2024-06-30T21:59:59.500000Z");
+/// assert_eq!(format!("{:.3}", format_rfc3339(time)), "2024-06-30T21:59:59.500Z");
+/// assert_eq!(format!("{:.0}", format_rfc3339(time)), "2024-06-30T21:59:59Z");
+/// ```
+pub fn format_rfc3339(time: SystemTime) -> Rfc3339 {
+    match time.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(after) => Rfc3339 {
+            secs: after.as_secs() as i64,
+            nanos: after.subsec_nanos(),
+        },
+        Err(err) => {
+            let before = err.duration();
+            let secs = -(before.as_secs() as i64);
+            match before.subsec_nanos() {
+                0 => Rfc3339 { secs, nanos: 0 },
+                nanos => Rfc3339 {
+                    secs: secs - 1,
+                    nanos: NANOS_PER_SEC - nanos,
+                },
+            }
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Parsing
+
+/// An error returned by [`parse_rfc3339`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseRfc3339Error(ParseErrorKind);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ParseErrorKind {
+    Syntax,
+    InvalidDate,
+    OutOfRange,
+}
+
+impl fmt::Display for ParseRfc3339Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.0 {
+            ParseErrorKind::Syntax => "invalid RFC 3339 timestamp syntax",
+            ParseErrorKind::InvalidDate => "invalid date or time in RFC 3339 timestamp",
+            ParseErrorKind::OutOfRange => "RFC 3339 timestamp is out of `SystemTime` range",
+        })
+    }
+}
+
+impl core::error::Error for ParseRfc3339Error {}
+
+/// Parses an [RFC 3339] timestamp, such as `2024-06-30T23:59:59.5+02:00`.
+///
+/// The date and time may be separated by `T`, `t` or a space. Fractional
+/// seconds beyond nanoseconds are truncated. Leap seconds (`:60`) are not
+/// supported.
+///
+/// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+///
+/// # Errors
+///
+/// Returns an error if `s` is not a valid timestamp, or if it cannot be
+/// represented as a [`SystemTime`].
+///
+/// # Examples
+///
+/// ```
+/// use vc_os::time::{Duration, SystemTime, parse_rfc3339};
+///
+/// let time = parse_rfc3339("2024-06-30T23:59:59.5+02:00").unwrap();
+/// let expected = SystemTime::UNIX_EPOCH + Duration::from_millis(1_719_784_799_500);
+/// assert_eq!(time, expected);
+///
+/// assert!(parse_rfc3339("2024-02-30T00:00:00Z").is_err());
+/// ```
+pub fn parse_rfc3339(s: &str) -> Result<SystemTime, ParseRfc3339Error> {
+    let (secs, nanos) = parse_parts(s.as_bytes())?;
+    let out_of_range = ParseRfc3339Error(ParseErrorKind::OutOfRange);
+
+    let time = if secs >= 0 {
+        let after = Duration::new(secs as u64, nanos);
+        SystemTime::UNIX_EPOCH.checked_add(after)
+    } else {
+        let before = Duration::new(secs.unsigned_abs(), 0) - Duration::from_nanos(nanos as u64);
+        SystemTime::UNIX_EPOCH.checked_sub(before)
+    };
+    time.ok_or(out_of_range)
+}
+
+/// Returns the seconds since the Unix epoch, rounded down, and the
+/// nanoseconds after them.
+fn parse_parts(s: &[u8]) -> Result<(i64, u32), ParseRfc3339Error> {
+    let syntax = ParseRfc3339Error(ParseErrorKind::Syntax);
+    let invalid = ParseRfc3339Error(ParseErrorKind::InvalidDate);
+
+    let mut cursor = Cursor(s);
+    let year = cursor.digits(4).ok_or(syntax)?;
+    cursor.expect(b"-").ok_or(syntax)?;
+    let month = cursor.digits(2).ok_or(syntax)?;
+    cursor.expect(b"-").ok_or(syntax)?;
+    let day = cursor.digits(2).ok_or(syntax)?;
+    cursor.expect(b"Tt ").ok_or(syntax)?;
+    let hour = cursor.digits(2).ok_or(syntax)?;
+    cursor.expect(b":").ok_or(syntax)?;
+    let minute = cursor.digits(2).ok_or(syntax)?;
+    cursor.expect(b":").ok_or(syntax)?;
+    let second = cursor.digits(2).ok_or(syntax)?;
+
+    let mut nanos = 0;
+    if cursor.expect(b".").is_some() {
+        let mut count = 0;
+        while let Some(digit) = cursor.digits(1) {
+            if count < 9 {
+                nanos = nanos * 10 + digit;
+            }
+            count += 1;
+        }
+        if count == 0 {
+            return Err(syntax);
+        }
+        nanos *= 10u32.pow(9u32.saturating_sub(count));
+    }
+
+    let offset = match cursor.next().ok_or(syntax)? {
+        b'Z' | b'z' => 0,
+        sign @ (b'+' | b'-') => {
+            let hours = cursor.digits(2).ok_or(syntax)?;
+            cursor.expect(b":").ok_or(syntax)?;
+            let minutes = cursor.digits(2).ok_or(syntax)?;
+            if hours > 23 || minutes > 59 {
+                return Err(invalid);
+            }
+            let offset = (hours * 3600 + minutes * 60) as i64;
+            if sign == b'-' { -offset } else { offset }
+        }
+        _ => return Err(syntax),
+    };
+    if !cursor.0.is_empty() {
+        return Err(syntax);
+    }
+
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return Err(invalid);
+    }
+
+    let days = days_from_civil(year as i64, month, day);
+    let secs = days * SECS_PER_DAY + (hour * 3600 + minute * 60 + second) as i64 - offset;
+    Ok((secs, nanos))
+}
+
+/// A byte cursor over the timestamp being parsed.
+struct Cursor<'a>(&'a [u8]);
+
+impl Cursor<'_> {
+    fn next(&mut self) -> Option<u8> {
+        let (&first, rest) = self.0.split_first()?;
+        self.0 = rest;
+        Some(first)
+    }
+
+    /// Consumes one byte if it is one of `any`.
+    fn expect(&mut self, any: &[u8]) -> Option<()> {
+        let &first = self.0.first()?;
+        any.contains(&first).then(|| self.0 = &self.0[1..])
+    }
+
+    /// Consumes exactly `count` ASCII digits.
+    fn digits(&mut self, count: usize) -> Option<u32> {
+        let digits = self.0.get(..count)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        self.0 = &self.0[count..];
+        Some(
+            digits
+                .iter()
+                .fold(0, |acc, &d| acc * 10 + (d - b'0') as u32),
+        )
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Calendar
+
+const fn is_leap_year(year: u32) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+const fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days since 1970-01-01 in the proleptic Gregorian calendar.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+const fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Returns the year, month and day of the given days since 1970-01-01.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+const fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::{format_rfc3339, parse_rfc3339};
+    use crate::time::{Duration, SystemTime};
+
+    #[test]
+    fn format() {
+        let epoch = SystemTime::UNIX_EPOCH;
+        assert_eq!(
+            format!("{}", format_rfc3339(epoch)),
+            "1970-01-01T00:00:00.000000Z"
+        );
+
+        let leap_day = epoch + Duration::new(951_782_400, 123_456_789);
+        assert_eq!(
+            format!("{:.9}", format_rfc3339(leap_day)),
+            "2000-02-29T00:00:00.123456789Z"
+        );
+        assert_eq!(
+            format!("{:.2}", format_rfc3339(leap_day)),
+            "2000-02-29T00:00:00.12Z"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let time = parse_rfc3339("1999-12-31t23:00:00.25-01:00").unwrap();
+        assert_eq!(
+            format!("{:.3}", format_rfc3339(time)),
+            "2000-01-01T00:00:00.250Z"
+        );
+
+        let time = parse_rfc3339("2024-01-01 00:00:00.1234567891Z").unwrap();
+        assert_eq!(
+            format!("{:.9}", format_rfc3339(time)),
+            "2024-01-01T00:00:00.123456789Z"
+        );
+
+        for bad in [
+            "2024-01-01",
+            "2024-01-01T00:00:00",
+            "2024-01-01T00:00:00.Z",
+            "2024-1-01T00:00:00Z",
+            "2024-01-01T00:00:00Z ",
+            "2023-02-29T00:00:00Z",
+            "2024-01-01T24:00:00Z",
+            "2024-01-01T00:00:60Z",
+            "2024-01-01T00:00:00+24:00",
+        ] {
+            assert!(parse_rfc3339(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn before_epoch() {
+        let Ok(time) = parse_rfc3339("1969-12-31T23:59:58.5Z") else {
+            // The fallback `SystemTime` cannot represent times before its epoch.
+            return;
+        };
+        assert_eq!(
+            SystemTime::UNIX_EPOCH.duration_since(time).unwrap(),
+            Duration::from_millis(1500)
+        );
+        assert_eq!(
+            format!("{:.1}", format_rfc3339(time)),
+            "1969-12-31T23:59:58.5Z"
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use core::time::Duration;
+
+use super::Instant;
+
+// -----------------------------------------------------------------------------
+// Stopwatch
+
+/// A pausable stopwatch based on the cross-platform [`Instant`].
+///
+/// The stopwatch accumulates time only while running. Pausing and
+/// resuming it does not lose the previously measured time.
+///
+/// # Examples
+///
+/// ```
+/// use vc_os::time::Stopwatch;
+///
+/// let mut watch = Stopwatch::start_new();
+/// // ... perform work ...
+/// watch.stop();
+///
+/// let measured = watch.elapsed();
+/// assert!(!watch.is_running());
+/// assert_eq!(watch.elapsed(), measured);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Stopwatch {
+    started: Option<Instant>,
+    accumulated: Duration,
+}
+
+impl Stopwatch {
+    /// Creates a stopped stopwatch with zero elapsed time.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            started: None,
+            accumulated: Duration::ZERO,
+        }
+    }
+
+    /// Creates a stopwatch and starts it immediately.
+    #[inline]
+    pub fn start_new() -> Self {
+        Self {
+            started: Some(Instant::now()),
+            accumulated: Duration::ZERO,
+        }
+    }
+
+    /// Returns `true` if the stopwatch is running.
+    #[inline]
+    pub const fn is_running(&self) -> bool {
+        self.started.is_some()
+    }
+
+    /// Starts or resumes the stopwatch.
+    ///
+    /// Does nothing if it is already running.
+    #[inline]
+    pub fn start(&mut self) {
+        if self.started.is_none() {
+            self.started = Some(Instant::now());
+        }
+    }
+
+    /// Pauses the stopwatch and returns the total elapsed time.
+    ///
+    /// Does nothing if it is already stopped.
+    #[inline]
+    pub fn stop(&mut self) -> Duration {
+        if let Some(started) = self.started.take() {
+            self.accumulated += started.elapsed();
+        }
+        self.accumulated
+    }
+
+    /// Stops the stopwatch and resets the elapsed time to zero.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.started = None;
+        self.accumulated = Duration::ZERO;
+    }
+
+    /// Resets the elapsed time to zero and starts the stopwatch again,
+    /// returning the elapsed time before the restart.
+    #[inline]
+    pub fn restart(&mut self) -> Duration {
+        let now = Instant::now();
+        let elapsed = self.elapsed_at(now);
+        self.started = Some(now);
+        self.accumulated = Duration::ZERO;
+        elapsed
+    }
+
+    /// Returns the total elapsed time, including the current running span.
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        match self.started {
+            Some(started) => self.accumulated + started.elapsed(),
+            None => self.accumulated,
+        }
+    }
+
+    /// Returns the total elapsed time as if it were measured at `now`.
+    #[inline]
+    pub fn elapsed_at(&self, now: Instant) -> Duration {
+        match self.started {
+            Some(started) => self.accumulated + now.saturating_duration_since(started),
+            None => self.accumulated,
+        }
+    }
+}
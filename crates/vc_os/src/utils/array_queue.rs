@@ -436,7 +436,7 @@ impl<T> fmt::Debug for ArrayQueue<T> {
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use alloc::vec::Vec;
-    use std::sync::atomic::{AtomicUsize, Ordering};
+    use core::sync::atomic::{AtomicUsize, Ordering};
     use std::thread::scope;
 
     use super::ArrayQueue;
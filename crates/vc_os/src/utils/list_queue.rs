@@ -693,7 +693,7 @@ pub struct PushLockGuard<'a, T>(SpinLockGuard<'a, (*mut Block<T>, usize)>);
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use alloc::vec::Vec;
-    use std::sync::atomic::{AtomicUsize, Ordering};
+    use core::sync::atomic::{AtomicUsize, Ordering};
     use std::thread::scope;
 
     use super::ListQueue;
@@ -14,6 +14,7 @@
 //!
 //! - [`ArrayQueue`]: A bounded MPMC queue backed by a fixed-size ring buffer.
 //! - [`ListQueue`]: An unbounded queue backed by linked blocks with idle-block reuse.
+//! - [`StealDeque`]: A bounded Chase-Lev deque with one owner and many stealers.
 //!
 //! # Execution Helpers
 //!
@@ -31,6 +32,7 @@ mod list_queue;
 mod once_flag;
 mod parallel;
 mod spin_lock;
+mod steal_deque;
 
 // -----------------------------------------------------------------------------
 // Exports
@@ -43,6 +45,7 @@ pub use list_queue::ListQueue;
 pub use once_flag::OnceFlag;
 pub use parallel::Parallel;
 pub use spin_lock::{SpinLock, SpinLockGuard};
+pub use steal_deque::StealDeque;
 
 // -----------------------------------------------------------------------------
 // Utils for test
@@ -50,8 +53,9 @@ pub use spin_lock::{SpinLock, SpinLockGuard};
 #[cfg(all(test, feature = "std"))]
 #[allow(dead_code, reason = "tests")]
 pub(crate) mod tests {
+    use alloc::boxed::Box;
     use core::{any::Any, panic::AssertUnwindSafe, sync::atomic};
-    use std::{boxed::Box, panic, thread};
+    use std::{panic, thread};
 
     pub(crate) fn test_unwind_panic<R>(f: impl FnOnce() -> R) -> Result<R, Box<dyn Any + Send>> {
         let prev_hook = panic::take_hook();
@@ -0,0 +1,342 @@
+//! A bounded variant of the Chase-Lev deque.
+//!
+//! See "Correct and Efficient Work-Stealing for Weak Memory Models"
+//! (Lê, Pop, Cohen, Zappa Nardelli, PPoPP 2013).
+#![expect(unsafe_code, reason = "original implementation need unsafe codes")]
+
+use alloc::boxed::Box;
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::mem::{self, MaybeUninit};
+use core::panic::{RefUnwindSafe, UnwindSafe};
+use core::ptr;
+
+use crate::sync::atomic::{self, AtomicIsize, Ordering};
+use crate::utils::{Backoff, CachePadded};
+
+// -----------------------------------------------------------------------------
+// StealDeque
+
+/// A bounded single-owner, multi-stealer work-stealing deque.
+///
+/// The owner thread pushes and pops elements at the bottom of the deque,
+/// in LIFO order, without contention unless a single element is left.
+/// Any thread may steal elements from the top, in FIFO order.
+///
+/// This is the building block of schedulers with per-thread task queues:
+/// each worker owns a deque, and idle workers steal from the others.
+///
+/// The buffer is allocated on construction and never grows, pushing into
+/// a full deque fails.
+///
+/// # Ownership
+///
+/// [`push`](Self::push), [`pop`](Self::pop) and
+/// [`steal_batch_and_pop`](Self::steal_batch_and_pop) are `unsafe`, because
+/// they must only be called from one thread at a time, the owner.
+/// They may run concurrently with [`steal`](Self::steal) on any thread.
+///
+/// # Examples
+///
+/// ```
+/// use vc_os::utils::StealDeque;
+///
+/// let deque = StealDeque::new(4);
+///
+/// // SAFETY: This thread is the only owner.
+/// unsafe {
+///     deque.push(1).unwrap();
+///     deque.push(2).unwrap();
+///     deque.push(3).unwrap();
+///
+///     assert_eq!(deque.pop(), Some(3));
+/// }
+/// assert_eq!(deque.steal(), Some(1));
+/// assert_eq!(deque.len(), 1);
+/// ```
+pub struct StealDeque<T> {
+    /// The index of the next element to steal.
+    top: CachePadded<AtomicIsize>,
+    /// The index of the next slot to push, only written by the owner.
+    bottom: CachePadded<AtomicIsize>,
+    /// The ring buffer, its length is a power of two.
+    buffer: Box<[UnsafeCell<MaybeUninit<T>>]>,
+}
+
+unsafe impl<T: Send> Sync for StealDeque<T> {}
+unsafe impl<T: Send> Send for StealDeque<T> {}
+impl<T> UnwindSafe for StealDeque<T> {}
+impl<T> RefUnwindSafe for StealDeque<T> {}
+
+impl<T> StealDeque<T> {
+    /// Creates a new deque, holding at least `cap` elements.
+    ///
+    /// The capacity is rounded up to the next power of two.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is zero.
+    pub fn new(cap: usize) -> Self {
+        assert!(cap > 0, "capacity must be non-zero");
+
+        let buffer = (0..cap.next_power_of_two())
+            .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+            .collect();
+
+        Self {
+            top: CachePadded::new(AtomicIsize::new(0)),
+            bottom: CachePadded::new(AtomicIsize::new(0)),
+            buffer,
+        }
+    }
+
+    #[inline(always)]
+    fn slot(&self, index: isize) -> *mut MaybeUninit<T> {
+        let index = (index as usize) & (self.buffer.len() - 1);
+        // SAFETY: masked by `len - 1`, and `len` is a power of two.
+        unsafe { self.buffer.get_unchecked(index).get() }
+    }
+
+    /// Pushes an element at the bottom of the deque.
+    ///
+    /// If the deque is full, the element is returned back as an error.
+    ///
+    /// # Safety
+    ///
+    /// Must not be called concurrently with other owner methods.
+    pub unsafe fn push(&self, value: T) -> Result<(), T> {
+        let b = self.bottom.load(Ordering::Relaxed);
+        let t = self.top.load(Ordering::Acquire);
+
+        if b.wrapping_sub(t) >= self.buffer.len() as isize {
+            return Err(value);
+        }
+
+        unsafe { self.slot(b).write(MaybeUninit::new(value)) };
+        self.bottom.store(b.wrapping_add(1), Ordering::Release);
+        Ok(())
+    }
+
+    /// Pops the element at the bottom of the deque, the last one pushed.
+    ///
+    /// # Safety
+    ///
+    /// Must not be called concurrently with other owner methods.
+    pub unsafe fn pop(&self) -> Option<T> {
+        let b = self.bottom.load(Ordering::Relaxed).wrapping_sub(1);
+        self.bottom.store(b, Ordering::Relaxed);
+        atomic::fence(Ordering::SeqCst);
+        let t = self.top.load(Ordering::Relaxed);
+
+        let len = b.wrapping_sub(t);
+        if len < 0 {
+            // Empty, restore the bottom.
+            self.bottom.store(b.wrapping_add(1), Ordering::Relaxed);
+            return None;
+        }
+
+        let value = unsafe { self.slot(b).read() };
+        if len > 0 {
+            return Some(unsafe { value.assume_init() });
+        }
+
+        // The last element, race against the stealers.
+        let won = self
+            .top
+            .compare_exchange(t, t.wrapping_add(1), Ordering::SeqCst, Ordering::Relaxed)
+            .is_ok();
+        self.bottom.store(b.wrapping_add(1), Ordering::Relaxed);
+
+        if won {
+            Some(unsafe { value.assume_init() })
+        } else {
+            // A stealer took it, our copy must not be dropped.
+            None
+        }
+    }
+
+    /// Steals the element at the top of the deque, the first one pushed.
+    ///
+    /// Returns `None` if the deque is empty. Contention with other stealers
+    /// is retried internally.
+    pub fn steal(&self) -> Option<T> {
+        let backoff = Backoff::new();
+        loop {
+            let t = self.top.load(Ordering::Acquire);
+            atomic::fence(Ordering::SeqCst);
+            let b = self.bottom.load(Ordering::Acquire);
+
+            if b.wrapping_sub(t) <= 0 {
+                return None;
+            }
+
+            // The slot may be overwritten concurrently if another stealer
+            // already took it, in which case the CAS below fails and the
+            // copy is discarded without being dropped.
+            let value = unsafe { ptr::read_volatile(self.slot(t)) };
+
+            if self
+                .top
+                .compare_exchange(t, t.wrapping_add(1), Ordering::SeqCst, Ordering::Relaxed)
+                .is_ok()
+            {
+                return Some(unsafe { value.assume_init() });
+            }
+
+            backoff.spin();
+        }
+    }
+
+    /// Steals about half of the elements into `dst` and returns one of them.
+    ///
+    /// At most as many elements as fit into `dst` are moved, which bounds
+    /// the time spent in a single steal. Returns `None` if the deque is empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must be the owner of `dst`, see [`push`](Self::push).
+    pub unsafe fn steal_batch_and_pop(&self, dst: &Self) -> Option<T> {
+        let first = self.steal()?;
+
+        // Stealers only remove elements, so the free space of `dst` can only grow.
+        let free = dst.capacity() - dst.len();
+        let count = self.len().div_ceil(2);
+        for _ in 0..count.min(free) {
+            let Some(value) = self.steal() else {
+                break;
+            };
+            if unsafe { dst.push(value) }.is_err() {
+                unreachable!("the destination deque has enough free space");
+            }
+        }
+
+        Some(first)
+    }
+
+    /// Returns the capacity of the deque.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns `true` if the deque is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of elements in the deque.
+    ///
+    /// The result may be outdated if other threads modify the deque.
+    pub fn len(&self) -> usize {
+        let t = self.top.load(Ordering::SeqCst);
+        let b = self.bottom.load(Ordering::SeqCst);
+        b.wrapping_sub(t).max(0) as usize
+    }
+}
+
+impl<T> Drop for StealDeque<T> {
+    fn drop(&mut self) {
+        if mem::needs_drop::<T>() {
+            let t = *self.top.get_mut();
+            let b = *self.bottom.get_mut();
+
+            let mut index = t;
+            while index != b {
+                unsafe { (*self.slot(index)).assume_init_drop() };
+                index = index.wrapping_add(1);
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for StealDeque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("StealDeque { .. }")
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::vec::Vec;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread::scope;
+
+    use super::StealDeque;
+
+    #[test]
+    fn smoke() {
+        let deque = StealDeque::new(3);
+        assert_eq!(deque.capacity(), 4);
+
+        unsafe {
+            for i in 0..4 {
+                deque.push(i).unwrap();
+            }
+            assert_eq!(deque.push(4), Err(4));
+            assert_eq!(deque.pop(), Some(3));
+        }
+        assert_eq!(deque.steal(), Some(0));
+
+        let dst = StealDeque::new(1);
+        assert_eq!(unsafe { deque.steal_batch_and_pop(&dst) }, Some(1));
+        assert_eq!(dst.steal(), Some(2));
+        assert!(deque.is_empty());
+        assert_eq!(unsafe { deque.pop() }, None);
+    }
+
+    #[test]
+    fn steal_concurrently() {
+        #[cfg(miri)]
+        const COUNT: usize = 50;
+        #[cfg(not(miri))]
+        const COUNT: usize = 50_000;
+        const STEALERS: usize = 3;
+
+        let deque = StealDeque::<usize>::new(16);
+        let v = (0..COUNT).map(|_| AtomicUsize::new(0)).collect::<Vec<_>>();
+        let done = AtomicUsize::new(0);
+
+        scope(|scope| {
+            for _ in 0..STEALERS {
+                scope.spawn(|| {
+                    let local = StealDeque::new(16);
+                    while done.load(Ordering::Acquire) < COUNT {
+                        let Some(n) = (unsafe { deque.steal_batch_and_pop(&local) }) else {
+                            continue;
+                        };
+                        v[n].fetch_add(1, Ordering::SeqCst);
+                        done.fetch_add(1, Ordering::AcqRel);
+                        while let Some(n) = unsafe { local.pop() } {
+                            v[n].fetch_add(1, Ordering::SeqCst);
+                            done.fetch_add(1, Ordering::AcqRel);
+                        }
+                    }
+                });
+            }
+
+            // The owner.
+            for i in 0..COUNT {
+                let mut value = i;
+                while let Err(back) = unsafe { deque.push(value) } {
+                    value = back;
+                    if let Some(n) = unsafe { deque.pop() } {
+                        v[n].fetch_add(1, Ordering::SeqCst);
+                        done.fetch_add(1, Ordering::AcqRel);
+                    }
+                }
+            }
+            while let Some(n) = unsafe { deque.pop() } {
+                v[n].fetch_add(1, Ordering::SeqCst);
+                done.fetch_add(1, Ordering::AcqRel);
+            }
+        });
+
+        for c in v {
+            assert_eq!(c.load(Ordering::SeqCst), 1);
+        }
+    }
+}
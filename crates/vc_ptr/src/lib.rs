@@ -24,14 +24,30 @@
 //!
 //! `OwningPtr` does **not** manage allocation; it typically points to stack values
 //! or data managed by other containers(e.g. `[MaybeUninit<T>; N]`).
+//!
+//! **Debug tracking**
+//!
+//! With the `debug` feature, values moved into an `OwningPtr` through
+//! [`OwningPtr::make`] or [`into_owning!`] are tracked, and misuse such as
+//! double-reads, reads after drop, or leaks panics instead of silently
+//! causing undefined behavior. This requires `std`, only takes effect with
+//! debug assertions, and has a noticeable cost. It is enabled by the `debug`
+//! feature of the `vc_core` crate.
 #![expect(unsafe_code, reason = "Raw pointers are inherently unsafe.")]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![no_std]
 
+#[cfg(feature = "debug")]
+extern crate alloc;
+
+#[cfg(feature = "debug")]
+extern crate std;
+
 // -----------------------------------------------------------------------------
 // Modules
 
 mod thin_slice;
+mod tracking;
 mod type_erased;
 
 // -----------------------------------------------------------------------------
@@ -39,3 +55,6 @@ mod type_erased;
 
 pub use thin_slice::{ThinSlice, ThinSliceMut};
 pub use type_erased::{OwningPtr, Ptr, PtrMut};
+
+#[doc(hidden)]
+pub use tracking::OwningScope;
@@ -0,0 +1,313 @@
+//! Liveness tracking for values behind [`OwningPtr`].
+//!
+//! With the `debug` feature, every value moved into an [`OwningPtr`] through
+//! [`OwningPtr::make`] or [`into_owning!`] is recorded in a global registry
+//! under a sequence id, together with the byte ranges that have been consumed.
+//!
+//! - Consuming bytes twice (`read`, `drop_as`, `move_to`) panics, which catches
+//!   double-reads and reads after drop, including those through aliased pointers
+//!   created by [`OwningPtr::new`] or [`PtrMut::promote`].
+//! - Leaving the scope with unconsumed bytes panics as a leak. Since padding is
+//!   never consumed, only gaps of at least the alignment of the value count,
+//!   so a leaked field smaller than that alignment may go unnoticed.
+//!
+//! Pointers to memory outside of a tracked scope (e.g. storage owned by
+//! containers) and zero-sized values are not tracked. Tracking only happens
+//! with debug assertions, and without the feature, everything here compiles
+//! to nothing.
+//!
+//! [`OwningPtr`]: crate::OwningPtr
+//! [`OwningPtr::make`]: crate::OwningPtr::make
+//! [`OwningPtr::new`]: crate::OwningPtr::new
+//! [`PtrMut::promote`]: crate::PtrMut::promote
+//! [`into_owning!`]: crate::into_owning
+
+use core::mem::ManuallyDrop;
+
+// -----------------------------------------------------------------------------
+// Consume
+
+/// The way the bytes behind an `OwningPtr` are consumed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Consume {
+    Read,
+    Drop,
+}
+
+/// Marks `size` bytes at `addr` as consumed.
+///
+/// # Panics
+/// With the `debug` feature, panics if any of these bytes were consumed before.
+#[cfg_attr(feature = "debug", track_caller)]
+#[cfg_attr(not(feature = "debug"), inline(always))]
+pub(crate) fn consume(addr: *mut u8, size: usize, kind: Consume) {
+    #[cfg(feature = "debug")]
+    if cfg!(debug_assertions) {
+        registry::consume(addr.addr(), size, kind);
+    }
+    #[cfg(not(feature = "debug"))]
+    let _ = (addr, size, kind);
+}
+
+// -----------------------------------------------------------------------------
+// OwningScope
+
+/// A guard that tracks a value for as long as it is pointed to by an `OwningPtr`.
+///
+/// Used by [`OwningPtr::make`] and [`into_owning!`], it should not be needed
+/// elsewhere.
+///
+/// [`OwningPtr::make`]: crate::OwningPtr::make
+/// [`into_owning!`]: crate::into_owning
+#[doc(hidden)]
+#[must_use]
+pub struct OwningScope {
+    #[cfg(feature = "debug")]
+    addr: Option<usize>,
+}
+
+impl OwningScope {
+    /// Starts tracking `value`, the scope ends when the guard is dropped.
+    #[inline(always)]
+    pub fn new<T>(value: &mut ManuallyDrop<T>) -> Self {
+        #[cfg(feature = "debug")]
+        {
+            let size = size_of::<T>();
+            let addr = (cfg!(debug_assertions) && size != 0).then(|| {
+                let addr = (&raw mut *value).addr();
+                registry::begin(addr, size, align_of::<T>());
+                addr
+            });
+            Self { addr }
+        }
+        #[cfg(not(feature = "debug"))]
+        {
+            let _ = value;
+            Self {}
+        }
+    }
+}
+
+#[cfg(feature = "debug")]
+impl Drop for OwningScope {
+    fn drop(&mut self) {
+        if let Some(addr) = self.addr {
+            registry::end(addr);
+        }
+    }
+}
+
+/// Returns the sequence id of the tracked value containing `addr`.
+#[cfg(feature = "debug")]
+pub(crate) fn id_of(addr: *mut u8) -> Option<u64> {
+    registry::id_of(addr.addr())
+}
+
+// -----------------------------------------------------------------------------
+// Registry
+
+#[cfg(feature = "debug")]
+mod registry {
+    use alloc::collections::BTreeMap;
+    use alloc::vec::Vec;
+    use core::ops::Range;
+    use core::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Mutex, MutexGuard};
+
+    use super::Consume;
+
+    struct Record {
+        id: u64,
+        size: usize,
+        align: usize,
+        consumed: Vec<(Range<usize>, Consume)>,
+    }
+
+    impl Record {
+        /// Returns the first range of at least `align` bytes that is not consumed,
+        /// relative to `addr`. Smaller gaps may be padding.
+        fn leaked(&mut self, addr: usize) -> Option<Range<usize>> {
+            self.consumed.sort_unstable_by_key(|(range, _)| range.start);
+            let mut start = addr;
+            let end = addr + self.size;
+            let starts = self
+                .consumed
+                .iter()
+                .map(|(range, _)| (range.start, range.end));
+            for (next, next_end) in starts.chain(core::iter::once((end, end))) {
+                if next - start >= self.align {
+                    return Some(start - addr..next - addr);
+                }
+                start = start.max(next_end);
+            }
+            None
+        }
+    }
+
+    static RECORDS: Mutex<BTreeMap<usize, Record>> = Mutex::new(BTreeMap::new());
+
+    fn records() -> MutexGuard<'static, BTreeMap<usize, Record>> {
+        RECORDS.lock().unwrap_or_else(|poison| poison.into_inner())
+    }
+
+    pub(super) fn begin(addr: usize, size: usize, align: usize) {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        let record = Record {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            size,
+            align,
+            consumed: Vec::new(),
+        };
+        records().insert(addr, record);
+    }
+
+    pub(super) fn end(addr: usize) {
+        let Some(mut record) = records().remove(&addr) else {
+            return;
+        };
+        if std::thread::panicking() {
+            return;
+        }
+        if record.consumed.is_empty() {
+            panic!(
+                "OwningPtr #{} to {addr:#x} is leaked, the value is neither read nor dropped",
+                record.id,
+            );
+        }
+        if let Some(range) = record.leaked(addr) {
+            panic!(
+                "OwningPtr #{} to {addr:#x} is partially leaked, bytes {range:?} of {} are neither read nor dropped",
+                record.id, record.size,
+            );
+        }
+    }
+
+    pub(super) fn id_of(addr: usize) -> Option<u64> {
+        let records = records();
+        let (start, record) = records.range(..=addr).next_back()?;
+        (addr < start + record.size).then_some(record.id)
+    }
+
+    #[track_caller]
+    pub(super) fn consume(addr: usize, size: usize, kind: Consume) {
+        if size == 0 {
+            return;
+        }
+
+        let range = addr..addr + size;
+        let conflict = {
+            let mut records = records();
+            let Some((start, record)) = records.range_mut(..=addr).next_back() else {
+                return;
+            };
+            if range.end > start + record.size {
+                return;
+            }
+            let conflict = record
+                .consumed
+                .iter()
+                .find(|(old, _)| old.start < range.end && range.start < old.end)
+                .map(|(_, old)| (record.id, *old));
+            if conflict.is_none() {
+                record.consumed.push((range, kind));
+            }
+            conflict
+        };
+
+        if let Some((id, old)) = conflict {
+            let name = |kind| match kind {
+                Consume::Read => "read",
+                Consume::Drop => "drop",
+            };
+            panic!(
+                "OwningPtr #{id} to {addr:#x} is consumed twice, {} after {}",
+                name(kind),
+                name(old),
+            );
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(all(test, feature = "debug"))]
+mod tests {
+    use alloc::string::String;
+    use core::mem::{ManuallyDrop, offset_of};
+    use core::ptr::NonNull;
+
+    use crate::OwningPtr;
+
+    #[test]
+    fn consumed_once() {
+        let value = String::from("hello");
+        let value = OwningPtr::make(value, |ptr| unsafe { ptr.read::<String>() });
+        assert_eq!(value, "hello");
+
+        OwningPtr::make((1_u32, String::from("field")), |mut ptr| unsafe {
+            assert!(ptr.debug_id().is_some());
+            ptr.take_field(offset_of!((u32, String), 0))
+                .drop_as::<u32>();
+            ptr.take_field(offset_of!((u32, String), 1))
+                .drop_as::<String>();
+        });
+    }
+
+    #[test]
+    #[should_panic = "read after read"]
+    fn double_read() {
+        OwningPtr::make(1_u64, |mut ptr| unsafe {
+            let alias = OwningPtr::new(ptr.borrow_mut().into_inner());
+            alias.read::<u64>();
+            ptr.read::<u64>();
+        });
+    }
+
+    #[test]
+    #[should_panic = "read after drop"]
+    fn read_after_drop() {
+        let value = String::from("hello");
+        crate::into_owning!(value);
+        let alias = unsafe { OwningPtr::new(NonNull::new_unchecked(value.as_ptr())) };
+        unsafe {
+            value.drop_as::<String>();
+            alias.read::<String>();
+        }
+    }
+
+    #[test]
+    #[should_panic = "is leaked"]
+    fn leak() {
+        OwningPtr::make(1_u64, |_ptr| {});
+    }
+
+    #[test]
+    #[should_panic = "is partially leaked, bytes 8..16 of 16"]
+    fn partial_leak() {
+        OwningPtr::make((1_u64, 2_u64), |mut ptr| unsafe {
+            ptr.take_field(offset_of!((u64, u64), 0)).drop_as::<u64>();
+        });
+    }
+
+    #[test]
+    fn padding() {
+        // 3 bytes of padding after the `u8`.
+        #[repr(C)]
+        struct Padded(u8, u32);
+
+        OwningPtr::make(Padded(1, 2), |mut ptr| unsafe {
+            ptr.take_field(offset_of!(Padded, 0)).drop_as::<u8>();
+            ptr.take_field(offset_of!(Padded, 1)).drop_as::<u32>();
+        });
+    }
+
+    #[test]
+    fn untracked() {
+        let mut value = ManuallyDrop::new(String::from("hello"));
+        let ptr = OwningPtr::from_value(&mut value);
+        assert!(ptr.debug_id().is_none());
+        unsafe { ptr.drop_as::<String>() };
+    }
+}
@@ -3,6 +3,8 @@ use core::marker::PhantomData;
 use core::mem::ManuallyDrop;
 use core::ptr::{self, NonNull};
 
+use crate::tracking::{self, Consume, OwningScope};
+
 // -----------------------------------------------------------------------------
 // Common methods
 
@@ -658,8 +660,10 @@ impl<'a> OwningPtr<'a> {
     ///
     /// unsafe{ ptr.drop_as::<String>(); }
     /// ```
+    #[cfg_attr(feature = "debug", track_caller)]
     #[inline(always)]
     pub unsafe fn drop_as<T>(self) {
+        tracking::consume(self.0.as_ptr(), size_of::<T>(), Consume::Drop);
         // SAFETY: see function docs.
         unsafe { ptr::drop_in_place::<T>(self.0.as_ptr().cast::<T>()) }
     }
@@ -689,12 +693,69 @@ impl<'a> OwningPtr<'a> {
     ///
     /// let x = unsafe{ ptr.read::<String>() };
     /// ```
+    ///
+    /// With the `debug` feature, the read is tracked and this function is not `const`.
+    #[cfg(not(feature = "debug"))]
     #[inline(always)]
     pub const unsafe fn read<T>(self) -> T {
         // SAFETY: see function docs.
         unsafe { ptr::read(self.0.as_ptr() as *mut T) }
     }
 
+    /// Consumes the [`OwningPtr`] to obtain ownership of the underlying data of type `T`.
+    ///
+    /// The caller must ensure the pointer is suitable for `T`.
+    ///
+    /// It is recommended to use [`debug_assert_aligned`](Self::debug_assert_aligned)
+    /// to check alignment before calling.
+    ///
+    /// # Safety
+    /// - `ptr` must be properly aligned for type `T`.
+    /// - `T` must be the erased pointee type for this [`OwningPtr`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_ptr::OwningPtr;
+    /// # use core::{ptr::NonNull, mem::ManuallyDrop};
+    /// let mut x = ManuallyDrop::new("1234".to_string());
+    /// let ptr: OwningPtr<'_> = unsafe {
+    ///     OwningPtr::new(NonNull::from_mut(&mut x).cast())
+    /// };
+    ///
+    /// // do something
+    ///
+    /// let x = unsafe{ ptr.read::<String>() };
+    /// ```
+    ///
+    /// With the `debug` feature, the read is tracked and this function is not `const`.
+    #[cfg(feature = "debug")]
+    #[track_caller]
+    #[inline(always)]
+    pub unsafe fn read<T>(self) -> T {
+        tracking::consume(self.0.as_ptr(), size_of::<T>(), Consume::Read);
+        // SAFETY: see function docs.
+        unsafe { ptr::read(self.0.as_ptr() as *mut T) }
+    }
+
+    /// Consumes the [`OwningPtr`] to move the underlying data into `dst`.
+    ///
+    /// This is the type-erased counterpart of [`read`](Self::read),
+    /// copying `size` bytes without knowing the pointee type.
+    ///
+    /// # Safety
+    /// - `size` must be the size of the erased pointee type.
+    /// - `dst` must be valid for writes of `size` bytes and properly aligned.
+    /// - `dst` must not overlap with the pointee.
+    /// - The previous value at `dst` (if any) is overwritten without being dropped.
+    #[cfg_attr(feature = "debug", track_caller)]
+    #[inline(always)]
+    pub unsafe fn move_to(self, dst: *mut u8, size: usize) {
+        tracking::consume(self.0.as_ptr(), size, Consume::Read);
+        // SAFETY: see function docs.
+        unsafe { ptr::copy_nonoverlapping::<u8>(self.0.as_ptr(), dst, size) }
+    }
+
     /// Writes `value` into the memory pointed to by this pointer.
     ///
     /// This uses `ptr::write`, so it does not read or drop any existing value.
@@ -731,6 +792,7 @@ impl<'a> OwningPtr<'a> {
     /// }
     ///
     /// OwningPtr::make(Foo { id: 1, data: [0; 10] }, |mut ptr| unsafe {
+    ///     ptr.take_field(core::mem::offset_of!(Foo, id)).read::<u32>();
     ///     ptr.take_field(core::mem::offset_of!(Foo, data)).read::<[u8; 10]>()
     /// });
     /// ```
@@ -765,6 +827,7 @@ impl<'a> OwningPtr<'a> {
     #[inline(always)]
     pub fn make<T, F: FnOnce(OwningPtr<'_>) -> R, R>(val: T, f: F) -> R {
         let mut val = ManuallyDrop::new(val);
+        let _scope = OwningScope::new(&mut val);
         f(OwningPtr(
             // SAFETY: the pointer is valid and aligned.
             unsafe { NonNull::new_unchecked(&raw mut val as *mut u8) },
@@ -778,6 +841,17 @@ impl<'a> OwningPtr<'a> {
         self.0.as_ptr()
     }
 
+    /// Returns the sequence id of the tracked value this pointer points into.
+    ///
+    /// Only values moved in through [`make`](Self::make) or [`into_owning!`]
+    /// are tracked, `None` is returned for other pointers.
+    ///
+    /// [`into_owning!`]: crate::into_owning
+    #[cfg(feature = "debug")]
+    pub fn debug_id(&self) -> Option<u64> {
+        tracking::id_of(self.0.as_ptr())
+    }
+
     /// Gets an [`Ptr`] from self with **smaller** lifetime.
     ///
     /// Lifetime will be consistent with `&OwningPtr`, not generic `'a`.
@@ -852,10 +926,12 @@ impl<'a> OwningPtr<'a> {
 macro_rules! into_owning {
     ($data:ident) => {
         let mut $data = ::core::mem::ManuallyDrop::new($data);
+        let _scope = $crate::OwningScope::new(&mut $data);
         let $data = $crate::OwningPtr::from_value(&mut $data);
     };
     ($data:ident as $ptr:ident) => {
         let mut $data = ::core::mem::ManuallyDrop::new($data);
+        let _scope = $crate::OwningScope::new(&mut $data);
         let $ptr = $crate::OwningPtr::from_value(&mut $data);
     };
 }
@@ -0,0 +1,61 @@
+//! Cost of deserializing nested structs, which reuse pooled field slots.
+//!
+//! Run with `cargo bench -p vc_reflect --bench deserialize`.
+
+use core::hint::black_box;
+use std::time::Instant;
+
+use serde::de::DeserializeSeed;
+use vc_reflect::Reflect;
+use vc_reflect::registry::TypeRegistry;
+use vc_reflect::serde::ReflectDeserializeDriver;
+
+const ROUNDS: u32 = 1000;
+
+#[derive(Reflect)]
+struct Leaf {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+#[derive(Reflect)]
+struct Node {
+    name: String,
+    a: Leaf,
+    b: Leaf,
+    c: Leaf,
+}
+
+#[derive(Reflect)]
+struct Scene {
+    nodes: Vec<Node>,
+}
+
+fn measure(name: &str, mut f: impl FnMut()) {
+    f(); // warm up
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    let average = start.elapsed() / ROUNDS;
+    println!("{name:<24} {average:>10.3?}");
+}
+
+fn main() {
+    let mut registry = TypeRegistry::new();
+    registry.register_recursive::<Scene>();
+
+    let leaf = "(x: 1.0, y: 2.0, z: 3.0)";
+    let node = format!("(name: \"node\", a: {leaf}, b: {leaf}, c: {leaf})");
+    let nodes = vec![node; 256].join(", ");
+    let input = format!("{{ \"deserialize::Scene\": (nodes: [{nodes}]) }}");
+
+    measure("nested structs", || {
+        let mut data = ron::Deserializer::from_str(&input).unwrap();
+        let output = ReflectDeserializeDriver::new(&registry)
+            .deserialize(&mut data)
+            .unwrap();
+        black_box(output);
+    });
+}
@@ -1,6 +1,6 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Expr, Path, Token, parse::ParseStream};
+use syn::{Expr, Ident, Path, Token, parse::ParseStream};
 
 /// A container for custom attribute expressions.
 ///
@@ -8,6 +8,7 @@ use syn::{Expr, Path, Token, parse::ParseStream};
 #[derive(Default)]
 pub(crate) struct CustomAttributes {
     attributes: Vec<Expr>,
+    keyed: Vec<(Ident, Expr)>,
 }
 
 impl CustomAttributes {
@@ -17,9 +18,22 @@ impl CustomAttributes {
     /// - `#[reflect(@Foo))]`
     /// - `#[reflect(@Bar::baz("qux"))]`
     /// - `#[reflect(@0..256u8)]`
+    /// - `#[reflect(@min = 0.0f32)]`, keyed by `"min"`
     pub fn parse_stream(&mut self, input: ParseStream) -> syn::Result<()> {
         input.parse::<Token![@]>()?;
-        self.attributes.push(input.parse::<Expr>()?);
+        if input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+            let key = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            if self.keyed.iter().any(|(k, _)| *k == key) {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format_args!("duplicate custom attribute key `{key}`."),
+                ));
+            }
+            self.keyed.push((key, input.parse::<Expr>()?));
+        } else {
+            self.attributes.push(input.parse::<Expr>()?);
+        }
         Ok(())
     }
 
@@ -31,12 +45,13 @@ impl CustomAttributes {
     /// .with_custom_attributes(
     ///     _path_::CustomAttributes::new()
     ///         (.with_attribute( ... ))*
+    ///         (.with_keyed_attribute("key", ... ))*
     /// )
     /// ```
     ///
     /// The type path will be parsed before returning.
     pub fn get_expression_with(&self, vc_reflect_path: &Path) -> TokenStream {
-        if self.attributes.is_empty() {
+        if self.attributes.is_empty() && self.keyed.is_empty() {
             return crate::utils::empty();
         }
 
@@ -48,12 +63,20 @@ impl CustomAttributes {
             }
         });
 
+        let with_keyed_attributes = self.keyed.iter().map(|(key, value)| {
+            let key = key.to_string();
+            quote! {
+                .with_keyed_attribute(#key, #value)
+            }
+        });
+
         let custom_attributes_ = crate::path::custom_attributes_(vc_reflect_path);
 
         quote! {
             .with_custom_attributes(
                 #custom_attributes_::with_capacity(#capacity)
                     #(#with_attributes)*
+                    #(#with_keyed_attributes)*
             )
         }
     }
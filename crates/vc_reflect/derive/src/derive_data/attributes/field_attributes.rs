@@ -1,7 +1,7 @@
 use proc_macro2::Span;
 
-use syn::{Attribute, MacroDelimiter, Meta, MetaList};
-use syn::{MetaNameValue, Token, parse::ParseStream};
+use syn::{Attribute, Expr, ExprLit, Lit, MacroDelimiter, Meta, MetaList, Path};
+use syn::{MetaNameValue, Token, parse::ParseStream, spanned::Spanned};
 
 use super::{CustomAttributes, ReflectDocs};
 
@@ -10,6 +10,11 @@ use crate::REFLECT_ATTRIBUTE;
 mod kw {
     syn::custom_keyword!(doc);
     syn::custom_keyword!(skip_serde);
+    syn::custom_keyword!(serialize_with);
+    syn::custom_keyword!(deserialize_with);
+    syn::custom_keyword!(get);
+    syn::custom_keyword!(get_mut);
+    syn::custom_keyword!(set);
 }
 
 #[derive(Default)]
@@ -20,6 +25,16 @@ pub(crate) struct FieldAttributes {
     pub docs: ReflectDocs,
     /// Determines how this field should be skipped during reflect (de)serialization.
     pub skip_serde: Option<Span>,
+    /// Custom serializer: `#[reflect(serialize_with = "path")]`.
+    pub serialize_with: Option<Path>,
+    /// Custom deserializer: `#[reflect(deserialize_with = "path")]`.
+    pub deserialize_with: Option<Path>,
+    /// Getter of private fields: `#[reflect(get = path)]`, `fn(&Self) -> &T`.
+    pub get: Option<Path>,
+    /// Mutable getter: `#[reflect(get_mut = path)]`, `fn(&mut Self) -> &mut T`.
+    pub get_mut: Option<Path>,
+    /// Setter: `#[reflect(set = path)]`, `fn(&mut Self, T)`.
+    pub set: Option<Path>,
 }
 
 impl FieldAttributes {
@@ -77,6 +92,19 @@ impl FieldAttributes {
             self.parse_docs(input)
         } else if lookahead.peek(kw::skip_serde) {
             self.parse_skip_serde(input)
+        } else if lookahead.peek(kw::serialize_with) {
+            parse_with_path(input, &mut self.serialize_with)
+        } else if lookahead.peek(kw::deserialize_with) {
+            parse_with_path(input, &mut self.deserialize_with)
+        } else if lookahead.peek(kw::get) {
+            let span = input.parse::<kw::get>()?.span;
+            Self::parse_accessor(input, span, &mut self.get)
+        } else if lookahead.peek(kw::get_mut) {
+            let span = input.parse::<kw::get_mut>()?.span;
+            Self::parse_accessor(input, span, &mut self.get_mut)
+        } else if lookahead.peek(kw::set) {
+            let span = input.parse::<kw::set>()?.span;
+            Self::parse_accessor(input, span, &mut self.set)
         } else {
             Err(lookahead.error())
         }
@@ -99,4 +127,57 @@ impl FieldAttributes {
         self.skip_serde = Some(s);
         Ok(())
     }
+
+    /// `skip_serde` and the custom (de)serializers are exclusive.
+    pub fn validity(&self) -> syn::Result<()> {
+        if let Some(span) = self.skip_serde
+            && (self.serialize_with.is_some() || self.deserialize_with.is_some())
+        {
+            return Err(syn::Error::new(
+                span,
+                "`skip_serde` cannot be used with `serialize_with` or `deserialize_with`.",
+            ));
+        }
+        Ok(())
+    }
+
+    fn parse_accessor(input: ParseStream, span: Span, slot: &mut Option<Path>) -> syn::Result<()> {
+        // #[reflect(get = path)]
+        input.parse::<Token![=]>()?;
+        if slot.replace(input.parse::<Path>()?).is_some() {
+            return Err(syn::Error::new(span, "duplicate field accessor"));
+        }
+        Ok(())
+    }
+
+    /// Whether the field is accessed through `get`, `get_mut` or `set`.
+    pub fn has_accessors(&self) -> bool {
+        self.get.is_some() || self.get_mut.is_some() || self.set.is_some()
+    }
+}
+
+/// Parses `serialize_with = "path"` or `deserialize_with = "path"`.
+///
+/// Shared with the type attributes.
+pub(super) fn parse_with_path(input: ParseStream, slot: &mut Option<Path>) -> syn::Result<()> {
+    let pair = input.parse::<MetaNameValue>()?;
+
+    if let Expr::Lit(ExprLit {
+        lit: Lit::Str(lit), ..
+    }) = &pair.value
+    {
+        if slot.replace(lit.parse::<Path>()?).is_some() {
+            return Err(syn::Error::new(
+                pair.span(),
+                "duplicate `serialize_with` or `deserialize_with`.",
+            ));
+        }
+    } else {
+        return Err(syn::Error::new(
+            pair.value.span(),
+            "Expected a string liternal value.",
+        ));
+    }
+
+    Ok(())
 }
@@ -54,6 +54,7 @@ pub(crate) struct TraitAvailableFlags {
     pub default: Option<Span>,
     pub clone: Option<Span>,
     pub debug: Option<Span>,
+    pub display: Option<Span>,
     pub hash: Option<Span>,
     pub eq: Option<Span>,
     pub cmp: Option<Span>,
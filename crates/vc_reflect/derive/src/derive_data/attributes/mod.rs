@@ -20,4 +20,4 @@ use flags::{TraitAvailableFlags, TraitImplSwitches};
 use reflect_docs::ReflectDocs;
 
 pub(crate) use field_attributes::FieldAttributes;
-pub(crate) use type_attributes::TypeAttributes;
+pub(crate) use type_attributes::{ProxyFormat, TypeAttributes};
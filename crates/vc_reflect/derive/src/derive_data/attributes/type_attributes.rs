@@ -1,8 +1,9 @@
 use proc_macro2::Span;
-use syn::{Attribute, Expr, ExprLit, Lit, MacroDelimiter};
-use syn::{Meta, MetaNameValue, Path, Token};
+use syn::{Attribute, Expr, ExprLit, Lit, LitStr, MacroDelimiter};
+use syn::{Meta, MetaNameValue, Path, Token, Type, WhereClause};
 use syn::{parse::ParseStream, spanned::Spanned};
 
+use super::field_attributes::parse_with_path;
 use super::{CustomAttributes, ReflectDocs, TraitAvailableFlags, TraitImplSwitches};
 
 use crate::REFLECT_ATTRIBUTE;
@@ -22,16 +23,35 @@ mod kw {
     syn::custom_keyword!(default);
     syn::custom_keyword!(clone);
     syn::custom_keyword!(debug);
+    syn::custom_keyword!(display);
     syn::custom_keyword!(hash);
     syn::custom_keyword!(eq);
     syn::custom_keyword!(cmp);
     syn::custom_keyword!(serialize);
     syn::custom_keyword!(deserialize);
+    syn::custom_keyword!(serialize_with);
+    syn::custom_keyword!(deserialize_with);
     syn::custom_keyword!(serde); // serialize + deserialize + auto_register
     syn::custom_keyword!(type_path);
+    syn::custom_keyword!(uuid);
+    syn::custom_keyword!(alias);
     syn::custom_keyword!(doc);
     syn::custom_keyword!(full); // serde + clone + debug + hash + partial_eq + partial_cmp + default
     syn::custom_keyword!(type_trait);
+    syn::custom_keyword!(from);
+    syn::custom_keyword!(readable_from);
+    syn::custom_keyword!(binary_from);
+    syn::custom_keyword!(no_field_bounds);
+    syn::custom_keyword!(derive_tests);
+}
+
+/// The serializer formats a proxy type is used for.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum ProxyFormat {
+    #[default]
+    Any,
+    HumanReadable,
+    Binary,
 }
 
 #[derive(Default)]
@@ -48,10 +68,28 @@ pub(crate) struct TypeAttributes {
     pub auto_register: Option<Span>,
     /// `#[reflect(type_path = "...")]`
     pub type_path: Option<Path>,
+    /// `#[reflect(uuid = "...")]`
+    pub uuid: Option<u128>,
+    /// `#[reflect(alias = "...")]`, can be repeated.
+    pub aliases: Vec<LitStr>,
     /// `#[reflect(doc = "...")]` or `#[doc = "..."]`
     pub docs: ReflectDocs,
     /// `#[reflect(type_trait = (...))]`
     pub extra_type_trait: Vec<Path>,
+    /// `#[reflect(from = "...")]`
+    pub proxy: Option<Type>,
+    /// `#[reflect(readable_from = "...")]` or `#[reflect(binary_from = "...")]`
+    pub proxy_format: ProxyFormat,
+    /// `#[reflect(where T: Trait, ...)]`
+    pub custom_where: Option<WhereClause>,
+    /// `#[reflect(no_field_bounds)]`
+    pub no_field_bounds: Option<Span>,
+    /// `#[reflect(derive_tests)]`
+    pub derive_tests: Option<Span>,
+    /// `#[reflect(serialize_with = "...")]`
+    pub serialize_with: Option<Path>,
+    /// `#[reflect(deserialize_with = "...")]`
+    pub deserialize_with: Option<Path>,
 }
 
 impl TypeAttributes {
@@ -65,6 +103,22 @@ impl TypeAttributes {
                 "#[reflect(clone)] must be specified when auto impl `Reflect` or `FromReflect` for Opaque Type.",
             ));
         }
+        if let Some(span) = self.avail_traits.serialize
+            && self.serialize_with.is_some()
+        {
+            return Err(syn::Error::new(
+                span,
+                "`serialize` cannot be used with `serialize_with`.",
+            ));
+        }
+        if let Some(span) = self.avail_traits.deserialize
+            && self.deserialize_with.is_some()
+        {
+            return Err(syn::Error::new(
+                span,
+                "`deserialize` cannot be used with `deserialize_with`.",
+            ));
+        }
         Ok(())
     }
 
@@ -134,18 +188,40 @@ impl TypeAttributes {
             self.parse_cmp(input)
         } else if lookahead.peek(kw::debug) {
             self.parse_debug(input)
+        } else if lookahead.peek(kw::display) {
+            self.parse_display(input)
         } else if lookahead.peek(kw::auto_register) {
             self.parse_auto_register(input)
         } else if lookahead.peek(kw::serialize) {
             self.parse_serialize(input)
         } else if lookahead.peek(kw::deserialize) {
             self.parse_deserialize(input)
+        } else if lookahead.peek(kw::serialize_with) {
+            parse_with_path(input, &mut self.serialize_with)
+        } else if lookahead.peek(kw::deserialize_with) {
+            parse_with_path(input, &mut self.deserialize_with)
         } else if lookahead.peek(kw::Opaque) {
             self.parse_opaque(input)
         } else if lookahead.peek(kw::type_path) {
             self.parse_type_path(input)
+        } else if lookahead.peek(kw::uuid) {
+            self.parse_uuid(input)
+        } else if lookahead.peek(kw::alias) {
+            self.parse_alias(input)
         } else if lookahead.peek(kw::type_trait) {
             self.parses_extra_type_trait(input)
+        } else if lookahead.peek(kw::from) {
+            self.parse_proxy(input, ProxyFormat::Any)
+        } else if lookahead.peek(kw::readable_from) {
+            self.parse_proxy(input, ProxyFormat::HumanReadable)
+        } else if lookahead.peek(kw::binary_from) {
+            self.parse_proxy(input, ProxyFormat::Binary)
+        } else if lookahead.peek(Token![where]) {
+            self.parse_custom_where(input)
+        } else if lookahead.peek(kw::no_field_bounds) {
+            self.parse_no_field_bounds(input)
+        } else if lookahead.peek(kw::derive_tests) {
+            self.parse_derive_tests(input)
         } else if lookahead.peek(kw::TypePath) {
             self.parse_trait_type_path(input)
         } else if lookahead.peek(kw::Typed) {
@@ -250,6 +326,13 @@ impl TypeAttributes {
         Ok(())
     }
 
+    // #[reflect(display)]
+    fn parse_display(&mut self, input: ParseStream) -> syn::Result<()> {
+        let s = input.parse::<kw::display>()?.span;
+        self.avail_traits.display = Some(s);
+        Ok(())
+    }
+
     // #[reflect(serialize)]
     fn parse_serialize(&mut self, input: ParseStream) -> syn::Result<()> {
         let s = input.parse::<kw::serialize>()?.span;
@@ -271,6 +354,31 @@ impl TypeAttributes {
         Ok(())
     }
 
+    // #[reflect(where T: Trait, ...)]
+    // The predicates are comma-separated, so this must be the last item.
+    fn parse_custom_where(&mut self, input: ParseStream) -> syn::Result<()> {
+        let clause = input.parse::<WhereClause>()?;
+        match &mut self.custom_where {
+            Some(custom_where) => custom_where.predicates.extend(clause.predicates),
+            None => self.custom_where = Some(clause),
+        }
+        Ok(())
+    }
+
+    // #[reflect(no_field_bounds)]
+    fn parse_no_field_bounds(&mut self, input: ParseStream) -> syn::Result<()> {
+        let s = input.parse::<kw::no_field_bounds>()?.span;
+        self.no_field_bounds = Some(s);
+        Ok(())
+    }
+
+    // #[reflect(derive_tests)]
+    fn parse_derive_tests(&mut self, input: ParseStream) -> syn::Result<()> {
+        let s = input.parse::<kw::derive_tests>()?.span;
+        self.derive_tests = Some(s);
+        Ok(())
+    }
+
     // #[reflect(auto_register)]
     fn parse_auto_register(&mut self, input: ParseStream) -> syn::Result<()> {
         let s = input.parse::<kw::auto_register>()?.span;
@@ -310,6 +418,88 @@ impl TypeAttributes {
         Ok(())
     }
 
+    // #[reflect(uuid = "...")]
+    fn parse_uuid(&mut self, input: ParseStream) -> syn::Result<()> {
+        let pair = input.parse::<MetaNameValue>()?;
+
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) = &pair.value
+        {
+            match parse_uuid_str(&lit.value()) {
+                Some(uuid) => self.uuid = Some(uuid),
+                None => {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "`uuid` should be `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx` or 32 hex digits.",
+                    ));
+                }
+            }
+        } else {
+            return Err(syn::Error::new(
+                pair.value.span(),
+                "Expected a string liternal value.",
+            ));
+        }
+
+        Ok(())
+    }
+
+    // #[reflect(alias = "...")]
+    fn parse_alias(&mut self, input: ParseStream) -> syn::Result<()> {
+        let pair = input.parse::<MetaNameValue>()?;
+
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) = &pair.value
+        {
+            let path: Path = syn::parse_str(&lit.value())?;
+            if path.leading_colon.is_some() {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "`alias` should not have leading-colon.",
+                ));
+            }
+            self.aliases.push(lit.clone());
+        } else {
+            return Err(syn::Error::new(
+                pair.value.span(),
+                "Expected a string liternal value.",
+            ));
+        }
+
+        Ok(())
+    }
+
+    // #[reflect(from = "...")]
+    // #[reflect(readable_from = "...")]
+    // #[reflect(binary_from = "...")]
+    fn parse_proxy(&mut self, input: ParseStream, format: ProxyFormat) -> syn::Result<()> {
+        let pair = input.parse::<MetaNameValue>()?;
+
+        if self.proxy.is_some() {
+            return Err(syn::Error::new(
+                pair.span(),
+                "Only one proxy type is supported, use a processor for more formats.",
+            ));
+        }
+
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) = &pair.value
+        {
+            self.proxy = Some(lit.parse::<Type>()?);
+            self.proxy_format = format;
+        } else {
+            return Err(syn::Error::new(
+                pair.value.span(),
+                "Expected a string liternal value.",
+            ));
+        }
+
+        Ok(())
+    }
+
     fn parse_trait_type_path(&mut self, input: ParseStream) -> syn::Result<()> {
         // #[reflect(TypePath = false)]
         let pair = input.parse::<MetaNameValue>()?;
@@ -539,3 +729,22 @@ impl TypeAttributes {
         Ok(())
     }
 }
+
+/// Same format as `TypeUuid::parse` in `vc_reflect`.
+fn parse_uuid_str(text: &str) -> Option<u128> {
+    let digits: String = match text.len() {
+        36 => {
+            let bytes = text.as_bytes();
+            if [8, 13, 18, 23].iter().any(|&i| bytes[i] != b'-') {
+                return None;
+            }
+            text.split('-').collect()
+        }
+        32 => text.into(),
+        _ => return None,
+    };
+    if digits.len() != 32 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    u128::from_str_radix(&digits, 16).ok()
+}
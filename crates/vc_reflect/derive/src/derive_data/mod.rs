@@ -14,7 +14,7 @@ mod reflect_type_parser;
 // -----------------------------------------------------------------------------
 // Internal API
 
-pub(crate) use attributes::{FieldAttributes, TypeAttributes};
+pub(crate) use attributes::{FieldAttributes, ProxyFormat, TypeAttributes};
 
 pub(crate) use define_parser::{ReflectOpaqueParser, ReflectTypePathParser};
 pub(crate) use reflect_type_parser::TypeParser;
@@ -33,6 +33,37 @@ impl<'a> ReflectDerive<'a> {
             ));
         }
 
+        // Aliases are plain paths, the generic arguments of the current path
+        // are not appended to them.
+        if let Some(alias) = type_attributes.aliases.first()
+            && input
+                .generics
+                .params
+                .iter()
+                .any(|param| !matches!(param, syn::GenericParam::Lifetime(_)))
+        {
+            return Err(syn::Error::new(
+                alias.span(),
+                "#[reflect(alias = \"...\")] is not supported for generic types.",
+            ));
+        }
+
+        // The generated tests need a concrete type, reachable from a child module.
+        if let Some(span) = type_attributes.derive_tests {
+            if source == ImplSourceKind::ImplForeignType {
+                return Err(syn::Error::new(
+                    span,
+                    "#[reflect(derive_tests)] is only supported by `#[derive(Reflect)]`.",
+                ));
+            }
+            if !input.generics.params.is_empty() {
+                return Err(syn::Error::new(
+                    span,
+                    "#[reflect(derive_tests)] is not supported for generic types.",
+                ));
+            }
+        }
+
         // After meeting the above conditions, they can all be considered as local types.
         //
         // There are other algorithms for Foreign TypePath and Primitive TypePath.
@@ -50,10 +81,22 @@ impl<'a> ReflectDerive<'a> {
 
         match &input.data {
             syn::Data::Struct(data_struct) => {
-                let fields = Self::colloct_struct_field(&data_struct.fields)?;
+                let fields = Self::colloct_struct_field(&data_struct.fields, 0)?;
+                Self::check_field_accessors(&meta, &fields)?;
+                // Foreign types only declare their fields, which may be private.
+                let field_offsets =
+                    source == ImplSourceKind::DeriveLocalType && !is_packed(&input.attrs);
                 match data_struct.fields {
-                    Fields::Named(..) => Ok(Self::Struct(ReflectStruct::new(meta, fields))),
-                    Fields::Unnamed(..) => Ok(Self::TupleStruct(ReflectStruct::new(meta, fields))),
+                    Fields::Named(..) => Ok(Self::Struct(ReflectStruct::new(
+                        meta,
+                        fields,
+                        field_offsets,
+                    ))),
+                    Fields::Unnamed(..) => Ok(Self::TupleStruct(ReflectStruct::new(
+                        meta,
+                        fields,
+                        field_offsets,
+                    ))),
                     Fields::Unit => Ok(Self::UnitStruct(meta)),
                 }
             }
@@ -69,7 +112,19 @@ impl<'a> ReflectDerive<'a> {
         }
     }
 
-    fn colloct_struct_field(fields: &'a Fields) -> syn::Result<Vec<StructField<'a>>> {
+    pub fn meta(&self) -> &ReflectMeta<'a> {
+        match self {
+            Self::Struct(info) | Self::TupleStruct(info) => info.meta(),
+            Self::Enum(info) => info.meta(),
+            Self::UnitStruct(meta) | Self::Opaque(meta) => meta,
+        }
+    }
+
+    /// `offset` is the number of fields in the previous enum variants.
+    fn colloct_struct_field(
+        fields: &'a Fields,
+        offset: usize,
+    ) -> syn::Result<Vec<StructField<'a>>> {
         if fields.len() > u16::MAX as usize {
             return Err(syn::Error::new(
                 Span::call_site(),
@@ -81,17 +136,46 @@ impl<'a> ReflectDerive<'a> {
 
         for (field_index, field) in fields.iter().enumerate() {
             let attrs = FieldAttributes::parse_attrs(&field.attrs)?;
+            attrs.validity()?;
 
             res.push(StructField {
                 data: field,
                 attrs,
                 field_index,
+                type_index: offset + field_index,
             });
         }
 
         Ok(res)
     }
 
+    /// Fields with `get`, `get_mut` or `set` cannot be named in struct
+    /// expressions, so the value is constructed from `Default` instead.
+    fn check_field_accessors(meta: &ReflectMeta, fields: &[StructField]) -> syn::Result<()> {
+        for field in fields.iter().filter(|field| field.attrs.has_accessors()) {
+            let span = field.data.span();
+            if field.attrs.get.is_none() {
+                return Err(syn::Error::new(
+                    span,
+                    "#[reflect(get = ...)] must be specified when using `get_mut` or `set`.",
+                ));
+            }
+            if field.is_boxed_reflect() {
+                return Err(syn::Error::new(
+                    span,
+                    "field accessors are not supported for `Box<dyn Reflect>` fields.",
+                ));
+            }
+            if meta.attrs().avail_traits.default.is_none() {
+                return Err(syn::Error::new(
+                    span,
+                    "#[reflect(default)] must be specified when using field accessors.",
+                ));
+            }
+        }
+        Ok(())
+    }
+
     fn collect_enum_variants(
         variants: &'a Punctuated<Variant, Comma>,
     ) -> syn::Result<Vec<EnumVariant<'a>>> {
@@ -111,8 +195,16 @@ impl<'a> ReflectDerive<'a> {
 
         let mut res: Vec<EnumVariant<'a>> = Vec::with_capacity(variants.len());
 
+        let mut offset = 0;
         for variant in variants.iter() {
-            let fields = Self::colloct_struct_field(&variant.fields)?;
+            let fields = Self::colloct_struct_field(&variant.fields, offset)?;
+            offset += fields.len();
+            if let Some(field) = fields.iter().find(|field| field.attrs.has_accessors()) {
+                return Err(syn::Error::new(
+                    field.data.span(),
+                    "field accessors are not supported in enum variants.",
+                ));
+            }
             let variant_fields = match variant.fields {
                 Fields::Named(..) => EnumVariantFields::Named(fields),
                 Fields::Unnamed(..) => EnumVariantFields::Unnamed(fields),
@@ -130,3 +222,25 @@ impl<'a> ReflectDerive<'a> {
         Ok(res)
     }
 }
+
+/// Whether the type has a `#[repr(packed)]` or `#[repr(packed(N))]` attribute.
+///
+/// Fields of packed types may be unaligned, so references to them cannot be
+/// created from their offsets.
+fn is_packed(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+        .any(|attr| {
+            let mut packed = false;
+            let _ = attr.parse_nested_meta(|meta| {
+                packed |= meta.path.is_ident("packed");
+                if meta.input.peek(syn::token::Paren) {
+                    let _content;
+                    syn::parenthesized!(_content in meta.input);
+                }
+                Ok(())
+            });
+            packed
+        })
+}
@@ -63,7 +63,8 @@ impl<'a> EnumVariant<'a> {
 
         let fields = self
             .active_fields()
-            .map(|field| field.to_info_tokens(vc_reflect_path));
+            // The layout of enum variants is not stable, no offsets are stored.
+            .map(|field| field.to_info_tokens(vc_reflect_path, false));
 
         let variant_name = &self.data.ident.to_string();
         let args = match &self.fields {
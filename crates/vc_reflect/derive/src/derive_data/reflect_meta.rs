@@ -268,6 +268,11 @@ impl<'a> ReflectMeta<'a> {
     ///     - `Typed + Reflect`: all implementations except for `TypePath` trait.
     ///     - `FromReflect`: only `FromReflect`
     ///     - `GetTypeMeta`: `GetTypeMeta`, and `FromReflect` if `FromReflect` is enabled.
+    ///     - None for recursive field types, like `Vec<Self>` in `Tree<T>`,
+    ///       the others then always require `FromReflect` if it is enabled.
+    ///     - None with `#[reflect(no_field_bounds)]`, the type params then
+    ///       require `Send + Sync` instead.
+    /// - Custom predicates of `#[reflect(where ...)]`: all implementations.
     ///
     /// Therefore, we need three function parameters to control them.
     ///
@@ -316,12 +321,27 @@ impl<'a> ReflectMeta<'a> {
         }
 
         if add_reflect_typed {
-            let p = self.field_type_predicates(add_get_type_meta, add_from_reflect);
-            if let Some(p) = p {
-                predicates.extend(p);
+            if self.attrs().no_field_bounds.is_some() {
+                // The fields still require `Self: Send + Sync`, e.g. `PhantomData<T>`.
+                predicates.extend(self.send_sync_predicates());
+            } else {
+                let p = self.field_type_predicates(add_get_type_meta, add_from_reflect);
+                if let Some(p) = p {
+                    predicates.extend(p);
+                }
             }
         }
 
+        // `#[reflect(where ...)]` is added to the other bounds.
+        if let Some(custom_where) = &self.attrs().custom_where {
+            predicates.extend(
+                custom_where
+                    .predicates
+                    .iter()
+                    .map(ToTokens::to_token_stream),
+            );
+        }
+
         generic_where_clause.extend(quote! { #predicates });
         let where_clause = if generic_where_clause.is_empty() {
             crate::utils::empty()
@@ -342,6 +362,15 @@ impl<'a> ReflectMeta<'a> {
         })
     }
 
+    fn send_sync_predicates(&self) -> impl Iterator<Item = TokenStream> + '_ {
+        use crate::path::fp::{SendFP, SyncFP};
+
+        self.generics().type_params().map(move |param| {
+            let ident = &param.ident;
+            quote!(#ident : #SendFP + #SyncFP)
+        })
+    }
+
     fn field_type_predicates(
         &self,
         add_get_type_meta: bool,
@@ -372,26 +401,17 @@ impl<'a> ReflectMeta<'a> {
             crate::utils::empty()
         };
 
-        let add_from_reflect_ = if add_from_reflect {
-            let from_reflect_ = crate::path::from_reflect_(vc_reflect_path);
-            quote!( + #from_reflect_ )
-        } else {
-            crate::utils::empty()
-        };
-
         // Do any of the identifiers in `idents` appear in `token_stream`?
         fn is_any_ident_in_token_stream(idents: &[syn::Ident], token_stream: TokenStream) -> bool {
             for token_tree in token_stream {
                 match token_tree {
-                    proc_macro2::TokenTree::Ident(ident) => {
-                        if idents.contains(&ident) {
-                            return true;
-                        }
+                    proc_macro2::TokenTree::Ident(ident) if idents.contains(&ident) => {
+                        return true;
                     }
-                    proc_macro2::TokenTree::Group(group) => {
-                        if is_any_ident_in_token_stream(idents, group.stream()) {
-                            return true;
-                        }
+                    proc_macro2::TokenTree::Group(group)
+                        if is_any_ident_in_token_stream(idents, group.stream()) =>
+                    {
+                        return true;
                     }
                     _ => {}
                 }
@@ -399,8 +419,34 @@ impl<'a> ReflectMeta<'a> {
             false
         }
 
+        // Field types referring to the type itself, e.g. `Vec<Self>`, are skipped,
+        // their bounds depend on the implementation being generated, which makes
+        // the trait solver overflow. Containers usually require `FromReflect` of
+        // their items, so the other field types must always provide it instead.
+        let self_idents = [
+            self.type_parser.self_ident().clone(),
+            syn::Ident::new("Self", proc_macro2::Span::call_site()),
+        ];
+        let is_recursive = self
+            .active_types
+            .iter()
+            .any(|ty| is_any_ident_in_token_stream(&self_idents, ty.to_token_stream()));
+
+        let add_from_reflect =
+            add_from_reflect || (is_recursive && self.attrs().impl_switchs.impl_from_reflect);
+
+        let add_from_reflect_ = if add_from_reflect {
+            let from_reflect_ = crate::path::from_reflect_(vc_reflect_path);
+            quote!( + #from_reflect_ )
+        } else {
+            crate::utils::empty()
+        };
+
         Some(self.active_types.iter().filter_map(move |ty| {
-            if is_any_ident_in_token_stream(&type_param_idents, ty.to_token_stream()) {
+            let tokens = ty.to_token_stream();
+            if is_any_ident_in_token_stream(&type_param_idents, tokens.clone())
+                && !is_any_ident_in_token_stream(&self_idents, tokens)
+            {
                 Some(quote! {
                     #ty: #reflect_ + #typed_ #add_from_reflect_ # get_type_meta_
                 })
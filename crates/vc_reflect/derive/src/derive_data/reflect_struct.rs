@@ -10,12 +10,16 @@ use super::{FieldAttributes, ReflectMeta};
 pub(crate) struct ReflectStruct<'a> {
     meta: ReflectMeta<'a>,
     fields: Vec<StructField<'a>>,
+    /// Whether the field infos carry byte offsets, see [`StructField::to_info_tokens`].
+    field_offsets: bool,
 }
 
 pub(crate) struct StructField<'a> {
     pub data: &'a Field,
     pub attrs: FieldAttributes,
     pub field_index: usize,
+    /// The index among all fields of the type, unique across enum variants.
+    pub type_index: usize,
 }
 
 // -----------------------------------------------------------------------------
@@ -25,7 +29,14 @@ impl<'a> StructField<'a> {
     /// Generates a `TokenStream` for `NamedField` or `UnnamedField` construction.
     ///
     /// This function is only allowed to be called for active fields(self.reflection_index is some).
-    pub fn to_info_tokens(&self, vc_reflect_path: &syn::Path) -> proc_macro2::TokenStream {
+    ///
+    /// If `with_offset` is set, the byte offset of the field in `Self` is stored,
+    /// unless the field is accessed through accessors or is a `Box<dyn Reflect>`.
+    pub fn to_info_tokens(
+        &self,
+        vc_reflect_path: &syn::Path,
+        with_offset: bool,
+    ) -> proc_macro2::TokenStream {
         let field_info = if self.data.ident.is_some() {
             crate::path::named_field_(vc_reflect_path) // String Literal
         } else {
@@ -37,7 +48,7 @@ impl<'a> StructField<'a> {
             None => self.field_index.to_token_stream(),
         };
 
-        let ty = &self.data.ty;
+        let ty = self.to_reflect_type(vc_reflect_path);
 
         // See [`CustomAttributes::get_expression_with`]
         let with_custom_attributes = self
@@ -54,14 +65,85 @@ impl<'a> StructField<'a> {
             crate::utils::empty()
         };
 
+        // See `impl_serde_with`, the traits are implemented for `Self`.
+        let type_index = self.type_index;
+        let with_serialize_with = if self.attrs.serialize_with.is_some() {
+            let type_trait_serialize_ = crate::path::type_trait_serialize_(vc_reflect_path);
+            quote! { .with_serialize_with(#type_trait_serialize_::with::<Self, #type_index>()) }
+        } else {
+            crate::utils::empty()
+        };
+        let with_deserialize_with = if self.attrs.deserialize_with.is_some() {
+            let type_trait_deserialize_ = crate::path::type_trait_deserialize_(vc_reflect_path);
+            quote! { .with_deserialize_with(#type_trait_deserialize_::with::<Self, #type_index>()) }
+        } else {
+            crate::utils::empty()
+        };
+
+        let mut field = quote! { #field_info::new::<#ty>(#name) };
+        if with_offset && !self.attrs.has_accessors() && !self.is_boxed_reflect() {
+            let member = self.to_member();
+            // SAFETY: `Self` stores the field of type `#ty` at this offset.
+            field = quote! {
+                unsafe { #field.with_offset(::core::mem::offset_of!(Self, #member)) }
+            };
+        }
+
         quote! {
-            #field_info::new::<#ty>(#name)
+            #field
                 #with_skip_serde
+                #with_serialize_with
+                #with_deserialize_with
                 #with_custom_attributes
                 #with_docs
         }
     }
 
+    /// Whether the field is a `Box<dyn Reflect>`.
+    ///
+    /// `Box<dyn Reflect>` does not implement `Reflect`, such fields are
+    /// reflected as the `dyn Reflect` inside.
+    pub fn is_boxed_reflect(&self) -> bool {
+        let syn::Type::Path(ty) = &self.data.ty else {
+            return false;
+        };
+        let Some(last) = ty.path.segments.last() else {
+            return false;
+        };
+        if ty.qself.is_some() || last.ident != "Box" {
+            return false;
+        }
+        let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+            return false;
+        };
+        let [syn::GenericArgument::Type(syn::Type::TraitObject(object))] =
+            args.args.iter().collect::<Vec<_>>()[..]
+        else {
+            return false;
+        };
+
+        let mut traits = object.bounds.iter().filter_map(|bound| match bound {
+            syn::TypeParamBound::Trait(bound) => bound.path.segments.last(),
+            _ => None,
+        });
+        matches!(
+            (traits.next(), traits.next()),
+            (Some(segment), None) if segment.ident == "Reflect"
+        )
+    }
+
+    /// Returns the type exposed to the reflection API.
+    ///
+    /// This is the field type, or `dyn Reflect` for `Box<dyn Reflect>`.
+    pub fn to_reflect_type(&self, vc_reflect_path: &syn::Path) -> proc_macro2::TokenStream {
+        if self.is_boxed_reflect() {
+            let reflect_ = crate::path::reflect_(vc_reflect_path);
+            quote!(dyn #reflect_)
+        } else {
+            self.data.ty.to_token_stream()
+        }
+    }
+
     /// Generates a [`syn::Member`] based on this field.
     ///
     /// If the field is unnamed, the declaration index is used.
@@ -73,6 +155,23 @@ impl<'a> StructField<'a> {
         }
     }
 
+    /// Generates the statement writing `value` through the `set` or `get_mut`
+    /// accessor of the field, `this` is an expression of `&mut Self`.
+    ///
+    /// Returns `None` if the field has no such accessor.
+    pub fn to_setter_tokens(
+        &self,
+        this: &proc_macro2::TokenStream,
+        value: &proc_macro2::TokenStream,
+    ) -> Option<proc_macro2::TokenStream> {
+        if let Some(set) = &self.attrs.set {
+            Some(quote!(#set(#this, #value);))
+        } else {
+            let get_mut = self.attrs.get_mut.as_ref()?;
+            Some(quote!(*#get_mut(#this) = #value;))
+        }
+    }
+
     /// Get the field name for the `field` function of `Struct/TupleStruct`.
     ///
     /// - Named fields return values similar to `"name"`.
@@ -89,8 +188,14 @@ impl<'a> StructField<'a> {
 // Struct Implementation
 
 impl<'a> ReflectStruct<'a> {
-    pub fn new(meta: ReflectMeta<'a>, fields: Vec<StructField<'a>>) -> Self {
-        let mut val = Self { meta, fields };
+    /// `field_offsets` must only be set if the fields are the actual fields
+    /// of the type and are aligned, i.e. the type is local and not packed.
+    pub fn new(meta: ReflectMeta<'a>, fields: Vec<StructField<'a>>, field_offsets: bool) -> Self {
+        let mut val = Self {
+            meta,
+            fields,
+            field_offsets,
+        };
 
         let active_types = val.active_fields().map(|f| f.data.ty.clone()).collect();
 
@@ -134,7 +239,7 @@ impl<'a> ReflectStruct<'a> {
 
         let field_infos = self
             .active_fields()
-            .map(|field| field.to_info_tokens(vc_reflect_path));
+            .map(|field| field.to_info_tokens(vc_reflect_path, self.field_offsets));
 
         // See [`CustomAttributes::get_expression_with`]
         let with_custom_attributes = self.meta.with_custom_attributes_expression();
@@ -162,7 +267,9 @@ impl<'a> ReflectStruct<'a> {
 pub(crate) struct FieldAccessors {
     /// The referenced field accessors, such as `&self.foo`.
     pub fields_ref: Vec<proc_macro2::TokenStream>,
-    /// The mutably referenced field accessors, such as `&mut self.foo`.
+    /// The optional mutably referenced field accessors, such as `Some(&mut self.foo)`.
+    ///
+    /// This is `None` for fields with a `get` but no `get_mut` accessor.
     pub fields_mut: Vec<proc_macro2::TokenStream>,
     /// The ordered set of field indices (basically just the range of [0, `field_count`).
     pub field_indices: Vec<usize>,
@@ -172,11 +279,30 @@ pub(crate) struct FieldAccessors {
 
 impl FieldAccessors {
     pub fn new(info: &ReflectStruct) -> Self {
+        use crate::path::fp::OptionFP;
+
         let (fields_ref, fields_mut): (Vec<_>, Vec<_>) = info
             .active_fields()
             .map(|field| {
                 let member = field.to_member();
-                (quote!(&self.#member), quote!(&mut self.#member))
+                let attrs = &field.attrs;
+                if let Some(get) = &attrs.get {
+                    let field_mut = match &attrs.get_mut {
+                        Some(get_mut) => quote!(#OptionFP::Some(#get_mut(self))),
+                        None => quote!(#OptionFP::None),
+                    };
+                    (quote!(#get(self)), field_mut)
+                } else if field.is_boxed_reflect() {
+                    (
+                        quote!(&*self.#member),
+                        quote!(#OptionFP::Some(&mut *self.#member)),
+                    )
+                } else {
+                    (
+                        quote!(&self.#member),
+                        quote!(#OptionFP::Some(&mut self.#member)),
+                    )
+                }
             })
             .unzip();
 
@@ -96,6 +96,14 @@ impl<'a> TypeParser<'a> {
         }
     }
 
+    /// The ident of the type itself, as written in its own fields.
+    pub(super) fn self_ident(&self) -> &Ident {
+        match self {
+            Self::Local { ident, .. } | Self::Primitive(ident) => ident,
+            Self::Foreign { path, .. } => &path.segments.last().unwrap().ident,
+        }
+    }
+
     /// Get (custom) ident
     fn get_ident(&self) -> &Ident {
         match self {
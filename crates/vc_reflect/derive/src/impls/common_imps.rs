@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::derive_data::ReflectMeta;
+use crate::derive_data::{ReflectMeta, ReflectStruct};
 
 /// Try `clone` or `reflect_clone` for `Reflect::apply`
 pub(crate) fn get_common_apply_tokens(meta: &ReflectMeta, input: &syn::Ident) -> TokenStream {
@@ -30,7 +30,8 @@ pub(crate) fn get_common_apply_tokens(meta: &ReflectMeta, input: &syn::Ident) ->
     }
 }
 
-/// Try `clone` or `reflect_clone` for `FromReflect::from_reflect`
+/// Try `clone` or `reflect_clone` for `FromReflect::from_reflect`,
+/// then the conversion from the proxy type if there is one.
 pub(crate) fn get_common_from_reflect_tokens(
     meta: &ReflectMeta,
     input: &syn::Ident,
@@ -40,7 +41,7 @@ pub(crate) fn get_common_from_reflect_tokens(
     let vc_reflect_path = meta.vc_reflect_path();
     let reflect_ = crate::path::reflect_(vc_reflect_path);
 
-    if meta.attrs().avail_traits.clone.is_some() {
+    let clone_tokens = if meta.attrs().avail_traits.clone.is_some() {
         quote! {
             if let #OptionFP::Some(__val__) = <dyn #reflect_>::downcast_ref::<Self>(#input) {
                 return #OptionFP::Some(#CloneFP::clone(__val__));
@@ -55,5 +56,78 @@ pub(crate) fn get_common_from_reflect_tokens(
                 return #OptionFP::Some(__val__);
             }
         }
+    };
+
+    let proxy_tokens = match &meta.attrs().proxy {
+        Some(proxy) => {
+            let from_reflect_ = crate::path::from_reflect_(vc_reflect_path);
+            quote! {
+                if <dyn #reflect_>::represents::<#proxy>(#input)
+                    && let #OptionFP::Some(__proxy__) = <#proxy as #from_reflect_>::from_reflect(#input)
+                {
+                    return #OptionFP::Some(<#proxy as ::core::convert::Into<Self>>::into(__proxy__));
+                }
+            }
+        }
+        None => crate::utils::empty(),
+    };
+
+    quote! {
+        #clone_tokens
+        #proxy_tokens
+    }
+}
+
+/// Apply the input to fields which have a `set` but no `get_mut` accessor,
+/// by applying to a clone of the field and writing it back.
+///
+/// `Struct::field_mut` returns `None` for these fields, so they are skipped
+/// by `struct_apply` and `tuple_struct_apply`.
+pub(crate) fn get_setter_apply_tokens(
+    info: &ReflectStruct,
+    is_tuple: bool,
+    input: &syn::Ident,
+) -> TokenStream {
+    use crate::path::fp::OptionFP;
+
+    let vc_reflect_path = info.meta().vc_reflect_path();
+    let reflect_ = crate::path::reflect_(vc_reflect_path);
+    let reflect_ref_ = crate::path::reflect_ref_(vc_reflect_path);
+    let macro_utils_ = crate::path::macro_utils_(vc_reflect_path);
+
+    let (struct_trait_path_, struct_kind_) = if is_tuple {
+        (
+            crate::path::tuple_struct_(vc_reflect_path),
+            quote!(TupleStruct),
+        )
+    } else {
+        (crate::path::struct_(vc_reflect_path), quote!(Struct))
+    };
+
+    let fields = info
+        .active_fields()
+        .filter(|field| field.attrs.get_mut.is_none())
+        .filter_map(|field| {
+            let get = field.attrs.get.as_ref()?;
+            let set = field.attrs.set.as_ref()?;
+            let field_ty = &field.data.ty;
+            let accessor = field.reflect_accessor();
+            Some(quote! {
+                if let #OptionFP::Some(__field__) = #struct_trait_path_::field(__struct__, #accessor) {
+                    let __value__ = #macro_utils_::__apply_field_copy::<#field_ty>(#get(self), __field__)?;
+                    #set(self, __value__);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if fields.is_empty() {
+        return crate::utils::empty();
+    }
+
+    quote! {
+        if let #reflect_ref_::#struct_kind_(__struct__) = #reflect_::reflect_ref(#input) {
+            #(#fields)*
+        }
     }
 }
@@ -0,0 +1,33 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::derive_data::ReflectMeta;
+
+/// Generate the tests of `#[reflect(derive_tests)]`.
+///
+/// The module is emitted next to the type, because tests inside
+/// the `const _: () = { ... }` block cannot be collected.
+pub(crate) fn impl_derive_tests(meta: &ReflectMeta, ident: &syn::Ident) -> TokenStream {
+    let Some(span) = meta.attrs().derive_tests else {
+        return crate::utils::empty();
+    };
+
+    let vc_reflect_path = meta.vc_reflect_path();
+    let assert_reflect_invariants_ = crate::path::assert_reflect_invariants_(vc_reflect_path, span);
+
+    let mod_ident = format_ident!(
+        "__reflect_tests_{}",
+        ident.to_string().to_lowercase(),
+        span = ident.span()
+    );
+
+    quote! {
+        #[cfg(test)]
+        mod #mod_ident {
+            #[test]
+            fn reflect_invariants() {
+                #assert_reflect_invariants_::<super::#ident>();
+            }
+        }
+    }
+}
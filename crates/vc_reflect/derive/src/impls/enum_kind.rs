@@ -2,6 +2,7 @@ use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::Ident;
 
+use super::impl_serde_with;
 use super::{get_auto_register_impl, get_common_apply_tokens};
 use super::{get_common_from_reflect_tokens, impl_trait_get_type_meta};
 use super::{impl_trait_reflect, impl_trait_type_path, impl_trait_typed};
@@ -73,12 +74,17 @@ pub(crate) fn impl_enum(info: &ReflectEnum) -> TokenStream {
         crate::utils::empty()
     };
 
+    // trait: SerializeWith, DeserializeWith
+    let serde_with_tokens = impl_serde_with(meta, info.active_fields());
+
     // featuer: auto_resiter
     let auto_register_tokens = get_auto_register_impl(meta);
 
     quote! {
         #auto_register_tokens
 
+        #serde_with_tokens
+
         #type_path_trait_tokens
 
         #typed_trait_tokens
@@ -534,7 +540,7 @@ fn get_enum_debug_impl(meta: &ReflectMeta) -> TokenStream {
     }
 }
 
-/// Generate partial `GetTypeMeta` implementation tokens.
+/// Generate the registrations of `GetTypeMeta::register_dependencies`.
 fn get_registry_dependencies(info: &ReflectEnum) -> TokenStream {
     let vc_reflect_path = info.meta().vc_reflect_path();
     let type_registry_ = crate::path::type_registry_(vc_reflect_path);
@@ -542,9 +548,7 @@ fn get_registry_dependencies(info: &ReflectEnum) -> TokenStream {
     let field_types = info.active_fields().map(|x| &x.data.ty);
 
     quote! {
-        fn register_dependencies(__registry__: &mut #type_registry_) {
-            #(#type_registry_::register::<#field_types>(__registry__);)*
-        }
+        #(#type_registry_::register::<#field_types>(__registry__);)*
     }
 }
 
@@ -12,6 +12,8 @@ pub(crate) fn match_reflect_impls(ast: DeriveInput, source: ImplSourceKind) -> T
         Err(err) => return err.into_compile_error().into(),
     };
 
+    let derive_tests = crate::impls::impl_derive_tests(reflect_derive.meta(), &ast.ident);
+
     let reflect_impls: proc_macro2::TokenStream = match reflect_derive {
         ReflectDerive::Struct(info) => crate::impls::impl_struct(&info),
         ReflectDerive::TupleStruct(info) => crate::impls::impl_tuple_struct(&info),
@@ -24,5 +26,7 @@ pub(crate) fn match_reflect_impls(ast: DeriveInput, source: ImplSourceKind) -> T
         const _: () = {
             #reflect_impls
         };
+
+        #derive_tests
     })
 }
@@ -11,7 +11,9 @@ mod unit_kind;
 
 mod auto_register;
 mod common_imps;
+mod derive_tests;
 mod reflect_trait;
+mod serde_with;
 mod struct_clone;
 mod struct_from_reflect;
 mod trait_get_type_meta;
@@ -27,7 +29,10 @@ pub(crate) use match_reflect::match_reflect_impls;
 use auto_register::get_auto_register_impl;
 use common_imps::get_common_apply_tokens;
 use common_imps::get_common_from_reflect_tokens;
+use common_imps::get_setter_apply_tokens;
+use derive_tests::impl_derive_tests;
 use enum_kind::impl_enum;
+use serde_with::impl_serde_with;
 use struct_clone::get_struct_clone_impl;
 use struct_from_reflect::impl_struct_from_reflect;
 use struct_kind::impl_struct;
@@ -2,6 +2,7 @@ use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::Ident;
 
+use super::impl_serde_with;
 use super::{get_auto_register_impl, impl_trait_get_type_meta};
 use super::{get_common_from_reflect_tokens, impl_trait_typed};
 use super::{impl_trait_reflect, impl_trait_type_path};
@@ -65,12 +66,17 @@ pub(crate) fn impl_opaque(meta: &ReflectMeta) -> TokenStream {
         crate::utils::empty()
     };
 
+    // trait: SerializeWith, DeserializeWith
+    let serde_with_tokens = impl_serde_with(meta, core::iter::empty());
+
     // featuer: auto_resiter
     let auto_register_tokens = get_auto_register_impl(meta);
 
     quote! {
         #auto_register_tokens
 
+        #serde_with_tokens
+
         #type_path_trait_tokens
 
         #typed_trait_tokens
@@ -1,7 +1,7 @@
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
-use syn::{Attribute, ItemTrait, Token, parse::Parse, parse_macro_input};
+use syn::{Attribute, ItemTrait, Token, TypeParamBound, parse::Parse, parse_macro_input};
 
 struct TraitInfo {
     item_trait: ItemTrait,
@@ -21,14 +21,38 @@ impl Parse for TraitInfo {
     }
 }
 
+/// Whether `Reflect` is one of the supertraits.
+fn has_reflect_supertrait(item_trait: &ItemTrait) -> bool {
+    item_trait.supertraits.iter().any(|bound| match bound {
+        TypeParamBound::Trait(bound) => bound
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Reflect"),
+        _ => false,
+    })
+}
+
 /// A trait attribute macro that allows a reflected type to be downcast to a trait object.
 ///
 /// This generates a struct that takes the form `MyTraitFromReflect`. An instance of this struct can then be
 /// used to perform the conversion.
+///
+/// If the trait is not generic and has `Reflect` as a supertrait, `dyn MyTrait` also implements
+/// `DynReflect`, so that `Box<dyn MyTrait>` can be reflected.
 pub(crate) fn impl_reflect_trait(input: TokenStream) -> TokenStream {
     use crate::path::fp::{CloneFP, OptionFP, ResultFP};
 
-    let trait_info = parse_macro_input!(input as TraitInfo);
+    let mut trait_info = parse_macro_input!(input as TraitInfo);
+
+    let dyn_reflect_tokens = if has_reflect_supertrait(&trait_info.item_trait)
+        && trait_info.item_trait.generics.params.is_empty()
+    {
+        impl_dyn_reflect(&mut trait_info.item_trait)
+    } else {
+        proc_macro2::TokenStream::new()
+    };
+
     let item_trait = &trait_info.item_trait;
     let trait_vis = &item_trait.vis;
     let trait_ident = &item_trait.ident;
@@ -62,6 +86,8 @@ pub(crate) fn impl_reflect_trait(input: TokenStream) -> TokenStream {
     TokenStream::from(quote! {
         #item_trait
 
+        #dyn_reflect_tokens
+
         #[doc = #struct_doc]
         #[derive(#CloneFP)]
         #trait_vis struct #reflect_trait_ident {
@@ -129,3 +155,104 @@ pub(crate) fn impl_reflect_trait(input: TokenStream) -> TokenStream {
         }
     })
 }
+
+/// Generates `TypePath` and `DynReflect` for `dyn MyTrait`.
+///
+/// Cloning a `Box<dyn MyTrait>` needs the concrete type, so a hidden supertrait
+/// with a blanket implementation is added to the trait.
+fn impl_dyn_reflect(item_trait: &mut ItemTrait) -> proc_macro2::TokenStream {
+    use crate::path::fp::{OptionFP, ResultFP};
+
+    let trait_vis = &item_trait.vis;
+    let trait_ident = &item_trait.ident;
+    let reflect_trait_ident =
+        syn::Ident::new(&format!("{trait_ident}FromReflect"), Span::call_site());
+    let clone_trait_ident =
+        syn::Ident::new(&format!("__{trait_ident}CloneBoxed"), Span::call_site());
+
+    let vc_reflect_path = crate::path::vc_reflect();
+    let reflect_ = crate::path::reflect_(&vc_reflect_path);
+    let macro_utils_ = crate::path::macro_utils_(&vc_reflect_path);
+    let type_path_ = crate::path::type_path_(&vc_reflect_path);
+    let dyn_reflect_ = crate::path::dyn_reflect_(&vc_reflect_path);
+    let type_registry_ = crate::path::type_registry_(&vc_reflect_path);
+
+    let type_name = format!("dyn {trait_ident}");
+
+    let tokens = quote! {
+        #[doc(hidden)]
+        #trait_vis trait #clone_trait_ident {
+            fn __clone_boxed(&self) -> #OptionFP<#macro_utils_::Box<dyn #trait_ident>>;
+        }
+
+        impl<T: #trait_ident> #clone_trait_ident for T {
+            fn __clone_boxed(&self) -> #OptionFP<#macro_utils_::Box<dyn #trait_ident>> {
+                let value = #reflect_::reflect_clone(self).ok()?;
+                <dyn #reflect_>::downcast::<T>(value)
+                    .ok()
+                    .map(|value| value as #macro_utils_::Box<dyn #trait_ident>)
+            }
+        }
+
+        impl #type_path_ for dyn #trait_ident {
+            #[inline]
+            fn type_path() -> &'static str {
+                ::core::concat!("dyn ", ::core::module_path!(), "::", ::core::stringify!(#trait_ident))
+            }
+
+            #[inline]
+            fn type_name() -> &'static str {
+                #type_name
+            }
+
+            #[inline]
+            fn type_ident() -> &'static str {
+                #type_name
+            }
+
+            #[inline]
+            fn module_path() -> #OptionFP<&'static str> {
+                #OptionFP::Some( ::core::module_path!() )
+            }
+        }
+
+        impl #dyn_reflect_ for dyn #trait_ident {
+            #[inline]
+            fn upcast(&self) -> &dyn #reflect_ {
+                self
+            }
+
+            #[inline]
+            fn upcast_mut(&mut self) -> &mut dyn #reflect_ {
+                self
+            }
+
+            #[inline]
+            fn upcast_boxed(self: #macro_utils_::Box<Self>) -> #macro_utils_::Box<dyn #reflect_> {
+                self
+            }
+
+            #[inline]
+            fn clone_boxed(&self) -> #OptionFP<#macro_utils_::Box<Self>> {
+                #clone_trait_ident::__clone_boxed(self)
+            }
+
+            fn downcast_boxed(
+                value: #macro_utils_::Box<dyn #reflect_>,
+                registry: #OptionFP<&#type_registry_>,
+            ) -> #ResultFP<#macro_utils_::Box<Self>, #macro_utils_::Box<dyn #reflect_>> {
+                let type_id = <dyn #reflect_ as ::core::any::Any>::type_id(&*value);
+                match registry.and_then(|registry| registry.get_type_trait::<#reflect_trait_ident>(type_id)) {
+                    #OptionFP::Some(from_reflect) => from_reflect.from_boxed(value),
+                    #OptionFP::None => #ResultFP::Err(value),
+                }
+            }
+        }
+    };
+
+    item_trait
+        .supertraits
+        .push(syn::parse_quote!(#clone_trait_ident));
+
+    tokens
+}
@@ -0,0 +1,101 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+
+use crate::derive_data::{ReflectMeta, StructField};
+
+/// The `N` of `SerializeWith<N>` for `#[reflect(serialize_with = "...")]` on the type.
+const TYPE_INDEX: usize = usize::MAX;
+
+/// Generate `SerializeWith` and `DeserializeWith` implementations for the
+/// `serialize_with` and `deserialize_with` attributes of the type and of its fields.
+///
+/// The traits are implemented for the type itself, the field is identified by its
+/// [`StructField::type_index`], see `vc_reflect::serde::SerializeWith`.
+pub(crate) fn impl_serde_with<'a>(
+    meta: &ReflectMeta,
+    fields: impl Iterator<Item = &'a StructField<'a>>,
+) -> TokenStream {
+    let vc_reflect_path = meta.vc_reflect_path();
+
+    let mut tokens = TokenStream::new();
+
+    let attrs = meta.attrs();
+    if let Some(path) = &attrs.serialize_with {
+        tokens.extend(impl_serialize_with(meta, TYPE_INDEX, &quote!(Self), path));
+    }
+    if let Some(path) = &attrs.deserialize_with {
+        tokens.extend(impl_deserialize_with(meta, TYPE_INDEX, &quote!(Self), path));
+    }
+
+    for field in fields {
+        let ty = field.to_reflect_type(vc_reflect_path);
+        if let Some(path) = &field.attrs.serialize_with {
+            tokens.extend(impl_serialize_with(meta, field.type_index, &ty, path));
+        }
+        if let Some(path) = &field.attrs.deserialize_with {
+            tokens.extend(impl_deserialize_with(meta, field.type_index, &ty, path));
+        }
+    }
+
+    tokens
+}
+
+fn impl_serialize_with(
+    meta: &ReflectMeta,
+    index: usize,
+    ty: &TokenStream,
+    path: &Path,
+) -> TokenStream {
+    use crate::path::fp::ResultFP;
+
+    let vc_reflect_path = meta.vc_reflect_path();
+    let serialize_with_ = crate::path::serialize_with_(vc_reflect_path);
+    let macro_utils_ = crate::path::macro_utils_(vc_reflect_path);
+
+    let real_ident = meta.real_ident();
+    let (impl_generics, ty_generics, where_clause) = meta.split_generics(true, false, false);
+
+    quote! {
+        impl #impl_generics #serialize_with_<#index> for #real_ident #ty_generics #where_clause {
+            type Value = #ty;
+
+            #[inline]
+            fn serialize<S: #macro_utils_::Serializer>(
+                value: &Self::Value,
+                serializer: S,
+            ) -> #ResultFP<S::Ok, S::Error> {
+                #path(value, serializer)
+            }
+        }
+    }
+}
+
+fn impl_deserialize_with(
+    meta: &ReflectMeta,
+    index: usize,
+    ty: &TokenStream,
+    path: &Path,
+) -> TokenStream {
+    use crate::path::fp::ResultFP;
+
+    let vc_reflect_path = meta.vc_reflect_path();
+    let deserialize_with_ = crate::path::deserialize_with_(vc_reflect_path);
+    let macro_utils_ = crate::path::macro_utils_(vc_reflect_path);
+
+    let real_ident = meta.real_ident();
+    let (impl_generics, ty_generics, where_clause) = meta.split_generics(true, false, false);
+
+    quote! {
+        impl #impl_generics #deserialize_with_<#index> for #real_ident #ty_generics #where_clause {
+            type Value = #ty;
+
+            #[inline]
+            fn deserialize<'de, D: #macro_utils_::Deserializer<'de>>(
+                deserializer: D,
+            ) -> #ResultFP<Self::Value, D::Error> {
+                #path(deserializer)
+            }
+        }
+    }
+}
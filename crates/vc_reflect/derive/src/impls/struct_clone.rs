@@ -29,8 +29,23 @@ pub(crate) fn get_struct_clone_impl(info: &ReflectStruct) -> TokenStream {
             let field_ty = &field.data.ty;
             let member = field.to_member();
 
+            if let Some(get) = &field.attrs.get {
+                let value = quote!(#macro_utils_::__reflect_clone_field::<#field_ty>(#get(self))?);
+                // Fields that only have a getter keep the default value.
+                if let Some(setter) = field.to_setter_tokens(&quote!(&mut __new_value__), &value) {
+                    tokens.extend(setter);
+                }
+                continue;
+            }
+
+            let value = if field.is_boxed_reflect() {
+                quote!(#reflect_::reflect_clone(&*self.#member)?)
+            } else {
+                quote!(#macro_utils_::__reflect_clone_field::<#field_ty>(&self.#member)?)
+            };
+
             tokens.extend(quote! {
-                __new_value__.#member = #macro_utils_::__reflect_clone_field::<#field_ty>(&self.#member)?;
+                __new_value__.#member = #value;
             });
         }
 
@@ -50,8 +65,14 @@ pub(crate) fn get_struct_clone_impl(info: &ReflectStruct) -> TokenStream {
             let field_ty = &field.data.ty;
             let member = field.to_member();
 
+            let value = if field.is_boxed_reflect() {
+                quote!(#reflect_::reflect_clone(&*self.#member)?)
+            } else {
+                quote!(#macro_utils_::__reflect_clone_field::<#field_ty>(&self.#member)?)
+            };
+
             tokens.extend(quote! {
-                #member: #macro_utils_::__reflect_clone_field::<#field_ty>(&self.#member)?,
+                #member: #value,
             });
         }
 
@@ -20,6 +20,7 @@ pub(crate) fn impl_struct_from_reflect(
     let reflect_ = crate::path::reflect_(vc_reflect_path);
     let from_reflect_ = crate::path::from_reflect_(vc_reflect_path);
     let reflect_ref_ = crate::path::reflect_ref_(vc_reflect_path);
+    let macro_utils_ = crate::path::macro_utils_(vc_reflect_path);
 
     let struct_trait_path_ = if is_tuple {
         crate::path::tuple_struct_(vc_reflect_path)
@@ -43,9 +44,14 @@ pub(crate) fn impl_struct_from_reflect(
             let member = field.to_member();
             let field_ty = field.data.ty.clone();
             let accessor = field.reflect_accessor();
+            let from_field = if field.is_boxed_reflect() {
+                quote!(#macro_utils_::__from_reflect_boxed(__field))
+            } else {
+                quote!(<#field_ty as #from_reflect_>::from_reflect(__field))
+            };
             let value = quote! {
                 match #struct_trait_path_::field(#input_, #accessor) {
-                    #OptionFP::Some(__field) => <#field_ty as #from_reflect_>::from_reflect(__field),
+                    #OptionFP::Some(__field) => #from_field,
                     #OptionFP::None => #OptionFP::None,
                 }
             };
@@ -64,15 +70,31 @@ pub(crate) fn impl_struct_from_reflect(
             crate::utils::empty()
         };
 
+        let this = quote!(&mut __this);
+        let field_val = quote!(__field_val);
+        let assigns = info
+            .active_fields()
+            .zip(&active_values)
+            .filter_map(|(field, value)| {
+                let setter = if field.attrs.has_accessors() {
+                    // Fields that only have a getter keep the default value.
+                    field.to_setter_tokens(&this, &field_val)?
+                } else {
+                    let member = field.to_member();
+                    quote!(__this.#member = #field_val;)
+                };
+                Some(quote! {
+                    if let #option_::Some(#field_val) = #value {
+                        #setter
+                    }
+                })
+            });
+
         quote! {
             if let #reflect_ref_::#struct_kind_(#input_) = #reflect_::reflect_ref(#input_) {
                 let mut __this = <Self as #DefaultFP>::default();
                 #check_field_len
-                #(
-                    if let #option_::Some(__field_val) = #active_values {
-                        __this.#active_members = __field_val;
-                    }
-                )*
+                #(#assigns)*
                 return #OptionFP::Some(__this);
             }
         }
@@ -2,8 +2,9 @@ use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::Ident;
 
+use super::impl_serde_with;
 use super::{get_auto_register_impl, get_struct_clone_impl};
-use super::{get_common_apply_tokens, impl_trait_typed};
+use super::{get_common_apply_tokens, get_setter_apply_tokens, impl_trait_typed};
 use super::{impl_struct_from_reflect, impl_trait_reflect};
 use super::{impl_trait_get_type_meta, impl_trait_type_path};
 
@@ -36,7 +37,7 @@ pub(crate) fn impl_struct(info: &ReflectStruct) -> TokenStream {
 
     // trait: Reflect
     let reflect_trait_tokens = if meta.attrs().impl_switchs.impl_reflect {
-        let apply_tokens = get_struct_apply_impl(meta);
+        let apply_tokens = get_struct_apply_impl(info);
         let to_dynamic_tokens = get_struct_to_dynamic_impl(meta);
         let reflect_clone_tokens = get_struct_clone_impl(info);
         let reflect_eq_tokens = get_struct_eq_impl(meta);
@@ -74,12 +75,17 @@ pub(crate) fn impl_struct(info: &ReflectStruct) -> TokenStream {
         crate::utils::empty()
     };
 
+    // trait: SerializeWith, DeserializeWith
+    let serde_with_tokens = impl_serde_with(meta, info.active_fields());
+
     // featuer: auto_resiter
     let auto_register_tokens = get_auto_register_impl(meta);
 
     quote! {
         #auto_register_tokens
 
+        #serde_with_tokens
+
         #type_path_trait_tokens
 
         #typed_trait_tokens
@@ -139,7 +145,7 @@ fn impl_trait_struct(info: &ReflectStruct) -> TokenStream {
 
             fn field_mut(&mut self, __name__: &str) -> #OptionFP<&mut dyn #reflect_> {
                 match __name__ {
-                    #(#field_names => #option_::Some(#fields_mut),)*
+                    #(#field_names => #fields_mut,)*
                     _ => #OptionFP::None,
                 }
             }
@@ -153,7 +159,7 @@ fn impl_trait_struct(info: &ReflectStruct) -> TokenStream {
 
             fn field_at_mut(&mut self, __index__: usize) -> #OptionFP<&mut dyn #reflect_> {
                 match __index__ {
-                    #(#field_indices => #option_::Some(#fields_mut),)*
+                    #(#field_indices => #fields_mut,)*
                     _ => #OptionFP::None,
                 }
             }
@@ -187,8 +193,9 @@ fn impl_trait_struct(info: &ReflectStruct) -> TokenStream {
 }
 
 /// Generate `Reflect::apply` implementation tokens.
-fn get_struct_apply_impl(meta: &ReflectMeta) -> TokenStream {
+fn get_struct_apply_impl(info: &ReflectStruct) -> TokenStream {
     use crate::path::fp::ResultFP;
+    let meta = info.meta();
 
     let vc_reflect_path = meta.vc_reflect_path();
     let reflect_ = crate::path::reflect_(vc_reflect_path);
@@ -198,12 +205,15 @@ fn get_struct_apply_impl(meta: &ReflectMeta) -> TokenStream {
     let input_ = Ident::new("__input__", Span::call_site());
 
     let clone_tokens = get_common_apply_tokens(meta, &input_);
+    let setter_tokens = get_setter_apply_tokens(info, false, &input_);
 
     quote! {
         fn apply(&mut self, #input_: &dyn #reflect_) -> #ResultFP<(), #apply_error_> {
             #clone_tokens
 
-            #struct_apply_(self, #input_)
+            #struct_apply_(self, #input_)?;
+            #setter_tokens
+            #ResultFP::Ok(())
         }
     }
 }
@@ -333,16 +343,16 @@ fn get_struct_debug_impl(meta: &ReflectMeta) -> TokenStream {
     }
 }
 
-/// Generate partial `GetTypeMeta` implementation tokens.
+/// Generate the registrations of `GetTypeMeta::register_dependencies`.
 fn get_registry_dependencies(info: &ReflectStruct) -> TokenStream {
     let vc_reflect_path = info.meta().vc_reflect_path();
     let type_registry_ = crate::path::type_registry_(vc_reflect_path);
 
-    let field_types = info.active_fields().map(|x| &x.data.ty);
+    let field_types = info
+        .active_fields()
+        .map(|x| x.to_reflect_type(vc_reflect_path));
 
     quote! {
-        fn register_dependencies(__registry__: &mut #type_registry_) {
-            #(#type_registry_::register::<#field_types>(__registry__);)*
-        }
+        #(#type_registry_::register::<#field_types>(__registry__);)*
     }
 }
@@ -2,11 +2,13 @@ use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{Ident, spanned::Spanned};
 
-use crate::derive_data::ReflectMeta;
+use crate::derive_data::{ProxyFormat, ReflectMeta};
+use crate::path::fp::TypeIdFP;
 
 /// Generate implementation code for `GetTypeMeta` trait.
 ///
-/// `register_deps_tokens` is usually related to the type of field.
+/// `register_deps_tokens` are the registrations of `register_dependencies`,
+/// usually the types of fields, taking `__registry__` as the registry.
 ///
 /// For param `add_from_reflect`, See [`ReflectMeta::split_generics`]
 pub(crate) fn impl_trait_get_type_meta(
@@ -18,6 +20,7 @@ pub(crate) fn impl_trait_get_type_meta(
     let type_meta_ = crate::path::type_meta_(vc_reflect_path);
     let from_type_ = crate::path::from_type_(vc_reflect_path);
     let type_trait_from_ptr = crate::path::type_trait_from_ptr_(vc_reflect_path);
+    let type_registry_ = crate::path::type_registry_(vc_reflect_path);
 
     let outer_ = Ident::new("__ret__", Span::call_site());
 
@@ -64,6 +67,19 @@ pub(crate) fn impl_trait_get_type_meta(
         None => crate::utils::empty(),
     };
 
+    // `serialize` and `serialize_with` are exclusive, see `TypeAttributes::validity`.
+    let insert_serialize_with = match &meta.attrs().serialize_with {
+        Some(_) => {
+            trait_counter += 1;
+            let type_trait_serialize_ = crate::path::type_trait_serialize_(vc_reflect_path);
+
+            quote! {
+                #type_meta_::insert_trait::<#type_trait_serialize_>(&mut #outer_, #type_trait_serialize_::with::<Self, { usize::MAX }>());
+            }
+        }
+        None => crate::utils::empty(),
+    };
+
     let insert_deserialize = match meta.attrs().avail_traits.deserialize {
         Some(span) => {
             trait_counter += 1;
@@ -77,6 +93,90 @@ pub(crate) fn impl_trait_get_type_meta(
         None => crate::utils::empty(),
     };
 
+    let insert_deserialize_with = match &meta.attrs().deserialize_with {
+        Some(_) => {
+            trait_counter += 1;
+            let type_trait_deserialize_ = crate::path::type_trait_deserialize_(vc_reflect_path);
+
+            quote! {
+                #type_meta_::insert_trait::<#type_trait_deserialize_>(&mut #outer_, #type_trait_deserialize_::with::<Self, { usize::MAX }>());
+            }
+        }
+        None => crate::utils::empty(),
+    };
+
+    let insert_debug = match meta.attrs().avail_traits.debug {
+        Some(span) => {
+            trait_counter += 1;
+            let type_trait_debug_ = crate::path::type_trait_debug_(vc_reflect_path);
+            let from_type_fn = Ident::new("from_type", span);
+
+            quote! {
+                #type_meta_::insert_trait::<#type_trait_debug_>(&mut #outer_, #from_type_::<Self>::#from_type_fn());
+            }
+        }
+        None => crate::utils::empty(),
+    };
+
+    let insert_display = match meta.attrs().avail_traits.display {
+        Some(span) => {
+            trait_counter += 1;
+            let type_trait_display_ = crate::path::type_trait_display_(vc_reflect_path);
+            let from_type_fn = Ident::new("from_type", span);
+
+            quote! {
+                #type_meta_::insert_trait::<#type_trait_display_>(&mut #outer_, #from_type_::<Self>::#from_type_fn());
+            }
+        }
+        None => crate::utils::empty(),
+    };
+
+    let insert_hash = match meta.attrs().avail_traits.hash {
+        Some(span) => {
+            trait_counter += 1;
+            let type_trait_hash_ = crate::path::type_trait_hash_(vc_reflect_path);
+            let from_type_fn = Ident::new("from_type", span);
+
+            quote! {
+                #type_meta_::insert_trait::<#type_trait_hash_>(&mut #outer_, #from_type_::<Self>::#from_type_fn());
+            }
+        }
+        None => crate::utils::empty(),
+    };
+
+    // The proxy is registered as a dependency, the drivers look it up by type id.
+    let (insert_proxy, register_proxy) = match &meta.attrs().proxy {
+        Some(proxy) => {
+            trait_counter += 1;
+            let type_trait_proxy_ = crate::path::type_trait_proxy_(vc_reflect_path);
+            let proxy_format_ = crate::path::proxy_format_(vc_reflect_path);
+            let format = match meta.attrs().proxy_format {
+                ProxyFormat::Any => quote!(Any),
+                ProxyFormat::HumanReadable => quote!(HumanReadable),
+                ProxyFormat::Binary => quote!(Binary),
+            };
+
+            (
+                quote! {
+                    #type_meta_::insert_trait::<#type_trait_proxy_>(
+                        &mut #outer_,
+                        #type_trait_proxy_::new::<Self, #proxy>().with_format(#proxy_format_::#format),
+                    );
+                },
+                quote! {
+                    #type_registry_::register::<#proxy>(__registry__);
+                },
+            )
+        }
+        None => (crate::utils::empty(), crate::utils::empty()),
+    };
+
+    let register_aliases = meta.attrs().aliases.iter().map(|alias| {
+        quote! {
+            #type_registry_::register_alias(__registry__, #alias, #TypeIdFP::of::<Self>());
+        }
+    });
+
     trait_counter += meta.attrs().extra_type_trait.len();
 
     let insert_extra_traits = meta.attrs().extra_type_trait.iter().map(|extra_path| {
@@ -99,12 +199,22 @@ pub(crate) fn impl_trait_get_type_meta(
                 #insert_from_reflect
                 #insert_default
                 #insert_serialize
+                #insert_serialize_with
                 #insert_deserialize
+                #insert_deserialize_with
+                #insert_debug
+                #insert_display
+                #insert_hash
+                #insert_proxy
                 #(#insert_extra_traits)*
                 #outer_
             }
 
-            #register_deps_tokens
+            fn register_dependencies(__registry__: &mut #type_registry_) {
+                #register_deps_tokens
+                #register_proxy
+                #(#register_aliases)*
+            }
         }
     }
 }
@@ -28,39 +28,69 @@ fn wrap_in_option(tokens: Option<TokenStream>) -> TokenStream {
     }
 }
 
-/// Generate implementation codes for `TypePath`
+/// Generate `type_uuid` for `#[reflect(uuid = "...")]`, combined with generic parameters.
+fn impl_type_uuid(meta: &ReflectMeta) -> TokenStream {
+    let Some(uuid) = meta.attrs().uuid else {
+        return crate::utils::empty();
+    };
+
+    let vc_reflect_path = meta.vc_reflect_path();
+    let trait_type_path_ = crate::path::type_path_(vc_reflect_path);
+    let type_uuid_ = crate::path::type_uuid_(vc_reflect_path);
+
+    let params = meta
+        .generics()
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Lifetime(_) => None,
+            syn::GenericParam::Type(type_param) => {
+                let ident = &type_param.ident;
+                Some(quote! {
+                    .combine(<#ident as #trait_type_path_>::type_uuid())
+                })
+            }
+            syn::GenericParam::Const(const_param) => {
+                let ident = &const_param.ident;
+                Some(quote! {
+                    .combine(#type_uuid_::from_u128(#ident as u128))
+                })
+            }
+        });
+
+    quote! {
+        #[inline]
+        fn type_uuid() -> #type_uuid_ {
+            #type_uuid_::from_u128(#uuid) #(#params)*
+        }
+    }
+}
+
+/// Generate implementation codes for `TypePath`,
+/// and `ConstTypePath` for non-generic types.
 pub(crate) fn impl_trait_type_path(meta: &ReflectMeta) -> TokenStream {
     let vc_reflect_path = meta.vc_reflect_path();
     let trait_type_path_ = crate::path::type_path_(vc_reflect_path);
 
     let real_ident = meta.real_ident();
+    let type_uuid = impl_type_uuid(meta);
+    let (impl_generics, ty_generics, where_clause) = meta.split_generics(false, false, false);
 
-    let (type_path, type_name, inline_flag) = if meta.contains_generics() {
-        (
-            static_path_cell(vc_reflect_path, meta.type_path_into_owned()),
-            static_path_cell(vc_reflect_path, meta.type_name_into_owned()),
-            crate::utils::empty(),
-        )
-    } else {
-        (
-            meta.type_path().into_borrowed(),
-            meta.type_name().into_borrowed(),
-            quote! { #[inline] },
-        )
-    };
+    if !meta.contains_generics() {
+        return impl_const_type_path(meta, type_uuid);
+    }
 
+    let type_path = static_path_cell(vc_reflect_path, meta.type_path_into_owned());
+    let type_name = static_path_cell(vc_reflect_path, meta.type_name_into_owned());
     let type_ident = meta.type_ident().into_borrowed();
     let module_path = wrap_in_option(meta.module_path().map(StringExpr::into_borrowed));
-    let (impl_generics, ty_generics, where_clause) = meta.split_generics(false, false, false);
 
     quote! {
         impl #impl_generics #trait_type_path_ for #real_ident #ty_generics #where_clause {
-            #inline_flag
             fn type_path() -> &'static str {
                 #type_path
             }
 
-            #inline_flag
             fn type_name() -> &'static str {
                 #type_name
             }
@@ -74,6 +104,55 @@ pub(crate) fn impl_trait_type_path(meta: &ReflectMeta) -> TokenStream {
             fn module_path() -> #OptionFP<&'static str> {
                 #module_path
             }
+
+            #type_uuid
+        }
+    }
+}
+
+/// Generate `ConstTypePath` for non-generic types, `TypePath` reads the constants.
+fn impl_const_type_path(meta: &ReflectMeta, type_uuid: TokenStream) -> TokenStream {
+    let vc_reflect_path = meta.vc_reflect_path();
+    let trait_type_path_ = crate::path::type_path_(vc_reflect_path);
+    let const_type_path_ = crate::path::const_type_path_(vc_reflect_path);
+
+    let real_ident = meta.real_ident();
+    let type_path = meta.type_path().into_borrowed();
+    let type_name = meta.type_name().into_borrowed();
+    let type_ident = meta.type_ident().into_borrowed();
+    let module_path = wrap_in_option(meta.module_path().map(StringExpr::into_borrowed));
+    let (impl_generics, ty_generics, where_clause) = meta.split_generics(false, false, false);
+
+    quote! {
+        impl #impl_generics #const_type_path_ for #real_ident #ty_generics #where_clause {
+            const TYPE_PATH: &'static str = #type_path;
+            const TYPE_NAME: &'static str = #type_name;
+            const TYPE_IDENT: &'static str = #type_ident;
+            const MODULE_PATH: #OptionFP<&'static str> = #module_path;
+        }
+
+        impl #impl_generics #trait_type_path_ for #real_ident #ty_generics #where_clause {
+            #[inline]
+            fn type_path() -> &'static str {
+                <Self as #const_type_path_>::TYPE_PATH
+            }
+
+            #[inline]
+            fn type_name() -> &'static str {
+                <Self as #const_type_path_>::TYPE_NAME
+            }
+
+            #[inline]
+            fn type_ident() -> &'static str {
+                <Self as #const_type_path_>::TYPE_IDENT
+            }
+
+            #[inline]
+            fn module_path() -> #OptionFP<&'static str> {
+                <Self as #const_type_path_>::MODULE_PATH
+            }
+
+            #type_uuid
         }
     }
 }
@@ -73,6 +73,36 @@ mod utils;
 ///
 /// This attribute can only be applied at the type level.
 ///
+/// ### Type UUID
+///
+/// `TypePath::type_uuid` defaults to a hash of the type path, which changes when the type is renamed.
+/// A fixed identifier can be specified instead:
+///
+/// ```rust, ignore
+/// #[derive(Reflect)]
+/// #[reflect(uuid = "6f1c2b5e-8a4d-4e0f-9b7a-3c2d1e0f4a5b")]
+/// struct Foo { /* ... */ }
+/// ```
+///
+/// For generic types, the identifiers of the generic parameters are combined into it.
+///
+/// This attribute can only be applied at the type level.
+///
+/// ### Type Alias
+///
+/// After renaming or moving a type, its former paths can be kept for deserialization:
+///
+/// ```rust, ignore
+/// #[derive(Reflect)]
+/// #[reflect(alias = "old::path::Foo", alias = "older::Foo")]
+/// struct Foo { /* ... */ }
+/// ```
+///
+/// The aliases are passed to `TypeRegistry::register_alias` when the type is registered.
+/// Generic types are not supported, because the generic arguments are not appended to aliases.
+///
+/// This attribute can only be applied at the type level.
+///
 /// ### Opaque Types
 ///
 /// Unit structs like `struct A;` are treated as `Opaque`. They contain no internal data,
@@ -118,17 +148,19 @@ mod utils;
 /// Available flags:
 ///
 /// - `clone`: Standard `Clone`
+/// - `debug`: Standard `Debug`
 /// - `hash`: Standard `Hash`
 /// - `eq`: Standard `PartialEq`
 /// - `cmp`: Standard `PartialOrd`
 /// - `default`: Standard `Default`
 /// - `serialize`: `serde::Serialize`
 /// - `deserialize`: `serde::Deserialize`
+/// - `display`: Standard `Display`, only used to register `ReflectDisplay`
 ///
 /// Two convenience bundles enable multiple flags simultaneously:
 ///
 /// - `serde`: `serialize` + `deserialize` + `auto_register`
-/// - `full`: All seven traits listed above + `auto_register`
+/// - `full`: All traits listed above except `display` + `auto_register`
 ///
 /// These attributes can only be applied at the type level.
 ///
@@ -151,6 +183,29 @@ mod utils;
 ///
 /// This attribute can only be applied at the type level.
 ///
+/// ## Derived Tests
+///
+/// Flags like `hash` and `eq` are trusted without checks, a flag that does not match the real
+/// implementation silently breaks reflection. The `derive_tests` attribute emits a `#[cfg(test)]`
+/// module next to the type, whose test calls `vc_reflect::testing::assert_reflect_invariants`.
+///
+/// ### Example
+///
+/// ```rust, ignore
+/// #[derive(Reflect, Default, Clone, PartialEq, Hash)]
+/// #[reflect(default, clone, eq, hash, derive_tests)]
+/// struct A { /* ... */ }
+/// ```
+///
+/// The test checks the default value (with the `default` flag) and generated values: `FromReflect`
+/// of `reflect_clone` must be equal to the value with the same `reflect_hash`, and the value must
+/// survive a serialization round trip.
+///
+/// The `testing` feature of `vc_reflect` is required in test builds, e.g. through `dev-dependencies`.
+/// Generic types are not supported, and the type must be declared at module level.
+///
+/// This attribute can only be applied at the type level.
+///
 /// ## Custom GetTypeMeta
 ///
 /// By default, a type's `get_type_meta` includes at least `ReflectFromPtr`. The following type traits may also be
@@ -161,6 +216,8 @@ mod utils;
 /// - `ReflectDefault`: If `Default` is marked as available via `#[reflect(default)]`.
 /// - `ReflectSerialize`: If `serde::Serialize` is marked as available via `#[reflect(serialize)]`.
 /// - `ReflectDeserialize`: If `serde::Deserialize` is marked as available via `#[reflect(deserialize)]`.
+/// - `ReflectDebug`, `ReflectDisplay`, `ReflectHash`: If the matching trait is marked as available via
+///   `#[reflect(debug)]`, `#[reflect(display)]` or `#[reflect(hash)]`.
 ///
 /// You can also manually add type traits using `#[reflect(type_trait = (...))]`. These will be automatically
 /// inserted into `get_type_meta`.
@@ -179,6 +236,62 @@ mod utils;
 ///
 /// This attribute can only be applied at the type level.
 ///
+/// ## Serialization Proxy
+///
+/// `#[reflect(from = "Proxy")]` (de)serializes the type as another reflected type, like serde's
+/// `from`/`into` attributes. The proxy must implement `From<Self>` and `Into<Self>`, and `ReflectProxy`
+/// is inserted into `get_type_meta`. The proxy type is registered as a dependency, and the generated
+/// `FromReflect` also accepts values of the proxy type.
+///
+/// ### Example
+///
+/// ```rust, ignore
+/// #[derive(Reflect)]
+/// #[reflect(from = "ColorHex")]
+/// struct Color { /* ... */ }
+/// // impl From<Color> for ColorHex, From<ColorHex> for Color ...
+/// ```
+///
+/// `#[reflect(readable_from = "Proxy")]` and `#[reflect(binary_from = "Proxy")]` only use the proxy
+/// for human-readable or binary formats, as told by `is_human_readable` of the (de)serializer. In the
+/// other formats, the type is (de)serialized as if it had no proxy, e.g. a hash written as a hex string
+/// in RON and as raw bytes in network snapshots. A type can only have one proxy.
+///
+/// This attribute can only be applied at the type level.
+///
+/// ## Custom Bounds
+///
+/// For generic types, the macro requires every field type containing a type parameter to implement
+/// the reflection traits, e.g. `Vec<T>: Reflect + Typed`. These bounds can be wrong for fields
+/// like `PhantomData<T>`, or unsatisfiable for recursive types.
+///
+/// `#[reflect(no_field_bounds)]` suppresses the bounds of the field types, the type parameters then
+/// only require `TypePath + Send + Sync`. `#[reflect(where T: Trait, ...)]` adds custom predicates
+/// to all generated implementations, on top of the other bounds.
+///
+/// ### Example
+///
+/// ```rust, ignore
+/// // `PhantomData<T>` is reflected for any `T: TypePath + Send + Sync`,
+/// // but the field bound would require `T: Reflect`.
+/// #[derive(Reflect)]
+/// #[reflect(no_field_bounds)]
+/// struct Handle<T> {
+///     id: u32,
+///     marker: PhantomData<T>,
+/// }
+///
+/// // The field bounds are kept, `T: Clone` is added.
+/// #[derive(Reflect)]
+/// #[reflect(where T: Clone)]
+/// struct Stack<T> {
+///     items: Vec<T>,
+/// }
+/// ```
+///
+/// The predicates are comma-separated, so `where` must be the last item of its `#[reflect(...)]`.
+/// These attributes can only be applied at the type level.
+///
 /// ## Documentation Reflection
 ///
 /// Enable the `reflect_docs` feature to include documentation in type information.
@@ -239,6 +352,18 @@ mod utils;
 /// // Only `2_i32` will be stored, overwriting `1_i32`.
 /// ```
 ///
+/// To store several attributes of the same type, use the keyed form `#[reflect(@key = Expr)]`.
+/// Keyed attributes are retrieved by key string, e.g. with `get_keyed_attribute`, and a key
+/// cannot be repeated.
+///
+/// ```rust, ignore
+/// #[derive(Reflect)]
+/// struct A {
+///     #[reflect(@min = 0.0_f32, @max = 1.0_f32)]
+///     volume: f32,
+/// }
+/// ```
+///
 /// This attribute can be applied at the type, field, and enum variant levels.
 ///
 /// ## skip_serde
@@ -259,6 +384,69 @@ mod utils;
 /// Important: This only takes effect with the default serialization provided by the reflection system.
 /// If the type is annotated with `reflect(serde)` and supports serialization via the serde library,
 /// this field attribute will not have any effect.
+///
+/// ## serialize_with and deserialize_with
+///
+/// `#[reflect(serialize_with = "path")]` and `#[reflect(deserialize_with = "path")]` (de)serialize a field
+/// through the given functions, which have the same signatures as in serde's attributes of the same names.
+/// They take precedence over the field type's `ReflectSerialize`/`ReflectDeserialize` and over processors.
+///
+/// ```rust, ignore
+/// #[derive(Reflect)]
+/// struct Texture {
+///     #[reflect(serialize_with = "hex::serialize", deserialize_with = "hex::deserialize")]
+///     data: Vec<u8>,
+/// }
+/// ```
+///
+/// At the type level, usually on opaque types, the functions are inserted into `get_type_meta` as
+/// `ReflectSerialize`/`ReflectDeserialize`, so the type does not need to implement serde traits.
+/// They cannot be combined with `#[reflect(serialize)]` or `#[reflect(deserialize)]`, nor with `skip_serde`.
+///
+/// ## Boxed trait objects
+///
+/// Fields of struct types may hold heterogeneous values as `Box<dyn Reflect>`, or as `Box<dyn MyTrait>`
+/// if `MyTrait` is annotated with [`reflect_trait`] and has `Reflect` as a supertrait.
+///
+/// These fields are treated as dynamic pass-through values: field accessors return the value inside,
+/// and they are serialized together with the type path of that value.
+/// `Box<dyn Reflect>` fields are reported as fields of type `dyn Reflect` in the type info.
+///
+/// ```rust, ignore
+/// #[derive(Reflect)]
+/// struct Plugin {
+///     shape: Box<dyn Shape>,
+///     data: Box<dyn Reflect>,
+/// }
+/// ```
+///
+/// `Box<dyn Reflect>` fields are not supported in enum variants.
+///
+/// ## Field accessors
+///
+/// Private fields of foreign types can be reflected through their accessor methods:
+///
+/// - `get = path`: `fn(&Self) -> &T`, required for the other two.
+/// - `get_mut = path`: `fn(&mut Self) -> &mut T`, used by `field_mut`.
+/// - `set = path`: `fn(&mut Self, T)`, used by `apply`, `reflect_clone` and `from_reflect`.
+///
+/// ```rust, ignore
+/// impl_reflect! {
+///     #[reflect(type_path = "physics::Body", default)]
+///     struct Body {
+///         #[reflect(get = Body::mass, set = Body::set_mass)]
+///         mass: f32,
+///         #[reflect(get = Body::inv_mass)]
+///         inv_mass: f32,
+///     }
+/// }
+/// ```
+///
+/// The values are built from `Default`, so the `default` flag is required.
+/// `field_mut` returns `None` for fields without `get_mut`, and fields with only
+/// a getter keep their default value in `reflect_clone` and `from_reflect`.
+///
+/// Field accessors are not supported in enum variants.
 #[proc_macro_derive(Reflect, attributes(reflect))]
 pub fn derive_full_reflect(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
@@ -309,7 +497,8 @@ pub fn derive_type_path(input: TokenStream) -> TokenStream {
 
 /// Implements reflection for foreign types.
 ///
-/// It requires full type information and access to fields.
+/// It requires full type information and access to fields, or accessor methods for
+/// private fields (see [field accessors](derive_full_reflect#field-accessors)).
 /// Because of the orphan rule, this is typically used inside the reflection crate itself.
 ///
 /// The usage is similar to [`derive Reflect`](derive_full_reflect).
@@ -493,6 +682,10 @@ pub fn impl_auto_register(input: TokenStream) -> TokenStream {
 /// - `from_mut`: cast `&mut dyn Reflect` to `&mut dyn {trait_name}`
 /// - `from_boxed`: cast `Box<dyn Reflect>` to `Box<dyn {trait_name}>`
 ///
+/// If the trait is not generic and has `Reflect` as a supertrait, `Box<dyn {trait_name}>` can
+/// be used as a field of reflected types, see `vc_reflect::impls::DynReflect`.
+/// A hidden supertrait is added to the trait for this, which is implemented for all types.
+///
 /// ## Example
 ///
 /// ```ignore
@@ -8,6 +8,20 @@ pub(crate) fn type_path_(vc_reflect_path: &syn::Path) -> TokenStream {
     }
 }
 
+#[inline(always)]
+pub(crate) fn const_type_path_(vc_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_reflect_path::info::ConstTypePath
+    }
+}
+
+#[inline(always)]
+pub(crate) fn type_uuid_(vc_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_reflect_path::info::TypeUuid
+    }
+}
+
 #[inline(always)]
 pub(crate) fn dynamic_type_path_(vc_reflect_path: &syn::Path) -> TokenStream {
     quote! {
@@ -56,6 +56,18 @@ pub(crate) fn auto_register_(
     }
 }
 
+/// Requires the `testing` feature, the span points errors at `derive_tests`.
+#[inline(always)]
+pub(crate) fn assert_reflect_invariants_(
+    vc_reflect_path: &syn::Path,
+    span: ::proc_macro2::Span,
+) -> TokenStream {
+    let testing = ::syn::Ident::new("testing", span);
+    quote! {
+        #vc_reflect_path::#testing::assert_reflect_invariants
+    }
+}
+
 // mod access;
 // `vc_reflect::access` does not require additional content.
 
@@ -80,9 +92,30 @@ pub(crate) fn from_reflect_(vc_reflect_path: &syn::Path) -> TokenStream {
     }
 }
 
+#[inline(always)]
+pub(crate) fn dyn_reflect_(vc_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_reflect_path::impls::DynReflect
+    }
+}
+
 #[inline(always)]
 pub(crate) fn reflect_hasher_(vc_reflect_path: &syn::Path) -> TokenStream {
     quote! {
         #vc_reflect_path::reflect_hasher
     }
 }
+
+#[inline(always)]
+pub(crate) fn serialize_with_(vc_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_reflect_path::serde::SerializeWith
+    }
+}
+
+#[inline(always)]
+pub(crate) fn deserialize_with_(vc_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_reflect_path::serde::DeserializeWith
+    }
+}
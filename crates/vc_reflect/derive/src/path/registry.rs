@@ -36,6 +36,27 @@ pub(crate) fn type_trait_default_(vc_reflect_path: &syn::Path) -> TokenStream {
     }
 }
 
+#[inline]
+pub(crate) fn type_trait_debug_(vc_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_reflect_path::registry::ReflectDebug
+    }
+}
+
+#[inline]
+pub(crate) fn type_trait_display_(vc_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_reflect_path::registry::ReflectDisplay
+    }
+}
+
+#[inline]
+pub(crate) fn type_trait_hash_(vc_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_reflect_path::registry::ReflectHash
+    }
+}
+
 #[inline]
 pub(crate) fn type_trait_from_ptr_(vc_reflect_path: &syn::Path) -> TokenStream {
     quote! {
@@ -63,3 +84,17 @@ pub(crate) fn type_trait_deserialize_(vc_reflect_path: &syn::Path) -> TokenStrea
         #vc_reflect_path::registry::ReflectDeserialize
     }
 }
+
+#[inline]
+pub(crate) fn type_trait_proxy_(vc_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_reflect_path::registry::ReflectProxy
+    }
+}
+
+#[inline]
+pub(crate) fn proxy_format_(vc_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vc_reflect_path::registry::ProxyFormat
+    }
+}
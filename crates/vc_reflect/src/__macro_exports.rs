@@ -20,6 +20,10 @@ pub mod macro_utils {
     // An efficient string concatenation function.
     pub use crate::impls::concat as __concat;
 
+    // Used by generated `SerializeWith` and `DeserializeWith` implementations,
+    // users may not depend on `serde` directly.
+    pub use serde_core::{Deserializer, Serializer};
+
     // Shared helper for generated `reflect_clone` implementations.
     pub fn __reflect_clone_field<T: crate::Reflect + crate::info::TypePath>(
         source: &T,
@@ -34,6 +38,28 @@ pub mod macro_utils {
             })
         }
     }
+
+    // Shared helper for generated `apply` of fields that only have a setter.
+    pub fn __apply_field_copy<T: crate::Reflect + crate::info::TypePath>(
+        source: &T,
+        input: &dyn crate::Reflect,
+    ) -> Result<T, crate::ops::ApplyError> {
+        let mut val =
+            __reflect_clone_field(source).map_err(|_| crate::ops::ApplyError::NotSupport {
+                type_path: T::type_path(),
+            })?;
+        val.apply(input)?;
+        Ok(val)
+    }
+
+    // Shared helper for generated `from_reflect` of `Box<dyn Reflect>` fields.
+    pub fn __from_reflect_boxed(source: &dyn crate::Reflect) -> Option<Box<dyn crate::Reflect>> {
+        Some(
+            source
+                .reflect_clone()
+                .unwrap_or_else(|_| source.to_dynamic()),
+        )
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -266,6 +266,19 @@ impl<'a> Accessor<'a> {
 // Error implementation
 
 impl<'a> AccessError<'a> {
+    #[inline]
+    pub(super) const fn new(
+        kind: AccessErrorKind,
+        accessor: Accessor<'a>,
+        offset: Option<usize>,
+    ) -> Self {
+        Self {
+            kind,
+            accessor,
+            offset,
+        }
+    }
+
     /// Returns the kind of [`AccessError`].
     #[inline]
     pub fn kind(&self) -> &AccessErrorKind {
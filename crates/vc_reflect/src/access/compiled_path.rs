@@ -0,0 +1,458 @@
+//! Provide type-checked, pre-resolved path accessing support
+
+use alloc::boxed::Box;
+use core::any::TypeId;
+use core::fmt;
+
+use vc_utils::vec::FastVec;
+
+use crate::Reflect;
+use crate::access::{AccessError, AccessErrorKind, Accessor, OffsetAccessor};
+use crate::info::{ReflectKind, TypeInfo};
+use crate::ops::{ReflectMut, ReflectRef};
+
+// -----------------------------------------------------------------------------
+// Error
+
+/// An error returned from a failed [`PathAccessor::compile`].
+///
+/// [`PathAccessor::compile`]: crate::access::PathAccessor::compile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompileError {
+    /// The path does not match the given type.
+    /// See [`AccessError`] for details.
+    AccessError(AccessError<'static>),
+    /// The path passes through an enum, whose active variant
+    /// is only known at runtime.
+    UnsupportedEnum {
+        /// The type path of the enum.
+        type_path: &'static str,
+        /// The offset of the accessor in its path string.
+        offset: Option<usize>,
+    },
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AccessError(err) => fmt::Display::fmt(err, f),
+            Self::UnsupportedEnum { type_path, offset } => {
+                write!(f, "Cannot compile a path through enum `{type_path}`")?;
+                if let Some(offset) = offset {
+                    write!(f, "(offset {offset})")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl core::error::Error for CompileError {}
+
+// -----------------------------------------------------------------------------
+// Compiled step
+
+/// A single resolved step, the kind of the container is known in advance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Step {
+    Field(usize),
+    TupleStructField(usize),
+    TupleField(usize),
+    ArrayItem(usize),
+    ListItem(usize),
+}
+
+impl Step {
+    #[inline]
+    fn access(self, base: &dyn Reflect) -> Option<&dyn Reflect> {
+        match (self, base.reflect_ref()) {
+            (Self::Field(index), ReflectRef::Struct(val)) => val.field_at(index),
+            (Self::TupleStructField(index), ReflectRef::TupleStruct(val)) => val.field(index),
+            (Self::TupleField(index), ReflectRef::Tuple(val)) => val.field(index),
+            (Self::ArrayItem(index), ReflectRef::Array(val)) => val.get(index),
+            (Self::ListItem(index), ReflectRef::List(val)) => val.get(index),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn access_mut(self, base: &mut dyn Reflect) -> Option<&mut dyn Reflect> {
+        match (self, base.reflect_mut()) {
+            (Self::Field(index), ReflectMut::Struct(val)) => val.field_at_mut(index),
+            (Self::TupleStructField(index), ReflectMut::TupleStruct(val)) => val.field_mut(index),
+            (Self::TupleField(index), ReflectMut::Tuple(val)) => val.field_mut(index),
+            (Self::ArrayItem(index), ReflectMut::Array(val)) => val.get_mut(index),
+            (Self::ListItem(index), ReflectMut::List(val)) => val.get_mut(index),
+            _ => None,
+        }
+    }
+
+    /// Resolves `accessor` against `info`, returns the step, the item's type info
+    /// and the byte offset of the item in its container, if known.
+    fn resolve(
+        accessor: &OffsetAccessor<'static>,
+        info: &'static TypeInfo,
+    ) -> Result<(Self, &'static TypeInfo, Option<usize>), CompileError> {
+        let missing = || AccessErrorKind::MissingField(info.kind());
+        let invalid = |expected: ReflectKind| AccessErrorKind::IncompatibleKinds {
+            expected,
+            actual: info.kind(),
+        };
+
+        let res = match (&accessor.accessor, info) {
+            (_, TypeInfo::Enum(_)) => {
+                return Err(CompileError::UnsupportedEnum {
+                    type_path: info.type_path(),
+                    offset: accessor.offset,
+                });
+            }
+            (Accessor::FieldName(name), TypeInfo::Struct(val)) => match val.index_of(name) {
+                Some(index) => {
+                    let field = val.field_at(index).unwrap();
+                    Ok((Self::Field(index), field.type_info(), field.offset()))
+                }
+                None => Err(missing()),
+            },
+            (&Accessor::FieldIndex(index), TypeInfo::Struct(val)) => match val.field_at(index) {
+                Some(field) => Ok((Self::Field(index), field.type_info(), field.offset())),
+                None => Err(missing()),
+            },
+            (Accessor::FieldName(_) | Accessor::FieldIndex(_), _) => {
+                Err(invalid(ReflectKind::Struct))
+            }
+            (&Accessor::TupleIndex(index), TypeInfo::TupleStruct(val)) => {
+                match val.field_at(index) {
+                    Some(field) => Ok((
+                        Self::TupleStructField(index),
+                        field.type_info(),
+                        field.offset(),
+                    )),
+                    None => Err(missing()),
+                }
+            }
+            (&Accessor::TupleIndex(index), TypeInfo::Tuple(val)) => match val.field_at(index) {
+                Some(field) => Ok((Self::TupleField(index), field.type_info(), field.offset())),
+                None => Err(missing()),
+            },
+            (Accessor::TupleIndex(_), _) => Err(invalid(ReflectKind::Tuple)),
+            (&Accessor::ListIndex(index), TypeInfo::Array(val)) => {
+                if index < val.len() {
+                    let offset = val.item_stride().map(|stride| stride * index);
+                    Ok((Self::ArrayItem(index), val.item_info(), offset))
+                } else {
+                    Err(missing())
+                }
+            }
+            // The length of a list is only known at runtime.
+            (&Accessor::ListIndex(index), TypeInfo::List(val)) => {
+                Ok((Self::ListItem(index), val.item_info(), None))
+            }
+            (Accessor::ListIndex(_), _) => Err(invalid(ReflectKind::List)),
+        };
+
+        res.map_err(|kind| {
+            CompileError::AccessError(AccessError::new(
+                kind,
+                accessor.accessor.clone(),
+                accessor.offset,
+            ))
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Compiled path
+
+/// A [`PathAccessor`] resolved against a concrete type, created by [`PathAccessor::compile`].
+///
+/// Field names are resolved to indices and every step is checked against the
+/// [`TypeInfo`] once, so accessing only compares the root [`TypeId`] and follows
+/// the indices, without name lookups or error construction. This is intended
+/// for code that binds a large number of paths once and evaluates them often,
+/// such as animation tracks.
+///
+/// If every step has a known layout, i.e. fields of derived structs, tuples
+/// and items of fixed-size arrays, the path is also resolved to the byte
+/// [`offset`] of the target in the root value. [`access_as`] and
+/// [`access_mut_as`] then only compare two [`TypeId`]s and offset the pointer.
+///
+/// Paths through enums cannot be compiled, because the active variant is only
+/// known at runtime. List indices are still bounds-checked on access.
+///
+/// Accessing returns `None` if the base value is not the compiled type
+/// (including dynamic types that merely represent it), or if a list index
+/// is out of bounds.
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::{Reflect, info::Typed, access::PathAccessor};
+///
+/// #[derive(Reflect)]
+/// struct Transform {
+///     translation: [f32; 3],
+///     scale: f32,
+/// }
+///
+/// let mut value = Transform { translation: [1.0, 2.0, 3.0], scale: 1.0 };
+///
+/// let path = PathAccessor::parse_static(".translation[1]")
+///     .unwrap()
+///     .compile(Transform::type_info())
+///     .unwrap();
+///
+/// assert!(path.target_info().type_is::<f32>());
+/// assert_eq!(*path.access_as::<f32>(&value).unwrap(), 2.0);
+///
+/// *path.access_mut_as::<f32>(&mut value).unwrap() = 5.0;
+/// assert_eq!(value.translation[1], 5.0);
+/// ```
+///
+/// [`offset`]: CompiledPath::offset
+/// [`access_as`]: CompiledPath::access_as
+/// [`access_mut_as`]: CompiledPath::access_mut_as
+/// [`PathAccessor`]: crate::access::PathAccessor
+/// [`PathAccessor::compile`]: crate::access::PathAccessor::compile
+#[expect(
+    clippy::len_without_is_empty,
+    reason = "`is_empty` here is meaningless"
+)]
+#[derive(Debug, Clone)]
+pub struct CompiledPath {
+    root: &'static TypeInfo,
+    target: &'static TypeInfo,
+    steps: Box<[Step]>,
+    offset: Option<usize>,
+}
+
+impl CompiledPath {
+    pub(super) fn new(
+        accessors: &[OffsetAccessor<'static>],
+        root: &'static TypeInfo,
+    ) -> Result<Self, CompileError> {
+        let mut vec: FastVec<Step, 8> = FastVec::new();
+        let data = vec.data();
+
+        let mut target = root;
+        let mut offset = Some(0);
+        for accessor in accessors {
+            let (step, info, step_offset) = Step::resolve(accessor, target)?;
+            data.push(step);
+            target = info;
+            offset = offset.zip(step_offset).map(|(base, step)| base + step);
+        }
+
+        Ok(Self {
+            root,
+            target,
+            steps: vec.into_boxed_slice(),
+            offset,
+        })
+    }
+
+    /// Returns the [`TypeInfo`] of the type this path was compiled for.
+    #[inline]
+    pub fn root_info(&self) -> &'static TypeInfo {
+        self.root
+    }
+
+    /// Returns the [`TypeInfo`] of the value this path points to.
+    #[inline]
+    pub fn target_info(&self) -> &'static TypeInfo {
+        self.target
+    }
+
+    /// Returns the number of steps in this path.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns the byte offset of the target in the root value,
+    /// if the layout of every step is known.
+    #[inline]
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Returns a reference to the value specified by the path.
+    ///
+    /// Returns `None` if `base` is not the compiled type,
+    /// or if a list index is out of bounds.
+    pub fn access<'r>(&self, base: &'r dyn Reflect) -> Option<&'r dyn Reflect> {
+        if base.type_id() != self.root.type_id() {
+            return None;
+        }
+        let mut it = base;
+        for step in &self.steps {
+            it = step.access(it)?;
+        }
+        Some(it)
+    }
+
+    /// Returns a mutable reference to the value specified by the path.
+    ///
+    /// Returns `None` if `base` is not the compiled type,
+    /// or if a list index is out of bounds.
+    pub fn access_mut<'r>(&self, base: &'r mut dyn Reflect) -> Option<&'r mut dyn Reflect> {
+        if base.type_id() != self.root.type_id() {
+            return None;
+        }
+        let mut it = base;
+        for step in &self.steps {
+            it = step.access_mut(it)?;
+        }
+        Some(it)
+    }
+
+    /// Returns a typed reference to the value specified by the path.
+    ///
+    /// Returns `None` if the access fails or the value is not `T`.
+    #[inline]
+    pub fn access_as<'r, T: Reflect>(&self, base: &'r dyn Reflect) -> Option<&'r T> {
+        if let Some(offset) = self.offset
+            && self.is_typed::<T>(base.type_id())
+        {
+            let ptr = (base as *const dyn Reflect).cast::<u8>();
+            // SAFETY: `base` is the root type and `T` is the target type, so
+            // `offset` is the offset of an aligned and initialized `T` in `base`,
+            // as guaranteed by the callers of the unsafe `with_offset` and
+            // `with_item_stride` that stored the offsets of each step.
+            #[expect(unsafe_code, reason = "Offset the pointer to a known field.")]
+            return Some(unsafe { &*ptr.add(offset).cast::<T>() });
+        }
+        self.access(base)?.downcast_ref::<T>()
+    }
+
+    /// Returns a mutable typed reference to the value specified by the path.
+    ///
+    /// Returns `None` if the access fails or the value is not `T`.
+    #[inline]
+    pub fn access_mut_as<'r, T: Reflect>(&self, base: &'r mut dyn Reflect) -> Option<&'r mut T> {
+        if let Some(offset) = self.offset
+            && self.is_typed::<T>((*base).type_id())
+        {
+            let ptr = (base as *mut dyn Reflect).cast::<u8>();
+            // SAFETY: see `access_as`, `base` is borrowed mutably for `'r`.
+            #[expect(unsafe_code, reason = "Offset the pointer to a known field.")]
+            return Some(unsafe { &mut *ptr.add(offset).cast::<T>() });
+        }
+        self.access_mut(base)?.downcast_mut::<T>()
+    }
+
+    /// Whether `base_id` is the root type and `T` is the target type.
+    #[inline]
+    fn is_typed<T: Reflect>(&self, base_id: TypeId) -> bool {
+        base_id == self.root.type_id() && TypeId::of::<T>() == self.target.type_id()
+    }
+}
+
+impl PartialEq for CompiledPath {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.root.type_id() == other.root.type_id() && self.steps == other.steps
+    }
+}
+
+impl Eq for CompiledPath {}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::CompileError;
+    use crate::Reflect;
+    use crate::access::PathAccessor;
+    use crate::info::Typed;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[derive(Reflect)]
+    struct Inner(u8, (bool, f32));
+
+    #[derive(Reflect)]
+    enum Kind {
+        A(u8),
+    }
+
+    #[derive(Reflect)]
+    struct Outer {
+        inner: Inner,
+        values: Vec<i32>,
+        kind: Kind,
+    }
+
+    fn compile(path: &'static str) -> Result<super::CompiledPath, CompileError> {
+        PathAccessor::parse_static(path)
+            .unwrap()
+            .compile(Outer::type_info())
+    }
+
+    #[test]
+    fn compile_and_access() {
+        let mut value = Outer {
+            inner: Inner(1, (true, 2.0)),
+            values: vec![10, 20],
+            kind: Kind::A(3),
+        };
+
+        let path = compile(".inner.1.1").unwrap();
+        assert!(path.target_info().type_is::<f32>());
+        *path.access_mut_as::<f32>(&mut value).unwrap() = 4.0;
+        assert_eq!((value.inner.1).1, 4.0);
+
+        let path = compile("#1[1]").unwrap();
+        assert_eq!(path, compile(".values[1]").unwrap());
+        assert_eq!(*path.access_as::<i32>(&value).unwrap(), 20);
+        assert!(compile(".values[2]").unwrap().access(&value).is_none());
+
+        // Wrong root type.
+        assert!(path.access(&value.inner).is_none());
+        assert!(path.access_as::<i32>(&value.inner).is_none());
+
+        assert!(matches!(
+            compile(".missing"),
+            Err(CompileError::AccessError(_))
+        ));
+        assert!(matches!(
+            compile(".inner.x"),
+            Err(CompileError::AccessError(_))
+        ));
+        assert!(matches!(
+            compile(".kind.0"),
+            Err(CompileError::UnsupportedEnum { .. })
+        ));
+    }
+
+    #[test]
+    fn byte_offsets() {
+        use core::mem::offset_of;
+
+        let mut value = Outer {
+            inner: Inner(1, (true, 2.0)),
+            values: vec![10, 20],
+            kind: Kind::A(3),
+        };
+
+        let path = compile(".inner.1.1").unwrap();
+        let expected = offset_of!(Outer, inner) + offset_of!(Inner, 1) + offset_of!((bool, f32), 1);
+        assert_eq!(path.offset(), Some(expected));
+        assert_eq!(*path.access_as::<f32>(&value).unwrap(), 2.0);
+        *path.access_mut_as::<f32>(&mut value).unwrap() = 6.0;
+        assert_eq!((value.inner.1).1, 6.0);
+        // The target type must match.
+        assert!(path.access_as::<u32>(&value).is_none());
+
+        // Arrays are contiguous, lists are not.
+        let path = PathAccessor::parse_static("[2].0")
+            .unwrap()
+            .compile(<[Inner; 4]>::type_info())
+            .unwrap();
+        assert_eq!(
+            path.offset(),
+            Some(2 * size_of::<Inner>() + offset_of!(Inner, 0))
+        );
+        assert_eq!(compile(".values[1]").unwrap().offset(), None);
+    }
+}
@@ -7,6 +7,8 @@
 //! - [`PathAccessor`]: a parsed, reusable accessor optimized for repeated queries.
 //!   Use this when you will run the same path multiple times — the path is
 //!   parsed once and then reused without additional parsing or allocation.
+//!   It can be further compiled against a [`TypeInfo`] into a [`CompiledPath`],
+//!   which resolves field names to indices ahead of time.
 //! - [`ReflectPathAccess`]: a convenience trait implemented for `Reflect` that
 //!   parses the provided access path on each call. It is suitable for one-off
 //!   lookups where reuse is not required.
@@ -70,11 +72,14 @@
 //! let val = accessor.access_as::<u8>(&foo).unwrap();
 //! assert_eq!(*val, 13);
 //! ```
+//!
+//! [`TypeInfo`]: crate::info::TypeInfo
 
 // -----------------------------------------------------------------------------
 // Modules
 
 mod accessor;
+mod compiled_path;
 mod path;
 mod path_access;
 mod string_parser;
@@ -84,5 +89,6 @@ mod string_parser;
 
 pub use accessor::{AccessError, AccessErrorKind};
 pub use accessor::{Accessor, OffsetAccessor};
+pub use compiled_path::{CompileError, CompiledPath};
 pub use path::{AccessPath, ParseError};
 pub use path_access::{PathAccessError, PathAccessor, ReflectPathAccess};
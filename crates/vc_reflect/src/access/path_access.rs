@@ -6,7 +6,9 @@ use core::fmt;
 use vc_utils::vec::FastVec;
 
 use crate::Reflect;
-use crate::access::{AccessError, AccessPath, OffsetAccessor, ParseError};
+use crate::access::{AccessError, AccessPath, CompileError, CompiledPath};
+use crate::access::{OffsetAccessor, ParseError};
+use crate::info::TypeInfo;
 use crate::ops::{Array, Enum, List, Struct, Tuple, TupleStruct};
 
 // -----------------------------------------------------------------------------
@@ -263,6 +265,26 @@ impl PathAccessor {
         }
     }
 
+    /// Resolves this path against `info` and creates a [`CompiledPath`].
+    ///
+    /// Returns [`CompileError`] if the path does not match the type,
+    /// or if it passes through an enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_reflect::{info::Typed, access::PathAccessor};
+    /// let accessor = PathAccessor::parse_static(".0[1]").unwrap();
+    /// let path = accessor.compile(<(Vec<i32>, u8)>::type_info()).unwrap();
+    ///
+    /// let foo = (vec![1_i32, 2, 3], 1_u8);
+    /// assert_eq!(*path.access_as::<i32>(&foo).unwrap(), 2);
+    /// ```
+    #[inline]
+    pub fn compile(&self, info: &'static TypeInfo) -> Result<CompiledPath, CompileError> {
+        CompiledPath::new(&self.0, info)
+    }
+
     /// Concat two `PathAccessor`.
     ///
     /// Note that this will not modify the `offset`,
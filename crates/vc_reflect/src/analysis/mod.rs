@@ -0,0 +1,22 @@
+//! Provides analyses of reflected values.
+//!
+//! ## Menu
+//!
+//! - [`deep_size_of`]: Estimates the memory used by a reflected value, including
+//!   the heap memory of lists, maps, strings and nested values.
+//! - [`Shared`]: An attribute, attached with `#[reflect(@Shared)]`, excluding
+//!   shared data such as the value inside an `Arc` from [`deep_size_of`].
+//! - [`HeapSize`]: A trait for the heap usage of opaque types, exposed to the
+//!   registry by [`ReflectHeapSize`].
+//!
+//! [`ReflectHeapSize`]: crate::registry::ReflectHeapSize
+
+// -----------------------------------------------------------------------------
+// Modules
+
+mod size;
+
+// -----------------------------------------------------------------------------
+// Exports
+
+pub use size::{HeapSize, Shared, deep_size_of};
@@ -0,0 +1,268 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use crate::Reflect;
+use crate::info::{CustomAttributes, NamedField, UnnamedField};
+use crate::ops::{ReflectRef, VariantFieldInfo};
+use crate::registry::{ReflectHeapSize, TypeRegistry};
+
+// -----------------------------------------------------------------------------
+// HeapSize
+
+/// A trait for the heap memory owned by a value.
+///
+/// [`deep_size_of`] cannot see the heap memory of opaque types, except for
+/// strings. Register [`ReflectHeapSize`] for the type (for example through
+/// `#[reflect(type_trait = ReflectHeapSize)]`) to report it.
+///
+/// See [`ReflectHeapSize`] for examples.
+pub trait HeapSize {
+    /// Returns the number of bytes owned by the value on the heap,
+    /// not including `size_of_val(self)`.
+    fn heap_size(&self) -> usize;
+}
+
+// -----------------------------------------------------------------------------
+// Shared
+
+/// Excludes the data behind a field from [`deep_size_of`], usually a
+/// [`SharedArc`] whose value is owned by many values.
+///
+/// The field itself, e.g. the pointer of the `Arc`, is still counted.
+///
+/// [`SharedArc`]: crate::impls::SharedArc
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::Reflect;
+/// use vc_reflect::analysis::Shared;
+/// use vc_reflect::impls::SharedArc;
+///
+/// #[derive(Reflect)]
+/// struct Mesh {
+///     vertices: Vec<[f32; 3]>,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Model {
+///     #[reflect(@Shared)]
+///     mesh: SharedArc<Mesh>,
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[reflect(type_path = "vc_reflect::analysis::Shared", clone, eq, hash, default)]
+pub struct Shared;
+
+// -----------------------------------------------------------------------------
+// deep_size_of
+
+/// Estimates the number of bytes used by a reflected value, both inline
+/// (`size_of_val`) and on the heap.
+///
+/// Values stored outside of their parent are counted with their own size,
+/// e.g. the elements of lists, maps and sets, boxed fields and the value
+/// inside a [`SharedArc`]. Opaque values, including a plain `Arc`, only count
+/// their inline size, except:
+///
+/// - `String` and `Cow<'static, str>`, which count their capacity.
+/// - Types with [`ReflectHeapSize`] registered, which count
+///   [`HeapSize::heap_size`] instead of visiting their content.
+///
+/// Fields marked with [`Shared`] only count their inline size.
+///
+/// This is an estimate: the spare capacity of lists, maps and sets and the
+/// bookkeeping of allocators and hash tables are not visible through
+/// reflection, and values shared without [`Shared`] are counted each time.
+///
+/// [`SharedArc`]: crate::impls::SharedArc
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::prelude::*;
+/// use vc_reflect::analysis::deep_size_of;
+///
+/// #[derive(Reflect)]
+/// struct Inventory {
+///     items: Vec<u32>,
+/// }
+///
+/// let registry = TypeRegistry::new();
+/// let inventory = Inventory { items: vec![1, 2, 3] };
+///
+/// assert_eq!(
+///     deep_size_of(&inventory, &registry),
+///     size_of::<Inventory>() + 3 * size_of::<u32>(),
+/// );
+/// ```
+pub fn deep_size_of(value: &dyn Reflect, registry: &TypeRegistry) -> usize {
+    size_of_val(value) + heap_size_of(value, registry)
+}
+
+/// Returns the address range of a value.
+#[inline]
+fn extent<T: ?Sized>(value: &T) -> (usize, usize) {
+    let start = core::ptr::from_ref(value).cast::<u8>().addr();
+    (start, start + size_of_val(value))
+}
+
+/// Returns `true` if the `inner` range is inside of `outer`.
+#[inline]
+fn contains(outer: (usize, usize), inner: (usize, usize)) -> bool {
+    inner.0 >= outer.0 && inner.1 <= outer.1
+}
+
+/// Returns the bytes owned by `value` outside of `size_of_val(value)`.
+fn heap_size_of(value: &dyn Reflect, registry: &TypeRegistry) -> usize {
+    if let Some(string) = value.downcast_ref::<String>() {
+        return string.capacity();
+    }
+    if let Some(Cow::Owned(string)) = value.downcast_ref::<Cow<'static, str>>() {
+        return string.capacity();
+    }
+
+    let info = value.represented_type_info();
+
+    if let Some(info) = info
+        && let Some(heap_size) = registry.get_type_trait::<ReflectHeapSize>(info.type_id())
+        && let Some(size) = heap_size.heap_size(value)
+    {
+        return size;
+    }
+
+    let reflect_ref = value.reflect_ref();
+    let data: &dyn Reflect = match reflect_ref {
+        ReflectRef::Struct(data) => data,
+        ReflectRef::TupleStruct(data) => data,
+        ReflectRef::Tuple(data) => data,
+        ReflectRef::List(data) => data,
+        ReflectRef::Array(data) => data,
+        ReflectRef::Map(data) => data,
+        ReflectRef::Set(data) => data,
+        ReflectRef::Enum(data) => data,
+        ReflectRef::Opaque(data) => data,
+    };
+
+    // Pointers such as `Box<dyn Trait>` reflect the value they point to.
+    let parent = extent(value);
+    if !contains(parent, extent(data)) {
+        return size_of_val(data) + heap_size_of(data, registry);
+    }
+
+    let visit = |child: &dyn Reflect, attrs: Option<&CustomAttributes>| {
+        if attrs.is_some_and(CustomAttributes::contains::<Shared>) {
+            return 0;
+        }
+        let outside = match contains(parent, extent(child)) {
+            true => 0,
+            false => size_of_val(child),
+        };
+        outside + heap_size_of(child, registry)
+    };
+
+    let mut size = 0;
+    match reflect_ref {
+        ReflectRef::Struct(data) => {
+            for (field_info, field) in data.iter_fields_with_info() {
+                size += visit(field, field_info.map(NamedField::custom_attributes));
+            }
+        }
+        ReflectRef::TupleStruct(data) => {
+            for (field_info, field) in data.iter_fields_with_info() {
+                size += visit(field, field_info.map(UnnamedField::custom_attributes));
+            }
+        }
+        ReflectRef::Tuple(data) => {
+            for field in data.iter_fields() {
+                size += visit(field, None);
+            }
+        }
+        ReflectRef::List(data) => {
+            for item in data.iter() {
+                size += visit(item, None);
+            }
+        }
+        ReflectRef::Array(data) => {
+            for item in data.iter() {
+                size += visit(item, None);
+            }
+        }
+        ReflectRef::Map(data) => {
+            for (key, val) in data.iter() {
+                size += visit(key, None);
+                size += visit(val, None);
+            }
+        }
+        ReflectRef::Set(data) => {
+            for item in data.iter() {
+                size += visit(item, None);
+            }
+        }
+        ReflectRef::Enum(data) => {
+            for (field_info, field) in data.iter_fields_with_info() {
+                let attrs = field_info.as_ref().map(VariantFieldInfo::custom_attributes);
+                size += visit(field.value(), attrs);
+            }
+        }
+        ReflectRef::Opaque(_) => {}
+    }
+
+    size
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::sync::Arc;
+    use alloc::vec::Vec;
+
+    use super::{Shared, deep_size_of};
+    use crate::Reflect;
+    use crate::impls::SharedArc;
+    use crate::registry::TypeRegistry;
+
+    #[derive(Reflect)]
+    struct Mesh {
+        name: String,
+        indices: Vec<u32>,
+    }
+
+    #[derive(Reflect)]
+    struct Model {
+        mesh: Mesh,
+        owned: SharedArc<Mesh>,
+        #[reflect(@Shared)]
+        shared: SharedArc<Mesh>,
+        opaque: Arc<Mesh>,
+    }
+
+    #[test]
+    fn deep_size() {
+        let registry = TypeRegistry::new();
+
+        let mesh = || Mesh {
+            name: String::with_capacity(10),
+            indices: Vec::from([1, 2, 3]),
+        };
+        let mesh_heap = 10 + 3 * size_of::<u32>();
+        assert_eq!(
+            deep_size_of(&mesh(), &registry),
+            size_of::<Mesh>() + mesh_heap
+        );
+
+        let model = Model {
+            mesh: mesh(),
+            owned: SharedArc::new(mesh()),
+            shared: SharedArc::new(mesh()),
+            opaque: Arc::new(mesh()),
+        };
+        assert_eq!(
+            deep_size_of(&model, &registry),
+            size_of::<Model>() + mesh_heap + (size_of::<Mesh>() + mesh_heap)
+        );
+    }
+}
@@ -0,0 +1,18 @@
+//! Provides human-readable formatting of reflected values.
+//!
+//! ## Menu
+//!
+//! - [`ReflectPrettyPrinter`]: Renders any `&dyn Reflect` as indented,
+//!   type-annotated text, independent of `Debug` implementations of containers.
+//! - [`PrettyDisplay`]: A [`Display`](core::fmt::Display) adapter returned by
+//!   [`ReflectPrettyPrinter::display`].
+
+// -----------------------------------------------------------------------------
+// Modules
+
+mod pretty;
+
+// -----------------------------------------------------------------------------
+// Exports
+
+pub use pretty::{PrettyDisplay, ReflectPrettyPrinter};
@@ -0,0 +1,384 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::TypeId;
+use core::fmt::{self, Display, Write};
+
+use crate::Reflect;
+use crate::info::VariantKind;
+use crate::ops::ReflectRef;
+
+// -----------------------------------------------------------------------------
+// ReflectPrettyPrinter
+
+/// Renders reflected values as indented, type-annotated text.
+///
+/// Unlike [`Reflect::reflect_debug`], the structure of the value is walked
+/// through reflection, so only opaque leaves rely on their own `reflect_debug`.
+/// The output is stable across runs, entries of maps and sets are sorted.
+///
+/// - Composite values start with their type: `Player { .. }`, `Vec<u32> [ .. ]`,
+///   `Option<u32>::Some( .. )`.
+/// - Leaves are written as `type = value`, e.g. `u32 = 10`.
+/// - Map entries are written as `key => value`, keys are rendered inline.
+///
+/// Dynamic values are annotated with the type they represent, if any.
+///
+/// Values nested deeper than the depth limit are elided as `..`. Values that
+/// are already being printed higher up in the tree (same address and type)
+/// are written as `<cycle>`, which guards against implementations that share
+/// data, e.g. through `Arc`, instead of recursing forever.
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::Reflect;
+/// use vc_reflect::fmt::ReflectPrettyPrinter;
+///
+/// #[derive(Reflect)]
+/// struct Player {
+///     name: String,
+///     items: Vec<u32>,
+/// }
+///
+/// let player = Player { name: "Alex".into(), items: vec![1, 2] };
+///
+/// let text = ReflectPrettyPrinter::new().display(&player).to_string();
+/// assert_eq!(text, "\
+/// Player {
+///     name: String = \"Alex\",
+///     items: Vec<u32> [
+///         u32 = 1,
+///         u32 = 2,
+///     ],
+/// }");
+///
+/// let text = ReflectPrettyPrinter::new().with_max_depth(0).display(&player).to_string();
+/// assert_eq!(text, "Player { .. }");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ReflectPrettyPrinter {
+    indent: usize,
+    max_depth: usize,
+    full_paths: bool,
+}
+
+impl Default for ReflectPrettyPrinter {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReflectPrettyPrinter {
+    /// Creates a printer with an indent of 4 spaces, no depth limit and
+    /// short type names.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            indent: 4,
+            max_depth: usize::MAX,
+            full_paths: false,
+        }
+    }
+
+    /// Sets the number of spaces per indentation level.
+    #[inline]
+    pub const fn with_indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the maximum nesting depth, deeper composite values are elided.
+    ///
+    /// With a depth of `0`, only the outermost value is shown.
+    #[inline]
+    pub const fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets whether types are annotated with their full [type path] instead
+    /// of their short name.
+    ///
+    /// [type path]: crate::info::TypePath::type_path
+    #[inline]
+    pub const fn with_full_paths(mut self, enabled: bool) -> Self {
+        self.full_paths = enabled;
+        self
+    }
+
+    /// Writes `value` to `out`.
+    pub fn print(&self, value: &dyn Reflect, out: &mut dyn Write) -> fmt::Result {
+        let mut printer = Printer {
+            config: self,
+            out,
+            stack: Vec::new(),
+        };
+        printer.value(value, 0)
+    }
+
+    /// Returns a [`Display`] adapter that prints `value`.
+    #[inline]
+    pub fn display<'a>(&'a self, value: &'a dyn Reflect) -> PrettyDisplay<'a> {
+        PrettyDisplay {
+            printer: self,
+            value,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// PrettyDisplay
+
+/// Prints a reflected value through [`Display`].
+///
+/// Created by [`ReflectPrettyPrinter::display`].
+#[derive(Clone, Copy)]
+pub struct PrettyDisplay<'a> {
+    printer: &'a ReflectPrettyPrinter,
+    value: &'a dyn Reflect,
+}
+
+impl Display for PrettyDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.printer.print(self.value, f)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Printer
+
+struct Printer<'a> {
+    config: &'a ReflectPrettyPrinter,
+    out: &'a mut dyn Write,
+    /// Composite values on the current path, by address and type.
+    stack: Vec<(usize, TypeId)>,
+}
+
+impl Printer<'_> {
+    fn type_name(&self, value: &dyn Reflect) -> &'static str {
+        match (value.represented_type_info(), self.config.full_paths) {
+            (Some(info), true) => info.type_path(),
+            (Some(info), false) => info.type_name(),
+            (None, true) => value.reflect_type_path(),
+            (None, false) => value.reflect_type_name(),
+        }
+    }
+
+    fn indent(&mut self, depth: usize) -> fmt::Result {
+        let width = depth * self.config.indent;
+        write!(self.out, "{:width$}", "")
+    }
+
+    fn value(&mut self, value: &dyn Reflect, depth: usize) -> fmt::Result {
+        let name = self.type_name(value);
+
+        let (open, close) = match value.reflect_ref() {
+            ReflectRef::Opaque(value) => return write!(self.out, "{name} = {value:?}"),
+            ReflectRef::Enum(data) => {
+                write!(self.out, "{name}::{}", data.variant_name())?;
+                match data.variant_kind() {
+                    VariantKind::Unit => return Ok(()),
+                    VariantKind::Tuple => ("(", ")"),
+                    VariantKind::Struct => (" {", "}"),
+                }
+            }
+            ReflectRef::Struct(_) | ReflectRef::Map(_) => {
+                write!(self.out, "{name}")?;
+                (" {", "}")
+            }
+            ReflectRef::TupleStruct(_) => {
+                write!(self.out, "{name}")?;
+                ("(", ")")
+            }
+            ReflectRef::Tuple(_) => {
+                write!(self.out, "{name}")?;
+                (" (", ")")
+            }
+            ReflectRef::List(_) | ReflectRef::Array(_) | ReflectRef::Set(_) => {
+                write!(self.out, "{name}")?;
+                (" [", "]")
+            }
+        };
+
+        if depth >= self.config.max_depth {
+            return write!(self.out, "{open} .. {close}");
+        }
+
+        let key = ((value as *const dyn Reflect).addr(), value.type_id());
+        if self.stack.contains(&key) {
+            return write!(self.out, "{open} <cycle> {close}");
+        }
+
+        self.stack.push(key);
+        self.out.write_str(open)?;
+        let len = self.children(value, depth + 1)?;
+        if len > 0 {
+            self.out.write_char('\n')?;
+            self.indent(depth)?;
+        }
+        self.out.write_str(close)?;
+        self.stack.pop();
+        Ok(())
+    }
+
+    /// Writes the children of a composite value, one per line, and
+    /// returns how many were written.
+    fn children(&mut self, value: &dyn Reflect, depth: usize) -> Result<usize, fmt::Error> {
+        let mut len = 0;
+        let mut child = |this: &mut Self, label: Option<&dyn Display>, item: &dyn Reflect| {
+            this.out.write_char('\n')?;
+            this.indent(depth)?;
+            if let Some(label) = label {
+                write!(this.out, "{label}")?;
+            }
+            this.value(item, depth)?;
+            len += 1;
+            this.out.write_char(',')
+        };
+
+        match value.reflect_ref() {
+            ReflectRef::Struct(data) => {
+                for (index, field) in data.iter_fields().enumerate() {
+                    let name = data.name_at(index).unwrap_or_default();
+                    child(self, Some(&format_args!("{name}: ")), field)?;
+                }
+            }
+            ReflectRef::TupleStruct(data) => {
+                for field in data.iter_fields() {
+                    child(self, None, field)?;
+                }
+            }
+            ReflectRef::Tuple(data) => {
+                for field in data.iter_fields() {
+                    child(self, None, field)?;
+                }
+            }
+            ReflectRef::List(data) => {
+                for item in data.iter() {
+                    child(self, None, item)?;
+                }
+            }
+            ReflectRef::Array(data) => {
+                for item in data.iter() {
+                    child(self, None, item)?;
+                }
+            }
+            ReflectRef::Map(data) => {
+                let mut entries: Vec<_> = data
+                    .iter()
+                    .map(|(key, val)| (format!("{key:?}"), key, val))
+                    .collect();
+                entries.sort_by(|a, b| a.1.reflect_cmp(b.1).unwrap_or_else(|| a.0.cmp(&b.0)));
+                for (text, _, val) in entries {
+                    child(self, Some(&format_args!("{text} => ")), val)?;
+                }
+            }
+            ReflectRef::Set(data) => {
+                let mut items: Vec<(String, &dyn Reflect)> = data
+                    .iter()
+                    .map(|item| (format!("{item:?}"), item))
+                    .collect();
+                items.sort_by(|a, b| a.1.reflect_cmp(b.1).unwrap_or_else(|| a.0.cmp(&b.0)));
+                for (_, item) in items {
+                    child(self, None, item)?;
+                }
+            }
+            ReflectRef::Enum(data) => {
+                for index in 0..data.field_len() {
+                    let Some(field) = data.field_at(index) else {
+                        continue;
+                    };
+                    match data.name_at(index) {
+                        Some(name) => child(self, Some(&format_args!("{name}: ")), field)?,
+                        None => child(self, None, field)?,
+                    }
+                }
+            }
+            ReflectRef::Opaque(_) => {}
+        }
+
+        Ok(len)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::ReflectPrettyPrinter;
+    use crate::Reflect;
+    use crate::ops::DynamicStruct;
+    use vc_utils::hash::HashMap;
+
+    #[derive(Reflect)]
+    enum Shape {
+        Empty,
+        Circle(f32),
+        Rect { w: u32, h: u32 },
+    }
+
+    #[derive(Reflect)]
+    struct Scene {
+        shapes: Vec<Shape>,
+        tags: HashMap<String, u8>,
+        pair: (u8, bool),
+    }
+
+    #[test]
+    fn pretty_print() {
+        let mut tags = HashMap::new();
+        tags.insert(String::from("b"), 2);
+        tags.insert(String::from("a"), 1);
+        let scene = Scene {
+            shapes: vec![Shape::Empty, Shape::Circle(1.5), Shape::Rect { w: 2, h: 3 }],
+            tags,
+            pair: (7, true),
+        };
+
+        let text = ReflectPrettyPrinter::new()
+            .with_indent(2)
+            .display(&scene)
+            .to_string();
+        let expected = "\
+Scene {
+  shapes: Vec<Shape> [
+    Shape::Empty,
+    Shape::Circle(
+      f32 = 1.5,
+    ),
+    Shape::Rect {
+      w: u32 = 2,
+      h: u32 = 3,
+    },
+  ],
+  tags: HashMap<String, u8, FixedHashState> {
+    \"a\" => u8 = 1,
+    \"b\" => u8 = 2,
+  },
+  pair: (u8, bool) (
+    u8 = 7,
+    bool = true,
+  ),
+}";
+        assert_eq!(text, expected);
+
+        let text = ReflectPrettyPrinter::new()
+            .with_max_depth(1)
+            .display(&scene)
+            .to_string();
+        assert!(text.contains("shapes: Vec<Shape> [ .. ],"));
+
+        // Dynamic values without a represented type use their own name.
+        let mut dynamic = DynamicStruct::default();
+        dynamic.extend("x", 1_u8);
+        let text = ReflectPrettyPrinter::new().display(&dynamic).to_string();
+        assert_eq!(text, "DynamicStruct {\n    x: u8 = 1,\n}");
+    }
+}
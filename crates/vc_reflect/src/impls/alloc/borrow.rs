@@ -3,6 +3,8 @@ use alloc::boxed::Box;
 use alloc::vec::Vec;
 use core::cmp::Ordering;
 
+use serde_core::Deserializer;
+
 use crate::derive::impl_type_path;
 use crate::impls::GenericTypeInfoCell;
 use crate::info::{ListInfo, OpaqueInfo, TypeInfo, TypePath, Typed};
@@ -11,6 +13,7 @@ use crate::registry::{
     FromType, GetTypeMeta, ReflectDefault, ReflectFromPtr, TypeMeta, TypeRegistry,
 };
 use crate::registry::{ReflectDeserialize, ReflectFromReflect, ReflectSerialize};
+use crate::serde::DeserializeWith;
 use crate::{FromReflect, Reflect};
 
 // -----------------------------------------------------------------------------
@@ -80,11 +83,26 @@ impl GetTypeMeta for Cow<'static, str> {
         meta.insert_trait::<ReflectFromReflect>(FromType::<Self>::from_type());
         meta.insert_trait::<ReflectDefault>(FromType::<Self>::from_type());
         meta.insert_trait::<ReflectSerialize>(FromType::<Self>::from_type());
-        meta.insert_trait::<ReflectDeserialize>(FromType::<Self>::from_type());
+        meta.insert_trait::<ReflectDeserialize>(
+            ReflectDeserialize::with::<CowStr, { usize::MAX }>(),
+        );
         meta
     }
 }
 
+/// Borrows the string from the input of [`DeserializeDriver::from_slice`] if possible.
+///
+/// [`DeserializeDriver::from_slice`]: crate::serde::DeserializeDriver::from_slice
+struct CowStr;
+
+impl DeserializeWith<{ usize::MAX }> for CowStr {
+    type Value = Cow<'static, str>;
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self::Value, D::Error> {
+        crate::serde::deserialize_cow_str(deserializer)
+    }
+}
+
 impl FromReflect for Cow<'static, str> {
     fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
         Some(reflect.downcast_ref::<Self>()?.clone())
@@ -195,6 +213,18 @@ impl<T: FromReflect + Typed + Clone> List for Cow<'static, [T]> {
             .map(Reflect::into_boxed_reflect)
             .collect()
     }
+
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect) -> bool) {
+        self.to_mut().retain(|value| f(value));
+    }
+
+    fn split_off(&mut self, index: usize) -> Vec<Box<dyn Reflect>> {
+        self.to_mut()
+            .split_off(index)
+            .into_iter()
+            .map(Reflect::into_boxed_reflect)
+            .collect()
+    }
 }
 
 impl<T: FromReflect + Typed + Clone> FromReflect for Cow<'static, [T]> {
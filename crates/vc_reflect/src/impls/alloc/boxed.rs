@@ -0,0 +1,356 @@
+use alloc::boxed::Box;
+use core::cmp::Ordering;
+use core::fmt;
+
+use crate::impls::{self, GenericTypePathCell};
+use crate::info::{OpaqueInfo, ReflectKind, TypeInfo, TypePath, Typed};
+use crate::ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef};
+use crate::registry::{FromType, GetTypeMeta, ReflectBox, ReflectFromReflect};
+use crate::registry::{TypeMeta, TypeRegistry};
+use crate::{FromReflect, Reflect};
+
+// -----------------------------------------------------------------------------
+// DynReflect
+
+/// A reflectable trait object, allowing `Box<Self>` to be used as a field
+/// of reflected types.
+///
+/// This is implemented for `dyn MyTrait` by [`reflect_trait`] if `MyTrait`
+/// has `Reflect` as a supertrait.
+///
+/// `Box<T>` is reflected as a dynamic pass-through value:
+///
+/// - Its [`TypeInfo`] is opaque, while [`Reflect::reflect_ref`],
+///   [`Reflect::represented_type_info`] and other accessors forward to the
+///   value inside.
+/// - It is serialized together with the type path of the value inside,
+///   see [`ReflectBox`].
+/// - [`FromReflect`] clones the value inside another box. A `Box<dyn MyTrait>`
+///   can only be created from another `Box<dyn MyTrait>`, as casting any
+///   other value requires the registry.
+///
+/// `Box<dyn Reflect>` does not implement [`Reflect`], as it is already used
+/// everywhere to pass reflected values around. Instead, `#[derive(Reflect)]`
+/// treats struct fields of this type as fields of type `dyn Reflect`, which
+/// behave like the boxes above.
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::Reflect;
+/// use vc_reflect::derive::reflect_trait;
+///
+/// #[reflect_trait]
+/// trait Shape: Reflect {
+///     fn area(&self) -> f32;
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Square(f32);
+///
+/// impl Shape for Square {
+///     fn area(&self) -> f32 {
+///         self.0 * self.0
+///     }
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Plugin {
+///     shape: Box<dyn Shape>,
+///     data: Box<dyn Reflect>,
+/// }
+///
+/// let plugin = Plugin {
+///     shape: Box::new(Square(2.0)),
+///     data: Box::new(String::from("hello")),
+/// };
+///
+/// let cloned = plugin.reflect_clone().unwrap().take::<Plugin>().unwrap();
+/// assert_eq!(cloned.shape.area(), 4.0);
+/// assert_eq!(cloned.data.downcast_ref::<String>().unwrap(), "hello");
+/// ```
+///
+/// [`reflect_trait`]: crate::derive::reflect_trait
+pub trait DynReflect: TypePath + Send + Sync + 'static {
+    /// Casts the trait object to `dyn Reflect`.
+    fn upcast(&self) -> &dyn Reflect;
+
+    /// Casts the trait object to `dyn Reflect`.
+    fn upcast_mut(&mut self) -> &mut dyn Reflect;
+
+    /// Casts the boxed trait object to `Box<dyn Reflect>`.
+    fn upcast_boxed(self: Box<Self>) -> Box<dyn Reflect>;
+
+    /// Clones the value inside a new box.
+    fn clone_boxed(&self) -> Option<Box<Self>>;
+
+    /// Casts a reflected value to this trait object.
+    ///
+    /// The value is returned unchanged if its type cannot be cast,
+    /// which may require the registry.
+    fn downcast_boxed(
+        value: Box<dyn Reflect>,
+        registry: Option<&TypeRegistry>,
+    ) -> Result<Box<Self>, Box<dyn Reflect>>;
+}
+
+// -----------------------------------------------------------------------------
+// Box<T>
+
+impl<T: DynReflect + ?Sized> TypePath for Box<T> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self>(|| impls::concat(&["alloc::boxed::Box<", T::type_path(), ">"]))
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self>(|| impls::concat(&["Box<", T::type_name(), ">"]))
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "Box"
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("alloc::boxed")
+    }
+}
+
+/// Returns the value inside `value` if it is a `Box<T>`.
+fn unbox<T: DynReflect + ?Sized>(value: &dyn Reflect) -> &dyn Reflect {
+    match value.downcast_ref::<Box<T>>() {
+        Some(value) => T::upcast(value),
+        None => value,
+    }
+}
+
+impl<T: DynReflect + ?Sized> Typed for Box<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: impls::GenericTypeInfoCell = impls::GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self>(|| TypeInfo::Opaque(OpaqueInfo::new::<Self>()))
+    }
+}
+
+impl<T: DynReflect + ?Sized> Reflect for Box<T> {
+    #[inline]
+    fn is_dynamic(&self) -> bool {
+        T::upcast(self).is_dynamic()
+    }
+
+    #[inline]
+    fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+        T::upcast(self).represented_type_info()
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = match value.downcast::<Self>() {
+            Ok(value) => *value,
+            Err(value) => T::downcast_boxed(value, None)?,
+        };
+        Ok(())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        T::upcast(self).reflect_kind()
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        T::upcast(self).reflect_ref()
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        T::upcast_mut(self).reflect_mut()
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        T::upcast_boxed(*self).reflect_owned()
+    }
+
+    #[inline]
+    fn apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        T::upcast_mut(self).apply(unbox::<T>(value))
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        match T::clone_boxed(self) {
+            Some(value) => Ok(Box::new(value)),
+            None => Err(ReflectCloneError::NotSupport {
+                type_path: Self::type_path(),
+            }),
+        }
+    }
+
+    #[inline]
+    fn to_dynamic(&self) -> Box<dyn Reflect> {
+        T::upcast(self).to_dynamic()
+    }
+
+    #[inline]
+    fn reflect_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        T::upcast(self).reflect_eq(unbox::<T>(other))
+    }
+
+    #[inline]
+    fn reflect_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        T::upcast(self).reflect_cmp(unbox::<T>(other))
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        T::upcast(self).reflect_hash()
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        T::upcast(self).reflect_debug(f)
+    }
+}
+
+impl<T: DynReflect + ?Sized> FromReflect for Box<T> {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let Some(value) = reflect.downcast_ref::<Self>() {
+            return T::clone_boxed(value);
+        }
+        let value = reflect
+            .reflect_clone()
+            .unwrap_or_else(|_| reflect.to_dynamic());
+        T::downcast_boxed(value, None).ok()
+    }
+
+    fn take_from_reflect(reflect: Box<dyn Reflect>) -> Result<Self, Box<dyn Reflect>> {
+        match reflect.downcast::<Self>() {
+            Ok(value) => Ok(*value),
+            Err(value) => T::downcast_boxed(value, None),
+        }
+    }
+}
+
+impl<T: DynReflect + ?Sized> GetTypeMeta for Box<T> {
+    fn get_type_meta() -> TypeMeta {
+        let mut meta = TypeMeta::with_capacity::<Self>(2);
+        meta.insert_trait::<ReflectFromReflect>(FromType::<Self>::from_type());
+        meta.insert_trait::<ReflectBox>(FromType::<Self>::from_type());
+        meta
+    }
+}
+
+// -----------------------------------------------------------------------------
+// dyn Reflect
+
+/// Registered as a dependency of types with `Box<dyn Reflect>` fields,
+/// so that the deserializer reads these fields with their type path.
+impl GetTypeMeta for dyn Reflect {
+    fn get_type_meta() -> TypeMeta {
+        let mut meta = TypeMeta::with_capacity::<Self>(1);
+        meta.insert_trait::<ReflectBox>(ReflectBox::pass_through());
+        meta
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+    use alloc::string::String;
+    use core::any::TypeId;
+
+    use crate::derive::reflect_trait;
+    use crate::info::{TypePath, Typed};
+    use crate::{FromReflect, Reflect};
+
+    #[reflect_trait]
+    trait Named: Reflect {
+        fn name(&self) -> &str;
+    }
+
+    #[derive(Reflect)]
+    #[reflect(type_trait = NamedFromReflect)]
+    struct Label(String);
+
+    impl Named for Label {
+        fn name(&self) -> &str {
+            &self.0
+        }
+    }
+
+    #[derive(Reflect)]
+    struct Holder {
+        named: Box<dyn Named>,
+        value: Box<dyn Reflect>,
+    }
+
+    #[test]
+    fn boxed_fields() {
+        assert_eq!(
+            <Box<dyn Named>>::type_path(),
+            concat!("alloc::boxed::Box<dyn ", module_path!(), "::Named>")
+        );
+        assert!(<Box<dyn Named>>::type_info().as_opaque().is_ok());
+
+        let holder = Holder {
+            named: Box::new(Label(String::from("a"))),
+            value: Box::new(String::from("b")),
+        };
+        let info = Holder::type_info().as_struct().unwrap();
+        assert_eq!(
+            info.field("value").unwrap().type_id(),
+            TypeId::of::<dyn Reflect>()
+        );
+
+        // Accessors see through the boxes.
+        let fields = holder.reflect_ref().as_struct().unwrap();
+        let named = fields.field("named").unwrap();
+        assert!(named.reflect_ref().as_tuple_struct().is_ok());
+        assert_eq!(
+            named.represented_type_info().unwrap().type_id(),
+            TypeId::of::<Label>()
+        );
+        let value = fields.field("value").unwrap();
+        assert!(value.reflect_eq(&String::from("b")).unwrap());
+
+        let cloned = Holder::from_reflect(&holder).unwrap();
+        assert_eq!(cloned.named.name(), "a");
+        assert_eq!(cloned.value.downcast_ref::<String>().unwrap(), "b");
+
+        // Trait objects cannot be cast from dynamic values without the registry.
+        assert!(Holder::from_reflect(&*holder.to_dynamic()).is_none());
+    }
+
+    #[test]
+    fn boxed_fields_serde() {
+        use serde_core::de::DeserializeSeed;
+
+        use crate::registry::TypeRegistry;
+        use crate::serde::{ReflectDeserializeDriver, ReflectSerializeDriver};
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Holder>();
+        registry.register::<Label>();
+
+        let holder = Holder {
+            named: Box::new(Label(String::from("a"))),
+            value: Box::new(7_u32),
+        };
+        let text = ron::to_string(&ReflectSerializeDriver::new(&holder, &registry)).unwrap();
+        assert!(text.contains(r#"named:{"vc_reflect::impls::alloc::boxed::tests::Label":("a")}"#));
+        assert!(text.contains(r#"value:{"u32":7}"#));
+
+        let mut de = ron::Deserializer::from_str(&text).unwrap();
+        let output = ReflectDeserializeDriver::new(&registry)
+            .deserialize(&mut de)
+            .unwrap()
+            .take::<Holder>()
+            .unwrap();
+        assert_eq!(output.named.name(), "a");
+        assert_eq!(output.value.downcast_ref::<u32>(), Some(&7));
+    }
+}
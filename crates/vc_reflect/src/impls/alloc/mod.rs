@@ -1,8 +1,12 @@
 mod binary_heap;
 mod borrow;
+mod boxed;
 mod btree_map;
 mod btree_set;
 mod string;
 mod sync;
 mod vec;
 mod vec_deque;
+
+pub use boxed::DynReflect;
+pub use sync::{SharedArc, SharedReflect};
@@ -1 +1 @@
-crate::derive::impl_reflect_opaque!(::alloc::string::String(full));
+crate::derive::impl_reflect_opaque!(::alloc::string::String(full, display));
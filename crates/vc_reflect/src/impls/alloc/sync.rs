@@ -1 +1,525 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::Deref;
+
+use crate::impls::{self, GenericTypePathCell};
+use crate::info::{GenericInfo, Generics, ReflectKind, TupleStructInfo, TypeInfo, TypeParamInfo};
+use crate::info::{TypePath, Typed, UnnamedField};
+use crate::ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef};
+use crate::ops::{TupleStruct, TupleStructFieldIter};
+use crate::registry::{FromType, GetTypeMeta, ReflectBox, ReflectFromPtr};
+use crate::registry::{ReflectFromReflect, ReflectProxy, TypeMeta, TypeRegistry};
+use crate::{FromReflect, Reflect};
+
 crate::derive::impl_reflect_opaque!(::alloc::sync::Arc<T: Send + Sync + ?Sized>(clone));
+
+// -----------------------------------------------------------------------------
+// SharedReflect
+
+/// A value that can be reflected behind a [`SharedArc`] pointer.
+///
+/// This is implemented for all sized reflected types, and for `dyn Reflect`.
+pub trait SharedReflect: Typed + Send + Sync + 'static {
+    /// Casts the value to `dyn Reflect`.
+    fn as_shared_reflect(&self) -> &dyn Reflect;
+
+    /// Casts the value to `dyn Reflect`.
+    fn as_shared_reflect_mut(&mut self) -> &mut dyn Reflect;
+
+    /// Moves a reflected value into a new `Arc`.
+    ///
+    /// The value is returned unchanged if it cannot be converted.
+    fn into_arc(value: Box<dyn Reflect>) -> Result<Arc<Self>, Box<dyn Reflect>>;
+
+    /// Creates a new `Arc` from a reflected value.
+    fn from_reflect_arc(value: &dyn Reflect) -> Option<Arc<Self>>;
+}
+
+impl<T: Typed + FromReflect> SharedReflect for T {
+    #[inline]
+    fn as_shared_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_shared_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn into_arc(value: Box<dyn Reflect>) -> Result<Arc<Self>, Box<dyn Reflect>> {
+        match value.downcast::<T>() {
+            Ok(value) => Ok(Arc::from(value)),
+            Err(value) => T::take_from_reflect(value).map(Arc::new),
+        }
+    }
+
+    #[inline]
+    fn from_reflect_arc(value: &dyn Reflect) -> Option<Arc<Self>> {
+        T::from_reflect(value).map(Arc::new)
+    }
+}
+
+impl SharedReflect for dyn Reflect {
+    #[inline]
+    fn as_shared_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_shared_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn into_arc(value: Box<dyn Reflect>) -> Result<Arc<Self>, Box<dyn Reflect>> {
+        Ok(Arc::from(value))
+    }
+
+    fn from_reflect_arc(value: &dyn Reflect) -> Option<Arc<Self>> {
+        let value = value.reflect_clone().unwrap_or_else(|_| value.to_dynamic());
+        Some(Arc::from(value))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// SharedArc
+
+/// An [`Arc`] whose value is visible to reflection.
+///
+/// A plain `Arc<T>` is reflected as an opaque value, for any `T`. Wrapping
+/// it in `SharedArc` reflects it as a tuple-struct with a single field `0` of
+/// type `T`, so path access and serialization can see the value inside:
+///
+/// - [`Reflect::reflect_ref`] and [`TupleStruct::field`] always give access
+///   to the value.
+/// - [`Reflect::reflect_mut`] and [`TupleStruct::field_mut`] only do so if
+///   the `Arc` is uniquely referenced. Otherwise, `reflect_mut` returns
+///   [`ReflectMut::Opaque`], `field_mut` returns `None` and [`Reflect::apply`]
+///   fails, the shared value is never modified or copied behind the back of
+///   other references.
+/// - [`Reflect::reflect_clone`] clones the `Arc`, not the value inside.
+/// - `SharedArc<T>` is serialized as `T` through [`ReflectProxy`], and
+///   `SharedArc<dyn Reflect>` together with the type path of the value inside,
+///   like `Box<dyn Reflect>`, through [`ReflectBox`].
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use vc_reflect::Reflect;
+/// use vc_reflect::access::ReflectPathAccess;
+/// use vc_reflect::impls::SharedArc;
+///
+/// #[derive(Reflect)]
+/// struct Mesh {
+///     vertices: Vec<[f32; 3]>,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Model {
+///     mesh: SharedArc<Mesh>,
+/// }
+///
+/// let mut model = Model {
+///     mesh: SharedArc::new(Mesh { vertices: vec![[0.0; 3]; 3] }),
+/// };
+///
+/// let len = model.access_as::<Vec<[f32; 3]>>(".mesh.0.vertices").unwrap().len();
+/// assert_eq!(len, 3);
+///
+/// // The mesh is unique, so it can be modified in place.
+/// model.access_mut_as::<Vec<[f32; 3]>>(".mesh.0.vertices").unwrap().clear();
+/// assert!(model.mesh.vertices.is_empty());
+///
+/// // Once shared, it is read-only.
+/// let shared = model.mesh.clone();
+/// assert!(model.access_mut(".mesh.0.vertices").is_err());
+/// assert!(Arc::ptr_eq(&shared.0, &model.mesh.0));
+/// ```
+#[repr(transparent)]
+pub struct SharedArc<T: ?Sized>(pub Arc<T>);
+
+impl<T> SharedArc<T> {
+    /// Moves `value` into a new `Arc`.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Self(Arc::new(value))
+    }
+}
+
+impl<T: ?Sized> SharedArc<T> {
+    /// Returns the `Arc` inside.
+    #[inline]
+    pub fn into_arc(self) -> Arc<T> {
+        self.0
+    }
+}
+
+impl<T: ?Sized> Clone for SharedArc<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ?Sized> Deref for SharedArc<T> {
+    type Target = Arc<T>;
+
+    #[inline]
+    fn deref(&self) -> &Arc<T> {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> From<Arc<T>> for SharedArc<T> {
+    #[inline]
+    fn from(value: Arc<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: fmt::Debug + ?Sized> fmt::Debug for SharedArc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SharedArc").field(&self.0).finish()
+    }
+}
+
+impl<T: TypePath + ?Sized> TypePath for SharedArc<T> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self>(|| {
+            impls::concat(&["vc_reflect::impls::SharedArc<", T::type_path(), ">"])
+        })
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self>(|| impls::concat(&["SharedArc<", T::type_name(), ">"]))
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "SharedArc"
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("vc_reflect::impls")
+    }
+}
+
+impl<T: SharedReflect + ?Sized> SharedArc<T> {
+    /// Returns the value inside `value`, if it is or represents a `SharedArc<T>`.
+    fn unshare(value: &dyn Reflect) -> &dyn Reflect {
+        if let Some(value) = value.downcast_ref::<Self>() {
+            return T::as_shared_reflect(&value.0);
+        }
+        if value.represents::<Self>()
+            && let ReflectRef::TupleStruct(value) = value.reflect_ref()
+            && let Some(field) = value.field(0)
+        {
+            return field;
+        }
+        value
+    }
+}
+
+impl<T: SharedReflect + ?Sized> Typed for SharedArc<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: impls::GenericTypeInfoCell = impls::GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self>(|| {
+            TypeInfo::TupleStruct(
+                TupleStructInfo::new::<Self>(&[UnnamedField::new::<T>(0)]).with_generics(
+                    Generics::from([GenericInfo::Type(TypeParamInfo::new::<T>("T"))]),
+                ),
+            )
+        })
+    }
+}
+
+impl<T: SharedReflect + ?Sized> TupleStruct for SharedArc<T> {
+    #[inline]
+    fn field(&self, index: usize) -> Option<&dyn Reflect> {
+        (index == 0).then(|| T::as_shared_reflect(&self.0))
+    }
+
+    #[inline]
+    fn field_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        if index != 0 {
+            return None;
+        }
+        Arc::get_mut(&mut self.0).map(T::as_shared_reflect_mut)
+    }
+
+    #[inline]
+    fn field_len(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn iter_fields(&self) -> TupleStructFieldIter<'_> {
+        TupleStructFieldIter::new(self)
+    }
+}
+
+impl<T: SharedReflect + ?Sized> Reflect for SharedArc<T> {
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = Self::take_from_reflect(value)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::TupleStruct
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::TupleStruct(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        if Arc::get_mut(&mut self.0).is_some() {
+            ReflectMut::TupleStruct(self)
+        } else {
+            ReflectMut::Opaque(self)
+        }
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::TupleStruct(self)
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        let value = Self::unshare(value);
+        match Arc::get_mut(&mut self.0) {
+            Some(this) => T::as_shared_reflect_mut(this).apply(value),
+            None => Err(ApplyError::NotSupport {
+                type_path: Self::type_path(),
+            }),
+        }
+    }
+
+    #[inline]
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.clone()))
+    }
+
+    #[inline]
+    fn reflect_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        T::as_shared_reflect(&self.0).reflect_eq(Self::unshare(other))
+    }
+
+    #[inline]
+    fn reflect_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        T::as_shared_reflect(&self.0).reflect_cmp(Self::unshare(other))
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        T::as_shared_reflect(&self.0).reflect_hash()
+    }
+
+    fn reflect_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SharedArc(")?;
+        T::as_shared_reflect(&self.0).reflect_debug(f)?;
+        f.write_str(")")
+    }
+}
+
+impl<T: SharedReflect + ?Sized> FromReflect for SharedArc<T> {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        match reflect.downcast_ref::<Self>() {
+            Some(value) => Some(value.clone()),
+            None => T::from_reflect_arc(Self::unshare(reflect)).map(Self),
+        }
+    }
+
+    fn take_from_reflect(reflect: Box<dyn Reflect>) -> Result<Self, Box<dyn Reflect>> {
+        match reflect.downcast::<Self>() {
+            Ok(value) => Ok(*value),
+            Err(value) if value.represents::<Self>() => {
+                Self::from_reflect(value.as_ref()).ok_or(value)
+            }
+            Err(value) => T::into_arc(value).map(Self),
+        }
+    }
+}
+
+impl<T: Typed + FromReflect + GetTypeMeta> GetTypeMeta for SharedArc<T> {
+    fn get_type_meta() -> TypeMeta {
+        let mut meta = TypeMeta::with_capacity::<Self>(3);
+        meta.insert_trait::<ReflectFromPtr>(FromType::<Self>::from_type());
+        meta.insert_trait::<ReflectFromReflect>(FromType::<Self>::from_type());
+        meta.insert_trait::<ReflectProxy>(FromType::<Self>::from_type());
+        meta
+    }
+
+    fn register_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+}
+
+impl GetTypeMeta for SharedArc<dyn Reflect> {
+    fn get_type_meta() -> TypeMeta {
+        let mut meta = TypeMeta::with_capacity::<Self>(2);
+        meta.insert_trait::<ReflectFromReflect>(FromType::<Self>::from_type());
+        meta.insert_trait::<ReflectBox>(FromType::<Self>::from_type());
+        meta
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+    use alloc::string::String;
+    use alloc::sync::Arc;
+
+    use super::SharedArc;
+    use crate::access::ReflectPathAccess;
+    use crate::info::{ReflectKind, TypePath, Typed};
+    use crate::registry::TypeRegistry;
+    use crate::{FromReflect, Reflect};
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(clone, eq)]
+    struct Asset {
+        name: String,
+        size: u32,
+    }
+
+    #[derive(Reflect)]
+    struct Holder {
+        asset: SharedArc<Asset>,
+        any: SharedArc<dyn Reflect>,
+        opaque: Arc<Asset>,
+    }
+
+    fn holder() -> Holder {
+        Holder {
+            asset: SharedArc::new(Asset {
+                name: String::from("a"),
+                size: 1,
+            }),
+            any: SharedArc(Arc::new(7_u32)),
+            opaque: Arc::new(Asset {
+                name: String::from("b"),
+                size: 2,
+            }),
+        }
+    }
+
+    #[test]
+    fn see_through() {
+        assert_eq!(
+            <SharedArc<Asset>>::type_path(),
+            concat!("vc_reflect::impls::SharedArc<", module_path!(), "::Asset>")
+        );
+        assert_eq!(
+            <SharedArc<dyn Reflect>>::type_name(),
+            "SharedArc<dyn Reflect>"
+        );
+        assert_eq!(
+            <SharedArc<Asset>>::type_info().kind(),
+            holder().asset.reflect_kind()
+        );
+
+        let holder = holder();
+        assert_eq!(*holder.access_as::<u32>(".asset.0.size").unwrap(), 1);
+        let any = holder.access(".any.0").unwrap();
+        assert!(any.reflect_eq(&7_u32).unwrap());
+        assert!(holder.access(".opaque.size").is_err());
+
+        let cloned = Holder::from_reflect(&holder).unwrap();
+        assert!(Arc::ptr_eq(&cloned.asset.0, &holder.asset.0));
+        let rebuilt = Holder::from_reflect(&*holder.to_dynamic()).unwrap();
+        assert_eq!(**rebuilt.asset, **holder.asset);
+        assert!(rebuilt.any.reflect_eq(&7_u32).unwrap());
+    }
+
+    #[test]
+    fn mutate_unique() {
+        let mut holder = holder();
+        *holder.access_mut_as::<u32>(".asset.0.size").unwrap() = 3;
+        assert_eq!(holder.asset.size, 3);
+
+        let mut patched = Asset::clone(&holder.asset);
+        patched.size = 4;
+        holder.asset.apply(&patched).unwrap();
+        assert_eq!(holder.asset.size, 4);
+    }
+
+    #[test]
+    fn shared_is_read_only() {
+        let mut holder = holder();
+        let shared = holder.asset.clone();
+
+        assert_eq!(holder.asset.reflect_mut().kind(), ReflectKind::Opaque);
+        assert!(holder.access_mut(".asset.0.size").is_err());
+        assert!(holder.asset.apply(&*shared.0).is_err());
+        assert!(Arc::ptr_eq(&holder.asset.0, &shared.0));
+
+        // Replacing the `Arc` is not a mutation of the shared value.
+        let replaced = Asset {
+            name: String::from("c"),
+            size: 5,
+        };
+        holder.asset.set(Box::new(replaced)).unwrap();
+        assert_eq!(holder.asset.size, 5);
+        assert_eq!(shared.size, 1);
+    }
+
+    #[test]
+    fn opaque() {
+        let text: Arc<str> = Arc::from("text");
+        assert_eq!(text.reflect_kind(), ReflectKind::Opaque);
+        assert_eq!(<Arc<str>>::type_path(), "alloc::sync::Arc<str>");
+
+        let cloned = <Arc<str>>::from_reflect(&text).unwrap();
+        assert!(Arc::ptr_eq(&cloned, &text));
+
+        let asset = holder().opaque;
+        assert_eq!(asset.reflect_kind(), ReflectKind::Opaque);
+        assert_eq!(<Arc<Asset>>::type_info().kind(), ReflectKind::Opaque);
+    }
+
+    #[test]
+    fn serde() {
+        use serde_core::de::DeserializeSeed;
+
+        use crate::serde::{ReflectDeserializeDriver, ReflectSerializeDriver};
+
+        #[derive(Reflect)]
+        struct Scene {
+            asset: SharedArc<Asset>,
+            any: SharedArc<dyn Reflect>,
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Scene>();
+
+        let holder = holder();
+        let scene = Scene {
+            asset: holder.asset.clone(),
+            any: holder.any.clone(),
+        };
+        let text = ron::to_string(&ReflectSerializeDriver::new(&scene, &registry)).unwrap();
+        assert!(text.contains(r#"asset:(name:"a",size:1)"#));
+        assert!(text.contains(r#"any:{"u32":7}"#));
+
+        let mut de = ron::Deserializer::from_str(&text).unwrap();
+        let output: Box<dyn Reflect> = ReflectDeserializeDriver::new(&registry)
+            .deserialize(&mut de)
+            .unwrap();
+        let output = output.take::<Scene>().unwrap();
+        assert_eq!(**output.asset, **scene.asset);
+        assert_eq!(output.any.downcast_ref::<u32>(), Some(&7));
+    }
+}
@@ -126,6 +126,17 @@ impl<T: Typed + FromReflect> List for Vec<T> {
             .map(Reflect::into_boxed_reflect)
             .collect()
     }
+
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect) -> bool) {
+        Self::retain(self, |value| f(value));
+    }
+
+    fn split_off(&mut self, index: usize) -> Vec<Box<dyn Reflect>> {
+        Self::split_off(self, index)
+            .into_iter()
+            .map(Reflect::into_boxed_reflect)
+            .collect()
+    }
 }
 
 impl<T: Typed + FromReflect> FromReflect for Vec<T> {
@@ -123,6 +123,17 @@ impl<T: Typed + FromReflect> List for VecDeque<T> {
     fn drain(&mut self) -> Vec<Box<dyn Reflect>> {
         self.drain(..).map(Reflect::into_boxed_reflect).collect()
     }
+
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect) -> bool) {
+        Self::retain(self, |value| f(value));
+    }
+
+    fn split_off(&mut self, index: usize) -> Vec<Box<dyn Reflect>> {
+        Self::split_off(self, index)
+            .into_iter()
+            .map(Reflect::into_boxed_reflect)
+            .collect()
+    }
 }
 
 impl<T: Typed + FromReflect + GetTypeMeta> GetTypeMeta for VecDeque<T> {
@@ -7,8 +7,10 @@
 
 use alloc::string::String;
 use core::any::{Any, TypeId};
+use core::ptr;
 
-use vc_os::sync::{OnceLock, PoisonError, RwLock};
+use vc_os::sync::atomic::{AtomicPtr, Ordering};
+use vc_os::sync::{PoisonError, RwLock};
 use vc_utils::extra::TypeIdMap;
 
 use crate::info::TypeInfo;
@@ -23,6 +25,16 @@ use crate::info::TypeInfo;
 /// Notr: There is no `NonGenericTypePathCell` because it can be replaced
 /// by a static string literal.
 ///
+/// ## Recursive types
+///
+/// The initializer runs without holding any lock, so it may request its own
+/// type information again, e.g. through the `TypeInfo` of a `Vec<Self>` field.
+/// The inner call builds and stores the info, the outer one then returns it.
+///
+/// This terminates because field, item and variant infos only keep thunks
+/// (`fn() -> &'static TypeInfo`), which are called on first access rather
+/// than when the containing `TypeInfo` is built.
+///
 /// ## Example
 ///
 /// ```ignore
@@ -47,20 +59,23 @@ use crate::info::TypeInfo;
 /// assert_eq!(info.field("a").unwrap().type_path(), "u32");
 /// assert_eq!(info.type_name(), "A2");
 /// ```
-pub struct NonGenericTypeInfoCell(OnceLock<&'static TypeInfo>);
+pub struct NonGenericTypeInfoCell(AtomicPtr<TypeInfo>);
 
+#[expect(unsafe_code, reason = "the pointer always comes from the leaked pool")]
 impl NonGenericTypeInfoCell {
     /// Create a empty cell.
     ///
     /// See [`NonGenericTypeInfoCell`].
     #[inline]
     pub const fn new() -> Self {
-        Self(OnceLock::new())
+        Self(AtomicPtr::new(ptr::null_mut()))
     }
 
     /// Returns a reference to the `Info` stored in the cell.
     ///
     /// If there is no entry found, a new one will be generated from the given function.
+    /// If another thread, or a recursive call, stores its info first, that one is
+    /// returned instead and the value of `f` is discarded.
     ///
     /// See [`NonGenericTypeInfoCell`].
     #[inline]
@@ -68,7 +83,28 @@ impl NonGenericTypeInfoCell {
     where
         F: FnOnce() -> TypeInfo,
     {
-        self.0.get_or_init(|| pool::leak_info(f()))
+        let info = self.0.load(Ordering::Acquire);
+        if info.is_null() {
+            self.init(f())
+        } else {
+            // SAFETY: Non-null pointers are leaked infos, valid for `'static`.
+            unsafe { &*info }
+        }
+    }
+
+    // Separate to reduce code compilation times
+    #[cold]
+    #[inline(never)]
+    fn init(&self, info: TypeInfo) -> &TypeInfo {
+        let info = ptr::from_ref(pool::leak_info(info)).cast_mut();
+        match self
+            .0
+            .compare_exchange(ptr::null_mut(), info, Ordering::AcqRel, Ordering::Acquire)
+        {
+            // SAFETY: Non-null pointers are leaked infos, valid for `'static`.
+            Ok(_) => unsafe { &*info },
+            Err(stored) => unsafe { &*stored },
+        }
     }
 }
 
@@ -77,6 +113,9 @@ impl NonGenericTypeInfoCell {
 
 /// Container for static storage of type information with generics.
 ///
+/// Like [`NonGenericTypeInfoCell`], the initializer runs without holding the
+/// lock, so recursive types are supported.
+///
 /// ## Example
 ///
 /// ```ignore
@@ -245,3 +284,130 @@ mod pool {
         }
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeMap;
+    use alloc::string::String;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::ptr;
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    use super::NonGenericTypeInfoCell;
+    use crate::info::{OpaqueInfo, TypeInfo, TypePath, Typed};
+    use crate::registry::TypeRegistry;
+    use crate::{FromReflect, Reflect};
+
+    #[derive(Reflect, Clone, Default, Debug, PartialEq)]
+    struct Node {
+        value: u32,
+        children: Vec<Node>,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum List {
+        Cons(i32, Vec<List>),
+        Nil,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Tree<T: Clone> {
+        value: T,
+        children: Vec<Tree<T>>,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Parent {
+        children: BTreeMap<String, Child>,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Child {
+        parent: Option<Parent>,
+    }
+
+    fn item_of<'a>(info: &'a TypeInfo, field: &str) -> &'a TypeInfo {
+        let field = info.as_struct().unwrap().field(field).unwrap();
+        field.type_info().as_list().unwrap().item_info()
+    }
+
+    #[test]
+    fn recursive_types() {
+        assert!(ptr::eq(
+            item_of(Node::type_info(), "children"),
+            Node::type_info()
+        ));
+        assert!(ptr::eq(
+            item_of(<Tree<u8>>::type_info(), "children"),
+            <Tree<u8>>::type_info()
+        ));
+
+        let cons = List::type_info()
+            .as_enum()
+            .unwrap()
+            .variant("Cons")
+            .unwrap();
+        let tail = cons.as_tuple_variant().unwrap().field_at(1).unwrap();
+        let tail = tail.type_info().as_list().unwrap().item_info();
+        assert!(ptr::eq(tail, List::type_info()));
+
+        let child = Parent::type_info()
+            .as_struct()
+            .unwrap()
+            .field("children")
+            .unwrap();
+        let child = child.type_info().as_map().unwrap().value_info();
+        let parent = child.as_struct().unwrap().field("parent").unwrap();
+        assert_eq!(
+            parent.type_info().type_path(),
+            <Option<Parent>>::type_path()
+        );
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Node>();
+        registry.register::<List>();
+        registry.register::<Tree<u8>>();
+        registry.register::<Parent>();
+        assert!(registry.contains(core::any::TypeId::of::<Child>()));
+        assert!(registry.contains(core::any::TypeId::of::<Vec<Tree<u8>>>()));
+
+        let tree = Tree {
+            value: 1_u8,
+            children: vec![Tree {
+                value: 2,
+                children: Vec::new(),
+            }],
+        };
+        let dynamic = tree.to_dynamic();
+        assert_eq!(<Tree<u8>>::from_reflect(&*dynamic), Some(tree.clone()));
+        assert!(tree.reflect_eq(&*dynamic).unwrap());
+
+        let list = List::Cons(1, vec![List::Cons(2, vec![List::Nil])]);
+        let cloned = list.reflect_clone().unwrap().take::<List>().unwrap();
+        assert_eq!(cloned, list);
+    }
+
+    #[test]
+    fn recursive_init() {
+        static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+        static REENTERED: AtomicBool = AtomicBool::new(false);
+
+        fn type_info() -> &'static TypeInfo {
+            CELL.get_or_init(|| {
+                // Requests its own info once while being initialized.
+                if !REENTERED.swap(true, Ordering::Relaxed) {
+                    let _ = type_info();
+                }
+                TypeInfo::Opaque(OpaqueInfo::new::<u8>())
+            })
+        }
+
+        let info = type_info();
+        assert!(REENTERED.load(Ordering::Relaxed));
+        assert!(ptr::eq(info, type_info()));
+    }
+}
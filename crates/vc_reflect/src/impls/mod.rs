@@ -14,8 +14,8 @@
 //!
 //! - basic:
 //!     - `i8`-`i128`, `u8`-`u128`, `isize`, `usize`, `f32`, `f64`
-//!     - `()`, `(P0,)`, `(P0, P1, ...)`. the num of P <= 12
-//!     - `[T; N]`
+//!     - `()`, `(P0,)`, `(P0, P1, ...)`. the num of P <= 16
+//!     - `[T; N]` for any `N`
 //!     - `&'static str`, `String`
 //! - core:
 //!     - `Atomic`: Ordering, I8-I64, U8-U64, Isize, Usize (without Ptr)
@@ -30,7 +30,8 @@
 //!     - `String`, `Vec<T>`, `VecDeque<T>`
 //!     - `Cow<'static, str>`, `Cow<'static, [T]>`
 //!     - `BTreeMap<K, V>`, `BTreeSet<T>`
-//!     - `Arc` (without `Box`)
+//!     - `Arc<T>` as opaque, [`SharedArc<T>`] to reflect the value inside
+//!     - `Box<dyn Trait>` for reflected traits, see [`DynReflect`]
 //! - std: ("std" feature)
 //!     - `OsString` `PathBuf`
 //!     - `HashMap` `HashSet`
@@ -42,6 +43,8 @@
 //!     - `time::Instant`
 //!
 //! [`concat`]: crate::impls::concat
+//! [`DynReflect`]: crate::impls::DynReflect
+//! [`SharedArc<T>`]: crate::impls::SharedArc
 //! [`Reflect::reflect_cmp`]: crate::Reflect::reflect_cmp
 //! [`Reflect::reflect_eq`]: crate::Reflect::reflect_eq
 //! [`Reflect::reflect_debug`]: crate::Reflect::reflect_debug
@@ -67,6 +70,7 @@ crate::cfg::std! { mod std; }
 // -----------------------------------------------------------------------------
 // Exports
 
+pub use self::alloc::{DynReflect, SharedArc, SharedReflect};
 pub use cell::{GenericTypeInfoCell, GenericTypePathCell, NonGenericTypeInfoCell};
 
 pub use utils::*;
@@ -51,9 +51,14 @@ impl<T: TypePath, const N: usize> TypePath for [T; N] {
 }
 
 impl<T: Reflect + Typed, const N: usize> Typed for [T; N] {
+    #[expect(unsafe_code, reason = "Store the layout of the array.")]
     fn type_info() -> &'static TypeInfo {
         static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
-        CELL.get_or_insert::<Self>(|| TypeInfo::Array(ArrayInfo::new::<Self, T>(N)))
+        CELL.get_or_insert::<Self>(|| {
+            let info = ArrayInfo::new::<Self, T>(N);
+            // SAFETY: the items of `[T; N]` are stored contiguously.
+            TypeInfo::Array(unsafe { info.with_item_stride(size_of::<T>()) })
+        })
     }
 }
 
@@ -1,4 +1,4 @@
-//! Implement reflection traits for tuples with a field count of 12 or less.
+//! Implement reflection traits for tuples with a field count of 16 or less.
 //!
 //! - [`TypePath`] -> [`DynamicTypePath`]
 //! - [`Typed`] -> [`DynamicTyped`]
@@ -93,7 +93,7 @@ macro_rules! impl_type_path_tuple {
     };
 }
 
-range_invoke!(impl_type_path_tuple, 12);
+range_invoke!(impl_type_path_tuple, 16);
 
 macro_rules! impl_reflect_tuple {
     (0: []) => {
@@ -208,20 +208,22 @@ macro_rules! impl_reflect_tuple {
     };
     (1 : [ $index:tt : $name:ident ]) => {
         #[cfg_attr(docsrs, doc(fake_variadic))]
-        #[cfg_attr(docsrs, doc = "This trait is implemented for tuples up to 12 items long.")]
+        #[cfg_attr(docsrs, doc = "This trait is implemented for tuples up to 16 items long.")]
         impl<$name: Reflect + Typed> Typed for ($name,) {
+            #[expect(unsafe_code, reason = "Store the layout of the tuple.")]
             fn type_info() -> &'static TypeInfo {
                 static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
                 CELL.get_or_insert::<Self>(|| {
-                    TypeInfo::Tuple(TupleInfo::new::<Self>(&[
-                        UnnamedField::new::<$name>($index)
-                    ]))
+                    let field = UnnamedField::new::<$name>($index);
+                    // SAFETY: the offset of the field `$index` of type `$name`.
+                    let field = unsafe { field.with_offset(core::mem::offset_of!(Self, $index)) };
+                    TypeInfo::Tuple(TupleInfo::new::<Self>(&[field]))
                 })
             }
         }
 
         #[cfg_attr(docsrs, doc(fake_variadic))]
-        #[cfg_attr(docsrs, doc = "This trait is implemented for tuples up to 12 items long.")]
+        #[cfg_attr(docsrs, doc = "This trait is implemented for tuples up to 16 items long.")]
         impl<$name: Reflect + Typed> Tuple for ($name,) {
             #[inline]
             fn field(&self, index: usize) -> Option<&dyn Reflect> {
@@ -258,7 +260,7 @@ macro_rules! impl_reflect_tuple {
         }
 
         #[cfg_attr(docsrs, doc(fake_variadic))]
-        #[cfg_attr(docsrs, doc = "This trait is implemented for tuples up to 12 items long.")]
+        #[cfg_attr(docsrs, doc = "This trait is implemented for tuples up to 16 items long.")]
         impl<$name: Reflect + Typed> Reflect for ($name,) {
             crate::reflection::impl_reflect_cast_fn!(Tuple);
             #[inline]
@@ -296,7 +298,7 @@ macro_rules! impl_reflect_tuple {
         }
 
         #[cfg_attr(docsrs, doc(fake_variadic))]
-        #[cfg_attr(docsrs, doc = "This trait is implemented for tuples up to 12 items long.")]
+        #[cfg_attr(docsrs, doc = "This trait is implemented for tuples up to 16 items long.")]
         impl<$name: Reflect + Typed + GetTypeMeta> GetTypeMeta for ($name,) {
             fn get_type_meta() -> TypeMeta {
                 let mut type_meta =  TypeMeta::with_capacity::<($name,)>(1);
@@ -310,7 +312,7 @@ macro_rules! impl_reflect_tuple {
         }
 
         #[cfg_attr(docsrs, doc(fake_variadic))]
-        #[cfg_attr(docsrs, doc = "This trait is implemented for tuples up to 12 items long.")]
+        #[cfg_attr(docsrs, doc = "This trait is implemented for tuples up to 16 items long.")]
         impl<$name: FromReflect + Typed> FromReflect for ($name,) {
             fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
                 let _ref_tuple = reflect.reflect_ref().as_tuple().ok()?;
@@ -326,12 +328,15 @@ macro_rules! impl_reflect_tuple {
     ($num:literal : [$($index:tt : $name:ident),*]) => {
         #[cfg_attr(docsrs, doc(hidden))]
         impl<$($name: Reflect + Typed),*> Typed for ($($name,)*) {
+            #[expect(unsafe_code, reason = "Store the layout of the tuple.")]
             fn type_info() -> &'static TypeInfo {
                 static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
                 CELL.get_or_insert::<Self>(|| {
-                    let fields = [
-                        $(UnnamedField::new::<$name>($index),)*
-                    ];
+                    // SAFETY: the offsets of the fields `$index` of type `$name`.
+                    let fields = unsafe {
+                        [$(UnnamedField::new::<$name>($index)
+                            .with_offset(core::mem::offset_of!(Self, $index)),)*]
+                    };
                     let info = TupleInfo::new::<Self>(&fields);
                     TypeInfo::Tuple(info)
                 })
@@ -446,6 +451,6 @@ macro_rules! impl_reflect_tuple {
     };
 }
 
-range_invoke!(impl_reflect_tuple, 12);
+range_invoke!(impl_reflect_tuple, 16);
 
 crate::derive::impl_auto_register!(());
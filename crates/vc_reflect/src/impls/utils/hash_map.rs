@@ -119,6 +119,13 @@ macro_rules! impl_reflect_for_hashmap {
                 Self::retain(self, move |key, value| f(key, value));
             }
 
+            fn extend_from(&mut self, other: $crate::ops::DynamicMap) {
+                Self::reserve(self, $crate::ops::Map::len(&other));
+                for (key, value) in other {
+                    <Self as $crate::ops::Map>::insert(self, key, value);
+                }
+            }
+
             fn insert(
                 &mut self,
                 key: ::alloc::boxed::Box<dyn $crate::Reflect>,
@@ -362,6 +369,13 @@ macro_rules! impl_reflect_for_fixedhashmap {
                 Self::retain(self, move |key, value| f(key, value));
             }
 
+            fn extend_from(&mut self, other: $crate::ops::DynamicMap) {
+                Self::reserve(self, $crate::ops::Map::len(&other));
+                for (key, value) in other {
+                    <Self as $crate::ops::Map>::insert(self, key, value);
+                }
+            }
+
             fn insert(
                 &mut self,
                 key: ::alloc::boxed::Box<dyn $crate::Reflect>,
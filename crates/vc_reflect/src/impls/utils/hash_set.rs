@@ -106,6 +106,13 @@ macro_rules! impl_reflect_for_hashset {
                 Self::retain(self, move |value| f(value));
             }
 
+            fn extend_from(&mut self, other: $crate::ops::DynamicSet) {
+                Self::reserve(self, $crate::ops::Set::len(&other));
+                for value in other {
+                    <Self as $crate::ops::Set>::insert(self, value);
+                }
+            }
+
             fn insert(&mut self, value: ::alloc::boxed::Box<dyn $crate::Reflect>) -> bool {
                 let value = T::take_from_reflect(value).unwrap_or_else(|value| panic!(
                     "Attempted to insert invalid value of type {}.",
@@ -301,6 +308,13 @@ macro_rules! impl_reflect_for_fixedhashset {
                 Self::retain(self, move |value| f(value));
             }
 
+            fn extend_from(&mut self, other: $crate::ops::DynamicSet) {
+                Self::reserve(self, $crate::ops::Set::len(&other));
+                for value in other {
+                    <Self as $crate::ops::Set>::insert(self, value);
+                }
+            }
+
             fn insert(&mut self, value: ::alloc::boxed::Box<dyn $crate::Reflect>) -> bool {
                 let value = T::take_from_reflect(value).unwrap_or_else(|value| {
                     panic!(
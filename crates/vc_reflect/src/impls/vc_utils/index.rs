@@ -9,7 +9,7 @@ use crate::derive::impl_type_path;
 use crate::impls::GenericTypeInfoCell;
 use crate::info::{GenericInfo, Generics, TypeParamInfo};
 use crate::info::{MapInfo, SetInfo, TypeInfo, TypePath, Typed};
-use crate::ops::{ApplyError, Map, ReflectCloneError, Set};
+use crate::ops::{ApplyError, DynamicMap, DynamicSet, Map, ReflectCloneError, Set};
 use crate::registry::{FromType, GetTypeMeta, TypeMeta, TypeRegistry};
 use crate::registry::{ReflectDefault, ReflectFromPtr, ReflectFromReflect};
 use crate::{FromReflect, Reflect};
@@ -123,6 +123,13 @@ where
         Self::retain(self, move |value| f(value));
     }
 
+    fn extend_from(&mut self, other: DynamicSet) {
+        Self::reserve(self, Set::len(&other));
+        for value in other {
+            <Self as Set>::insert(self, value);
+        }
+    }
+
     fn insert(&mut self, value: Box<dyn Reflect>) -> bool {
         let value = T::take_from_reflect(value).unwrap_or_else(|value| {
             panic!(
@@ -325,6 +332,13 @@ where
         Self::retain(self, move |key, value| f(key, value));
     }
 
+    fn extend_from(&mut self, other: DynamicMap) {
+        Self::reserve(self, Map::len(&other));
+        for (key, value) in other {
+            <Self as Map>::insert(self, key, value);
+        }
+    }
+
     fn insert(
         &mut self,
         key: Box<dyn Reflect>,
@@ -33,6 +33,7 @@ pub struct ArrayInfo {
     item_id: TypeId,
     // `TypeInfo` is created on the first visit, use function pointers to delay it.
     item_info: fn() -> &'static TypeInfo,
+    item_stride: Option<usize>,
     len: usize,
     #[cfg(feature = "reflect_docs")]
     docs: Option<&'static str>,
@@ -62,12 +63,42 @@ impl ArrayInfo {
             generics: Generics::new(),
             item_id: TypeId::of::<TItem>(),
             item_info: TItem::type_info,
+            item_stride: None,
             len,
             #[cfg(feature = "reflect_docs")]
             docs: None,
         }
     }
 
+    /// Replaces the stored item stride.
+    ///
+    /// This is used by the implementation for `[T; N]`, there is usually no
+    /// reason to call it by hand.
+    ///
+    /// # Safety
+    ///
+    /// The items must be stored contiguously from the start of the array type,
+    /// `stride` bytes apart, e.g. `size_of::<T>()` for `[T; N]`. [`CompiledPath`]
+    /// dereferences the offsets without checking them.
+    ///
+    /// [`CompiledPath`]: crate::access::CompiledPath
+    #[doc(hidden)]
+    #[inline]
+    #[expect(unsafe_code, reason = "The caller guarantees the layout.")]
+    pub unsafe fn with_item_stride(self, stride: usize) -> Self {
+        Self {
+            item_stride: Some(stride),
+            ..self
+        }
+    }
+
+    /// Returns the distance in bytes between two items, if the items are
+    /// stored contiguously, e.g. `Some(size_of::<T>())` for `[T; N]`.
+    #[inline]
+    pub const fn item_stride(&self) -> Option<usize> {
+        self.item_stride
+    }
+
     /// The compile-time length of the array.
     #[inline]
     pub const fn len(&self) -> usize {
@@ -1,4 +1,5 @@
 use alloc::boxed::Box;
+use alloc::vec::Vec;
 use core::any::TypeId;
 
 use vc_utils::extra::TypeIdMap;
@@ -15,6 +16,9 @@ use crate::Reflect;
 /// Attributes are stored by their [`TypeId`].
 /// Because of this, there can only be one attribute per type.
 ///
+/// Keyed attributes, created by `#[reflect(@key = expr)]`, are stored by their
+/// key instead, so several attributes of the same type can coexist.
+///
 /// # Example
 ///
 /// ```
@@ -38,10 +42,28 @@ use crate::Reflect;
 /// let attrs = field.custom_attributes();
 /// assert!(attrs.is_empty());
 /// ```
+///
+/// Keyed attributes:
+///
+/// ```
+/// # use vc_reflect::{Reflect, info::Typed};
+/// #[derive(Reflect)]
+/// struct Volume {
+///     #[reflect(@min = 0.0f32, @max = 1.0f32)]
+///     value: f32,
+/// }
+///
+/// let info = <Volume as Typed>::type_info().as_struct().unwrap();
+/// let field = info.field("value").unwrap();
+///
+/// assert_eq!(field.get_keyed_attribute::<f32>("min"), Some(&0.0));
+/// assert_eq!(field.get_keyed_attribute::<f32>("max"), Some(&1.0));
+/// assert!(!field.has_attribute::<f32>());
+/// ```
 #[derive(Default)]
-#[repr(transparent)]
 pub struct CustomAttributes {
     attributes: TypeIdMap<Box<dyn Reflect>>,
+    keyed: Vec<(&'static str, Box<dyn Reflect>)>,
 }
 
 impl CustomAttributes {
@@ -60,6 +82,7 @@ impl CustomAttributes {
     pub const fn new() -> Self {
         Self {
             attributes: TypeIdMap::new(),
+            keyed: Vec::new(),
         }
     }
 
@@ -68,6 +91,7 @@ impl CustomAttributes {
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             attributes: TypeIdMap::with_capacity(capacity),
+            keyed: Vec::new(),
         }
     }
 
@@ -81,7 +105,20 @@ impl CustomAttributes {
         self
     }
 
-    /// Returns an iterator over the stored attributes.
+    /// Adds a keyed attribute.
+    ///
+    /// Keyed attributes are independent of the attributes keyed by type;
+    /// later insertions for the same key overwrite earlier values.
+    pub fn with_keyed_attribute<T: Reflect>(mut self, key: &'static str, value: T) -> Self {
+        let value: Box<dyn Reflect> = Box::new(value);
+        match self.keyed.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, old)) => *old = value,
+            None => self.keyed.push((key, value)),
+        }
+        self
+    }
+
+    /// Returns an iterator over the stored attributes, keyed attributes are not included.
     #[inline]
     pub fn iter(&self) -> impl ExactSizeIterator<Item = (&TypeId, &dyn Reflect)> {
         self.attributes.iter().map(|(key, val)| (key, &**val))
@@ -112,22 +149,52 @@ impl CustomAttributes {
         self.attributes.get(&id).map(core::ops::Deref::deref)
     }
 
-    /// Returns the number of stored attributes.
+    /// Returns an iterator over the keyed attributes, in insertion order.
+    #[inline]
+    pub fn iter_keyed(&self) -> impl ExactSizeIterator<Item = (&'static str, &dyn Reflect)> {
+        self.keyed.iter().map(|(key, val)| (*key, &**val))
+    }
+
+    /// Returns `true` if it contains the keyed attribute with the given key.
+    #[inline]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.keyed.iter().any(|(k, _)| *k == key)
+    }
+
+    /// Returns the keyed attribute of type `T` with the given key, if present.
+    #[inline]
+    pub fn get_keyed<T: Reflect>(&self, key: &str) -> Option<&T> {
+        self.get_keyed_dyn(key)
+            .and_then(<dyn Reflect>::downcast_ref)
+    }
+
+    /// Returns the keyed attribute with the given key, if present.
+    pub fn get_keyed_dyn(&self, key: &str) -> Option<&dyn Reflect> {
+        self.keyed
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, val)| &**val)
+    }
+
+    /// Returns the number of stored attributes, including keyed attributes.
     #[inline]
     pub fn len(&self) -> usize {
-        self.attributes.len()
+        self.attributes.len() + self.keyed.len()
     }
 
-    /// Returns `true` if no attributes are stored.
+    /// Returns `true` if no attributes are stored, including keyed attributes.
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.attributes.is_empty()
+        self.attributes.is_empty() && self.keyed.is_empty()
     }
 }
 
 impl core::fmt::Debug for CustomAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_set().entries(self.attributes.values()).finish()
+        let mut f = f.debug_set();
+        f.entries(self.attributes.values());
+        f.entries(self.keyed.iter().map(|(key, val)| (key, val)));
+        f.finish()
     }
 }
 
@@ -172,6 +239,16 @@ macro_rules! impl_custom_attributes_fn {
         pub fn has_attribute_by_id(&self, type_id: ::core::any::TypeId) -> bool {
             self.custom_attributes().contains_by_id(type_id)
         }
+
+        /// Returns the keyed attribute of type `T` with the given key, if present.
+        pub fn get_keyed_attribute<T: $crate::Reflect>(&self, key: &str) -> Option<&T> {
+            self.custom_attributes().get_keyed::<T>(key)
+        }
+
+        /// Returns `true` if it contains the keyed attribute with the given key.
+        pub fn has_keyed_attribute(&self, key: &str) -> bool {
+            self.custom_attributes().contains_key(key)
+        }
     };
 }
 
@@ -225,4 +302,23 @@ mod tests {
         let collected = attrs.iter().count();
         assert_eq!(collected, 2);
     }
+
+    #[test]
+    fn keyed() {
+        let attrs = CustomAttributes::new()
+            .with_attribute(0.5_f32)
+            .with_keyed_attribute("min", 0.0_f32)
+            .with_keyed_attribute("max", 2.0_f32)
+            .with_keyed_attribute("max", 1.0_f32);
+
+        assert_eq!(attrs.len(), 3);
+        assert_eq!(attrs.get::<f32>(), Some(&0.5));
+        assert_eq!(attrs.get_keyed::<f32>("min"), Some(&0.0));
+        assert_eq!(attrs.get_keyed::<f32>("max"), Some(&1.0));
+        assert_eq!(attrs.get_keyed::<f64>("max"), None);
+        assert!(!attrs.contains_key("step"));
+
+        let keys: alloc::vec::Vec<_> = attrs.iter_keyed().map(|(key, _)| key).collect();
+        assert_eq!(keys, ["min", "max"]);
+    }
 }
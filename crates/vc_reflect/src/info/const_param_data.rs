@@ -1,3 +1,5 @@
+use core::fmt;
+
 /// A container for [`ConstParamInfo`](crate::info::ConstParamInfo).
 ///
 /// Internal type, users should not use this type directly.
@@ -32,6 +34,27 @@ pub enum ConstParamData {
     Bool(bool),
 }
 
+impl fmt::Display for ConstParamData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::U8(v) => fmt::Display::fmt(v, f),
+            Self::U16(v) => fmt::Display::fmt(v, f),
+            Self::U32(v) => fmt::Display::fmt(v, f),
+            Self::U64(v) => fmt::Display::fmt(v, f),
+            Self::U128(v) => fmt::Display::fmt(v, f),
+            Self::Usize(v) => fmt::Display::fmt(v, f),
+            Self::I8(v) => fmt::Display::fmt(v, f),
+            Self::I16(v) => fmt::Display::fmt(v, f),
+            Self::I32(v) => fmt::Display::fmt(v, f),
+            Self::I64(v) => fmt::Display::fmt(v, f),
+            Self::I128(v) => fmt::Display::fmt(v, f),
+            Self::Isize(v) => fmt::Display::fmt(v, f),
+            Self::Char(v) => fmt::Display::fmt(v, f),
+            Self::Bool(v) => fmt::Display::fmt(v, f),
+        }
+    }
+}
+
 macro_rules! impl_from_fn {
     ($ty:ident, $kind:ident) => {
         impl From<$ty> for ConstParamData {
@@ -4,11 +4,12 @@ use vc_os::sync::Arc;
 
 use crate::info::{CustomAttributes, TypeInfo, Typed, impl_docs_fn};
 use crate::info::{impl_custom_attributes_fn, impl_with_custom_attributes};
+use crate::registry::{ReflectDeserialize, ReflectSerialize};
 
 // -----------------------------------------------------------------------------
 // NamedField
 
-/// Information for a named (struct) field, size = 48.
+/// Information for a named (struct) field, size = 88.
 ///
 /// # Examples
 ///
@@ -32,9 +33,12 @@ pub struct NamedField {
     name: &'static str,
     // `TypeInfo` is created on first access; using a function pointer delays it.
     type_info: fn() -> &'static TypeInfo,
+    offset: Option<usize>,
     // Use `Option` to reduce unnecessary heap requests (when empty content).
     custom_attributes: Option<Arc<CustomAttributes>>,
     skip_serde: bool,
+    serialize_with: Option<ReflectSerialize>,
+    deserialize_with: Option<ReflectDeserialize>,
     #[cfg(feature = "reflect_docs")]
     docs: Option<&'static str>,
 }
@@ -46,13 +50,16 @@ impl NamedField {
 
     /// Creates a new [`NamedField`] for the given field `name` and type `T`.
     #[inline]
-    pub const fn new<T: Typed>(name: &'static str) -> Self {
+    pub const fn new<T: Typed + ?Sized>(name: &'static str) -> Self {
         Self {
             name,
             type_info: T::type_info,
             type_id: TypeId::of::<T>(),
+            offset: None,
             custom_attributes: None,
             skip_serde: false,
+            serialize_with: None,
+            deserialize_with: None,
             #[cfg(feature = "reflect_docs")]
             docs: None,
         }
@@ -82,6 +89,41 @@ impl NamedField {
         (self.type_info)()
     }
 
+    /// Replaces the stored byte offset.
+    ///
+    /// This is used by `#[derive(Reflect)]`, there is usually no reason to
+    /// call it by hand.
+    ///
+    /// # Safety
+    ///
+    /// `offset` must be the offset in bytes of this field in the type whose
+    /// [`TypeInfo`] contains it, e.g. `offset_of!(Self, field)`, and the field
+    /// must be of the type given to [`NamedField::new`]. [`CompiledPath`]
+    /// dereferences the offset without checking it.
+    ///
+    /// [`CompiledPath`]: crate::access::CompiledPath
+    #[doc(hidden)]
+    #[inline]
+    #[expect(unsafe_code, reason = "The caller guarantees the layout.")]
+    pub unsafe fn with_offset(self, offset: usize) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    /// Returns the byte offset of the field in its container, if known.
+    ///
+    /// `#[derive(Reflect)]` sets it for fields of structs, except fields with
+    /// custom accessors and fields of `#[repr(packed)]` types. It is used by
+    /// [`CompiledPath`] to access fields without dynamic dispatch.
+    ///
+    /// [`CompiledPath`]: crate::access::CompiledPath
+    #[inline]
+    pub const fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
     /// Replaces stored skip_serde flag.
     #[inline]
     pub fn with_skip_serde(self, val: bool) -> Self {
@@ -106,12 +148,50 @@ impl NamedField {
     pub const fn skip_serde(&self) -> bool {
         self.skip_serde
     }
+
+    /// Replaces stored custom serializer.
+    #[inline]
+    pub fn with_serialize_with(self, val: ReflectSerialize) -> Self {
+        Self {
+            serialize_with: Some(val),
+            ..self
+        }
+    }
+
+    /// Replaces stored custom deserializer.
+    #[inline]
+    pub fn with_deserialize_with(self, val: ReflectDeserialize) -> Self {
+        Self {
+            deserialize_with: Some(val),
+            ..self
+        }
+    }
+
+    /// Returns the serializer set by `#[reflect(serialize_with = "path")]`.
+    ///
+    /// Like [`skip_serde`](Self::skip_serde), this only affects reflection-based
+    /// serialization. It takes precedence over processors and over the
+    /// [`ReflectSerialize`] registered for the field type.
+    #[inline]
+    pub const fn serialize_with(&self) -> Option<&ReflectSerialize> {
+        self.serialize_with.as_ref()
+    }
+
+    /// Returns the deserializer set by `#[reflect(deserialize_with = "path")]`.
+    ///
+    /// Like [`skip_serde`](Self::skip_serde), this only affects reflection-based
+    /// deserialization. It takes precedence over processors and over the
+    /// [`ReflectDeserialize`] registered for the field type.
+    #[inline]
+    pub const fn deserialize_with(&self) -> Option<&ReflectDeserialize> {
+        self.deserialize_with.as_ref()
+    }
 }
 
 // -----------------------------------------------------------------------------
 // UnnamedField
 
-/// Information for an unnamed (tuple) field, size = 40.
+/// Information for an unnamed (tuple) field, size = 80.
 ///
 /// # Examples
 ///
@@ -133,9 +213,12 @@ pub struct UnnamedField {
     index: usize,
     // `TypeInfo` is created on first access; using a function pointer delays it.
     type_info: fn() -> &'static TypeInfo,
+    offset: Option<usize>,
     // Use `Option` to reduce unnecessary heap requests (when empty content).
     custom_attributes: Option<Arc<CustomAttributes>>,
     skip_serde: bool,
+    serialize_with: Option<ReflectSerialize>,
+    deserialize_with: Option<ReflectDeserialize>,
     #[cfg(feature = "reflect_docs")]
     docs: Option<&'static str>,
 }
@@ -147,13 +230,16 @@ impl UnnamedField {
 
     /// Creates a new [`UnnamedField`] for the field at `index` with type `T`.
     #[inline]
-    pub const fn new<T: Typed>(index: usize) -> Self {
+    pub const fn new<T: Typed + ?Sized>(index: usize) -> Self {
         Self {
             index,
             type_info: T::type_info,
             type_id: TypeId::of::<T>(),
+            offset: None,
             custom_attributes: None,
             skip_serde: false,
+            serialize_with: None,
+            deserialize_with: None,
             #[cfg(feature = "reflect_docs")]
             docs: None,
         }
@@ -183,6 +269,41 @@ impl UnnamedField {
         (self.type_info)()
     }
 
+    /// Replaces the stored byte offset.
+    ///
+    /// This is used by `#[derive(Reflect)]`, there is usually no reason to
+    /// call it by hand.
+    ///
+    /// # Safety
+    ///
+    /// `offset` must be the offset in bytes of this field in the type whose
+    /// [`TypeInfo`] contains it, e.g. `offset_of!(Self, 0)`, and the field
+    /// must be of the type given to [`UnnamedField::new`]. [`CompiledPath`]
+    /// dereferences the offset without checking it.
+    ///
+    /// [`CompiledPath`]: crate::access::CompiledPath
+    #[doc(hidden)]
+    #[inline]
+    #[expect(unsafe_code, reason = "The caller guarantees the layout.")]
+    pub unsafe fn with_offset(self, offset: usize) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    /// Returns the byte offset of the field in its container, if known.
+    ///
+    /// `#[derive(Reflect)]` sets it for fields of structs, except fields with
+    /// custom accessors and fields of `#[repr(packed)]` types. It is used by
+    /// [`CompiledPath`] to access fields without dynamic dispatch.
+    ///
+    /// [`CompiledPath`]: crate::access::CompiledPath
+    #[inline]
+    pub const fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
     /// Replaces stored skip_serde flag.
     #[inline]
     pub fn with_skip_serde(self, val: bool) -> Self {
@@ -207,4 +328,42 @@ impl UnnamedField {
     pub const fn skip_serde(&self) -> bool {
         self.skip_serde
     }
+
+    /// Replaces stored custom serializer.
+    #[inline]
+    pub fn with_serialize_with(self, val: ReflectSerialize) -> Self {
+        Self {
+            serialize_with: Some(val),
+            ..self
+        }
+    }
+
+    /// Replaces stored custom deserializer.
+    #[inline]
+    pub fn with_deserialize_with(self, val: ReflectDeserialize) -> Self {
+        Self {
+            deserialize_with: Some(val),
+            ..self
+        }
+    }
+
+    /// Returns the serializer set by `#[reflect(serialize_with = "path")]`.
+    ///
+    /// Like [`skip_serde`](Self::skip_serde), this only affects reflection-based
+    /// serialization. It takes precedence over processors and over the
+    /// [`ReflectSerialize`] registered for the field type.
+    #[inline]
+    pub const fn serialize_with(&self) -> Option<&ReflectSerialize> {
+        self.serialize_with.as_ref()
+    }
+
+    /// Returns the deserializer set by `#[reflect(deserialize_with = "path")]`.
+    ///
+    /// Like [`skip_serde`](Self::skip_serde), this only affects reflection-based
+    /// deserialization. It takes precedence over processors and over the
+    /// [`ReflectDeserialize`] registered for the field type.
+    #[inline]
+    pub const fn deserialize_with(&self) -> Option<&ReflectDeserialize> {
+        self.deserialize_with.as_ref()
+    }
 }
@@ -121,6 +121,12 @@ impl ConstParamInfo {
     pub fn value<T: TryFrom<ConstParamData>>(&self) -> Option<T> {
         (*self.value).try_into().ok()
     }
+
+    /// Returns the raw const value, regardless of its type.
+    #[inline]
+    pub(crate) fn data(&self) -> ConstParamData {
+        *self.value
+    }
 }
 
 // -----------------------------------------------------------------------------
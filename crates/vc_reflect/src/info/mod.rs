@@ -9,10 +9,13 @@
 //!     - [`type_name`](TypePath::type_name): The name without module path, may be duplicated.
 //!     - [`type_ident`](TypePath::type_ident): The name without generics and module path.
 //!     - [`module_path`](TypePath::module_path): Optional module path, for example `vc_reflect::info`.
+//!     - [`type_uuid`](TypePath::type_uuid): A stable 128-bit identifier, see [`TypeUuid`].
 //!
 //! - [`DynamicTypePath`]: Dynamic dispatch support for `TypePath`.
 //!
-//! - [`TypePathTable`]: A struct storing four function pointers for a single type's `TypePath` implementation.
+//! - [`TypePathTable`]: A struct storing function pointers for a single type's `TypePath` implementation.
+//!
+//! - [`TypeUuid`]: A 16-byte type identifier, stable across compilations and optionally across renames.
 //!
 //! - [`Type`]: A compact type descriptor containing a `TypeId` and a `TypePathTable`.
 //!
@@ -74,6 +77,7 @@ mod tuple_info;
 mod tuple_struct_info;
 mod type_info;
 mod type_path;
+mod type_uuid;
 mod typed;
 mod variant_info;
 
@@ -105,7 +109,8 @@ pub use struct_info::StructInfo;
 pub use tuple_info::TupleInfo;
 pub use tuple_struct_info::TupleStructInfo;
 pub use type_info::{ReflectKind, ReflectKindError, TypeInfo};
-pub use type_path::{DynamicTypePath, Type, TypePath, TypePathTable};
+pub use type_path::{ConstTypePath, DynamicTypePath, Type, TypePath, TypePathTable};
+pub use type_uuid::TypeUuid;
 pub use typed::{DynamicTyped, Typed};
 pub use variant_info::{StructVariantInfo, TupleVariantInfo, UnitVariantInfo};
 pub use variant_info::{VariantInfo, VariantKind, VariantKindError};
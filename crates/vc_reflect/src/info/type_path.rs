@@ -1,5 +1,7 @@
 use core::any::{Any, TypeId};
 
+use crate::info::TypeUuid;
+
 // -----------------------------------------------------------------------------
 // TypePath
 
@@ -14,6 +16,7 @@ use core::any::{Any, TypeId};
 /// - [`type_name`]: Type name without module path, may be duplicated.
 /// - [`type_ident`]: The shortest type name without module path and generics.
 /// - [`module_path`]: Optional module path.
+/// - [`type_uuid`]: A 128-bit identifier, by default the hash of the type path.
 ///
 /// We did not provide `crate_name` in `TypePath`, this can save some memory.
 ///
@@ -110,6 +113,7 @@ use core::any::{Any, TypeId};
 /// when manually implementing `TypePath`.
 ///
 /// For non generic types, implementation is simple.
+/// Consider also implementing [`ConstTypePath`], like the derive macros do.
 ///
 /// ```
 /// use vc_reflect::info::TypePath;
@@ -154,6 +158,7 @@ use core::any::{Any, TypeId};
 /// [`type_name`]: TypePath::type_name
 /// [`type_ident`]: TypePath::type_ident
 /// [`module_path`]: TypePath::module_path
+/// [`type_uuid`]: TypePath::type_uuid
 /// [`GenericTypePathCell`]: crate::impls::GenericTypePathCell
 pub trait TypePath: 'static {
     /// Returns the fully qualified path with generics of the target type.
@@ -187,6 +192,57 @@ pub trait TypePath: 'static {
     fn module_path() -> Option<&'static str> {
         None
     }
+
+    /// Returns the stable 128-bit identifier of the type.
+    ///
+    /// Defaults to the hash of [`type_path`](TypePath::type_path), the derive
+    /// macro overrides it with `#[reflect(uuid = "...")]`. See [`TypeUuid`].
+    #[inline]
+    fn type_uuid() -> TypeUuid {
+        TypeUuid::from_type_path(Self::type_path())
+    }
+}
+
+// -----------------------------------------------------------------------------
+// ConstTypePath
+
+/// The [`TypePath`] of a non-generic type, available in const contexts.
+///
+/// The derive macros and [`impl_type_path!`](crate::derive::impl_type_path)
+/// implement this for every type without generic parameters, and their
+/// [`TypePath`] methods simply return these constants, so no lazy cell or
+/// allocation is involved.
+///
+/// Generic types cannot implement it, as their paths are built at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::Reflect;
+/// use vc_reflect::info::{ConstTypePath, TypePath};
+///
+/// #[derive(Reflect)]
+/// #[reflect(type_path = "my_crate::foo::Foo")]
+/// struct Foo;
+///
+/// const PATHS: [&str; 2] = [Foo::TYPE_PATH, <u32 as ConstTypePath>::TYPE_PATH];
+///
+/// assert_eq!(PATHS, ["my_crate::foo::Foo", "u32"]);
+/// assert_eq!(Foo::MODULE_PATH, Some("my_crate::foo"));
+/// assert_eq!(Foo::TYPE_PATH, Foo::type_path());
+/// ```
+pub trait ConstTypePath: TypePath {
+    /// See [`TypePath::type_path`].
+    const TYPE_PATH: &'static str;
+
+    /// See [`TypePath::type_name`].
+    const TYPE_NAME: &'static str;
+
+    /// See [`TypePath::type_ident`].
+    const TYPE_IDENT: &'static str;
+
+    /// See [`TypePath::module_path`].
+    const MODULE_PATH: Option<&'static str>;
 }
 
 // -----------------------------------------------------------------------------
@@ -228,6 +284,11 @@ pub trait DynamicTypePath {
     ///
     /// See [`TypePath::module_path`].
     fn reflect_module_path(&self) -> Option<&'static str>;
+
+    /// Returns the stable identifier of the underlying type.
+    ///
+    /// See [`TypePath::type_uuid`].
+    fn reflect_type_uuid(&self) -> TypeUuid;
 }
 
 impl<T: TypePath> DynamicTypePath for T {
@@ -250,6 +311,11 @@ impl<T: TypePath> DynamicTypePath for T {
     fn reflect_module_path(&self) -> Option<&'static str> {
         Self::module_path()
     }
+
+    #[inline]
+    fn reflect_type_uuid(&self) -> TypeUuid {
+        Self::type_uuid()
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -281,6 +347,7 @@ pub struct TypePathTable {
     type_name: fn() -> &'static str,
     type_ident: fn() -> &'static str,
     module_path: fn() -> Option<&'static str>,
+    type_uuid: fn() -> TypeUuid,
 }
 
 impl TypePathTable {
@@ -292,6 +359,7 @@ impl TypePathTable {
             type_name: T::type_name,
             type_ident: T::type_ident,
             module_path: T::module_path,
+            type_uuid: T::type_uuid,
         }
     }
 
@@ -319,6 +387,12 @@ impl TypePathTable {
         (self.module_path)()
     }
 
+    /// See [`TypePath::type_uuid`]
+    #[inline(always)]
+    pub fn uuid(&self) -> TypeUuid {
+        (self.type_uuid)()
+    }
+
     /// Parse `crate_name` from `module_path`.
     #[inline]
     pub fn crate_name(&self) -> Option<&'static str> {
@@ -340,6 +414,7 @@ impl core::fmt::Debug for TypePathTable {
             .field("type_ident", &self.ident())
             .field("module_path", &self.module_path())
             .field("crate_name", &self.crate_name())
+            .field("type_uuid", &self.uuid())
             .finish()
     }
 }
@@ -458,6 +533,12 @@ impl Type {
     pub fn crate_name(&self) -> Option<&'static str> {
         self.type_path_table.crate_name()
     }
+
+    /// See [`TypePath::type_uuid`].
+    #[inline]
+    pub fn uuid(&self) -> TypeUuid {
+        self.type_path_table.uuid()
+    }
 }
 
 /// This implementation purely relies on the [`TypeId`] of the type,
@@ -553,6 +634,12 @@ macro_rules! impl_type_fn {
         pub fn crate_name(&self) -> Option<&'static str> {
             self.ty().crate_name()
         }
+
+        /// Returns the stable type identifier.
+        #[inline]
+        pub fn type_uuid(&self) -> $crate::info::TypeUuid {
+            self.ty().uuid()
+        }
     };
 }
 
@@ -0,0 +1,210 @@
+use core::fmt;
+
+// -----------------------------------------------------------------------------
+// TypeUuid
+
+/// A stable 128-bit identifier of a reflected type.
+///
+/// Returned by [`TypePath::type_uuid`], it is a compact alternative to the
+/// type path for serialized data and network protocols.
+///
+/// By default, it is a hash of the type path, see [`TypeUuid::from_type_path`].
+/// It is therefore stable across compilations, but changes when the type is
+/// renamed or moved. A fixed value can be specified with
+/// `#[reflect(uuid = "...")]`, which survives renames. For generic types, the
+/// fixed value is combined with the identifiers of the type parameters.
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::Reflect;
+/// use vc_reflect::info::{TypePath, TypeUuid};
+///
+/// #[derive(Reflect)]
+/// #[reflect(uuid = "6f1c2b5e-8a4d-4e0f-9b7a-3c2d1e0f4a5b")]
+/// struct Health(f32);
+///
+/// #[derive(Reflect)]
+/// struct Mana(f32);
+///
+/// assert_eq!(
+///     Health::type_uuid(),
+///     TypeUuid::from_u128(0x6f1c2b5e_8a4d_4e0f_9b7a_3c2d1e0f4a5b),
+/// );
+/// assert_eq!(Mana::type_uuid(), TypeUuid::from_type_path(Mana::type_path()));
+///
+/// let text = Health::type_uuid().to_string();
+/// assert_eq!(TypeUuid::parse(&text), Some(Health::type_uuid()));
+/// ```
+///
+/// [`TypePath::type_uuid`]: crate::info::TypePath::type_uuid
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TypeUuid(u128);
+
+impl TypeUuid {
+    /// Creates an identifier from its integer value.
+    #[inline(always)]
+    pub const fn from_u128(value: u128) -> Self {
+        Self(value)
+    }
+
+    /// Returns the integer value of the identifier.
+    #[inline(always)]
+    pub const fn as_u128(self) -> u128 {
+        self.0
+    }
+
+    /// Creates an identifier from its big-endian bytes.
+    #[inline(always)]
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(u128::from_be_bytes(bytes))
+    }
+
+    /// Returns the big-endian bytes of the identifier.
+    #[inline(always)]
+    pub const fn to_bytes(self) -> [u8; 16] {
+        self.0.to_be_bytes()
+    }
+
+    /// Creates an identifier by hashing a type path.
+    ///
+    /// This uses the 128-bit FNV-1a hash, which does not depend on the
+    /// platform or the compiler version.
+    pub const fn from_type_path(type_path: &str) -> Self {
+        const OFFSET: u128 = 0x6c62272e_07bb0142_62b82175_6295c58d;
+        const PRIME: u128 = 0x00000000_01000000_00000000_0000013b;
+
+        let bytes = type_path.as_bytes();
+        let mut hash = OFFSET;
+        let mut index = 0;
+        while index < bytes.len() {
+            hash ^= bytes[index] as u128;
+            hash = hash.wrapping_mul(PRIME);
+            index += 1;
+        }
+        Self(hash)
+    }
+
+    /// Combines two identifiers, e.g. of a generic type and a type parameter.
+    ///
+    /// The result depends on the order of the operands.
+    #[inline]
+    pub const fn combine(self, other: Self) -> Self {
+        const PRIME: u128 = 0x00000000_01000000_00000000_0000013b;
+        Self(self.0.rotate_left(5).wrapping_mul(PRIME) ^ other.0)
+    }
+
+    /// Parses an identifier in the hyphenated form
+    /// `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`, or as 32 hex digits.
+    ///
+    /// Returns `None` if the string is not a valid identifier.
+    pub const fn parse(text: &str) -> Option<Self> {
+        let bytes = text.as_bytes();
+        let hyphenated = match bytes.len() {
+            36 => true,
+            32 => false,
+            _ => return None,
+        };
+
+        let mut value: u128 = 0;
+        let mut index = 0;
+        while index < bytes.len() {
+            let byte = bytes[index];
+            index += 1;
+            if hyphenated && matches!(index - 1, 8 | 13 | 18 | 23) {
+                if byte != b'-' {
+                    return None;
+                }
+                continue;
+            }
+            let digit = match byte {
+                b'0'..=b'9' => byte - b'0',
+                b'a'..=b'f' => byte - b'a' + 10,
+                b'A'..=b'F' => byte - b'A' + 10,
+                _ => return None,
+            };
+            value = (value << 4) | digit as u128;
+        }
+        Some(Self(value))
+    }
+}
+
+/// Formats the identifier in the hyphenated form.
+impl fmt::Display for TypeUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let v = self.0;
+        write!(
+            f,
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            (v >> 96) as u32,
+            (v >> 80) as u16,
+            (v >> 64) as u16,
+            (v >> 48) as u16,
+            v & 0xffff_ffff_ffff,
+        )
+    }
+}
+
+impl fmt::Debug for TypeUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TypeUuid({self})")
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::TypeUuid;
+    use crate::Reflect;
+    use crate::info::TypePath;
+
+    #[test]
+    fn parse_and_format() {
+        let uuid = TypeUuid::from_u128(0x0123_4567_89ab_cdef_0011_2233_4455_6677);
+        let text = uuid.to_string();
+        assert_eq!(text, "01234567-89ab-cdef-0011-223344556677");
+        assert_eq!(TypeUuid::parse(&text), Some(uuid));
+        assert_eq!(
+            TypeUuid::parse("0123456789ABCDEF0011223344556677"),
+            Some(uuid)
+        );
+        assert_eq!(TypeUuid::from_bytes(uuid.to_bytes()), uuid);
+
+        assert_eq!(
+            TypeUuid::parse("01234567-89ab-cdef-0011_223344556677"),
+            None
+        );
+        assert_eq!(TypeUuid::parse("0123456789abcdef"), None);
+        assert_eq!(TypeUuid::parse("g123456789abcdef0011223344556677"), None);
+    }
+
+    #[test]
+    fn stable_hash() {
+        // The hash must never change, serialized data depends on it.
+        assert_eq!(
+            TypeUuid::from_type_path(""),
+            TypeUuid::from_u128(0x6c62272e_07bb0142_62b82175_6295c58d),
+        );
+        assert_eq!(u32::type_uuid(), TypeUuid::from_type_path("u32"));
+        assert_ne!(<Option<u32>>::type_uuid(), <Option<u64>>::type_uuid());
+    }
+
+    #[test]
+    fn fixed_generic_uuid() {
+        #[derive(Reflect)]
+        #[reflect(uuid = "00000000000000000000000000000001")]
+        struct Wrap<T, const N: usize>([T; N]);
+
+        let base = TypeUuid::from_u128(1);
+        assert_eq!(
+            <Wrap<u8, 2>>::type_uuid(),
+            base.combine(u8::type_uuid())
+                .combine(TypeUuid::from_u128(2)),
+        );
+        assert_ne!(<Wrap<u8, 2>>::type_uuid(), <Wrap<u8, 3>>::type_uuid());
+    }
+}
@@ -10,9 +10,11 @@
 pub mod cfg {
     vc_cfg::define_alias! {
         #[cfg(feature = "std")] => std,
-        #[cfg(all(feature = "std", any(debug_assertions, feature = "debug")))] => debug,
+        all(std, cfg(any(debug_assertions, feature = "debug"))) => debug,
         #[cfg(feature = "auto_register")] => auto_register,
         #[cfg(feature = "reflect_docs")] => reflect_docs,
+        #[cfg(feature = "small_str")] => small_str,
+        #[cfg(feature = "testing")] => testing,
     }
 }
 
@@ -39,11 +41,18 @@ extern crate alloc;
 mod reflection;
 
 pub mod access;
+pub mod analysis;
+pub mod fmt;
 pub mod impls;
 pub mod info;
 pub mod ops;
 pub mod registry;
 pub mod serde;
+pub mod validate;
+
+cfg::testing! {
+    pub mod testing;
+}
 
 // -----------------------------------------------------------------------------
 // Top-Level exports
@@ -5,7 +5,10 @@ use crate::info::{ReflectKind, ReflectKindError};
 
 /// A enumeration of all error outcomes
 /// that might happen when running [`apply`](crate::Reflect::apply).
+///
+/// New variants may be added as `apply` learns new ways to fail.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum ApplyError {
     /// Special reflection type, not allowed to apply.
     NotSupport { type_path: &'static str },
@@ -26,6 +29,9 @@ pub enum ApplyError {
     },
     /// Attempted to apply an array or tuple like type to another of different size, e.g. a `[u8; 4]` to `[u8; 3]`.
     DifferentSize { from_size: usize, to_size: usize },
+    /// A missing value could not be created, because its type has no registered
+    /// [`ReflectDefault`](crate::registry::ReflectDefault).
+    MissingDefault { type_path: &'static str },
 }
 
 impl fmt::Display for ApplyError {
@@ -52,6 +58,9 @@ impl fmt::Display for ApplyError {
                     "attempted to apply type with {from_size} size to {to_size} size"
                 )
             }
+            Self::MissingDefault { type_path } => {
+                write!(f, "type `{type_path}` has no registered `ReflectDefault`")
+            }
         }
     }
 }
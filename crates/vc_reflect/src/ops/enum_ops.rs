@@ -1,4 +1,4 @@
-use alloc::borrow::{Cow, ToOwned};
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::string::String;
 use core::cmp::Ordering;
@@ -6,11 +6,13 @@ use core::fmt;
 
 use crate::Reflect;
 use crate::impls::NonGenericTypeInfoCell;
-use crate::info::{OpaqueInfo, TypeInfo, TypePath, Typed, VariantKind};
+use crate::info::{OpaqueInfo, TypeInfo, TypePath, Typed, VariantInfo, VariantKind};
+use crate::ops::name::{self, Name};
 use crate::ops::{ApplyError, ReflectCloneError};
 use crate::ops::{DynamicStruct, DynamicTuple, DynamicVariant};
-use crate::ops::{Struct, Tuple, VariantFieldIter};
+use crate::ops::{Struct, Tuple, VariantFieldInfoIter, VariantFieldIter};
 use crate::reflection::impl_reflect_cast_fn;
+use crate::registry::{ReflectDefault, TypeRegistry};
 
 // -----------------------------------------------------------------------------
 // Dynamic Enum
@@ -56,7 +58,7 @@ use crate::reflection::impl_reflect_cast_fn;
 pub struct DynamicEnum {
     info: Option<&'static TypeInfo>,
     variant_index: usize,
-    variant_name: Cow<'static, str>,
+    variant_name: Name,
     variant: DynamicVariant,
 }
 
@@ -109,11 +111,22 @@ impl DynamicEnum {
         Self {
             info: None,
             variant_index: index,
-            variant_name: name.into(),
+            variant_name: name::from_cow(name.into()),
             variant: variant.into(),
         }
     }
 
+    /// Creates a dynamic enum with an already converted name.
+    #[inline]
+    fn named(index: usize, name: Name, variant: DynamicVariant) -> Self {
+        Self {
+            info: None,
+            variant_index: index,
+            variant_name: name,
+            variant,
+        }
+    }
+
     /// Sets the [`TypeInfo`] that this dynamic enum represents.
     ///
     /// When set, [`Reflect::represented_type_info`] will return this information,
@@ -154,7 +167,7 @@ impl DynamicEnum {
         I: Into<Cow<'static, str>>,
         V: Into<DynamicVariant>,
     {
-        self.variant_name = name.into();
+        self.variant_name = name::from_cow(name.into());
         self.variant_index = index;
         self.variant = variant.into();
     }
@@ -236,9 +249,9 @@ impl DynamicEnum {
     #[inline(never)]
     pub fn from_ref<TEnum: Enum + ?Sized>(value: &TEnum) -> Self {
         let mut dyn_enum = match value.variant_kind() {
-            VariantKind::Unit => DynamicEnum::new(
+            VariantKind::Unit => DynamicEnum::named(
                 value.variant_index(),
-                value.variant_name().to_owned(),
+                name::copy(value.variant_name()),
                 DynamicVariant::Unit,
             ),
             VariantKind::Tuple => {
@@ -246,9 +259,9 @@ impl DynamicEnum {
                 for field in value.iter_fields() {
                     data.extend_boxed(field.value().to_dynamic());
                 }
-                DynamicEnum::new(
+                DynamicEnum::named(
                     value.variant_index(),
-                    value.variant_name().to_owned(),
+                    name::copy(value.variant_name()),
                     DynamicVariant::Tuple(data),
                 )
             }
@@ -256,11 +269,11 @@ impl DynamicEnum {
                 let mut data = DynamicStruct::with_capacity(value.field_len());
                 for field in value.iter_fields() {
                     let name = field.name().unwrap();
-                    data.extend_boxed(name.to_owned(), field.value().to_dynamic());
+                    data.extend_named(name::copy(name), field.value().to_dynamic());
                 }
-                DynamicEnum::new(
+                DynamicEnum::named(
                     value.variant_index(),
-                    value.variant_name().to_owned(),
+                    name::copy(value.variant_name()),
                     DynamicVariant::Struct(data),
                 )
             }
@@ -308,8 +321,8 @@ impl Reflect for DynamicEnum {
                 VariantKind::Struct => {
                     let mut dyn_struct = DynamicStruct::with_capacity(y.field_len());
                     for y_field in y.iter_fields() {
-                        dyn_struct.extend_boxed(
-                            y_field.name().unwrap().to_owned(),
+                        dyn_struct.extend_named(
+                            name::copy(y_field.name().unwrap()),
                             y_field.value().to_dynamic(),
                         );
                     }
@@ -317,7 +330,9 @@ impl Reflect for DynamicEnum {
                 }
             };
 
-            self.set_variant(y.variant_index(), y.variant_name().to_owned(), dyn_variant);
+            self.variant_name = name::copy(y.variant_name());
+            self.variant_index = y.variant_index();
+            self.variant = dyn_variant;
         }
         Ok(())
     }
@@ -752,6 +767,200 @@ impl dyn Enum {
         self.field_at_mut(index)
             .and_then(<dyn Reflect>::downcast_mut)
     }
+
+    /// Returns an iterator over the fields of the current variant, together
+    /// with their [`VariantFieldInfo`] information.
+    ///
+    /// The information comes from the [`represented_type_info`] of the current
+    /// variant, it is `None` if the enum has no type information, or the type
+    /// information has no such field.
+    ///
+    /// [`VariantFieldInfo`]: crate::ops::VariantFieldInfo
+    /// [`represented_type_info`]: Reflect::represented_type_info
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_reflect::{ops::Enum, Reflect};
+    /// #[derive(Reflect)]
+    /// enum Foo {
+    ///     A { x: i32 },
+    ///     B(bool),
+    /// }
+    ///
+    /// let foo = Foo::A { x: 10 };
+    /// let foo_ref: &dyn Enum = &foo;
+    ///
+    /// let mut iter = foo_ref.iter_fields_with_info();
+    /// let (info, field) = iter.next().unwrap();
+    /// assert_eq!(info.unwrap().name(), Some("x"));
+    /// assert_eq!(field.value().downcast_ref::<i32>(), Some(&10));
+    /// ```
+    #[inline]
+    pub fn iter_fields_with_info(&self) -> VariantFieldInfoIter<'_> {
+        VariantFieldInfoIter::new(self)
+    }
+
+    /// Switches the enum to the variant `name`, with `variant` as its payload.
+    ///
+    /// If the enum already holds this variant, the payload is applied field by
+    /// field, so a struct payload may be partial. Otherwise the new value is
+    /// built through `FromReflect` and the payload must contain every field,
+    /// see [`set_variant_with_defaults`] to fill the missing ones.
+    ///
+    /// The variant is looked up in the [represented type info], dynamic enums
+    /// without type info can only be switched to their current variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_reflect::{Reflect, ops::{Enum, DynamicTuple, DynamicVariant}};
+    /// #[derive(Reflect, Debug, PartialEq)]
+    /// enum Shape {
+    ///     Point,
+    ///     Circle(f32),
+    /// }
+    ///
+    /// let mut shape = Shape::Point;
+    /// let mut radius = DynamicTuple::default();
+    /// radius.extend(2.0_f32);
+    ///
+    /// let shape_enum: &mut dyn Enum = &mut shape;
+    /// shape_enum.set_variant("Circle", DynamicVariant::Tuple(radius)).unwrap();
+    /// assert_eq!(shape, Shape::Circle(2.0));
+    /// ```
+    ///
+    /// [`set_variant_with_defaults`]: Self::set_variant_with_defaults
+    /// [represented type info]: Reflect::represented_type_info
+    pub fn set_variant(&mut self, name: &str, variant: DynamicVariant) -> Result<(), ApplyError> {
+        let index = match self.variant_info_of(name)? {
+            Some((index, _)) => index,
+            None => self.variant_index(),
+        };
+        let value = DynamicEnum::named(index, name::copy(name), variant);
+        self.apply(&value)
+    }
+
+    /// Switches the enum to the variant `name` like [`set_variant`], creating
+    /// the fields missing from `variant`.
+    ///
+    /// Missing fields keep their current value if the enum already holds this
+    /// variant. Otherwise they are created with the [`ReflectDefault`] registered
+    /// for their type, which is what an editor needs when the variant is picked
+    /// from a dropdown. A `Unit` payload stands for an empty one.
+    ///
+    /// Tuple fields are matched by position, so only trailing fields can be missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_reflect::{Reflect, ops::{Enum, DynamicVariant}, registry::TypeRegistry};
+    /// #[derive(Reflect, Debug, PartialEq)]
+    /// enum Shape {
+    ///     Point,
+    ///     Rect { width: f32, height: f32 },
+    /// }
+    ///
+    /// let registry = TypeRegistry::new();
+    /// let mut shape = Shape::Point;
+    ///
+    /// let shape_enum: &mut dyn Enum = &mut shape;
+    /// shape_enum
+    ///     .set_variant_with_defaults("Rect", DynamicVariant::Unit, &registry)
+    ///     .unwrap();
+    /// assert_eq!(shape, Shape::Rect { width: 0.0, height: 0.0 });
+    /// ```
+    ///
+    /// [`set_variant`]: Self::set_variant
+    pub fn set_variant_with_defaults(
+        &mut self,
+        name: &str,
+        variant: DynamicVariant,
+        registry: &TypeRegistry,
+    ) -> Result<(), ApplyError> {
+        let Some((_, info)) = self.variant_info_of(name)? else {
+            return self.set_variant(name, variant);
+        };
+        let current = self.variant_name() == name;
+
+        let create = |field: Option<&dyn Reflect>, type_info: &'static TypeInfo| {
+            if current && let Some(field) = field {
+                return Ok(field.to_dynamic());
+            }
+            registry
+                .get_type_trait::<ReflectDefault>(type_info.type_id())
+                .map(ReflectDefault::default)
+                .ok_or(ApplyError::MissingDefault {
+                    type_path: type_info.type_path(),
+                })
+        };
+
+        let variant = match (info, variant) {
+            (VariantInfo::Struct(info), DynamicVariant::Unit) => {
+                let mut data = DynamicStruct::with_capacity(info.field_len());
+                for field in info.iter() {
+                    data.extend_boxed(
+                        field.name(),
+                        create(self.field(field.name()), field.type_info())?,
+                    );
+                }
+                DynamicVariant::Struct(data)
+            }
+            (VariantInfo::Struct(info), DynamicVariant::Struct(mut data)) => {
+                for field in info.iter() {
+                    if data.field(field.name()).is_none() {
+                        data.extend_boxed(
+                            field.name(),
+                            create(self.field(field.name()), field.type_info())?,
+                        );
+                    }
+                }
+                DynamicVariant::Struct(data)
+            }
+            (VariantInfo::Tuple(info), DynamicVariant::Unit) => {
+                let mut data = DynamicTuple::with_capacity(info.field_len());
+                for field in info.iter() {
+                    data.extend_boxed(create(self.field_at(field.index()), field.type_info())?);
+                }
+                DynamicVariant::Tuple(data)
+            }
+            (VariantInfo::Tuple(info), DynamicVariant::Tuple(mut data)) => {
+                for field in info.iter().skip(data.field_len()) {
+                    data.extend_boxed(create(self.field_at(field.index()), field.type_info())?);
+                }
+                DynamicVariant::Tuple(data)
+            }
+            // Kind mismatches are reported by `apply`.
+            (_, variant) => variant,
+        };
+
+        self.set_variant(name, variant)
+    }
+
+    /// Returns the index and info of the variant `name`, or `None` if the
+    /// type info is not available.
+    fn variant_info_of(
+        &self,
+        name: &str,
+    ) -> Result<Option<(usize, &'static VariantInfo)>, ApplyError> {
+        let mismatched = || ApplyError::MismatchedVariant {
+            from_variant: Cow::Owned(crate::impls::concat(&[
+                self.reflect_type_path(),
+                "::",
+                name,
+            ])),
+            to_variant: Cow::Owned(self.variant_path()),
+        };
+
+        match self.represented_type_info() {
+            Some(TypeInfo::Enum(info)) => {
+                let index = info.index_of(name).ok_or_else(mismatched)?;
+                Ok(info.variant_at(index).map(|variant| (index, variant)))
+            }
+            _ if self.variant_name() == name => Ok(None),
+            _ => Err(mismatched()),
+        }
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -759,8 +968,11 @@ impl dyn Enum {
 
 #[cfg(test)]
 mod tests {
-    use super::DynamicEnum;
+    use super::{DynamicEnum, Enum};
+    use crate::Reflect;
     use crate::info::TypePath;
+    use crate::ops::{ApplyError, DynamicStruct, DynamicVariant};
+    use crate::registry::TypeRegistry;
 
     #[test]
     fn type_path() {
@@ -769,4 +981,41 @@ mod tests {
         assert!(DynamicEnum::type_ident() == "DynamicEnum");
         assert!(DynamicEnum::type_name() == "DynamicEnum");
     }
+
+    #[test]
+    fn set_variant_with_defaults() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Foo {
+            A { x: u32, y: u32 },
+            B(u8, u16),
+        }
+
+        let registry = TypeRegistry::new();
+        let mut foo = Foo::A { x: 1, y: 2 };
+
+        // The current fields are kept.
+        let mut data = DynamicStruct::default();
+        data.extend("y", 5_u32);
+        let foo_enum: &mut dyn Enum = &mut foo;
+        foo_enum
+            .set_variant_with_defaults("A", DynamicVariant::Struct(data), &registry)
+            .unwrap();
+        assert_eq!(foo, Foo::A { x: 1, y: 5 });
+
+        let foo_enum: &mut dyn Enum = &mut foo;
+        foo_enum
+            .set_variant_with_defaults("B", DynamicVariant::Unit, &registry)
+            .unwrap();
+        assert_eq!(foo, Foo::B(0, 0));
+
+        let foo_enum: &mut dyn Enum = &mut foo;
+        let err = foo_enum.set_variant("D", DynamicVariant::Unit).unwrap_err();
+        assert!(matches!(err, ApplyError::MismatchedVariant { .. }));
+
+        // Only the payload of the current variant may be partial.
+        let foo_enum: &mut dyn Enum = &mut foo;
+        let err = foo_enum.set_variant("A", DynamicVariant::Unit).unwrap_err();
+        assert!(matches!(err, ApplyError::MismatchedVariant { .. }));
+        assert_eq!(foo, Foo::B(0, 0));
+    }
 }
@@ -0,0 +1,125 @@
+use core::cmp::Ordering;
+use core::fmt;
+
+use crate::Reflect;
+
+// -----------------------------------------------------------------------------
+// KeyEq
+
+/// The equality used by [`DynamicMap`] and [`DynamicSet`] to compare keys.
+///
+/// Keys without [`Reflect::reflect_hash`] support share a single hash bucket,
+/// so they are found by comparing them one by one with this equality.
+///
+/// [`DynamicMap`]: crate::ops::DynamicMap
+/// [`DynamicSet`]: crate::ops::DynamicSet
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::ops::{DynamicMap, KeyEq, Map};
+///
+/// let mut map = DynamicMap::new();
+/// map.set_key_eq(KeyEq::EqOrCmp);
+/// map.extend(1.5_f32, "a");
+/// map.extend(1.5_f32, "b");
+///
+/// assert_eq!(map.len(), 1);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub enum KeyEq {
+    /// Compares keys with [`Reflect::reflect_eq`].
+    #[default]
+    Eq,
+    /// Compares keys with [`Reflect::reflect_eq`], and falls back to
+    /// [`Reflect::reflect_cmp`] if equality is not supported.
+    EqOrCmp,
+    /// Compares keys with a custom function, which returns `None` if
+    /// the keys cannot be compared.
+    Custom(fn(&dyn Reflect, &dyn Reflect) -> Option<bool>),
+}
+
+impl KeyEq {
+    /// Returns whether `key` and `other` are equal, or `None` if they
+    /// cannot be compared.
+    pub fn eq(self, key: &dyn Reflect, other: &dyn Reflect) -> Option<bool> {
+        match self {
+            Self::Eq => key.reflect_eq(other),
+            Self::EqOrCmp => key
+                .reflect_eq(other)
+                .or_else(|| Some(key.reflect_cmp(other)? == Ordering::Equal)),
+            Self::Custom(eq) => eq(key, other),
+        }
+    }
+
+    /// Returns an error if `key` cannot be compared with itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if `key` is not equal to itself.
+    pub(super) fn check(self, key: &dyn Reflect) -> Result<(), KeyEqError> {
+        let Some(eq) = self.eq(key, key) else {
+            return Err(KeyEqError::new(key, key));
+        };
+        debug_assert!(
+            eq,
+            "The key is not equal to itself: `{}`.",
+            key.reflect_type_path(),
+        );
+        Ok(())
+    }
+}
+
+/// The hash of a key in [`DynamicMap`] and [`DynamicSet`].
+///
+/// Keys that do not support [`Reflect::reflect_hash`] share the same bucket.
+///
+/// [`DynamicMap`]: crate::ops::DynamicMap
+/// [`DynamicSet`]: crate::ops::DynamicSet
+#[inline]
+pub(super) fn key_hash(key: &dyn Reflect) -> u64 {
+    const UNHASHED: u64 = 0;
+    key.reflect_hash().unwrap_or(UNHASHED)
+}
+
+// -----------------------------------------------------------------------------
+// KeyEqError
+
+/// An error returned when two keys cannot be compared with the [`KeyEq`]
+/// of a [`DynamicMap`] or [`DynamicSet`].
+///
+/// [`DynamicMap`]: crate::ops::DynamicMap
+/// [`DynamicSet`]: crate::ops::DynamicSet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyEqError {
+    /// The type path of the inserted or looked up key.
+    pub key: &'static str,
+    /// The type path of the key it was compared with.
+    pub other: &'static str,
+}
+
+impl KeyEqError {
+    #[cold]
+    pub(super) fn new(key: &dyn Reflect, other: &dyn Reflect) -> Self {
+        Self {
+            key: key.reflect_type_path(),
+            other: other.reflect_type_path(),
+        }
+    }
+}
+
+impl fmt::Display for KeyEqError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.key == self.other {
+            write!(f, "keys of type `{}` cannot be compared", self.key)
+        } else {
+            write!(
+                f,
+                "key of type `{}` cannot be compared with key of type `{}`",
+                self.key, self.other,
+            )
+        }
+    }
+}
+
+impl core::error::Error for KeyEqError {}
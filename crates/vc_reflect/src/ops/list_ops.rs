@@ -594,6 +594,69 @@ pub trait List: Reflect {
     /// ```
     fn drain(&mut self) -> Vec<Box<dyn Reflect>>;
 
+    /// Retain only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` for which `f(&e)` returns `false`.
+    /// The order of the retained elements is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_reflect::{Reflect, ops::List};
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let list_ref: &mut dyn List = &mut vec;
+    ///
+    /// list_ref.retain(&mut |value| {
+    ///     value.downcast_ref::<i32>().map(|v| v % 2 == 0).unwrap_or(false)
+    /// });
+    ///
+    /// assert_eq!(vec, vec![2, 4]);
+    /// ```
+    ///
+    /// The default implementation removes the elements one by one with [`List::remove`].
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect) -> bool) {
+        let mut index = 0;
+        while let Some(value) = self.get(index) {
+            if f(value) {
+                index += 1;
+            } else {
+                self.remove(index);
+            }
+        }
+    }
+
+    /// Splits the list into two at the given index.
+    ///
+    /// Returns the elements in the range `[index, len)` in order,
+    /// and leaves the elements `[0, index)` in the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_reflect::{Reflect, ops::List};
+    /// let mut vec = vec![1, 2, 3];
+    /// let list_ref: &mut dyn List = &mut vec;
+    ///
+    /// let tail = list_ref.split_off(1);
+    /// assert_eq!(tail.len(), 2);
+    /// assert_eq!(tail[0].downcast_ref::<i32>(), Some(&2));
+    /// assert_eq!(vec, vec![1]);
+    /// ```
+    ///
+    /// The default implementation removes the elements one by one with [`List::remove`].
+    fn split_off(&mut self, index: usize) -> Vec<Box<dyn Reflect>> {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "`index` (is {index}) should be <= len (is {len})"
+        );
+        (index..len).map(|_| self.remove(index)).collect()
+    }
+
     /// Creates a [`DynamicList`] copy of this list.
     ///
     /// This is useful when you need a dynamic, mutable copy of a list.
@@ -675,6 +738,16 @@ impl List for DynamicList {
         self.values.drain(..).collect()
     }
 
+    #[inline]
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect) -> bool) {
+        self.values.retain(|value| f(&**value));
+    }
+
+    #[inline]
+    fn split_off(&mut self, index: usize) -> Vec<Box<dyn Reflect>> {
+        self.values.split_off(index)
+    }
+
     #[inline]
     fn is_empty(&self) -> bool {
         self.values.is_empty()
@@ -790,7 +863,10 @@ impl FusedIterator for ListItemIter<'_> {}
 
 #[cfg(test)]
 mod tests {
-    use super::DynamicList;
+    use alloc::boxed::Box;
+    use alloc::vec::Vec;
+
+    use super::{DynamicList, List};
     use crate::info::TypePath;
 
     #[test]
@@ -800,4 +876,19 @@ mod tests {
         assert!(DynamicList::type_ident() == "DynamicList");
         assert!(DynamicList::type_name() == "DynamicList");
     }
+
+    #[test]
+    fn positional_edit() {
+        let mut list: DynamicList = [1_u32, 2, 3, 4].into_iter().collect();
+        list.insert(0, Box::new(0_u32));
+        assert_eq!(list.remove(2).take::<u32>().unwrap(), 2);
+
+        list.retain(&mut |v| v.downcast_ref::<u32>() != Some(&3));
+        let tail = list.split_off(1);
+
+        assert_eq!(list.get(0).unwrap().downcast_ref::<u32>(), Some(&0));
+        assert_eq!(list.len(), 1);
+        let tail: Vec<u32> = tail.into_iter().map(|v| v.take().unwrap()).collect();
+        assert_eq!(tail, [1, 4]);
+    }
 }
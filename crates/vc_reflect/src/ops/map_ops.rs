@@ -7,7 +7,8 @@ use vc_utils::hash::{HashTable, hash_table};
 use crate::Reflect;
 use crate::impls::NonGenericTypeInfoCell;
 use crate::info::{OpaqueInfo, TypeInfo, TypePath, Typed};
-use crate::ops::{ApplyError, ReflectCloneError};
+use crate::ops::key_eq::key_hash;
+use crate::ops::{ApplyError, KeyEq, KeyEqError, ReflectCloneError};
 
 // -----------------------------------------------------------------------------
 // Dynamic Map
@@ -30,10 +31,12 @@ use crate::ops::{ApplyError, ReflectCloneError};
 /// # Key Requirements
 ///
 /// Keys in a `DynamicMap` must support:
-/// - Hashing via [`Reflect::reflect_hash`]
-/// - Equality comparison via [`Reflect::reflect_eq`]
+/// - Equality comparison via its [`KeyEq`], [`Reflect::reflect_eq`] by default
 /// - Self-equality (a key must be equal to itself)
 ///
+/// Keys are hashed via [`Reflect::reflect_hash`]. Keys without hashing support,
+/// such as floats, share a single bucket and are compared one by one.
+///
 /// # Examples
 ///
 /// ## Creating and populating a dynamic map
@@ -69,6 +72,7 @@ use crate::ops::{ApplyError, ReflectCloneError};
 #[derive(Default)]
 pub struct DynamicMap {
     info: Option<&'static TypeInfo>,
+    key_eq: KeyEq,
     hash_table: HashTable<(Box<dyn Reflect>, Box<dyn Reflect>)>,
 }
 
@@ -113,6 +117,7 @@ impl DynamicMap {
     pub const fn new() -> Self {
         Self {
             info: None,
+            key_eq: KeyEq::Eq,
             hash_table: HashTable::new(),
         }
     }
@@ -125,6 +130,7 @@ impl DynamicMap {
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             info: None,
+            key_eq: KeyEq::Eq,
             hash_table: HashTable::with_capacity(capacity),
         }
     }
@@ -149,6 +155,21 @@ impl DynamicMap {
         }
     }
 
+    /// Returns the [`KeyEq`] used to compare keys.
+    #[inline]
+    pub const fn key_eq(&self) -> KeyEq {
+        self.key_eq
+    }
+
+    /// Sets the [`KeyEq`] used to compare keys.
+    ///
+    /// This should be set before inserting keys, existing keys are not
+    /// deduplicated again.
+    #[inline]
+    pub const fn set_key_eq(&mut self, key_eq: KeyEq) {
+        self.key_eq = key_eq;
+    }
+
     /// Inserts a boxed key-value pair into the map.
     ///
     /// This is the low-level version of [`extend`] that accepts already-boxed values.
@@ -161,10 +182,11 @@ impl DynamicMap {
     /// # Panics
     ///
     /// Panics if:
-    /// - The key does not support [`Reflect::reflect_hash`]
-    /// - The key does not support [`Reflect::reflect_eq`]
+    /// - The key cannot be compared by the [`KeyEq`] of this map
     /// - The key is not equal to itself (violates reflexivity)
     ///
+    /// See [`try_extend_boxed`] for a version that returns an error instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -178,29 +200,66 @@ impl DynamicMap {
     /// ```
     ///
     /// [`extend`]: DynamicMap::extend
+    /// [`try_extend_boxed`]: DynamicMap::try_extend_boxed
     pub fn extend_boxed(
         &mut self,
         key: Box<dyn Reflect>,
         value: Box<dyn Reflect>,
     ) -> Option<Box<dyn Reflect>> {
-        debug_assert_eq!(
-            key.reflect_eq(&*key),
-            Some(true),
-            "The key is not `reflect_eq` to itself: `{}`.",
-            key.reflect_type_path(),
-        );
-
-        let hash = Self::internal_hash(&*key);
-        let eq = Self::internal_eq(&*key);
-        match self.hash_table.find_mut(hash, eq) {
-            Some((_, old)) => Some(core::mem::replace(old, value)),
+        self.try_extend_boxed(key, value)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Inserts a boxed key-value pair into the map, or returns an error if the
+    /// key cannot be compared by the [`KeyEq`] of this map.
+    ///
+    /// See [`extend_boxed`] for the returned value.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the key is not equal to itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_reflect::ops::DynamicMap;
+    /// # use vc_reflect::ops::KeyEq;
+    /// let mut map = DynamicMap::new();
+    /// map.set_key_eq(KeyEq::Custom(|_, _| None));
+    ///
+    /// let err = map.try_extend_boxed(Box::new(1_u32), Box::new("one")).unwrap_err();
+    /// assert_eq!(err.to_string(), "keys of type `u32` cannot be compared");
+    /// ```
+    ///
+    /// [`extend_boxed`]: DynamicMap::extend_boxed
+    pub fn try_extend_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    ) -> Result<Option<Box<dyn Reflect>>, KeyEqError> {
+        let key_eq = self.key_eq;
+        key_eq.check(&*key)?;
+
+        let mut error = None;
+        let eq = |(other, _): &(Box<dyn Reflect>, Box<dyn Reflect>)| {
+            key_eq.eq(&*key, &**other).unwrap_or_else(|| {
+                error = Some(KeyEqError::new(&*key, &**other));
+                false
+            })
+        };
+        let found = self.hash_table.find_mut(key_hash(&*key), eq);
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        match found {
+            Some((_, old)) => Ok(Some(core::mem::replace(old, value))),
             None => {
-                self.hash_table.insert_unique(
-                    Self::internal_hash(key.as_ref()),
-                    (key, value),
-                    |(key, _)| Self::internal_hash(&**key),
-                );
-                None
+                self.hash_table
+                    .insert_unique(key_hash(key.as_ref()), (key, value), |(key, _)| {
+                        key_hash(&**key)
+                    });
+                Ok(None)
             }
         }
     }
@@ -218,8 +277,7 @@ impl DynamicMap {
     /// # Panics
     ///
     /// Panics if:
-    /// - The key does not support [`Reflect::reflect_hash`]
-    /// - The key does not support [`Reflect::reflect_eq`]
+    /// - The key cannot be compared by the [`KeyEq`] of this map
     /// - The key is not equal to itself (violates reflexivity)
     ///
     /// # Examples
@@ -240,31 +298,19 @@ impl DynamicMap {
         self.extend_boxed(Box::new(key), Box::new(value))
     }
 
-    /// Computes the hash of a value for internal use.
+    /// Creates an equality comparison function for a key.
     ///
     /// # Panics
     ///
-    /// Panics if the value does not support `reflect_hash`.
-    fn internal_hash(value: &dyn Reflect) -> u64 {
-        value.reflect_hash().unwrap_or_else(|| {
-            panic!(
-                "the given value of type `{}` does not support reflect hashing",
-                value.reflect_type_path(),
-            );
-        })
-    }
-
-    /// Creates an equality comparison function for a key.
+    /// The function panics if the keys cannot be compared by `key_eq`.
     fn internal_eq(
+        key_eq: KeyEq,
         key: &dyn Reflect,
     ) -> impl FnMut(&(Box<dyn Reflect>, Box<dyn Reflect>)) -> bool + '_ {
-        |(other, _)| {
-            key.reflect_eq(&**other).unwrap_or_else(|| {
-                panic!(
-                    "the given value of type `{}` does not support reflect hashing",
-                    other.reflect_type_path(),
-                )
-            })
+        move |(other, _)| {
+            key_eq
+                .eq(key, &**other)
+                .unwrap_or_else(|| panic!("{}", KeyEqError::new(key, &**other)))
         }
     }
 }
@@ -521,6 +567,35 @@ pub trait Map: Reflect {
     /// Remove all pairs `(k, v)` for which `f(&k, &mut v)` returns `false`.
     fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect, &mut dyn Reflect) -> bool);
 
+    /// Moves all key-value pairs of `other` into this map.
+    ///
+    /// Existing values are replaced, as if each pair was passed to [`Map::insert`].
+    /// Implementations may reserve space for all pairs in advance.
+    ///
+    /// # Panics
+    ///
+    /// May panic if a key or value is incompatible with the map, see [`Map::insert`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_reflect::ops::{Map, DynamicMap};
+    /// # use std::collections::BTreeMap;
+    /// let mut map: BTreeMap<&str, i32> = [("a", 1)].into_iter().collect();
+    ///
+    /// let other: DynamicMap = [("a", 10), ("b", 2)].into_iter().collect();
+    /// let map_ref: &mut dyn Map = &mut map;
+    /// map_ref.extend_from(other);
+    ///
+    /// assert_eq!(map.get("a"), Some(&10));
+    /// assert_eq!(map.get("b"), Some(&2));
+    /// ```
+    fn extend_from(&mut self, other: DynamicMap) {
+        for (key, value) in other {
+            self.insert(key, value);
+        }
+    }
+
     /// Creates a new [`DynamicMap`] from this map.
     ///
     /// This method converts the map to a dynamic representation.
@@ -638,14 +713,14 @@ impl Map for DynamicMap {
     #[inline]
     fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
         self.hash_table
-            .find(Self::internal_hash(key), Self::internal_eq(key))
+            .find(key_hash(key), Self::internal_eq(self.key_eq, key))
             .map(|(_, value)| &**value)
     }
 
     #[inline]
     fn get_mut(&mut self, key: &dyn Reflect) -> Option<&mut dyn Reflect> {
         self.hash_table
-            .find_mut(Self::internal_hash(key), Self::internal_eq(key))
+            .find_mut(key_hash(key), Self::internal_eq(self.key_eq, key))
             .map(|(_, value)| &mut **value)
     }
 
@@ -671,6 +746,26 @@ impl Map for DynamicMap {
             .retain(move |(key, value)| f(&**key, &mut **value));
     }
 
+    fn to_dynamic_map(&self) -> DynamicMap {
+        // Keeps the `KeyEq`, so keys that need it can be inserted again.
+        let mut map = DynamicMap::with_capacity(self.len());
+        map.info = self.info;
+        map.key_eq = self.key_eq;
+        for (key, value) in self.hash_table.iter() {
+            let key = key.reflect_clone().unwrap_or_else(|_| key.to_dynamic());
+            map.extend_boxed(key, value.to_dynamic());
+        }
+        map
+    }
+
+    fn extend_from(&mut self, other: DynamicMap) {
+        self.hash_table
+            .reserve(other.len(), |(key, _)| key_hash(&**key));
+        for (key, value) in other {
+            self.extend_boxed(key, value);
+        }
+    }
+
     #[inline]
     fn insert(
         &mut self,
@@ -690,8 +785,8 @@ impl Map for DynamicMap {
     }
 
     fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
-        let hash = Self::internal_hash(key);
-        let eq = Self::internal_eq(key);
+        let hash = key_hash(key);
+        let eq = Self::internal_eq(self.key_eq, key);
         match self.hash_table.find_entry(hash, eq) {
             Ok(entry) => {
                 let ((_, old_value), _) = entry.remove();
@@ -761,8 +856,13 @@ impl dyn Map {
 
 #[cfg(test)]
 mod tests {
-    use super::DynamicMap;
+    use alloc::boxed::Box;
+    use alloc::string::ToString;
+
+    use super::{DynamicMap, Map};
     use crate::info::TypePath;
+    use crate::ops::KeyEq;
+    use vc_utils::hash::HashMap;
 
     #[test]
     fn type_path() {
@@ -771,4 +871,45 @@ mod tests {
         assert!(DynamicMap::type_ident() == "DynamicMap");
         assert!(DynamicMap::type_name() == "DynamicMap");
     }
+
+    #[test]
+    fn extend_from() {
+        let mut dynamic: DynamicMap = [(1_u32, 10_i32), (2, 20)].into_iter().collect();
+        dynamic.extend_from([(2_u32, 200_i32), (3, 300)].into_iter().collect());
+        assert_eq!(dynamic.len(), 3);
+        assert_eq!(<dyn Map>::get_as::<i32>(&dynamic, &2_u32), Some(&200));
+
+        let mut map: HashMap<u32, i32> = HashMap::new();
+        map.insert(1, 1);
+        <dyn Map>::extend_from(&mut map, dynamic);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get(&1), Some(&10));
+        assert_eq!(map.get(&3), Some(&300));
+    }
+
+    #[test]
+    fn unhashable_keys() {
+        let mut dynamic = DynamicMap::new();
+        dynamic.extend(1.5_f32, 1_i32);
+        dynamic.extend(2.5_f32, 2_i32);
+        dynamic.extend(1_u32, 3_i32);
+        assert!(dynamic.extend(1.5_f32, 10_i32).is_some());
+        assert_eq!(dynamic.len(), 3);
+        assert_eq!(<dyn Map>::get_as::<i32>(&dynamic, &1.5_f32), Some(&10));
+        assert!(dynamic.remove(&2.5_f32).is_some());
+        assert!(dynamic.get(&2.5_f32).is_none());
+        assert!(dynamic.remove(&1_u32).is_some());
+
+        dynamic.set_key_eq(KeyEq::Custom(|a, b| {
+            (a.reflect_type_path() == "f32").then(|| a.reflect_eq(b).unwrap_or(false))
+        }));
+        let err = dynamic
+            .try_extend_boxed(Box::new(2_u32), Box::new(0_i32))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "keys of type `u32` cannot be compared");
+
+        let cloned = dynamic.to_dynamic_map();
+        assert!(matches!(cloned.key_eq(), KeyEq::Custom(_)));
+        assert_eq!(cloned.len(), 1);
+    }
 }
@@ -49,9 +49,11 @@ mod apply_error;
 mod array_ops;
 mod clone_error;
 mod enum_ops;
+mod key_eq;
 mod kind;
 mod list_ops;
 mod map_ops;
+mod name;
 mod set_ops;
 mod struct_ops;
 mod tuple_ops;
@@ -64,6 +66,7 @@ mod variant_ops;
 pub use apply_error::ApplyError;
 pub use clone_error::ReflectCloneError;
 
+pub use key_eq::{KeyEq, KeyEqError};
 pub use kind::{ReflectMut, ReflectOwned, ReflectRef};
 
 pub use array_ops::{Array, ArrayItemIter, DynamicArray};
@@ -71,7 +74,9 @@ pub use enum_ops::{DynamicEnum, Enum};
 pub use list_ops::{DynamicList, List, ListItemIter};
 pub use map_ops::{DynamicMap, Map};
 pub use set_ops::{DynamicSet, Set};
-pub use struct_ops::{DynamicStruct, Struct, StructFieldIter};
+pub use struct_ops::{DynamicStruct, Struct, StructFieldInfoIter, StructFieldIter};
 pub use tuple_ops::{DynamicTuple, Tuple, TupleFieldIter};
-pub use tuple_struct_ops::{DynamicTupleStruct, TupleStruct, TupleStructFieldIter};
+pub use tuple_struct_ops::{DynamicTupleStruct, TupleStruct};
+pub use tuple_struct_ops::{TupleStructFieldInfoIter, TupleStructFieldIter};
 pub use variant_ops::{DynamicVariant, VariantField, VariantFieldIter};
+pub use variant_ops::{VariantFieldInfo, VariantFieldInfoIter};
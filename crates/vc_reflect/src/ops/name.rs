@@ -0,0 +1,44 @@
+//! Storage of field and variant names in dynamic types.
+//!
+//! Names are usually short, with the `small_str` feature they are stored
+//! inline instead of allocating, and cloning a dynamic value does not copy
+//! its names to the heap.
+
+use alloc::borrow::Cow;
+
+crate::cfg::small_str! {
+    if {
+        /// A field or variant name.
+        pub(crate) type Name = smol_str::SmolStr;
+
+        /// Converts a name, without allocating if it is borrowed.
+        #[inline]
+        pub(crate) fn from_cow(name: Cow<'static, str>) -> Name {
+            match name {
+                Cow::Borrowed(name) => Name::new_static(name),
+                Cow::Owned(name) => Name::from(name),
+            }
+        }
+
+        /// Copies a name, without allocating if it is short.
+        #[inline]
+        pub(crate) fn copy(name: &str) -> Name {
+            Name::new(name)
+        }
+    } else {
+        /// A field or variant name.
+        pub(crate) type Name = Cow<'static, str>;
+
+        /// Converts a name, without allocating if it is borrowed.
+        #[inline(always)]
+        pub(crate) fn from_cow(name: Cow<'static, str>) -> Name {
+            name
+        }
+
+        /// Copies a name.
+        #[inline]
+        pub(crate) fn copy(name: &str) -> Name {
+            Cow::Owned(name.into())
+        }
+    }
+}
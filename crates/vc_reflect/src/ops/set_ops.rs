@@ -7,7 +7,8 @@ use vc_utils::hash::{HashTable, hash_table};
 use crate::Reflect;
 use crate::impls::NonGenericTypeInfoCell;
 use crate::info::{OpaqueInfo, TypeInfo, TypePath, Typed};
-use crate::ops::{ApplyError, ReflectCloneError};
+use crate::ops::key_eq::key_hash;
+use crate::ops::{ApplyError, KeyEq, KeyEqError, ReflectCloneError};
 use crate::reflection::impl_reflect_cast_fn;
 
 // -----------------------------------------------------------------------------
@@ -31,10 +32,12 @@ use crate::reflection::impl_reflect_cast_fn;
 /// # Value Requirements
 ///
 /// Values stored in a `DynamicSet` must support:
-/// - Hashing via [`Reflect::reflect_hash`]
-/// - Equality comparison via [`Reflect::reflect_eq`]
+/// - Equality comparison via its [`KeyEq`], [`Reflect::reflect_eq`] by default
 /// - Self-equality (a value must be equal to itself)
 ///
+/// Values are hashed via [`Reflect::reflect_hash`]. Values without hashing
+/// support, such as floats, share a single bucket and are compared one by one.
+///
 /// # Examples
 ///
 /// ## Creating and populating a dynamic set
@@ -71,6 +74,7 @@ use crate::reflection::impl_reflect_cast_fn;
 #[derive(Default)]
 pub struct DynamicSet {
     info: Option<&'static TypeInfo>,
+    key_eq: KeyEq,
     hash_table: HashTable<Box<dyn Reflect>>,
 }
 
@@ -123,6 +127,7 @@ impl DynamicSet {
     pub const fn new() -> Self {
         Self {
             info: None,
+            key_eq: KeyEq::Eq,
             hash_table: HashTable::new(),
         }
     }
@@ -135,6 +140,7 @@ impl DynamicSet {
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             info: None,
+            key_eq: KeyEq::Eq,
             hash_table: HashTable::with_capacity(capacity),
         }
     }
@@ -161,6 +167,21 @@ impl DynamicSet {
         }
     }
 
+    /// Returns the [`KeyEq`] used to compare values.
+    #[inline]
+    pub const fn key_eq(&self) -> KeyEq {
+        self.key_eq
+    }
+
+    /// Sets the [`KeyEq`] used to compare values.
+    ///
+    /// This should be set before inserting values, existing values are not
+    /// deduplicated again.
+    #[inline]
+    pub const fn set_key_eq(&mut self, key_eq: KeyEq) {
+        self.key_eq = key_eq;
+    }
+
     /// Inserts a boxed value into the set.
     ///
     /// This is the low-level insertion API that accepts an already-boxed
@@ -172,8 +193,9 @@ impl DynamicSet {
     ///
     /// # Panics
     ///
-    /// Panics if the value does not support `reflect_hash` or
-    /// `reflect_eq`, or if it is not equal to itself.
+    /// Panics if the value cannot be compared by the [`KeyEq`] of this set,
+    /// or if it is not equal to itself. See [`try_extend_boxed`] for a version
+    /// that returns an error instead.
     ///
     /// # Examples
     ///
@@ -183,29 +205,49 @@ impl DynamicSet {
     /// assert!(set.extend_boxed(Box::new("a")));
     /// assert!(!set.extend_boxed(Box::new("a")));
     /// ```
+    ///
+    /// [`try_extend_boxed`]: DynamicSet::try_extend_boxed
     pub fn extend_boxed(&mut self, value: Box<dyn Reflect>) -> bool {
-        debug_assert_eq!(
-            value.reflect_eq(&*value),
-            Some(true),
-            "The value is not `reflect_eq` to itself: `{}`.",
-            value.reflect_type_path(),
-        );
-
-        match self
-            .hash_table
-            .find_mut(Self::internal_hash(&*value), Self::internal_eq(&*value))
-        {
+        self.try_extend_boxed(value)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Inserts a boxed value into the set, or returns an error if the value
+    /// cannot be compared by the [`KeyEq`] of this set.
+    ///
+    /// See [`extend_boxed`] for the returned value.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the value is not equal to itself.
+    ///
+    /// [`extend_boxed`]: DynamicSet::extend_boxed
+    pub fn try_extend_boxed(&mut self, value: Box<dyn Reflect>) -> Result<bool, KeyEqError> {
+        let key_eq = self.key_eq;
+        key_eq.check(&*value)?;
+
+        let mut error = None;
+        let found = self.hash_table.find_mut(key_hash(&*value), |other| {
+            key_eq.eq(&*value, &**other).unwrap_or_else(|| {
+                error = Some(KeyEqError::new(&*value, &**other));
+                false
+            })
+        });
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        match found {
             Some(old) => {
                 *old = value;
-                false
+                Ok(false)
             }
             None => {
-                self.hash_table.insert_unique(
-                    Self::internal_hash(value.as_ref()),
-                    value,
-                    |boxed| Self::internal_hash(boxed.as_ref()),
-                );
-                true
+                self.hash_table
+                    .insert_unique(key_hash(value.as_ref()), value, |boxed| {
+                        key_hash(boxed.as_ref())
+                    });
+                Ok(true)
             }
         }
     }
@@ -230,34 +272,19 @@ impl DynamicSet {
         self.extend_boxed(Box::new(value))
     }
 
-    /// Compute the internal hash used by the set for a value.
-    ///
-    /// This calls `Reflect::reflect_hash` and panics if hashing is not
-    /// supported by the value. The returned `u64` is used by the underlying
-    /// hash table for indexing and lookups.
-    fn internal_hash(value: &dyn Reflect) -> u64 {
-        value.reflect_hash().unwrap_or_else(|| {
-            panic!(
-                "the given value of type `{}` does not support reflect hashing",
-                value.reflect_type_path(),
-            );
-        })
-    }
-
     /// Creates an equality predicate for comparing an owned boxed value with
     /// entries in the hash table.
     ///
-    /// The returned closure compares `value` with `other` using
-    /// `Reflect::reflect_eq`, and will panic if that operation is not
-    /// supported on the compared value.
-    fn internal_eq(value: &dyn Reflect) -> impl FnMut(&Box<dyn Reflect>) -> bool + '_ {
-        |other| {
-            value.reflect_eq(&**other).unwrap_or_else(|| {
-                panic!(
-                    "the given value of type `{}` does not support reflect hashing",
-                    other.reflect_type_path(),
-                )
-            })
+    /// The returned closure compares `value` with `other` using `key_eq`,
+    /// and will panic if they cannot be compared.
+    fn internal_eq(
+        key_eq: KeyEq,
+        value: &dyn Reflect,
+    ) -> impl FnMut(&Box<dyn Reflect>) -> bool + '_ {
+        move |other| {
+            key_eq
+                .eq(value, &**other)
+                .unwrap_or_else(|| panic!("{}", KeyEqError::new(value, &**other)))
         }
     }
 }
@@ -501,6 +528,36 @@ pub trait Set: Reflect {
     /// ```
     fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect) -> bool);
 
+    /// Moves all values of `other` into this set.
+    ///
+    /// Equal values are replaced, as if each value was passed to [`Set::insert`].
+    /// Implementations may reserve space for all values in advance.
+    ///
+    /// # Panics
+    ///
+    /// May panic if a value is incompatible with the set, see [`Set::insert`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use vc_reflect::ops::{DynamicSet, Set};
+    ///
+    /// let mut set: BTreeSet<i32> = [1, 2].into_iter().collect();
+    ///
+    /// let other: DynamicSet = [2, 3].into_iter().collect();
+    /// let set_ref: &mut dyn Set = &mut set;
+    /// set_ref.extend_from(other);
+    ///
+    /// assert_eq!(set_ref.len(), 3);
+    /// assert!(set_ref.contains(&3));
+    /// ```
+    fn extend_from(&mut self, other: DynamicSet) {
+        for value in other {
+            self.insert(value);
+        }
+    }
+
     /// Creates a new [`DynamicSet`] from this set.
     ///
     /// Usually, `to_dynamic_map` recursively converts all data to a dynamic type,
@@ -614,7 +671,7 @@ impl Set for DynamicSet {
     #[inline]
     fn get(&self, value: &dyn Reflect) -> Option<&dyn Reflect> {
         self.hash_table
-            .find(Self::internal_hash(value), Self::internal_eq(value))
+            .find(key_hash(value), Self::internal_eq(self.key_eq, value))
             .map(Deref::deref)
     }
 
@@ -638,6 +695,25 @@ impl Set for DynamicSet {
         self.hash_table.retain(move |value| f(&**value));
     }
 
+    fn to_dynamic_set(&self) -> DynamicSet {
+        // Keeps the `KeyEq`, so values that need it can be inserted again.
+        let mut set = DynamicSet::with_capacity(self.len());
+        set.info = self.info;
+        set.key_eq = self.key_eq;
+        for value in self.hash_table.iter() {
+            set.extend_boxed(value.reflect_clone().unwrap_or_else(|_| value.to_dynamic()));
+        }
+        set
+    }
+
+    fn extend_from(&mut self, other: DynamicSet) {
+        self.hash_table
+            .reserve(other.len(), |value| key_hash(&**value));
+        for value in other {
+            self.extend_boxed(value);
+        }
+    }
+
     fn insert(&mut self, value: Box<dyn Reflect>) -> bool {
         self.extend_boxed(value)
     }
@@ -650,7 +726,7 @@ impl Set for DynamicSet {
     #[inline]
     fn remove(&mut self, value: &dyn Reflect) -> bool {
         self.hash_table
-            .find_entry(Self::internal_hash(value), Self::internal_eq(value))
+            .find_entry(key_hash(value), Self::internal_eq(self.key_eq, value))
             .map(hash_table::OccupiedEntry::remove)
             .is_ok()
     }
@@ -658,7 +734,7 @@ impl Set for DynamicSet {
     #[inline]
     fn contains(&self, value: &dyn Reflect) -> bool {
         self.hash_table
-            .find(Self::internal_hash(value), Self::internal_eq(value))
+            .find(key_hash(value), Self::internal_eq(self.key_eq, value))
             .is_some()
     }
 }
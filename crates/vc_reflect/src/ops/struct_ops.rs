@@ -1,4 +1,4 @@
-use alloc::borrow::{Cow, ToOwned};
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::vec::Vec;
 use core::cmp::Ordering;
@@ -10,7 +10,8 @@ use vc_utils::hash::HashMap;
 
 use crate::Reflect;
 use crate::impls::NonGenericTypeInfoCell;
-use crate::info::{OpaqueInfo, TypeInfo, TypePath, Typed};
+use crate::info::{NamedField, OpaqueInfo, StructInfo, TypeInfo, TypePath, Typed};
+use crate::ops::name::{self, Name};
 use crate::ops::{ApplyError, ReflectCloneError};
 use crate::reflection::impl_reflect_cast_fn;
 
@@ -90,8 +91,8 @@ use crate::reflection::impl_reflect_cast_fn;
 pub struct DynamicStruct {
     info: Option<&'static TypeInfo>,
     fields: Vec<Box<dyn Reflect>>,
-    field_names: Vec<Cow<'static, str>>,
-    field_indices: HashMap<Cow<'static, str>, usize>,
+    field_names: Vec<Name>,
+    field_indices: HashMap<Name, usize>,
 }
 
 // Explicitly implemented here so that code readers do not need
@@ -199,8 +200,12 @@ impl DynamicStruct {
     ///
     /// [`extend`]: DynamicStruct::extend
     pub fn extend_boxed(&mut self, name: impl Into<Cow<'static, str>>, value: Box<dyn Reflect>) {
-        let name: Cow<'static, str> = name.into();
-        if let Some(index) = self.field_indices.get(&name) {
+        self.extend_named(name::from_cow(name.into()), value);
+    }
+
+    /// Appends a field with an already converted name.
+    pub(crate) fn extend_named(&mut self, name: Name, value: Box<dyn Reflect>) {
+        if let Some(index) = self.field_indices.get(&*name) {
             self.fields[*index] = value;
         } else {
             self.fields.push(value);
@@ -500,7 +505,7 @@ pub trait Struct: Reflect {
         let mut dynamic_struct = DynamicStruct::with_capacity(self.field_len());
         dynamic_struct.set_type_info(self.represented_type_info());
         for (i, val) in self.iter_fields().enumerate() {
-            dynamic_struct.extend_boxed(self.name_at(i).unwrap().to_owned(), val.to_dynamic());
+            dynamic_struct.extend_named(name::copy(self.name_at(i).unwrap()), val.to_dynamic());
         }
         dynamic_struct
     }
@@ -663,6 +668,36 @@ impl dyn Struct {
         self.field_at_mut(index)
             .and_then(<dyn Reflect>::downcast_mut)
     }
+
+    /// Returns an iterator over the fields of the struct, together with their
+    /// [`NamedField`] information.
+    ///
+    /// The information comes from [`represented_type_info`] and is matched by
+    /// field name, it is `None` if the struct has no type information, or the
+    /// type information has no such field.
+    ///
+    /// [`represented_type_info`]: Reflect::represented_type_info
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_reflect::{ops::Struct, Reflect};
+    /// #[derive(Reflect)]
+    /// struct Foo{ a: i32, b: bool };
+    ///
+    /// let foo = Foo{ a: 1, b: true };
+    /// let foo_ref: &dyn Struct = &foo;
+    ///
+    /// let mut iter = foo_ref.iter_fields_with_info();
+    /// let (info, value) = iter.next().unwrap();
+    /// assert_eq!(info.unwrap().name(), "a");
+    /// assert!(info.unwrap().type_is::<i32>());
+    /// assert_eq!(value.downcast_ref::<i32>(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn iter_fields_with_info(&self) -> StructFieldInfoIter<'_> {
+        StructFieldInfoIter::new(self)
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -728,6 +763,76 @@ impl<'a> Iterator for StructFieldIter<'a> {
 impl ExactSizeIterator for StructFieldIter<'_> {}
 impl FusedIterator for StructFieldIter<'_> {}
 
+/// An iterator over the fields of a struct, with their [`NamedField`] information.
+///
+/// This is an [`ExactSizeIterator`] that yields the information and the value
+/// of each field in order, see [`iter_fields_with_info`] for details.
+///
+/// [`iter_fields_with_info`]: <dyn Struct>::iter_fields_with_info
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::{Reflect, ops::{Struct, StructFieldInfoIter}};
+///
+/// #[derive(Reflect)]
+/// struct Foo{ a: i32, b: bool };
+///
+/// let ts = Foo{ a: 1, b: true };
+/// let mut iter = StructFieldInfoIter::new(&ts);
+///
+/// assert_eq!(iter.len(), 2);
+/// let (info, value) = iter.next().unwrap();
+/// assert_eq!(info.unwrap().name(), "a");
+/// assert_eq!(value.downcast_ref::<i32>(), Some(&1));
+/// ```
+pub struct StructFieldInfoIter<'a> {
+    struct_val: &'a dyn Struct,
+    info: Option<&'static StructInfo>,
+    index: usize,
+}
+
+impl<'a> StructFieldInfoIter<'a> {
+    /// Creates a new iterator for the given struct.
+    #[inline]
+    pub fn new(value: &'a dyn Struct) -> Self {
+        StructFieldInfoIter {
+            struct_val: value,
+            info: value
+                .represented_type_info()
+                .and_then(|info| info.as_struct().ok()),
+            index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for StructFieldInfoIter<'a> {
+    type Item = (Option<&'static NamedField>, &'a dyn Reflect);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.struct_val.field_at(self.index)?;
+        let info = self.info.and_then(|info| {
+            let name = self.struct_val.name_at(self.index)?;
+            // Dynamic structs may order their fields differently.
+            match info.field_at(self.index) {
+                Some(field) if field.name() == name => Some(field),
+                _ => info.field(name),
+            }
+        });
+        self.index += 1;
+        Some((info, value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = self.struct_val.field_len() - self.index;
+        (hint, Some(hint))
+    }
+}
+
+impl ExactSizeIterator for StructFieldInfoIter<'_> {}
+impl FusedIterator for StructFieldInfoIter<'_> {}
+
 // -----------------------------------------------------------------------------
 // Tests
 
@@ -743,4 +848,156 @@ mod tests {
         assert!(DynamicStruct::type_ident() == "DynamicStruct");
         assert!(DynamicStruct::type_name() == "DynamicStruct");
     }
+
+    mod foreign {
+        #[derive(Default, Debug, PartialEq)]
+        pub struct Body {
+            mass: f32,
+            inv_mass: f32,
+            name: u32,
+        }
+
+        impl Body {
+            pub fn mass(&self) -> &f32 {
+                &self.mass
+            }
+
+            pub fn set_mass(&mut self, mass: f32) {
+                self.mass = mass;
+                self.inv_mass = 1.0 / mass;
+            }
+
+            pub fn inv_mass(&self) -> &f32 {
+                &self.inv_mass
+            }
+
+            pub fn name(&self) -> &u32 {
+                &self.name
+            }
+
+            pub fn name_mut(&mut self) -> &mut u32 {
+                &mut self.name
+            }
+        }
+    }
+
+    use foreign::Body;
+
+    crate::derive::impl_reflect! {
+        #[reflect(type_path = "foreign::Body", default)]
+        struct Body {
+            #[reflect(get = Body::mass, set = Body::set_mass)]
+            mass: f32,
+            #[reflect(get = Body::inv_mass)]
+            inv_mass: f32,
+            #[reflect(get = Body::name, get_mut = Body::name_mut)]
+            name: u32,
+        }
+    }
+
+    #[test]
+    fn field_accessors() {
+        use super::Struct;
+        use crate::{FromReflect, Reflect};
+
+        let mut body = Body::default();
+        body.set_mass(2.0);
+        assert_eq!(
+            body.field("inv_mass").unwrap().downcast_ref(),
+            Some(&0.5_f32)
+        );
+        assert!(body.field_mut("mass").is_none());
+        assert!(body.field_mut("inv_mass").is_none());
+        *body
+            .field_mut("name")
+            .unwrap()
+            .downcast_mut::<u32>()
+            .unwrap() = 3;
+
+        // `mass` is applied through the setter, which also updates `inv_mass`.
+        let mut patch = DynamicStruct::default();
+        patch.extend("mass", 4.0_f32);
+        body.apply(&patch).unwrap();
+        assert_eq!(*body.inv_mass(), 0.25);
+        assert_eq!(*body.name(), 3);
+
+        let cloned = body.reflect_clone().unwrap().take::<Body>().unwrap();
+        assert_eq!(cloned, body);
+        assert_eq!(Body::from_reflect(&*body.to_dynamic()), Some(body));
+    }
+
+    #[test]
+    fn fields_with_info() {
+        use super::Struct;
+        use crate::info::Typed;
+
+        let mut dynamic = DynamicStruct::default();
+        dynamic.extend("name", 1_u32);
+        dynamic.extend("mass", 2.0_f32);
+        dynamic.extend("extra", false);
+
+        let names = |value: &dyn Struct| {
+            value
+                .iter_fields_with_info()
+                .map(|(info, _)| info.map(|info| info.name()))
+                .collect::<alloc::vec::Vec<_>>()
+        };
+        assert_eq!(names(&dynamic), [None, None, None]);
+
+        dynamic.set_type_info(Some(Body::type_info()));
+        assert_eq!(names(&dynamic), [Some("name"), Some("mass"), None]);
+        assert_eq!(
+            names(&Body::default()),
+            [Some("mass"), Some("inv_mass"), Some("name")]
+        );
+    }
+
+    #[derive(crate::derive::Reflect, Debug, PartialEq)]
+    #[reflect(where T: Clone)]
+    struct Stack<T> {
+        items: alloc::vec::Vec<T>,
+    }
+
+    #[test]
+    fn custom_where() {
+        use super::Struct;
+        use crate::{FromReflect, Reflect};
+
+        let stack = Stack {
+            items: alloc::vec![1_u32, 2],
+        };
+        assert_eq!(stack.field_len(), 1);
+        assert_eq!(
+            Stack::<u32>::from_reflect(&*stack.to_dynamic()),
+            Some(stack)
+        );
+    }
+
+    /// `PhantomData<T>` only needs `T: TypePath + Send + Sync`.
+    #[derive(crate::derive::Reflect, Debug, PartialEq)]
+    #[reflect(no_field_bounds)]
+    struct Marker<T> {
+        id: u32,
+        marker: core::marker::PhantomData<T>,
+    }
+
+    #[test]
+    fn no_field_bounds() {
+        use super::Struct;
+        use crate::{FromReflect, Reflect};
+
+        /// Implements `TypePath`, but not `Reflect`.
+        #[derive(crate::derive::TypePath, Debug, PartialEq)]
+        struct Tag;
+
+        let marker = Marker::<Tag> {
+            id: 3,
+            marker: core::marker::PhantomData,
+        };
+        assert_eq!(marker.field_len(), 2);
+        assert_eq!(
+            Marker::<Tag>::from_reflect(&*marker.to_dynamic()),
+            Some(marker)
+        );
+    }
 }
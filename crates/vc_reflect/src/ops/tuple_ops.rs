@@ -339,7 +339,7 @@ impl<'a> IntoIterator for &'a DynamicTuple {
 /// - Rust tuples (`(T, U, V)`)
 /// - Types that can be viewed as tuples through reflection
 ///
-/// This trait is automatically implemented for arbitrary tuples of up to **12**
+/// This trait is automatically implemented for arbitrary tuples of up to **16**
 /// elements, provided that each element implements [`Reflect`].
 ///
 /// # Contract
@@ -631,7 +631,8 @@ impl FusedIterator for TupleFieldIter<'_> {}
 
 #[cfg(test)]
 mod tests {
-    use super::DynamicTuple;
+    use super::{DynamicTuple, Tuple};
+    use crate::FromReflect;
     use crate::info::TypePath;
 
     #[test]
@@ -641,4 +642,34 @@ mod tests {
         assert!(DynamicTuple::type_ident() == "DynamicTuple");
         assert!(DynamicTuple::type_name() == "DynamicTuple");
     }
+
+    #[test]
+    fn large_tuple() {
+        type Large = (
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u16,
+        );
+        let value: Large = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+
+        assert_eq!(value.field_len(), 16);
+        assert_eq!(value.field(15).unwrap().downcast_ref::<u16>(), Some(&15));
+
+        let dynamic = value.to_dynamic_tuple();
+        let output = Large::from_reflect(&dynamic).unwrap();
+        assert_eq!(output.15, 15);
+    }
 }
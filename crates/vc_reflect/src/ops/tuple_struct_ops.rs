@@ -5,7 +5,7 @@ use core::iter::FusedIterator;
 
 use crate::Reflect;
 use crate::impls::NonGenericTypeInfoCell;
-use crate::info::{OpaqueInfo, TypeInfo, TypePath, Typed};
+use crate::info::{OpaqueInfo, TupleStructInfo, TypeInfo, TypePath, Typed, UnnamedField};
 use crate::ops::{ApplyError, ReflectCloneError};
 use crate::reflection::impl_reflect_cast_fn;
 
@@ -478,6 +478,36 @@ impl dyn TupleStruct {
     pub fn field_mut_as<T: Reflect>(&mut self, index: usize) -> Option<&mut T> {
         self.field_mut(index).and_then(<dyn Reflect>::downcast_mut)
     }
+
+    /// Returns an iterator over the fields of the tuple-struct, together with
+    /// their [`UnnamedField`] information.
+    ///
+    /// The information comes from [`represented_type_info`], it is `None` if
+    /// the tuple-struct has no type information, or the type information has
+    /// fewer fields.
+    ///
+    /// [`represented_type_info`]: Reflect::represented_type_info
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_reflect::{ops::TupleStruct, Reflect};
+    /// #[derive(Reflect)]
+    /// struct Foo(i32, &'static str);
+    ///
+    /// let ts = Foo(10_i32, "hello");
+    /// let ts_ref: &dyn TupleStruct = &ts;
+    ///
+    /// let mut iter = ts_ref.iter_fields_with_info();
+    /// let (info, value) = iter.next().unwrap();
+    /// assert_eq!(info.unwrap().index(), 0);
+    /// assert!(info.unwrap().type_is::<i32>());
+    /// assert_eq!(value.downcast_ref::<i32>(), Some(&10));
+    /// ```
+    #[inline]
+    pub fn iter_fields_with_info(&self) -> TupleStructFieldInfoIter<'_> {
+        TupleStructFieldInfoIter::new(self)
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -543,6 +573,70 @@ impl<'a> Iterator for TupleStructFieldIter<'a> {
 impl ExactSizeIterator for TupleStructFieldIter<'_> {}
 impl FusedIterator for TupleStructFieldIter<'_> {}
 
+/// An iterator over the fields of a tuple-struct, with their [`UnnamedField`] information.
+///
+/// This is an [`ExactSizeIterator`] that yields the information and the value
+/// of each field in order, see [`iter_fields_with_info`] for details.
+///
+/// [`iter_fields_with_info`]: <dyn TupleStruct>::iter_fields_with_info
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::{Reflect, ops::{TupleStruct, TupleStructFieldInfoIter}};
+///
+/// #[derive(Reflect)]
+/// struct Foo(i32, &'static str, bool);
+///
+/// let ts = Foo(1, "test", true);
+/// let mut iter = TupleStructFieldInfoIter::new(&ts);
+///
+/// assert_eq!(iter.len(), 3);
+/// let (info, value) = iter.next().unwrap();
+/// assert!(info.unwrap().type_is::<i32>());
+/// assert_eq!(value.downcast_ref::<i32>(), Some(&1));
+/// ```
+pub struct TupleStructFieldInfoIter<'a> {
+    tuple_struct: &'a dyn TupleStruct,
+    info: Option<&'static TupleStructInfo>,
+    index: usize,
+}
+
+impl<'a> TupleStructFieldInfoIter<'a> {
+    /// Creates a new iterator for the given tuple-struct.
+    #[inline]
+    pub fn new(value: &'a dyn TupleStruct) -> Self {
+        TupleStructFieldInfoIter {
+            tuple_struct: value,
+            info: value
+                .represented_type_info()
+                .and_then(|info| info.as_tuple_struct().ok()),
+            index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for TupleStructFieldInfoIter<'a> {
+    type Item = (Option<&'static UnnamedField>, &'a dyn Reflect);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.tuple_struct.field(self.index)?;
+        let info = self.info.and_then(|info| info.field_at(self.index));
+        self.index += 1;
+        Some((info, value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = self.tuple_struct.field_len() - self.index;
+        (hint, Some(hint))
+    }
+}
+
+impl ExactSizeIterator for TupleStructFieldInfoIter<'_> {}
+impl FusedIterator for TupleStructFieldInfoIter<'_> {}
+
 // -----------------------------------------------------------------------------
 // Tests
 
@@ -1,7 +1,7 @@
 use core::iter::FusedIterator;
 
 use crate::Reflect;
-use crate::info::VariantKind;
+use crate::info::{NamedField, TypeInfo, UnnamedField, VariantInfo, VariantKind};
 use crate::ops::{DynamicStruct, DynamicTuple, Enum, Struct, Tuple};
 
 // -----------------------------------------------------------------------------
@@ -152,3 +152,124 @@ impl<'a> Iterator for VariantFieldIter<'a> {
 
 impl ExactSizeIterator for VariantFieldIter<'_> {}
 impl FusedIterator for VariantFieldIter<'_> {}
+
+// -----------------------------------------------------------------------------
+// Variant Field Info Iterator
+
+/// The type information of a field in an enum variant.
+///
+/// This is the counterpart of [`VariantField`] in the type information.
+#[derive(Clone, Copy, Debug)]
+pub enum VariantFieldInfo {
+    /// The information of a field in a struct variant.
+    Struct(&'static NamedField),
+    /// The information of a field in a tuple variant.
+    Tuple(&'static UnnamedField),
+}
+
+impl VariantFieldInfo {
+    /// Returns the name of a struct variant field, or `None` for a tuple variant field.
+    #[inline]
+    pub fn name(&self) -> Option<&'static str> {
+        if let Self::Struct(info) = self {
+            Some(info.name())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the [`TypeInfo`] of the field type.
+    #[inline]
+    pub fn type_info(&self) -> &'static TypeInfo {
+        match self {
+            Self::Struct(info) => info.type_info(),
+            Self::Tuple(info) => info.type_info(),
+        }
+    }
+
+    /// Returns the custom attributes of the field.
+    #[inline]
+    pub fn custom_attributes(&self) -> &'static crate::info::CustomAttributes {
+        match self {
+            Self::Struct(info) => info.custom_attributes(),
+            Self::Tuple(info) => info.custom_attributes(),
+        }
+    }
+}
+
+/// An iterator over the fields in the current enum variant, with their
+/// [`VariantFieldInfo`] information.
+///
+/// This is an [`ExactSizeIterator`] that yields the information and the
+/// [`VariantField`] of each field in order, see [`iter_fields_with_info`]
+/// for details.
+///
+/// [`iter_fields_with_info`]: <dyn Enum>::iter_fields_with_info
+pub struct VariantFieldInfoIter<'a> {
+    container: &'a dyn Enum,
+    info: Option<&'static VariantInfo>,
+    index: usize,
+}
+
+impl<'a> VariantFieldInfoIter<'a> {
+    /// Creates a new iterator for the given enum.
+    #[inline]
+    pub fn new(container: &'a dyn Enum) -> Self {
+        Self {
+            container,
+            info: container
+                .represented_type_info()
+                .and_then(|info| info.as_enum().ok())
+                .and_then(|info| info.variant(container.variant_name())),
+            index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for VariantFieldInfoIter<'a> {
+    type Item = (Option<VariantFieldInfo>, VariantField<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = match self.container.variant_kind() {
+            VariantKind::Unit => None,
+            VariantKind::Tuple => {
+                let value = self.container.field_at(self.index)?;
+                let info = match self.info {
+                    Some(VariantInfo::Tuple(info)) => info.field_at(self.index),
+                    _ => None,
+                };
+                Some((
+                    info.map(VariantFieldInfo::Tuple),
+                    VariantField::Tuple(value),
+                ))
+            }
+            VariantKind::Struct => {
+                let name = self.container.name_at(self.index)?;
+                let value = self.container.field(name)?;
+                let info = match self.info {
+                    // Dynamic enums may order their fields differently.
+                    Some(VariantInfo::Struct(info)) => match info.field_at(self.index) {
+                        Some(field) if field.name() == name => Some(field),
+                        _ => info.field(name),
+                    },
+                    _ => None,
+                };
+                Some((
+                    info.map(VariantFieldInfo::Struct),
+                    VariantField::Struct(name, value),
+                ))
+            }
+        };
+        self.index += item.is_some() as usize;
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = self.container.field_len() - self.index;
+        (hint, Some(hint))
+    }
+}
+
+impl ExactSizeIterator for VariantFieldInfoIter<'_> {}
+impl FusedIterator for VariantFieldInfoIter<'_> {}
@@ -7,12 +7,19 @@
 //! - [`TypeMeta`]: A container including a [`TypeInfo`] and a [`TypeTrait`] table.
 //! - [`GetTypeMeta`]: A trait that constructs a [`TypeMeta`] from a type.
 //! - [`TypeRegistry`]: A container for storing and querying [`TypeMeta`] values.
+//!     - [`MergePolicy`]: Resolves duplicate registrations in [`TypeRegistry::merge`].
+//!     - [`FrozenTypeRegistry`]: A lock-free, read-only snapshot of a registry.
 //! - TypeTraits:
 //!     - [`ReflectDefault`]: Provides [`Default`] support for reflected types.
+//!     - [`ReflectDebug`], [`ReflectDisplay`], [`ReflectHash`]: Format or hash any registered value.
 //!     - [`ReflectFromPtr`]: Converts raw pointers into reflection references.
 //!     - [`ReflectFromReflect`]: Provide [`FromReflect`] support for deserialization.
 //!     - [`ReflectSerialize`]: Provides serialization support for reflected types.
 //!     - [`ReflectDeserialize`]: Provides deserialization support for reflected types.
+//!     - [`ReflectValidate`]: Provides [`Validate`] support for reflected types.
+//!     - [`ReflectHeapSize`]: Provides [`HeapSize`] support for [`deep_size_of`].
+//!     - [`ReflectBox`]: Accesses the value inside a reflected `Box<dyn Trait>`.
+//!     - [`ReflectProxy`]: Converts a type to and from its serialized proxy type.
 //! - [`reflect_trait`]: An attribute macro that generates a `{Trait}FromReflect` helper usable as a [`TypeTrait`].
 //!
 //! ## auto_register
@@ -42,6 +49,9 @@
 //! [`reflect_trait`]: crate::derive::reflect_trait
 //! [`FromReflect`]: crate::FromReflect
 //! [`TypeInfo`]: crate::info::TypeInfo
+//! [`Validate`]: crate::validate::Validate
+//! [`HeapSize`]: crate::analysis::HeapSize
+//! [`deep_size_of`]: crate::analysis::deep_size_of
 
 // -----------------------------------------------------------------------------
 // Modules
@@ -56,9 +66,13 @@ mod type_trait;
 // Exports
 
 pub use from_type::FromType;
-pub use traits::ReflectDefault;
+pub use traits::ReflectHeapSize;
+pub use traits::ReflectValidate;
+pub use traits::{ProxyFormat, ReflectBox, ReflectDefault, ReflectProxy};
+pub use traits::{ReflectDebug, ReflectDisplay, ReflectHash};
 pub use traits::{ReflectDeserialize, ReflectSerialize};
 pub use traits::{ReflectFromPtr, ReflectFromReflect};
 pub use type_meta::{GetTypeMeta, TypeMeta};
+pub use type_registry::{FrozenTypeRegistry, MergeError, MergePolicy};
 pub use type_registry::{TypeRegistry, TypeRegistryArc};
 pub use type_trait::TypeTrait;
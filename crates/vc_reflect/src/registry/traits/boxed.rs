@@ -0,0 +1,136 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+
+use crate::Reflect;
+use crate::impls::{DynReflect, SharedArc};
+use crate::info::TypePath;
+use crate::registry::{FromType, TypeRegistry};
+
+/// A container providing access to the value inside a reflected `Box<dyn Trait>`.
+///
+/// It is registered for every `Box<T>` where `T` is a reflectable trait object,
+/// see [`DynReflect`], for [`SharedArc<dyn Reflect>`], and for `dyn Reflect`, the
+/// field type of `Box<dyn Reflect>` fields. The (de)serialization drivers use it
+/// to treat such boxes as dynamic pass-through values: the inner value is
+/// written together with its type path, and read back through the registry.
+///
+/// # Examples
+///
+/// ```
+/// use core::any::TypeId;
+/// use vc_reflect::Reflect;
+/// use vc_reflect::derive::reflect_trait;
+/// use vc_reflect::registry::{ReflectBox, TypeRegistry};
+///
+/// #[reflect_trait]
+/// trait Shape: Reflect {}
+///
+/// #[derive(Reflect)]
+/// #[reflect(type_trait = ShapeFromReflect)]
+/// struct Square(f32);
+///
+/// impl Shape for Square {}
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Box<dyn Shape>>();
+/// registry.register::<Square>();
+///
+/// let boxed = registry
+///     .get_type_trait::<ReflectBox>(TypeId::of::<Box<dyn Shape>>())
+///     .unwrap();
+///
+/// let value: Box<dyn Shape> = Box::new(Square(2.0));
+/// let inner = boxed.unbox(&value).unwrap();
+/// assert!(inner.is::<Square>());
+///
+/// let value = boxed.rebox(Box::new(Square(3.0)), &registry).unwrap();
+/// assert!(value.is::<Box<dyn Shape>>());
+///
+/// // `u32` does not implement `Shape`.
+/// assert!(boxed.rebox(Box::new(5_u32), &registry).is_err());
+/// ```
+#[derive(Clone)]
+pub struct ReflectBox {
+    unbox: fn(&dyn Reflect) -> Option<&dyn Reflect>,
+    rebox: fn(Box<dyn Reflect>, &TypeRegistry) -> Result<Box<dyn Reflect>, Box<dyn Reflect>>,
+}
+
+impl ReflectBox {
+    /// Returns the value inside the box.
+    ///
+    /// Returns `None` if `value` is not the box type this was created for.
+    /// For `dyn Reflect`, `value` is returned as is.
+    #[inline(always)]
+    pub fn unbox<'a>(&self, value: &'a dyn Reflect) -> Option<&'a dyn Reflect> {
+        (self.unbox)(value)
+    }
+
+    /// Moves `value` into the box type, returned as a reflected value.
+    ///
+    /// For `dyn Reflect`, `value` is returned as is. Other trait objects are
+    /// cast through the registry, `value` is returned as the error if its type
+    /// cannot be cast.
+    #[inline(always)]
+    pub fn rebox(
+        &self,
+        value: Box<dyn Reflect>,
+        registry: &TypeRegistry,
+    ) -> Result<Box<dyn Reflect>, Box<dyn Reflect>> {
+        (self.rebox)(value, registry)
+    }
+
+    /// The identity, for values stored as `dyn Reflect`.
+    pub(crate) fn pass_through() -> Self {
+        Self {
+            unbox: |value| Some(value),
+            rebox: |value, _| Ok(value),
+        }
+    }
+}
+
+impl<T: DynReflect + ?Sized> FromType<Box<T>> for ReflectBox {
+    fn from_type() -> Self {
+        Self {
+            unbox: |value| value.downcast_ref::<Box<T>>().map(|value| T::upcast(value)),
+            rebox: |value, registry| {
+                T::downcast_boxed(value, Some(registry))
+                    .map(|value| Box::new(value) as Box<dyn Reflect>)
+            },
+        }
+    }
+}
+
+impl FromType<SharedArc<dyn Reflect>> for ReflectBox {
+    fn from_type() -> Self {
+        Self {
+            unbox: |value| {
+                value
+                    .downcast_ref::<SharedArc<dyn Reflect>>()
+                    .map(|value| &*value.0)
+            },
+            rebox: |value, _| Ok(Box::new(SharedArc(Arc::<dyn Reflect>::from(value)))),
+        }
+    }
+}
+
+impl TypePath for ReflectBox {
+    #[inline(always)]
+    fn type_path() -> &'static str {
+        "vc_reflect::registry::ReflectBox"
+    }
+
+    #[inline(always)]
+    fn type_name() -> &'static str {
+        "ReflectBox"
+    }
+
+    #[inline(always)]
+    fn type_ident() -> &'static str {
+        "ReflectBox"
+    }
+
+    #[inline(always)]
+    fn module_path() -> Option<&'static str> {
+        Some("vc_reflect::registry")
+    }
+}
@@ -5,6 +5,7 @@ use serde_core::{Deserialize, Deserializer};
 use crate::Reflect;
 use crate::info::{TypePath, Typed};
 use crate::registry::FromType;
+use crate::serde::DeserializeWith;
 
 /// A container providing `serde` deserialization support for reflected types.
 ///
@@ -41,7 +42,7 @@ use crate::registry::FromType;
 ///
 /// assert_eq!(val.take::<MyStruct>().unwrap(), MyStruct{ value: 123 });
 /// ```
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct ReflectDeserialize {
     func: fn(
         deserializer: &mut dyn erased_serde::Deserializer,
@@ -49,6 +50,16 @@ pub struct ReflectDeserialize {
 }
 
 impl ReflectDeserialize {
+    /// Creates a [`ReflectDeserialize`] calling the function of a
+    /// `#[reflect(deserialize_with = "path")]` attribute.
+    ///
+    /// The value type does not need to implement `Deserialize`.
+    pub fn with<W: DeserializeWith<N>, const N: usize>() -> Self {
+        Self {
+            func: |deserializer| Ok(Box::new(W::deserialize(deserializer)?)),
+        }
+    }
+
     /// Deserializes a reflected value.
     ///
     /// See [`ReflectDeserialize`] for examples.
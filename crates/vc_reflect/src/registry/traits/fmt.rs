@@ -0,0 +1,188 @@
+use core::fmt::{Debug, Display};
+
+use crate::Reflect;
+use crate::info::{TypePath, Typed};
+use crate::registry::FromType;
+
+// -----------------------------------------------------------------------------
+// ReflectDebug
+
+/// A container providing [`Debug`] support for reflected types.
+///
+/// This works like a [`reflect_trait`] generated type for [`Debug`], allowing
+/// tools to format any registered value through the [`TypeRegistry`].
+///
+/// Registered automatically for types marked with `#[reflect(debug)]` (or `full`).
+///
+/// # Examples
+///
+/// ```
+/// use core::any::TypeId;
+/// use vc_reflect::prelude::*;
+/// use vc_reflect::registry::ReflectDebug;
+///
+/// #[derive(Reflect, Debug)]
+/// #[reflect(debug)]
+/// struct Foo(u32);
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Foo>();
+///
+/// let value: &dyn Reflect = &Foo(1);
+/// let debug = registry.get_type_trait::<ReflectDebug>(TypeId::of::<Foo>()).unwrap();
+/// assert_eq!(format!("{:?}", debug.from_ref(value).unwrap()), "Foo(1)");
+/// ```
+///
+/// [`reflect_trait`]: crate::derive::reflect_trait
+/// [`TypeRegistry`]: crate::registry::TypeRegistry
+#[derive(Clone)]
+pub struct ReflectDebug {
+    from_ref_func: fn(&dyn Reflect) -> Option<&dyn Debug>,
+}
+
+impl ReflectDebug {
+    /// Downcast a `&dyn Reflect` type to `&dyn Debug`.
+    ///
+    /// If the type cannot be downcast, `None` is returned.
+    #[inline]
+    pub fn from_ref<'a>(&self, value: &'a dyn Reflect) -> Option<&'a dyn Debug> {
+        (self.from_ref_func)(value)
+    }
+}
+
+impl<T: Debug + Typed + Reflect> FromType<T> for ReflectDebug {
+    fn from_type() -> Self {
+        Self {
+            from_ref_func: |value| value.downcast_ref::<T>().map(|value| value as &dyn Debug),
+        }
+    }
+}
+
+impl TypePath for ReflectDebug {
+    #[inline(always)]
+    fn type_path() -> &'static str {
+        "vc_reflect::registry::ReflectDebug"
+    }
+
+    #[inline(always)]
+    fn type_name() -> &'static str {
+        "ReflectDebug"
+    }
+
+    #[inline(always)]
+    fn type_ident() -> &'static str {
+        "ReflectDebug"
+    }
+
+    #[inline(always)]
+    fn module_path() -> Option<&'static str> {
+        Some("vc_reflect::registry")
+    }
+}
+
+// -----------------------------------------------------------------------------
+// ReflectDisplay
+
+/// A container providing [`Display`] support for reflected types.
+///
+/// This works like a [`reflect_trait`] generated type for [`Display`], allowing
+/// tools to print any registered value through the [`TypeRegistry`].
+///
+/// Registered automatically for types marked with `#[reflect(display)]`.
+/// Unlike `debug`, this flag is not part of `full`.
+///
+/// [`reflect_trait`]: crate::derive::reflect_trait
+/// [`TypeRegistry`]: crate::registry::TypeRegistry
+#[derive(Clone)]
+pub struct ReflectDisplay {
+    from_ref_func: fn(&dyn Reflect) -> Option<&dyn Display>,
+}
+
+impl ReflectDisplay {
+    /// Downcast a `&dyn Reflect` type to `&dyn Display`.
+    ///
+    /// If the type cannot be downcast, `None` is returned.
+    #[inline]
+    pub fn from_ref<'a>(&self, value: &'a dyn Reflect) -> Option<&'a dyn Display> {
+        (self.from_ref_func)(value)
+    }
+}
+
+impl<T: Display + Typed + Reflect> FromType<T> for ReflectDisplay {
+    fn from_type() -> Self {
+        Self {
+            from_ref_func: |value| value.downcast_ref::<T>().map(|value| value as &dyn Display),
+        }
+    }
+}
+
+impl TypePath for ReflectDisplay {
+    #[inline(always)]
+    fn type_path() -> &'static str {
+        "vc_reflect::registry::ReflectDisplay"
+    }
+
+    #[inline(always)]
+    fn type_name() -> &'static str {
+        "ReflectDisplay"
+    }
+
+    #[inline(always)]
+    fn type_ident() -> &'static str {
+        "ReflectDisplay"
+    }
+
+    #[inline(always)]
+    fn module_path() -> Option<&'static str> {
+        Some("vc_reflect::registry")
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::string::String;
+    use core::any::TypeId;
+
+    use super::{ReflectDebug, ReflectDisplay};
+    use crate::Reflect;
+    use crate::registry::{ReflectHash, TypeRegistry};
+
+    #[derive(Reflect, Debug, Hash, PartialEq)]
+    #[reflect(debug, hash, display)]
+    struct Name(String);
+
+    impl core::fmt::Display for Name {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
+    #[test]
+    fn registered_by_flags() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Name>();
+
+        let name: &dyn Reflect = &Name("foo".into());
+        let id = TypeId::of::<Name>();
+        let debug = registry.get_type_trait::<ReflectDebug>(id).unwrap();
+        let display = registry.get_type_trait::<ReflectDisplay>(id).unwrap();
+        let hash = registry.get_type_trait::<ReflectHash>(id).unwrap();
+        assert_eq!(
+            format!("{:?}", debug.from_ref(name).unwrap()),
+            "Name(\"foo\")"
+        );
+        assert_eq!(format!("{}", display.from_ref(name).unwrap()), "foo");
+        assert_eq!(hash.hash(name), name.reflect_hash());
+        assert!(debug.from_ref(&1_u32).is_none());
+
+        // Built-in types enable the flags as well.
+        let id = TypeId::of::<u32>();
+        let display = registry.get_type_trait::<ReflectDisplay>(id).unwrap();
+        assert_eq!(format!("{}", display.from_ref(&7_u32).unwrap()), "7");
+        assert!(registry.get_type_trait::<ReflectHash>(id).is_some());
+    }
+}
@@ -0,0 +1,76 @@
+use core::hash::{Hash, Hasher};
+
+use crate::info::{TypePath, Typed};
+use crate::registry::FromType;
+use crate::{Reflect, reflect_hasher};
+
+/// A container providing [`Hash`] support for reflected types.
+///
+/// `Hash` is not dyn-compatible, so this hashes the value with
+/// [`reflect_hasher`] directly, matching `Reflect::reflect_hash` of types
+/// marked with the `hash` flag.
+///
+/// Registered automatically for types marked with `#[reflect(hash)]` (or `full`).
+///
+/// # Examples
+///
+/// ```
+/// use core::any::TypeId;
+/// use vc_reflect::prelude::*;
+/// use vc_reflect::registry::ReflectHash;
+///
+/// let registry = TypeRegistry::new();
+///
+/// let hash = registry.get_type_trait::<ReflectHash>(TypeId::of::<u32>()).unwrap();
+/// assert_eq!(hash.hash(&1_u32), (&1_u32 as &dyn Reflect).reflect_hash());
+/// assert_eq!(hash.hash(&1_u64), None);
+/// ```
+#[derive(Clone)]
+pub struct ReflectHash {
+    func: fn(&dyn Reflect) -> Option<u64>,
+}
+
+impl ReflectHash {
+    /// Hashes the value with [`reflect_hasher`].
+    ///
+    /// If the value is not of the registered type, `None` is returned.
+    #[inline]
+    pub fn hash(&self, value: &dyn Reflect) -> Option<u64> {
+        (self.func)(value)
+    }
+}
+
+impl<T: Hash + Typed + Reflect> FromType<T> for ReflectHash {
+    fn from_type() -> Self {
+        Self {
+            func: |value| {
+                let value = value.downcast_ref::<T>()?;
+                let mut hasher = reflect_hasher();
+                value.hash(&mut hasher);
+                Some(hasher.finish())
+            },
+        }
+    }
+}
+
+impl TypePath for ReflectHash {
+    #[inline(always)]
+    fn type_path() -> &'static str {
+        "vc_reflect::registry::ReflectHash"
+    }
+
+    #[inline(always)]
+    fn type_name() -> &'static str {
+        "ReflectHash"
+    }
+
+    #[inline(always)]
+    fn type_ident() -> &'static str {
+        "ReflectHash"
+    }
+
+    #[inline(always)]
+    fn module_path() -> Option<&'static str> {
+        Some("vc_reflect::registry")
+    }
+}
@@ -0,0 +1,100 @@
+use crate::Reflect;
+use crate::analysis::HeapSize;
+use crate::info::{TypePath, Typed};
+use crate::registry::FromType;
+
+/// A container providing [`HeapSize`] support for reflected types.
+///
+/// It is used by [`deep_size_of`](crate::analysis::deep_size_of) for types
+/// whose heap memory is not visible through reflection, such as opaque types.
+///
+/// # Creating a instance
+///
+/// ```
+/// use vc_reflect::prelude::*;
+/// use vc_reflect::registry::ReflectHeapSize;
+/// use vc_reflect::analysis::HeapSize;
+///
+/// #[derive(Reflect, Clone)]
+/// #[reflect(Opaque, clone)]
+/// struct Buffer(Vec<u8>);
+///
+/// impl HeapSize for Buffer {
+///     fn heap_size(&self) -> usize {
+///         self.0.capacity()
+///     }
+/// }
+///
+/// let heap_size: ReflectHeapSize = FromType::<Buffer>::from_type();
+/// ```
+///
+/// # Derive macro support
+///
+/// Use `#[reflect(type_trait = ReflectHeapSize)]` to register it automatically.
+///
+/// ```
+/// use vc_reflect::prelude::*;
+/// use vc_reflect::registry::ReflectHeapSize;
+/// use vc_reflect::analysis::{HeapSize, deep_size_of};
+///
+/// #[derive(Reflect, Clone)]
+/// #[reflect(Opaque, clone, type_trait = ReflectHeapSize)]
+/// struct Buffer(Vec<u8>);
+///
+/// impl HeapSize for Buffer {
+///     fn heap_size(&self) -> usize {
+///         self.0.capacity()
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Buffer>();
+///
+/// let buffer = Buffer(Vec::with_capacity(64));
+/// assert_eq!(deep_size_of(&buffer, &registry), size_of::<Buffer>() + 64);
+/// ```
+#[derive(Clone)]
+pub struct ReflectHeapSize {
+    func: fn(&dyn Reflect) -> Option<usize>,
+}
+
+impl ReflectHeapSize {
+    /// Call T's [`HeapSize`].
+    ///
+    /// Returns `None` if the value is not exactly `T`, e.g. a dynamic type
+    /// that only represents `T`.
+    #[inline(always)]
+    pub fn heap_size(&self, value: &dyn Reflect) -> Option<usize> {
+        (self.func)(value)
+    }
+}
+
+impl<T: HeapSize + Typed + Reflect> FromType<T> for ReflectHeapSize {
+    fn from_type() -> Self {
+        Self {
+            func: |value| value.downcast_ref::<T>().map(T::heap_size),
+        }
+    }
+}
+
+impl TypePath for ReflectHeapSize {
+    #[inline(always)]
+    fn type_path() -> &'static str {
+        "vc_reflect::registry::ReflectHeapSize"
+    }
+
+    #[inline(always)]
+    fn type_name() -> &'static str {
+        "ReflectHeapSize"
+    }
+
+    #[inline(always)]
+    fn type_ident() -> &'static str {
+        "ReflectHeapSize"
+    }
+
+    #[inline(always)]
+    fn module_path() -> Option<&'static str> {
+        Some("vc_reflect::registry")
+    }
+}
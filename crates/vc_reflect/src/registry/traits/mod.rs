@@ -1,17 +1,29 @@
 // -----------------------------------------------------------------------------
 // Modules
 
+mod boxed;
 mod default;
 mod deserialize;
+mod fmt;
 mod from_ptr;
 mod from_reflect;
+mod hash;
+mod heap_size;
+mod proxy;
 mod serialize;
+mod validate;
 
 // -----------------------------------------------------------------------------
 // Exports
 
+pub use boxed::ReflectBox;
 pub use default::ReflectDefault;
 pub use deserialize::ReflectDeserialize;
+pub use fmt::{ReflectDebug, ReflectDisplay};
 pub use from_ptr::ReflectFromPtr;
 pub use from_reflect::ReflectFromReflect;
+pub use hash::ReflectHash;
+pub use heap_size::ReflectHeapSize;
+pub use proxy::{ProxyFormat, ReflectProxy};
 pub use serialize::ReflectSerialize;
+pub use validate::ReflectValidate;
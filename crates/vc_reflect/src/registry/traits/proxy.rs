@@ -0,0 +1,325 @@
+use alloc::boxed::Box;
+use core::any::TypeId;
+
+use crate::impls::SharedArc;
+use crate::info::{TypePath, Typed};
+use crate::registry::FromType;
+use crate::{FromReflect, Reflect};
+
+/// The serializer formats a [`ReflectProxy`] is used for.
+///
+/// Formats are distinguished by `is_human_readable` of `serde`'s serializers
+/// and deserializers, e.g. RON and JSON are human-readable, bincode is not.
+/// In the other formats, the type is (de)serialized as if it had no proxy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ProxyFormat {
+    /// All formats, registered by `#[reflect(from = "Proxy")]`.
+    #[default]
+    Any,
+    /// Human-readable formats, registered by `#[reflect(readable_from = "Proxy")]`.
+    HumanReadable,
+    /// Binary formats, registered by `#[reflect(binary_from = "Proxy")]`.
+    Binary,
+}
+
+/// A container converting a reflected type to and from its proxy type.
+///
+/// A proxy is another reflected type used as the serialized representation,
+/// the reflection counterpart of serde's `from`/`into` attributes. It is
+/// registered by `#[reflect(from = "Proxy")]`, and the (de)serialization
+/// drivers use it in place of the reflected layout of the type.
+///
+/// The value is converted with [`From`] and [`Into`], cloned through
+/// [`Reflect::reflect_clone`] when serializing. A proxy can be restricted to
+/// human-readable or binary formats, see [`ProxyFormat`].
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::Reflect;
+/// use vc_reflect::registry::TypeRegistry;
+/// use vc_reflect::serde::{ReflectDeserializeDriver, ReflectSerializeDriver};
+/// use serde::de::DeserializeSeed;
+///
+/// /// Stored as linear color, written as sRGB bytes.
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// #[reflect(clone, from = "Srgb")]
+/// struct Color {
+///     linear: [f32; 3],
+/// }
+///
+/// #[derive(Reflect, Clone)]
+/// struct Srgb(u8, u8, u8);
+///
+/// impl From<Color> for Srgb {
+///     fn from(c: Color) -> Self {
+///         let [r, g, b] = c.linear.map(|x| (x * 255.0) as u8);
+///         Srgb(r, g, b)
+///     }
+/// }
+///
+/// impl From<Srgb> for Color {
+///     fn from(c: Srgb) -> Self {
+///         let linear = [c.0, c.1, c.2].map(|x| x as f32 / 255.0);
+///         Color { linear }
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Color>();
+///
+/// let color = Color { linear: [1.0, 0.0, 0.0] };
+/// let output = ron::to_string(&ReflectSerializeDriver::new(&color, &registry)).unwrap();
+/// assert!(output.ends_with("::Color\":(255,0,0)}"));
+///
+/// let mut deserializer = ron::Deserializer::from_str(&output).unwrap();
+/// let value = ReflectDeserializeDriver::new(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert_eq!(value.take::<Color>().unwrap(), color);
+/// ```
+#[derive(Clone)]
+pub struct ReflectProxy {
+    proxy_type_id: TypeId,
+    format: ProxyFormat,
+    into_proxy: fn(&dyn Reflect) -> Option<Box<dyn Reflect>>,
+    from_proxy: fn(&dyn Reflect) -> Option<Box<dyn Reflect>>,
+}
+
+impl ReflectProxy {
+    /// Creates the container for type `T` with the proxy type `P`.
+    pub fn new<T, P>() -> Self
+    where
+        T: Reflect + Into<P>,
+        P: Typed + FromReflect + Into<T>,
+    {
+        Self {
+            proxy_type_id: TypeId::of::<P>(),
+            format: ProxyFormat::Any,
+            into_proxy: |value| {
+                let value = value.downcast_ref::<T>()?.reflect_clone().ok()?;
+                let value = value.take::<T>().ok()?;
+                Some(Into::<P>::into(value).into_boxed_reflect())
+            },
+            from_proxy: |proxy| {
+                let proxy = P::from_reflect(proxy)?;
+                Some(Into::<T>::into(proxy).into_boxed_reflect())
+            },
+        }
+    }
+
+    /// Restricts the proxy to the serializer formats of `format`.
+    ///
+    /// Registered by `#[reflect(readable_from = "Proxy")]` and
+    /// `#[reflect(binary_from = "Proxy")]`.
+    #[inline]
+    pub const fn with_format(mut self, format: ProxyFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Returns the serializer formats the proxy is used for.
+    #[inline(always)]
+    pub const fn format(&self) -> ProxyFormat {
+        self.format
+    }
+
+    /// Returns `true` if the proxy is used for a format, given by
+    /// `is_human_readable` of its (de)serializer.
+    #[inline]
+    pub const fn is_used_for(&self, human_readable: bool) -> bool {
+        match self.format {
+            ProxyFormat::Any => true,
+            ProxyFormat::HumanReadable => human_readable,
+            ProxyFormat::Binary => !human_readable,
+        }
+    }
+
+    /// Returns the [`TypeId`] of the proxy type.
+    #[inline(always)]
+    pub fn proxy_type_id(&self) -> TypeId {
+        self.proxy_type_id
+    }
+
+    /// Converts `value` to the proxy type.
+    ///
+    /// Returns `None` if `value` is not the type this was created for,
+    /// or cannot be cloned.
+    #[inline(always)]
+    pub fn into_proxy(&self, value: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.into_proxy)(value)
+    }
+
+    /// Converts a (possibly dynamic) proxy value back to the type.
+    ///
+    /// Returns `None` if `proxy` cannot be converted to the proxy type.
+    #[inline(always)]
+    pub fn from_proxy(&self, proxy: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.from_proxy)(proxy)
+    }
+}
+
+/// Serializes [`SharedArc<T>`] as the value inside.
+impl<T: Typed + FromReflect> FromType<SharedArc<T>> for ReflectProxy {
+    fn from_type() -> Self {
+        Self {
+            proxy_type_id: TypeId::of::<T>(),
+            format: ProxyFormat::Any,
+            into_proxy: |value| {
+                let value: &T = value.downcast_ref::<SharedArc<T>>()?;
+                Some(value.reflect_clone().unwrap_or_else(|_| value.to_dynamic()))
+            },
+            from_proxy: |proxy| Some(Box::new(SharedArc::new(T::from_reflect(proxy)?))),
+        }
+    }
+}
+
+impl TypePath for ReflectProxy {
+    #[inline(always)]
+    fn type_path() -> &'static str {
+        "vc_reflect::registry::ReflectProxy"
+    }
+
+    #[inline(always)]
+    fn type_name() -> &'static str {
+        "ReflectProxy"
+    }
+
+    #[inline(always)]
+    fn type_ident() -> &'static str {
+        "ReflectProxy"
+    }
+
+    #[inline(always)]
+    fn module_path() -> Option<&'static str> {
+        Some("vc_reflect::registry")
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::string::String;
+
+    use super::ReflectProxy;
+    use crate::info::TypePath;
+    use crate::ops::DynamicTupleStruct;
+    use crate::registry::TypeRegistry;
+    use crate::{FromReflect, Reflect};
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(from = "Wire")]
+    struct Millis {
+        secs: f32,
+    }
+
+    #[derive(Reflect)]
+    struct Wire(u32);
+
+    impl From<Millis> for Wire {
+        fn from(value: Millis) -> Self {
+            Wire((value.secs * 1000.0) as u32)
+        }
+    }
+
+    impl From<Wire> for Millis {
+        fn from(value: Wire) -> Self {
+            Millis {
+                secs: value.0 as f32 / 1000.0,
+            }
+        }
+    }
+
+    /// Written as a hex string in human-readable formats, as bytes otherwise.
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(readable_from = "Hex")]
+    struct Key([u8; 4]);
+
+    #[derive(Reflect)]
+    struct Hex(String);
+
+    impl From<Key> for Hex {
+        fn from(value: Key) -> Self {
+            Hex(value.0.iter().map(|b| format!("{b:02x}")).collect())
+        }
+    }
+
+    impl From<Hex> for Key {
+        fn from(value: Hex) -> Self {
+            let byte = |i: usize| u8::from_str_radix(&value.0[2 * i..2 * i + 2], 16).unwrap();
+            Key([byte(0), byte(1), byte(2), byte(3)])
+        }
+    }
+
+    #[test]
+    fn type_path() {
+        assert!(ReflectProxy::type_path() == "vc_reflect::registry::ReflectProxy");
+        assert!(ReflectProxy::module_path() == Some("vc_reflect::registry"));
+        assert!(ReflectProxy::type_ident() == "ReflectProxy");
+        assert!(ReflectProxy::type_name() == "ReflectProxy");
+    }
+
+    #[test]
+    fn convert() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Millis>();
+        assert!(registry.contains(core::any::TypeId::of::<Wire>()));
+
+        let proxy = registry
+            .get_type_trait::<ReflectProxy>(core::any::TypeId::of::<Millis>())
+            .unwrap();
+
+        let wire = proxy.into_proxy(&Millis { secs: 1.5 }).unwrap();
+        assert_eq!(wire.downcast_ref::<Wire>().unwrap().0, 1500);
+        assert!(proxy.into_proxy(&Wire(0)).is_none());
+
+        let mut dynamic = DynamicTupleStruct::new();
+        dynamic.extend(250_u32);
+        let value = proxy.from_proxy(&dynamic).unwrap();
+        assert_eq!(value.take::<Millis>().unwrap(), Millis { secs: 0.25 });
+
+        // `FromReflect` accepts the proxy as well.
+        assert_eq!(
+            Millis::from_reflect(&Wire(2000)),
+            Some(Millis { secs: 2.0 })
+        );
+    }
+
+    #[test]
+    fn human_readable_format() {
+        use bincode::Options;
+        use serde::de::DeserializeSeed;
+
+        use crate::serde::{DeserializeDriver, SerializeDriver};
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Key>();
+        let meta = registry.get(core::any::TypeId::of::<Key>()).unwrap();
+        let key = Key([0xde, 0xad, 0xbe, 0xef]);
+
+        let json = serde_json::to_string(&SerializeDriver::new(&key, &registry)).unwrap();
+        assert_eq!(json, r#""deadbeef""#);
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let value = DeserializeDriver::new(meta, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            Key::from_reflect(&*value),
+            Some(Key([0xde, 0xad, 0xbe, 0xef]))
+        );
+
+        let options = bincode::DefaultOptions::new();
+        let bytes = options
+            .serialize(&SerializeDriver::new(&key, &registry))
+            .unwrap();
+        assert_eq!(bytes, [0xde, 0xad, 0xbe, 0xef]);
+        let mut deserializer = bincode::Deserializer::from_slice(&bytes, options);
+        let value = DeserializeDriver::new(meta, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Key::from_reflect(&*value), Some(key));
+    }
+}
@@ -3,6 +3,7 @@ use serde_core::{Serialize, Serializer};
 use crate::Reflect;
 use crate::info::{TypePath, Typed};
 use crate::registry::FromType;
+use crate::serde::{SerializeAdapter, SerializeWith};
 
 /// A container providing `serde` serialization support for reflected types.
 ///
@@ -44,7 +45,7 @@ use crate::registry::FromType;
 ///
 /// assert_eq!(output, r#"(value:123)"#);
 /// ```
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct ReflectSerialize {
     fun: fn(value: &dyn Reflect) -> &dyn erased_serde::Serialize,
 }
@@ -67,6 +68,53 @@ impl<T: Serialize + Typed + Reflect> FromType<T> for ReflectSerialize {
 }
 
 impl ReflectSerialize {
+    /// Creates a [`ReflectSerialize`] calling the function of a
+    /// `#[reflect(serialize_with = "path")]` attribute.
+    ///
+    /// The value type does not need to implement `Serialize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::any::TypeId;
+    /// use serde::Serializer;
+    /// use vc_reflect::registry::{ReflectSerialize, TypeRegistry};
+    /// use vc_reflect::Reflect;
+    ///
+    /// fn as_hex<S: Serializer>(value: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+    ///     serializer.serialize_str(&format!("#{:06x}", value.0))
+    /// }
+    ///
+    /// #[derive(Reflect, Clone)]
+    /// #[reflect(Opaque, clone, serialize_with = "as_hex")]
+    /// struct Color(u32);
+    ///
+    /// let mut registry = TypeRegistry::new();
+    /// registry.register::<Color>();
+    ///
+    /// let processor = registry.get_type_trait::<ReflectSerialize>(TypeId::of::<Color>()).unwrap();
+    ///
+    /// let mut output = String::new();
+    /// let mut serializer = ron::Serializer::new(&mut output, None).unwrap();
+    /// processor.serialize(&Color(0xff8000), &mut serializer).unwrap();
+    ///
+    /// assert_eq!(output, r##""#ff8000""##);
+    /// ```
+    pub fn with<W: SerializeWith<N> + 'static, const N: usize>() -> Self {
+        Self {
+            fun: |value| match value.downcast_ref::<W::Value>() {
+                Some(val) => SerializeAdapter::<W, N>::new(val) as &dyn erased_serde::Serialize,
+                None => {
+                    panic!(
+                        "Serial type mismatched, Serial Type `{}` with Value Type: {}",
+                        W::Value::type_path(),
+                        value.reflect_type_path(),
+                    );
+                }
+            },
+        }
+    }
+
     /// Serializes a reflected value.
     ///
     /// See [`ReflectSerialize`] for examples.
@@ -113,8 +161,61 @@ impl TypePath for ReflectSerialize {
 
 #[cfg(test)]
 mod tests {
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
     use super::ReflectSerialize;
     use crate::info::TypePath;
+    use crate::registry::TypeRegistry;
+    use crate::{FromReflect, Reflect};
+
+    #[expect(clippy::ptr_arg, reason = "The signature must match the field type.")]
+    fn to_hex<S: Serializer>(value: &Vec<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+        let text: String = value.iter().map(|b| format!("{b:02x}")).collect();
+        serializer.serialize_str(&text)
+    }
+
+    fn from_hex<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        (0..text.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&text[i..i + 2], 16).map_err(serde::de::Error::custom))
+            .collect()
+    }
+
+    fn color_to_str<S: Serializer>(value: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("#{:06x}", value.0))
+    }
+
+    fn color_from_str<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        u32::from_str_radix(text.trim_start_matches('#'), 16)
+            .map(Color)
+            .map_err(serde::de::Error::custom)
+    }
+
+    /// Does not implement serde traits.
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(Opaque, clone, serialize_with = "color_to_str")]
+    #[reflect(deserialize_with = "color_from_str")]
+    struct Color(u32);
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Texture<T> {
+        #[reflect(serialize_with = "to_hex", deserialize_with = "from_hex")]
+        data: Vec<u8>,
+        tint: Color,
+        extra: T,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Source {
+        Path(String),
+        Bytes(#[reflect(serialize_with = "to_hex", deserialize_with = "from_hex")] Vec<u8>),
+    }
 
     #[test]
     fn type_path() {
@@ -123,4 +224,43 @@ mod tests {
         assert!(ReflectSerialize::type_ident() == "ReflectSerialize");
         assert!(ReflectSerialize::type_name() == "ReflectSerialize");
     }
+
+    #[test]
+    fn serialize_with() {
+        use serde::de::DeserializeSeed;
+
+        use crate::serde::{DeserializeDriver, SerializeDriver};
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Texture<u8>>();
+        registry.register::<Source>();
+
+        let texture = Texture {
+            data: alloc::vec![0xca, 0xfe],
+            tint: Color(0xff8000),
+            extra: 7_u8,
+        };
+        let json = serde_json::to_string(&SerializeDriver::new(&texture, &registry)).unwrap();
+        assert_eq!(json, r##"{"data":"cafe","tint":"#ff8000","extra":7}"##);
+
+        let meta = registry
+            .get(core::any::TypeId::of::<Texture<u8>>())
+            .unwrap();
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let value = DeserializeDriver::new(meta, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Texture::<u8>::from_reflect(&*value), Some(texture));
+
+        let source = Source::Bytes(alloc::vec![0x01, 0x23]);
+        let json = serde_json::to_string(&SerializeDriver::new(&source, &registry)).unwrap();
+        assert_eq!(json, r#"{"Bytes":"0123"}"#);
+
+        let meta = registry.get(core::any::TypeId::of::<Source>()).unwrap();
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let value = DeserializeDriver::new(meta, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Source::from_reflect(&*value), Some(source));
+    }
 }
@@ -0,0 +1,100 @@
+use crate::Reflect;
+use crate::info::{TypePath, Typed};
+use crate::registry::FromType;
+use crate::validate::{Validate, ValidationErrorKind};
+
+/// A container providing [`Validate`] support for reflected types.
+///
+/// It is used by [`validate`](crate::validate::validate) to run type-specific
+/// checks in addition to the attribute-based ones.
+///
+/// # Creating a instance
+///
+/// ```
+/// use vc_reflect::prelude::*;
+/// use vc_reflect::registry::ReflectValidate;
+/// use vc_reflect::validate::{Validate, ValidationErrorKind};
+///
+/// #[derive(Reflect)]
+/// struct Foo(u32);
+///
+/// impl Validate for Foo {
+///     fn validate(&self) -> Result<(), ValidationErrorKind> {
+///         Ok(())
+///     }
+/// }
+///
+/// let validator: ReflectValidate = FromType::<Foo>::from_type();
+/// ```
+///
+/// # Derive macro support
+///
+/// Use `#[reflect(type_trait = ReflectValidate)]` to register it automatically.
+///
+/// ```
+/// use core::any::TypeId;
+/// use vc_reflect::prelude::*;
+/// use vc_reflect::registry::ReflectValidate;
+/// use vc_reflect::validate::{Validate, ValidationErrorKind};
+///
+/// #[derive(Reflect)]
+/// #[reflect(type_trait = ReflectValidate)]
+/// struct Foo(u32);
+///
+/// impl Validate for Foo {
+///     fn validate(&self) -> Result<(), ValidationErrorKind> {
+///         Ok(())
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Foo>();
+///
+/// let validator = registry.get_type_trait::<ReflectValidate>(TypeId::of::<Foo>());
+/// assert!(validator.is_some());
+/// ```
+#[derive(Clone)]
+pub struct ReflectValidate {
+    func: fn(&dyn Reflect) -> Option<Result<(), ValidationErrorKind>>,
+}
+
+impl ReflectValidate {
+    /// Call T's [`Validate`].
+    ///
+    /// Returns `None` if the value is not exactly `T`, e.g. a dynamic type
+    /// that only represents `T`.
+    #[inline(always)]
+    pub fn validate(&self, value: &dyn Reflect) -> Option<Result<(), ValidationErrorKind>> {
+        (self.func)(value)
+    }
+}
+
+impl<T: Validate + Typed + Reflect> FromType<T> for ReflectValidate {
+    fn from_type() -> Self {
+        Self {
+            func: |value| value.downcast_ref::<T>().map(T::validate),
+        }
+    }
+}
+
+impl TypePath for ReflectValidate {
+    #[inline(always)]
+    fn type_path() -> &'static str {
+        "vc_reflect::registry::ReflectValidate"
+    }
+
+    #[inline(always)]
+    fn type_name() -> &'static str {
+        "ReflectValidate"
+    }
+
+    #[inline(always)]
+    fn type_ident() -> &'static str {
+        "ReflectValidate"
+    }
+
+    #[inline(always)]
+    fn module_path() -> Option<&'static str> {
+        Some("vc_reflect::registry")
+    }
+}
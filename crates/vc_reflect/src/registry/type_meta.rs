@@ -59,7 +59,7 @@ impl TypeMeta {
     /// let mut meta = TypeMeta::of::<String>();
     /// ```
     #[inline]
-    pub fn of<T: Typed>() -> Self {
+    pub fn of<T: Typed + ?Sized>() -> Self {
         let type_info = T::type_info();
         let ty = type_info.ty();
         Self {
@@ -71,7 +71,7 @@ impl TypeMeta {
 
     /// Create a empty [`TypeMeta`] from a type with capacity.
     #[inline]
-    pub fn with_capacity<T: Typed>(capacity: usize) -> Self {
+    pub fn with_capacity<T: Typed + ?Sized>(capacity: usize) -> Self {
         let type_info = T::type_info();
         let ty = type_info.ty();
         Self {
@@ -81,6 +81,20 @@ impl TypeMeta {
         }
     }
 
+    /// Create a empty [`TypeMeta`] from the [`TypeInfo`] of a type.
+    ///
+    /// This is useful when only the [`TypeInfo`] is known, e.g. for the
+    /// field types of another type. Type traits usually need the concrete
+    /// type, prefer [`TypeMeta::of`] when it is available.
+    #[inline]
+    pub fn from_type_info(type_info: &'static TypeInfo) -> Self {
+        Self {
+            ty: type_info.ty(),
+            type_info,
+            trait_table: TypeIdMap::new(),
+        }
+    }
+
     /// Returns the [`TypeInfo`] .
     #[inline(always)]
     pub const fn type_info(&self) -> &'static TypeInfo {
@@ -1,11 +1,17 @@
+use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
 use core::any::TypeId;
+use core::fmt;
+use core::ops::Deref;
 
-use vc_utils::extra::TypeIdMap;
+use vc_utils::extra::{TypeIdEntry, TypeIdMap};
 use vc_utils::hash::{HashMap, HashSet};
 
-use crate::info::{TypeInfo, Typed};
-use crate::registry::{FromType, GetTypeMeta, TypeMeta, TypeTrait};
+use crate::Reflect;
+use crate::info::{NamedField, TypeInfo, TypeUuid, Typed, UnnamedField, VariantInfo};
+use crate::registry::{FromType, GetTypeMeta, ReflectFromReflect, TypeMeta, TypeTrait};
 
 // -----------------------------------------------------------------------------
 // TypeRegistry
@@ -42,9 +48,12 @@ use crate::registry::{FromType, GetTypeMeta, TypeMeta, TypeTrait};
 /// [reflected]: crate
 /// [Registering]: TypeRegistry::register
 /// [crate-level documentation]: crate
+#[derive(Clone)]
 pub struct TypeRegistry {
     type_meta_table: TypeIdMap<TypeMeta>,
     type_path_to_id: HashMap<&'static str, TypeId>,
+    type_uuid_to_id: HashMap<TypeUuid, TypeId>,
+    type_path_aliases: HashMap<&'static str, TypeId>,
     type_name_to_id: HashMap<&'static str, TypeId>,
     ambiguous_names: HashSet<&'static str>,
 }
@@ -64,6 +73,8 @@ impl TypeRegistry {
         Self {
             type_meta_table: TypeIdMap::new(),
             type_path_to_id: HashMap::new(),
+            type_uuid_to_id: HashMap::new(),
+            type_path_aliases: HashMap::new(),
             type_name_to_id: HashMap::new(),
             ambiguous_names: HashSet::new(),
         }
@@ -104,6 +115,8 @@ impl TypeRegistry {
     fn add_new_type_indices(
         type_meta: &TypeMeta,
         type_path_to_id: &mut HashMap<&'static str, TypeId>,
+        type_uuid_to_id: &mut HashMap<TypeUuid, TypeId>,
+        type_path_aliases: &HashMap<&'static str, TypeId>,
         type_name_to_id: &mut HashMap<&'static str, TypeId>,
         ambiguous_names: &mut HashSet<&'static str>,
     ) {
@@ -123,6 +136,14 @@ impl TypeRegistry {
 
         // For new type, assuming that the full path cannot be duplicated.
         type_path_to_id.insert(ty.path(), ty.id());
+        // A uuid may only be taken over from an alias with the same path.
+        let _old = type_uuid_to_id.insert(ty.uuid(), ty.id());
+        debug_assert!(
+            _old.is_none_or(|id| id == ty.id() || type_path_aliases.contains_key(ty.path())),
+            "type uuid {} of `{}` is already used by another type",
+            ty.uuid(),
+            ty.path(),
+        );
     }
 
     // - If key [`TypeId`] has already exist, the function will do nothing and return `false`.
@@ -133,6 +154,8 @@ impl TypeRegistry {
             Self::add_new_type_indices(
                 &meta,
                 &mut self.type_path_to_id,
+                &mut self.type_uuid_to_id,
+                &self.type_path_aliases,
                 &mut self.type_name_to_id,
                 &mut self.ambiguous_names,
             );
@@ -154,6 +177,8 @@ impl TypeRegistry {
             Self::add_new_type_indices(
                 &type_meta,
                 &mut self.type_path_to_id,
+                &mut self.type_uuid_to_id,
+                &self.type_path_aliases,
                 &mut self.type_name_to_id,
                 &mut self.ambiguous_names,
             );
@@ -172,15 +197,22 @@ impl TypeRegistry {
     /// This method will _not_ register type dependencies.
     /// Use [`register`](Self::register) to register a type with its dependencies.
     pub fn insert_type_meta(&mut self, type_meta: TypeMeta) {
-        if !self.type_meta_table.contains(&type_meta.type_id()) {
-            Self::add_new_type_indices(
-                &type_meta,
-                &mut self.type_path_to_id,
-                &mut self.type_name_to_id,
-                &mut self.ambiguous_names,
-            );
+        match self.type_meta_table.entry(type_meta.type_id()) {
+            TypeIdEntry::Occupied(mut entry) => {
+                entry.insert(type_meta);
+            }
+            TypeIdEntry::Vacant(entry) => {
+                Self::add_new_type_indices(
+                    &type_meta,
+                    &mut self.type_path_to_id,
+                    &mut self.type_uuid_to_id,
+                    &self.type_path_aliases,
+                    &mut self.type_name_to_id,
+                    &mut self.ambiguous_names,
+                );
+                entry.insert(type_meta);
+            }
         }
-        self.type_meta_table.insert(type_meta.type_id(), type_meta);
     }
 
     /// Attempts to register the type `T` if it has not yet been registered already.
@@ -220,13 +252,95 @@ impl TypeRegistry {
     /// // Its type data
     /// assert!(type_registry.get_type_trait::<ReflectDefault>(TypeId::of::<Foo>()).is_some());
     /// ```
-    pub fn register<T: GetTypeMeta>(&mut self) -> &mut Self {
+    pub fn register<T: GetTypeMeta + ?Sized>(&mut self) -> &mut Self {
         if self.register_internal(TypeId::of::<T>(), T::get_type_meta) {
             T::register_dependencies(self);
         }
         self
     }
 
+    /// Registers the type `T` with its dependencies, then walks its [`TypeInfo`]
+    /// to register every type it refers to that is still missing.
+    ///
+    /// [`register`](Self::register) relies on [`GetTypeMeta::register_dependencies`],
+    /// which manual implementations may omit. This method follows fields, items,
+    /// keys and values of nested types instead, so deserializing a `T` does not
+    /// fail on a missing type deep inside nested generics.
+    ///
+    /// Types found this way only have their [`TypeInfo`], without type traits,
+    /// see [`TypeMeta::from_type_info`]. Already registered types are kept as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::any::TypeId;
+    /// # use vc_reflect::{Reflect, registry::{GetTypeMeta, TypeMeta, TypeRegistry}};
+    /// #[derive(Reflect)]
+    /// struct Item(u8);
+    ///
+    /// #[derive(Reflect)]
+    /// #[reflect(GetTypeMeta = false)]
+    /// struct Inventory {
+    ///     items: Vec<Item>,
+    /// }
+    ///
+    /// // Does not register its dependencies.
+    /// impl GetTypeMeta for Inventory {
+    ///     fn get_type_meta() -> TypeMeta {
+    ///         TypeMeta::of::<Self>()
+    ///     }
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::empty();
+    /// registry.register::<Inventory>();
+    /// assert!(!registry.contains(TypeId::of::<Item>()));
+    ///
+    /// registry.register_recursive::<Inventory>();
+    /// assert!(registry.contains(TypeId::of::<Vec<Item>>()));
+    /// assert!(registry.contains(TypeId::of::<Item>()));
+    /// assert!(registry.contains(TypeId::of::<u8>()));
+    /// ```
+    pub fn register_recursive<T: GetTypeMeta + ?Sized>(&mut self) -> &mut Self {
+        self.register::<T>();
+
+        // Recursive types refer to themselves, so each type is visited once.
+        let mut visited: TypeIdMap<()> = TypeIdMap::new();
+        let mut stack = vec![T::type_info()];
+        while let Some(type_info) = stack.pop() {
+            if !visited.try_insert(type_info.type_id(), || ()) {
+                continue;
+            }
+            self.try_insert_type_meta(TypeMeta::from_type_info(type_info));
+
+            match type_info {
+                TypeInfo::Struct(info) => stack.extend(info.iter().map(NamedField::type_info)),
+                TypeInfo::TupleStruct(info) => {
+                    stack.extend(info.iter().map(UnnamedField::type_info));
+                }
+                TypeInfo::Tuple(info) => stack.extend(info.iter().map(UnnamedField::type_info)),
+                TypeInfo::List(info) => stack.push(info.item_info()),
+                TypeInfo::Array(info) => stack.push(info.item_info()),
+                TypeInfo::Map(info) => stack.extend([info.key_info(), info.value_info()]),
+                TypeInfo::Set(info) => stack.push(info.value_info()),
+                TypeInfo::Enum(info) => {
+                    for variant in info.iter() {
+                        match variant {
+                            VariantInfo::Struct(info) => {
+                                stack.extend(info.iter().map(NamedField::type_info));
+                            }
+                            VariantInfo::Tuple(info) => {
+                                stack.extend(info.iter().map(UnnamedField::type_info));
+                            }
+                            VariantInfo::Unit(_) => {}
+                        }
+                    }
+                }
+                TypeInfo::Opaque(_) => {}
+            }
+        }
+        self
+    }
+
     /// Attempts to register the referenced type `T` if it has not yet been registered.
     ///
     /// See [`register`](TypeRegistry::register) for more details.
@@ -366,31 +480,123 @@ impl TypeRegistry {
     /// Returns a reference to the [`TypeMeta`] of the type with
     /// the given [type path].
     ///
-    /// If no type with the given type path has been registered, returns `None`.
+    /// If no type with the given type path or [alias] has been registered, returns `None`.
     ///
     /// [type path]: crate::info::TypePath::type_path
+    /// [alias]: Self::register_alias
     pub fn get_with_type_path(&self, type_path: &str) -> Option<&TypeMeta> {
         // Manual inline
         match self.type_path_to_id.get(type_path) {
             Some(id) => self.get(*id),
-            None => None,
+            None => match self.type_path_aliases.get(type_path) {
+                Some(id) => self.get(*id),
+                None => None,
+            },
         }
     }
 
     /// Returns a mutable reference to the [`TypeMeta`] of the type with
     /// the given [type path].
     ///
-    /// If no type with the given type path has been registered, returns `None`.
+    /// If no type with the given type path or [alias] has been registered, returns `None`.
     ///
     /// [type path]: crate::info::TypePath::type_path
+    /// [alias]: Self::register_alias
     pub fn get_with_type_path_mut(&mut self, type_path: &str) -> Option<&mut TypeMeta> {
         // Manual inline
         match self.type_path_to_id.get(type_path) {
             Some(id) => self.get_mut(*id),
+            None => match self.type_path_aliases.get(type_path) {
+                Some(id) => self.get_mut(*id),
+                None => None,
+            },
+        }
+    }
+
+    /// Returns a reference to the [`TypeMeta`] of the type with the given [type uuid].
+    ///
+    /// If no type with the given uuid or [alias] has been registered, returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vc_reflect::{Reflect, info::TypePath, registry::TypeRegistry};
+    ///
+    /// #[derive(Reflect)]
+    /// #[reflect(uuid = "6f1c2b5e-8a4d-4e0f-9b7a-3c2d1e0f4a5b")]
+    /// struct Health(f32);
+    ///
+    /// let mut registry = TypeRegistry::new();
+    /// registry.register::<Health>();
+    ///
+    /// let meta = registry.get_with_type_uuid(Health::type_uuid()).unwrap();
+    /// assert_eq!(meta.ty().path(), Health::type_path());
+    /// ```
+    ///
+    /// [type uuid]: crate::info::TypePath::type_uuid
+    /// [alias]: Self::register_alias
+    pub fn get_with_type_uuid(&self, type_uuid: TypeUuid) -> Option<&TypeMeta> {
+        match self.type_uuid_to_id.get(&type_uuid) {
+            Some(id) => self.get(*id),
             None => None,
         }
     }
 
+    /// Returns a mutable reference to the [`TypeMeta`] of the type with
+    /// the given [type uuid].
+    ///
+    /// If no type with the given uuid or [alias] has been registered, returns `None`.
+    ///
+    /// [type uuid]: crate::info::TypePath::type_uuid
+    /// [alias]: Self::register_alias
+    pub fn get_with_type_uuid_mut(&mut self, type_uuid: TypeUuid) -> Option<&mut TypeMeta> {
+        match self.type_uuid_to_id.get(&type_uuid) {
+            Some(id) => self.get_mut(*id),
+            None => None,
+        }
+    }
+
+    /// Registers a former type path of a type, e.g. after it was renamed or moved.
+    ///
+    /// [`get_with_type_path`] falls back to the aliases, so data serialized
+    /// with the old path can still be loaded. The [default uuid] of the old path
+    /// is also mapped to the type, unless it belongs to another type.
+    ///
+    /// The type itself does not need to be registered yet. For derived types,
+    /// aliases can also be declared with `#[reflect(alias = "...")]`, which are
+    /// registered together with the type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::any::TypeId;
+    /// use vc_reflect::{Reflect, info::{TypePath, TypeUuid}, registry::TypeRegistry};
+    ///
+    /// #[derive(Reflect)]
+    /// struct Health(f32);
+    ///
+    /// let mut registry = TypeRegistry::new();
+    /// registry
+    ///     .register::<Health>()
+    ///     .register_alias("game::stats::Hp", TypeId::of::<Health>());
+    ///
+    /// let meta = registry.get_with_type_path("game::stats::Hp").unwrap();
+    /// assert_eq!(meta.ty().path(), Health::type_path());
+    ///
+    /// let old_uuid = TypeUuid::from_type_path("game::stats::Hp");
+    /// assert!(registry.get_with_type_uuid(old_uuid).is_some());
+    /// ```
+    ///
+    /// [`get_with_type_path`]: Self::get_with_type_path
+    /// [default uuid]: TypeUuid::from_type_path
+    pub fn register_alias(&mut self, alias: &'static str, type_id: TypeId) -> &mut Self {
+        self.type_path_aliases.insert(alias, type_id);
+        self.type_uuid_to_id
+            .entry(TypeUuid::from_type_path(alias))
+            .or_insert(type_id);
+        self
+    }
+
     /// Returns a reference to the [`TypeMeta`] of the type with the given [type name].
     ///
     /// If the type name is ambiguous, or if no type with the given path
@@ -475,6 +681,51 @@ impl TypeRegistry {
         self.get(type_id).map(TypeMeta::type_info)
     }
 
+    /// Builds a value of the type with the given [type path] from `value`,
+    /// through its [`ReflectFromReflect`].
+    ///
+    /// `value` is usually a dynamic type, such as a deserialized
+    /// [`DynamicStruct`], so the target type only needs to be known at runtime.
+    ///
+    /// Returns `None` if the type is not registered, has no `ReflectFromReflect`,
+    /// or `value` cannot be converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vc_reflect::{Reflect, info::TypePath, ops::DynamicStruct, registry::TypeRegistry};
+    ///
+    /// #[derive(Reflect, PartialEq, Debug)]
+    /// struct Foo {
+    ///     id: u32,
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::new();
+    /// registry.register::<Foo>();
+    ///
+    /// let mut dynamic = DynamicStruct::default();
+    /// dynamic.extend("id", 7_u32);
+    ///
+    /// // e.g. read from a scene file
+    /// let type_path: &str = Foo::type_path();
+    ///
+    /// let value = registry.from_reflect_boxed(type_path, &dynamic).unwrap();
+    /// assert_eq!(value.take::<Foo>().unwrap(), Foo { id: 7 });
+    /// ```
+    ///
+    /// [type path]: crate::info::TypePath::type_path
+    /// [`ReflectFromReflect`]: crate::registry::ReflectFromReflect
+    /// [`DynamicStruct`]: crate::ops::DynamicStruct
+    pub fn from_reflect_boxed(
+        &self,
+        type_path: &str,
+        value: &dyn Reflect,
+    ) -> Option<Box<dyn Reflect>> {
+        self.get_with_type_path(type_path)?
+            .get_trait::<ReflectFromReflect>()?
+            .from_reflect(value)
+    }
+
     /// Returns an iterator over the [`TypeMeta`]s of the registered types.
     pub fn iter(&self) -> impl ExactSizeIterator<Item = &TypeMeta> {
         self.type_meta_table.values()
@@ -493,14 +744,205 @@ impl TypeRegistry {
             type_trait.map(|t| (item, t))
         })
     }
+
+    /// Moves all types of `other` into this registry, e.g. to collect
+    /// per-plugin registries into the registry of the application.
+    ///
+    /// Types registered in both registries are resolved by `policy`,
+    /// see [`MergePolicy`].
+    ///
+    /// # Errors
+    ///
+    /// With [`MergePolicy::Error`], returns the type paths of all conflicting
+    /// registrations, in which case nothing is merged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::any::TypeId;
+    /// # use vc_reflect::{Reflect, info::TypePath};
+    /// use vc_reflect::registry::{MergePolicy, ReflectDefault, TypeMeta, TypeRegistry};
+    ///
+    /// #[derive(Reflect, Default)]
+    /// #[reflect(default)]
+    /// struct Foo;
+    ///
+    /// let mut plugin = TypeRegistry::new();
+    /// plugin.register::<Foo>();
+    ///
+    /// let mut app = TypeRegistry::new();
+    /// app.insert_type_meta(TypeMeta::of::<Foo>());
+    ///
+    /// // Both registries contain the same `String` registration, which is fine,
+    /// // but `Foo` is registered with different type traits.
+    /// let err = app.merge(plugin.clone(), MergePolicy::Error).unwrap_err();
+    /// assert_eq!(err.type_paths(), [Foo::type_path()]);
+    ///
+    /// app.merge(plugin, MergePolicy::Overwrite).unwrap();
+    /// assert!(app.get_type_trait::<ReflectDefault>(TypeId::of::<Foo>()).is_some());
+    /// ```
+    pub fn merge(&mut self, other: TypeRegistry, policy: MergePolicy) -> Result<(), MergeError> {
+        if policy == MergePolicy::Error {
+            let mut type_paths: Vec<&'static str> = other
+                .iter()
+                .filter(|meta| match self.get(meta.type_id()) {
+                    Some(existing) => !same_traits(existing, meta),
+                    None => false,
+                })
+                .map(|meta| meta.ty().path())
+                .collect();
+            if !type_paths.is_empty() {
+                type_paths.sort_unstable();
+                return Err(MergeError { type_paths });
+            }
+        }
+
+        for (alias, type_id) in other.type_path_aliases {
+            self.register_alias(alias, type_id);
+        }
+
+        for (_, type_meta) in other.type_meta_table {
+            match policy {
+                MergePolicy::Overwrite => self.insert_type_meta(type_meta),
+                MergePolicy::KeepFirst | MergePolicy::Error => {
+                    self.try_insert_type_meta(type_meta);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Converts the registry into a read-only [`FrozenTypeRegistry`].
+    pub fn freeze(mut self) -> FrozenTypeRegistry {
+        self.type_meta_table.shrink_to_fit();
+        self.type_path_to_id.shrink_to_fit();
+        self.type_uuid_to_id.shrink_to_fit();
+        self.type_path_aliases.shrink_to_fit();
+        self.type_name_to_id.shrink_to_fit();
+        self.ambiguous_names.shrink_to_fit();
+        FrozenTypeRegistry {
+            internal: Arc::new(self),
+        }
+    }
+}
+
+/// Whether both metas contain the same set of type traits.
+fn same_traits(a: &TypeMeta, b: &TypeMeta) -> bool {
+    a.trait_count() == b.trait_count() && a.trait_iter().all(|(id, _)| b.has_trait_by_id(id))
 }
 
 // -----------------------------------------------------------------------------
-// TypeRegistryArc
+// MergePolicy
+
+/// Decides how [`TypeRegistry::merge`] handles types registered in both registries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MergePolicy {
+    /// Keeps the [`TypeMeta`] already in the registry.
+    KeepFirst,
+    /// Replaces the [`TypeMeta`] already in the registry.
+    Overwrite,
+    /// Fails if the two registrations differ, i.e. do not contain the same
+    /// [type traits](TypeTrait). Identical registrations are kept once.
+    Error,
+}
+
+/// The error returned by [`TypeRegistry::merge`] for conflicting registrations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeError {
+    type_paths: Vec<&'static str>,
+}
+
+impl MergeError {
+    /// Returns the sorted type paths of the conflicting types.
+    #[inline]
+    pub fn type_paths(&self) -> &[&'static str] {
+        &self.type_paths
+    }
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("conflicting registrations for ")?;
+        for (index, type_path) in self.type_paths.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "`{type_path}`")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for MergeError {}
+
+// -----------------------------------------------------------------------------
+// FrozenTypeRegistry
 
 use vc_os::sync::{Arc, PoisonError};
 use vc_os::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
+/// A read-only snapshot of a [`TypeRegistry`], created by [`TypeRegistry::freeze`].
+///
+/// Unlike [`TypeRegistryArc`], lookups take no lock, and cloning only
+/// increments a reference count. It dereferences to the [`TypeRegistry`],
+/// so it can be passed to everything that reads a registry, such as the
+/// (de)serialization drivers.
+///
+/// # Example
+///
+/// ```
+/// # use core::any::TypeId;
+/// use vc_reflect::registry::TypeRegistry;
+///
+/// let frozen = TypeRegistry::new().freeze();
+/// let shared = frozen.clone();
+///
+/// assert!(shared.contains(TypeId::of::<u32>()));
+///
+/// // Changes require thawing the snapshot first.
+/// let mut registry = frozen.into_registry();
+/// registry.register::<Option<u32>>();
+/// ```
+#[derive(Clone)]
+pub struct FrozenTypeRegistry {
+    internal: Arc<TypeRegistry>,
+}
+
+impl FrozenTypeRegistry {
+    /// Converts the snapshot back into a [`TypeRegistry`].
+    ///
+    /// The registry is cloned if the snapshot is still shared.
+    #[inline]
+    pub fn into_registry(self) -> TypeRegistry {
+        Arc::unwrap_or_clone(self.internal)
+    }
+}
+
+impl Deref for FrozenTypeRegistry {
+    type Target = TypeRegistry;
+
+    #[inline(always)]
+    fn deref(&self) -> &TypeRegistry {
+        &self.internal
+    }
+}
+
+impl From<TypeRegistry> for FrozenTypeRegistry {
+    #[inline]
+    fn from(registry: TypeRegistry) -> Self {
+        registry.freeze()
+    }
+}
+
+impl fmt::Debug for FrozenTypeRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.internal.type_path_to_id.keys().fmt(f)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// TypeRegistryArc
+
 #[derive(Clone, Default)]
 pub struct TypeRegistryArc {
     /// The wrapped [`TypeRegistry`].
@@ -519,10 +961,22 @@ impl TypeRegistryArc {
             .write()
             .unwrap_or_else(PoisonError::into_inner)
     }
+
+    /// Registers the type `T` if it has not yet been registered, see
+    /// [`TypeRegistry::register`].
+    ///
+    /// Only a read lock is taken if `T` is already registered, so this can be
+    /// called on hot paths that lazily register the types they use, e.g. the
+    /// instantiations of generic types, from several threads at once.
+    pub fn register<T: GetTypeMeta + ?Sized>(&self) {
+        if !self.read().contains(TypeId::of::<T>()) {
+            self.write().register::<T>();
+        }
+    }
 }
 
-impl core::fmt::Debug for TypeRegistryArc {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+impl fmt::Debug for TypeRegistryArc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.internal
             .read()
             .unwrap_or_else(PoisonError::into_inner)
@@ -537,13 +991,14 @@ impl core::fmt::Debug for TypeRegistryArc {
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
     use alloc::vec::Vec;
     use core::any::TypeId;
 
-    use super::{TypeRegistry, TypeRegistryArc};
+    use super::{MergePolicy, TypeRegistry, TypeRegistryArc};
     use crate::Reflect;
-    use crate::info::TypePath;
-    use crate::registry::{ReflectDefault, ReflectFromPtr};
+    use crate::info::{TypePath, TypeUuid};
+    use crate::registry::{ReflectDefault, ReflectFromPtr, TypeMeta};
 
     mod foo {
         use crate::Reflect;
@@ -585,6 +1040,23 @@ mod tests {
         assert!(registry.get_with_type_name("MyType").is_none());
     }
 
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "is already used by another type")]
+    fn uuid_collision() {
+        #[derive(Reflect)]
+        #[reflect(uuid = "6f1c2b5e-8a4d-4e0f-9b7a-3c2d1e0f4a5b")]
+        struct First;
+
+        #[derive(Reflect)]
+        #[reflect(uuid = "6f1c2b5e-8a4d-4e0f-9b7a-3c2d1e0f4a5b")]
+        struct Second;
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<First>();
+        registry.register::<Second>();
+    }
+
     #[test]
     fn registers_traits() {
         let mut registry = TypeRegistry::default();
@@ -612,4 +1084,87 @@ mod tests {
         arc.write().register::<NeedsDefault>();
         assert!(arc.read().contains(TypeId::of::<NeedsDefault>()));
     }
+
+    #[test]
+    fn merge_registries() {
+        let mut first = TypeRegistry::empty();
+        first.register::<foo::MyType>();
+        first.insert_type_meta(TypeMeta::of::<NeedsDefault>());
+
+        let mut second = TypeRegistry::empty();
+        second.register::<bar::MyType>();
+        second.register::<NeedsDefault>();
+
+        let err = first
+            .clone()
+            .merge(second.clone(), MergePolicy::Error)
+            .unwrap_err();
+        assert_eq!(err.type_paths(), [NeedsDefault::type_path()]);
+        assert!(err.to_string().contains("NeedsDefault`"));
+
+        first.merge(second, MergePolicy::KeepFirst).unwrap();
+        // The first registration is kept, without `ReflectDefault`.
+        assert!(
+            first
+                .get_type_trait::<ReflectDefault>(TypeId::of::<NeedsDefault>())
+                .is_none()
+        );
+        assert!(first.contains(TypeId::of::<i32>()));
+        assert!(first.is_ambiguous("MyType"));
+        assert!(first.get_with_type_path(bar::MyType::type_path()).is_some());
+
+        let frozen = first.freeze();
+        assert!(frozen.clone().contains(TypeId::of::<NeedsDefault>()));
+        assert!(frozen.into_registry().is_ambiguous("MyType"));
+    }
+
+    #[derive(Reflect)]
+    #[reflect(alias = "old::Renamed", alias = "older::Renamed")]
+    struct Renamed;
+
+    #[test]
+    fn aliases() {
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Renamed>();
+
+        for path in ["old::Renamed", "older::Renamed", Renamed::type_path()] {
+            let meta = registry.get_with_type_path(path).unwrap();
+            assert_eq!(meta.type_id(), TypeId::of::<Renamed>());
+        }
+        let old_uuid = TypeUuid::from_type_path("old::Renamed");
+        assert!(registry.get_with_type_uuid(old_uuid).is_some());
+
+        // Aliases never shadow registered type paths.
+        registry.register::<foo::MyType>();
+        registry.register_alias(foo::MyType::type_path(), TypeId::of::<Renamed>());
+        let meta = registry
+            .get_with_type_path(foo::MyType::type_path())
+            .unwrap();
+        assert_eq!(meta.type_id(), TypeId::of::<foo::MyType>());
+    }
+
+    #[derive(Reflect)]
+    struct Node {
+        children: Vec<Node>,
+        value: Option<(u8, NeedsDefault)>,
+    }
+
+    #[test]
+    fn register_recursive() {
+        let mut registry = TypeRegistry::empty();
+        registry.register_recursive::<Node>();
+        assert!(registry.contains(TypeId::of::<Vec<Node>>()));
+        assert!(registry.contains(TypeId::of::<(u8, NeedsDefault)>()));
+        // Types with `GetTypeMeta` keep their type traits.
+        assert!(
+            registry
+                .get_type_trait::<ReflectDefault>(TypeId::of::<NeedsDefault>())
+                .is_some()
+        );
+
+        let arc = TypeRegistryArc::default();
+        arc.register::<Node>();
+        arc.register::<Node>();
+        assert!(arc.read().contains(TypeId::of::<i32>()));
+    }
 }
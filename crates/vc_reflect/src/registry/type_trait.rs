@@ -119,10 +119,10 @@ mod tests {
         let type_trait: &dyn TypeTrait = &marker;
 
         assert!(type_trait.is::<Marker>());
-        assert_eq!(type_trait.downcast_ref::<Marker>().unwrap().0, true);
+        assert!(type_trait.downcast_ref::<Marker>().unwrap().0);
         assert_eq!(alloc::format!("{type_trait:?}"), "Marker");
 
         let cloned = type_trait.clone_type_trait();
-        assert_eq!(cloned.downcast_ref::<Marker>().unwrap().0, true);
+        assert!(cloned.downcast_ref::<Marker>().unwrap().0);
     }
 }
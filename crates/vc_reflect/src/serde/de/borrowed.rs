@@ -0,0 +1,140 @@
+use alloc::borrow::{Cow, ToOwned};
+use core::fmt;
+
+use serde_core::Deserializer;
+use serde_core::de::{Error, Visitor};
+
+crate::cfg::std! {
+    use core::cell::Cell;
+
+    std::thread_local! {
+        /// The `'static` input of the innermost [`DeserializeDriver::from_slice`].
+        ///
+        /// [`DeserializeDriver::from_slice`]: super::DeserializeDriver::from_slice
+        static BORROWED_INPUT: Cell<&'static [u8]> = const { Cell::new(&[]) };
+    }
+}
+
+/// Makes strings inside `input` borrowable while alive,
+/// restores the previous input when dropped.
+pub(super) struct BorrowScope {
+    #[cfg(feature = "std")]
+    previous: &'static [u8],
+}
+
+impl BorrowScope {
+    #[inline]
+    pub(super) fn new(input: &'static [u8]) -> Self {
+        crate::cfg::std! {
+            if {
+                Self {
+                    previous: BORROWED_INPUT.replace(input),
+                }
+            } else {
+                let _ = input;
+                Self {}
+            }
+        }
+    }
+}
+
+impl Drop for BorrowScope {
+    fn drop(&mut self) {
+        crate::cfg::std! {
+            BORROWED_INPUT.set(self.previous);
+        }
+    }
+}
+
+/// Returns `value` with a `'static` lifetime if it lies inside the input
+/// of the current [`BorrowScope`].
+fn borrow_static(value: &str) -> Option<&'static str> {
+    crate::cfg::std! {
+        if {
+            let input = BORROWED_INPUT.get();
+            let start = (value.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+            let bytes = input.get(start..)?.get(..value.len())?;
+            // `bytes` is the memory of `value`, so it is valid UTF-8.
+            core::str::from_utf8(bytes).ok()
+        } else {
+            let _ = value;
+            None
+        }
+    }
+}
+
+/// Deserializes a `Cow<'static, str>`, borrowing it from the input of
+/// [`DeserializeDriver::from_slice`] when the format hands out borrowed strings.
+///
+/// [`DeserializeDriver::from_slice`]: super::DeserializeDriver::from_slice
+pub(crate) fn deserialize_cow_str<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Cow<'static, str>, D::Error> {
+    struct CowStrVisitor;
+
+    impl<'de> Visitor<'de> for CowStrVisitor {
+        type Value = Cow<'static, str>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a string")
+        }
+
+        fn visit_borrowed_str<E: Error>(self, value: &'de str) -> Result<Self::Value, E> {
+            Ok(borrow_static(value).map_or_else(|| Cow::Owned(value.to_owned()), Cow::Borrowed))
+        }
+
+        fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+            Ok(Cow::Owned(value.to_owned()))
+        }
+
+        fn visit_string<E: Error>(self, value: alloc::string::String) -> Result<Self::Value, E> {
+            Ok(Cow::Owned(value))
+        }
+    }
+
+    deserializer.deserialize_str(CowStrVisitor)
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::borrow::Cow;
+    use alloc::string::String;
+
+    use super::{BorrowScope, borrow_static, deserialize_cow_str};
+
+    static INPUT: &str = r#"{"name":"borrowed"}"#;
+
+    #[test]
+    fn borrow_inside_input() {
+        let name = &INPUT[9..17];
+        assert_eq!(borrow_static(name), None);
+
+        let scope = BorrowScope::new(INPUT.as_bytes());
+        assert_eq!(borrow_static(name), Some("borrowed"));
+        assert_eq!(borrow_static(&String::from("borrowed")), None);
+
+        // Nested scopes restore the outer input.
+        {
+            let _inner = BorrowScope::new(b"other");
+            assert_eq!(borrow_static(name), None);
+        }
+        assert_eq!(borrow_static(name), Some("borrowed"));
+
+        drop(scope);
+        assert_eq!(borrow_static(name), None);
+    }
+
+    #[test]
+    fn cow_str() {
+        static INPUT: &str = r#""text""#;
+        let read = || deserialize_cow_str(&mut serde_json::Deserializer::from_str(INPUT)).unwrap();
+
+        assert!(matches!(read(), Cow::Owned(text) if text == "text"));
+
+        let _scope = BorrowScope::new(INPUT.as_bytes());
+        assert!(matches!(read(), Cow::Borrowed("text")));
+    }
+}
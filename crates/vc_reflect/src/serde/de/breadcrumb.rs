@@ -0,0 +1,158 @@
+use core::fmt;
+
+use serde_core::Deserializer;
+use serde_core::de::{DeserializeSeed, Error};
+
+crate::cfg::std! {
+    use alloc::vec::Vec;
+
+    /// The path to the value being deserialized, and the number of
+    /// [`DeserializeDriver`]s currently running.
+    ///
+    /// [`DeserializeDriver`]: super::DeserializeDriver
+    struct Breadcrumb {
+        depth: usize,
+        path: Vec<Crumb>,
+    }
+
+    std::thread_local! {
+        static BREADCRUMB: core::cell::RefCell<Breadcrumb> = const {
+            core::cell::RefCell::new(Breadcrumb { depth: 0, path: Vec::new() })
+        };
+    }
+
+    /// Leaves a [`DeserializeDriver`] even if it panics.
+    ///
+    /// [`DeserializeDriver`]: super::DeserializeDriver
+    struct DepthGuard;
+
+    impl Drop for DepthGuard {
+        fn drop(&mut self) {
+            BREADCRUMB.with_borrow_mut(|crumbs| crumbs.depth -= 1);
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Crumb
+
+/// A segment of the path to the value being deserialized.
+#[derive(Clone, Copy)]
+pub(super) enum Crumb {
+    /// The root type, displayed as its type ident.
+    ///
+    /// The path is only tracked with the `std` feature.
+    #[cfg(feature = "std")]
+    Root(&'static str),
+    /// A named field, displayed as `.name`.
+    Field(&'static str),
+    /// A tuple field, displayed as `.0`.
+    TupleField(usize),
+    /// An element of a list, array, set or map, displayed as `[3]`.
+    Index(usize),
+    /// An enum variant, displayed as `::Name`.
+    Variant(&'static str),
+}
+
+impl fmt::Display for Crumb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            Self::Root(ident) => f.write_str(ident),
+            Self::Field(name) => write!(f, ".{name}"),
+            Self::TupleField(index) => write!(f, ".{index}"),
+            Self::Index(index) => write!(f, "[{index}]"),
+            Self::Variant(name) => write!(f, "::{name}"),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Path tracking
+
+/// Runs `f` with `crumb` appended to the path.
+///
+/// The crumb is kept on errors, so the root driver can report where the
+/// error happened.
+#[inline]
+pub(super) fn scoped<T, E>(crumb: Crumb, f: impl FnOnce() -> Result<T, E>) -> Result<T, E> {
+    crate::cfg::std! {
+        if {
+            BREADCRUMB.with_borrow_mut(|crumbs| crumbs.path.push(crumb));
+            let result = f();
+            if result.is_ok() {
+                BREADCRUMB.with_borrow_mut(|crumbs| crumbs.path.pop());
+            }
+            result
+        } else {
+            let _ = crumb;
+            f()
+        }
+    }
+}
+
+/// Runs a [`DeserializeDriver`] for the type `ident`.
+///
+/// The outermost driver starts a new path, and appends it to its error,
+/// such as `invalid type: string "a", expected u32 (at Scene.entities[3].x)`.
+///
+/// [`DeserializeDriver`]: super::DeserializeDriver
+pub(super) fn root<T, E: Error>(
+    ident: &'static str,
+    f: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    crate::cfg::std! {
+        if {
+            let is_root = BREADCRUMB.with_borrow_mut(|crumbs| {
+                crumbs.depth += 1;
+                let is_root = crumbs.depth == 1;
+                if is_root {
+                    crumbs.path.clear();
+                    crumbs.path.push(Crumb::Root(ident));
+                }
+                is_root
+            });
+            let guard = DepthGuard;
+            let result = f();
+            drop(guard);
+
+            if !is_root {
+                return result;
+            }
+
+            let path = BREADCRUMB.with_borrow_mut(|crumbs| core::mem::take(&mut crumbs.path));
+            result.map_err(|err| {
+                E::custom(format_args!("{err} (at {})", Path(&path)))
+            })
+        } else {
+            let _ = ident;
+            f()
+        }
+    }
+}
+
+crate::cfg::std! {
+    struct Path<'a>(&'a [Crumb]);
+
+    impl fmt::Display for Path<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.iter().try_for_each(|crumb| crumb.fmt(f))
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Crumbed
+
+/// A [`DeserializeSeed`] that deserializes `S` with a [`Crumb`] appended to the path.
+pub(super) struct Crumbed<S>(pub Crumb, pub S);
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for Crumbed<S> {
+    type Value = S::Value;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let Crumbed(crumb, seed) = self;
+        scoped(crumb, || seed.deserialize(deserializer))
+    }
+}
@@ -7,10 +7,13 @@ use serde_core::de::{DeserializeSeed, Error, IgnoredAny, MapAccess, Visitor};
 
 use super::DeserializeProcessor;
 use super::array_visitor::ArrayVisitor;
+use super::borrowed::BorrowScope;
+use super::breadcrumb;
 use super::enum_visitor::EnumVisitor;
 use super::list_visitor::ListVisitor;
 use super::map_visitor::MapVisitor;
 use super::option_visitor::OptionVisitor;
+use super::scratch::ScratchScope;
 use super::set_visitor::SetVisitor;
 use super::struct_visitor::StructVisitor;
 use super::tuple_struct_visitor::TupleStructVisitor;
@@ -19,7 +22,7 @@ use super::tuple_visitor::TupleVisitor;
 use crate::Reflect;
 use crate::info::{TypeInfo, Typed};
 use crate::registry::{GetTypeMeta, TypeMeta, TypeRegistry};
-use crate::registry::{ReflectDeserialize, ReflectFromReflect};
+use crate::registry::{ReflectBox, ReflectDeserialize, ReflectFromReflect, ReflectProxy};
 
 crate::cfg::debug! {
     use super::error_utils::TYPE_INFO_STACK;
@@ -40,7 +43,9 @@ crate::cfg::debug! {
 ///    If the processor supports the type (successfully or with an error), return its result immediately.
 ///
 /// 2. **Type Trait Fallback**: If no processor is available, look for [`ReflectDeserialize`]
-///    in the [`TypeMeta`] and use its implementation.
+///    in the [`TypeMeta`] and use its implementation. A registered [`ReflectProxy`]
+///    takes precedence, the proxy type is then deserialized and converted. Proxies restricted
+///    to human-readable or binary formats are checked with [`Deserializer::is_human_readable`].
 ///
 /// 3. **Reflection Default**: As a last resort, use the reflection system's default deserialization method,
 ///    which returns dynamic types always.
@@ -143,12 +148,47 @@ crate::cfg::debug! {
 /// assert_eq!(value.take::<MyStruct>().unwrap(), MyStruct { value: 123 });
 /// ```
 ///
+/// # Errors
+///
+/// With the `std` feature, errors end with the path of the value that failed,
+/// made of field names, tuple fields (`.0`), element indices (`[3]`) and enum
+/// variants (`::Name`), starting at the root type ident. This is available in
+/// release builds too, unlike the type info stack added in debug builds.
+///
+/// ```
+/// # use serde_core::de::DeserializeSeed;
+/// # use vc_reflect::{Reflect, serde::DeserializeDriver, registry::TypeRegistry};
+/// #[derive(Reflect)]
+/// struct Scene {
+///     entities: Vec<Transform>,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Transform {
+///     translation: (f32, f32),
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Scene>();
+///
+/// let input = r#"(entities: [(translation: (0.0, 1.0)), (translation: (2.0, "3.0"))])"#;
+///
+/// let meta = registry.get(core::any::TypeId::of::<Scene>()).unwrap();
+/// let mut data = ron::Deserializer::from_str(input).unwrap();
+/// let error = DeserializeDriver::new(meta, &registry)
+///     .deserialize(&mut data)
+///     .unwrap_err();
+///
+/// assert!(error.to_string().contains("(at Scene.entities[1].translation.1)"));
+/// ```
+///
 /// [`SerializeDriver`]: crate::serde::SerializeDriver
 /// [`ReflectSerializeDriver`]: crate::serde::ReflectSerializeDriver
 pub struct DeserializeDriver<'a, P: DeserializeProcessor = ()> {
     type_meta: &'a TypeMeta,
     registry: &'a TypeRegistry,
     processor: Option<&'a mut P>,
+    validation: bool,
 }
 
 impl<'a> DeserializeDriver<'a, ()> {
@@ -162,6 +202,7 @@ impl<'a> DeserializeDriver<'a, ()> {
             type_meta,
             registry,
             processor: None,
+            validation: false,
         }
     }
 
@@ -181,6 +222,7 @@ impl<'a> DeserializeDriver<'a, ()> {
             type_meta,
             registry,
             processor: None,
+            validation: false,
         }
     }
 }
@@ -200,6 +242,7 @@ impl<'a, P: DeserializeProcessor> DeserializeDriver<'a, P> {
             type_meta,
             registry,
             processor: Some(processor),
+            validation: false,
         }
     }
 
@@ -214,17 +257,93 @@ impl<'a, P: DeserializeProcessor> DeserializeDriver<'a, P> {
             type_meta,
             registry,
             processor,
+            validation: false,
         }
     }
+
+    /// Sets whether to [`validate`] the deserialized value.
+    ///
+    /// Disabled by default. When enabled, a validation failure is
+    /// reported as a deserialization error.
+    ///
+    /// [`validate`]: crate::validate::validate
+    #[inline]
+    pub fn with_validation(mut self, enabled: bool) -> Self {
+        self.validation = enabled;
+        self
+    }
 }
 
 impl<'de, P: DeserializeProcessor> DeserializeSeed<'de> for DeserializeDriver<'_, P> {
     type Value = Box<dyn Reflect>;
 
-    fn deserialize<D: Deserializer<'de>>(
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let ident = self.type_meta.type_info().type_ident();
+        breadcrumb::root(ident, || self.deserialize_value(deserializer))
+    }
+}
+
+impl<P: DeserializeProcessor> DeserializeDriver<'_, P> {
+    /// Deserializes a value from `deserializer`, which reads the `'static` buffer `input`.
+    ///
+    /// This behaves like [`DeserializeSeed::deserialize`], but `Cow<'static, str>`
+    /// values that the format hands out as borrowed strings point into `input`
+    /// instead of being copied, which saves an allocation per string when loading
+    /// embedded or leaked data. Other strings are copied as usual, and so are all
+    /// strings without the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::borrow::Cow;
+    /// # use vc_reflect::{Reflect, FromReflect, serde::DeserializeDriver, registry::TypeRegistry};
+    /// #[derive(Reflect)]
+    /// struct Label {
+    ///     text: Cow<'static, str>,
+    /// }
+    ///
+    /// static INPUT: &str = r#"{ "text": "hello" }"#;
+    ///
+    /// let mut registry = TypeRegistry::new();
+    /// registry.register::<Label>();
+    ///
+    /// let mut data = serde_json::Deserializer::from_str(INPUT);
+    /// let value = DeserializeDriver::of::<Label>(&registry)
+    ///     .from_slice(INPUT.as_bytes(), &mut data)
+    ///     .unwrap();
+    ///
+    /// let label = Label::from_reflect(&*value).unwrap();
+    /// assert!(matches!(label.text, Cow::Borrowed("hello")));
+    /// ```
+    pub fn from_slice<'de, D: Deserializer<'de>>(
+        self,
+        input: &'static [u8],
+        deserializer: D,
+    ) -> Result<Box<dyn Reflect>, D::Error> {
+        let _borrow = BorrowScope::new(input);
+        self.deserialize(deserializer)
+    }
+
+    fn deserialize_value<'de, D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Box<dyn Reflect>, D::Error> {
+        let registry = self.registry;
+        let validation = self.validation;
+        let value = self.deserialize_unvalidated(deserializer)?;
+
+        // Values built by the processor are validated as well.
+        if validation && let Err(errors) = crate::validate::validate(&*value, registry) {
+            return Err(Error::custom(errors));
+        }
+        Ok(value)
+    }
+
+    fn deserialize_unvalidated<'de, D: Deserializer<'de>>(
         mut self,
         deserializer: D,
-    ) -> Result<Self::Value, D::Error> {
+    ) -> Result<Box<dyn Reflect>, D::Error> {
         let deserializer = if let Some(processor) = self.processor.as_deref_mut() {
             match processor.try_deserialize(self.type_meta, self.registry, deserializer) {
                 Ok(Ok(value)) => return Ok(value),
@@ -235,6 +354,46 @@ impl<'de, P: DeserializeProcessor> DeserializeSeed<'de> for DeserializeDriver<'_
             deserializer
         };
 
+        // Boxed trait objects are read with the type path of the value inside.
+        if let Some(boxed) = self.type_meta.get_trait::<ReflectBox>() {
+            let value = deserializer.deserialize_map(ReflectDeserializeDriverVisitor {
+                registry: self.registry,
+                processor: self.processor,
+                validation: false,
+            })?;
+            return boxed.rebox(value, self.registry).map_err(|value| {
+                Error::custom(format!(
+                    "`{}` cannot be stored as `{}`",
+                    value.reflect_type_path(),
+                    self.type_meta.type_info().type_path(),
+                ))
+            });
+        }
+
+        // Types with a proxy for this format are read as the proxy value, then converted.
+        if let Some(proxy) = self.type_meta.get_trait::<ReflectProxy>()
+            && proxy.is_used_for(deserializer.is_human_readable())
+        {
+            let type_path = self.type_meta.type_info().type_path();
+            let Some(proxy_meta) = self.registry.get(proxy.proxy_type_id()) else {
+                return Err(Error::custom(format!(
+                    "the proxy type of `{type_path}` is not registered",
+                )));
+            };
+            let value = DeserializeDriver {
+                type_meta: proxy_meta,
+                validation: false,
+                ..self
+            }
+            .deserialize(deserializer)?;
+            return proxy.from_proxy(&*value).ok_or_else(|| {
+                Error::custom(format!(
+                    "`{}` cannot be converted to `{type_path}`",
+                    value.reflect_type_path(),
+                ))
+            });
+        }
+
         if let Some(deserialize_reflect) = self.type_meta.get_trait::<ReflectDeserialize>() {
             return deserialize_reflect.deserialize(deserializer);
         }
@@ -413,6 +572,9 @@ impl<'de, P: DeserializeProcessor> DeserializeSeed<'de> for DeserializeDriver<'_
 /// }
 /// ```
 ///
+/// The type path is looked up with [`TypeRegistry::get_with_type_path`], so
+/// paths registered as [aliases] are accepted too.
+///
 /// # Output
 ///
 /// This deserializer will return a [`Box<dyn Reflect>`] containing the deserialized data.
@@ -462,6 +624,7 @@ impl<'de, P: DeserializeProcessor> DeserializeSeed<'de> for DeserializeDriver<'_
 /// ```
 ///
 /// [`ReflectSerializeDriver`]: crate::serde::ReflectSerializeDriver
+/// [aliases]: TypeRegistry::register_alias
 /// [`Box<dyn Reflect>`]: crate::Reflect
 /// [`Box<DynamicStruct>`]: crate::ops::DynamicStruct
 /// [`Box<DynamicList>`]: crate::ops::DynamicList
@@ -469,6 +632,7 @@ impl<'de, P: DeserializeProcessor> DeserializeSeed<'de> for DeserializeDriver<'_
 pub struct ReflectDeserializeDriver<'a, P: DeserializeProcessor = ()> {
     registry: &'a TypeRegistry,
     processor: Option<&'a mut P>,
+    validation: bool,
 }
 
 impl<'a> ReflectDeserializeDriver<'a, ()> {
@@ -477,6 +641,7 @@ impl<'a> ReflectDeserializeDriver<'a, ()> {
         Self {
             registry,
             processor: None,
+            validation: false,
         }
     }
 }
@@ -487,70 +652,123 @@ impl<'a, P: DeserializeProcessor> ReflectDeserializeDriver<'a, P> {
         Self {
             registry,
             processor: Some(processor),
+            validation: false,
         }
     }
+
+    /// Sets whether to [`validate`] the deserialized value.
+    ///
+    /// Disabled by default. When enabled, validation runs once on the final
+    /// value (after the [`ReflectFromReflect`] conversion), and a failure is
+    /// reported as a deserialization error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde_core::de::DeserializeSeed;
+    /// # use vc_reflect::prelude::{Reflect, TypeRegistry, ReflectDeserializeDriver};
+    /// use vc_reflect::validate::Range;
+    ///
+    /// #[derive(Reflect)]
+    /// #[reflect(type_path = "my_crate::Volume")]
+    /// struct Volume {
+    ///     #[reflect(@Range::new(0.0, 1.0))]
+    ///     level: f32,
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::new();
+    /// registry.register::<Volume>();
+    ///
+    /// let input = r#"{ "my_crate::Volume": ( level: 2.0 ) }"#;
+    ///
+    /// let mut data = ron::Deserializer::from_str(input).unwrap();
+    /// let deserializer = ReflectDeserializeDriver::new(&registry).with_validation(true);
+    ///
+    /// assert!(deserializer.deserialize(&mut data).is_err());
+    /// ```
+    ///
+    /// [`validate`]: crate::validate::validate
+    #[inline]
+    pub fn with_validation(mut self, enabled: bool) -> Self {
+        self.validation = enabled;
+        self
+    }
 }
 
 impl<'de, P: DeserializeProcessor> DeserializeSeed<'de> for ReflectDeserializeDriver<'_, P> {
     type Value = Box<dyn Reflect>;
 
     fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
-        struct ReflectDeserializeDriverVisitor<'a, P> {
-            registry: &'a TypeRegistry,
-            processor: Option<&'a mut P>,
-        }
-
-        impl<'de, P: DeserializeProcessor> Visitor<'de> for ReflectDeserializeDriverVisitor<'_, P> {
-            type Value = Box<dyn Reflect>;
-
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter
-                    .write_str("map containing `type` and `value` entries for the reflected value")
-            }
-
-            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
-            where
-                A: MapAccess<'de>,
-            {
-                // Resolve the target type metadata from the registry.
-                let type_meta = map
-                    .next_key_seed(TypePathDeserializer::new(self.registry))?
-                    .ok_or_else(|| Error::invalid_length(0, &"a single entry"))?;
-
-                let value = map.next_value_seed(DeserializeDriver::new_internal(
-                    type_meta,
-                    self.registry,
-                    self.processor,
-                ))?;
-
-                if map.next_key::<IgnoredAny>()?.is_some() {
-                    return Err(Error::invalid_length(2, &"a single entry"));
-                }
-
-                if (*value).type_id() != type_meta.type_id()
-                    && let Some(from_reflect) = type_meta.get_trait::<ReflectFromReflect>()
-                    && let Some(target_value) = from_reflect.from_reflect(&*value)
-                {
-                    return Ok(target_value);
-                }
-
-                Ok(value)
-            }
-        }
-
         crate::cfg::debug! {
             // Defensive cleanup for early-return paths in debug builds.
             TYPE_INFO_STACK.with_borrow_mut(|stack|stack.clear());
         }
 
+        // Temporary buffers are reused while reading the value, and freed after.
+        let _scratch = ScratchScope::enter();
+
         deserializer.deserialize_map(ReflectDeserializeDriverVisitor {
             registry: self.registry,
             processor: self.processor,
+            validation: self.validation,
         })
     }
 }
 
 /// A helper that resolves [`TypeMeta`] from a type-path string.
+/// Reads a single-entry map of a type path and the data of that type,
+/// see [`ReflectDeserializeDriver`].
+struct ReflectDeserializeDriverVisitor<'a, P> {
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+    validation: bool,
+}
+
+impl<'de, P: DeserializeProcessor> Visitor<'de> for ReflectDeserializeDriverVisitor<'_, P> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("map containing `type` and `value` entries for the reflected value")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        // Resolve the target type metadata from the registry.
+        let type_meta = map
+            .next_key_seed(TypePathDeserializer::new(self.registry))?
+            .ok_or_else(|| Error::invalid_length(0, &"a single entry"))?;
+
+        let value = map.next_value_seed(DeserializeDriver::new_internal(
+            type_meta,
+            self.registry,
+            self.processor,
+        ))?;
+
+        if map.next_key::<IgnoredAny>()?.is_some() {
+            return Err(Error::invalid_length(2, &"a single entry"));
+        }
+
+        let value = if (*value).type_id() != type_meta.type_id()
+            && let Some(from_reflect) = type_meta.get_trait::<ReflectFromReflect>()
+            && let Some(target_value) = from_reflect.from_reflect(&*value)
+        {
+            target_value
+        } else {
+            value
+        };
+
+        if self.validation
+            && let Err(errors) = crate::validate::validate(&*value, self.registry)
+        {
+            return Err(Error::custom(errors));
+        }
+
+        Ok(value)
+    }
+}
+
 struct TypePathDeserializer<'a> {
     registry: &'a TypeRegistry,
 }
@@ -586,3 +804,18 @@ impl<'a, 'de> DeserializeSeed<'de> for TypePathDeserializer<'a> {
         deserializer.deserialize_str(TypePathVisitor(self.registry))
     }
 }
+
+// -----------------------------------------------------------------------------
+// WithDeserializer
+
+/// Deserializes a field through its `deserialize_with` function.
+pub(super) struct WithDeserializer<'a>(pub &'a ReflectDeserialize);
+
+impl<'de> DeserializeSeed<'de> for WithDeserializer<'_> {
+    type Value = Box<dyn Reflect>;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.0.deserialize(deserializer)
+    }
+}
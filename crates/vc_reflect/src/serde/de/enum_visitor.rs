@@ -5,6 +5,8 @@ use serde_core::Deserializer;
 use serde_core::de::{DeserializeSeed, Error, Visitor};
 use serde_core::de::{EnumAccess, MapAccess, SeqAccess, VariantAccess};
 
+use super::breadcrumb::{self, Crumb};
+use super::driver::WithDeserializer;
 use super::error_utils::make_custom_error;
 use super::struct_like_utils::{visit_struct, visit_struct_seq};
 use super::tuple_like_utils::{TupleLikeInfo, visit_tuple};
@@ -41,49 +43,56 @@ impl<'de, P: DeserializeProcessor> Visitor<'de> for EnumVisitor<'_, P> {
             enum_info: self.enum_info,
         })?;
 
-        let value: DynamicVariant = match variant_info {
-            VariantInfo::Unit(_) => variant.unit_variant()?.into(),
-            VariantInfo::Struct(info) => variant
-                .struct_variant(
-                    info.field_names(),
-                    StructVariantVisitor {
-                        struct_info: info,
-                        registry: self.registry,
-                        processor: self.processor,
-                    },
-                )?
-                .into(),
-            VariantInfo::Tuple(info) => {
-                if info.field_len() == 1 && !info.field_at(0).unwrap().skip_serde() {
-                    let field = TupleLikeInfo::field_at(info, 0)?;
-                    let Some(type_meta) = self.registry.get(field.type_id()) else {
-                        return Err(make_custom_error(format!(
-                            "no TypeMeta found for type `{}`",
-                            field.type_info().type_path(),
-                        )));
-                    };
-
-                    let value = variant.newtype_variant_seed(DeserializeDriver::new_internal(
-                        type_meta,
-                        self.registry,
-                        self.processor,
-                    ))?;
-                    let mut dynamic = DynamicTuple::with_capacity(1);
-                    dynamic.extend_boxed(value);
-                    dynamic.into()
-                } else {
-                    let dynamic = variant.tuple_variant(
-                        info.field_len(),
-                        TupleVariantVisitor {
-                            tuple_info: info,
+        let crumb = Crumb::Variant(variant_info.name());
+        let value = breadcrumb::scoped(crumb, || {
+            Ok::<DynamicVariant, A::Error>(match variant_info {
+                VariantInfo::Unit(_) => variant.unit_variant()?.into(),
+                VariantInfo::Struct(info) => variant
+                    .struct_variant(
+                        info.field_names(),
+                        StructVariantVisitor {
+                            struct_info: info,
                             registry: self.registry,
                             processor: self.processor,
                         },
-                    )?;
-                    dynamic.into()
+                    )?
+                    .into(),
+                VariantInfo::Tuple(info) => {
+                    if info.field_len() == 1 && !info.field_at(0).unwrap().skip_serde() {
+                        let field = TupleLikeInfo::field_at(info, 0)?;
+                        let value = if let Some(with) = field.deserialize_with() {
+                            variant.newtype_variant_seed(WithDeserializer(with))?
+                        } else {
+                            let Some(type_meta) = self.registry.get(field.type_id()) else {
+                                return Err(make_custom_error(format!(
+                                    "no TypeMeta found for type `{}`",
+                                    field.type_info().type_path(),
+                                )));
+                            };
+
+                            variant.newtype_variant_seed(DeserializeDriver::new_internal(
+                                type_meta,
+                                self.registry,
+                                self.processor,
+                            ))?
+                        };
+                        let mut dynamic = DynamicTuple::with_capacity(1);
+                        dynamic.extend_boxed(value);
+                        dynamic.into()
+                    } else {
+                        let dynamic = variant.tuple_variant(
+                            info.field_len(),
+                            TupleVariantVisitor {
+                                tuple_info: info,
+                                registry: self.registry,
+                                processor: self.processor,
+                            },
+                        )?;
+                        dynamic.into()
+                    }
                 }
-            }
-        };
+            })
+        })?;
         let variant_name = variant_info.name();
         let variant_index = self.enum_info.index_of(variant_name).unwrap();
         let dynamic_enum = DynamicEnum::new(variant_index, variant_name, value);
@@ -3,6 +3,7 @@ use core::fmt::{self, Formatter};
 
 use serde_core::de::{MapAccess, Visitor};
 
+use super::breadcrumb::{Crumb, Crumbed};
 use super::error_utils::make_custom_error;
 use super::{DeserializeDriver, DeserializeProcessor};
 
@@ -46,18 +47,24 @@ impl<'de, P: DeserializeProcessor> Visitor<'de> for MapVisitor<'_, P> {
         let capacity_hint = map.size_hint().unwrap_or_default();
         let mut dynamic = DynamicMap::with_capacity(capacity_hint);
 
-        while let Some(key) = map.next_key_seed(DeserializeDriver::new_internal(
-            key_meta,
-            self.registry,
-            self.processor.as_deref_mut(),
+        let mut index = 0;
+        while let Some(key) = map.next_key_seed(Crumbed(
+            Crumb::Index(index),
+            DeserializeDriver::new_internal(key_meta, self.registry, self.processor.as_deref_mut()),
         ))? {
-            let value = map.next_value_seed(DeserializeDriver::new_internal(
-                value_meta,
-                self.registry,
-                self.processor.as_deref_mut(),
+            let value = map.next_value_seed(Crumbed(
+                Crumb::Index(index),
+                DeserializeDriver::new_internal(
+                    value_meta,
+                    self.registry,
+                    self.processor.as_deref_mut(),
+                ),
             ))?;
 
-            dynamic.extend_boxed(key, value);
+            dynamic
+                .try_extend_boxed(key, value)
+                .map_err(make_custom_error)?;
+            index += 1;
         }
 
         Ok(dynamic)
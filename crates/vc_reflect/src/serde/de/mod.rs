@@ -1,9 +1,12 @@
 // -----------------------------------------------------------------------------
 // Modules
 
+mod borrowed;
+mod breadcrumb;
 mod driver;
 mod error_utils;
 mod processor;
+mod scratch;
 
 mod array_visitor;
 mod enum_visitor;
@@ -23,3 +26,5 @@ mod tuple_like_utils;
 
 pub use driver::{DeserializeDriver, ReflectDeserializeDriver};
 pub use processor::DeserializeProcessor;
+
+pub(crate) use borrowed::deserialize_cow_str;
@@ -28,8 +28,17 @@ use crate::registry::{TypeMeta, TypeRegistry};
 ///
 /// See deserialization rules in [`DeserializeDriver`] .
 ///
+/// ## Format Awareness
+///
+/// The deserializer is passed unchanged, so a processor can pick a different form for
+/// human-readable and binary formats with `Deserializer::is_human_readable`, e.g. a hex
+/// string for RON scenes and raw bytes for network snapshots. For a whole type, a
+/// [`ReflectProxy`] restricted by [`ProxyFormat`] is usually simpler.
+///
 /// [`ReflectDeserialize`]: crate::registry::ReflectDeserialize
 /// [`DeserializeDriver`]: crate::serde::DeserializeDriver
+/// [`ReflectProxy`]: crate::registry::ReflectProxy
+/// [`ProxyFormat`]: crate::registry::ProxyFormat
 /// [`&dyn Reflect`]: crate::Reflect
 /// [`&TypeRegistry`]: crate::registry::TypeRegistry
 /// [`&TypeMeta`]: crate::registry::TypeMeta
@@ -0,0 +1,136 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::Reflect;
+
+/// Temporary field slots of a struct, filled in any order.
+pub(super) type Slots = Vec<Option<Box<dyn Reflect>>>;
+
+crate::cfg::std! {
+    /// Released buffers above this capacity are dropped instead of pooled.
+    const MAX_SLOTS_CAPACITY: usize = 256;
+
+    std::thread_local! {
+        /// Slot buffers released by finished structs, reused by the next ones.
+        ///
+        /// Nested structs take buffers in stack order, so the pool only grows
+        /// to the nesting depth of the deserialized data.
+        static SLOTS_POOL: core::cell::RefCell<Vec<Slots>> =
+            const { core::cell::RefCell::new(Vec::new()) };
+
+        /// The number of live [`ScratchScope`]s on this thread.
+        static SCRATCH_DEPTH: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+    }
+}
+
+/// Takes a buffer of `len` empty slots, reusing a released one if possible.
+pub(super) fn take_slots(len: usize) -> Slots {
+    crate::cfg::std! {
+        if {
+            let mut slots = SLOTS_POOL
+                .with_borrow_mut(Vec::pop)
+                .unwrap_or_default();
+            slots.resize_with(len, || None);
+            slots
+        } else {
+            (0..len).map(|_| None).collect()
+        }
+    }
+}
+
+/// Releases a buffer whose values were moved out.
+pub(super) fn release_slots(mut slots: Slots) {
+    slots.clear();
+    crate::cfg::std! {
+        if slots.capacity() <= MAX_SLOTS_CAPACITY {
+            SLOTS_POOL.with_borrow_mut(|pool| pool.push(slots));
+        }
+    }
+}
+
+/// Frees the pooled buffers when a root value is deserialized, even on errors.
+///
+/// Drivers may run inside each other, e.g. from a [`DeserializeProcessor`],
+/// so only the outermost scope frees the pool.
+///
+/// [`DeserializeProcessor`]: super::DeserializeProcessor
+pub(super) struct ScratchScope {
+    _private: (),
+}
+
+impl ScratchScope {
+    #[inline]
+    pub(super) fn enter() -> Self {
+        crate::cfg::std! {
+            SCRATCH_DEPTH.set(SCRATCH_DEPTH.get() + 1);
+        }
+        Self { _private: () }
+    }
+}
+
+impl Drop for ScratchScope {
+    fn drop(&mut self) {
+        crate::cfg::std! {
+            let depth = SCRATCH_DEPTH.get() - 1;
+            SCRATCH_DEPTH.set(depth);
+            if depth == 0 {
+                SLOTS_POOL.with_borrow_mut(Vec::clear);
+            }
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{SLOTS_POOL, ScratchScope, release_slots, take_slots};
+
+    #[test]
+    fn reuse_slots() {
+        let _scratch = ScratchScope::enter();
+
+        let slots = take_slots(4);
+        assert_eq!(slots.len(), 4);
+        let ptr = slots.as_ptr();
+        release_slots(slots);
+
+        // Nested structs take the released buffer back, filled with `None`.
+        let slots = take_slots(2);
+        assert_eq!(slots.as_ptr(), ptr);
+        assert!(slots.iter().all(Option::is_none));
+        release_slots(slots);
+
+        // Oversized buffers are not kept.
+        release_slots(Vec::with_capacity(super::MAX_SLOTS_CAPACITY + 1));
+        assert_eq!(SLOTS_POOL.with_borrow(Vec::len), 1);
+    }
+
+    #[test]
+    fn clear_on_scope_end() {
+        {
+            let _scratch = ScratchScope::enter();
+            release_slots(take_slots(8));
+            assert_eq!(SLOTS_POOL.with_borrow(Vec::len), 1);
+        }
+        assert_eq!(SLOTS_POOL.with_borrow(Vec::len), 0);
+    }
+
+    #[test]
+    fn keep_pool_in_nested_scope() {
+        let outer = ScratchScope::enter();
+        release_slots(take_slots(8));
+        {
+            let _inner = ScratchScope::enter();
+            release_slots(take_slots(8));
+        }
+        // The inner driver leaves the buffers of the outer one alone.
+        assert_eq!(SLOTS_POOL.with_borrow(Vec::len), 1);
+
+        drop(outer);
+        assert_eq!(SLOTS_POOL.with_borrow(Vec::len), 0);
+    }
+}
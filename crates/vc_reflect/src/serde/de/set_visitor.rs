@@ -3,6 +3,7 @@ use core::fmt::{self, Formatter};
 
 use serde_core::de::{SeqAccess, Visitor};
 
+use super::breadcrumb::{Crumb, Crumbed};
 use super::error_utils::make_custom_error;
 use super::{DeserializeDriver, DeserializeProcessor};
 
@@ -40,12 +41,17 @@ impl<'de, P: DeserializeProcessor> Visitor<'de> for SetVisitor<'_, P> {
         let capacity_hint = set.size_hint().unwrap_or_default();
         let mut dynamic = DynamicSet::with_capacity(capacity_hint);
 
-        while let Some(value) = set.next_element_seed(DeserializeDriver::new_internal(
-            type_meta,
-            self.registry,
-            self.processor.as_deref_mut(),
+        let mut index = 0;
+        while let Some(value) = set.next_element_seed(Crumbed(
+            Crumb::Index(index),
+            DeserializeDriver::new_internal(
+                type_meta,
+                self.registry,
+                self.processor.as_deref_mut(),
+            ),
         ))? {
-            dynamic.extend_boxed(value);
+            dynamic.try_extend_boxed(value).map_err(make_custom_error)?;
+            index += 1;
         }
 
         Ok(dynamic)
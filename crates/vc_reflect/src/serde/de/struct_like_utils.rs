@@ -1,16 +1,17 @@
-use alloc::boxed::Box;
 use alloc::format;
-use alloc::string::String;
 use core::fmt;
 
-use serde_core::de::{Error, IgnoredAny, MapAccess, SeqAccess, Visitor};
-use serde_core::{Deserialize, Deserializer};
-use vc_utils::hash::HashMap;
+use serde_core::Deserializer;
+use serde_core::de::{
+    DeserializeSeed, Error, IgnoredAny, MapAccess, SeqAccess, Unexpected, Visitor,
+};
 
+use super::breadcrumb::{Crumb, Crumbed};
+use super::driver::WithDeserializer;
 use super::error_utils::make_custom_error;
+use super::scratch::{release_slots, take_slots};
 use super::{DeserializeDriver, DeserializeProcessor};
 
-use crate::Reflect;
 use crate::info::{NamedField, StructInfo, StructVariantInfo};
 use crate::ops::DynamicStruct;
 use crate::registry::{ReflectDefault, TypeRegistry};
@@ -21,7 +22,7 @@ use crate::registry::{ReflectDefault, TypeRegistry};
 /// A helper trait for accessing type information from struct-like types.
 pub(super) trait StructLikeInfo {
     fn name(&self) -> &'static str;
-    fn field<E: Error>(&self, name: &str) -> Result<&NamedField, E>;
+    fn index_of<E: Error>(&self, name: &str) -> Result<usize, E>;
     fn field_at<E: Error>(&self, index: usize) -> Result<&NamedField, E>;
     fn field_len(&self) -> usize;
 }
@@ -31,8 +32,8 @@ impl StructLikeInfo for StructInfo {
         self.type_path()
     }
 
-    fn field<E: Error>(&self, name: &str) -> Result<&NamedField, E> {
-        <Self>::field(self, name).ok_or_else(|| {
+    fn index_of<E: Error>(&self, name: &str) -> Result<usize, E> {
+        <Self>::index_of(self, name).ok_or_else(|| {
             Error::custom(format!(
                 "no field named `{}` on struct `{}`",
                 name,
@@ -62,8 +63,8 @@ impl StructLikeInfo for StructVariantInfo {
         <Self>::name(self)
     }
 
-    fn field<E: Error>(&self, name: &str) -> Result<&NamedField, E> {
-        <Self>::field(self, name).ok_or_else(|| {
+    fn index_of<E: Error>(&self, name: &str) -> Result<usize, E> {
+        <Self>::index_of(self, name).ok_or_else(|| {
             Error::custom(format!(
                 "no field named `{}` on variant `{}`",
                 name,
@@ -89,16 +90,22 @@ impl StructLikeInfo for StructVariantInfo {
 }
 
 // -----------------------------------------------------------------------------
-// Ident parser
+// Field parser
 
-struct Ident(pub String);
+/// Resolves a field name to its index, without allocating the name.
+///
+/// Formats that can borrow from the input hand over the name in place,
+/// the others pass a transient buffer.
+struct FieldIndex<'a, T>(&'a T);
+
+impl<'de, T: StructLikeInfo> DeserializeSeed<'de> for FieldIndex<'_, T> {
+    type Value = usize;
 
-impl<'de> Deserialize<'de> for Ident {
-    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        struct IdentVisitor;
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        struct FieldVisitor<'a, T>(&'a T);
 
-        impl<'de> Visitor<'de> for IdentVisitor {
-            type Value = Ident;
+        impl<'de, T: StructLikeInfo> Visitor<'de> for FieldVisitor<'_, T> {
+            type Value = usize;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
                 formatter.write_str("identifier")
@@ -106,16 +113,18 @@ impl<'de> Deserialize<'de> for Ident {
 
             #[inline]
             fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
-                Ok(Ident(String::from(value)))
+                self.0.index_of(value)
             }
 
-            #[inline]
-            fn visit_string<E: Error>(self, value: String) -> Result<Self::Value, E> {
-                Ok(Ident(value))
+            fn visit_bytes<E: Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+                match core::str::from_utf8(value) {
+                    Ok(value) => self.0.index_of(value),
+                    Err(_) => Err(Error::invalid_value(Unexpected::Bytes(value), &self)),
+                }
             }
         }
 
-        deserializer.deserialize_identifier(IdentVisitor)
+        deserializer.deserialize_identifier(FieldVisitor(self.0))
     }
 }
 
@@ -137,31 +146,35 @@ where
     P: DeserializeProcessor,
 {
     let field_len = info.field_len();
-    let mut buffer: HashMap<String, Box<dyn Reflect>> = HashMap::with_capacity(field_len);
+    let mut buffer = take_slots(field_len);
 
-    while let Some(Ident(key)) = map.next_key::<Ident>()? {
-        let field = info.field::<V::Error>(&key)?;
+    while let Some(index) = map.next_key_seed(FieldIndex(info))? {
+        let field = info.field_at::<V::Error>(index)?;
+        if let Some(with) = field.deserialize_with() {
+            let seed = Crumbed(Crumb::Field(field.name()), WithDeserializer(with));
+            buffer[index] = Some(map.next_value_seed(seed)?);
+            continue;
+        }
         let Some(type_meta) = registry.get(field.type_id()) else {
             return Err(make_custom_error(format!(
                 "no TypeMeta found for type `{}`",
                 field.type_info().type_path(),
             )));
         };
-        let value = map.next_value_seed(DeserializeDriver::new_internal(
-            type_meta,
-            registry,
-            processor.as_deref_mut(),
+        let value = map.next_value_seed(Crumbed(
+            Crumb::Field(field.name()),
+            DeserializeDriver::new_internal(type_meta, registry, processor.as_deref_mut()),
         ))?;
-        buffer.insert(key, value);
+        buffer[index] = Some(value);
     }
 
     let mut dynamic = DynamicStruct::with_capacity(field_len);
 
-    for index in 0..field_len {
+    for (index, value) in buffer.drain(..).enumerate() {
         let field = info.field_at::<V::Error>(index)?;
         let field_name: &'static str = field.name();
 
-        if let Some(value) = buffer.remove(field_name) {
+        if let Some(value) = value {
             dynamic.extend_boxed(field_name, value);
         } else if field.skip_serde() {
             if let Some(ctor) = registry.get_type_trait::<ReflectDefault>(field.type_id()) {
@@ -183,6 +196,7 @@ where
         }
     }
 
+    release_slots(buffer);
     Ok(dynamic)
 }
 
@@ -220,18 +234,21 @@ where
             }
         }
 
-        let Some(type_meta) = registry.get(field.type_id()) else {
-            return Err(make_custom_error(format!(
-                "no TypeMeta found for type `{}`",
-                field.type_info().type_path(),
-            )));
-        };
+        let value = if let Some(with) = field.deserialize_with() {
+            seq.next_element_seed(Crumbed(Crumb::Field(field_name), WithDeserializer(with)))?
+        } else {
+            let Some(type_meta) = registry.get(field.type_id()) else {
+                return Err(make_custom_error(format!(
+                    "no TypeMeta found for type `{}`",
+                    field.type_info().type_path(),
+                )));
+            };
 
-        let value = seq.next_element_seed(DeserializeDriver::new_internal(
-            type_meta,
-            registry,
-            processor.as_deref_mut(),
-        ))?;
+            seq.next_element_seed(Crumbed(
+                Crumb::Field(field_name),
+                DeserializeDriver::new_internal(type_meta, registry, processor.as_deref_mut()),
+            ))?
+        };
 
         let Some(value) = value else {
             return Err(make_custom_error(format!(
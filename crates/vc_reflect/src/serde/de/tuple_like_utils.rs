@@ -2,6 +2,8 @@ use alloc::format;
 
 use serde_core::de::{Error, IgnoredAny, SeqAccess};
 
+use super::breadcrumb::{Crumb, Crumbed};
+use super::driver::WithDeserializer;
 use super::error_utils::make_custom_error;
 use super::{DeserializeDriver, DeserializeProcessor};
 
@@ -120,18 +122,21 @@ where
             }
         }
 
-        let Some(type_meta) = registry.get(field.type_id()) else {
-            return Err(make_custom_error(format!(
-                "no TypeMeta found for type `{}`",
-                field.type_info().type_path(),
-            )));
-        };
+        let value = if let Some(with) = field.deserialize_with() {
+            seq.next_element_seed(Crumbed(Crumb::TupleField(index), WithDeserializer(with)))?
+        } else {
+            let Some(type_meta) = registry.get(field.type_id()) else {
+                return Err(make_custom_error(format!(
+                    "no TypeMeta found for type `{}`",
+                    field.type_info().type_path(),
+                )));
+            };
 
-        let value = seq.next_element_seed(DeserializeDriver::new_internal(
-            type_meta,
-            registry,
-            processor.as_deref_mut(),
-        ))?;
+            seq.next_element_seed(Crumbed(
+                Crumb::TupleField(index),
+                DeserializeDriver::new_internal(type_meta, registry, processor.as_deref_mut()),
+            ))?
+        };
 
         let Some(value) = value else {
             return Err(make_custom_error(format!(
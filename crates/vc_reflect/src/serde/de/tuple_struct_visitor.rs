@@ -8,6 +8,7 @@ use crate::info::TupleStructInfo;
 use crate::ops::{DynamicTuple, DynamicTupleStruct};
 use crate::registry::TypeRegistry;
 
+use super::driver::WithDeserializer;
 use super::error_utils::make_custom_error;
 use super::tuple_like_utils::visit_tuple;
 use super::{DeserializeDriver, DeserializeProcessor};
@@ -51,17 +52,21 @@ impl<'de, P: DeserializeProcessor> Visitor<'de> for TupleStructVisitor<'_, P> {
         // it should call 'visit_tuple' instead of 'visit_newtype_struct'.
         assert!(self.tuple_struct_info.field_len() == 1 && !field.skip_serde());
 
-        let Some(type_meta) = self.registry.get(field.type_id()) else {
-            return Err(make_custom_error(format!(
-                "no TypeMeta found for type `{}`",
-                field.type_info().type_path(),
-            )));
-        };
-
         let mut dynamic = DynamicTupleStruct::with_capacity(1);
 
-        let de = DeserializeDriver::new_internal(type_meta, self.registry, self.processor);
-        let value = de.deserialize(deserializer)?;
+        let value = if let Some(with) = field.deserialize_with() {
+            WithDeserializer(with).deserialize(deserializer)?
+        } else {
+            let Some(type_meta) = self.registry.get(field.type_id()) else {
+                return Err(make_custom_error(format!(
+                    "no TypeMeta found for type `{}`",
+                    field.type_info().type_path(),
+                )));
+            };
+
+            let de = DeserializeDriver::new_internal(type_meta, self.registry, self.processor);
+            de.deserialize(deserializer)?
+        };
 
         dynamic.extend_boxed(value);
 
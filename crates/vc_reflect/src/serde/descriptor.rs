@@ -0,0 +1,247 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use serde_core::de::{DeserializeSeed, Error};
+use serde_core::{Deserialize, Deserializer, Serialize, Serializer};
+use vc_os::sync::LazyLock;
+
+use crate::FromReflect;
+use crate::Reflect;
+use crate::info::{GenericInfo, NamedField, TypeInfo, UnnamedField, VariantInfo};
+use crate::registry::TypeRegistry;
+use crate::serde::{DeserializeDriver, SerializeDriver};
+
+// -----------------------------------------------------------------------------
+// TypeDescriptor
+
+/// An owned, serializable mirror of a [`TypeInfo`].
+///
+/// [`TypeInfo`] is built from `'static` references and function pointers,
+/// so it can only describe types linked into the current binary. A
+/// `TypeDescriptor` copies the same metadata into plain data, which can be
+/// sent to another process, for example an external editor rendering
+/// inspectors for types it does not link.
+///
+/// Types are referred to by their [type path](crate::info::TypePath::type_path),
+/// a field of type `Foo` only stores the path of `Foo`, whose own descriptor
+/// can be looked up in the same [catalog](Self::catalog).
+///
+/// Custom attributes are type-erased and are not part of the descriptor.
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::{Reflect, info::Typed, serde::TypeDescriptor};
+///
+/// #[derive(Reflect)]
+/// struct Foo {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// let descriptor = TypeDescriptor::new(Foo::type_info());
+/// let text = ron::to_string(&descriptor).unwrap();
+///
+/// let output: TypeDescriptor = ron::from_str(&text).unwrap();
+/// assert_eq!(output, descriptor);
+/// assert_eq!(output.type_name, "Foo");
+/// ```
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(type_path = "vc_reflect::serde::TypeDescriptor")]
+pub struct TypeDescriptor {
+    /// The full type path, see [`TypeInfo::type_path`].
+    pub type_path: String,
+    /// The type name without module path, see [`TypeInfo::type_name`].
+    pub type_name: String,
+    /// The structure of the type.
+    pub shape: TypeShape,
+    /// The instantiated generic parameters.
+    pub generics: Vec<GenericDescriptor>,
+    /// The documentation, only present with the `reflect_docs` feature.
+    pub docs: Option<String>,
+}
+
+/// The structure of a [`TypeDescriptor`], mirroring the variants of [`TypeInfo`].
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(type_path = "vc_reflect::serde::TypeShape")]
+pub enum TypeShape {
+    Struct(Vec<FieldDescriptor>),
+    TupleStruct(Vec<FieldDescriptor>),
+    Tuple(Vec<FieldDescriptor>),
+    List { item: String },
+    Array { item: String, len: usize },
+    Map { key: String, value: String },
+    Set { value: String },
+    Enum(Vec<VariantDescriptor>),
+    Opaque,
+}
+
+/// A field of a struct, tuple or enum variant.
+///
+/// Unnamed fields use their index as name.
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(type_path = "vc_reflect::serde::FieldDescriptor")]
+pub struct FieldDescriptor {
+    pub name: String,
+    pub type_path: String,
+    /// Whether the field is skipped by reflection-based serialization.
+    pub skip_serde: bool,
+    pub docs: Option<String>,
+}
+
+/// A variant of an enum.
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(type_path = "vc_reflect::serde::VariantDescriptor")]
+pub struct VariantDescriptor {
+    pub name: String,
+    pub shape: VariantShape,
+    pub docs: Option<String>,
+}
+
+/// The structure of a [`VariantDescriptor`], mirroring [`VariantKind`].
+///
+/// [`VariantKind`]: crate::info::VariantKind
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(type_path = "vc_reflect::serde::VariantShape")]
+pub enum VariantShape {
+    Struct(Vec<FieldDescriptor>),
+    Tuple(Vec<FieldDescriptor>),
+    Unit,
+}
+
+/// An instantiated generic parameter, mirroring [`GenericInfo`].
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(type_path = "vc_reflect::serde::GenericDescriptor")]
+pub enum GenericDescriptor {
+    Type {
+        name: String,
+        type_path: String,
+        default: Option<String>,
+    },
+    /// The value is rendered as it would be written in Rust, e.g. `5` or `true`.
+    Const {
+        name: String,
+        type_path: String,
+        value: String,
+    },
+}
+
+impl TypeDescriptor {
+    /// Creates a descriptor from the type information.
+    pub fn new(info: &TypeInfo) -> Self {
+        let shape = match info {
+            TypeInfo::Struct(info) => TypeShape::Struct(info.iter().map(named).collect()),
+            TypeInfo::TupleStruct(info) => {
+                TypeShape::TupleStruct(info.iter().map(unnamed).collect())
+            }
+            TypeInfo::Tuple(info) => TypeShape::Tuple(info.iter().map(unnamed).collect()),
+            TypeInfo::List(info) => TypeShape::List {
+                item: info.item_info().type_path().into(),
+            },
+            TypeInfo::Array(info) => TypeShape::Array {
+                item: info.item_info().type_path().into(),
+                len: info.len(),
+            },
+            TypeInfo::Map(info) => TypeShape::Map {
+                key: info.key_info().type_path().into(),
+                value: info.value_info().type_path().into(),
+            },
+            TypeInfo::Set(info) => TypeShape::Set {
+                value: info.value_info().type_path().into(),
+            },
+            TypeInfo::Enum(info) => TypeShape::Enum(info.iter().map(variant).collect()),
+            TypeInfo::Opaque(_) => TypeShape::Opaque,
+        };
+
+        Self {
+            type_path: info.type_path().into(),
+            type_name: info.type_name().into(),
+            shape,
+            generics: info.generics().iter().map(generic).collect(),
+            docs: info.docs().map(Into::into),
+        }
+    }
+
+    /// Creates the descriptors of all types in the registry, sorted by type path.
+    ///
+    /// This is the whole catalog a remote process needs to render the types.
+    pub fn catalog(registry: &TypeRegistry) -> Vec<Self> {
+        let mut catalog: Vec<Self> = registry
+            .iter()
+            .map(|meta| Self::new(meta.type_info()))
+            .collect();
+        catalog.sort_unstable_by(|a, b| a.type_path.cmp(&b.type_path));
+        catalog
+    }
+}
+
+fn named(field: &NamedField) -> FieldDescriptor {
+    FieldDescriptor {
+        name: field.name().into(),
+        type_path: field.type_info().type_path().into(),
+        skip_serde: field.skip_serde(),
+        docs: field.docs().map(Into::into),
+    }
+}
+
+fn unnamed(field: &UnnamedField) -> FieldDescriptor {
+    FieldDescriptor {
+        name: field.index().to_string(),
+        type_path: field.type_info().type_path().into(),
+        skip_serde: field.skip_serde(),
+        docs: field.docs().map(Into::into),
+    }
+}
+
+fn variant(info: &VariantInfo) -> VariantDescriptor {
+    let shape = match info {
+        VariantInfo::Struct(info) => VariantShape::Struct(info.iter().map(named).collect()),
+        VariantInfo::Tuple(info) => VariantShape::Tuple(info.iter().map(unnamed).collect()),
+        VariantInfo::Unit(_) => VariantShape::Unit,
+    };
+    VariantDescriptor {
+        name: info.name().into(),
+        shape,
+        docs: info.docs().map(Into::into),
+    }
+}
+
+fn generic(info: &GenericInfo) -> GenericDescriptor {
+    match info {
+        GenericInfo::Type(info) => GenericDescriptor::Type {
+            name: info.name().into(),
+            type_path: info.type_path().into(),
+            default: info.default().map(|ty| ty.path().into()),
+        },
+        GenericInfo::Const(info) => GenericDescriptor::Const {
+            name: info.name().into(),
+            type_path: info.type_path().into(),
+            value: info.data().to_string(),
+        },
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Serde
+
+// The descriptors are serialized through reflection, this registry
+// contains them and their dependencies.
+static REGISTRY: LazyLock<TypeRegistry> = LazyLock::new(|| {
+    let mut registry = TypeRegistry::new();
+    registry.register::<TypeDescriptor>();
+    registry
+});
+
+impl Serialize for TypeDescriptor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializeDriver::new(self, &REGISTRY).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TypeDescriptor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = DeserializeDriver::of::<TypeDescriptor>(&REGISTRY).deserialize(deserializer)?;
+        TypeDescriptor::from_reflect(&*value)
+            .ok_or_else(|| D::Error::custom("invalid `TypeDescriptor`"))
+    }
+}
@@ -116,6 +116,13 @@
 //! }
 //! ```
 //!
+//! ## Type Descriptors
+//!
+//! [`TypeDescriptor`] is an owned mirror of [`TypeInfo`] that implements `serde` traits,
+//! so the registered types can be exported to another process, see
+//! [`TypeDescriptor::catalog`].
+//!
+//! [`TypeInfo`]: crate::info::TypeInfo
 //! [`TypeMeta`]: crate::registry::TypeMeta
 //! [`ReflectDeserialize`]: crate::registry::ReflectDeserialize
 //! [`ReflectSerialize`]: crate::registry::ReflectSerialize
@@ -132,10 +139,18 @@ crate::cfg::debug! {
 // Modules
 
 mod de;
+mod descriptor;
 mod ser;
+mod with;
 
 // -----------------------------------------------------------------------------
 // Exports
 
+pub(crate) use de::deserialize_cow_str;
 pub use de::{DeserializeDriver, DeserializeProcessor, ReflectDeserializeDriver};
+pub use descriptor::{FieldDescriptor, GenericDescriptor, TypeDescriptor, TypeShape};
+pub use descriptor::{VariantDescriptor, VariantShape};
 pub use ser::{ReflectSerializeDriver, SerializeDriver, SerializeProcessor};
+pub use with::{DeserializeWith, SerializeWith};
+
+pub(crate) use with::SerializeAdapter;
@@ -1,4 +1,5 @@
 use alloc::format;
+use core::any::TypeId;
 
 use serde_core::ser::{self, SerializeMap};
 use serde_core::{Serialize, Serializer};
@@ -19,7 +20,8 @@ crate::cfg::debug! {
 
 use crate::Reflect;
 use crate::ops::ReflectRef;
-use crate::registry::{ReflectSerialize, TypeRegistry};
+use crate::registry::TypeRegistry;
+use crate::registry::{ReflectBox, ReflectFromReflect, ReflectProxy, ReflectSerialize};
 
 // -----------------------------------------------------------------------------
 // SerializeDriver
@@ -34,7 +36,9 @@ use crate::registry::{ReflectSerialize, TypeRegistry};
 ///    If the processor handles the type (successfully or with an error), its result is returned immediately.
 ///
 /// 2. **Trait Fallback**: If no processor is available, looks for [`ReflectSerialize`]
-///    in the type metadata and uses its implementation.
+///    in the type metadata and uses its implementation. A registered [`ReflectProxy`]
+///    takes precedence, the value is then serialized as its proxy type. Proxies restricted
+///    to human-readable or binary formats are checked with [`Serializer::is_human_readable`].
 ///
 /// 3. **Reflection Default**: As a last resort, uses the reflection system's default serialization method.
 ///
@@ -171,6 +175,36 @@ impl<'a, P: SerializeProcessor> Serialize for SerializeDriver<'a, P> {
             serializer
         };
 
+        // Boxed trait objects are written with the type path of the value inside.
+        if let Some(boxed) = self
+            .registry
+            .get_type_trait::<ReflectBox>(self.value.type_id())
+            && let Some(value) = boxed.unbox(self.value)
+        {
+            return TypedSerializer {
+                value,
+                registry: self.registry,
+                processor: self.processor,
+            }
+            .serialize(serializer);
+        }
+
+        // Types with a proxy for this format are written as the proxy value.
+        if let Some(proxy) = self
+            .registry
+            .get_type_trait::<ReflectProxy>(self.value.type_id())
+            && proxy.is_used_for(serializer.is_human_readable())
+        {
+            let Some(value) = proxy.into_proxy(self.value) else {
+                return Err(ser::Error::custom(format!(
+                    "`{}` cannot be converted to its proxy type.",
+                    self.value.reflect_type_path(),
+                )));
+            };
+            return SerializeDriver::new_internal(&*value, self.registry, self.processor)
+                .serialize(serializer);
+        }
+
         // Prefer the type's registered serde serializer when available.
         if let Some(p) = self
             .registry
@@ -359,6 +393,30 @@ impl<P: SerializeProcessor> Serialize for ReflectSerializeDriver<'_, P> {
             TYPE_INFO_STACK.with_borrow_mut(|stack|stack.clear());
         }
 
+        TypedSerializer {
+            value: self.value,
+            registry: self.registry,
+            processor: self.processor,
+        }
+        .serialize(serializer)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// TypedSerializer
+
+/// Serializes a value as a single-entry map of its type path and its data,
+/// see [`ReflectSerializeDriver`].
+///
+/// Used for dynamic pass-through values, e.g. `Box<dyn Reflect>` fields.
+pub(super) struct TypedSerializer<'a, P: SerializeProcessor> {
+    pub value: &'a dyn Reflect,
+    pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a P>,
+}
+
+impl<P: SerializeProcessor> Serialize for TypedSerializer<'_, P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let Some(info) = self.value.represented_type_info() else {
             return Err(ser::Error::custom(format!(
                 "cannot get represented type from type: `{}`.",
@@ -375,3 +433,37 @@ impl<P: SerializeProcessor> Serialize for ReflectSerializeDriver<'_, P> {
         state.end()
     }
 }
+
+// -----------------------------------------------------------------------------
+// WithSerializer
+
+/// Serializes a field through its `serialize_with` function.
+///
+/// Values of other types, e.g. the dynamic fields of a `DynamicStruct`, are
+/// converted to the field type with `ReflectFromReflect` first.
+pub(super) struct WithSerializer<'a> {
+    pub value: &'a dyn Reflect,
+    pub type_id: TypeId,
+    pub with: &'a ReflectSerialize,
+    pub registry: &'a TypeRegistry,
+}
+
+impl Serialize for WithSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.value.type_id() == self.type_id {
+            return self.with.serialize(self.value, serializer);
+        }
+
+        let Some(value) = self
+            .registry
+            .get_type_trait::<ReflectFromReflect>(self.type_id)
+            .and_then(|from_reflect| from_reflect.from_reflect(self.value))
+        else {
+            return Err(ser::Error::custom(format!(
+                "`{}` cannot be converted to the field type of `serialize_with`.",
+                self.value.reflect_type_path(),
+            )));
+        };
+        self.with.serialize(&*value, serializer)
+    }
+}
@@ -3,6 +3,7 @@ use alloc::format;
 use serde_core::ser::{SerializeStructVariant, SerializeTupleVariant};
 use serde_core::{Serialize, Serializer};
 
+use super::driver::WithSerializer;
 use super::error_utils::make_custom_error;
 use super::{SerializeDriver, SerializeProcessor};
 
@@ -74,16 +75,26 @@ impl<P: SerializeProcessor> Serialize for EnumSerializer<'_, P> {
                     serde_len,
                 )?;
 
-                for name in info
-                    .iter()
-                    .filter_map(|f| (!f.skip_serde()).then_some(f.name()))
-                {
+                for field in info.iter().filter(|f| !f.skip_serde()) {
+                    let name = field.name();
                     // If fields match in type and count but a field is missing, panic directly.
                     let value = self.enum_value.field(name).unwrap();
-                    state.serialize_field(
-                        name,
-                        &SerializeDriver::new_internal(value, self.registry, self.processor),
-                    )?;
+                    if let Some(with) = field.serialize_with() {
+                        state.serialize_field(
+                            name,
+                            &WithSerializer {
+                                value,
+                                type_id: field.type_id(),
+                                with,
+                                registry: self.registry,
+                            },
+                        )?;
+                    } else {
+                        state.serialize_field(
+                            name,
+                            &SerializeDriver::new_internal(value, self.registry, self.processor),
+                        )?;
+                    }
                 }
 
                 state.end()
@@ -102,6 +113,18 @@ impl<P: SerializeProcessor> Serialize for EnumSerializer<'_, P> {
                             self.registry,
                             self.processor,
                         ))
+                    } else if let Some(with) = info.field_at(0).unwrap().serialize_with() {
+                        serializer.serialize_newtype_variant(
+                            enum_name,
+                            variant_index,
+                            variant_name,
+                            &WithSerializer {
+                                value,
+                                type_id: info.field_at(0).unwrap().type_id(),
+                                with,
+                                registry: self.registry,
+                            },
+                        )
                     } else {
                         serializer.serialize_newtype_variant(
                             enum_name,
@@ -118,16 +141,22 @@ impl<P: SerializeProcessor> Serialize for EnumSerializer<'_, P> {
                         serde_len,
                     )?;
 
-                    for index in info
-                        .iter()
-                        .filter_map(|f| (!f.skip_serde()).then_some(f.index()))
-                    {
-                        let value = self.enum_value.field_at(index).unwrap();
-                        state.serialize_field(&SerializeDriver::new_internal(
-                            value,
-                            self.registry,
-                            self.processor,
-                        ))?;
+                    for field in info.iter().filter(|f| !f.skip_serde()) {
+                        let value = self.enum_value.field_at(field.index()).unwrap();
+                        if let Some(with) = field.serialize_with() {
+                            state.serialize_field(&WithSerializer {
+                                value,
+                                type_id: field.type_id(),
+                                with,
+                                registry: self.registry,
+                            })?;
+                        } else {
+                            state.serialize_field(&SerializeDriver::new_internal(
+                                value,
+                                self.registry,
+                                self.processor,
+                            ))?;
+                        }
                     }
 
                     state.end()
@@ -26,8 +26,17 @@ use crate::registry::TypeRegistry;
 ///
 /// See serialization rules in [`SerializeDriver`] .
 ///
+/// ## Format Awareness
+///
+/// The serializer is passed unchanged, so a processor can pick a different form for
+/// human-readable and binary formats with `Serializer::is_human_readable`, e.g. a hex
+/// string for RON scenes and raw bytes for network snapshots. For a whole type, a
+/// [`ReflectProxy`] restricted by [`ProxyFormat`] is usually simpler.
+///
 /// [`ReflectSerialize`]: crate::registry::ReflectSerialize
 /// [`SerializeDriver`]: crate::serde::SerializeDriver
+/// [`ReflectProxy`]: crate::registry::ReflectProxy
+/// [`ProxyFormat`]: crate::registry::ProxyFormat
 /// [`&dyn Reflect`]: crate::Reflect
 /// [`&TypeRegistry`]: crate::registry::TypeRegistry
 /// [`serde::Serializer`]: serde_core::Serializer
@@ -1,11 +1,14 @@
 use alloc::format;
+use core::any::TypeId;
 
 use serde_core::ser::SerializeStruct;
 use serde_core::{Serialize, Serializer};
 
+use super::driver::{TypedSerializer, WithSerializer};
 use super::error_utils::make_custom_error;
 use super::{SerializeDriver, SerializeProcessor};
 
+use crate::Reflect;
 use crate::info::TypeInfo;
 use crate::ops::Struct;
 use crate::registry::TypeRegistry;
@@ -47,16 +50,35 @@ impl<P: SerializeProcessor> Serialize for StructSerializer<'_, P> {
 
         let mut state = serializer.serialize_struct(type_ident, serde_len)?;
 
-        for name in struct_info
-            .iter()
-            .filter_map(|f| (!f.skip_serde()).then_some(f.name()))
-        {
+        for field in struct_info.iter().filter(|f| !f.skip_serde()) {
+            let name = field.name();
             // If fields match in type and count but a field is missing, panic directly.
             let value = self.struct_value.field(name).unwrap();
-            state.serialize_field(
-                name,
-                &SerializeDriver::new_internal(value, self.registry, self.processor),
-            )?;
+            if let Some(with) = field.serialize_with() {
+                state.serialize_field(
+                    name,
+                    &WithSerializer {
+                        value,
+                        type_id: field.type_id(),
+                        with,
+                        registry: self.registry,
+                    },
+                )?;
+            } else if field.type_id() == TypeId::of::<dyn Reflect>() {
+                state.serialize_field(
+                    name,
+                    &TypedSerializer {
+                        value,
+                        registry: self.registry,
+                        processor: self.processor,
+                    },
+                )?;
+            } else {
+                state.serialize_field(
+                    name,
+                    &SerializeDriver::new_internal(value, self.registry, self.processor),
+                )?;
+            }
         }
 
         state.end()
@@ -1,11 +1,14 @@
 use alloc::format;
+use core::any::TypeId;
 
 use serde_core::ser::SerializeTupleStruct;
 use serde_core::{Serialize, Serializer};
 
+use super::driver::{TypedSerializer, WithSerializer};
 use super::error_utils::make_custom_error;
 use super::{SerializeDriver, SerializeProcessor};
 
+use crate::Reflect;
 use crate::info::TypeInfo;
 use crate::ops::TupleStruct;
 use crate::registry::TypeRegistry;
@@ -49,23 +52,57 @@ impl<P: SerializeProcessor> Serialize for TupleStructSerializer<'_, P> {
         if field_len == 1 && serde_len == 1 {
             vc_utils::cold_path();
             let value = self.tuple_struct.field(0).unwrap();
-            serializer.serialize_newtype_struct(
-                type_ident,
-                &SerializeDriver::new_internal(value, self.registry, self.processor),
-            )
+            let field = tuple_struct_info.field_at(0).unwrap();
+            if let Some(with) = field.serialize_with() {
+                serializer.serialize_newtype_struct(
+                    type_ident,
+                    &WithSerializer {
+                        value,
+                        type_id: field.type_id(),
+                        with,
+                        registry: self.registry,
+                    },
+                )
+            } else if field.type_id() == TypeId::of::<dyn Reflect>() {
+                serializer.serialize_newtype_struct(
+                    type_ident,
+                    &TypedSerializer {
+                        value,
+                        registry: self.registry,
+                        processor: self.processor,
+                    },
+                )
+            } else {
+                serializer.serialize_newtype_struct(
+                    type_ident,
+                    &SerializeDriver::new_internal(value, self.registry, self.processor),
+                )
+            }
         } else {
             let mut state = serializer.serialize_tuple_struct(type_ident, serde_len)?;
 
-            for index in tuple_struct_info
-                .iter()
-                .filter_map(|f| (!f.skip_serde()).then_some(f.index()))
-            {
-                let value = self.tuple_struct.field(index).unwrap();
-                state.serialize_field(&SerializeDriver::new_internal(
-                    value,
-                    self.registry,
-                    self.processor,
-                ))?;
+            for field in tuple_struct_info.iter().filter(|f| !f.skip_serde()) {
+                let value = self.tuple_struct.field(field.index()).unwrap();
+                if let Some(with) = field.serialize_with() {
+                    state.serialize_field(&WithSerializer {
+                        value,
+                        type_id: field.type_id(),
+                        with,
+                        registry: self.registry,
+                    })?;
+                } else if field.type_id() == TypeId::of::<dyn Reflect>() {
+                    state.serialize_field(&TypedSerializer {
+                        value,
+                        registry: self.registry,
+                        processor: self.processor,
+                    })?;
+                } else {
+                    state.serialize_field(&SerializeDriver::new_internal(
+                        value,
+                        self.registry,
+                        self.processor,
+                    ))?;
+                }
             }
 
             state.end()
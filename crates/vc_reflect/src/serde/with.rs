@@ -0,0 +1,87 @@
+#![expect(
+    unsafe_code,
+    reason = "Cast references to `repr(transparent)` wrappers."
+)]
+
+use core::marker::PhantomData;
+
+use serde_core::{Deserializer, Serialize, Serializer};
+
+use crate::Reflect;
+use crate::info::TypePath;
+
+// -----------------------------------------------------------------------------
+// SerializeWith
+
+/// Serializes a value through a function instead of its `Serialize` implementation.
+///
+/// This is implemented by the derive macro for `#[reflect(serialize_with = "path")]`,
+/// on the reflected type itself. `N` tells the annotated fields apart, it is
+/// `usize::MAX` when the attribute is placed on the type.
+///
+/// The function has the same signature as in `#[serde(serialize_with = "path")]`:
+///
+/// ```ignore
+/// fn path<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error>;
+/// ```
+///
+/// See [`ReflectSerialize::with`] for the type trait built from it.
+///
+/// [`ReflectSerialize::with`]: crate::registry::ReflectSerialize::with
+pub trait SerializeWith<const N: usize> {
+    /// The type of the annotated field, or `Self`.
+    type Value: Reflect + TypePath;
+
+    /// Serializes the value, by calling the function of the attribute.
+    fn serialize<S: Serializer>(value: &Self::Value, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+// -----------------------------------------------------------------------------
+// DeserializeWith
+
+/// Deserializes a value through a function instead of its `Deserialize` implementation.
+///
+/// This is implemented by the derive macro for `#[reflect(deserialize_with = "path")]`,
+/// see [`SerializeWith`] for the meaning of `N`.
+///
+/// The function has the same signature as in `#[serde(deserialize_with = "path")]`:
+///
+/// ```ignore
+/// fn path<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error>;
+/// ```
+///
+/// See [`ReflectDeserialize::with`] for the type trait built from it.
+///
+/// [`ReflectDeserialize::with`]: crate::registry::ReflectDeserialize::with
+pub trait DeserializeWith<const N: usize> {
+    /// The type of the annotated field, or `Self`.
+    type Value: Reflect + TypePath;
+
+    /// Deserializes the value, by calling the function of the attribute.
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self::Value, D::Error>;
+}
+
+// -----------------------------------------------------------------------------
+// SerializeAdapter
+
+/// Implements `Serialize` for `W::Value` through [`SerializeWith`].
+#[repr(transparent)]
+pub(crate) struct SerializeAdapter<W: SerializeWith<N>, const N: usize> {
+    _marker: PhantomData<fn() -> W>,
+    value: W::Value,
+}
+
+impl<W: SerializeWith<N>, const N: usize> SerializeAdapter<W, N> {
+    #[inline(always)]
+    pub(crate) fn new(value: &W::Value) -> &Self {
+        // SAFETY: `Self` is `repr(transparent)` over `W::Value`.
+        unsafe { &*core::ptr::from_ref(value).cast::<Self>() }
+    }
+}
+
+impl<W: SerializeWith<N>, const N: usize> Serialize for SerializeAdapter<W, N> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        W::serialize(&self.value, serializer)
+    }
+}
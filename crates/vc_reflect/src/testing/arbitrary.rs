@@ -0,0 +1,261 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::any::TypeId;
+
+use crate::Reflect;
+use crate::info::{NamedField, TypeInfo, UnnamedField, VariantInfo};
+use crate::ops::{DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet};
+use crate::ops::{DynamicStruct, DynamicTuple, DynamicTupleStruct, DynamicVariant};
+
+/// Containers are empty and enums prefer unit variants past this depth,
+/// so that recursive types terminate.
+const MAX_DEPTH: usize = 8;
+
+/// The largest number of generated items of lists, maps and sets.
+const MAX_LEN: u64 = 4;
+
+/// Generates a random value of the type described by `info`.
+///
+/// Composite types produce dynamic values, such as [`DynamicStruct`], which
+/// represent `info` and can be converted with [`FromReflect`] or applied to
+/// an existing value. Leaf values are concrete.
+///
+/// `rng` returns random numbers: a seeded generator gives reproducible
+/// values, while a fuzzer can return the bytes of its input. Floats are
+/// always finite, so generated values compare equal to themselves.
+///
+/// Returns `None` if the type contains an opaque type other than `bool`,
+/// `char`, integers, floats and `String`.
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::FromReflect;
+/// use vc_reflect::info::Typed;
+/// use vc_reflect::testing::{arbitrary_dynamic, assert_round_trip};
+///
+/// #[derive(vc_reflect::Reflect, Debug)]
+/// enum Shape {
+///     Circle(f32),
+///     Polygon { points: Vec<(i32, i32)> },
+/// }
+///
+/// let mut state = 0x2545_f491_4f6c_dd1d_u64;
+/// let mut rng = || {
+///     state ^= state << 13;
+///     state ^= state >> 7;
+///     state ^= state << 17;
+///     state
+/// };
+///
+/// for _ in 0..16 {
+///     let dynamic = arbitrary_dynamic(Shape::type_info(), &mut rng).unwrap();
+///     let shape = Shape::from_reflect(&*dynamic).unwrap();
+///     assert_round_trip(&shape);
+/// }
+/// ```
+///
+/// [`FromReflect`]: crate::FromReflect
+pub fn arbitrary_dynamic(
+    info: &'static TypeInfo,
+    rng: &mut impl FnMut() -> u64,
+) -> Option<Box<dyn Reflect>> {
+    generate(info, rng, 0)
+}
+
+fn generate(
+    type_info: &'static TypeInfo,
+    rng: &mut impl FnMut() -> u64,
+    depth: usize,
+) -> Option<Box<dyn Reflect>> {
+    let next = depth + 1;
+    let len = |rng: &mut dyn FnMut() -> u64| match depth < MAX_DEPTH {
+        true => rng() % (MAX_LEN + 1),
+        false => 0,
+    };
+
+    match type_info {
+        TypeInfo::Struct(info) => {
+            let mut dynamic = DynamicStruct::with_capacity(info.field_len());
+            for field in info.iter() {
+                dynamic.extend_boxed(field.name(), generate(field.type_info(), rng, next)?);
+            }
+            dynamic.set_type_info(Some(type_info));
+            Some(Box::new(dynamic))
+        }
+        TypeInfo::TupleStruct(info) => {
+            let mut dynamic = DynamicTupleStruct::with_capacity(info.field_len());
+            for field in info.iter() {
+                dynamic.extend_boxed(generate(field.type_info(), rng, next)?);
+            }
+            dynamic.set_type_info(Some(type_info));
+            Some(Box::new(dynamic))
+        }
+        TypeInfo::Tuple(info) => {
+            let mut dynamic = DynamicTuple::with_capacity(info.field_len());
+            for field in info.iter() {
+                dynamic.extend_boxed(generate(field.type_info(), rng, next)?);
+            }
+            dynamic.set_type_info(Some(type_info));
+            Some(Box::new(dynamic))
+        }
+        TypeInfo::List(info) => {
+            let len = len(rng);
+            let mut dynamic = DynamicList::with_capacity(len as usize);
+            for _ in 0..len {
+                dynamic.extend_boxed(generate(info.item_info(), rng, next)?);
+            }
+            dynamic.set_type_info(Some(type_info));
+            Some(Box::new(dynamic))
+        }
+        TypeInfo::Array(info) => {
+            let mut dynamic = DynamicArray::with_capacity(info.len());
+            for _ in 0..info.len() {
+                dynamic.extend_boxed(generate(info.item_info(), rng, next)?);
+            }
+            dynamic.set_type_info(Some(type_info));
+            Some(Box::new(dynamic))
+        }
+        TypeInfo::Map(info) => {
+            let len = len(rng);
+            let mut dynamic = DynamicMap::with_capacity(len as usize);
+            for _ in 0..len {
+                let key = generate(info.key_info(), rng, next)?;
+                let value = generate(info.value_info(), rng, next)?;
+                dynamic.extend_boxed(key, value);
+            }
+            dynamic.set_type_info(Some(type_info));
+            Some(Box::new(dynamic))
+        }
+        TypeInfo::Set(info) => {
+            let len = len(rng);
+            let mut dynamic = DynamicSet::with_capacity(len as usize);
+            for _ in 0..len {
+                dynamic.extend_boxed(generate(info.value_info(), rng, next)?);
+            }
+            dynamic.set_type_info(Some(type_info));
+            Some(Box::new(dynamic))
+        }
+        TypeInfo::Enum(info) => {
+            let index = if depth < MAX_DEPTH {
+                (rng() % info.variant_len() as u64) as usize
+            } else {
+                info.iter()
+                    .position(|variant| matches!(variant, VariantInfo::Unit(_)))
+                    .unwrap_or(0)
+            };
+            let variant = info.variant_at(index)?;
+            let data = match variant {
+                VariantInfo::Unit(_) => DynamicVariant::Unit,
+                VariantInfo::Tuple(variant) => {
+                    let mut tuple = DynamicTuple::with_capacity(variant.field_len());
+                    for field in variant.iter().map(UnnamedField::type_info) {
+                        tuple.extend_boxed(generate(field, rng, next)?);
+                    }
+                    DynamicVariant::Tuple(tuple)
+                }
+                VariantInfo::Struct(variant) => {
+                    let mut data = DynamicStruct::with_capacity(variant.field_len());
+                    for field in variant.iter() {
+                        let value = generate(NamedField::type_info(field), rng, next)?;
+                        data.extend_boxed(field.name(), value);
+                    }
+                    DynamicVariant::Struct(data)
+                }
+            };
+            let mut dynamic = DynamicEnum::new(index, variant.name(), data);
+            dynamic.set_type_info(Some(type_info));
+            Some(Box::new(dynamic))
+        }
+        TypeInfo::Opaque(info) => opaque(info.type_id(), rng),
+    }
+}
+
+fn opaque(type_id: TypeId, rng: &mut impl FnMut() -> u64) -> Option<Box<dyn Reflect>> {
+    macro_rules! integers {
+        ($($ty:ty),*) => {$(
+            if type_id == TypeId::of::<$ty>() {
+                return Some(Box::new(rng() as $ty));
+            }
+        )*};
+    }
+
+    integers!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+    if type_id == TypeId::of::<u128>() {
+        return Some(Box::new((u128::from(rng()) << 64) | u128::from(rng())));
+    }
+    if type_id == TypeId::of::<i128>() {
+        return Some(Box::new(((u128::from(rng()) << 64) | u128::from(rng())) as i128));
+    }
+    if type_id == TypeId::of::<bool>() {
+        return Some(Box::new(rng() & 1 == 1));
+    }
+    // Exactly representable, so text formats print and parse the same value.
+    if type_id == TypeId::of::<f32>() {
+        return Some(Box::new((rng() as i32 >> 8) as f32 / 256.0));
+    }
+    if type_id == TypeId::of::<f64>() {
+        return Some(Box::new((rng() as i64 >> 11) as f64 / 1024.0));
+    }
+    if type_id == TypeId::of::<char>() {
+        let code = (rng() % 0x11_0000) as u32;
+        return Some(Box::new(char::from_u32(code).unwrap_or('\u{fffd}')));
+    }
+    if type_id == TypeId::of::<String>() {
+        const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 _";
+        let len = rng() % (MAX_LEN * 2 + 1);
+        let string: String = (0..len)
+            .map(|_| CHARS[(rng() % CHARS.len() as u64) as usize] as char)
+            .collect();
+        return Some(Box::new(string));
+    }
+
+    None
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{MAX_DEPTH, arbitrary_dynamic};
+    use crate::info::Typed;
+    use crate::{FromReflect, Reflect};
+
+    #[derive(Reflect, Debug)]
+    enum Tree {
+        Leaf,
+        Node(Vec<Tree>),
+    }
+
+    impl Tree {
+        fn depth(&self) -> usize {
+            match self {
+                Self::Leaf => 0,
+                Self::Node(children) => 1 + children.iter().map(Self::depth).max().unwrap_or(0),
+            }
+        }
+    }
+
+    #[derive(Reflect, Debug)]
+    struct Label {
+        text: &'static str,
+    }
+
+    #[test]
+    fn recursion_terminates() {
+        // Always picks `Node` with one child, until the depth limit.
+        let dynamic = arbitrary_dynamic(Tree::type_info(), &mut || 1).unwrap();
+        let tree = Tree::from_reflect(&*dynamic).unwrap();
+        assert!(tree.depth() > 0);
+        assert!(tree.depth() <= MAX_DEPTH);
+    }
+
+    #[test]
+    fn unsupported_opaque() {
+        assert!(arbitrary_dynamic(Label::type_info(), &mut || 0).is_none());
+    }
+}
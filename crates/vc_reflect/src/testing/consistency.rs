@@ -0,0 +1,264 @@
+use alloc::vec::Vec;
+use core::{error, fmt};
+
+use crate::info::{NamedField, TypeInfo, UnnamedField, VariantInfo};
+use crate::registry::{ReflectDefault, ReflectFromReflect, TypeMeta, TypeRegistry};
+
+// -----------------------------------------------------------------------------
+// RegistryIssueKind
+
+/// The kind of inconsistency found by [`check_registry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryIssueKind {
+    /// Looking up the type path returns another type, e.g. a manual
+    /// `TypePath` implementation reusing the path of another type.
+    TypePathConflict {
+        /// The type path of the type returned by the lookup.
+        other: &'static str,
+    },
+    /// A field, item, key or value type is not registered, so the type
+    /// cannot be deserialized.
+    MissingDependency {
+        /// The type path of the missing type.
+        dependency: &'static str,
+    },
+    /// [`ReflectDefault`] creates a value of another type.
+    DefaultMismatch {
+        /// The type path of the created value, or `<unknown>` for dynamic
+        /// values without type info.
+        actual: &'static str,
+    },
+    /// [`ReflectFromReflect`] fails to convert the dynamic form of the default value.
+    FromReflectFailed,
+    /// [`ReflectFromReflect`] converts the dynamic form of the default value
+    /// into a value which is not equal to it.
+    FromReflectMismatch,
+}
+
+impl fmt::Display for RegistryIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TypePathConflict { other } => {
+                write!(f, "the type path is registered for `{other}`")
+            }
+            Self::MissingDependency { dependency } => {
+                write!(f, "the dependency `{dependency}` is not registered")
+            }
+            Self::DefaultMismatch { actual } => {
+                write!(f, "`ReflectDefault` creates a value of type `{actual}`")
+            }
+            Self::FromReflectFailed => {
+                f.write_str("`ReflectFromReflect` cannot convert the dynamic default value")
+            }
+            Self::FromReflectMismatch => {
+                f.write_str("`ReflectFromReflect` changes the dynamic default value")
+            }
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// RegistryIssue
+
+/// A single inconsistency of a registered type, found by [`check_registry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistryIssue {
+    /// The type path of the inconsistent type.
+    pub type_path: &'static str,
+    /// What is inconsistent.
+    pub kind: RegistryIssueKind,
+}
+
+impl fmt::Display for RegistryIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`: {}", self.type_path, self.kind)
+    }
+}
+
+impl error::Error for RegistryIssue {}
+
+// -----------------------------------------------------------------------------
+// check_registry
+
+/// Checks that the registered types are consistent with each other, and
+/// returns every issue found, in no particular order.
+///
+/// For each registered type:
+///
+/// - Its type path must resolve to itself.
+/// - The types of its fields, items, keys, values and variant fields must
+///   be registered.
+/// - If [`ReflectDefault`] is registered, the default value must be of this type.
+///   If [`ReflectFromReflect`] is also registered, it must convert the
+///   dynamic form of the default value into an equal value, when
+///   [`reflect_eq`](crate::Reflect::reflect_eq) supports them.
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::prelude::*;
+/// use vc_reflect::testing::{RegistryIssueKind, check_registry};
+///
+/// #[derive(Reflect, Default)]
+/// #[reflect(default)]
+/// struct Item {
+///     tags: Vec<String>,
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Item>();
+/// assert!(check_registry(&registry).is_empty());
+///
+/// let mut registry = TypeRegistry::empty();
+/// registry.insert_type_meta(TypeMeta::of::<Item>());
+/// let issues = check_registry(&registry);
+/// assert_eq!(
+///     issues[0].kind,
+///     RegistryIssueKind::MissingDependency { dependency: "alloc::vec::Vec<String>" },
+/// );
+/// ```
+pub fn check_registry(registry: &TypeRegistry) -> Vec<RegistryIssue> {
+    let mut issues = Vec::new();
+    for meta in registry.iter() {
+        check_type(registry, meta, &mut issues);
+    }
+    issues
+}
+
+fn check_type(registry: &TypeRegistry, meta: &TypeMeta, issues: &mut Vec<RegistryIssue>) {
+    let info = meta.type_info();
+    let type_path = info.type_path();
+    let mut push = |kind| issues.push(RegistryIssue { type_path, kind });
+
+    if let Some(other) = registry.get_with_type_path(type_path)
+        && other.type_info().type_id() != info.type_id()
+    {
+        push(RegistryIssueKind::TypePathConflict {
+            other: other.type_info().type_path(),
+        });
+    }
+
+    let mut check_dependency = |dependency: &'static TypeInfo| {
+        if !registry.contains(dependency.type_id()) {
+            push(RegistryIssueKind::MissingDependency {
+                dependency: dependency.type_path(),
+            });
+        }
+    };
+    match info {
+        TypeInfo::Struct(info) => info.iter().map(NamedField::type_info).for_each(check_dependency),
+        TypeInfo::TupleStruct(info) => {
+            info.iter().map(UnnamedField::type_info).for_each(check_dependency);
+        }
+        TypeInfo::Tuple(info) => info.iter().map(UnnamedField::type_info).for_each(check_dependency),
+        TypeInfo::List(info) => check_dependency(info.item_info()),
+        TypeInfo::Array(info) => check_dependency(info.item_info()),
+        TypeInfo::Map(info) => {
+            check_dependency(info.key_info());
+            check_dependency(info.value_info());
+        }
+        TypeInfo::Set(info) => check_dependency(info.value_info()),
+        TypeInfo::Enum(info) => {
+            for variant in info.iter() {
+                match variant {
+                    VariantInfo::Struct(info) => {
+                        info.iter().map(NamedField::type_info).for_each(&mut check_dependency);
+                    }
+                    VariantInfo::Tuple(info) => {
+                        info.iter().map(UnnamedField::type_info).for_each(&mut check_dependency);
+                    }
+                    VariantInfo::Unit(_) => {}
+                }
+            }
+        }
+        TypeInfo::Opaque(_) => {}
+    }
+
+    let Some(default) = meta.get_trait::<ReflectDefault>() else {
+        return;
+    };
+    let value = default.default();
+    let actual = value.represented_type_info();
+    if actual.is_none_or(|actual| actual.type_id() != info.type_id()) {
+        let actual = actual.map_or("<unknown>", TypeInfo::type_path);
+        push(RegistryIssueKind::DefaultMismatch { actual });
+        return;
+    }
+
+    let Some(from_reflect) = meta.get_trait::<ReflectFromReflect>() else {
+        return;
+    };
+    match from_reflect.from_reflect(&*value.to_dynamic()) {
+        None => push(RegistryIssueKind::FromReflectFailed),
+        Some(output) => {
+            if output.reflect_eq(&*value) == Some(false) {
+                push(RegistryIssueKind::FromReflectMismatch);
+            }
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use super::{RegistryIssue, RegistryIssueKind, check_registry};
+    use crate::Reflect;
+    use crate::info::TypePath;
+    use crate::registry::{FromType, ReflectDefault, TypeMeta, TypeRegistry};
+
+    #[derive(Reflect, Default)]
+    #[reflect(default)]
+    struct Item {
+        tags: Vec<String>,
+    }
+
+    #[derive(Reflect)]
+    enum Slot {
+        Empty,
+        Full(Item),
+    }
+
+    #[test]
+    fn consistent() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Item>().register::<Slot>();
+        assert_eq!(check_registry(&registry), Vec::new());
+    }
+
+    #[test]
+    fn missing_variant_dependency() {
+        let mut registry = TypeRegistry::empty();
+        registry.insert_type_meta(TypeMeta::of::<Slot>());
+        assert_eq!(
+            check_registry(&registry),
+            [RegistryIssue {
+                type_path: Slot::type_path(),
+                kind: RegistryIssueKind::MissingDependency {
+                    dependency: Item::type_path(),
+                },
+            }],
+        );
+    }
+
+    #[test]
+    fn default_mismatch() {
+        let mut meta = TypeMeta::of::<Item>();
+        meta.insert_trait::<ReflectDefault>(FromType::<u32>::from_type());
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Vec<String>>();
+        registry.insert_type_meta(meta);
+        assert_eq!(
+            check_registry(&registry),
+            [RegistryIssue {
+                type_path: Item::type_path(),
+                kind: RegistryIssueKind::DefaultMismatch { actual: "u32" },
+            }],
+        );
+    }
+}
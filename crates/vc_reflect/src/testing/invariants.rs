@@ -0,0 +1,172 @@
+use alloc::vec::Vec;
+use core::any::TypeId;
+
+use crate::FromReflect;
+use crate::info::Typed;
+use crate::registry::{GetTypeMeta, ReflectDefault, TypeRegistry};
+use crate::testing::{arbitrary_dynamic, assert_round_trip_with};
+
+/// The number of generated values checked besides the default value.
+const ARBITRARY_VALUES: usize = 16;
+
+/// Asserts that the reflection implementation of `T` is consistent, using its
+/// default value and a fixed set of generated values.
+///
+/// This is the function called by the tests of `#[reflect(derive_tests)]`.
+///
+/// The default value is used if [`ReflectDefault`] is registered, e.g. with
+/// `#[reflect(default)]`. Values are generated by [`arbitrary_dynamic`] with a
+/// fixed seed, if every field type is supported. For each value `x`:
+///
+/// - `T::from_reflect(x.reflect_clone())` must succeed and be equal to `x`,
+///   according to [`Reflect::reflect_eq`].
+/// - If `x.reflect_hash()` returns `Some`, the clone must have the same hash.
+/// - `x` must survive [`assert_round_trip_with`].
+///
+/// The flags `eq` and `hash` replace the field-by-field implementations with
+/// the ones of the type, so these checks catch flags that do not match the
+/// actual `PartialEq` or `Hash` implementations.
+///
+/// # Panics
+///
+/// Panics if a check fails, or if no value can be created at all.
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::Reflect;
+/// use vc_reflect::testing::assert_reflect_invariants;
+///
+/// #[derive(Reflect, Default, Debug, Clone, PartialEq, Hash)]
+/// #[reflect(default, clone, eq, hash)]
+/// struct Tag {
+///     name: String,
+///     layer: u8,
+/// }
+///
+/// assert_reflect_invariants::<Tag>();
+/// ```
+///
+/// [`Reflect::reflect_eq`]: crate::Reflect::reflect_eq
+#[track_caller]
+pub fn assert_reflect_invariants<T: FromReflect + Typed + GetTypeMeta>() {
+    let type_path = T::type_path();
+
+    let mut registry = TypeRegistry::new();
+    registry.register_recursive::<T>();
+
+    let mut values: Vec<T> = Vec::with_capacity(ARBITRARY_VALUES + 1);
+    if let Some(default) = registry.get_type_trait::<ReflectDefault>(TypeId::of::<T>()) {
+        match T::from_reflect(&*default.default()) {
+            Some(value) => values.push(value),
+            None => panic!("`{type_path}` cannot be created from its default value"),
+        }
+    }
+
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut rng = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    for _ in 0..ARBITRARY_VALUES {
+        let Some(dynamic) = arbitrary_dynamic(T::type_info(), &mut rng) else {
+            break;
+        };
+        match T::from_reflect(&*dynamic) {
+            Some(value) => values.push(value),
+            None => panic!("`{type_path}` cannot be created from {dynamic:?}"),
+        }
+    }
+
+    if values.is_empty() {
+        panic!(
+            "no value of `{type_path}` to check, register `ReflectDefault` \
+            or use field types supported by `arbitrary_dynamic`"
+        );
+    }
+
+    for value in &values {
+        check_clone(value);
+        assert_round_trip_with(value, &registry);
+    }
+}
+
+#[track_caller]
+fn check_clone<T: FromReflect + Typed>(value: &T) {
+    let type_path = T::type_path();
+
+    let cloned = match value.reflect_clone() {
+        Ok(cloned) => cloned,
+        Err(err) => panic!("failed to clone `{type_path}`: {err}"),
+    };
+    let Some(output) = T::from_reflect(&*cloned) else {
+        panic!("`{type_path}` cannot be created from its clone: {cloned:?}");
+    };
+
+    match value.reflect_eq(&output) {
+        Some(true) => {}
+        Some(false) => panic!(
+            "`{type_path}` is not equal to its clone\n  input: {:?}\n output: {:?}",
+            value.as_reflect(),
+            output.as_reflect(),
+        ),
+        None => panic!("`{type_path}` cannot be compared, `reflect_eq` returned `None`"),
+    }
+
+    if let Some(hash) = value.reflect_hash() {
+        let other = output.reflect_hash();
+        if other != Some(hash) {
+            panic!(
+                "`{type_path}` and its equal clone have different hashes: {hash} and {other:?}\n  value: {:?}",
+                value.as_reflect(),
+            );
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::hash::{Hash, Hasher};
+
+    use crate::Reflect;
+
+    #[derive(Reflect, Default, Debug, Clone, PartialEq, Hash)]
+    #[reflect(default, clone, eq, hash, derive_tests)]
+    struct Item {
+        name: String,
+        counts: Vec<u16>,
+        kind: Kind,
+    }
+
+    #[derive(Reflect, Default, Debug, Clone, PartialEq, Hash)]
+    #[reflect(derive_tests)]
+    enum Kind {
+        #[default]
+        Empty,
+        Weight(u32),
+    }
+
+    /// The `hash` flag lies, equal values have different hashes.
+    #[derive(Reflect, Default, Debug, Clone, PartialEq)]
+    #[reflect(default, clone, eq, hash)]
+    struct Lying(u8);
+
+    impl Hash for Lying {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            (self as *const Self as usize).hash(state);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "different hashes")]
+    fn lying_hash() {
+        super::assert_reflect_invariants::<Lying>();
+    }
+}
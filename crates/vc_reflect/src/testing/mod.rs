@@ -0,0 +1,38 @@
+//! Provides helpers for testing reflection implementations, enabled by the
+//! `testing` feature.
+//!
+//! Manual implementations, such as `impl_reflect!` blocks or hand-written
+//! [`GetTypeMeta`], are easy to get subtly wrong. These helpers check them
+//! the same way in every downstream crate.
+//!
+//! ## Menu
+//!
+//! - [`assert_round_trip`]: Serializes a value through reflection, deserializes
+//!   it back and asserts the result is equal, typed and untyped.
+//!     - [`assert_round_trip_with`]: The same, with a given registry.
+//! - [`assert_reflect_invariants`]: Checks clones, equality, hashing and round
+//!   trips of default and generated values, used by `#[reflect(derive_tests)]`.
+//! - [`arbitrary_dynamic`]: Generates a random dynamic value from a [`TypeInfo`],
+//!   usable with a seeded generator or as a fuzzing entry point.
+//! - [`check_registry`]: Reports inconsistencies of the registered types,
+//!   such as missing dependencies or a [`ReflectDefault`] returning another type.
+//!
+//! [`GetTypeMeta`]: crate::registry::GetTypeMeta
+//! [`TypeInfo`]: crate::info::TypeInfo
+//! [`ReflectDefault`]: crate::registry::ReflectDefault
+
+// -----------------------------------------------------------------------------
+// Modules
+
+mod arbitrary;
+mod consistency;
+mod invariants;
+mod round_trip;
+
+// -----------------------------------------------------------------------------
+// Exports
+
+pub use arbitrary::arbitrary_dynamic;
+pub use consistency::{RegistryIssue, RegistryIssueKind, check_registry};
+pub use invariants::assert_reflect_invariants;
+pub use round_trip::{assert_round_trip, assert_round_trip_with};
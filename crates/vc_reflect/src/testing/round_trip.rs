@@ -0,0 +1,160 @@
+use core::any::TypeId;
+
+use serde_core::de::DeserializeSeed;
+
+use crate::{FromReflect, Reflect};
+use crate::info::Typed;
+use crate::registry::{GetTypeMeta, TypeRegistry};
+use crate::serde::{DeserializeDriver, ReflectDeserializeDriver};
+use crate::serde::{ReflectSerializeDriver, SerializeDriver};
+
+/// Asserts that a value survives a serialization round trip through reflection.
+///
+/// `T` and the types it refers to are registered in a new [`TypeRegistry`]
+/// with [`register_recursive`], then [`assert_round_trip_with`] is called.
+///
+/// # Panics
+///
+/// See [`assert_round_trip_with`].
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::Reflect;
+/// use vc_reflect::testing::assert_round_trip;
+///
+/// #[derive(Reflect, Debug)]
+/// struct Player {
+///     name: String,
+///     scores: Vec<u32>,
+/// }
+///
+/// assert_round_trip(&Player {
+///     name: "Alice".into(),
+///     scores: vec![7, 9],
+/// });
+/// ```
+///
+/// [`register_recursive`]: TypeRegistry::register_recursive
+#[track_caller]
+pub fn assert_round_trip<T: FromReflect + Typed + GetTypeMeta>(value: &T) {
+    let mut registry = TypeRegistry::new();
+    registry.register_recursive::<T>();
+    assert_round_trip_with(value, &registry);
+}
+
+/// Asserts that a value survives a serialization round trip through reflection,
+/// using the given registry.
+///
+/// The value is serialized to JSON twice, with [`SerializeDriver`] and with
+/// [`ReflectSerializeDriver`], and deserialized back with the matching
+/// [`DeserializeDriver`] or [`ReflectDeserializeDriver`]. Each output is
+/// converted to `T` with [`FromReflect`] and compared to the input with
+/// [`Reflect::reflect_eq`].
+///
+/// # Panics
+///
+/// Panics with the JSON text if a step fails, or if the values are not equal.
+/// The comparison must be supported: `reflect_eq` must not return `None`.
+///
+/// [`Reflect::reflect_eq`]: crate::Reflect::reflect_eq
+#[track_caller]
+pub fn assert_round_trip_with<T: FromReflect + Typed>(value: &T, registry: &TypeRegistry) {
+    let type_path = T::type_path();
+    let Some(meta) = registry.get(TypeId::of::<T>()) else {
+        panic!("`{type_path}` is not registered");
+    };
+
+    let json = match serde_json::to_string(&SerializeDriver::new(value, registry)) {
+        Ok(json) => json,
+        Err(err) => panic!("failed to serialize `{type_path}`: {err}"),
+    };
+    let mut de = serde_json::Deserializer::from_str(&json);
+    let output = match DeserializeDriver::new(meta, registry).deserialize(&mut de) {
+        Ok(output) => output,
+        Err(err) => panic!("failed to deserialize `{type_path}` from {json}: {err}"),
+    };
+    check_output(value, &*output, &json);
+
+    let json = match serde_json::to_string(&ReflectSerializeDriver::new(value, registry)) {
+        Ok(json) => json,
+        Err(err) => panic!("failed to serialize `{type_path}` with its type path: {err}"),
+    };
+    let mut de = serde_json::Deserializer::from_str(&json);
+    let output = match ReflectDeserializeDriver::new(registry).deserialize(&mut de) {
+        Ok(output) => output,
+        Err(err) => panic!("failed to deserialize `{type_path}` from {json}: {err}"),
+    };
+    check_output(value, &*output, &json);
+}
+
+#[track_caller]
+fn check_output<T: FromReflect + Typed>(value: &T, output: &dyn Reflect, json: &str) {
+    let type_path = T::type_path();
+
+    let Some(output) = T::from_reflect(output) else {
+        panic!("`{type_path}` cannot be created from the output of {json}: {output:?}");
+    };
+    match value.reflect_eq(&output) {
+        Some(true) => {}
+        Some(false) => panic!(
+            "`{type_path}` changed after a round trip through {json}\n  input: {:?}\n output: {:?}",
+            value.as_reflect(),
+            output.as_reflect(),
+        ),
+        None => panic!("`{type_path}` cannot be compared, `reflect_eq` returned `None`"),
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::{assert_round_trip, assert_round_trip_with};
+    use crate::Reflect;
+    use crate::registry::TypeRegistry;
+
+    #[derive(Reflect, Debug)]
+    struct Player {
+        name: String,
+        scores: Vec<u32>,
+        rank: Option<u8>,
+    }
+
+    /// The `eq` flag lies, no value is equal to another.
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(clone, eq)]
+    struct Never(u8);
+
+    impl PartialEq for Never {
+        fn eq(&self, _: &Self) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn player() {
+        assert_round_trip(&Player {
+            name: String::from("Alice"),
+            scores: vec![7, 9],
+            rank: Some(1),
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "is not registered")]
+    fn unregistered() {
+        assert_round_trip_with(&7_u32, &TypeRegistry::empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "changed after a round trip")]
+    fn never_equal() {
+        assert_round_trip(&Never(1));
+    }
+}
@@ -0,0 +1,132 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use super::ValidationErrorKind;
+use crate::Reflect;
+use crate::ops::ReflectRef;
+
+// -----------------------------------------------------------------------------
+// Range
+
+/// Requires a numeric value to lie in the inclusive range `[min, max]`.
+///
+/// Supports all primitive integer and float types.
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::Reflect;
+/// use vc_reflect::validate::Range;
+///
+/// #[derive(Reflect)]
+/// struct Volume {
+///     #[reflect(@Range::new(0.0, 1.0))]
+///     level: f32,
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(type_path = "vc_reflect::validate::Range", clone, eq)]
+pub struct Range {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Range {
+    /// Creates a new inclusive range.
+    #[inline]
+    pub const fn new(min: f64, max: f64) -> Self {
+        Self { min, max }
+    }
+
+    /// Checks the given value against this range.
+    pub fn check(&self, value: &dyn Reflect) -> Result<(), ValidationErrorKind> {
+        let Some(number) = as_f64(value) else {
+            return Err(ValidationErrorKind::Unsupported {
+                attribute: "Range",
+                type_path: Cow::Borrowed(value.reflect_type_path()),
+            });
+        };
+
+        if number >= self.min && number <= self.max {
+            Ok(())
+        } else {
+            Err(ValidationErrorKind::OutOfRange {
+                value: number,
+                min: self.min,
+                max: self.max,
+            })
+        }
+    }
+}
+
+/// Converts a reflected primitive number to `f64`.
+fn as_f64(value: &dyn Reflect) -> Option<f64> {
+    macro_rules! try_cast {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(v) = value.downcast_ref::<$ty>() {
+                    return Some(*v as f64);
+                }
+            )*
+        };
+    }
+
+    try_cast!(
+        f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+    );
+    None
+}
+
+// -----------------------------------------------------------------------------
+// NonEmpty
+
+/// Requires a string or container to contain at least one element.
+///
+/// Supports `String`, `&'static str`, `Cow<'static, str>`
+/// and all lists, arrays, maps and sets.
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::Reflect;
+/// use vc_reflect::validate::NonEmpty;
+///
+/// #[derive(Reflect)]
+/// struct Player {
+///     #[reflect(@NonEmpty)]
+///     name: String,
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[reflect(type_path = "vc_reflect::validate::NonEmpty", clone, eq, hash, default)]
+pub struct NonEmpty;
+
+impl NonEmpty {
+    /// Checks the given value is not empty.
+    pub fn check(&self, value: &dyn Reflect) -> Result<(), ValidationErrorKind> {
+        let is_empty = if let Some(s) = value.downcast_ref::<String>() {
+            Some(s.is_empty())
+        } else if let Some(s) = value.downcast_ref::<&'static str>() {
+            Some(s.is_empty())
+        } else if let Some(s) = value.downcast_ref::<Cow<'static, str>>() {
+            Some(s.is_empty())
+        } else {
+            match value.reflect_ref() {
+                ReflectRef::List(list) => Some(list.is_empty()),
+                ReflectRef::Array(array) => Some(array.len() == 0),
+                ReflectRef::Map(map) => Some(map.is_empty()),
+                ReflectRef::Set(set) => Some(set.is_empty()),
+                _ => None,
+            }
+        };
+
+        match is_empty {
+            Some(false) => Ok(()),
+            Some(true) => Err(ValidationErrorKind::Empty),
+            None => Err(ValidationErrorKind::Unsupported {
+                attribute: "NonEmpty",
+                type_path: Cow::Borrowed(value.reflect_type_path()),
+            }),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::{error, fmt};
+
+// -----------------------------------------------------------------------------
+// ValidationErrorKind
+
+/// The reason why a value failed validation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationErrorKind {
+    /// A numeric value is outside of the inclusive [`Range`](crate::validate::Range).
+    OutOfRange { value: f64, min: f64, max: f64 },
+    /// A string or container is empty, but [`NonEmpty`](crate::validate::NonEmpty) is required.
+    Empty,
+    /// A validation attribute was attached to a value it cannot check,
+    /// e.g. a `Range` on a `String`.
+    Unsupported {
+        attribute: &'static str,
+        type_path: Cow<'static, str>,
+    },
+    /// A custom failure reported by a [`Validate`](crate::validate::Validate) implementation.
+    Custom(Cow<'static, str>),
+}
+
+impl fmt::Display for ValidationErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange { value, min, max } => {
+                write!(f, "value {value} is out of range [{min}, {max}]")
+            }
+            Self::Empty => f.write_str("value must not be empty"),
+            Self::Unsupported {
+                attribute,
+                type_path,
+            } => {
+                write!(f, "attribute `{attribute}` cannot validate `{type_path}`")
+            }
+            Self::Custom(msg) => f.write_str(msg),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// ValidationError
+
+/// A single validation failure, located by a reflection path such as `.items[0].name`.
+///
+/// An empty path refers to the root value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    pub path: String,
+    pub kind: ValidationErrorKind,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "<root>: {}", self.kind)
+        } else {
+            write!(f, "{}: {}", self.path, self.kind)
+        }
+    }
+}
+
+impl error::Error for ValidationError {}
+
+// -----------------------------------------------------------------------------
+// ValidationErrors
+
+/// All failures collected by a single [`validate`](crate::validate::validate) call.
+///
+/// Validation does not stop at the first failure, so that tools can
+/// report every invalid field at once.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationErrors {
+    errors: Vec<ValidationError>,
+}
+
+impl ValidationErrors {
+    /// Creates an empty error list.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { errors: Vec::new() }
+    }
+
+    /// Appends an error.
+    #[inline]
+    pub fn push(&mut self, error: ValidationError) {
+        self.errors.push(error);
+    }
+
+    /// Returns the number of errors.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns `true` if there is no error.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns an iterator over the errors.
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, ValidationError> {
+        self.errors.iter()
+    }
+
+    /// Returns the errors as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    /// Converts into the inner error list.
+    #[inline]
+    pub fn into_vec(self) -> Vec<ValidationError> {
+        self.errors
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut iter = self.errors.iter();
+        if let Some(first) = iter.next() {
+            fmt::Display::fmt(first, f)?;
+        }
+        for error in iter {
+            f.write_str("; ")?;
+            fmt::Display::fmt(error, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for ValidationErrors {}
+
+impl<'a> IntoIterator for &'a ValidationErrors {
+    type Item = &'a ValidationError;
+    type IntoIter = core::slice::Iter<'a, ValidationError>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+impl IntoIterator for ValidationErrors {
+    type Item = ValidationError;
+    type IntoIter = alloc::vec::IntoIter<ValidationError>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
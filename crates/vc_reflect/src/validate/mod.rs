@@ -0,0 +1,32 @@
+//! Provides value validation driven by custom attributes and type traits.
+//!
+//! ## Menu
+//!
+//! - [`validate`]: Recursively validates a reflected value, collecting all failures.
+//! - Standard attributes, attached with `#[reflect(@...)]`:
+//!     - [`Range`]: A numeric value must lie in the inclusive range `[min, max]`.
+//!     - [`NonEmpty`]: A string or container must not be empty.
+//! - [`Validate`]: A trait for type-specific checks, exposed to the registry by
+//!   [`ReflectValidate`].
+//! - [`ValidationErrors`]: A list of [`ValidationError`], each with a path and a
+//!   [`ValidationErrorKind`].
+//!
+//! The deserialization drivers can run [`validate`] on their output,
+//! see [`ReflectDeserializeDriver::with_validation`].
+//!
+//! [`ReflectValidate`]: crate::registry::ReflectValidate
+//! [`ReflectDeserializeDriver::with_validation`]: crate::serde::ReflectDeserializeDriver::with_validation
+
+// -----------------------------------------------------------------------------
+// Modules
+
+mod attributes;
+mod error;
+mod validator;
+
+// -----------------------------------------------------------------------------
+// Exports
+
+pub use attributes::{NonEmpty, Range};
+pub use error::{ValidationError, ValidationErrorKind, ValidationErrors};
+pub use validator::{Validate, validate};
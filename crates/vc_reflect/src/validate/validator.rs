@@ -0,0 +1,368 @@
+use alloc::string::String;
+use core::fmt::Write;
+
+use super::{NonEmpty, Range, ValidationError, ValidationErrorKind, ValidationErrors};
+use crate::Reflect;
+use crate::info::{CustomAttributes, NamedField, UnnamedField};
+use crate::ops::{ReflectRef, VariantField, VariantFieldInfo};
+use crate::registry::{ReflectValidate, TypeRegistry};
+
+// -----------------------------------------------------------------------------
+// Validate
+
+/// A trait for type-specific validation logic.
+///
+/// Register [`ReflectValidate`] for the type (for example through
+/// `#[reflect(type_trait = ReflectValidate)]`) so that [`validate`] can
+/// invoke it on type-erased values.
+///
+/// See [`ReflectValidate`] for examples.
+pub trait Validate {
+    /// Checks the value, returning the reason on failure.
+    fn validate(&self) -> Result<(), ValidationErrorKind>;
+}
+
+// -----------------------------------------------------------------------------
+// validate
+
+/// Recursively validates a reflected value.
+///
+/// For every visited value, the following checks are performed:
+///
+/// 1. Standard attributes ([`Range`], [`NonEmpty`]) attached to the field or variant
+///    that contains the value.
+/// 2. Standard attributes attached to the value's type.
+/// 3. The [`ReflectValidate`] type trait registered for the value's type, if any.
+///
+/// Unknown attributes are ignored. All failures are collected and located with
+/// a path in the syntax of [`access`](crate::access), e.g. `.items[0].name`.
+///
+/// Dynamic values are validated through the type they represent, but
+/// [`ReflectValidate`] can only check concrete values.
+///
+/// # Examples
+///
+/// ```
+/// use vc_reflect::prelude::*;
+/// use vc_reflect::validate::{validate, NonEmpty, Range};
+///
+/// #[derive(Reflect)]
+/// struct Player {
+///     #[reflect(@NonEmpty)]
+///     name: String,
+///     #[reflect(@Range::new(1.0, 100.0))]
+///     level: u32,
+/// }
+///
+/// let registry = TypeRegistry::new();
+///
+/// let player = Player { name: "Alex".into(), level: 10 };
+/// assert!(validate(&player, &registry).is_ok());
+///
+/// let player = Player { name: String::new(), level: 0 };
+/// let errors = validate(&player, &registry).unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(errors.as_slice()[0].path, ".name");
+/// assert_eq!(errors.as_slice()[1].path, ".level");
+/// ```
+pub fn validate(value: &dyn Reflect, registry: &TypeRegistry) -> Result<(), ValidationErrors> {
+    let mut validator = Validator {
+        registry,
+        path: String::new(),
+        errors: ValidationErrors::new(),
+    };
+
+    validator.visit(value, None);
+
+    if validator.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(validator.errors)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Validator
+
+struct Validator<'a> {
+    registry: &'a TypeRegistry,
+    path: String,
+    errors: ValidationErrors,
+}
+
+impl Validator<'_> {
+    fn push(&mut self, kind: ValidationErrorKind) {
+        self.errors.push(ValidationError {
+            path: self.path.clone(),
+            kind,
+        });
+    }
+
+    fn check_attributes(&mut self, value: &dyn Reflect, attributes: &CustomAttributes) {
+        if let Some(range) = attributes.get::<Range>()
+            && let Err(kind) = range.check(value)
+        {
+            self.push(kind);
+        }
+        if let Some(non_empty) = attributes.get::<NonEmpty>()
+            && let Err(kind) = non_empty.check(value)
+        {
+            self.push(kind);
+        }
+    }
+
+    fn visit_child(
+        &mut self,
+        value: &dyn Reflect,
+        attributes: Option<&CustomAttributes>,
+        segment: core::fmt::Arguments<'_>,
+    ) {
+        let len = self.path.len();
+        // Writing to a `String` never fails.
+        let _ = self.path.write_fmt(segment);
+        self.visit(value, attributes);
+        self.path.truncate(len);
+    }
+
+    fn visit(&mut self, value: &dyn Reflect, attributes: Option<&CustomAttributes>) {
+        if let Some(attributes) = attributes {
+            self.check_attributes(value, attributes);
+        }
+
+        let info = value.represented_type_info();
+
+        if let Some(info) = info {
+            self.check_attributes(value, info.custom_attributes());
+
+            if let Some(validator) = self
+                .registry
+                .get_type_trait::<ReflectValidate>(info.type_id())
+                && let Some(Err(kind)) = validator.validate(value)
+            {
+                self.push(kind);
+            }
+        }
+
+        match value.reflect_ref() {
+            ReflectRef::Struct(data) => {
+                for (index, (field_info, field)) in data.iter_fields_with_info().enumerate() {
+                    let Some(name) = data.name_at(index) else {
+                        continue;
+                    };
+                    let attrs = field_info.map(NamedField::custom_attributes);
+                    self.visit_child(field, attrs, format_args!(".{name}"));
+                }
+            }
+            ReflectRef::TupleStruct(data) => {
+                for (index, (field_info, field)) in data.iter_fields_with_info().enumerate() {
+                    let attrs = field_info.map(UnnamedField::custom_attributes);
+                    self.visit_child(field, attrs, format_args!(".{index}"));
+                }
+            }
+            ReflectRef::Tuple(data) => {
+                for (index, field) in data.iter_fields().enumerate() {
+                    self.visit_child(field, None, format_args!(".{index}"));
+                }
+            }
+            ReflectRef::List(data) => {
+                for (index, item) in data.iter().enumerate() {
+                    self.visit_child(item, None, format_args!("[{index}]"));
+                }
+            }
+            ReflectRef::Array(data) => {
+                for (index, item) in data.iter().enumerate() {
+                    self.visit_child(item, None, format_args!("[{index}]"));
+                }
+            }
+            ReflectRef::Map(data) => {
+                for (key, val) in data.iter() {
+                    self.visit_child(val, None, format_args!("[{key:?}]"));
+                }
+            }
+            ReflectRef::Set(data) => {
+                for item in data.iter() {
+                    self.visit_child(item, None, format_args!("[{item:?}]"));
+                }
+            }
+            ReflectRef::Enum(data) => {
+                let variant = info
+                    .and_then(|info| info.as_enum().ok())
+                    .and_then(|info| info.variant(data.variant_name()));
+
+                if let Some(variant) = variant {
+                    self.check_attributes(value, variant.custom_attributes());
+                }
+
+                for (index, (field_info, field)) in data.iter_fields_with_info().enumerate() {
+                    let attrs = field_info.as_ref().map(VariantFieldInfo::custom_attributes);
+                    match field {
+                        VariantField::Struct(name, field) => {
+                            self.visit_child(field, attrs, format_args!(".{name}"));
+                        }
+                        VariantField::Tuple(field) => {
+                            self.visit_child(field, attrs, format_args!(".{index}"));
+                        }
+                    }
+                }
+            }
+            ReflectRef::Opaque(_) => {}
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::{Validate, validate};
+    use crate::Reflect;
+    use crate::registry::{ReflectValidate, TypeRegistry};
+    use crate::validate::{NonEmpty, Range, ValidationErrorKind};
+
+    #[derive(Reflect)]
+    #[reflect(type_trait = ReflectValidate)]
+    struct Even(u32);
+
+    impl Validate for Even {
+        fn validate(&self) -> Result<(), ValidationErrorKind> {
+            if self.0.is_multiple_of(2) {
+                Ok(())
+            } else {
+                Err(ValidationErrorKind::Custom("odd".into()))
+            }
+        }
+    }
+
+    #[derive(Reflect)]
+    struct Config {
+        #[reflect(@NonEmpty)]
+        name: String,
+        #[reflect(@Range::new(0.0, 1.0))]
+        volume: f32,
+        #[reflect(@NonEmpty)]
+        values: Vec<Even>,
+        #[reflect(@Range::new(0.0, 1.0))]
+        label: String,
+    }
+
+    #[derive(Reflect)]
+    enum Shape {
+        Circle {
+            #[reflect(@Range::new(0.0, 10.0))]
+            radius: f64,
+        },
+        Square(#[reflect(@Range::new(0.0, 5.0))] i32),
+    }
+
+    #[test]
+    fn collect_errors() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Even>();
+
+        let config = Config {
+            name: String::new(),
+            volume: 2.0,
+            values: vec![Even(2), Even(3)],
+            label: String::from("x"),
+        };
+
+        let errors = validate(&config, &registry).unwrap_err();
+        let paths: Vec<&str> = errors.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, [".name", ".volume", ".values[1]", ".label"]);
+
+        assert_eq!(errors.as_slice()[0].kind, ValidationErrorKind::Empty);
+        assert!(matches!(
+            errors.as_slice()[1].kind,
+            ValidationErrorKind::OutOfRange { .. }
+        ));
+        assert!(matches!(
+            errors.as_slice()[3].kind,
+            ValidationErrorKind::Unsupported { .. }
+        ));
+    }
+
+    #[test]
+    fn enum_and_dynamic() {
+        let registry = TypeRegistry::new();
+
+        assert!(validate(&Shape::Circle { radius: 1.0 }, &registry).is_ok());
+
+        let errors = validate(&Shape::Square(6), &registry).unwrap_err();
+        assert_eq!(errors.as_slice()[0].path, ".0");
+
+        let dynamic = Shape::Circle { radius: 11.0 }.to_dynamic();
+        let errors = validate(&*dynamic, &registry).unwrap_err();
+        assert_eq!(errors.as_slice()[0].path, ".radius");
+    }
+
+    #[test]
+    fn deserialize_with_processor() {
+        use alloc::boxed::Box;
+        use core::any::TypeId;
+
+        use serde::de::DeserializeSeed;
+        use serde_core::Deserializer;
+
+        use crate::registry::TypeMeta;
+        use crate::serde::{DeserializeDriver, DeserializeProcessor, SerializeDriver};
+
+        /// Builds `Even` values when `build` is set, counting the calls.
+        struct Counter {
+            build: bool,
+            calls: usize,
+        }
+
+        impl DeserializeProcessor for Counter {
+            fn try_deserialize<'de, D: Deserializer<'de>>(
+                &mut self,
+                registration: &TypeMeta,
+                _registry: &TypeRegistry,
+                deserializer: D,
+            ) -> Result<Result<Box<dyn Reflect>, D::Error>, D> {
+                if registration.type_id() != TypeId::of::<Even>() {
+                    return Err(deserializer);
+                }
+                self.calls += 1;
+                if !self.build {
+                    return Err(deserializer);
+                }
+                let value = <u32 as serde::Deserialize>::deserialize(deserializer);
+                Ok(value.map(|value| Box::new(Even(value)) as Box<dyn Reflect>))
+            }
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Even>();
+        let meta = registry.get(TypeId::of::<Even>()).unwrap();
+
+        // Values built by the processor are validated.
+        let mut processor = Counter {
+            build: true,
+            calls: 0,
+        };
+        let mut deserializer = serde_json::Deserializer::from_str("3");
+        let result = DeserializeDriver::with_processor(meta, &registry, &mut processor)
+            .with_validation(true)
+            .deserialize(&mut deserializer);
+        assert!(result.is_err());
+        assert_eq!(processor.calls, 1);
+
+        // A declining processor is only asked once.
+        let json = serde_json::to_string(&SerializeDriver::new(&Even(2), &registry)).unwrap();
+        let mut processor = Counter {
+            build: false,
+            calls: 0,
+        };
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let result = DeserializeDriver::with_processor(meta, &registry, &mut processor)
+            .with_validation(true)
+            .deserialize(&mut deserializer);
+        assert!(result.is_ok());
+        assert_eq!(processor.calls, 1);
+    }
+}
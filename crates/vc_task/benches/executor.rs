@@ -0,0 +1,74 @@
+//! Throughput of the multi-threaded executor.
+//!
+//! Run with `cargo bench -p vc_task --bench executor`.
+
+use core::hint::black_box;
+use std::time::Instant;
+
+use vc_task::futures_lite::future::yield_now;
+use vc_task::{TaskPool, TaskPoolBuilder};
+
+const ROUNDS: u32 = 20;
+
+fn measure(name: &str, threads: usize, mut f: impl FnMut()) {
+    f(); // warm up
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    let average = start.elapsed() / ROUNDS;
+    println!("{name:<16} threads: {threads:>2}  {average:>10.3?}");
+}
+
+/// Many tiny tasks spawned from the calling thread.
+fn spawn_flat(pool: &TaskPool) {
+    pool.scope(|s| {
+        for i in 0..10_000_u64 {
+            s.spawn(async move { black_box(i) });
+        }
+    });
+}
+
+/// Tasks that spawn and await their own children, like systems
+/// splitting their work across the pool.
+fn spawn_nested(pool: &'static TaskPool) {
+    pool.scope(|s| {
+        for i in 0..64_u64 {
+            s.spawn(async move {
+                let children: Vec<_> = (0..128_u64)
+                    .map(|j| pool.spawn(async move { black_box(i * j) }))
+                    .collect();
+                for child in children {
+                    child.await;
+                }
+            });
+        }
+    });
+}
+
+/// Tasks that yield repeatedly, which go through the global queue.
+fn yield_heavy(pool: &TaskPool) {
+    pool.scope(|s| {
+        for _ in 0..256 {
+            s.spawn(async {
+                for _ in 0..16 {
+                    yield_now().await;
+                }
+            });
+        }
+    });
+}
+
+fn main() {
+    let available = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let mut threads = vec![1, 2, 4, 8, 16];
+    threads.retain(|&n| n <= available.max(2));
+
+    for n in threads {
+        let pool: &'static TaskPool =
+            Box::leak(Box::new(TaskPoolBuilder::new().thread_num(n).build()));
+        measure("spawn_flat", n, || spawn_flat(pool));
+        measure("spawn_nested", n, || spawn_nested(pool));
+        measure("yield_heavy", n, || yield_heavy(pool));
+    }
+}
@@ -0,0 +1,238 @@
+//! Hooks for observing the lifecycle of tasks.
+//!
+//! See [`TaskInstrumentation`] for details.
+
+use core::future::{Future, poll_fn};
+use core::pin::pin;
+use core::time::Duration;
+
+use vc_os::sync::Arc;
+use vc_os::sync::atomic::{AtomicU64, Ordering};
+use vc_os::time::Instant;
+
+// -----------------------------------------------------------------------------
+// TaskInfo
+
+/// Identifies an instrumented task in [`TaskInstrumentation`] callbacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TaskInfo {
+    /// An id that is unique within the process, allocated when the task is spawned.
+    pub id: u64,
+    /// The label passed to `spawn_labeled`, or `None` for unlabeled tasks.
+    pub label: Option<&'static str>,
+}
+
+impl TaskInfo {
+    fn new(label: Option<&'static str>) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            label,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// TaskTiming
+
+/// Durations of a completed task, passed to [`TaskInstrumentation::on_complete`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TaskTiming {
+    /// Time spent inside `poll`, i.e. actually running.
+    pub busy: Duration,
+    /// Wall time from the first poll to completion, including time
+    /// spent waiting to be woken.
+    pub total: Duration,
+}
+
+// -----------------------------------------------------------------------------
+// TaskInstrumentation
+
+/// Callbacks invoked over the lifecycle of every task spawned on a [`TaskPool`].
+///
+/// Set through [`TaskPoolBuilder::instrumentation`]. This is intended for
+/// feeding external profilers without modifying the executors.
+///
+/// - [`on_spawn`] runs on the spawning thread, before the task is scheduled.
+/// - [`on_start`] runs on the executing thread, right before the first poll.
+/// - [`on_complete`] runs on the executing thread, after the future returns.
+///
+/// Tasks that panic or are cancelled do not report completion.
+///
+/// All methods have empty default implementations. Timing relies on
+/// [`Instant`], so `no_std` targets without a default time source must
+/// configure one before any task is instrumented.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+/// use vc_task::{TaskInfo, TaskInstrumentation, TaskPoolBuilder, TaskTiming, block_on};
+///
+/// #[derive(Default)]
+/// struct Counter(AtomicUsize);
+///
+/// struct Hooks(Arc<Counter>);
+///
+/// impl TaskInstrumentation for Hooks {
+///     fn on_complete(&self, task: &TaskInfo, _timing: TaskTiming) {
+///         assert_eq!(task.label, Some("answer"));
+///         self.0.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let counter = Arc::new(Counter::default());
+/// let pool = TaskPoolBuilder::new()
+///     .thread_num(1)
+///     .instrumentation(Hooks(counter.clone()))
+///     .build();
+///
+/// let task = pool.spawn_labeled("answer", async { 42 });
+/// assert_eq!(block_on(task), 42);
+/// assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+/// ```
+///
+/// [`TaskPool`]: crate::TaskPool
+/// [`TaskPoolBuilder::instrumentation`]: crate::TaskPoolBuilder::instrumentation
+/// [`on_spawn`]: Self::on_spawn
+/// [`on_start`]: Self::on_start
+/// [`on_complete`]: Self::on_complete
+pub trait TaskInstrumentation: Send + Sync + 'static {
+    /// Called when a task is spawned.
+    fn on_spawn(&self, _task: &TaskInfo) {}
+
+    /// Called when a task is polled for the first time.
+    fn on_start(&self, _task: &TaskInfo) {}
+
+    /// Called when a task has completed.
+    fn on_complete(&self, _task: &TaskInfo, _timing: TaskTiming) {}
+}
+
+impl core::fmt::Debug for dyn TaskInstrumentation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("TaskInstrumentation")
+    }
+}
+
+/// Shared handle to the instrumentation of a task pool.
+pub(crate) type Instrumentation = Option<Arc<dyn TaskInstrumentation>>;
+
+/// Wraps a future so that it reports to `hooks`.
+///
+/// [`TaskInstrumentation::on_spawn`] is invoked immediately. Without hooks,
+/// the returned future only forwards to the inner one, and registers the task
+/// with the watchdog if the `debug` feature is enabled.
+pub(crate) fn instrument<F: Future>(
+    hooks: Instrumentation,
+    label: Option<&'static str>,
+    future: F,
+) -> impl Future<Output = F::Output> {
+    let hooks = hooks.map(|hooks| {
+        let info = TaskInfo::new(label);
+        hooks.on_spawn(&info);
+        (hooks, info)
+    });
+
+    crate::cfg::debug! {
+        // The watchdog reports pending tasks, with or without hooks.
+        let task = hooks.as_ref().map_or_else(|| TaskInfo::new(label), |(_, info)| *info);
+        let guard = crate::watchdog::TaskGuard::new(&task);
+    }
+
+    async move {
+        crate::cfg::debug! {
+            let _guard = guard;
+        }
+
+        let Some((hooks, info)) = hooks else {
+            return future.await;
+        };
+
+        hooks.on_start(&info);
+
+        let start = Instant::now();
+        let mut busy = Duration::ZERO;
+        let mut future = pin!(future);
+
+        let output = poll_fn(|cx| {
+            let begin = Instant::now();
+            let poll = future.as_mut().poll(cx);
+            busy += begin.elapsed();
+            poll
+        })
+        .await;
+
+        let total = start.elapsed();
+        hooks.on_complete(&info, TaskTiming { busy, total });
+
+        output
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::vec::Vec;
+    use std::sync::Mutex;
+
+    use vc_os::sync::Arc;
+
+    use super::{TaskInfo, TaskInstrumentation, TaskTiming};
+    use crate::TaskPoolBuilder;
+
+    #[derive(Default)]
+    struct Recorder {
+        spawned: Mutex<Vec<TaskInfo>>,
+        started: Mutex<Vec<TaskInfo>>,
+        completed: Mutex<Vec<(TaskInfo, TaskTiming)>>,
+    }
+
+    struct Hooks(Arc<Recorder>);
+
+    impl TaskInstrumentation for Hooks {
+        fn on_spawn(&self, task: &TaskInfo) {
+            self.0.spawned.lock().unwrap().push(*task);
+        }
+
+        fn on_start(&self, task: &TaskInfo) {
+            self.0.started.lock().unwrap().push(*task);
+        }
+
+        fn on_complete(&self, task: &TaskInfo, timing: TaskTiming) {
+            self.0.completed.lock().unwrap().push((*task, timing));
+        }
+    }
+
+    #[test]
+    fn scope_hooks() {
+        let recorder = Arc::new(Recorder::default());
+        let pool = TaskPoolBuilder::new()
+            .thread_num(1)
+            .instrumentation(Hooks(recorder.clone()))
+            .build();
+
+        let results = pool.scope(|scope| {
+            scope.spawn(async { 1 });
+            scope.spawn_labeled("two", async { 2 });
+            scope.spawn_on_scope(async { 3 });
+        });
+        assert_eq!(results.len(), 3);
+
+        let spawned = recorder.spawned.lock().unwrap();
+        assert_eq!(spawned.len(), 3);
+        assert_eq!(spawned.iter().filter(|t| t.label == Some("two")).count(), 1);
+        assert_eq!(recorder.started.lock().unwrap().len(), 3);
+
+        let completed = recorder.completed.lock().unwrap();
+        assert_eq!(completed.len(), 3);
+        assert!(
+            completed
+                .iter()
+                .all(|(_, timing)| timing.busy <= timing.total)
+        );
+    }
+}
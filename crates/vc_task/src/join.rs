@@ -0,0 +1,350 @@
+//! Combinators awaiting several [`Task`]s.
+
+use alloc::task::Wake;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::future::{Future, poll_fn};
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use core::{fmt, mem};
+
+use futures_lite::future::{try_zip, zip};
+use vc_os::sync::{Arc, Mutex, PoisonError};
+
+use crate::Task;
+
+// -----------------------------------------------------------------------------
+// Task::join
+
+impl<T> Task<T> {
+    /// Awaits two tasks concurrently and returns both outputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vc_task::{Task, TaskPool, block_on};
+    ///
+    /// let pool = TaskPool::new();
+    /// let a = pool.spawn(async { 1 });
+    /// let b = pool.spawn(async { "two" });
+    ///
+    /// assert_eq!(block_on(Task::join(a, b)), (1, "two"));
+    /// ```
+    pub async fn join<U>(a: Self, b: Task<U>) -> (T, U) {
+        zip(a, b).await
+    }
+}
+
+impl<T, E> Task<Result<T, E>> {
+    /// Awaits two fallible tasks concurrently.
+    ///
+    /// Returns the first error as soon as either task fails,
+    /// and cancels the other task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vc_task::{Task, TaskPool, block_on};
+    ///
+    /// let pool = TaskPool::new();
+    /// let a = pool.spawn(async { Ok::<_, &str>(1) });
+    /// let b = pool.spawn(async { Err::<u8, _>("failed") });
+    ///
+    /// assert_eq!(block_on(Task::try_join(a, b)), Err("failed"));
+    /// ```
+    pub async fn try_join<U>(a: Self, b: Task<Result<U, E>>) -> Result<(T, U), E> {
+        try_zip(a, b).await
+    }
+}
+
+// -----------------------------------------------------------------------------
+// TaskGroup
+
+/// A task of a group, `O` is the task output and `T` the stored value.
+enum Slot<O, T> {
+    Pending(Task<O>),
+    Done(T),
+}
+
+impl<O, T> Slot<O, T> {
+    fn into_value(self) -> T {
+        match self {
+            Self::Done(value) => value,
+            Self::Pending(_) => unreachable!("the task group is not finished"),
+        }
+    }
+}
+
+/// A group of spawned [`Task`]s that are awaited together.
+///
+/// Tasks are spawned on any task pool and pushed into the group, so the group
+/// works the same on every backend. The outputs are returned in push order.
+///
+/// Dropping the group cancels all tasks that have not finished.
+///
+/// # Examples
+///
+/// ```
+/// use vc_task::{TaskGroup, TaskPool, block_on};
+///
+/// let pool = TaskPool::new();
+/// let group: TaskGroup<u32> = (0..4).map(|i| pool.spawn(async move { i * i })).collect();
+///
+/// assert_eq!(block_on(group.join()), [0, 1, 4, 9]);
+/// ```
+pub struct TaskGroup<T> {
+    tasks: Vec<Task<T>>,
+}
+
+impl<T> Default for TaskGroup<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for TaskGroup<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskGroup")
+            .field("len", &self.tasks.len())
+            .finish()
+    }
+}
+
+impl<T> TaskGroup<T> {
+    /// Creates an empty group.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { tasks: Vec::new() }
+    }
+
+    /// Adds a spawned task to the group.
+    #[inline]
+    pub fn push(&mut self, task: Task<T>) {
+        self.tasks.push(task);
+    }
+
+    /// Returns the number of tasks in the group.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Returns `true` if the group contains no tasks.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Awaits all tasks and returns their outputs in push order.
+    pub async fn join(self) -> Vec<T> {
+        match poll_group(self.tasks, Ok::<T, Infallible>).await {
+            Ok(values) => values,
+            Err(never) => match never {},
+        }
+    }
+}
+
+impl<T, E> TaskGroup<Result<T, E>> {
+    /// Awaits all fallible tasks and returns their outputs in push order.
+    ///
+    /// Returns the first error as soon as any task fails,
+    /// and cancels all tasks that have not finished.
+    ///
+    /// # Platform-Specific Behavior
+    ///
+    /// Canceling tasks is unsupported on the web, the remaining tasks
+    /// keep running but their outputs are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vc_task::{TaskGroup, TaskPool, block_on};
+    ///
+    /// let pool = TaskPool::new();
+    /// let mut group = TaskGroup::new();
+    /// group.push(pool.spawn(async { Ok(1) }));
+    /// group.push(pool.spawn(async { Err("failed") }));
+    ///
+    /// assert_eq!(block_on(group.try_join()), Err("failed"));
+    /// ```
+    pub async fn try_join(self) -> Result<Vec<T>, E> {
+        // Returning early drops the pending tasks, which cancels them.
+        poll_group(self.tasks, core::convert::identity).await
+    }
+}
+
+/// The tasks of a group woken since the group was last polled.
+struct ReadySet {
+    indices: Mutex<Vec<usize>>,
+    waker: Mutex<Option<Waker>>,
+}
+
+/// Marks a task of a group as ready and wakes the group.
+struct TaskWaker {
+    index: usize,
+    ready: Arc<ReadySet>,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let ready = &self.ready;
+        ready
+            .indices
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(self.index);
+        let waker = ready.waker.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(waker) = waker.as_ref() {
+            waker.wake_by_ref();
+        }
+    }
+}
+
+/// Awaits all tasks, mapping their outputs with `f` and stopping at the first error.
+///
+/// Each task has its own waker, so only the woken tasks are polled again.
+async fn poll_group<O, T, E>(
+    tasks: Vec<Task<O>>,
+    mut f: impl FnMut(O) -> Result<T, E>,
+) -> Result<Vec<T>, E> {
+    let len = tasks.len();
+    let ready = Arc::new(ReadySet {
+        indices: Mutex::new((0..len).collect()),
+        waker: Mutex::new(None),
+    });
+    let wakers: Vec<Waker> = (0..len)
+        .map(|index| {
+            let ready = ready.clone();
+            Waker::from(Arc::new(TaskWaker { index, ready }))
+        })
+        .collect();
+    let mut slots: Vec<Slot<O, T>> = tasks.into_iter().map(Slot::Pending).collect();
+    let mut pending = len;
+
+    poll_fn(|cx| {
+        // Registered first, so wakes during the polls below are not lost.
+        let mut outer = ready.waker.lock().unwrap_or_else(PoisonError::into_inner);
+        if !outer
+            .as_ref()
+            .is_some_and(|outer| outer.will_wake(cx.waker()))
+        {
+            *outer = Some(cx.waker().clone());
+        }
+        drop(outer);
+
+        let indices = mem::take(&mut *ready.indices.lock().unwrap_or_else(PoisonError::into_inner));
+        for index in indices {
+            let Slot::Pending(task) = &mut slots[index] else {
+                continue;
+            };
+            let mut task_cx = Context::from_waker(&wakers[index]);
+            if let Poll::Ready(output) = Pin::new(task).poll(&mut task_cx) {
+                match f(output) {
+                    Ok(value) => slots[index] = Slot::Done(value),
+                    Err(err) => return Poll::Ready(Err(err)),
+                }
+                pending -= 1;
+            }
+        }
+
+        if pending == 0 {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    })
+    .await?;
+
+    Ok(slots.into_iter().map(Slot::into_value).collect())
+}
+
+impl<T> Extend<Task<T>> for TaskGroup<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Task<T>>>(&mut self, iter: I) {
+        self.tasks.extend(iter);
+    }
+}
+
+impl<T> FromIterator<Task<T>> for TaskGroup<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Task<T>>>(iter: I) -> Self {
+        Self {
+            tasks: Vec::from_iter(iter),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use futures_lite::future::{pending, yield_now};
+    use vc_os::sync::Arc;
+    use vc_os::sync::atomic::{AtomicBool, Ordering};
+
+    use super::TaskGroup;
+    use crate::{TaskPool, block_on};
+
+    /// Sets the flag when dropped, e.g. when its task is canceled.
+    struct DropFlag(Arc<AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::Release);
+        }
+    }
+
+    #[test]
+    fn try_join_cancels() {
+        let pool = TaskPool::new();
+        let dropped = Arc::new(AtomicBool::new(false));
+        let flag = DropFlag(dropped.clone());
+
+        let mut group = TaskGroup::new();
+        group.push(pool.spawn(async move {
+            let _flag = flag;
+            pending::<Result<u32, &str>>().await
+        }));
+        group.push(pool.spawn(async { Err("failed") }));
+        assert_eq!(group.len(), 2);
+        assert_eq!(block_on(group.try_join()), Err("failed"));
+
+        let group: TaskGroup<Result<u32, &str>> =
+            (0..3).map(|i| pool.spawn(async move { Ok(i) })).collect();
+        assert_eq!(block_on(group.try_join()), Ok([0, 1, 2].into()));
+
+        // The executor drops the future of a canceled task when it runs next.
+        for _ in 0..1000 {
+            if dropped.load(Ordering::Acquire) {
+                break;
+            }
+            vc_os::thread::sleep(Duration::from_millis(1));
+        }
+        assert!(dropped.load(Ordering::Acquire));
+        assert!(block_on(TaskGroup::<u32>::new().join()).is_empty());
+    }
+
+    #[test]
+    fn join_yielding() {
+        let pool = TaskPool::new();
+        let group: TaskGroup<u32> = (0..8)
+            .map(|i| {
+                pool.spawn(async move {
+                    for _ in 0..i {
+                        yield_now().await;
+                    }
+                    i
+                })
+            })
+            .collect();
+        assert_eq!(block_on(group.join()), [0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+}
@@ -12,8 +12,9 @@ pub mod cfg {
         #[cfg(feature = "std")] => std,
         #[cfg(all(target_arch = "wasm32", feature = "web"))] => web,
         #[cfg(all(feature = "std", feature = "async_io"))] => async_io,
+        #[cfg(feature = "debug")] => debug,
         #[cfg(all(feature = "std", not(feature = "web")))] => multi_thread,
-        #[cfg(any(not(feature = "std"), feature = "web"))] => single_thread,
+        not(multi_thread) => single_thread,
     }
 }
 
@@ -34,23 +35,42 @@ mod macro_utils;
 
 mod platform;
 
+mod instrument;
 mod iter;
+mod join;
 mod slice;
 
 pub mod futures;
 
+cfg::debug! {
+    pub mod watchdog;
+}
+
 // -----------------------------------------------------------------------------
 // Exports
 
 pub use cond_send::{BoxedFuture, CondSendFuture};
 
-pub use platform::tick_local_executor_on_main_thread;
 pub use platform::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool};
 pub use platform::{Scope, TaskPool, TaskPoolBuilder};
 pub use platform::{ScopeExecutor, ScopeExecutorTicker};
 pub use platform::{Task, block_on};
+pub use platform::{
+    tick_local_executor_for, tick_local_executor_on_main_thread, tick_local_executor_up_to,
+};
+
+cfg::switch! {
+    cfg::web => {}
+    cfg::std => {
+        pub use platform::PinnedTaskPool;
+    }
+    _ => {}
+}
+
+pub use instrument::{TaskInfo, TaskInstrumentation, TaskTiming};
 
 pub use iter::ParallelIterator;
+pub use join::TaskGroup;
 pub use slice::{ParallelSlice, ParallelSliceMut};
 
 // -----------------------------------------------------------------------------
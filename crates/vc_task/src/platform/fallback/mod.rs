@@ -48,6 +48,17 @@ pub fn block_on<T>(future: impl Future<Output = T>) -> T {
     }
 }
 
+crate::cfg::debug! {
+    use alloc::vec::Vec;
+
+    use crate::watchdog::ExecutorState;
+
+    /// Collects the queue of the global executor, for the watchdog.
+    pub(crate) fn executor_states(states: &mut Vec<ExecutorState>) {
+        states.push(task_pool::executor_state());
+    }
+}
+
 // -----------------------------------------------------------------------------
 // task_pools
 
@@ -78,32 +89,3 @@ taskpool! {
     /// See [`TaskPool`] documentation for details on Bevy tasks.
     (IO_TASK_POOL, IoTaskPool)
 }
-
-/// A function used to tick the global tasks pools on the main thread.
-/// This will run a maximum of 100 local tasks per executor per call to this function.
-///
-/// # Warning
-///
-/// This function *must* be called on the main thread, or the task pools will not be updated appropriately.
-pub fn tick_local_executor_on_main_thread() {
-    COMPUTE_TASK_POOL
-        .get()
-        .unwrap()
-        .with_local_executor(|compute_local_executor| {
-            ASYNC_COMPUTE_TASK_POOL
-                .get()
-                .unwrap()
-                .with_local_executor(|async_local_executor| {
-                    IO_TASK_POOL
-                        .get()
-                        .unwrap()
-                        .with_local_executor(|io_local_executor| {
-                            for _ in 0..100 {
-                                compute_local_executor.try_tick();
-                                async_local_executor.try_tick();
-                                io_local_executor.try_tick();
-                            }
-                        });
-                });
-        });
-}
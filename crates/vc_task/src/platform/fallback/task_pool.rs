@@ -8,6 +8,8 @@ use core::mem;
 
 use vc_os::sync::Arc;
 
+use crate::instrument::{Instrumentation, TaskInstrumentation, instrument};
+
 use super::ScopeExecutor;
 use super::{GlobalExecutor, LocalExecutor};
 use super::{Task, block_on};
@@ -17,13 +19,17 @@ use super::{Task, block_on};
 
 /// Used to create a [`TaskPool`].
 #[derive(Default)]
-pub struct TaskPoolBuilder {}
+pub struct TaskPoolBuilder {
+    instrumentation: Instrumentation,
+}
 
 impl TaskPoolBuilder {
     /// Creates a new `TaskPoolBuilder` instance
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            instrumentation: None,
+        }
     }
 
     /// No op on the single threaded task pool
@@ -56,10 +62,33 @@ impl TaskPoolBuilder {
         self
     }
 
+    /// No op on the single threaded task pool
+    #[inline(always)]
+    pub fn max_blocking_threads(self, _max_blocking_threads: usize) -> Self {
+        self
+    }
+
+    /// No op on the single threaded task pool
+    #[inline(always)]
+    pub fn core_affinity(self, _first_core: usize) -> Self {
+        self
+    }
+
+    /// Sets the hooks invoked over the lifecycle of every task spawned on the pool.
+    ///
+    /// See [`TaskInstrumentation`] for details.
+    #[inline]
+    pub fn instrumentation(mut self, hooks: impl TaskInstrumentation) -> Self {
+        self.instrumentation = Some(Arc::new(hooks));
+        self
+    }
+
     /// Creates a new [`TaskPool`]
     #[inline(always)]
     pub fn build(self) -> TaskPool {
-        TaskPool {}
+        TaskPool {
+            instrumentation: self.instrumentation,
+        }
     }
 }
 
@@ -69,6 +98,21 @@ impl TaskPoolBuilder {
 // Because we do not have thread-locals without std, we cannot use LocalExecutor here.
 static LOCAL_EXECUTOR: GlobalExecutor<'static> = const { GlobalExecutor::new() };
 
+crate::cfg::debug! {
+    use crate::watchdog::ExecutorState;
+
+    pub(super) fn executor_state() -> ExecutorState {
+        #[expect(unsafe_code, reason = "The watchdog checks on the blocked main thread.")]
+        let local_executor = unsafe { LOCAL_EXECUTOR.inner() };
+
+        ExecutorState {
+            name: String::from("TaskPool"),
+            queued: local_executor.len(),
+            workers: Vec::new(),
+        }
+    }
+}
+
 // -----------------------------------------------------------------------------
 // TaskPool
 
@@ -77,13 +121,15 @@ static LOCAL_EXECUTOR: GlobalExecutor<'static> = const { GlobalExecutor::new() }
 /// Tasks are futures that are being automatically driven by the pool
 /// on threads owned by the pool. In this case - main thread only.
 #[derive(Debug, Default)]
-pub struct TaskPool {}
+pub struct TaskPool {
+    instrumentation: Instrumentation,
+}
 
 impl TaskPool {
     /// Create a `TaskPool` with the default configuration.
     #[inline(always)]
     pub fn new() -> Self {
-        TaskPool {}
+        TaskPoolBuilder::new().build()
     }
 
     /// Return the number of threads owned by the task pool
@@ -183,10 +229,14 @@ impl TaskPool {
         // SAFETY: As above, all futures must complete in this function so we can change the lifetime
         let pending_tasks: &'env Cell<usize> = unsafe { mem::transmute(&pending_tasks) };
 
+        // SAFETY: As above, all futures must complete in this function so we can change the lifetime
+        let instrumentation: &'env Instrumentation = unsafe { mem::transmute(&self.instrumentation) };
+
         let mut scope = Scope {
             executor_ref,
             pending_tasks,
             results_ref,
+            instrumentation,
             scope: PhantomData,
             env: PhantomData,
         };
@@ -196,9 +246,22 @@ impl TaskPool {
 
         f(scope_ref);
 
+        crate::cfg::debug! {
+            let wait = crate::watchdog::Wait::new(crate::watchdog::WaitKind::Scope);
+            let mut last_pending = pending_tasks.get();
+        }
+
         // Wait until the scope is complete
         block_on(executor.run(async {
             while pending_tasks.get() != 0 {
+                crate::cfg::debug! {
+                    if pending_tasks.get() != last_pending {
+                        last_pending = pending_tasks.get();
+                        wait.progress();
+                    } else {
+                        wait.check();
+                    }
+                }
                 futures_lite::future::yield_now().await;
             }
         }));
@@ -228,13 +291,48 @@ impl TaskPool {
         #[expect(unsafe_code, reason = "Caller ensure call in main thread.")]
         let local_executor = unsafe { LOCAL_EXECUTOR.inner() };
 
-        let task = local_executor.spawn(future);
+        let task = local_executor.spawn(instrument(self.instrumentation.clone(), None, future));
+        // Loop until all tasks are done
+        while local_executor.try_tick() {}
+
+        Task(task)
+    }
+
+    /// Spawns a static future onto the thread pool with a label.
+    ///
+    /// This is identical to [`TaskPool::spawn`], except that the label is
+    /// reported to the [`TaskInstrumentation`] of the pool.
+    pub fn spawn_labeled<T>(
+        &self,
+        label: &'static str,
+        future: impl Future<Output = T> + 'static + Send + Sync,
+    ) -> Task<T>
+    where
+        T: 'static + Send + Sync,
+    {
+        #[expect(unsafe_code, reason = "Caller ensure call in main thread.")]
+        let local_executor = unsafe { LOCAL_EXECUTOR.inner() };
+
+        let task = local_executor.spawn(instrument(self.instrumentation.clone(), Some(label), future));
         // Loop until all tasks are done
         while local_executor.try_tick() {}
 
         Task(task)
     }
 
+    /// Runs a blocking closure and returns its result as a [`Task`].
+    ///
+    /// There are no threads to offload to, so the closure runs inline,
+    /// blocking the current thread, before this function returns. The
+    /// returned task is already complete.
+    ///
+    /// The caller **must** ensure execution occurs **on the main thread**.
+    pub fn spawn_blocking<T: 'static>(&self, f: impl FnOnce() -> T + Send + 'static) -> Task<T> {
+        // Run before spawning, so the closure never waits behind queued tasks.
+        let value = f();
+        self.spawn_local(core::future::ready(value))
+    }
+
     /// Spawns a static future on local thread task queue.
     ///
     /// This is functionally identical to [`TaskPool::spawn`].
@@ -247,7 +345,7 @@ impl TaskPool {
         #[expect(unsafe_code, reason = "Caller ensure call in main thread.")]
         let local_executor = unsafe { LOCAL_EXECUTOR.inner() };
 
-        let task = local_executor.spawn(future);
+        let task = local_executor.spawn(instrument(self.instrumentation.clone(), None, future));
         // Loop until all tasks are done
         while local_executor.try_tick() {}
 
@@ -268,6 +366,8 @@ pub struct Scope<'scope, 'env: 'scope, T> {
     pending_tasks: &'scope Cell<usize>,
     // Vector to gather results of all futures spawned during scope run
     results_ref: &'env RefCell<Vec<Option<T>>>,
+    // Task lifecycle hooks of the pool
+    instrumentation: &'scope Instrumentation,
 
     // make `Scope` invariant over 'scope and 'env
     scope: PhantomData<&'scope mut &'scope ()>,
@@ -318,6 +418,33 @@ impl<'scope, 'env, T: Send + 'env> Scope<'scope, 'env, T> {
     ///
     /// For more information, see [`TaskPool::scope`].
     pub fn spawn_on_scope<Fut: Future<Output = T> + 'scope + Send>(&self, f: Fut) {
+        self.spawn_inner(None, f);
+    }
+
+    /// Spawns a scoped `!Send` future onto the executor.
+    ///
+    /// The scope *must* outlive the provided future. The results of the future
+    /// will be returned as a part of [`TaskPool::scope`]'s return value.
+    ///
+    /// On the single threaded task pool, every scoped future already runs on the
+    /// scope's thread, this only lifts the `Send` bound of [`Scope::spawn_on_scope`].
+    ///
+    /// For more information, see [`TaskPool::scope`].
+    pub fn spawn_local<Fut: Future<Output = T> + 'scope>(&self, f: Fut) {
+        self.spawn_inner(None, f);
+    }
+
+    /// Spawns a scoped future onto the executor with a label.
+    ///
+    /// This is identical to [`Scope::spawn`], except that the label is
+    /// reported to the [`TaskInstrumentation`] of the pool.
+    pub fn spawn_labeled<Fut: Future<Output = T> + 'scope + Send>(&self, label: &'static str, f: Fut) {
+        self.spawn_inner(Some(label), f);
+    }
+
+    fn spawn_inner<Fut: Future<Output = T> + 'scope>(&self, label: Option<&'static str>, f: Fut) {
+        let f = instrument(self.instrumentation.clone(), label, f);
+
         // increment the number of pending tasks
         let pending_tasks = self.pending_tasks;
         pending_tasks.update(|i| i + 1);
@@ -365,7 +492,7 @@ mod test {
     #[test]
     fn scoped_spawn() {
         let (sender, receiver) = async_channel::unbounded();
-        let task_pool = TaskPool {};
+        let task_pool = TaskPool::new();
         let _thread = thread::spawn(move || {
             let duration = time::Duration::from_millis(50);
             thread::sleep(duration);
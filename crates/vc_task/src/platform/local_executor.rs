@@ -80,6 +80,16 @@ impl<'a> LocalExecutor<'a> {
         }
     }
 
+    /// Returns the number of scheduled tasks.
+    #[cfg(all(
+        feature = "debug",
+        any(not(feature = "std"), all(target_arch = "wasm32", feature = "web"))
+    ))]
+    pub(crate) fn len(&self) -> usize {
+        // SAFETY: single thread access
+        unsafe { (&*self.queue.get()).len() }
+    }
+
     /// Spawns a single task onto the executor.
     ///
     /// The returned `Task<T>` can be awaited to retrieve the future's result.
@@ -145,7 +155,7 @@ impl<'a> LocalExecutor<'a> {
     /// If no tasks are available, the executor will wait until a task is scheduled.
     ///
     /// We separate this from `run` to reduce compilation overhead.
-    async fn tick(&self) {
+    pub(crate) async fn tick(&self) {
         poll_fn(|ctx| {
             unsafe {
                 match &mut *self.waker.get() {
@@ -205,7 +215,7 @@ mod tests {
         let ex = LocalExecutor::new();
         let task = ex.spawn(async { 42 });
 
-        let result = block_on(ex.run(async { task.await }));
+        let result = block_on(ex.run(task));
         assert_eq!(result, 42);
     }
 
@@ -249,7 +259,7 @@ mod tests {
             inner_result * 2
         });
 
-        let result = block_on(ex.run(async { outer_task.await }));
+        let result = block_on(ex.run(outer_task));
         assert_eq!(result, 200);
     }
 }
@@ -17,8 +17,179 @@ cfg::switch! {
     }
 }
 
-pub use impls::tick_local_executor_on_main_thread;
+pub use impls::Task;
 pub use impls::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool};
 pub use impls::{Scope, TaskPool, TaskPoolBuilder};
 pub use impls::{ScopeExecutor, ScopeExecutorTicker};
-pub use impls::{Task, block_on};
+
+cfg::switch! {
+    cfg::web => {}
+    cfg::std => {
+        pub use impls::PinnedTaskPool;
+    }
+    _ => {}
+}
+
+crate::cfg::debug! {
+    if {
+        /// Blocks the current thread on `future` until it completes.
+        ///
+        /// With the `debug` feature, the call is reported when it stops
+        /// making progress, see [`watchdog`](crate::watchdog).
+        pub fn block_on<T>(future: impl Future<Output = T>) -> T {
+            use crate::watchdog::{WaitKind, watch};
+
+            impls::block_on(watch(WaitKind::BlockOn, future))
+        }
+
+        pub(crate) use impls::executor_states;
+    } else {
+        pub use impls::block_on;
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Main thread ticking
+
+use vc_os::time::{Duration, Instant};
+
+/// The number of local tasks run by [`tick_local_executor_on_main_thread`],
+/// 100 for each of the three global task pools.
+const MAIN_THREAD_TICK_LIMIT: usize = 300;
+
+/// A function used to tick the global tasks pools on the main thread.
+///
+/// This will run a maximum of 100 local tasks per task pool per call to this
+/// function, and returns early once no task is ready.
+///
+/// See [`tick_local_executor_up_to`] to choose the limit, and
+/// [`tick_local_executor_for`] for a time-budgeted alternative.
+///
+/// # Warning
+///
+/// This function *must* be called on the main thread, or the task pools will not be updated appropriately.
+///
+/// # Panics
+///
+/// Panics if the [`ComputeTaskPool`] has not been initialized yet.
+pub fn tick_local_executor_on_main_thread() {
+    tick_local_executor_up_to(MAIN_THREAD_TICK_LIMIT);
+}
+
+/// Ticks the global task pools on the main thread until no local task is ready
+/// or `limit` tasks have run, and returns the number of tasks that were run.
+///
+/// In wasm, where the main thread also drives the browser, a small limit keeps
+/// a frame from being stalled by a burst of local tasks.
+///
+/// # Warning
+///
+/// This function *must* be called on the main thread, or the task pools will not be updated appropriately.
+///
+/// # Panics
+///
+/// Panics if the [`ComputeTaskPool`] has not been initialized yet.
+pub fn tick_local_executor_up_to(limit: usize) -> usize {
+    tick_main_thread_while(|ran| ran < limit)
+}
+
+/// Ticks the global task pools on the main thread until no local task is ready
+/// or `budget` has elapsed, and returns the number of tasks that were run.
+///
+/// The budget is checked before each task, so a single slow task can still
+/// exceed it.
+///
+/// # Fairness
+///
+/// The three global task pools share the local executor of the main thread,
+/// whose tasks run in the order they were woken. A task that wakes itself again
+/// is queued behind every task that is already ready, so a busy pool cannot
+/// starve the others, e.g. IO tasks still make progress while compute tasks keep
+/// yielding.
+///
+/// # Warning
+///
+/// This function *must* be called on the main thread, or the task pools will not be updated appropriately.
+///
+/// In wasm, the local executor is also driven by the JS event loop, ticking it
+/// here only makes local tasks progress earlier. Without `std`, a time source
+/// must be available for [`Instant`].
+///
+/// # Panics
+///
+/// Panics if the [`ComputeTaskPool`] has not been initialized yet.
+pub fn tick_local_executor_for(budget: Duration) -> usize {
+    let start = Instant::now();
+    tick_main_thread_while(|_| start.elapsed() < budget)
+}
+
+/// Runs the ready local tasks of the main thread while `keep_going` returns
+/// `true`, which receives the number of tasks run so far.
+fn tick_main_thread_while(mut keep_going: impl FnMut(usize) -> bool) -> usize {
+    // All task pools share one local executor per thread.
+    ComputeTaskPool::get().with_local_executor(|local_executor| {
+        let mut ran = 0;
+        while keep_going(ran) && local_executor.try_tick() {
+            ran += 1;
+        }
+        ran
+    })
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    use futures_lite::future::yield_now;
+    use vc_os::time::Duration;
+
+    use super::{ComputeTaskPool, IoTaskPool, TaskPool};
+
+    #[test]
+    fn tick_local_executor_for() {
+        let compute = ComputeTaskPool::get_or_init(TaskPool::new);
+        let io = IoTaskPool::get_or_init(TaskPool::new);
+
+        // Never completes, but keeps waking itself.
+        compute
+            .spawn_local(async {
+                loop {
+                    yield_now().await;
+                }
+            })
+            .detach();
+
+        let done = Rc::new(Cell::new(false));
+        let flag = done.clone();
+        io.spawn_local(async move {
+            yield_now().await;
+            flag.set(true);
+        })
+        .detach();
+
+        let ran = super::tick_local_executor_for(Duration::from_millis(10));
+        assert!(done.get());
+        assert!(ran >= 3);
+    }
+
+    #[test]
+    fn tick_local_executor_up_to() {
+        let compute = ComputeTaskPool::get_or_init(TaskPool::new);
+
+        // Never completes, but keeps waking itself.
+        compute
+            .spawn_local(async {
+                loop {
+                    yield_now().await;
+                }
+            })
+            .detach();
+
+        assert_eq!(super::tick_local_executor_up_to(0), 0);
+        assert_eq!(super::tick_local_executor_up_to(5), 5);
+    }
+}
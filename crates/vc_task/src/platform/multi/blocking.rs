@@ -0,0 +1,242 @@
+//! This module provides the `BlockingExecutor` of [`TaskPool::spawn_blocking`].
+//!
+//! Blocking closures run on a separate, elastic set of threads, so they never
+//! occupy the workers of the `GlobalExecutor`. A thread is created when a task
+//! is queued and no thread is idle, up to a configured limit, and exits after
+//! being idle for [`IDLE_TIMEOUT`].
+//!
+//! [`TaskPool::spawn_blocking`]: super::TaskPool::spawn_blocking
+
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+use core::future::Future;
+use core::time::Duration;
+
+use std::thread;
+
+use async_task::Runnable;
+use vc_os::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+
+use super::task_pool::CallOnDrop;
+
+/// Idle blocking threads exit after this long without work.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The default limit of blocking threads per pool.
+pub(super) const DEFAULT_MAX_BLOCKING_THREADS: usize = 64;
+
+// -----------------------------------------------------------------------------
+// BlockingConfig
+
+/// Thread options, taken from the `TaskPoolBuilder`.
+pub(super) struct BlockingConfig {
+    pub max_threads: usize,
+    pub stack_size: Option<usize>,
+    pub thread_name: Option<String>,
+    pub on_thread_spawn: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+    pub on_thread_destroy: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+}
+
+// -----------------------------------------------------------------------------
+// BlockingExecutor
+
+struct State {
+    queue: VecDeque<Runnable>,
+    thread_num: usize,
+    idle_num: usize,
+    next_id: usize,
+    shutdown: bool,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    condvar: Condvar,
+    config: BlockingConfig,
+}
+
+impl Shared {
+    #[inline]
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// An executor running blocking tasks on dedicated threads.
+pub(super) struct BlockingExecutor {
+    shared: Arc<Shared>,
+}
+
+impl BlockingExecutor {
+    pub fn new(config: BlockingConfig) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                state: Mutex::new(State {
+                    queue: VecDeque::new(),
+                    thread_num: 0,
+                    idle_num: 0,
+                    next_id: 0,
+                    shutdown: false,
+                }),
+                condvar: Condvar::new(),
+                config,
+            }),
+        }
+    }
+
+    /// Spawns a future that is polled on a blocking thread.
+    ///
+    /// The future is expected to complete in the first poll.
+    pub fn spawn<T: Send + 'static>(
+        &self,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> async_task::Task<T> {
+        let shared = Arc::clone(&self.shared);
+        let schedule = move |runnable| Self::schedule(&shared, runnable);
+
+        let (runnable, task) = async_task::Builder::new()
+            .propagate_panic(true)
+            .spawn(|()| future, schedule);
+
+        runnable.schedule();
+        task
+    }
+
+    /// Stops idle threads, busy threads exit once the queue is empty.
+    ///
+    /// Threads are not joined, a blocking closure may never return.
+    pub fn shutdown(&self) {
+        self.shared.lock().shutdown = true;
+        self.shared.condvar.notify_all();
+    }
+
+    fn schedule(shared: &Arc<Shared>, runnable: Runnable) {
+        let mut state = shared.lock();
+        if state.shutdown && state.thread_num == 0 {
+            // Nobody is left to run it, dropping cancels the task.
+            return;
+        }
+
+        state.queue.push_back(runnable);
+        if state.queue.len() <= state.idle_num || state.thread_num >= shared.config.max_threads {
+            drop(state);
+            shared.condvar.notify_one();
+            return;
+        }
+
+        state.thread_num += 1;
+        let id = state.next_id;
+        state.next_id += 1;
+        drop(state);
+
+        Self::spawn_thread(Arc::clone(shared), id);
+    }
+
+    fn spawn_thread(shared: Arc<Shared>, id: usize) {
+        let config = &shared.config;
+        let thread_name = if let Some(thread_name) = config.thread_name.as_deref() {
+            format!("{thread_name} blocking ({id})")
+        } else {
+            format!("TaskPool blocking ({id})")
+        };
+
+        let mut thread_builder = thread::Builder::new().name(thread_name);
+        if let Some(stack_size) = config.stack_size {
+            thread_builder = thread_builder.stack_size(stack_size);
+        }
+
+        thread_builder
+            .spawn(move || Self::run(&shared))
+            .expect("Failed to spawn thread.");
+    }
+
+    fn run(shared: &Shared) {
+        if let Some(on_spawn) = shared.config.on_thread_spawn.as_ref() {
+            on_spawn();
+        }
+        let _destructor = CallOnDrop(shared.config.on_thread_destroy.clone());
+
+        let mut state = shared.lock();
+        loop {
+            if let Some(runnable) = state.queue.pop_front() {
+                drop(state);
+                // Panics are caught and propagated to the `Task`.
+                runnable.run();
+                state = shared.lock();
+                continue;
+            }
+
+            if state.shutdown {
+                break;
+            }
+
+            state.idle_num += 1;
+            let (guard, res) = shared
+                .condvar
+                .wait_timeout(state, IDLE_TIMEOUT)
+                .unwrap_or_else(PoisonError::into_inner);
+            state = guard;
+            state.idle_num -= 1;
+
+            if res.timed_out() && state.queue.is_empty() {
+                break;
+            }
+        }
+        state.thread_num -= 1;
+    }
+}
+
+impl fmt::Debug for BlockingExecutor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.shared.lock();
+        f.debug_struct("BlockingExecutor")
+            .field("thread_num", &state.thread_num)
+            .field("idle_num", &state.idle_num)
+            .field("queued", &state.queue.len())
+            .finish()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use std::sync::Barrier;
+    use std::thread;
+
+    use vc_os::sync::Arc;
+
+    use crate::{TaskPoolBuilder, block_on};
+
+    #[test]
+    fn spawn_blocking() {
+        let pool = TaskPoolBuilder::new().thread_num(1).build();
+
+        // All closures block until each of them is running,
+        // so this only completes with one thread per closure.
+        let barrier = Arc::new(Barrier::new(4));
+        let tasks: Vec<_> = (0..4)
+            .map(|i| {
+                let barrier = Arc::clone(&barrier);
+                pool.spawn_blocking(move || {
+                    barrier.wait();
+                    (i, thread::current().name().map(Into::into))
+                })
+            })
+            .collect();
+
+        for (i, task) in tasks.into_iter().enumerate() {
+            let (value, name): (usize, Option<String>) = block_on(task);
+            assert_eq!(value, i);
+            assert!(name.unwrap().contains("blocking"));
+        }
+
+        let task = pool.spawn_blocking(|| -> u32 { panic!("blocking panic") });
+        let res = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| block_on(task)));
+        assert!(res.is_err());
+    }
+}
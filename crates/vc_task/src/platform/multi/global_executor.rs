@@ -18,13 +18,13 @@ use alloc::boxed::Box;
 
 use std::thread_local;
 
-use async_task::{Runnable, Task};
+use async_task::{Runnable, ScheduleInfo, Task, WithInfo};
 use futures_lite::FutureExt;
 use futures_lite::future::poll_fn;
 
 use vc_os::sync::{Mutex, PoisonError};
 use vc_os::utils::{CachePadded, ListQueue};
-use vc_os::utils::ArrayQueue;
+use vc_os::utils::StealDeque;
 use vc_os::sync::atomic::{AtomicBool, Ordering};
 use vc_utils::extra::ArrayDeque;
 
@@ -35,9 +35,9 @@ use super::XorShift64Star;
 
 /// Capacity of each worker's local task queue.
 /// 
-/// Using 63 ensures `crossbeam::ArrayQueue` allocates exactly 64 slots (next power of two).
+/// `StealDeque` rounds its capacity up to a power of two, so 64 is used as is.
 /// This balance provides good throughput while keeping cache footprint reasonable.
-const WORKER_QUEUE_SIZE: usize = 63;
+const WORKER_QUEUE_SIZE: usize = 64;
 
 /// Number of tasks processed before a worker yields to the scheduler.
 /// This prevents long-running tasks from starving other work.
@@ -65,6 +65,11 @@ const RUN_BATCH: usize = 200;
 /// it will also attempt to steal tasks from other threads' `Worker`
 /// instances to balance workloads.
 /// 
+/// Tasks spawned or woken on a bound worker thread are pushed into its
+/// local queue, so that busy schedules do not contend on the global queue.
+/// Tasks that wake themselves while running (e.g. by yielding) go to the
+/// global queue instead, so they cannot starve the rest of the local queue.
+/// 
 /// Since we have three task pools but the main thread only has one `Worker`,
 /// the main thread's `Worker` is not bound to any specific `GlobalExecutor`.
 /// Consequently, the main thread `Worker` has no local queue and directly
@@ -109,8 +114,9 @@ struct State {
 /// that can participate in a task pool.
 struct Seat {
     /// Local, bounded task queue for this worker
-    /// Uses `ArrayQueue` for lock-free push/pop operations
-    queue: ArrayQueue<Runnable>,
+    /// Uses `StealDeque`: the worker pushes and pops without contention,
+    /// other workers steal from the other end
+    queue: StealDeque<Runnable>,
     /// Indicates whether this seat is occupied by a bound worker
     /// Set during worker initialization via atomic compare-and-swap
     occupied: AtomicBool,
@@ -135,7 +141,7 @@ struct Worker {
     /// Pointer to the global executor state
     state: Cell<*const State>,
     /// Pointer to the thread’s local task queue
-    queue: Cell<*const ArrayQueue<Runnable>>,
+    queue: Cell<*const StealDeque<Runnable>>,
     /// Index of this worker’s seat in the global executor
     seat_index: Cell<usize>,
     /// Current activity state of the worker
@@ -313,7 +319,7 @@ impl Worker {
     fn steal_global(&self) -> Option<Runnable> {
         /// We assume that steal from other workers frequently failed.
         #[inline(never)]
-        fn steal_global_inner(src: &ListQueue<Runnable>, dst: &ArrayQueue<Runnable>) {
+        fn steal_global_inner(src: &ListQueue<Runnable>, dst: &StealDeque<Runnable>) {
             let mut deque = ArrayDeque::<Runnable, WORKER_QUEUE_SIZE>::new();
 
             let mut guard = src.lock_pop();
@@ -328,12 +334,15 @@ impl Worker {
             ::core::mem::drop(guard);
 
             while let Some(runnable) = deque.pop_front() {
-                // dst must be not empty.
-                dst.push(runnable).unwrap();
+                // SAFETY: the current thread owns `dst`.
+                // dst must be empty, only stealers run concurrently.
+                if unsafe{ dst.push(runnable) }.is_err() {
+                    unreachable!("local queue is full");
+                }
             }
         }
         let src: &ListQueue<Runnable> = &self.state().queue;
-        let dst: &ArrayQueue<Runnable> = self.queue();
+        let dst: &StealDeque<Runnable> = self.queue();
 
         if let Some(r) = src.pop() {
             steal_global_inner(src, dst);
@@ -347,22 +356,8 @@ impl Worker {
 
     #[inline(never)]
     fn steal_worker(&self) -> Option<Runnable> {
-        /// We assume that steal from other workers frequently failed.
-        #[inline(never)]
-        fn steal_woker_inner(src: &ArrayQueue<Runnable>, dst: &ArrayQueue<Runnable>) {
-            let len = (src.len() + 1) >> 1;
-            for _ in 0..len {
-                if let Some(runnable) = src.pop() {
-                    // dst must be not empty.
-                    dst.push(runnable).unwrap();
-                } else {
-                    return;
-                }
-            }
-        }
-
         let state = self.state();
-        let dst: &ArrayQueue<Runnable> = self.queue();
+        let dst: &StealDeque<Runnable> = self.queue();
 
         // Pick a random starting point in the iterator list and rotate the list.
         let worker_num = state.seats.len();
@@ -373,10 +368,11 @@ impl Worker {
             .filter(|seat| !ptr::eq(&seat.queue, dst));
 
         // Try stealing from each local queue in the list.
+        // Each steal moves at most half of the victim's queue.
         for worker_seat in iter {
-            let src: &ArrayQueue<Runnable> = &worker_seat.queue;
-            if let Some(r) = src.pop() {
-                steal_woker_inner(src, dst);
+            let src: &StealDeque<Runnable> = &worker_seat.queue;
+            // SAFETY: the current thread owns `dst`.
+            if let Some(r) = unsafe{ src.steal_batch_and_pop(dst) } {
                 self.wake();
                 self.wake_one();
                 return Some(r);
@@ -402,7 +398,7 @@ impl Worker {
     /// # Safety
     /// Must only be called after successful `bind()`
     #[inline(always)]
-    const fn queue(&self) -> &ArrayQueue<Runnable> {
+    const fn queue(&self) -> &StealDeque<Runnable> {
         debug_assert!(!self.queue.get().is_null());
         unsafe{ &*self.queue.get() }
     }
@@ -476,7 +472,8 @@ impl Worker {
     #[inline(always)]
     fn fetch_runnable(&self) -> Option<Runnable> {
         let local_queue = self.queue();
-        if let Some(runnable) = local_queue.pop() {
+        // SAFETY: the current thread owns its local queue.
+        if let Some(runnable) = unsafe{ local_queue.pop() } {
             self.wake();
             return Some(runnable);
         }
@@ -548,6 +545,24 @@ impl Worker {
 
 }
 
+/// Pushes `runnable` into the local queue of the current thread,
+/// if it is a worker bound to `state`.
+/// 
+/// Returns `runnable` back if it is not, or if the local queue is full.
+fn push_local(state: &State, runnable: Runnable) -> Result<(), Runnable> {
+    let mut runnable = Some(runnable);
+    let _ = LOCAL_WORKER.try_with(|worker| {
+        if ptr::eq(worker.state.get(), state) && !worker.queue.get().is_null() {
+            // SAFETY: the current thread owns its local queue.
+            runnable = unsafe{ worker.queue().push(runnable.take().unwrap()) }.err();
+        }
+    });
+    match runnable {
+        Some(runnable) => Err(runnable),
+        None => Ok(()),
+    }
+}
+
 // -----------------------------------------------------------------------------
 // GlobalExecutor Implementation
 
@@ -569,7 +584,7 @@ impl<'a> GlobalExecutor<'a> {
                 seats: CachePadded::new(
                     (0..worker_num).map(|_|Seat{
                         occupied: AtomicBool::new(false),
-                        queue: ArrayQueue::new(WORKER_QUEUE_SIZE),
+                        queue: StealDeque::new(WORKER_QUEUE_SIZE),
                     }).collect()
                 ),
                 lounge: Mutex::new(Lounge {
@@ -622,8 +637,12 @@ impl<'a> GlobalExecutor<'a> {
     pub fn spawn<T: Send + 'a>(&self, future: impl Future<Output = T> + Send + 'a) -> Task<T> {
         let state = &self.state;
 
-        let schedule = move |runnable| {
-            state.queue.push(runnable);
+        let schedule = move |runnable, info: ScheduleInfo| {
+            if info.woken_while_running {
+                state.queue.push(runnable);
+            } else if let Err(runnable) = push_local(state, runnable) {
+                state.queue.push(runnable);
+            }
             state.wake_one();
         };
 
@@ -638,7 +657,7 @@ impl<'a> GlobalExecutor<'a> {
         let (runnable, task) = unsafe {
             async_task::Builder::new()
                 .propagate_panic(true)
-                .spawn_unchecked(|()|future, schedule)
+                .spawn_unchecked(|()|future, WithInfo(schedule))
         };
 
         // Immediately schedule the task for execution
@@ -666,6 +685,30 @@ impl<'a> GlobalExecutor<'a> {
             local_worker.run(&self.state, future)
         }).await
     }
+
+    /// Returns the state of the queues and workers, for the watchdog.
+    #[cfg(feature = "debug")]
+    pub fn executor_state(&self, name: alloc::string::String) -> crate::watchdog::ExecutorState {
+        use crate::watchdog::{ExecutorState, WorkerState};
+
+        let lounge = self.state.lounge
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let workers = self.state.seats.iter()
+            .zip(lounge.wakers.iter())
+            .map(|(seat, waker)| WorkerState {
+                queued: seat.queue.len(),
+                sleeping: waker.is_some(),
+            })
+            .collect();
+
+        ExecutorState {
+            name,
+            queued: self.state.queue.len(),
+            workers,
+        }
+    }
 }
 
 unsafe impl Send for GlobalExecutor<'_> {}
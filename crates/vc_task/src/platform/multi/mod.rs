@@ -7,6 +7,7 @@ mod xor_shift;
 mod scope_executor;
 mod task_pool;
 mod global_executor;
+mod blocking;
 
 // -----------------------------------------------------------------------------
 // Internal API
@@ -22,7 +23,11 @@ use super::local_executor::LocalExecutor;
 
 pub use task::Task;
 pub use scope_executor::{ScopeExecutor, ScopeExecutorTicker};
-pub use task_pool::{TaskPool, TaskPoolBuilder, Scope};
+pub use task_pool::{PinnedTaskPool, TaskPool, TaskPoolBuilder, Scope};
+
+crate::cfg::debug! {
+    pub(crate) use task_pool::executor_states;
+}
 
 // -----------------------------------------------------------------------------
 // block_on
@@ -67,33 +72,3 @@ taskpool! {
     /// See [`TaskPool`] documentation for details.
     (IO_TASK_POOL, IoTaskPool)
 }
-
-/// A function used to tick the global tasks pools on the main thread.
-/// 
-/// This will run a maximum of 100 local tasks per executor per call to this function.
-///
-/// # Warning
-///
-/// This function *must* be called on the main thread, or the task pools will not be updated appropriately.
-pub fn tick_local_executor_on_main_thread() {
-    COMPUTE_TASK_POOL
-        .get()
-        .unwrap()
-        .with_local_executor(|compute_local_executor| {
-            ASYNC_COMPUTE_TASK_POOL
-                .get()
-                .unwrap()
-                .with_local_executor(|async_local_executor| {
-                    IO_TASK_POOL
-                        .get()
-                        .unwrap()
-                        .with_local_executor(|io_local_executor| {
-                            for _ in 0..100 {
-                                compute_local_executor.try_tick();
-                                async_local_executor.try_tick();
-                                io_local_executor.try_tick();
-                            }
-                        });
-                });
-        });
-}
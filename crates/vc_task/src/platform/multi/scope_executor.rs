@@ -193,6 +193,50 @@ impl<'task> ScopeExecutor<'task> {
         task
     }
 
+    /// Spawns a `!Send` task onto this executor.
+    ///
+    /// Unlike [`spawn`](Self::spawn), the task must be spawned from the
+    /// executor's owning thread, where it is also executed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from a thread other than the owning thread.
+    /// Panics during task execution are propagated to the returned [`Task`].
+    pub fn spawn_local<T: Send + 'task>(
+        &self,
+        future: impl Future<Output = T> + 'task,
+    ) -> Task<T> {
+        assert!(
+            thread::current().id() == self.thread_id,
+            "`!Send` tasks can only be spawned on the thread of the `ScopeExecutor`.",
+        );
+
+        let queue = &self.queue;
+        let waker = &self.waker;
+
+        let schedule = move |runnable: Runnable| {
+            queue.push(runnable);
+            waker.wake();
+        };
+
+        // SAFETY:
+        // - `future` is created on the owning thread, and `Runnable`s are only
+        //   run by the ticker, which is only available on the owning thread.
+        // - `schedule` only captures `Sync` references, so wakers may be used
+        //   from any thread.
+        // - Self is `thread_local`, so schedule is to `'static`.
+        // - If `future` is not `'static`, borrowed variables must outlive its [`Runnable`].
+        let (runnable, task) = unsafe {
+            async_task::Builder::new()
+                .propagate_panic(true)
+                .spawn_unchecked(|()|future, schedule)
+        };
+
+        runnable.schedule();
+
+        task
+    }
+
     /// Returns a ticker that can drive this executor.
     ///
     /// The ticker is only available on the thread where the executor was
@@ -17,7 +17,10 @@ use futures_lite::FutureExt;
 use vc_os::utils::ListQueue;
 use async_task::FallibleTask;
 
+use crate::instrument::{Instrumentation, TaskInstrumentation, instrument};
+
 use super::GlobalExecutor;
+use super::blocking::{BlockingConfig, BlockingExecutor, DEFAULT_MAX_BLOCKING_THREADS};
 use super::LocalExecutor;
 use super::{ScopeExecutor, ScopeExecutorTicker};
 use super::{block_on, Task};
@@ -25,7 +28,7 @@ use super::{block_on, Task};
 // -----------------------------------------------------------------------------
 // OnDrop
 
-struct CallOnDrop(Option<Arc<dyn Fn() + Send + Sync + 'static>>);
+pub(super) struct CallOnDrop(pub(super) Option<Arc<dyn Fn() + Send + Sync + 'static>>);
 
 impl Drop for CallOnDrop {
     fn drop(&mut self) {
@@ -54,6 +57,15 @@ impl Drop for CallOnDrop {
 ///
 /// - [`on_thread_destroy`]: Callback executed once when each thread is about to terminate.
 ///
+/// - [`instrumentation`]: Hooks invoked when tasks are spawned, started and completed.
+///
+/// - [`max_blocking_threads`]: Limit of the threads running [`TaskPool::spawn_blocking`].
+///   Default: `64`.
+///
+/// - [`core_affinity`]: Pins the worker threads to consecutive cores. Default: not pinned.
+///
+/// [`build_pinned`] creates a [`PinnedTaskPool`] instead, with a single pinned thread.
+///
 /// # Examples
 ///
 /// ```
@@ -84,6 +96,10 @@ impl Drop for CallOnDrop {
 /// [`stack_size`]: Self::stack_size
 /// [`on_thread_spawn`]: Self::on_thread_spawn
 /// [`on_thread_destroy`]: Self::on_thread_destroy
+/// [`instrumentation`]: Self::instrumentation
+/// [`max_blocking_threads`]: Self::max_blocking_threads
+/// [`core_affinity`]: Self::core_affinity
+/// [`build_pinned`]: Self::build_pinned
 #[derive(Default)]
 #[must_use]
 pub struct TaskPoolBuilder {
@@ -97,6 +113,12 @@ pub struct TaskPoolBuilder {
     on_thread_spawn: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
     /// Called on thread termination.
     on_thread_destroy: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+    /// Task lifecycle hooks.
+    instrumentation: Instrumentation,
+    /// Limit of blocking threads. If `None`, uses the default.
+    max_blocking_threads: Option<usize>,
+    /// Core of the first worker thread. If `None`, threads are not pinned.
+    core_affinity: Option<usize>,
 }
 
 impl TaskPoolBuilder {
@@ -109,6 +131,9 @@ impl TaskPoolBuilder {
             thread_name: None,
             on_thread_spawn: None,
             on_thread_destroy: None,
+            instrumentation: None,
+            max_blocking_threads: None,
+            core_affinity: None,
         }
     }
 
@@ -161,11 +186,78 @@ impl TaskPoolBuilder {
         self
     }
 
+    /// Sets the hooks invoked over the lifecycle of every task spawned on the pool.
+    ///
+    /// See [`TaskInstrumentation`] for details.
+    #[inline]
+    pub fn instrumentation(mut self, hooks: impl TaskInstrumentation) -> Self {
+        self.instrumentation = Some(Arc::new(hooks));
+        self
+    }
+
+    /// Sets the maximum number of threads running [`TaskPool::spawn_blocking`].
+    ///
+    /// These threads are created on demand and exit when idle, separately from
+    /// the worker threads. Blocking tasks beyond the limit wait in a queue.
+    /// Values below `1` are treated as `1`.
+    #[inline]
+    pub fn max_blocking_threads(mut self, max_blocking_threads: usize) -> Self {
+        self.max_blocking_threads = Some(max_blocking_threads);
+        self
+    }
+
+    /// Pins the worker threads to logical cores, the `i`-th thread to
+    /// `first_core + i`, see [`vc_os::thread::set_affinity`].
+    ///
+    /// Where pinning is not supported or fails, the threads run unpinned
+    /// and a warning is logged.
+    #[inline]
+    pub fn core_affinity(mut self, first_core: usize) -> Self {
+        self.core_affinity = Some(first_core);
+        self
+    }
+
     /// Creates a [`TaskPool`] with the configured options.
     #[inline]
     pub fn build(self) -> TaskPool {
         TaskPool::new_internal(self)
     }
+
+    /// Creates a [`PinnedTaskPool`], a pool with exactly one worker thread
+    /// pinned to the logical core `core`.
+    ///
+    /// The thread name, stack size, callbacks and instrumentation are used,
+    /// the thread number and core affinity are overridden.
+    #[inline]
+    pub fn build_pinned(mut self, core: usize) -> PinnedTaskPool {
+        self.thread_num = Some(1);
+        self.core_affinity = Some(core);
+        PinnedTaskPool::new(self.build())
+    }
+}
+
+crate::cfg::debug! {
+    use vc_os::sync::{Mutex, PoisonError, Weak};
+
+    use crate::watchdog::ExecutorState;
+
+    /// The executors of all task pools and their thread names, for the watchdog.
+    static EXECUTORS: Mutex<Vec<(String, Weak<GlobalExecutor<'static>>)>> = Mutex::new(Vec::new());
+
+    fn register_executor(name: String, executor: &Arc<GlobalExecutor<'static>>) {
+        let mut executors = EXECUTORS.lock().unwrap_or_else(PoisonError::into_inner);
+        executors.retain(|(_, executor)| executor.strong_count() > 0);
+        executors.push((name, Arc::downgrade(executor)));
+    }
+
+    pub(crate) fn executor_states(states: &mut Vec<ExecutorState>) {
+        let executors = EXECUTORS.lock().unwrap_or_else(PoisonError::into_inner);
+        for (name, executor) in executors.iter() {
+            if let Some(executor) = executor.upgrade() {
+                states.push(executor.executor_state(name.clone()));
+            }
+        }
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -198,6 +290,9 @@ thread_local! {
 /// - `spawn_local` accepts non‑`Send` tasks.
 /// - `scope_with_executor` allows sending tasks to a specific thread.
 ///
+/// Blocking closures, such as file IO, should use [`TaskPool::spawn_blocking`]
+/// instead, which runs them on separate threads.
+///
 /// Returned task handles are futures themselves, so they can be awaited with
 /// [`block_on`] or composed with other async utilities.
 /// 
@@ -297,6 +392,10 @@ pub struct TaskPool {
     threads: Box<[JoinHandle<()>]>,
     /// Shutdown signal sender.
     shutdown_tx: async_channel::Sender<()>,
+    /// Task lifecycle hooks.
+    instrumentation: Instrumentation,
+    /// Threads for blocking tasks.
+    blocking: BlockingExecutor,
 }
 
 impl TaskPool {
@@ -320,6 +419,11 @@ impl TaskPool {
         // GlobalExecutor
         let executor = Arc::new(GlobalExecutor::new(thread_num));
 
+        crate::cfg::debug! {
+            let name = builder.thread_name.as_deref().unwrap_or("TaskPool");
+            register_executor(String::from(name), &executor);
+        }
+
         // Create threads
         let threads: Box<[JoinHandle<()>]> = (0..thread_num)
             .map(|i| {
@@ -343,9 +447,16 @@ impl TaskPool {
 
                 let on_thread_spawn = builder.on_thread_spawn.clone();
                 let on_thread_destroy = builder.on_thread_destroy.clone();
+                let core = builder.core_affinity.map(|first_core| first_core + i);
 
                 thread_builder
                     .spawn(move || {
+                        if let Some(core) = core
+                            && !vc_os::thread::set_affinity(core)
+                        {
+                            vc_os::log::warn!("failed to pin task pool thread to core {core}");
+                        }
+
                         // bind and initialize `LOCAL_WORKER`.
                         global_ex.bind_local_worker();
 
@@ -378,10 +489,23 @@ impl TaskPool {
             })
             .collect();
 
+        let blocking = BlockingExecutor::new(BlockingConfig {
+            max_threads: builder
+                .max_blocking_threads
+                .unwrap_or(DEFAULT_MAX_BLOCKING_THREADS)
+                .max(1),
+            stack_size: builder.stack_size,
+            thread_name: builder.thread_name,
+            on_thread_spawn: builder.on_thread_spawn,
+            on_thread_destroy: builder.on_thread_destroy,
+        });
+
         Self {
             executor,
             threads,
             shutdown_tx,
+            instrumentation: builder.instrumentation,
+            blocking,
         }
     }
 
@@ -454,7 +578,52 @@ impl TaskPool {
         &self,
         future: impl Future<Output = T> + Send + 'static,
     ) -> Task<T> {
-        Task(self.executor.spawn(future))
+        Task(self.executor.spawn(instrument(self.instrumentation.clone(), None, future)))
+    }
+
+    /// Spawns a `'static` future onto the task pool with a label.
+    ///
+    /// This is identical to [`TaskPool::spawn`], except that the label is
+    /// reported to the [`TaskInstrumentation`] of the pool.
+    #[inline]
+    pub fn spawn_labeled<T: Send + 'static>(
+        &self,
+        label: &'static str,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Task<T> {
+        Task(self.executor.spawn(instrument(self.instrumentation.clone(), Some(label), future)))
+    }
+
+    /// Runs a blocking closure on a dedicated thread.
+    ///
+    /// The closure runs on a separate set of threads, so blocking calls such as
+    /// file IO do not starve the async tasks of the worker threads. Threads are
+    /// created on demand up to [`TaskPoolBuilder::max_blocking_threads`], and
+    /// exit after being idle for a while.
+    ///
+    /// Returns a [`Task`] resolving to the return value of the closure.
+    /// A panic in the closure is propagated when the task is awaited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vc_task::{TaskPool, block_on};
+    ///
+    /// let pool = TaskPool::new();
+    /// let task = pool.spawn_blocking(|| {
+    ///     std::thread::sleep(std::time::Duration::from_millis(10));
+    ///     42
+    /// });
+    ///
+    /// assert_eq!(block_on(task), 42);
+    /// ```
+    #[inline]
+    pub fn spawn_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Task<T> {
+        let future = instrument(self.instrumentation.clone(), None, async move { f() });
+        Task(self.blocking.spawn(future))
     }
 
     /// Spawns a `'static` but `!Send` future onto the task pool.
@@ -496,6 +665,7 @@ impl TaskPool {
         &self,
         future: impl Future<Output = T> + 'static,
     ) -> Task<T> {
+        let future = instrument(self.instrumentation.clone(), None, future);
         Task(LOCAL_EXECUTOR.with(|ex| ex.spawn(future)))
     }
 
@@ -628,6 +798,7 @@ impl TaskPool {
         let global_executor: &'env GlobalExecutor<'env> = unsafe { mem::transmute(global_executor) };
         let external_executor: &'env ScopeExecutor<'env> = unsafe { mem::transmute(external_executor) };
         let scope_executor: &'env ScopeExecutor<'env> = unsafe { mem::transmute(scope_executor) };
+        let instrumentation: &'env Instrumentation = unsafe { mem::transmute(&self.instrumentation) };
 
         let task_queue: ListQueue<FallibleTask<Result<T, Box<dyn Any + Send>>>> = ListQueue::default();
         let spawned: &'env ListQueue<FallibleTask<Result<T, Box<dyn Any + Send>>>> =
@@ -637,6 +808,7 @@ impl TaskPool {
             global_executor,
             external_executor,
             scope_executor,
+            instrumentation,
             spawned,
             scope: PhantomData,
             env: PhantomData,
@@ -651,12 +823,19 @@ impl TaskPool {
             return Vec::new();
         }
 
+        crate::cfg::debug! {
+            let wait = crate::watchdog::Wait::new(crate::watchdog::WaitKind::Scope);
+        }
+
         // block utils all tasks are finished.
         block_on(async move {
             let get_results = async {
                 let mut results = Vec::with_capacity(spawned.len());
                 while let Some(task) = spawned.pop() {
                     if let Some(res) = task.await {
+                        crate::cfg::debug! {
+                            wait.progress();
+                        }
                         match res {
                             Ok(res) => results.push(res),
                             Err(payload) => std::panic::resume_unwind(payload),
@@ -747,6 +926,7 @@ impl Default for TaskPool {
 impl Drop for TaskPool {
     fn drop(&mut self) {
         self.shutdown_tx.close();
+        self.blocking.shutdown();
 
         let panicking = thread::panicking();
 
@@ -761,6 +941,110 @@ impl Drop for TaskPool {
     }
 }
 
+// -----------------------------------------------------------------------------
+// PinnedTaskPool
+
+/// A task pool with exactly one worker thread, pinned to a logical core.
+///
+/// Tasks spawned on it never run on another thread, so they never migrate
+/// cores, e.g. for real-time audio mixing or a render thread. Pinning is done
+/// with [`vc_os::thread::set_affinity`], where it is not supported the thread
+/// still exists but may migrate.
+///
+/// It is created by [`TaskPoolBuilder::build_pinned`], which also sets the
+/// thread name and stack size. Unlike [`TaskPool`], it has no scopes, since
+/// the calling thread would help running the tasks.
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+/// use vc_task::{TaskPoolBuilder, block_on};
+///
+/// let pool = TaskPoolBuilder::new()
+///     .thread_name("audio".to_string())
+///     .build_pinned(0);
+///
+/// // The future is created on the pinned thread, so it may be `!Send`.
+/// let task = pool.spawn_local(|| async {
+///     let mixer = Rc::new(7);
+///     *mixer * 6
+/// });
+///
+/// assert_eq!(block_on(task), 42);
+/// ```
+#[derive(Debug)]
+pub struct PinnedTaskPool {
+    pool: TaskPool,
+}
+
+impl PinnedTaskPool {
+    #[inline]
+    fn new(pool: TaskPool) -> Self {
+        debug_assert_eq!(pool.threads.len(), 1);
+        Self { pool }
+    }
+
+    /// Returns the pinned thread.
+    #[inline]
+    pub fn thread(&self) -> &thread::Thread {
+        self.pool.threads[0].thread()
+    }
+
+    /// Spawns a `'static` future onto the pinned thread.
+    ///
+    /// See [`TaskPool::spawn`].
+    #[inline]
+    pub fn spawn<T: Send + 'static>(
+        &self,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Task<T> {
+        self.pool.spawn(future)
+    }
+
+    /// Spawns a `'static` future onto the pinned thread with a label.
+    ///
+    /// See [`TaskPool::spawn_labeled`].
+    #[inline]
+    pub fn spawn_labeled<T: Send + 'static>(
+        &self,
+        label: &'static str,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Task<T> {
+        self.pool.spawn_labeled(label, future)
+    }
+
+    /// Creates a future on the pinned thread and spawns it on the local executor
+    /// of that thread, so the future itself does not need to be `Send`.
+    ///
+    /// This is the owned handle to the local executor of the pinned thread,
+    /// see [`TaskPool::spawn_local`].
+    pub fn spawn_local<T, F, Fut>(&self, f: F) -> Task<T>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = T> + 'static,
+    {
+        let instrumentation = self.pool.instrumentation.clone();
+        // Only the inner task is instrumented, the outer one just forwards it.
+        Task(self.pool.executor.spawn(async move {
+            let future = instrument(instrumentation, None, f());
+            LOCAL_EXECUTOR.with(|ex| ex.spawn(future)).await
+        }))
+    }
+
+    /// Runs a blocking closure on a dedicated thread, not the pinned one.
+    ///
+    /// See [`TaskPool::spawn_blocking`].
+    #[inline]
+    pub fn spawn_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Task<T> {
+        self.pool.spawn_blocking(f)
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Scope
 
@@ -773,6 +1057,7 @@ pub struct Scope<'scope, 'env: 'scope, T> {
     global_executor: &'scope GlobalExecutor<'scope>,
     external_executor: &'scope ScopeExecutor<'scope>,
     scope_executor: &'scope ScopeExecutor<'scope>,
+    instrumentation: &'scope Instrumentation,
     spawned: &'scope ListQueue<FallibleTask<Result<T, Box<dyn Any + Send>>>>,
     // make `Scope` invariant over 'scope and 'env
     scope: PhantomData<&'scope mut &'scope ()>,
@@ -798,6 +1083,21 @@ impl<'scope, 'env, T: Send + 'scope> Scope<'scope, 'env, T> {
     /// For futures that should run on the same thread as the scope, use
     /// [`Scope::spawn_on_scope`] instead.
     pub fn spawn<Fut: Future<Output = T> + 'scope + Send>(&self, f: Fut) {
+        let f = instrument(self.instrumentation.clone(), None, f);
+        let task = self
+            .global_executor
+            .spawn(AssertUnwindSafe(f).catch_unwind())
+            .fallible();
+
+        self.spawned.push(task);
+    }
+
+    /// Spawns a scoped future onto the task pool with a label.
+    ///
+    /// This is identical to [`Scope::spawn`], except that the label is
+    /// reported to the [`TaskInstrumentation`] of the pool.
+    pub fn spawn_labeled<Fut: Future<Output = T> + 'scope + Send>(&self, label: &'static str, f: Fut) {
+        let f = instrument(self.instrumentation.clone(), Some(label), f);
         let task = self
             .global_executor
             .spawn(AssertUnwindSafe(f).catch_unwind())
@@ -816,6 +1116,7 @@ impl<'scope, 'env, T: Send + 'scope> Scope<'scope, 'env, T> {
     ///
     /// Prefer [`Scope::spawn`] unless the future must run on the scope's thread.
     pub fn spawn_on_scope<Fut: Future<Output = T> + 'scope + Send>(&self, f: Fut) {
+        let f = instrument(self.instrumentation.clone(), None, f);
         let task = self
             .scope_executor
             .spawn(AssertUnwindSafe(f).catch_unwind())
@@ -824,6 +1125,29 @@ impl<'scope, 'env, T: Send + 'scope> Scope<'scope, 'env, T> {
         self.spawned.push(task);
     }
 
+    /// Spawns a scoped `!Send` future onto the thread where the scope is running.
+    ///
+    /// This is [`Scope::spawn_on_scope`] for futures that must never leave the
+    /// scope's thread, e.g. futures holding graphics contexts. As the future
+    /// cannot be sent, it must also be spawned from that thread, usually
+    /// directly in the closure passed to [`TaskPool::scope`].
+    ///
+    /// The scope must outlive the future. The future's result will be included
+    /// in the vector returned by [`TaskPool::scope`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from a thread other than the scope's thread.
+    pub fn spawn_local<Fut: Future<Output = T> + 'scope>(&self, f: Fut) {
+        let f = instrument(self.instrumentation.clone(), None, f);
+        let task = self
+            .scope_executor
+            .spawn_local(AssertUnwindSafe(f).catch_unwind())
+            .fallible();
+
+        self.spawned.push(task);
+    }
+
     /// Spawns a scoped future onto the thread of an external executor.
     ///
     /// Submits the task to the specified `ScopeExecutor`. If that executor
@@ -838,6 +1162,7 @@ impl<'scope, 'env, T: Send + 'scope> Scope<'scope, 'env, T> {
     ///
     /// Prefer [`Scope::spawn`] unless the future must run on the external thread.
     pub fn spawn_on_external<Fut: Future<Output = T> + 'scope + Send>(&self, f: Fut) {
+        let f = instrument(self.instrumentation.clone(), None, f);
         let task = self
             .external_executor
             .spawn(AssertUnwindSafe(f).catch_unwind())
@@ -860,3 +1185,56 @@ where
     }
 }
 
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::rc::Rc;
+    use alloc::vec;
+
+    use std::thread;
+
+    use futures_lite::future::yield_now;
+
+    use super::{TaskPool, TaskPoolBuilder};
+    use crate::block_on;
+
+    #[test]
+    fn scope_spawn_local() {
+        let pool = TaskPool::new();
+        let scope_thread = thread::current().id();
+
+        let mut results = pool.scope(|scope| {
+            scope.spawn(async { 1 });
+            scope.spawn_local(async move {
+                // Held across an await point, so the future is `!Send`.
+                let value = Rc::new(2);
+                yield_now().await;
+                assert_eq!(thread::current().id(), scope_thread);
+                *value
+            });
+        });
+
+        results.sort_unstable();
+        assert_eq!(results, vec![1, 2]);
+    }
+
+    #[test]
+    fn pinned_task_pool() {
+        let pool = TaskPoolBuilder::new()
+            .thread_name("pinned".into())
+            .build_pinned(0);
+        assert_eq!(pool.thread().name(), Some("pinned (0)"));
+
+        let pinned = pool.thread().id();
+        let spawned = block_on(pool.spawn(async { thread::current().id() }));
+        let local = block_on(pool.spawn_local(|| async {
+            let value = Rc::new(thread::current().id());
+            yield_now().await;
+            *value
+        }));
+        assert_eq!(spawned, pinned);
+        assert_eq!(local, pinned);
+    }
+}
@@ -23,6 +23,17 @@ pub use task_pool::{Scope, TaskPool, TaskPoolBuilder};
 
 pub use futures_lite::future::block_on;
 
+crate::cfg::debug! {
+    use alloc::vec::Vec;
+
+    use crate::watchdog::ExecutorState;
+
+    /// Collects the queue of the local executor, for the watchdog.
+    pub(crate) fn executor_states(states: &mut Vec<ExecutorState>) {
+        states.push(task_pool::executor_state());
+    }
+}
+
 // -----------------------------------------------------------------------------
 // task_pools
 
@@ -53,18 +64,3 @@ taskpool! {
     /// See [`TaskPool`] documentation for details on Bevy tasks.
     (IO_TASK_POOL, IoTaskPool)
 }
-
-/// A function used to tick the global tasks pools on the main thread.
-/// This will run a maximum of 100 local tasks per executor per call to this function.
-///
-/// # Warning
-///
-/// This function *must* be called on the main thread, or the task pools will not be updated appropriately.
-/// 
-/// # Behavior
-/// 
-/// In wasm, this function do nothing.
-pub fn tick_local_executor_on_main_thread() {
-    // do-nothing
-}
-
@@ -1,26 +1,34 @@
+use core::fmt;
 use core::marker::PhantomData;
 
 use async_task::Task;
 
+use super::LocalExecutor;
+
 // -----------------------------------------------------------------------------
 // Scope Executor
 
-/// This is a dummy struct for wasm support to provide
-/// the same api as with the multithreaded task pool.
+/// An executor for scoped tasks or tasks sent to a specific thread.
 ///
-/// # Panics
-/// Panic if call it's spawn or tick function.
-#[derive(Debug)]
-pub struct ScopeExecutor<'a> {
-    _marker: PhantomData<&'a ()>,
+/// Browsers run all tasks on the main thread, so this is a thin wrapper of a
+/// single-threaded executor that provides the same API as the multithreaded
+/// task pool. Tasks only make progress when the executor is ticked, which
+/// [`TaskPool::scope_with_executor`] does for its tasks.
+///
+/// [`TaskPool::scope_with_executor`]: super::TaskPool::scope_with_executor
+pub struct ScopeExecutor<'task> {
+    executor: LocalExecutor<'task>,
 }
 
+#[expect(unsafe_code, reason = "Assuming it's single threaded environment.")]
+unsafe impl Send for ScopeExecutor<'_> {}
+#[expect(unsafe_code, reason = "Assuming it's single threaded environment.")]
+unsafe impl Sync for ScopeExecutor<'_> {}
+
 impl<'task> Default for ScopeExecutor<'task> {
     #[inline(always)]
     fn default() -> Self {
-        Self {
-            _marker: PhantomData,
-        }
+        Self::new()
     }
 }
 
@@ -29,31 +37,34 @@ impl<'task> ScopeExecutor<'task> {
     #[inline(always)]
     pub fn new() -> Self {
         Self {
-            _marker: PhantomData,
+            executor: LocalExecutor::new(),
         }
     }
 
-    /// Spawn a task on the thread executor
+    /// Spawns a task onto this executor.
+    ///
+    /// Task execution only begins once the executor is ticked.
     ///
     /// # Panics
     ///
-    /// Panic if this function be used in `wasm` env.
-    #[deprecated = "`ScopeExecutor` cannot be used in wasm env."]
+    /// Panics during task execution are propagated to the returned [`Task`].
+    #[inline]
     pub fn spawn<T: Send + 'task>(
         &self,
-        _future: impl Future<Output = T> + Send + 'task,
+        future: impl Future<Output = T> + Send + 'task,
     ) -> Task<T> {
-        unreachable!("`ScopeExecutor` cannot be used in wasm env.")
+        self.executor.spawn(future)
     }
 
-    /// Gets the [`ScopeExecutorTicker`] for this executor.
-    ///
-    /// # Panics
+    /// Returns a ticker that can drive this executor.
     ///
-    /// Panic if this function be used in `wasm` env.
-    #[deprecated = "`ScopeExecutor` cannot be used in wasm env."]
+    /// There is a single thread in browsers, so this always returns `Some`.
+    #[inline]
     pub fn ticker<'ticker>(&'ticker self) -> Option<ScopeExecutorTicker<'task, 'ticker>> {
-        unreachable!("`ScopeExecutor` cannot be used in wasm env.")
+        Some(ScopeExecutorTicker {
+            executor: self,
+            _marker: PhantomData,
+        })
     }
 
     /// Returns true if `self` and `other`'s executor is same.
@@ -63,6 +74,14 @@ impl<'task> ScopeExecutor<'task> {
     }
 }
 
+impl fmt::Debug for ScopeExecutor<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScopeExecutor")
+            .field("executor", &self.executor)
+            .finish()
+    }
+}
+
 // -----------------------------------------------------------------------------
 // ScopeExecutorTicker
 
@@ -70,35 +89,27 @@ impl<'task> ScopeExecutor<'task> {
 ///
 /// The executor does not make progress unless it is
 /// manually ticked on the thread it was created on.
-///
-/// Cannot be used in `wasm` env.
 #[derive(Debug)]
 pub struct ScopeExecutorTicker<'task, 'ticker> {
-    _executor: &'ticker ScopeExecutor<'task>,
+    executor: &'ticker ScopeExecutor<'task>,
     // make type not send or sync
     _marker: PhantomData<*const ()>,
 }
 
 impl<'task, 'ticker> ScopeExecutorTicker<'task, 'ticker> {
-    /// Tick the thread executor.
+    /// Polls and executes a single task asynchronously.
     ///
-    /// # Panics
-    ///
-    /// Panic if this function be used in `wasm` env.
-    #[deprecated = "`ScopeExecutor` cannot be used in wasm env."]
+    /// If no task is available, this waits until a task is scheduled.
+    #[inline]
     pub async fn tick(&self) {
-        unreachable!("`ScopeExecutor` cannot be used in wasm env.")
+        self.executor.executor.tick().await;
     }
 
     /// Synchronously try to tick a task on the executor.
     ///
     /// Returns false if does not find a task to tick.
-    ///
-    /// # Panics
-    ///
-    /// Panic if this function be used in `wasm` env.
-    #[deprecated = "`ScopeExecutor` cannot be used in wasm env."]
+    #[inline]
     pub fn try_tick(&self) -> bool {
-        unreachable!("`ScopeExecutor` cannot be used in wasm env.")
+        self.executor.executor.try_tick()
     }
 }
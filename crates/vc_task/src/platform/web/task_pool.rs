@@ -10,21 +10,27 @@ use core::mem;
 use vc_os::sync::Arc;
 
 use super::LocalExecutor;
+use crate::instrument::{Instrumentation, TaskInstrumentation, instrument};
+
 use super::ScopeExecutor;
-use super::{block_on, Task};
+use super::Task;
 
 // -----------------------------------------------------------------------------
 // TaskPoolBuilder
 
 /// Used to create a [`TaskPool`].
 #[derive(Default)]
-pub struct TaskPoolBuilder {}
+pub struct TaskPoolBuilder {
+    instrumentation: Instrumentation,
+}
 
 impl TaskPoolBuilder {
     /// Creates a new `TaskPoolBuilder` instance
     #[inline(always)]
     pub const fn new() -> Self {
-        Self{}
+        Self {
+            instrumentation: None,
+        }
     }
 
     /// No op on the single threaded task pool
@@ -57,10 +63,27 @@ impl TaskPoolBuilder {
         self
     }
 
+    /// No op on the single threaded task pool
+    #[inline(always)]
+    pub fn max_blocking_threads(self, _max_blocking_threads: usize) -> Self {
+        self
+    }
+
+    /// Sets the hooks invoked over the lifecycle of every task spawned on the pool.
+    ///
+    /// See [`TaskInstrumentation`] for details.
+    #[inline]
+    pub fn instrumentation(mut self, hooks: impl TaskInstrumentation) -> Self {
+        self.instrumentation = Some(Arc::new(hooks));
+        self
+    }
+
     /// Creates a new [`TaskPool`]
     #[inline(always)]
     pub fn build(self) -> TaskPool {
-        TaskPool {}
+        TaskPool {
+            instrumentation: self.instrumentation,
+        }
     }
 }
 
@@ -69,6 +92,38 @@ impl TaskPoolBuilder {
 
 std::thread_local! {
     static LOCAL_EXECUTOR: LocalExecutor<'static> = const { LocalExecutor::new() };
+    static LOCAL_EXECUTOR_DRIVEN: Cell<bool> = const { Cell::new(false) };
+}
+
+crate::cfg::debug! {
+    use crate::watchdog::ExecutorState;
+
+    pub(super) fn executor_state() -> ExecutorState {
+        ExecutorState {
+            name: String::from("TaskPool"),
+            queued: LOCAL_EXECUTOR.with(LocalExecutor::len),
+            workers: Vec::new(),
+        }
+    }
+}
+
+/// Runs `f` with the local executor of this thread.
+///
+/// On first use, a future running the executor is handed to the JS event loop,
+/// so that local tasks are polled in microtasks without manual ticking.
+#[expect(unsafe_code, reason = "Required to transmute lifetimes.")]
+fn with_driven_local_executor<R>(f: impl FnOnce(&LocalExecutor<'static>) -> R) -> R {
+    use vc_os::exports::wasm_bindgen_futures::spawn_local;
+
+    LOCAL_EXECUTOR.with(|executor| {
+        if !LOCAL_EXECUTOR_DRIVEN.replace(true) {
+            // SAFETY: The browser's main thread never exits, so the thread local
+            // outlives the driver.
+            let executor: &'static LocalExecutor<'static> = unsafe { mem::transmute(executor) };
+            spawn_local(executor.run(core::future::pending::<()>()));
+        }
+        f(executor)
+    })
 }
 
 // -----------------------------------------------------------------------------
@@ -79,13 +134,15 @@ std::thread_local! {
 /// Tasks are futures that are being automatically driven by the pool
 /// on threads owned by the pool. In this case - main thread only.
 #[derive(Debug, Default)]
-pub struct TaskPool {}
+pub struct TaskPool {
+    instrumentation: Instrumentation,
+}
 
 impl TaskPool {
     /// Create a `TaskPool` with the default configuration.
     #[inline(always)]
     pub fn new() -> Self {
-        TaskPool {}
+        TaskPoolBuilder::new().build()
     }
 
     /// Return the number of threads owned by the task pool
@@ -101,6 +158,9 @@ impl TaskPool {
     /// Typically used to tick the local executor on the
     /// main thread as it needs to share time with other things.
     ///
+    /// In browsers, the local executor is also driven by the JS event loop,
+    /// so spawned tasks make progress in microtasks even if it's never ticked.
+    ///
     /// ```ignore
     /// use vc_task::TaskPool;
     ///
@@ -112,7 +172,7 @@ impl TaskPool {
     where
         F: FnOnce(&LocalExecutor) -> R,
     {
-        LOCAL_EXECUTOR.with(f)
+        with_driven_local_executor(f)
     }
 
     /// Just create a new `ScopeExecutor` for wasm
@@ -146,11 +206,24 @@ impl TaskPool {
     /// returning.
     ///
     /// This is similar to `rayon::scope` and `crossbeam::scope`
+    ///
+    /// # Behavior
+    ///
+    /// The browser's event loop cannot be blocked, so the scope ticks its tasks
+    /// synchronously until all of them are completed. `thread_executor` is ticked
+    /// as well, and the local executor if `tick_task_pool_executor` is `true`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scoped tasks are still pending but none of the executors
+    /// has a task to run, e.g. a task is waiting for a browser event or timer,
+    /// which can never fire while the scope blocks the main thread. Use
+    /// [`TaskPool::spawn`] for such work instead.
     #[expect(unsafe_code, reason = "Required to transmute lifetimes.")]
     pub fn scope_with_executor<'env, F, T>(
         &self,
-        _tick_task_pool_executor: bool,
-        _thread_executor: Option<&ScopeExecutor>,
+        tick_task_pool_executor: bool,
+        thread_executor: Option<&ScopeExecutor>,
         f: F,
     ) -> Vec<T>
     where
@@ -181,10 +254,14 @@ impl TaskPool {
         // SAFETY: As above, all futures must complete in this function so we can change the lifetime
         let pending_tasks: &'env Cell<usize> = unsafe { mem::transmute(&pending_tasks) };
 
+        // SAFETY: As above, all futures must complete in this function so we can change the lifetime
+        let instrumentation: &'env Instrumentation = unsafe { mem::transmute(&self.instrumentation) };
+
         let mut scope = Scope {
             executor_ref,
             pending_tasks,
             results_ref,
+            instrumentation,
             scope: PhantomData,
             env: PhantomData,
         };
@@ -194,12 +271,25 @@ impl TaskPool {
 
         f(scope_ref);
 
-        // Wait until the scope is complete
-        block_on(executor.run(async {
-            while pending_tasks.get() != 0 {
-                futures_lite::future::yield_now().await;
+        // Tick until the scope is complete
+        let thread_ticker = thread_executor.and_then(ScopeExecutor::ticker);
+        while pending_tasks.get() != 0 {
+            let mut progress = executor.try_tick();
+            if let Some(ticker) = &thread_ticker {
+                progress |= ticker.try_tick();
             }
-        }));
+            if tick_task_pool_executor {
+                progress |= LOCAL_EXECUTOR.with(LocalExecutor::try_tick);
+            }
+            if !progress {
+                panic!(
+                    "{} scoped task(s) can never complete: they are waiting for \
+                    an event that cannot happen while the scope blocks the main \
+                    thread, use `TaskPool::spawn` instead",
+                    pending_tasks.get(),
+                );
+            }
+        }
 
         results
             .take()
@@ -226,7 +316,31 @@ impl TaskPool {
     where
         T: 'static/* + Send */
     {
-        Task::wrap_future(future)
+        Task::wrap_future(instrument(self.instrumentation.clone(), None, future))
+    }
+
+    /// Spawns a static future onto the thread pool with a label.
+    ///
+    /// This is identical to [`TaskPool::spawn`], except that the label is
+    /// reported to the [`TaskInstrumentation`] of the pool.
+    pub fn spawn_labeled<T>(
+        &self,
+        label: &'static str,
+        future: impl Future<Output = T> + 'static/* + Send */,
+    ) -> Task<T> 
+    where
+        T: 'static/* + Send */
+    {
+        Task::wrap_future(instrument(self.instrumentation.clone(), Some(label), future))
+    }
+
+    /// Runs a blocking closure and returns its result as a [`Task`].
+    ///
+    /// There are no threads to offload to, so the closure runs inline,
+    /// blocking the current thread.
+    pub fn spawn_blocking<T: 'static>(&self, f: impl FnOnce() -> T + Send + 'static) -> Task<T> {
+        let value = f();
+        Task::wrap_future(instrument(self.instrumentation.clone(), None, async move { value }))
     }
 
     /// Spawns a static future on the JS event loop.
@@ -236,7 +350,7 @@ impl TaskPool {
         &self,
         future: impl Future<Output = T> + 'static,
     ) -> Task<T> {
-        Task::wrap_future(future)
+        Task::wrap_future(instrument(self.instrumentation.clone(), None, future))
     }
 }
 
@@ -253,6 +367,8 @@ pub struct Scope<'scope, 'env: 'scope, T> {
     pending_tasks: &'scope Cell<usize>,
     // Vector to gather results of all futures spawned during scope run
     results_ref: &'env RefCell<Vec<Option<T>>>,
+    // Task lifecycle hooks of the pool
+    instrumentation: &'scope Instrumentation,
 
     // make `Scope` invariant over 'scope and 'env
     scope: PhantomData<&'scope mut &'scope ()>,
@@ -303,6 +419,32 @@ impl<'scope, 'env, T: Send + 'env> Scope<'scope, 'env, T> {
     ///
     /// For more information, see [`TaskPool::scope`].
     pub fn spawn_on_scope<Fut: Future<Output = T> + 'scope>(&self, f: Fut) {
+        self.spawn_inner(None, f);
+    }
+
+    /// Spawns a scoped `!Send` future onto the executor.
+    ///
+    /// The scope *must* outlive the provided future. The results of the future
+    /// will be returned as a part of [`TaskPool::scope`]'s return value.
+    ///
+    /// On the single threaded task pool, it just calls [`Scope::spawn_on_scope`].
+    ///
+    /// For more information, see [`TaskPool::scope`].
+    pub fn spawn_local<Fut: Future<Output = T> + 'scope>(&self, f: Fut) {
+        self.spawn_on_scope(f);
+    }
+
+    /// Spawns a scoped future onto the executor with a label.
+    ///
+    /// This is identical to [`Scope::spawn`], except that the label is
+    /// reported to the [`TaskInstrumentation`] of the pool.
+    pub fn spawn_labeled<Fut: Future<Output = T> + 'scope>(&self, label: &'static str, f: Fut) {
+        self.spawn_inner(Some(label), f);
+    }
+
+    fn spawn_inner<Fut: Future<Output = T> + 'scope>(&self, label: Option<&'static str>, f: Fut) {
+        let f = instrument(self.instrumentation.clone(), label, f);
+
         // increment the number of pending tasks
         let pending_tasks = self.pending_tasks;
         pending_tasks.update(|i| i + 1);
@@ -337,29 +479,26 @@ impl<'scope, 'env, T: Send + 'env> Scope<'scope, 'env, T> {
 
 #[cfg(all(test, feature = "web"))]
 mod test {
-    use std::{time, thread};
+    use alloc::vec;
 
     use super::*;
 
-    /// This test creates a scope with a single task that goes to sleep for a
-    /// nontrivial amount of time. At one point, the scope would (incorrectly)
-    /// return early under these conditions, causing a crash.
-    ///
-    /// The correct behavior is for the scope to block until the receiver is
-    /// woken by the external thread.
     #[test]
     fn scoped_spawn() {
-        let (sender, receiver) = async_channel::unbounded();
-        let task_pool = TaskPool {};
-        let _thread = thread::spawn(move || {
-            let duration = time::Duration::from_millis(50);
-            thread::sleep(duration);
-            let _ = sender.send(0);
-        });
-        task_pool.scope(|scope| {
-            scope.spawn(async {
-                receiver.recv().await
+        let task_pool = TaskPool::new();
+        let thread_executor = ScopeExecutor::new();
+        let mut outputs = task_pool.scope_with_executor(true, Some(&thread_executor), |scope| {
+            let (sender, receiver) = async_channel::unbounded();
+            scope.spawn(async move {
+                receiver.recv().await.unwrap()
+            });
+            scope.spawn_on_external(async move {
+                futures_lite::future::yield_now().await;
+                sender.send(1).await.unwrap();
+                2
             });
         });
+        outputs.sort();
+        assert_eq!(outputs, vec![1, 2]);
     }
 }
@@ -0,0 +1,452 @@
+//! Detection of blocking calls that stopped making progress.
+//!
+//! With the `debug` feature, every [`block_on`] and [`TaskPool::scope`] call
+//! is watched. When one of them makes no progress for [`timeout`], a
+//! [`StallReport`] is passed to the handler set by [`set_handler`], which logs
+//! it as a warning by default. Each stall is reported once, until the call
+//! makes progress again.
+//!
+//! - A `block_on` call makes progress when its future is woken.
+//! - A `scope` call makes progress when one of its tasks completes.
+//!
+//! In multi-threaded mode, a background thread checks the calls periodically.
+//! In single-threaded mode, the blocked call checks itself while polling, which
+//! relies on [`Instant`], so `no_std` targets must configure a time source.
+//!
+//! A long-running task also counts as a stall, the timeout should be larger
+//! than the slowest expected task.
+//!
+//! [`block_on`]: crate::block_on
+//! [`TaskPool::scope`]: crate::TaskPool::scope
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::task::Wake;
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::{Future, poll_fn};
+use core::pin::pin;
+use core::task::{Context, Waker};
+use core::time::Duration;
+
+use vc_os::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use vc_os::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use vc_os::time::Instant;
+
+use crate::TaskInfo;
+
+// -----------------------------------------------------------------------------
+// Config
+
+/// The default of [`timeout`], 10 seconds.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+static TIMEOUT_MS: AtomicU64 = AtomicU64::new(DEFAULT_TIMEOUT.as_millis() as u64);
+
+/// Returns the duration without progress after which a call is reported.
+#[inline]
+pub fn timeout() -> Duration {
+    Duration::from_millis(TIMEOUT_MS.load(Ordering::Relaxed))
+}
+
+/// Sets the duration without progress after which a call is reported.
+///
+/// The timeout has a resolution of milliseconds.
+pub fn set_timeout(timeout: Duration) {
+    let ms = u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX);
+    TIMEOUT_MS.store(ms.max(1), Ordering::Relaxed);
+
+    // The watchdog thread may be sleeping for a quarter of the old timeout.
+    crate::cfg::multi_thread! {
+        if let Some(thread) = WATCHDOG.get() {
+            thread.unpark();
+        }
+    }
+}
+
+/// Sets the function receiving the [`StallReport`]s.
+///
+/// The handler runs on the watchdog thread in multi-threaded mode, and on
+/// the stalled thread otherwise. The default handler logs the report as a
+/// warning through [`vc_os::log`].
+pub fn set_handler(handler: fn(&StallReport)) {
+    registry().handler = handler;
+}
+
+fn log_report(report: &StallReport) {
+    vc_os::log::warn!("{report}");
+}
+
+// -----------------------------------------------------------------------------
+// StallReport
+
+/// The kind of a watched call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WaitKind {
+    /// A [`block_on`](crate::block_on) call.
+    BlockOn,
+    /// A [`TaskPool::scope`](crate::TaskPool::scope) call.
+    Scope,
+}
+
+/// A thread blocked in a watched call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaitState {
+    /// The kind of the call.
+    pub kind: WaitKind,
+    /// The name of the thread, `None` for unnamed threads or without `std`.
+    pub thread: Option<String>,
+    /// The time since the call last made progress.
+    pub idle: Duration,
+}
+
+/// The queues of a task pool executor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutorState {
+    /// The thread name of the task pool.
+    pub name: String,
+    /// The number of tasks in the shared queue.
+    pub queued: usize,
+    /// The worker threads, empty in single-threaded mode.
+    pub workers: Vec<WorkerState>,
+}
+
+/// A worker thread of a task pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkerState {
+    /// The number of tasks in the local queue of the worker.
+    pub queued: usize,
+    /// Whether the worker is waiting for tasks.
+    pub sleeping: bool,
+}
+
+/// The state of the task pools when a call stalled, see the [module docs](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StallReport {
+    /// The stalled call.
+    pub stalled: WaitState,
+    /// All watched calls, including the stalled one.
+    pub waits: Vec<WaitState>,
+    /// The spawned tasks that have not completed, in spawn order.
+    pub pending_tasks: Vec<TaskInfo>,
+    /// The executors of the task pools.
+    pub executors: Vec<ExecutorState>,
+}
+
+impl fmt::Display for WaitState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let thread = self.thread.as_deref().unwrap_or("<unnamed>");
+        write!(f, "{:?} on thread `{thread}`, idle for {:?}", self.kind, self.idle)
+    }
+}
+
+impl fmt::Display for StallReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "no progress in {}", self.stalled)?;
+
+        writeln!(f, "waiting threads:")?;
+        for wait in &self.waits {
+            writeln!(f, "  {wait}")?;
+        }
+
+        writeln!(f, "pending tasks: {}", self.pending_tasks.len())?;
+        for task in &self.pending_tasks {
+            let label = task.label.unwrap_or("<unlabeled>");
+            writeln!(f, "  #{} {label}", task.id)?;
+        }
+
+        write!(f, "executors:")?;
+        for executor in &self.executors {
+            write!(f, "\n  `{}`: {} queued", executor.name, executor.queued)?;
+            for (index, worker) in executor.workers.iter().enumerate() {
+                let state = if worker.sleeping { "sleeping" } else { "working" };
+                write!(f, "\n    worker {index}: {state}, {} queued", worker.queued)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Registry
+
+struct Entry {
+    kind: WaitKind,
+    thread: Option<String>,
+    last_progress: Instant,
+    reported: bool,
+}
+
+struct Registry {
+    next_wait: u64,
+    waits: BTreeMap<u64, Entry>,
+    tasks: BTreeMap<u64, Option<&'static str>>,
+    handler: fn(&StallReport),
+}
+
+static REGISTRY: Mutex<Registry> = Mutex::new(Registry {
+    next_wait: 0,
+    waits: BTreeMap::new(),
+    tasks: BTreeMap::new(),
+    handler: log_report,
+});
+
+fn registry() -> MutexGuard<'static, Registry> {
+    REGISTRY.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Reports the calls that exceeded the timeout, or only `wait` if specified.
+fn check(wait: Option<u64>) {
+    let timeout = timeout();
+    let now = Instant::now();
+
+    let mut registry = registry();
+    let stalled: Vec<u64> = registry
+        .waits
+        .iter()
+        .filter(|&(&id, entry)| {
+            wait.is_none_or(|wait| wait == id)
+                && !entry.reported
+                && now.saturating_duration_since(entry.last_progress) >= timeout
+        })
+        .map(|(&id, _)| id)
+        .collect();
+    if stalled.is_empty() {
+        return;
+    }
+
+    let state = |entry: &Entry| WaitState {
+        kind: entry.kind,
+        thread: entry.thread.clone(),
+        idle: now.saturating_duration_since(entry.last_progress),
+    };
+    let waits = registry.waits.values().map(state).collect();
+    let mut reports = Vec::with_capacity(stalled.len());
+    for id in stalled {
+        let entry = registry.waits.get_mut(&id).unwrap();
+        entry.reported = true;
+        reports.push(state(entry));
+    }
+
+    let pending_tasks = registry
+        .tasks
+        .iter()
+        .map(|(&id, &label)| TaskInfo { id, label })
+        .collect();
+    let handler = registry.handler;
+    // The executors and the handler may spawn or wait themselves.
+    drop(registry);
+
+    let mut executors = Vec::new();
+    crate::platform::executor_states(&mut executors);
+
+    let mut report = StallReport {
+        stalled: reports[0].clone(),
+        waits,
+        pending_tasks,
+        executors,
+    };
+    for state in reports {
+        report.stalled = state;
+        handler(&report);
+    }
+}
+
+crate::cfg::multi_thread! {
+    /// The thread checking all calls, unparked by [`set_timeout`].
+    static WATCHDOG: std::sync::OnceLock<std::thread::Thread> = std::sync::OnceLock::new();
+
+    /// Starts the thread checking all calls, once.
+    fn spawn_watchdog() {
+        static SPAWN: std::sync::Once = std::sync::Once::new();
+
+        SPAWN.call_once(|| {
+            let spawned = std::thread::Builder::new()
+                .name("vc_task::watchdog".into())
+                .spawn(|| {
+                    loop {
+                        // The timeout is read again after `set_timeout` unparks the thread.
+                        std::thread::park_timeout(timeout() / 4);
+                        check(None);
+                    }
+                });
+            // Without the thread, stalls are still reported by polled calls.
+            if let Ok(handle) = spawned {
+                let _ = WATCHDOG.set(handle.thread().clone());
+            }
+        });
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Guards
+
+/// Keeps a spawned task in [`StallReport::pending_tasks`] until dropped.
+pub(crate) struct TaskGuard(u64);
+
+impl TaskGuard {
+    pub(crate) fn new(task: &TaskInfo) -> Self {
+        registry().tasks.insert(task.id, task.label);
+        Self(task.id)
+    }
+}
+
+impl Drop for TaskGuard {
+    fn drop(&mut self) {
+        registry().tasks.remove(&self.0);
+    }
+}
+
+/// A watched call, until dropped.
+pub(crate) struct Wait(u64);
+
+impl Wait {
+    pub(crate) fn new(kind: WaitKind) -> Self {
+        crate::cfg::multi_thread! {
+            spawn_watchdog();
+        }
+
+        let thread = crate::cfg::std! {
+            if {
+                std::thread::current().name().map(String::from)
+            } else {
+                None
+            }
+        };
+
+        let mut registry = registry();
+        let id = registry.next_wait;
+        registry.next_wait += 1;
+        registry.waits.insert(
+            id,
+            Entry {
+                kind,
+                thread,
+                last_progress: Instant::now(),
+                reported: false,
+            },
+        );
+        Self(id)
+    }
+
+    /// Records that the call made progress.
+    pub(crate) fn progress(&self) {
+        if let Some(entry) = registry().waits.get_mut(&self.0) {
+            entry.last_progress = Instant::now();
+            entry.reported = false;
+        }
+    }
+
+    /// Reports the call if it exceeded the timeout.
+    pub(crate) fn check(&self) {
+        check(Some(self.0));
+    }
+}
+
+impl Drop for Wait {
+    fn drop(&mut self) {
+        registry().waits.remove(&self.0);
+    }
+}
+
+// -----------------------------------------------------------------------------
+// block_on
+
+/// Forwards wakes to the waker of the blocked call, recording them.
+struct Signal {
+    woken: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl Wake for Signal {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::Release);
+        let waker = self.waker.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(waker) = waker.as_ref() {
+            waker.wake_by_ref();
+        }
+    }
+}
+
+/// Watches `future` as a call of `kind`, making progress whenever it is woken.
+pub(crate) async fn watch<F: Future>(kind: WaitKind, future: F) -> F::Output {
+    let wait = Wait::new(kind);
+    let signal = Arc::new(Signal {
+        woken: AtomicBool::new(false),
+        waker: Mutex::new(None),
+    });
+    let waker = Waker::from(signal.clone());
+    let mut future = pin!(future);
+
+    poll_fn(|cx| {
+        let mut outer = signal.waker.lock().unwrap_or_else(PoisonError::into_inner);
+        if !outer.as_ref().is_some_and(|outer| outer.will_wake(cx.waker())) {
+            *outer = Some(cx.waker().clone());
+        }
+        drop(outer);
+
+        if signal.woken.swap(false, Ordering::Acquire) {
+            wait.progress();
+        } else {
+            wait.check();
+        }
+        future.as_mut().poll(&mut Context::from_waker(&waker))
+    })
+    .await
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::vec::Vec;
+    use core::time::Duration;
+    use std::sync::Mutex;
+
+    use super::{DEFAULT_TIMEOUT, StallReport, WaitKind};
+    use crate::{TaskPool, block_on};
+
+    static REPORTS: Mutex<Vec<StallReport>> = Mutex::new(Vec::new());
+
+    /// Restores the global handler and timeout, even if the test fails.
+    struct ResetOnDrop;
+
+    impl Drop for ResetOnDrop {
+        fn drop(&mut self) {
+            super::set_handler(super::log_report);
+            super::set_timeout(DEFAULT_TIMEOUT);
+            REPORTS.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        }
+    }
+
+    #[test]
+    fn report_stalled_block_on() {
+        let _reset = ResetOnDrop;
+        super::set_handler(|report| REPORTS.lock().unwrap().push(report.clone()));
+        super::set_timeout(Duration::from_millis(50));
+
+        let pool = TaskPool::new();
+        let (sender, receiver) = async_channel::bounded::<()>(1);
+        let task = pool.spawn_labeled("stuck", async move { receiver.recv().await });
+
+        let _thread = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(500));
+            sender.send_blocking(()).unwrap();
+        });
+        block_on(task).unwrap();
+
+        let reports = REPORTS.lock().unwrap();
+        let report = reports
+            .iter()
+            .find(|r| r.pending_tasks.iter().any(|t| t.label == Some("stuck")))
+            .unwrap();
+        assert_eq!(report.stalled.kind, WaitKind::BlockOn);
+        assert!(report.stalled.idle >= Duration::from_millis(50));
+        assert!(!report.executors.is_empty());
+    }
+}
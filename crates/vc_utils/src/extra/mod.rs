@@ -16,4 +16,4 @@ pub use array_deque::ArrayDeque;
 pub use block_list::BlockList;
 pub use bloom_filter::BloomFilter;
 pub use page_pool::PagePool;
-pub use typeid_map::TypeIdMap;
+pub use typeid_map::{OccupiedTypeIdEntry, TypeIdEntry, TypeIdMap, VacantTypeIdEntry};
@@ -3,7 +3,7 @@ use core::fmt::Debug;
 
 use crate::hash::NoOpHashState;
 use crate::hash::hashbrown::HashMap;
-use crate::hash::hashbrown::hash_map::Entry;
+use crate::hash::hashbrown::hash_map::{self, Entry};
 
 // -----------------------------------------------------------------------------
 // TypeIdMap
@@ -46,6 +46,16 @@ impl<V> TypeIdMap<V> {
         Self(HashMap::with_capacity_and_hasher(capacity, NoOpHashState))
     }
 
+    /// Reserves capacity for at least `additional` more elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new allocation size overflows `usize`.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
     /// Shrinks the capacity of the map as much as possible.
     ///
     /// It will drop down as much as possible while maintaining the internal rules
@@ -82,12 +92,53 @@ impl<V> TypeIdMap<V> {
     /// The closure `f` is only called if the key is not present.
     #[inline]
     pub fn get_or_insert(&mut self, type_id: TypeId, f: impl FnOnce() -> V) -> &mut V {
+        self.entry(type_id).or_insert_with(f)
+    }
+
+    /// Gets a mutable reference to the value associated with the given type,
+    /// inserting the result of `f` if the type is not present.
+    ///
+    /// See [`get_or_insert`](Self::get_or_insert).
+    #[inline(always)]
+    pub fn get_or_insert_with<T: ?Sized + 'static>(&mut self, f: impl FnOnce() -> V) -> &mut V {
+        self.get_or_insert(TypeId::of::<T>(), f)
+    }
+
+    /// Gets the entry of the given key for in-place manipulation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::any::TypeId;
+    /// use vc_utils::extra::{TypeIdEntry, TypeIdMap};
+    ///
+    /// let mut map = TypeIdMap::<u32>::new();
+    /// *map.entry(TypeId::of::<u8>()).or_insert(0) += 1;
+    /// *map.entry(TypeId::of::<u8>()).or_insert(0) += 1;
+    /// assert_eq!(map.get_type::<u8>(), Some(&2));
+    ///
+    /// match map.entry_type::<u16>() {
+    ///     TypeIdEntry::Occupied(_) => unreachable!(),
+    ///     TypeIdEntry::Vacant(entry) => {
+    ///         entry.insert(7);
+    ///     }
+    /// }
+    /// assert_eq!(map.get_type::<u16>(), Some(&7));
+    /// ```
+    #[inline]
+    pub fn entry(&mut self, type_id: TypeId) -> TypeIdEntry<'_, V> {
         match self.0.entry(type_id) {
-            Entry::Vacant(entry) => entry.insert(f()),
-            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Occupied(entry) => TypeIdEntry::Occupied(OccupiedTypeIdEntry(entry)),
+            Entry::Vacant(entry) => TypeIdEntry::Vacant(VacantTypeIdEntry(entry)),
         }
     }
 
+    /// Gets the entry of the given type for in-place manipulation.
+    #[inline(always)]
+    pub fn entry_type<T: ?Sized + 'static>(&mut self) -> TypeIdEntry<'_, V> {
+        self.entry(TypeId::of::<T>())
+    }
+
     /// Returns a reference to the value corresponding to the type.
     pub fn get(&self, type_id: &TypeId) -> Option<&V> {
         self.0.get(type_id)
@@ -143,6 +194,14 @@ impl<V> TypeIdMap<V> {
         self.remove(&TypeId::of::<T>())
     }
 
+    /// Retains only the pairs specified by the predicate.
+    ///
+    /// Removes all pairs for which `f` returns `false`, in arbitrary order.
+    #[inline]
+    pub fn retain(&mut self, mut f: impl FnMut(&TypeId, &mut V) -> bool) {
+        self.0.retain(|type_id, v| f(type_id, v));
+    }
+
     /// Clears the map, removing all key-value pairs.
     ///
     /// Keeps the allocated memory for reuse.
@@ -239,3 +298,206 @@ impl<T: Debug> Debug for TypeIdMap<T> {
         Debug::fmt(&self.0, f)
     }
 }
+
+impl<V> FromIterator<(TypeId, V)> for TypeIdMap<V> {
+    fn from_iter<I: IntoIterator<Item = (TypeId, V)>>(iter: I) -> Self {
+        Self(HashMap::from_iter(iter))
+    }
+}
+
+impl<V> Extend<(TypeId, V)> for TypeIdMap<V> {
+    fn extend<I: IntoIterator<Item = (TypeId, V)>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<V> IntoIterator for TypeIdMap<V> {
+    type Item = (TypeId, V);
+    type IntoIter = hash_map::IntoIter<TypeId, V>;
+
+    /// Creates a consuming iterator visiting all pairs in arbitrary order.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// TypeIdEntry
+
+/// A view into a single entry of a [`TypeIdMap`], which may be vacant or occupied.
+///
+/// Created by [`TypeIdMap::entry`] and [`TypeIdMap::entry_type`].
+pub enum TypeIdEntry<'a, V> {
+    /// An occupied entry.
+    Occupied(OccupiedTypeIdEntry<'a, V>),
+    /// A vacant entry.
+    Vacant(VacantTypeIdEntry<'a, V>),
+}
+
+/// A view into an occupied entry of a [`TypeIdMap`].
+pub struct OccupiedTypeIdEntry<'a, V>(hash_map::OccupiedEntry<'a, TypeId, V, NoOpHashState>);
+
+/// A view into a vacant entry of a [`TypeIdMap`].
+pub struct VacantTypeIdEntry<'a, V>(hash_map::VacantEntry<'a, TypeId, V, NoOpHashState>);
+
+impl<'a, V> TypeIdEntry<'a, V> {
+    /// Returns the key of this entry.
+    #[inline]
+    pub fn key(&self) -> &TypeId {
+        match self {
+            Self::Occupied(entry) => entry.key(),
+            Self::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting `default` if empty,
+    /// and returns a mutable reference to the value.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Ensures a value is in the entry by inserting the result of `f` if empty,
+    /// and returns a mutable reference to the value.
+    #[inline]
+    pub fn or_insert_with(self, f: impl FnOnce() -> V) -> &'a mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(f()),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the default value if empty,
+    /// and returns a mutable reference to the value.
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Calls `f` with the value if the entry is occupied.
+    #[inline]
+    pub fn and_modify(mut self, f: impl FnOnce(&mut V)) -> Self {
+        if let Self::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, V> OccupiedTypeIdEntry<'a, V> {
+    /// Returns the key of this entry.
+    #[inline]
+    pub fn key(&self) -> &TypeId {
+        self.0.key()
+    }
+
+    /// Returns a reference to the value.
+    #[inline]
+    pub fn get(&self) -> &V {
+        self.0.get()
+    }
+
+    /// Returns a mutable reference to the value.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V {
+        self.0.get_mut()
+    }
+
+    /// Converts the entry into a mutable reference to the value,
+    /// with the lifetime of the map.
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        self.0.into_mut()
+    }
+
+    /// Replaces the value, returning the old one.
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V {
+        self.0.insert(value)
+    }
+
+    /// Removes the entry from the map, returning its value.
+    #[inline]
+    pub fn remove(self) -> V {
+        self.0.remove()
+    }
+}
+
+impl<'a, V> VacantTypeIdEntry<'a, V> {
+    /// Returns the key of this entry.
+    #[inline]
+    pub fn key(&self) -> &TypeId {
+        self.0.key()
+    }
+
+    /// Inserts the value into the map, returning a mutable reference to it.
+    #[inline]
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.0.insert(value)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::{TypeIdEntry, TypeIdMap};
+    use alloc::vec::Vec;
+    use core::any::TypeId;
+
+    #[test]
+    fn entry() {
+        let mut map = TypeIdMap::<u32>::new();
+
+        // `and_modify` only runs on occupied entries.
+        map.entry_type::<u8>().and_modify(|v| *v += 1).or_insert(1);
+        assert_eq!(map.get_type::<u8>(), Some(&1));
+        map.entry_type::<u8>().and_modify(|v| *v += 1).or_insert(1);
+        assert_eq!(map.get_type::<u8>(), Some(&2));
+
+        *map.entry_type::<u16>().or_default() += 5;
+        assert_eq!(map.get_type::<u16>(), Some(&5));
+        assert_eq!(*map.entry_type::<u16>().or_default(), 5);
+
+        match map.entry_type::<u16>() {
+            TypeIdEntry::Occupied(entry) => {
+                assert_eq!(entry.key(), &TypeId::of::<u16>());
+                assert_eq!(entry.remove(), 5);
+            }
+            TypeIdEntry::Vacant(_) => unreachable!(),
+        }
+        assert!(!map.contains_type::<u16>());
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn remove_retain() {
+        let mut map: TypeIdMap<u32> = [
+            (TypeId::of::<u8>(), 1),
+            (TypeId::of::<u16>(), 2),
+            (TypeId::of::<u32>(), 3),
+            (TypeId::of::<u64>(), 4),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(map.len(), 4);
+
+        assert_eq!(map.remove_type::<u8>(), Some(1));
+        assert_eq!(map.remove(&TypeId::of::<u8>()), None);
+
+        map.retain(|_, v| {
+            *v *= 10;
+            *v != 30
+        });
+        assert!(!map.contains_type::<u32>());
+
+        let mut values: Vec<u32> = map.values().copied().collect();
+        values.sort_unstable();
+        assert_eq!(values, [20, 40]);
+    }
+}
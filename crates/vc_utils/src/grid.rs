@@ -0,0 +1,92 @@
+//! Blocked iteration over 2D grids.
+//!
+//! Grid-based code (tilemaps, pathfinding, image-like buffers) often visits
+//! every cell of a row-major `width * height` grid. Visiting the grid tile by
+//! tile keeps neighbouring rows in cache, and gives independent units of work
+//! for parallel processing.
+//!
+//! - [`for_each_tile`]: Calls a function for each tile of the grid.
+//! - [`tile_count`]: Returns the number of tiles along each axis.
+//!
+//! With the `rayon` feature, [`par_for_each_tile`] processes the tiles in parallel.
+//!
+//! [`par_for_each_tile`]: crate::parallel::par_for_each_tile
+
+use core::ops::Range;
+
+/// Returns the number of tiles along the x and y axes.
+///
+/// Tiles on the right and bottom edges are shorter if `tile` does not
+/// divide `width` or `height`.
+///
+/// # Panics
+///
+/// Panics if `tile` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use vc_utils::grid::tile_count;
+///
+/// assert_eq!(tile_count(10, 4, 4), (3, 1));
+/// assert_eq!(tile_count(0, 4, 4), (0, 1));
+/// ```
+#[inline]
+pub const fn tile_count(width: usize, height: usize, tile: usize) -> (usize, usize) {
+    assert!(tile != 0, "tile size must be non-zero");
+    (width.div_ceil(tile), height.div_ceil(tile))
+}
+
+/// Returns the x and y ranges of the tile `index`, in row-major tile order.
+#[inline]
+pub(crate) fn tile_at(
+    width: usize,
+    height: usize,
+    tile: usize,
+    tiles_x: usize,
+    index: usize,
+) -> (Range<usize>, Range<usize>) {
+    let x = (index % tiles_x) * tile;
+    let y = (index / tiles_x) * tile;
+    (x..width.min(x + tile), y..height.min(y + tile))
+}
+
+/// Calls `f` with the x and y ranges of each `tile * tile` block of a
+/// `width * height` grid.
+///
+/// Tiles are visited in row-major order, and cover each cell exactly once.
+/// Tiles on the right and bottom edges are shorter if `tile` does not divide
+/// `width` or `height`.
+///
+/// # Panics
+///
+/// Panics if `tile` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use vc_utils::grid::for_each_tile;
+///
+/// let (width, height) = (5, 3);
+/// let mut cells = vec![0; width * height];
+///
+/// for_each_tile(width, height, 2, |xs, ys| {
+///     for y in ys {
+///         for x in xs.clone() {
+///             cells[y * width + x] += 1;
+///         }
+///     }
+/// });
+///
+/// assert!(cells.iter().all(|&n| n == 1));
+/// ```
+pub fn for_each_tile<F>(width: usize, height: usize, tile: usize, mut f: F)
+where
+    F: FnMut(Range<usize>, Range<usize>),
+{
+    let (tiles_x, tiles_y) = tile_count(width, height, tile);
+    for index in 0..tiles_x * tiles_y {
+        let (xs, ys) = tile_at(width, height, tile, tiles_x, index);
+        f(xs, ys);
+    }
+}
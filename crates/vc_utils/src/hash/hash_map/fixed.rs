@@ -262,6 +262,36 @@ where
     }
 }
 
+crate::cfg::rayon! {
+    impl<K, V, S, X> rayon::iter::ParallelExtend<X> for HashMap<K, V, S>
+    where
+        hb::HashMap<K, V, S>: rayon::iter::ParallelExtend<X>,
+        X: Send,
+    {
+        #[inline(always)]
+        fn par_extend<I>(&mut self, par_iter: I)
+        where
+            I: rayon::iter::IntoParallelIterator<Item = X>,
+        {
+            self.0.par_extend(par_iter);
+        }
+    }
+
+    impl<K, V, S, X> rayon::iter::FromParallelIterator<X> for HashMap<K, V, S>
+    where
+        hb::HashMap<K, V, S>: rayon::iter::FromParallelIterator<X>,
+        X: Send,
+    {
+        #[inline(always)]
+        fn from_par_iter<I>(par_iter: I) -> Self
+        where
+            I: rayon::iter::IntoParallelIterator<Item = X>,
+        {
+            Self(rayon::iter::FromParallelIterator::from_par_iter(par_iter))
+        }
+    }
+}
+
 impl<K, V, S> serde_core::Serialize for HashMap<K, V, S>
 where
     hb::HashMap<K, V, S>: serde_core::Serialize,
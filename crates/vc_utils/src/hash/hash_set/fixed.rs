@@ -266,6 +266,36 @@ impl<T, S> From<crate::hash::HashMap<T, (), S>> for HashSet<T, S> {
     }
 }
 
+crate::cfg::rayon! {
+    impl<T, S, X> rayon::iter::ParallelExtend<X> for HashSet<T, S>
+    where
+        hb::HashSet<T, S>: rayon::iter::ParallelExtend<X>,
+        X: Send,
+    {
+        #[inline(always)]
+        fn par_extend<I>(&mut self, par_iter: I)
+        where
+            I: rayon::iter::IntoParallelIterator<Item = X>,
+        {
+            self.0.par_extend(par_iter);
+        }
+    }
+
+    impl<T, S, X> rayon::iter::FromParallelIterator<X> for HashSet<T, S>
+    where
+        hb::HashSet<T, S>: rayon::iter::FromParallelIterator<X>,
+        X: Send,
+    {
+        #[inline(always)]
+        fn from_par_iter<I>(par_iter: I) -> Self
+        where
+            I: rayon::iter::IntoParallelIterator<Item = X>,
+        {
+            Self(rayon::iter::FromParallelIterator::from_par_iter(par_iter))
+        }
+    }
+}
+
 impl<T, S> serde_core::Serialize for HashSet<T, S>
 where
     hb::HashSet<T, S>: serde_core::Serialize,
@@ -0,0 +1,275 @@
+//! Provide [`Hashed`] and [`PreHashMap`].
+
+use core::fmt::Debug;
+use core::hash::{BuildHasher, Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use hashbrown::Equivalent;
+use hashbrown::hash_map::RawEntryMut;
+
+use crate::hash::{FixedHashState, NoOpHashMap};
+
+// -----------------------------------------------------------------------------
+// Hashed
+
+/// A value with its hash computed in advance.
+///
+/// Hashing a `Hashed` writes the stored `u64` only, so used together with
+/// [`NoOpHashState`](crate::hash::NoOpHashState) the value is never hashed
+/// again, see [`PreHashMap`].
+///
+/// The hash only depends on the value and `S`. Like [`Borrow`], types that
+/// hash identically produce the same hash, so a `Hashed<&str>` matches a
+/// `Hashed<String>` built from the same text.
+///
+/// # Examples
+///
+/// ```
+/// use vc_utils::hash::Hashed;
+///
+/// let a = Hashed::new(String::from("foo"));
+/// let b = Hashed::new("foo");
+///
+/// assert_eq!(a.hash(), b.hash());
+/// assert_eq!(*a, *b);
+/// ```
+///
+/// [`Borrow`]: core::borrow::Borrow
+pub struct Hashed<V, S = FixedHashState> {
+    hash: u64,
+    value: V,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<V: Hash> Hashed<V> {
+    /// Computes the hash of `value` with [`FixedHashState`].
+    #[inline]
+    pub fn new(value: V) -> Self {
+        Self::with_hasher(value, &FixedHashState)
+    }
+}
+
+impl<V: Hash, S: BuildHasher> Hashed<V, S> {
+    /// Computes the hash of `value` with the given hash builder.
+    #[inline]
+    pub fn with_hasher(value: V, hash_builder: &S) -> Self {
+        Self {
+            hash: hash_builder.hash_one(&value),
+            value,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<V, S> Hashed<V, S> {
+    /// Returns the precomputed hash.
+    #[inline(always)]
+    pub const fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Returns the inner value.
+    #[inline(always)]
+    pub fn into_inner(self) -> V {
+        self.value
+    }
+
+    /// Borrows the value, keeping the hash.
+    ///
+    /// The result can be used to query a [`PreHashMap`].
+    #[inline]
+    pub const fn by_ref(&self) -> Hashed<&V, S> {
+        Hashed {
+            hash: self.hash,
+            value: &self.value,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<V, S> Deref for Hashed<V, S> {
+    type Target = V;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<V, S> Hash for Hashed<V, S> {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+impl<V: PartialEq, S> PartialEq for Hashed<V, S> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.value == other.value
+    }
+}
+
+impl<V: Eq, S> Eq for Hashed<V, S> {}
+
+impl<V: Clone, S> Clone for Hashed<V, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            hash: self.hash,
+            value: self.value.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<V: Copy, S> Copy for Hashed<V, S> {}
+
+impl<V: Debug, S> Debug for Hashed<V, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Hashed")
+            .field("hash", &self.hash)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// PreHashMap
+
+/// A [`NoOpHashMap`] whose keys are [`Hashed`], so keys are hashed only once.
+///
+/// Besides the usual methods, it can be queried by a `Hashed<&Q>` for any `Q`
+/// [equivalent](Equivalent) to the key type, without building a key. For
+/// example, a map keyed by `Hashed<String>` is queried by `Hashed<&str>`.
+///
+/// # Examples
+///
+/// ```
+/// use vc_utils::hash::{Hashed, PreHashMap};
+///
+/// let mut map = PreHashMap::<String, u32>::new();
+/// map.insert(Hashed::new(String::from("foo")), 1);
+///
+/// let key = Hashed::new("foo");
+/// assert_eq!(map.get_hashed(key), Some(&1));
+///
+/// *map.get_or_insert_with_hashed(Hashed::new("bar"), String::from, || 0) += 2;
+/// assert_eq!(map.get_hashed(Hashed::new("bar")), Some(&2));
+/// ```
+pub type PreHashMap<K, V, S = FixedHashState> = NoOpHashMap<Hashed<K, S>, V>;
+
+impl<K, V, S> NoOpHashMap<Hashed<K, S>, V> {
+    /// Returns a reference to the value of the key equivalent to `key`.
+    #[inline]
+    pub fn get_hashed<Q>(&self, key: Hashed<&Q, S>) -> Option<&V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.raw_entry()
+            .from_hash(key.hash, |k| key.value.equivalent(&k.value))
+            .map(|(_, v)| v)
+    }
+
+    /// Returns a mutable reference to the value of the key equivalent to `key`.
+    #[inline]
+    pub fn get_hashed_mut<Q>(&mut self, key: Hashed<&Q, S>) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        match self
+            .raw_entry_mut()
+            .from_hash(key.hash, |k| key.value.equivalent(&k.value))
+        {
+            RawEntryMut::Occupied(entry) => Some(entry.into_mut()),
+            RawEntryMut::Vacant(_) => None,
+        }
+    }
+
+    /// Returns `true` if the map contains a key equivalent to `key`.
+    #[inline]
+    pub fn contains_hashed<Q>(&self, key: Hashed<&Q, S>) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.get_hashed(key).is_some()
+    }
+
+    /// Removes the key equivalent to `key`, returning its value.
+    #[inline]
+    pub fn remove_hashed<Q>(&mut self, key: Hashed<&Q, S>) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        match self
+            .raw_entry_mut()
+            .from_hash(key.hash, |k| key.value.equivalent(&k.value))
+        {
+            RawEntryMut::Occupied(entry) => Some(entry.remove()),
+            RawEntryMut::Vacant(_) => None,
+        }
+    }
+
+    /// Returns the value of the key equivalent to `key`, inserting one if
+    /// it is missing.
+    ///
+    /// The key is only built by `to_key` when missing, and reuses the hash
+    /// of `key`, so `to_key` must return a key that hashes identically.
+    pub fn get_or_insert_with_hashed<'a, Q>(
+        &mut self,
+        key: Hashed<&'a Q, S>,
+        to_key: impl FnOnce(&'a Q) -> K,
+        value: impl FnOnce() -> V,
+    ) -> &mut V
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        match self
+            .raw_entry_mut()
+            .from_hash(key.hash, |k| key.value.equivalent(&k.value))
+        {
+            RawEntryMut::Occupied(entry) => entry.into_mut(),
+            RawEntryMut::Vacant(entry) => {
+                let k = Hashed {
+                    hash: key.hash,
+                    value: to_key(key.value),
+                    marker: PhantomData,
+                };
+                entry.insert_hashed_nocheck(key.hash, k, value()).1
+            }
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use super::{Hashed, PreHashMap};
+    use crate::hash::HashMap;
+
+    #[test]
+    fn heterogeneous_lookup() {
+        let mut map = HashMap::<String, u32>::new();
+        map.insert(String::from("foo"), 1);
+        assert_eq!(map.get("foo"), Some(&1));
+
+        let mut map = PreHashMap::<String, u32>::new();
+        let owned = Hashed::new(String::from("foo"));
+        map.insert(owned.clone(), 1);
+
+        assert_eq!(map.get(&owned), Some(&1));
+        assert_eq!(map.get_hashed(owned.by_ref()), Some(&1));
+        assert_eq!(map.get_hashed(Hashed::new("foo")), Some(&1));
+        assert!(!map.contains_hashed(Hashed::new("bar")));
+
+        *map.get_or_insert_with_hashed(Hashed::new("bar"), String::from, || 1) += 1;
+        assert_eq!(map.get(&Hashed::new(String::from("bar"))), Some(&2));
+        assert_eq!(map.remove_hashed(Hashed::new("foo")), Some(1));
+        assert_eq!(map.len(), 1);
+    }
+}
@@ -3,6 +3,7 @@
 // -----------------------------------------------------------------------------
 // Modules
 
+mod hashed;
 mod hasher;
 
 pub mod hash_map;
@@ -12,6 +13,7 @@ pub mod hash_table;
 // -----------------------------------------------------------------------------
 // Exports
 
+pub use hashed::{Hashed, PreHashMap};
 pub use hasher::{FixedHashState, FixedHasher};
 pub use hasher::{NoOpHashState, NoOpHasher};
 pub use hasher::{SparseHashState, SparseHasher};
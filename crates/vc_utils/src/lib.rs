@@ -2,6 +2,16 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![no_std]
 
+// -----------------------------------------------------------------------------
+// Compilation config
+
+/// Some macros used for compilation control.
+pub mod cfg {
+    vc_cfg::define_alias! {
+        #[cfg(feature = "rayon")] => rayon,
+    }
+}
+
 // -----------------------------------------------------------------------------
 // No STD Support
 
@@ -14,12 +24,17 @@ mod cold_path;
 mod range_invoke;
 
 pub mod extra;
+pub mod grid;
 pub mod hash;
 pub mod index;
 pub mod num;
 
 pub mod vec;
 
+cfg::rayon! {
+    pub mod parallel;
+}
+
 // -----------------------------------------------------------------------------
 // Top-level exports
 
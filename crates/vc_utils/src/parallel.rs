@@ -0,0 +1,180 @@
+//! Parallel helpers for slices and hash containers, based on *[rayon]*.
+//!
+//! Only available when the `rayon` feature is enabled.
+//!
+//! - [`par_sort_unstable_by_key`]: Sort a slice in parallel by a derived key.
+//! - [`par_chunks_map`]: Map each chunk of a slice in parallel, preserving chunk order.
+//! - [`par_for_each_tile`]: Process the tiles of a 2D grid in parallel.
+//! - [`par_extend`]: Extend a [`HashMap`] from a parallel iterator.
+//!
+//! Small inputs fall back to the sequential implementation, because
+//! the cost of splitting work across threads outweighs the benefit.
+//!
+//! [`HashMap`]: crate::hash::HashMap
+
+use alloc::vec::Vec;
+use core::hash::{BuildHasher, Hash};
+use core::ops::Range;
+
+use rayon::iter::{IntoParallelIterator, ParallelExtend, ParallelIterator};
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+
+use crate::grid::{for_each_tile, tile_at, tile_count};
+use crate::hash::HashMap;
+
+// -----------------------------------------------------------------------------
+// Re-export crates
+
+pub use rayon;
+
+// -----------------------------------------------------------------------------
+// Config
+
+/// Slices shorter than this are processed sequentially.
+pub const SEQUENTIAL_THRESHOLD: usize = 1024;
+
+// -----------------------------------------------------------------------------
+// Slice helpers
+
+/// Sorts the slice in parallel with a key extraction function,
+/// but might not preserve the order of equal elements.
+///
+/// Falls back to [`slice::sort_unstable_by_key`] if the slice length
+/// is less than [`SEQUENTIAL_THRESHOLD`].
+///
+/// # Examples
+///
+/// ```
+/// use vc_utils::parallel::par_sort_unstable_by_key;
+///
+/// let mut v = [-5i32, 4, 1, -3, 2];
+/// par_sort_unstable_by_key(&mut v, |k| k.abs());
+///
+/// assert_eq!(v, [1, 2, -3, 4, -5]);
+/// ```
+pub fn par_sort_unstable_by_key<T, K, F>(slice: &mut [T], f: F)
+where
+    T: Send,
+    K: Ord,
+    F: Fn(&T) -> K + Sync,
+{
+    if slice.len() < SEQUENTIAL_THRESHOLD {
+        slice.sort_unstable_by_key(f);
+    } else {
+        slice.par_sort_unstable_by_key(f);
+    }
+}
+
+/// Splits the slice into chunks of `chunk_size` elements and maps each
+/// chunk in parallel, returning the results in chunk order.
+///
+/// The last chunk will be shorter if `chunk_size` does not divide the
+/// length of the slice.
+///
+/// # Panics
+///
+/// Panics if `chunk_size` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use vc_utils::parallel::par_chunks_map;
+///
+/// let v = [1, 2, 3, 4, 5, 6, 7];
+/// let sums = par_chunks_map(&v, 3, |chunk| chunk.iter().sum::<i32>());
+///
+/// assert_eq!(sums, [6, 15, 7]);
+/// ```
+pub fn par_chunks_map<T, R, F>(slice: &[T], chunk_size: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&[T]) -> R + Sync + Send,
+{
+    assert!(chunk_size != 0, "chunk size must be non-zero");
+
+    if slice.len() <= chunk_size {
+        return slice.chunks(chunk_size).map(f).collect();
+    }
+
+    slice.par_chunks(chunk_size).map(f).collect()
+}
+
+// -----------------------------------------------------------------------------
+// Grid helpers
+
+/// Calls `f` with the x and y ranges of each `tile * tile` block of a
+/// `width * height` grid, processing the tiles in parallel.
+///
+/// This is the parallel version of [`for_each_tile`], the tiles are the
+/// same but are visited in no particular order.
+///
+/// Falls back to [`for_each_tile`] if the grid has less than
+/// [`SEQUENTIAL_THRESHOLD`] cells.
+///
+/// # Panics
+///
+/// Panics if `tile` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use core::sync::atomic::{AtomicUsize, Ordering};
+/// use vc_utils::parallel::par_for_each_tile;
+///
+/// let cells = AtomicUsize::new(0);
+/// par_for_each_tile(100, 50, 16, |xs, ys| {
+///     cells.fetch_add(xs.len() * ys.len(), Ordering::Relaxed);
+/// });
+///
+/// assert_eq!(cells.into_inner(), 100 * 50);
+/// ```
+///
+/// [`for_each_tile`]: crate::grid::for_each_tile
+pub fn par_for_each_tile<F>(width: usize, height: usize, tile: usize, f: F)
+where
+    F: Fn(Range<usize>, Range<usize>) + Sync + Send,
+{
+    if width.saturating_mul(height) < SEQUENTIAL_THRESHOLD {
+        return for_each_tile(width, height, tile, f);
+    }
+
+    let (tiles_x, tiles_y) = tile_count(width, height, tile);
+    (0..tiles_x * tiles_y).into_par_iter().for_each(|index| {
+        let (xs, ys) = tile_at(width, height, tile, tiles_x, index);
+        f(xs, ys);
+    });
+}
+
+// -----------------------------------------------------------------------------
+// Hash container helpers
+
+/// Extends the [`HashMap`] with the key-value pairs of a parallel iterator.
+///
+/// Equivalent to [`ParallelExtend::par_extend`], provided as a free function
+/// so that the call site does not need to import the rayon trait.
+///
+/// # Examples
+///
+/// ```
+/// use vc_utils::hash::HashMap;
+/// use vc_utils::parallel::par_extend;
+///
+/// let mut map: HashMap<u32, u32> = HashMap::new();
+/// par_extend(&mut map, (0..100u32).map(|i| (i, i * 2)).collect::<Vec<_>>());
+///
+/// assert_eq!(map.len(), 100);
+/// assert_eq!(map[&7], 14);
+/// ```
+///
+/// [`HashMap`]: crate::hash::HashMap
+#[inline]
+pub fn par_extend<K, V, S, I>(map: &mut HashMap<K, V, S>, par_iter: I)
+where
+    K: Eq + Hash + Send,
+    V: Send,
+    S: BuildHasher,
+    I: IntoParallelIterator<Item = (K, V)>,
+{
+    map.par_extend(par_iter);
+}
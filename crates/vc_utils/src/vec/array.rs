@@ -280,6 +280,24 @@ impl<T, const N: usize> ArrayVec<T, N> {
         vec
     }
 
+    /// Converts a [`ArrayVec`] to a [`Box<[T]>`](Box).
+    ///
+    /// Allocates exactly `len` elements, like [`into_vec`](ArrayVec::into_vec).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_utils::vec::ArrayVec;
+    /// let mut vec = ArrayVec::<i32, 5>::new();
+    /// vec.extend([1, 2]);
+    /// let boxed: Box<[i32]> = vec.into_boxed_slice();
+    /// assert_eq!(&*boxed, &[1, 2]);
+    /// ```
+    #[inline]
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        self.into_vec().into_boxed_slice()
+    }
+
     /// Appends an element to the back of the vector.
     ///
     /// # Panics
@@ -816,6 +834,33 @@ impl<T: PartialEq, const N: usize> ArrayVec<T, N> {
     }
 }
 
+impl<T: Clone, const N: usize> ArrayVec<T, N> {
+    /// Clones and appends all elements in a slice to the vector.
+    ///
+    /// # Panics
+    /// Panics if the remaining capacity is insufficient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_utils::vec::ArrayVec;
+    /// let mut vec = ArrayVec::<i32, 4>::new();
+    /// vec.push(1);
+    /// vec.extend_from_slice(&[2, 3]);
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        assert!(
+            other.len() <= N - self.len,
+            "length overflow during `extend_from_slice`"
+        );
+        other.iter().for_each(|item| {
+            // SAFETY: the capacity is checked above.
+            unsafe { self.push_unchecked(item.clone()) }
+        });
+    }
+}
+
 impl<T: Clone, const N: usize> Clone for ArrayVec<T, N> {
     /// See [`Clone::clone`]
     ///
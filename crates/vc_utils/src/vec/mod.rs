@@ -37,14 +37,22 @@
 //! All three types aim to reduce heap allocations for small workloads.
 //! For larger payloads and cross-boundary ownership transfer, converting
 //! into [`Vec`](alloc::vec::Vec) is often the most interoperable choice.
+//!
+//! # Sorting
+//!
+//! [`radix_sort_by_key_u32`] and [`radix_sort_by_key_u64`] are stable LSD radix
+//! sorts for slices with integer keys, e.g. entity or render keys. Use
+//! [`RadixSortBuffers`] to reuse the scratch memory across calls.
 #![expect(unsafe_code, reason = "original implementation")]
 
 pub mod array;
 pub mod fast;
 pub mod small;
 
+mod radix;
 mod utils;
 
 pub use array::ArrayVec;
 pub use fast::FastVec;
+pub use radix::{RadixSortBuffers, radix_sort_by_key_u32, radix_sort_by_key_u64};
 pub use small::SmallVec;
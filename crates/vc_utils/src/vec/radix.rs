@@ -0,0 +1,260 @@
+//! LSD radix sort for slices keyed by `u32` or `u64`.
+//!
+//! The sort works on `(key, index)` pairs stored in [`RadixSortBuffers`], then
+//! moves the elements into place with swaps, so `T` needs neither `Copy` nor
+//! `Clone`. Like [`slice::sort_by_key`], the sort is stable.
+//!
+//! Byte positions where all keys are equal are skipped, so keys using only the
+//! low bits (e.g. entity indices) cost fewer passes.
+
+use alloc::vec::Vec;
+
+/// Below this length, the standard library sort is faster.
+const SMALL_SORT_THRESHOLD: usize = 64;
+
+// -----------------------------------------------------------------------------
+// RadixSortBuffers
+
+/// Scratch buffers for radix sorting, reusable across calls.
+///
+/// Keeping one instance around (e.g. in a system local or a resource) avoids
+/// the per-call allocations of [`radix_sort_by_key_u32`] and
+/// [`radix_sort_by_key_u64`].
+///
+/// # Examples
+///
+/// ```
+/// use vc_utils::vec::RadixSortBuffers;
+///
+/// let mut buffers = RadixSortBuffers::new();
+/// let mut items = [(3_u32, 'c'), (1, 'a'), (2, 'b')];
+/// buffers.sort_by_key_u32(&mut items, |item| item.0);
+/// assert_eq!(items, [(1, 'a'), (2, 'b'), (3, 'c')]);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct RadixSortBuffers {
+    /// `u32` key in the high half, index in the low half.
+    packed: [Vec<u64>; 2],
+    pairs: [Vec<(u64, u32)>; 2],
+}
+
+impl RadixSortBuffers {
+    /// Creates empty buffers, does not allocate.
+    pub const fn new() -> Self {
+        Self {
+            packed: [Vec::new(), Vec::new()],
+            pairs: [Vec::new(), Vec::new()],
+        }
+    }
+
+    /// Releases the memory held by the buffers.
+    pub fn shrink_to_fit(&mut self) {
+        self.packed.iter_mut().for_each(|v| {
+            v.clear();
+            v.shrink_to_fit();
+        });
+        self.pairs.iter_mut().for_each(|v| {
+            v.clear();
+            v.shrink_to_fit();
+        });
+    }
+
+    /// Sorts the slice with a `u32` key extraction function, see [`radix_sort_by_key_u32`].
+    pub fn sort_by_key_u32<T>(&mut self, slice: &mut [T], mut key: impl FnMut(&T) -> u32) {
+        if slice.len() < SMALL_SORT_THRESHOLD {
+            slice.sort_by_key(key);
+            return;
+        }
+        assert!(
+            slice.len() <= u32::MAX as usize,
+            "slice is too long for radix sort"
+        );
+
+        let [src, tmp] = &mut self.packed;
+        src.clear();
+        src.extend(
+            slice
+                .iter()
+                .enumerate()
+                .map(|(i, item)| ((key(item) as u64) << 32) | i as u64),
+        );
+        lsd_sort(src, tmp, 4, |v, byte| (v >> (32 + byte * 8)) as u8);
+        apply_order(slice, src, |v| *v as u32 as usize, |v, i| *v = i as u64);
+    }
+
+    /// Sorts the slice with a `u64` key extraction function, see [`radix_sort_by_key_u64`].
+    pub fn sort_by_key_u64<T>(&mut self, slice: &mut [T], mut key: impl FnMut(&T) -> u64) {
+        if slice.len() < SMALL_SORT_THRESHOLD {
+            slice.sort_by_key(key);
+            return;
+        }
+        assert!(
+            slice.len() <= u32::MAX as usize,
+            "slice is too long for radix sort"
+        );
+
+        let [src, tmp] = &mut self.pairs;
+        src.clear();
+        src.extend(
+            slice
+                .iter()
+                .enumerate()
+                .map(|(i, item)| (key(item), i as u32)),
+        );
+        lsd_sort(src, tmp, 8, |v, byte| (v.0 >> (byte * 8)) as u8);
+        apply_order(slice, src, |v| v.1 as usize, |v, i| v.1 = i as u32);
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Functions
+
+/// Sorts the slice with a `u32` key extraction function, using LSD radix sort.
+///
+/// This sort is stable and runs in `O(n)`, but allocates scratch memory on
+/// every call. Use [`RadixSortBuffers`] to reuse it.
+///
+/// # Panics
+///
+/// Panics if the slice has more than `u32::MAX` elements.
+///
+/// # Examples
+///
+/// ```
+/// use vc_utils::vec::radix_sort_by_key_u32;
+///
+/// let mut v = [5_u32, 1, 4, 2, 3];
+/// radix_sort_by_key_u32(&mut v, |x| *x);
+/// assert_eq!(v, [1, 2, 3, 4, 5]);
+/// ```
+pub fn radix_sort_by_key_u32<T>(slice: &mut [T], key: impl FnMut(&T) -> u32) {
+    RadixSortBuffers::new().sort_by_key_u32(slice, key);
+}
+
+/// Sorts the slice with a `u64` key extraction function, using LSD radix sort.
+///
+/// This sort is stable and runs in `O(n)`, but allocates scratch memory on
+/// every call. Use [`RadixSortBuffers`] to reuse it.
+///
+/// # Panics
+///
+/// Panics if the slice has more than `u32::MAX` elements.
+pub fn radix_sort_by_key_u64<T>(slice: &mut [T], key: impl FnMut(&T) -> u64) {
+    RadixSortBuffers::new().sort_by_key_u64(slice, key);
+}
+
+// -----------------------------------------------------------------------------
+// Implementation
+
+/// Sorts `src` by the first `bytes` bytes returned by `digit`, using `tmp` as scratch.
+fn lsd_sort<E: Copy>(
+    src: &mut Vec<E>,
+    tmp: &mut Vec<E>,
+    bytes: usize,
+    digit: impl Fn(E, usize) -> u8,
+) {
+    let len = src.len();
+
+    // All histograms are built in a single pass.
+    let mut counts = [[0_usize; 256]; 8];
+    for &v in src.iter() {
+        for (byte, count) in counts[..bytes].iter_mut().enumerate() {
+            count[digit(v, byte) as usize] += 1;
+        }
+    }
+
+    tmp.clear();
+    tmp.resize(len, src[0]);
+
+    for (byte, count) in counts[..bytes].iter().enumerate() {
+        // Every key has the same digit, this pass would not move anything.
+        if count.contains(&len) {
+            continue;
+        }
+
+        let mut offsets = [0_usize; 256];
+        let mut sum = 0;
+        for (offset, &n) in offsets.iter_mut().zip(count) {
+            *offset = sum;
+            sum += n;
+        }
+
+        for &v in src.iter() {
+            let offset = &mut offsets[digit(v, byte) as usize];
+            tmp[*offset] = v;
+            *offset += 1;
+        }
+        core::mem::swap(src, tmp);
+    }
+}
+
+/// Moves the element at `index(order[i])` to `i`, following permutation cycles.
+///
+/// Each visited entry is marked with `mark(entry, i)` so `index` returns `i`.
+fn apply_order<T, E>(
+    slice: &mut [T],
+    order: &mut [E],
+    index: impl Fn(&E) -> usize,
+    mark: impl Fn(&mut E, usize),
+) {
+    for start in 0..slice.len() {
+        let mut current = start;
+        loop {
+            let next = index(&order[current]);
+            mark(&mut order[current], current);
+            if next == start || next == current {
+                break;
+            }
+            slice.swap(current, next);
+            current = next;
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::RadixSortBuffers;
+
+    /// Deterministic xorshift, keeps the tests reproducible.
+    fn random_keys(len: usize, mask: u64) -> Vec<u64> {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state & mask
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_stable_sort() {
+        let mut buffers = RadixSortBuffers::new();
+        for (len, mask) in [
+            (10, u64::MAX),
+            (1000, 0xff),
+            (1000, u64::MAX),
+            (5000, 0xff00_0000),
+        ] {
+            let items: Vec<(u64, usize)> = random_keys(len, mask).into_iter().zip(0..).collect();
+
+            let mut expected = items.clone();
+            expected.sort_by_key(|item| item.0);
+            let mut sorted = items.clone();
+            buffers.sort_by_key_u64(&mut sorted, |item| item.0);
+            assert_eq!(sorted, expected);
+
+            let mut expected = items.clone();
+            expected.sort_by_key(|item| item.0 as u32);
+            let mut sorted = items;
+            buffers.sort_by_key_u32(&mut sorted, |item| item.0 as u32);
+            assert_eq!(sorted, expected);
+        }
+    }
+}
@@ -2,10 +2,12 @@ use alloc::boxed::Box;
 use alloc::vec::Vec;
 use core::fmt::Debug;
 use core::iter::FusedIterator;
+use core::marker::PhantomData;
 use core::mem::ManuallyDrop;
 use core::ptr;
 
-use super::utils::min_cap;
+use super::ArrayVec;
+use super::utils::{min_cap, split_range_bound};
 use crate::cold_path;
 use cache::Cache;
 
@@ -317,6 +319,21 @@ impl<T, const N: usize> SmallVec<T, N> {
         }
     }
 
+    /// Convert [`SmallVec`] to [`Box<[T]>`](Box).
+    ///
+    /// This will drop any excess capacity, see [`Vec::into_boxed_slice`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use vc_utils::vec::SmallVec;
+    /// let vec: SmallVec<_, 4> = [1, 2, 3].into();
+    /// assert_eq!(&*vec.into_boxed_slice(), &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        self.into_vec().into_boxed_slice()
+    }
+
     /// Extracts a slice containing the entire vector.
     #[inline]
     pub const fn as_slice(&self) -> &[T] {
@@ -534,6 +551,90 @@ impl<T, const N: usize> SmallVec<T, N> {
         }
     }
 
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// This method operates in place, visiting each element exactly once in the
+    /// original order, and preserves the order of the retained elements.
+    ///
+    /// This function does not affect the position (stack/heap) of the data.
+    ///
+    /// # Examples
+    /// ```
+    /// # use vc_utils::vec::SmallVec;
+    /// let mut vec: SmallVec<_, 4> = [1, 2, 3, 4].into();
+    /// vec.retain(|v| *v % 2 == 0);
+    /// assert_eq!(vec, [2, 4]);
+    /// ```
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|v| f(v));
+    }
+
+    /// Retains only the elements specified by the predicate, passing a mutable reference to it.
+    ///
+    /// See [`retain`](SmallVec::retain).
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        match &mut self.0 {
+            InnerVec::Cache(vec) => {
+                // Kept elements are swapped to the front, the elements between
+                // `kept` and `index` are all rejected, so the order is preserved.
+                let slice = vec.as_mut_slice();
+                let mut kept = 0;
+                for index in 0..slice.len() {
+                    if f(&mut slice[index]) {
+                        slice.swap(kept, index);
+                        kept += 1;
+                    }
+                }
+                vec.truncate(kept);
+            }
+            InnerVec::Heap(vec) => {
+                cold_path();
+                vec.retain_mut(f);
+            }
+        }
+    }
+
+    /// Removes the specified range from the vector in bulk,
+    /// returning all removed elements as an iterator.
+    ///
+    /// Unlike [`Vec::drain`], the elements are removed from a stack vector
+    /// immediately, even if the iterator is leaked.
+    ///
+    /// This function does not affect the position (stack/heap) of the data.
+    ///
+    /// # Panics
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use vc_utils::vec::SmallVec;
+    /// let mut v: SmallVec<_, 4> = [1, 2, 3, 4].into();
+    /// let u: Vec<_> = v.drain(1..3).collect();
+    /// assert_eq!(v, [1, 4]);
+    /// assert_eq!(u, [2, 3]);
+    /// ```
+    pub fn drain<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, N> {
+        match &mut self.0 {
+            InnerVec::Cache(vec) => {
+                let (start, end) = split_range_bound(&range, vec.len());
+                // Move the range to the end, then pop it in reverse.
+                vec.as_mut_slice()[start..].rotate_left(end - start);
+                let mut drained: ArrayVec<T, N> = ArrayVec::new();
+                for _ in start..end {
+                    drained.push(vec.pop().unwrap());
+                }
+                drained.reverse();
+                Drain(InternalDrain::Cache(drained.into_iter(), PhantomData))
+            }
+            InnerVec::Heap(vec) => {
+                cold_path();
+                Drain(InternalDrain::Heap(vec.drain(range)))
+            }
+        }
+    }
+
     /// Clears the vector, removing all values.
     ///
     /// Note that this method has no effect on the allocated capacity of the vector.
@@ -558,6 +659,24 @@ impl<T, const N: usize> SmallVec<T, N> {
     }
 }
 
+impl<T: Clone, const N: usize> SmallVec<T, N> {
+    /// Clones and appends all elements in a slice to the vector.
+    ///
+    /// If the heap is insufficient, it will switch to [`Vec`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use vc_utils::vec::SmallVec;
+    /// let mut vec: SmallVec<_, 4> = [1].into();
+    /// vec.extend_from_slice(&[2, 3, 4, 5]);
+    /// assert_eq!(vec, [1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        self.extend(other);
+    }
+}
+
 impl<T: PartialEq, const N: usize> SmallVec<T, N> {
     /// Removes consecutive repeated elements in the vector according
     /// to the PartialEq trait implementation.
@@ -819,6 +938,80 @@ impl<T: Debug, const N: usize> Debug for IntoIter<T, N> {
     }
 }
 
+// -----------------------------------------------------------------------------
+// Drain
+
+enum InternalDrain<'a, T, const N: usize> {
+    Cache(super::array::IntoIter<T, N>, PhantomData<&'a mut T>),
+    Heap(alloc::vec::Drain<'a, T>),
+}
+
+/// A draining iterator for [`SmallVec`], see [`SmallVec::drain`].
+#[repr(transparent)]
+pub struct Drain<'a, T, const N: usize>(InternalDrain<'a, T, N>);
+
+impl<T, const N: usize> Drain<'_, T, N> {
+    /// Returns a slice of the remaining elements that have not been yielded yet.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        match &self.0 {
+            InternalDrain::Cache(iter, _) => iter.as_slice(),
+            InternalDrain::Heap(iter) => {
+                cold_path();
+                iter.as_slice()
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for Drain<'_, T, N> {
+    type Item = T;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            InternalDrain::Cache(iter, _) => Iterator::next(iter),
+            InternalDrain::Heap(iter) => {
+                cold_path();
+                Iterator::next(iter)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.0 {
+            InternalDrain::Cache(iter, _) => Iterator::size_hint(iter),
+            InternalDrain::Heap(iter) => {
+                cold_path();
+                Iterator::size_hint(iter)
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for Drain<'_, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            InternalDrain::Cache(iter, _) => DoubleEndedIterator::next_back(iter),
+            InternalDrain::Heap(iter) => {
+                cold_path();
+                DoubleEndedIterator::next_back(iter)
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Drain<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for Drain<'_, T, N> {}
+
+impl<T: Debug, const N: usize> Debug for Drain<'_, T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Drain").field(&self.as_slice()).finish()
+    }
+}
+
 mod cache {
     use crate::cold_path;
     use crate::num::NonMaxUsize;
@@ -1395,4 +1588,35 @@ mod tests {
         drop(vec);
         assert_eq!(DROPS.load(Ordering::SeqCst), 3);
     }
+
+    #[test]
+    fn drain_retain_drops() {
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Tracker(u32);
+        impl Drop for Tracker {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        DROPS.store(0, Ordering::SeqCst);
+        let mut vec = SmallVec::<Tracker, 4>::new();
+        (0..4).for_each(|i| vec.push(Tracker(i)));
+
+        // Unyielded elements are dropped with the iterator.
+        let mut drain = vec.drain(1..3);
+        assert_eq!(drain.next().map(|t| t.0), Some(1));
+        drop(drain);
+        assert_eq!(DROPS.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            vec.iter().map(|t| t.0).collect::<alloc::vec::Vec<_>>(),
+            [0, 3]
+        );
+
+        vec.retain(|t| t.0 == 3);
+        assert_eq!(DROPS.load(Ordering::SeqCst), 3);
+        assert_eq!(vec.len(), 1);
+        assert_eq!(vec[0].0, 3);
+    }
 }